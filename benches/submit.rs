@@ -1,8 +1,8 @@
-use axum::Json;
 use criterion::{black_box, criterion_group, criterion_main, BatchSize, Criterion};
 use mozart::{
+    check_submission, generate_test_code,
     model::{Parameter, ParameterType, Submission, TestCase},
-    submit,
+    state::AppState,
 };
 use tokio::runtime::Runtime;
 
@@ -19,6 +19,10 @@ fn pass(c: &mut Criterion) {
                 value_type: ParameterType::Int,
                 value: String::from("5"),
             }]),
+            stdin: None,
+            expected_error: None,
+            label: None,
+            hidden: false,
         };
 
         test_cases.push(test_case);
@@ -35,13 +39,23 @@ fn pass(c: &mut Criterion) {
         ]
         .join("\n"),
         test_cases: test_cases.into_boxed_slice(),
+        unordered_lists: false,
+        files: Box::new([]),
+        checker: None,
+        reference_solution: None,
+        only_ids: None,
+        compiler_flags: Box::new([]),
+        debug: false,
+        float_tolerance: None,
+        toolchain_version: None,
+        metadata: None,
     };
 
     c.bench_function("pass baseline", |b| {
         b.to_async(Runtime::new().expect("failed to initialise tokio runtime"))
             .iter_batched(
-                || Json(submission.clone()),
-                |submission: Json<Submission>| submit(black_box(submission)),
+                || submission.clone(),
+                |submission| check_submission(black_box(submission), AppState::default()),
                 BatchSize::SmallInput,
             )
     });
@@ -60,6 +74,10 @@ fn fail(c: &mut Criterion) {
                 value_type: ParameterType::Int,
                 value: String::from("5"),
             }]),
+            stdin: None,
+            expected_error: None,
+            label: None,
+            hidden: false,
         };
 
         test_cases.push(test_case);
@@ -68,17 +86,104 @@ fn fail(c: &mut Criterion) {
     let submission = Submission {
         solution: ["module Solution where", "", "solution x = x"].join("\n"),
         test_cases: test_cases.into_boxed_slice(),
+        unordered_lists: false,
+        files: Box::new([]),
+        checker: None,
+        reference_solution: None,
+        only_ids: None,
+        compiler_flags: Box::new([]),
+        debug: false,
+        float_tolerance: None,
+        toolchain_version: None,
+        metadata: None,
     };
 
     c.bench_function("fail baseline", |b| {
         b.to_async(Runtime::new().expect("failed to initialise tokio runtime"))
             .iter_batched(
-                || Json(submission.clone()),
-                |submission: Json<Submission>| submit(black_box(submission)),
+                || submission.clone(),
+                |submission| check_submission(black_box(submission), AppState::default()),
                 BatchSize::SmallInput,
             )
     });
 }
 
-criterion_group!(benches, pass, fail);
+fn pass_large(c: &mut Criterion) {
+    let mut test_cases = Vec::with_capacity(1000);
+    for id in 0..1000 {
+        let test_case = TestCase {
+            id,
+            input_parameters: Box::new([Parameter {
+                value_type: ParameterType::Int,
+                value: id.to_string(),
+            }]),
+            output_parameters: Box::new([Parameter {
+                value_type: ParameterType::Int,
+                value: id.to_string(),
+            }]),
+            stdin: None,
+            expected_error: None,
+            label: None,
+            hidden: false,
+        };
+
+        test_cases.push(test_case);
+    }
+
+    let submission = Submission {
+        solution: ["module Solution where", "", "solution x = x"].join("\n"),
+        test_cases: test_cases.into_boxed_slice(),
+        unordered_lists: false,
+        files: Box::new([]),
+        checker: None,
+        reference_solution: None,
+        only_ids: None,
+        compiler_flags: Box::new([]),
+        debug: false,
+        float_tolerance: None,
+        toolchain_version: None,
+        metadata: None,
+    };
+
+    // end to end, the cost of generating the test code of many test cases is included in compiling and executing it
+    c.bench_function("pass 1000 test cases", |b| {
+        b.to_async(Runtime::new().expect("failed to initialise tokio runtime"))
+            .iter_batched(
+                || submission.clone(),
+                |submission| check_submission(black_box(submission), AppState::default()),
+                BatchSize::SmallInput,
+            )
+    });
+}
+
+fn generate_large(c: &mut Criterion) {
+    let mut test_cases = Vec::with_capacity(1000);
+    for id in 0..1000 {
+        let test_case = TestCase {
+            id,
+            input_parameters: Box::new([Parameter {
+                value_type: ParameterType::Int,
+                value: id.to_string(),
+            }]),
+            output_parameters: Box::new([Parameter {
+                value_type: ParameterType::Int,
+                value: id.to_string(),
+            }]),
+            stdin: None,
+            expected_error: None,
+            label: None,
+            hidden: false,
+        };
+
+        test_cases.push(test_case);
+    }
+    let state = AppState::default();
+
+    // unlike checking the whole submission, this is not dominated by compiling and executing it
+    c.bench_function("generate 1000 test cases", |b| {
+        b.iter(|| generate_test_code(black_box(&test_cases), state.clone()))
+    });
+}
+
+criterion_group!(benches, pass, fail, pass_large, generate_large);
 criterion_main!(benches);
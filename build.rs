@@ -0,0 +1,30 @@
+//! Selects the language of mozart from its language features, as the `language` cfg, e.g. `cfg(language = "python")`.
+//!
+//! The language items of the crate are compiled for the selected language rather than for the features themselves,
+//! such that a build without exactly one language feature, which `lib.rs` rejects with `compile_error!`,
+//! still selects a single language, and only reports that error.
+
+use std::env;
+
+/// The languages of mozart, in the order they are selected in if more than one language feature is enabled.
+const LANGUAGES: &[&str] = &["cpp", "haskell", "python", "kotlin"];
+
+/// The language that is selected if no language feature is enabled.
+const FALLBACK_LANGUAGE: &str = "python";
+
+fn main() {
+    let values = LANGUAGES
+        .iter()
+        .map(|language| format!("\"{language}\""))
+        .collect::<Vec<_>>()
+        .join(", ");
+    println!("cargo:rustc-check-cfg=cfg(language, values({values}))");
+
+    let language = LANGUAGES
+        .iter()
+        .find(|language| {
+            env::var_os(format!("CARGO_FEATURE_{}", language.to_uppercase())).is_some()
+        })
+        .unwrap_or(&FALLBACK_LANGUAGE);
+    println!("cargo:rustc-cfg=language=\"{language}\"");
+}
@@ -0,0 +1,165 @@
+//! Contains the optional API key authentication of mozart.
+//!
+//! Authentication is only enabled if [`Config::api_key`](crate::config::Config::api_key) is set,
+//! in which case requests must supply the key as a bearer token in the `Authorization` header.
+
+use axum::{
+    body::Body,
+    extract::State,
+    http::{header::AUTHORIZATION, Request, StatusCode},
+    middleware::Next,
+    response::{IntoResponse, Response},
+};
+use ring::hmac;
+use std::sync::Arc;
+use tracing::{debug, info};
+
+/// A middleware that rejects requests not carrying the configured API key with a `401` status code.
+///
+/// The key is compared in constant time, such that the time it takes to reject a guess does not tell how much of it
+/// was right, by verifying the tag of the API key for the supplied token, as `ring` no longer offers comparing slices.
+///
+/// If no API key is configured, every request is let through.
+pub async fn authorize(
+    State(api_key): State<Option<Arc<str>>>,
+    req: Request<Body>,
+    next: Next,
+) -> Response {
+    let Some(api_key) = api_key else {
+        return next.run(req).await;
+    };
+
+    let token = req
+        .headers()
+        .get(AUTHORIZATION)
+        .and_then(|value| value.to_str().ok())
+        .and_then(|value| value.strip_prefix("Bearer "));
+
+    match token {
+        Some(token) if is_api_key(token, &api_key) => next.run(req).await,
+        Some(_) => {
+            info!("rejected request with wrong api key");
+            StatusCode::UNAUTHORIZED.into_response()
+        }
+        None => {
+            debug!("rejected request without api key");
+            StatusCode::UNAUTHORIZED.into_response()
+        }
+    }
+}
+
+/// Checks whether the `token` is the `api_key`, in constant time.
+fn is_api_key(token: &str, api_key: &str) -> bool {
+    let key = hmac::Key::new(hmac::HMAC_SHA256, api_key.as_bytes());
+    let tag = hmac::sign(&key, api_key.as_bytes());
+    hmac::verify(&key, token.as_bytes(), tag.as_ref()).is_ok()
+}
+
+#[cfg(test)]
+mod authorize {
+    use super::authorize;
+    use axum::{
+        body::Body,
+        http::{request::Builder, Method, StatusCode},
+        middleware::from_fn_with_state,
+        routing::get,
+        Router,
+    };
+    use std::sync::Arc;
+    use tower::ServiceExt;
+
+    /// A test util function to make a router with a single authorized route using the supplied `api_key`.
+    fn router(api_key: Option<&str>) -> Router {
+        Router::new()
+            .route("/", get(|| async { StatusCode::OK }))
+            .route_layer(from_fn_with_state(api_key.map(Arc::from), authorize))
+    }
+
+    #[tokio::test]
+    async fn no_key_configured() {
+        let request = Builder::new()
+            .method(Method::GET)
+            .uri("/")
+            .body(Body::empty())
+            .expect("failed to build request");
+        let expected = StatusCode::OK;
+
+        let actual = router(None)
+            .oneshot(request)
+            .await
+            .expect("failed to await oneshot");
+
+        assert_eq!(actual.status(), expected);
+    }
+
+    #[tokio::test]
+    async fn present_key() {
+        let request = Builder::new()
+            .method(Method::GET)
+            .uri("/")
+            .header("Authorization", "Bearer secret")
+            .body(Body::empty())
+            .expect("failed to build request");
+        let expected = StatusCode::OK;
+
+        let actual = router(Some("secret"))
+            .oneshot(request)
+            .await
+            .expect("failed to await oneshot");
+
+        assert_eq!(actual.status(), expected);
+    }
+
+    #[tokio::test]
+    async fn absent_key() {
+        let request = Builder::new()
+            .method(Method::GET)
+            .uri("/")
+            .body(Body::empty())
+            .expect("failed to build request");
+        let expected = StatusCode::UNAUTHORIZED;
+
+        let actual = router(Some("secret"))
+            .oneshot(request)
+            .await
+            .expect("failed to await oneshot");
+
+        assert_eq!(actual.status(), expected);
+    }
+
+    #[tokio::test]
+    async fn prefix_of_key() {
+        let request = Builder::new()
+            .method(Method::GET)
+            .uri("/")
+            .header("Authorization", "Bearer secre")
+            .body(Body::empty())
+            .expect("failed to build request");
+        let expected = StatusCode::UNAUTHORIZED;
+
+        let actual = router(Some("secret"))
+            .oneshot(request)
+            .await
+            .expect("failed to await oneshot");
+
+        assert_eq!(actual.status(), expected);
+    }
+
+    #[tokio::test]
+    async fn wrong_key() {
+        let request = Builder::new()
+            .method(Method::GET)
+            .uri("/")
+            .header("Authorization", "Bearer guess")
+            .body(Body::empty())
+            .expect("failed to build request");
+        let expected = StatusCode::UNAUTHORIZED;
+
+        let actual = router(Some("secret"))
+            .oneshot(request)
+            .await
+            .expect("failed to await oneshot");
+
+        assert_eq!(actual.status(), expected);
+    }
+}
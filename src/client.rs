@@ -0,0 +1,50 @@
+//! Contains a typed client for submitting solutions to a running mozart instance.
+//!
+//! The client reuses the request and response types of mozart itself, so that they cannot drift apart.
+
+use crate::{model::Submission, response::SubmissionResult};
+use reqwest::{Client, StatusCode};
+use thiserror::Error;
+
+/// An error that occurs in relation to submitting a solution to mozart.
+#[derive(Debug, Error)]
+pub enum ClientError {
+    /// The request could not be sent, or the response body could not be read or deserialized.
+    #[error("the request to mozart failed: {0}")]
+    Request(#[from] reqwest::Error),
+
+    /// Mozart responded with a status code that is not successful.
+    ///
+    /// The provided `StatusCode` should contain the status code of the response.
+    #[error("mozart responded with status code {0}")]
+    Status(StatusCode),
+}
+
+/// Submits the `submission` to the mozart instance at `base_url`, e.g. `http://localhost:8080`.
+///
+/// The `api_key` is sent as a bearer token, which is required if the instance is configured with an API key.
+///
+/// # Errors
+/// Returns a `ClientError::Request` if the request could not be sent or the response could not be deserialized,
+/// and a `ClientError::Status` if mozart responded with a status code that is not successful.
+pub async fn submit(
+    base_url: &str,
+    api_key: Option<&str>,
+    submission: &Submission,
+) -> Result<SubmissionResult, ClientError> {
+    let mut request = Client::new()
+        .post(format!("{}/submit", base_url.trim_end_matches('/')))
+        .json(submission); // also sets the `Content-Type: application/json` header
+    if let Some(api_key) = api_key {
+        request = request.bearer_auth(api_key);
+    }
+
+    let response = request.send().await?;
+
+    let status = response.status();
+    if !status.is_success() {
+        return Err(ClientError::Status(status));
+    }
+
+    Ok(response.json().await?)
+}
@@ -0,0 +1,509 @@
+//! Contains the configuration of mozart, i.e. the tunables that are threaded from [`crate::app`] into the handlers.
+
+use std::{env, path::PathBuf, time::Duration};
+use tracing::info;
+use uuid::Uuid;
+
+/// The parent directory of the working directories of submissions, if not configured otherwise.
+const DEFAULT_WORKDIR: &str = "/mozart";
+
+/// The name of the environment variable containing the number of leading characters of a uuid that shard its
+/// working directory.
+const WORKDIR_SHARD_LENGTH_ENV_VAR: &str = "MOZART_WORKDIR_SHARD_LENGTH";
+
+/// The number of leading characters of a uuid that shard its working directory, if [`WORKDIR_SHARD_LENGTH_ENV_VAR`]
+/// is not set, which spreads the working directories over 256 shards.
+const DEFAULT_WORKDIR_SHARD_LENGTH: usize = 2;
+
+#[cfg(not(feature = "ci"))]
+/// The timeout duration for the compilation and execution process.
+const DEFAULT_TIMEOUT: Duration = Duration::from_secs(5);
+
+#[cfg(feature = "ci")]
+/// The timeout duration used during pipeline workflows.
+const DEFAULT_TIMEOUT: Duration = Duration::from_secs(30);
+
+#[cfg(not(feature = "ci"))]
+/// The time a single test case may run for, before it is marked as timed out and the next test case is run.
+const DEFAULT_TEST_CASE_TIMEOUT: Duration = Duration::from_secs(1);
+
+#[cfg(feature = "ci")]
+/// The time a single test case may run for during pipeline workflows.
+const DEFAULT_TEST_CASE_TIMEOUT: Duration = Duration::from_secs(5);
+
+/// The name of the environment variable containing the time in milliseconds a timed out execution is given to exit
+/// after `SIGTERM`, before it is killed with `SIGKILL`.
+const KILL_GRACE_PERIOD_ENV_VAR: &str = "MOZART_KILL_GRACE_PERIOD_MS";
+
+/// The time a timed out execution is given to exit after `SIGTERM`, if [`KILL_GRACE_PERIOD_ENV_VAR`] is not set.
+const DEFAULT_KILL_GRACE_PERIOD: Duration = Duration::from_millis(100);
+
+/// The name of the environment variable containing the time in milliseconds a whole request may take.
+const REQUEST_TIMEOUT_ENV_VAR: &str = "MOZART_REQUEST_TIMEOUT_MS";
+
+/// The time a whole request may take, if [`REQUEST_TIMEOUT_ENV_VAR`] is not set.
+const DEFAULT_REQUEST_TIMEOUT: Duration = Duration::from_secs(90);
+
+/// The name of the environment variable containing the maximum number of submissions that are checked at the same time.
+const CONCURRENCY_LIMIT_ENV_VAR: &str = "MOZART_CONCURRENCY_LIMIT";
+
+/// The maximum number of submissions that are checked at the same time, if [`CONCURRENCY_LIMIT_ENV_VAR`] is not set.
+const DEFAULT_CONCURRENCY_LIMIT: usize = 4;
+
+/// The name of the environment variable containing the maximum length of a value reported for a wrong answer.
+const MAX_VALUE_LENGTH_ENV_VAR: &str = "MOZART_MAX_VALUE_LENGTH";
+
+/// The maximum length of a value reported for a wrong answer, if [`MAX_VALUE_LENGTH_ENV_VAR`] is not set.
+const DEFAULT_MAX_VALUE_LENGTH: usize = 1000;
+
+/// The name of the environment variable containing the maximum length of the stdout of a solution in a response.
+const MAX_STDOUT_LENGTH_ENV_VAR: &str = "MOZART_MAX_STDOUT_LENGTH";
+
+/// The maximum length of the stdout of a solution in a response, if [`MAX_STDOUT_LENGTH_ENV_VAR`] is not set.
+const DEFAULT_MAX_STDOUT_LENGTH: usize = 10000;
+
+/// The name of the environment variable containing the maximum number of results a single test case may report.
+const MAX_RESULTS_PER_TEST_CASE_ENV_VAR: &str = "MOZART_MAX_RESULTS_PER_TEST_CASE";
+
+/// The maximum number of results a single test case may report, if [`MAX_RESULTS_PER_TEST_CASE_ENV_VAR`] is not set.
+const DEFAULT_MAX_RESULTS_PER_TEST_CASE: usize = 64;
+
+/// The name of the environment variable that quotes the characters and strings of wrong answers if it is `true`.
+const QUOTE_VALUES_ENV_VAR: &str = "MOZART_QUOTE_VALUES";
+
+/// The name of the environment variable containing the maximum length in bytes of a solution.
+const MAX_SOLUTION_LENGTH_ENV_VAR: &str = "MOZART_MAX_SOLUTION_LENGTH";
+
+/// The maximum length in bytes of a solution, if [`MAX_SOLUTION_LENGTH_ENV_VAR`] is not set.
+const DEFAULT_MAX_SOLUTION_LENGTH: usize = 100 * 1024;
+
+/// The name of the environment variable containing the maximum size in bytes of a file the solution execution may write.
+const MAX_FILE_SIZE_ENV_VAR: &str = "MOZART_MAX_FILE_SIZE";
+
+/// The maximum size in bytes of a file the solution execution may write, if [`MAX_FILE_SIZE_ENV_VAR`] is not set.
+const DEFAULT_MAX_FILE_SIZE: u64 = 10 * 1024 * 1024;
+
+/// The name of the environment variable containing the comma separated compiler flags that submissions may supply.
+const ALLOWED_COMPILER_FLAGS_ENV_VAR: &str = "MOZART_COMPILER_FLAGS";
+
+/// The name of the environment variable containing the comma separated modules that Python solutions may import.
+const PYTHON_MODULES_ENV_VAR: &str = "MOZART_PYTHON_MODULES";
+
+/// The modules that Python solutions may import, if [`PYTHON_MODULES_ENV_VAR`] is not set,
+/// which are the standard library modules that everyday solutions use.
+const DEFAULT_PYTHON_MODULES: &[&str] = &[
+    "bisect",
+    "collections",
+    "copy",
+    "dataclasses",
+    "decimal",
+    "enum",
+    "fractions",
+    "functools",
+    "heapq",
+    "itertools",
+    "math",
+    "operator",
+    "random",
+    "re",
+    "statistics",
+    "string",
+    "time",
+    "typing",
+];
+
+/// The name of the environment variable containing the comma separated environment variables of mozart that are passed
+/// on to the solution execution.
+const EXECUTION_ENV_VARS_ENV_VAR: &str = "MOZART_EXECUTION_ENV_VARS";
+
+/// The name of the environment variable containing the comma separated toolchain versions that submissions may request.
+const TOOLCHAIN_VERSIONS_ENV_VAR: &str = "MOZART_TOOLCHAIN_VERSIONS";
+
+/// The name of the environment variable that makes quick submissions run every test case if it is `false`.
+const QUICK_FAIL_FAST_ENV_VAR: &str = "MOZART_QUICK_FAIL_FAST";
+
+/// The name of the environment variable that allows submissions to request the generated test file if it is `true`.
+const ALLOW_DEBUG_ENV_VAR: &str = "MOZART_ALLOW_DEBUG";
+
+/// The name of the environment variable that makes compile warnings of solutions fail them if it is `true`.
+const WARNINGS_AS_ERRORS_ENV_VAR: &str = "MOZART_WARNINGS_AS_ERRORS";
+
+/// The name of the environment variable containing the shared secret that submission results are signed with.
+const SIGNING_KEY_ENV_VAR: &str = "MOZART_SIGNING_KEY";
+
+/// The name of the environment variable containing the API key that requests must be authenticated with.
+const API_KEY_ENV_VAR: &str = "MOZART_API_KEY";
+
+/// The tunables of mozart.
+///
+/// [`Config::default`] is the configuration used if nothing is configured, and [`Config::from_env`] is the one
+/// mozart is started with. A test can construct the router with any other configuration, e.g. a shorter timeout.
+#[derive(Debug, Clone)]
+pub struct Config {
+    /// The parent directory of the working directories of submissions.
+    pub workdir: PathBuf,
+
+    /// The number of leading hexadecimal characters of the uuid of a working directory that name the shard, i.e.
+    /// the subdirectory of [`Config::workdir`], it is placed in.
+    ///
+    /// It keeps any single directory from holding every working directory under high throughput,
+    /// and the working directories are placed directly in [`Config::workdir`] if it is zero.
+    pub workdir_shard_length: usize,
+
+    /// The time the compilation and the execution of a submission may each take.
+    pub timeout: Duration,
+
+    /// The time a single test case may run for, before it is marked as timed out and the next test case is run.
+    pub test_case_timeout: Duration,
+
+    /// The time an execution that exceeded [`Config::timeout`] is given to exit after `SIGTERM`, e.g. to flush its
+    /// buffered output, before it is killed with `SIGKILL`. It is killed right away if it is zero.
+    pub kill_grace_period: Duration,
+
+    /// The time a whole request may take, including waiting for other submissions, compilation and execution,
+    /// before it is responded to with `504 Gateway Timeout`, which bounds the latency a client can observe.
+    pub request_timeout: Duration,
+
+    /// The maximum number of submissions that are checked at the same time across every endpoint, where the others
+    /// wait for their turn, and the submissions of a batch count individually.
+    ///
+    /// A limit of zero is treated as one, as it would otherwise keep every submission waiting.
+    pub concurrency_limit: usize,
+
+    /// The maximum number of characters of a value reported for a wrong answer, before it is truncated.
+    ///
+    /// It keeps the response bounded, even if a solution returns an enormous value.
+    pub max_value_length: usize,
+
+    /// The maximum number of characters of what a solution wrote to stdout that is responded with, before it is
+    /// truncated.
+    ///
+    /// It keeps the response bounded, even if a solution prints in a loop.
+    pub max_stdout_length: usize,
+
+    /// The maximum number of results a single test case may report between its started and ended markers,
+    /// e.g. one per assertion of a test harness that checks multiple assertions per test case.
+    pub max_results_per_test_case: usize,
+
+    /// Whether the characters and strings of a wrong answer are quoted and escaped like in the enabled language,
+    /// e.g. `'a'` or `"hello"`, instead of being displayed as their text.
+    ///
+    /// It applies to the input parameters as well as to the actual and expected values, so that they are displayed alike.
+    /// Characters and strings nested in other values, e.g. in a list, are always quoted.
+    pub quote_values: bool,
+
+    /// The maximum length in bytes of a solution, beyond which it is rejected before it is compiled.
+    ///
+    /// It is cheaper than letting a giant solution run into the compilation timeout.
+    pub max_solution_length: usize,
+
+    /// The maximum size in bytes of a file the solution execution may write, which contains runaway file writes.
+    ///
+    /// Writing beyond it fails, or kills the process if it does not ignore `SIGXFSZ`, instead of filling the disk.
+    pub max_file_size: u64,
+
+    /// The names of the environment variables of mozart that are passed on to the solution execution, in addition to
+    /// the fixed ones every execution is given, where only `PATH` is passed on by default.
+    ///
+    /// The execution does not see any other environment variable of mozart, e.g. its API key.
+    pub execution_env_vars: Box<[String]>,
+
+    /// The compiler flags that submissions may supply, where none are allowed by default.
+    pub allowed_compiler_flags: Box<[String]>,
+
+    /// The modules that Python solutions may import, which only applies to Python.
+    ///
+    /// The restriction is not a security boundary, as any module can be reached through the internals of Python,
+    /// so the execution is contained by the jail and the restricted user regardless of it.
+    pub python_modules: Box<[String]>,
+
+    /// The versions of the toolchain that submissions may request, which must be installed next to the default one,
+    /// where only the default toolchain is allowed by default.
+    pub toolchain_versions: Box<[String]>,
+
+    /// Whether submissions may request the generated test file, which is meant for non-production deployments.
+    pub allow_debug: bool,
+
+    /// Whether the compile warnings of a solution are treated as errors, failing it with a compilation error,
+    /// which only applies to Haskell, where the solution is compiled with `-Werror`.
+    pub warnings_as_errors: bool,
+
+    /// Whether a quick submission stops at the first test case that does not pass, as the remaining test cases cannot
+    /// change whether it passed.
+    pub quick_fail_fast: bool,
+
+    /// The shared secret that the responded submission results are signed with, such that clients can verify that
+    /// the verdict was not tampered with, where results are not signed by default.
+    pub signing_key: Option<String>,
+
+    /// The API key that requests must supply as a bearer token, except those checking the health of mozart,
+    /// see [`crate::app`], where requests are not authenticated by default.
+    pub api_key: Option<String>,
+}
+
+impl Default for Config {
+    fn default() -> Self {
+        Self {
+            workdir: PathBuf::from(DEFAULT_WORKDIR),
+            workdir_shard_length: DEFAULT_WORKDIR_SHARD_LENGTH,
+            timeout: DEFAULT_TIMEOUT,
+            test_case_timeout: DEFAULT_TEST_CASE_TIMEOUT,
+            kill_grace_period: DEFAULT_KILL_GRACE_PERIOD,
+            request_timeout: DEFAULT_REQUEST_TIMEOUT,
+            concurrency_limit: DEFAULT_CONCURRENCY_LIMIT,
+            max_value_length: DEFAULT_MAX_VALUE_LENGTH,
+            max_stdout_length: DEFAULT_MAX_STDOUT_LENGTH,
+            max_results_per_test_case: DEFAULT_MAX_RESULTS_PER_TEST_CASE,
+            quote_values: false,
+            max_solution_length: DEFAULT_MAX_SOLUTION_LENGTH,
+            max_file_size: DEFAULT_MAX_FILE_SIZE,
+            execution_env_vars: Box::new([]),
+            allowed_compiler_flags: Box::new([]),
+            python_modules: DEFAULT_PYTHON_MODULES
+                .iter()
+                .map(|module| module.to_string())
+                .collect(),
+            toolchain_versions: Box::new([]),
+            allow_debug: false,
+            warnings_as_errors: false,
+            quick_fail_fast: true,
+            signing_key: None,
+            api_key: None,
+        }
+    }
+}
+
+impl Config {
+    /// Creates the default configuration, overridden by the environment variables that are set.
+    ///
+    /// A value that cannot be parsed, or a concurrency limit of zero, is ignored in favour of the default.
+    pub fn from_env() -> Self {
+        let default = Self::default();
+
+        let workdir_shard_length = env::var(WORKDIR_SHARD_LENGTH_ENV_VAR)
+            .ok()
+            .and_then(|value| value.parse().ok())
+            .unwrap_or(default.workdir_shard_length);
+        info!("working directories are sharded by {workdir_shard_length} characters of their uuid");
+
+        let kill_grace_period = env::var(KILL_GRACE_PERIOD_ENV_VAR)
+            .ok()
+            .and_then(|value| value.parse().ok())
+            .map_or(default.kill_grace_period, Duration::from_millis);
+        info!("timed out executions are killed {kill_grace_period:?} after being terminated");
+
+        let request_timeout = env::var(REQUEST_TIMEOUT_ENV_VAR)
+            .ok()
+            .and_then(|value| value.parse().ok())
+            .map_or(default.request_timeout, Duration::from_millis);
+        info!("requests time out after {request_timeout:?}");
+
+        let concurrency_limit = env::var(CONCURRENCY_LIMIT_ENV_VAR)
+            .ok()
+            .and_then(|value| value.parse().ok())
+            .filter(|&limit| limit > 0)
+            .unwrap_or(default.concurrency_limit);
+        info!("up to {concurrency_limit} submissions are checked at the same time");
+
+        let max_value_length = env::var(MAX_VALUE_LENGTH_ENV_VAR)
+            .ok()
+            .and_then(|value| value.parse().ok())
+            .unwrap_or(default.max_value_length);
+        info!("values of wrong answers are truncated to {max_value_length} characters");
+
+        let max_stdout_length = env::var(MAX_STDOUT_LENGTH_ENV_VAR)
+            .ok()
+            .and_then(|value| value.parse().ok())
+            .unwrap_or(default.max_stdout_length);
+        info!("stdout of solutions is truncated to {max_stdout_length} characters");
+
+        let max_results_per_test_case = env::var(MAX_RESULTS_PER_TEST_CASE_ENV_VAR)
+            .ok()
+            .and_then(|value| value.parse().ok())
+            .unwrap_or(default.max_results_per_test_case);
+        info!("test cases may report up to {max_results_per_test_case} results");
+
+        let quote_values = env::var(QUOTE_VALUES_ENV_VAR).is_ok_and(|value| value == "true");
+        if quote_values {
+            info!("characters and strings of wrong answers are quoted");
+        }
+
+        let max_solution_length = env::var(MAX_SOLUTION_LENGTH_ENV_VAR)
+            .ok()
+            .and_then(|value| value.parse().ok())
+            .unwrap_or(default.max_solution_length);
+        info!("solutions are limited to {max_solution_length} bytes");
+
+        let max_file_size = env::var(MAX_FILE_SIZE_ENV_VAR)
+            .ok()
+            .and_then(|value| value.parse().ok())
+            .unwrap_or(default.max_file_size);
+        info!("files written by solutions are limited to {max_file_size} bytes");
+
+        let allowed_compiler_flags = env::var(ALLOWED_COMPILER_FLAGS_ENV_VAR)
+            .map(|flags| {
+                flags
+                    .split(',')
+                    .map(str::trim)
+                    .filter(|flag| !flag.is_empty())
+                    .map(String::from)
+                    .collect()
+            })
+            .unwrap_or(default.allowed_compiler_flags);
+        info!(
+            "submissions may supply the compiler flags: {}",
+            allowed_compiler_flags.join(", ")
+        );
+
+        let execution_env_vars = env::var(EXECUTION_ENV_VARS_ENV_VAR)
+            .map(|names| {
+                names
+                    .split(',')
+                    .map(str::trim)
+                    .filter(|name| !name.is_empty())
+                    .map(String::from)
+                    .collect()
+            })
+            .unwrap_or(default.execution_env_vars);
+        info!(
+            "the environment variables passed on to executions: {}",
+            execution_env_vars.join(", ")
+        );
+
+        let python_modules = env::var(PYTHON_MODULES_ENV_VAR)
+            .map(|modules| {
+                modules
+                    .split(',')
+                    .map(str::trim)
+                    .filter(|module| !module.is_empty())
+                    .map(String::from)
+                    .collect()
+            })
+            .unwrap_or(default.python_modules);
+        info!("python solutions may import: {}", python_modules.join(", "));
+
+        let toolchain_versions = env::var(TOOLCHAIN_VERSIONS_ENV_VAR)
+            .map(|versions| {
+                versions
+                    .split(',')
+                    .map(str::trim)
+                    .filter(|version| !version.is_empty())
+                    .map(String::from)
+                    .collect()
+            })
+            .unwrap_or(default.toolchain_versions);
+        info!(
+            "submissions may request the toolchain versions: {}",
+            toolchain_versions.join(", ")
+        );
+
+        let allow_debug = env::var(ALLOW_DEBUG_ENV_VAR).is_ok_and(|value| value == "true");
+        if allow_debug {
+            info!("submissions may request the generated test file");
+        }
+
+        let warnings_as_errors =
+            env::var(WARNINGS_AS_ERRORS_ENV_VAR).is_ok_and(|value| value == "true");
+        if warnings_as_errors {
+            info!("compile warnings of solutions are treated as errors");
+        }
+
+        let quick_fail_fast = env::var(QUICK_FAIL_FAST_ENV_VAR)
+            .map_or(default.quick_fail_fast, |value| value != "false");
+        if !quick_fail_fast {
+            info!("quick submissions run every test case");
+        }
+
+        let signing_key = env::var(SIGNING_KEY_ENV_VAR)
+            .ok()
+            .filter(|key| !key.is_empty());
+        if signing_key.is_some() {
+            info!("submission results are signed");
+        }
+
+        let api_key = env::var(API_KEY_ENV_VAR).ok().filter(|key| !key.is_empty());
+        if api_key.is_some() {
+            info!("requests must be authenticated with the api key");
+        }
+
+        Self {
+            workdir_shard_length,
+            kill_grace_period,
+            request_timeout,
+            concurrency_limit,
+            max_value_length,
+            max_stdout_length,
+            max_results_per_test_case,
+            quote_values,
+            max_solution_length,
+            max_file_size,
+            execution_env_vars,
+            allowed_compiler_flags,
+            python_modules,
+            toolchain_versions,
+            allow_debug,
+            warnings_as_errors,
+            quick_fail_fast,
+            signing_key,
+            api_key,
+            ..default
+        }
+    }
+
+    /// Gets the working directory of the submission with the `uuid`, inside its shard of [`Config::workdir`].
+    pub fn working_directory(&self, uuid: &Uuid) -> PathBuf {
+        let simple = uuid.simple().to_string();
+        let shard = &simple[..self.workdir_shard_length.min(simple.len())];
+
+        self.workdir.join(shard).join(uuid.to_string())
+    }
+}
+
+#[cfg(test)]
+mod working_directory {
+    use super::Config;
+    use std::path::PathBuf;
+    use uuid::Uuid;
+
+    /// The uuid of the working directories of the tests.
+    const UUID: &str = "67e55044-10b1-426f-9247-bb680e5fe0c8";
+
+    #[test]
+    fn sharded() {
+        let config = Config::default();
+        let input = Uuid::parse_str(UUID).expect("failed to parse uuid");
+        let expected = PathBuf::from(format!("/mozart/67/{UUID}"));
+
+        let actual = config.working_directory(&input);
+
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn unsharded() {
+        let config = Config {
+            workdir_shard_length: 0,
+            ..Config::default()
+        };
+        let input = Uuid::parse_str(UUID).expect("failed to parse uuid");
+        let expected = PathBuf::from(format!("/mozart/{UUID}"));
+
+        let actual = config.working_directory(&input);
+
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn shard_longer_than_uuid() {
+        let config = Config {
+            workdir_shard_length: 100,
+            ..Config::default()
+        };
+        let input = Uuid::parse_str(UUID).expect("failed to parse uuid");
+        let expected = PathBuf::from(format!("/mozart/67e5504410b1426f9247bb680e5fe0c8/{UUID}"));
+
+        let actual = config.working_directory(&input);
+
+        assert_eq!(actual, expected);
+    }
+}
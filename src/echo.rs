@@ -0,0 +1,164 @@
+//! Contains the dry-run mode of mozart, which checks submissions without compiling or executing them.
+//!
+//! It exists so that clients can validate their request and response handling without a toolchain installed.
+
+use crate::{
+    model::{Parameter, Submission, TestCaseFailureReason, TestCaseResult, TestResult},
+    response::SubmissionResult,
+};
+use tracing::info;
+
+/// Checks the submission deterministically, where a test case passes if its input parameters equal its output parameters.
+///
+/// The solution is never compiled nor executed, and a failing test case reports its input values as the actual values.
+pub fn echo_submission(submission: &Submission) -> SubmissionResult {
+    info!("echoing submission");
+
+    let test_case_results = submission
+        .selected_test_cases()
+        .iter()
+        .map(|test_case| {
+            let test_result = if test_case.input_parameters == test_case.output_parameters {
+                TestResult::Pass
+            } else {
+                TestResult::Failure(TestCaseFailureReason::WrongAnswer {
+                    input_parameters: test_case.input_parameters.clone(),
+                    actual: join_values(&test_case.input_parameters),
+                    expected: join_values(&test_case.output_parameters),
+                    actual_value: None,
+                    expected_value: None,
+                })
+            };
+
+            TestCaseResult::new(test_case, test_result)
+        })
+        .collect::<Box<[_]>>();
+
+    if test_case_results
+        .iter()
+        .all(|tcr| tcr.test_result == TestResult::Pass)
+    {
+        SubmissionResult::Pass
+    } else {
+        SubmissionResult::Failure(test_case_results)
+    }
+}
+
+/// Joins the values of the `parameters` with commas.
+fn join_values(parameters: &[Parameter]) -> String {
+    parameters
+        .iter()
+        .map(|parameter| parameter.value.as_str())
+        .collect::<Vec<_>>()
+        .join(",")
+}
+
+#[cfg(test)]
+mod echo_submission {
+    use super::echo_submission;
+    use crate::{
+        model::{
+            Parameter, ParameterType, Submission, TestCase, TestCaseFailureReason, TestCaseResult,
+            TestResult,
+        },
+        response::SubmissionResult,
+    };
+
+    /// A test util function to make an int parameter with the supplied `value`.
+    fn int(value: &str) -> Parameter {
+        Parameter {
+            value_type: ParameterType::Int,
+            value: String::from(value),
+        }
+    }
+
+    #[test]
+    fn input_equals_output() {
+        let input = Submission {
+            solution: String::new(),
+            test_cases: Box::new([TestCase {
+                id: 0,
+                input_parameters: Box::new([int("1"), int("2")]),
+                output_parameters: Box::new([int("1"), int("2")]),
+                stdin: None,
+                expected_error: None,
+                label: None,
+                hidden: false,
+            }]),
+            unordered_lists: false,
+            files: Box::new([]),
+            checker: None,
+            reference_solution: None,
+            only_ids: None,
+            compiler_flags: Box::new([]),
+            debug: false,
+            float_tolerance: None,
+            toolchain_version: None,
+            metadata: None,
+        };
+        let expected = SubmissionResult::Pass;
+
+        let actual = echo_submission(&input);
+
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn input_differs_from_output() {
+        let input = Submission {
+            solution: String::new(),
+            test_cases: Box::new([
+                TestCase {
+                    id: 0,
+                    input_parameters: Box::new([int("1")]),
+                    output_parameters: Box::new([int("1")]),
+                    stdin: None,
+                    expected_error: None,
+                    label: None,
+                    hidden: false,
+                },
+                TestCase {
+                    id: 1,
+                    input_parameters: Box::new([int("1"), int("2")]),
+                    output_parameters: Box::new([int("3")]),
+                    stdin: None,
+                    expected_error: None,
+                    label: None,
+                    hidden: false,
+                },
+            ]),
+            unordered_lists: false,
+            files: Box::new([]),
+            checker: None,
+            reference_solution: None,
+            only_ids: None,
+            compiler_flags: Box::new([]),
+            debug: false,
+            float_tolerance: None,
+            toolchain_version: None,
+            metadata: None,
+        };
+        let expected = SubmissionResult::Failure(Box::new([
+            TestCaseResult {
+                id: 0,
+                test_result: TestResult::Pass,
+                label: None,
+            },
+            TestCaseResult {
+                id: 1,
+                test_result: TestResult::Failure(TestCaseFailureReason::WrongAnswer {
+                    input_parameters: Box::new([int("1"), int("2")]),
+                    actual: String::from("1,2"),
+                    expected: String::from("3"),
+                    actual_value: None,
+                    expected_value: None,
+                }),
+                label: None,
+            },
+        ]));
+
+        let actual = echo_submission(&input);
+
+        assert_eq!(actual, expected);
+    }
+}
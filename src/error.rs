@@ -1,6 +1,6 @@
 //! Contains errors and related values.
 
-use crate::model::TestCaseResult;
+use crate::model::{CompilationDiagnostic, TestCaseResult};
 use std::time::Duration;
 use thiserror::Error;
 
@@ -20,11 +20,23 @@ pub enum SubmissionError {
     #[error("an internal server error occurred")]
     Internal,
 
+    /// The toolchain of the enabled language is not installed, which means that the deployment is misconfigured.
+    ///
+    /// It is responded to like [`SubmissionError::Internal`], but is logged and reported by the readiness check
+    /// as such, so it is not mistaken for a transient failure.
+    ///
+    /// The provided `String` should contain the name of the program that could not be found.
+    #[error("the toolchain '{0}' is not installed")]
+    ToolchainNotFound(String),
+
     /// There was an error during the compilation of the submitted solution.
     ///
     /// The provided `String` should contain the underlying compilation error.
+    ///
+    /// The `Box<[CompilationDiagnostic]>` contains the diagnostics that could be extracted from the compilation error,
+    /// and is empty if none could be extracted.
     #[error("an error occurred during compilation: {0}")]
-    Compilation(String),
+    Compilation(String, Box<[CompilationDiagnostic]>),
 
     /// The compilation process exceeded the set timeout, and was therefore stopped prematurely.
     ///
@@ -34,6 +46,9 @@ pub enum SubmissionError {
 
     /// The execution process exceeded the set timeout, and was therefore stopped prematurely.
     ///
+    /// It is only returned if no test case was started yet, as otherwise the results of the finished test cases are
+    /// kept, and the timeout is reported as the result of the test case that was running or would have run next.
+    ///
     /// The provided `Duration` should contain the timeout duration that was exceeded.
     #[error("execution exceeded the timeout limit of {0:?}")]
     ExecuteTimeout(Duration),
@@ -46,9 +61,85 @@ pub enum SubmissionError {
     #[error("the submission did not pass all test cases")]
     Failure(Box<[TestCaseResult]>),
 
+    /// The submission did not fail any test case, but whether it passed some of them could not be determined.
+    ///
+    /// The `Box<[TestCaseResult]>` contains the results, of which at least one is unknown.
+    ///
+    /// This error variant should NOT be stringified, instead it should be converted to a `[SubmissionResult::Inconclusive]`.
+    #[error("the submission did not fail any test case, but some test cases have unknown results")]
+    Inconclusive(Box<[TestCaseResult]>),
+
     /// The execution process stopped due to an error.
     ///
     /// This could be things like syntax errors in interpretted languages.
     #[error("an error occured during execution: {0}")]
     Execution(String),
+
+    /// The submitted solution is empty or only consists of whitespace, so there is nothing to check.
+    #[error("solution is empty")]
+    EmptySolution,
+
+    /// The submitted solution is longer than the maximum length configured by the server.
+    ///
+    /// The provided `usize` should contain the maximum number of bytes of a solution.
+    #[error("the solution exceeded the limit of {0} bytes")]
+    SolutionTooLong(usize),
+
+    /// The supplied checker is empty or only consists of whitespace, so it cannot decide whether a test case passed.
+    #[error("checker is empty")]
+    EmptyChecker,
+
+    /// The solution does not meet a requirement of the language, e.g. a missing module declaration.
+    ///
+    /// The provided `String` should describe the requirement that is not met.
+    #[error("invalid solution: {0}")]
+    InvalidSolution(String),
+
+    /// A parameter of the submission has a value that does not match its type.
+    ///
+    /// The provided `String` should describe the offending value and its expected type.
+    #[error("invalid parameter: {0}")]
+    InvalidParameter(String),
+
+    /// Multiple test cases of the submission share the same id, making their results ambiguous.
+    ///
+    /// The provided `u64` should contain the id that is not unique.
+    #[error("multiple test cases have the id {0}")]
+    DuplicateTestCaseId(u64),
+
+    /// The submission requested to only run a test case that it does not have.
+    ///
+    /// The provided `u64` should contain the requested id that no test case has.
+    #[error("no test case has the id {0}")]
+    UnknownTestCaseId(u64),
+
+    /// The submission supplied a compiler flag that is not in the allowlist configured by the server.
+    ///
+    /// The provided `String` should contain the offending flag.
+    #[error("the compiler flag '{0}' is not allowed")]
+    DisallowedCompilerFlag(String),
+
+    /// The submission requested a toolchain version that is not in the allowlist configured by the server.
+    ///
+    /// The provided `String` should contain the requested version.
+    #[error("the toolchain version '{0}' is not available")]
+    UnknownToolchainVersion(String),
+
+    /// The float tolerance of the submission is not valid, e.g. because it is negative.
+    ///
+    /// The provided `String` should describe why the tolerance is not valid.
+    #[error("invalid float tolerance: {0}")]
+    InvalidFloatTolerance(String),
+
+    /// An auxiliary file of the submission has a name that is not allowed.
+    ///
+    /// The provided `String` should describe the offending name and why it is not allowed.
+    #[error("invalid file name: {0}")]
+    InvalidFileName(String),
+
+    /// The execution process wrote more output than is captured, making the test output incomplete.
+    ///
+    /// The provided `usize` should contain the maximum number of bytes that are captured.
+    #[error("the output exceeded the limit of {0} bytes")]
+    OutputLimitExceeded(usize),
 }
@@ -0,0 +1,340 @@
+//! Contains the idempotency keys of mozart, which let clients safely retry a submission without it being checked again.
+//!
+//! A submission supplied with a key that is in-flight or was recently checked is responded to with the response of
+//! the first submission with that key, which avoids recompiling and rerunning the solution when a client times out.
+//! A key is bound to the submission it was first used with, so it cannot be reused for a different submission.
+//! At most [`MAX_KEYS`] keys are remembered at a time, where the oldest key is forgotten to make room for a new one.
+
+use crate::response::SubmissionResult;
+use ring::digest::{digest, SHA256};
+use std::{
+    collections::HashMap,
+    future::Future,
+    sync::{Arc, Mutex},
+    time::{Duration, Instant},
+};
+use thiserror::Error;
+use tokio::sync::OnceCell;
+use tracing::{debug, info};
+
+/// The time a key is remembered for after it was first used.
+const KEY_TTL: Duration = Duration::from_secs(300);
+
+/// The maximum number of keys that are remembered at a time, such that a client using a new key for every request
+/// cannot grow them without bound within [`KEY_TTL`].
+const MAX_KEYS: usize = 10_000;
+
+/// A response that can be remembered for an idempotency key.
+pub trait Remember: Clone {
+    /// Determines whether the response is remembered, rather than giving a retry another chance to succeed.
+    fn is_remembered(&self) -> bool;
+}
+
+impl Remember for SubmissionResult {
+    /// A [`SubmissionResult::InternalError`] is never remembered.
+    fn is_remembered(&self) -> bool {
+        !matches!(self, SubmissionResult::InternalError)
+    }
+}
+
+/// The error of using an idempotency key for a different submission than the one it was first used with.
+#[derive(Debug, PartialEq, Eq, Error)]
+#[error("the idempotency key was first used with a different submission")]
+pub struct KeyReused;
+
+/// A key that has been used, along with the response to the submission it was first used with.
+struct Entry<T> {
+    /// When the key was first used.
+    created: Instant,
+
+    /// The SHA-256 digest of the submission the key was first used with.
+    fingerprint: Box<[u8]>,
+
+    /// The response to the submission, which is unset while it is in-flight.
+    response: Arc<OnceCell<T>>,
+}
+
+/// The keys that are in-flight or were used within their time to live, [`KEY_TTL`] by default, along with the
+/// responses of type `T` to their submissions.
+pub struct IdempotencyKeys<T> {
+    keys: Mutex<HashMap<String, Entry<T>>>,
+
+    /// The maximum number of keys that are remembered at a time.
+    max_keys: usize,
+
+    /// The time a key is remembered for after it was first used.
+    ttl: Duration,
+}
+
+impl<T> Default for IdempotencyKeys<T> {
+    fn default() -> Self {
+        Self::new(MAX_KEYS, KEY_TTL)
+    }
+}
+
+impl<T> IdempotencyKeys<T> {
+    /// Creates the keys, remembering at most `max_keys` of them at a time, each for `ttl` after it was first used.
+    fn new(max_keys: usize, ttl: Duration) -> Self {
+        Self {
+            keys: Mutex::default(),
+            max_keys,
+            ttl,
+        }
+    }
+}
+
+impl<T: Remember> IdempotencyKeys<T> {
+    /// Gets the response to the submission first checked with the `key`, or checks it with `check` if the key is
+    /// unknown, where `submission` is the serialized submission, which the key is bound to.
+    ///
+    /// If a submission with the key is in-flight, it waits for it to finish instead of checking it again.
+    /// A key is forgotten its time to live after it was first used, even if its check never finished, e.g. because the
+    /// request timed out, so that such a key does not stay forever.
+    /// If the maximum number of keys are remembered, the oldest one is forgotten before a new key is used, in which
+    /// case a retry with that key is checked again.
+    ///
+    /// # Errors
+    /// Returns a `KeyReused` if the key was first used with a different submission.
+    pub async fn check_once<F>(
+        &self,
+        key: String,
+        submission: &[u8],
+        check: F,
+    ) -> Result<T, KeyReused>
+    where
+        F: Future<Output = T>,
+    {
+        let fingerprint = digest(&SHA256, submission);
+        let response = {
+            let mut keys = self
+                .keys
+                .lock()
+                .expect("idempotency keys should never be poisoned");
+            keys.retain(|_, entry| entry.created.elapsed() < self.ttl);
+            if keys.len() >= self.max_keys && !keys.contains_key(&key) {
+                let oldest = keys
+                    .iter()
+                    .min_by_key(|(_, entry)| entry.created)
+                    .map(|(key, _)| key.clone());
+                if let Some(oldest) = oldest {
+                    info!("forgetting the oldest idempotency key to make room for a new one");
+                    keys.remove(&oldest);
+                }
+            }
+
+            let entry = keys.entry(key).or_insert_with_key(|key| {
+                debug!("first use of idempotency key '{}'", key);
+                Entry {
+                    created: Instant::now(),
+                    fingerprint: Box::from(fingerprint.as_ref()),
+                    response: Arc::new(OnceCell::new()),
+                }
+            });
+            if *entry.fingerprint != *fingerprint.as_ref() {
+                info!("idempotency key was reused for a different submission");
+                return Err(KeyReused);
+            }
+            Arc::clone(&entry.response)
+        };
+
+        if response.initialized() {
+            info!("responding with the remembered response of the idempotency key");
+        }
+
+        let response = response
+            .get_or_try_init(|| async {
+                let response = check.await;
+                if response.is_remembered() {
+                    Ok(response)
+                } else {
+                    Err(response)
+                }
+            })
+            .await;
+
+        Ok(match response {
+            Ok(response) => response.clone(),
+            Err(response) => response,
+        })
+    }
+}
+
+#[cfg(test)]
+mod check_once {
+    use super::{IdempotencyKeys, KeyReused, MAX_KEYS};
+    use crate::response::SubmissionResult;
+    use std::{
+        future,
+        sync::atomic::{AtomicUsize, Ordering},
+        time::Duration,
+    };
+    use tokio::time::sleep;
+
+    #[tokio::test]
+    async fn same_key() {
+        let keys = IdempotencyKeys::<SubmissionResult>::default();
+        let key = String::from("key");
+        let checks = AtomicUsize::new(0);
+        let check = || async {
+            checks.fetch_add(1, Ordering::Relaxed);
+            SubmissionResult::Pass
+        };
+
+        let first = keys.check_once(key.clone(), b"submission", check()).await;
+        let second = keys.check_once(key, b"submission", check()).await;
+
+        assert_eq!(first, Ok(SubmissionResult::Pass));
+        assert_eq!(second, Ok(SubmissionResult::Pass));
+        assert_eq!(checks.load(Ordering::Relaxed), 1);
+    }
+
+    #[tokio::test]
+    async fn concurrent_same_key() {
+        let keys = IdempotencyKeys::<SubmissionResult>::default();
+        let key = String::from("key");
+        let checks = AtomicUsize::new(0);
+        let check = || async {
+            checks.fetch_add(1, Ordering::Relaxed);
+            tokio::task::yield_now().await;
+            SubmissionResult::Pass
+        };
+
+        let (first, second) = tokio::join!(
+            keys.check_once(key.clone(), b"submission", check()),
+            keys.check_once(key, b"submission", check())
+        );
+
+        assert_eq!(first, Ok(SubmissionResult::Pass));
+        assert_eq!(second, Ok(SubmissionResult::Pass));
+        assert_eq!(checks.load(Ordering::Relaxed), 1);
+    }
+
+    #[tokio::test]
+    async fn different_keys() {
+        let keys = IdempotencyKeys::<SubmissionResult>::default();
+        let checks = AtomicUsize::new(0);
+        let check = || async {
+            checks.fetch_add(1, Ordering::Relaxed);
+            SubmissionResult::Pass
+        };
+
+        let first = keys
+            .check_once(String::from("first"), b"submission", check())
+            .await;
+        let second = keys
+            .check_once(String::from("second"), b"submission", check())
+            .await;
+
+        assert_eq!(first, Ok(SubmissionResult::Pass));
+        assert_eq!(second, Ok(SubmissionResult::Pass));
+        assert_eq!(checks.load(Ordering::Relaxed), 2);
+    }
+
+    #[tokio::test]
+    async fn internal_error_not_remembered() {
+        let keys = IdempotencyKeys::<SubmissionResult>::default();
+        let key = String::from("key");
+        let checks = AtomicUsize::new(0);
+
+        let first = keys
+            .check_once(key.clone(), b"submission", async {
+                checks.fetch_add(1, Ordering::Relaxed);
+                SubmissionResult::InternalError
+            })
+            .await;
+        let second = keys
+            .check_once(key, b"submission", async {
+                checks.fetch_add(1, Ordering::Relaxed);
+                SubmissionResult::Pass
+            })
+            .await;
+
+        assert_eq!(first, Ok(SubmissionResult::InternalError));
+        assert_eq!(second, Ok(SubmissionResult::Pass));
+        assert_eq!(checks.load(Ordering::Relaxed), 2);
+    }
+
+    #[tokio::test]
+    async fn different_submission() {
+        let keys = IdempotencyKeys::<SubmissionResult>::default();
+        let key = String::from("key");
+        let checks = AtomicUsize::new(0);
+        let check = || async {
+            checks.fetch_add(1, Ordering::Relaxed);
+            SubmissionResult::Pass
+        };
+
+        let first = keys.check_once(key.clone(), b"submission", check()).await;
+        let second = keys.check_once(key, b"another submission", check()).await;
+
+        assert_eq!(first, Ok(SubmissionResult::Pass));
+        assert_eq!(second, Err(KeyReused));
+        assert_eq!(checks.load(Ordering::Relaxed), 1);
+    }
+
+    #[tokio::test]
+    async fn oldest_key_forgotten_when_full() {
+        let keys = IdempotencyKeys::<SubmissionResult>::new(2, Duration::from_secs(300));
+        let checks = AtomicUsize::new(0);
+        let check = || async {
+            checks.fetch_add(1, Ordering::Relaxed);
+            SubmissionResult::Pass
+        };
+
+        for key in ["first", "second"] {
+            let _ = keys
+                .check_once(String::from(key), b"submission", check())
+                .await;
+            // the keys are used apart, such that the first one is strictly the oldest
+            sleep(Duration::from_millis(1)).await;
+        }
+        let _ = keys
+            .check_once(String::from("third"), b"submission", check())
+            .await;
+        let second = keys
+            .check_once(String::from("second"), b"submission", check())
+            .await;
+        let first = keys
+            .check_once(String::from("first"), b"submission", check())
+            .await;
+
+        // the first key made room for the third, so it is checked again, unlike the second key
+        assert_eq!(second, Ok(SubmissionResult::Pass));
+        assert_eq!(first, Ok(SubmissionResult::Pass));
+        assert_eq!(checks.load(Ordering::Relaxed), 4);
+        assert_eq!(
+            keys.keys
+                .lock()
+                .expect("idempotency keys should never be poisoned")
+                .len(),
+            2
+        );
+    }
+
+    #[tokio::test]
+    async fn unfinished_check_expires() {
+        let ttl = Duration::from_millis(10);
+        let keys = IdempotencyKeys::<SubmissionResult>::new(MAX_KEYS, ttl);
+        let check = keys.check_once(
+            String::from("first"),
+            b"submission",
+            future::pending::<SubmissionResult>(),
+        );
+        // the check is dropped before it finished, e.g. because the request timed out
+        let _ = tokio::time::timeout(Duration::from_millis(1), check).await;
+        sleep(ttl).await;
+
+        let second = keys
+            .check_once(String::from("second"), b"submission", async {
+                SubmissionResult::Pass
+            })
+            .await;
+
+        assert_eq!(second, Ok(SubmissionResult::Pass));
+        assert!(!keys
+            .keys
+            .lock()
+            .expect("idempotency keys should never be poisoned")
+            .contains_key("first"));
+    }
+}
@@ -1,44 +1,175 @@
+use auth::authorize;
 use axum::{
     body::Body,
-    http::{Request, StatusCode},
-    middleware::{from_fn, Next},
-    routing::{get, post},
+    extract::{
+        rejection::{JsonRejection, StringRejection},
+        Path, Query, State,
+    },
+    http::{HeaderMap, HeaderValue, Request, StatusCode},
+    middleware::{from_fn, from_fn_with_state, Next},
+    response::{IntoResponse, Response},
+    routing::{delete, get, post},
     serve, Json, Router,
 };
+use config::Config;
 use error::SubmissionError;
-use model::Submission;
-use response::SubmissionResult;
+use idempotency::Remember;
+use model::{Capabilities, Health, SelfTest, Submission, TestCase};
+use response::{
+    AnnotatedSubmissionResult, DebugSubmissionResult, ErrorCode, QuickResult, ResultOrder,
+    SubmissionResult,
+};
+use retry::retry_io;
 use runner::TestRunner;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use state::AppState;
 use std::{
-    fs,
-    path::PathBuf,
+    env, fs,
+    future::{pending, Future},
+    io::ErrorKind,
+    path::{Path as FsPath, PathBuf},
     process::{Command, Stdio},
-    sync::LazyLock,
+    sync::{
+        atomic::{AtomicUsize, Ordering},
+        Arc, LazyLock,
+    },
+    time::{Duration, Instant},
+};
+use tokio::{
+    net::TcpListener,
+    select,
+    signal::{
+        ctrl_c,
+        unix::{signal, SignalKind},
+    },
+    sync::oneshot,
+    time::sleep,
 };
-use tokio::net::TcpListener;
+use tokio_rustls::rustls::ServerConfig;
 use tower_http::trace::TraceLayer;
-use tracing::{debug, error, info, info_span};
+use tracing::{debug, error, info, info_span, warn, Instrument};
 use uuid::Uuid;
 
+#[cfg(all(feature = "no-sandbox", not(debug_assertions)))]
+compile_error!("the `no-sandbox` feature is only for local development, and cannot be enabled in release builds");
+
+// the build script still selects a single language in the cases below, such that these are the only errors reported
+#[cfg(not(any(
+    feature = "cpp",
+    feature = "haskell",
+    feature = "python",
+    feature = "kotlin"
+)))]
+compile_error!("no language feature is enabled, but exactly one of `cpp`, `haskell`, `python` and `kotlin` must be, e.g. `--features haskell`");
+
+#[cfg(any(
+    all(feature = "cpp", feature = "haskell"),
+    all(feature = "cpp", feature = "python"),
+    all(feature = "cpp", feature = "kotlin"),
+    all(feature = "haskell", feature = "python"),
+    all(feature = "haskell", feature = "kotlin"),
+    all(feature = "python", feature = "kotlin"),
+))]
+compile_error!("multiple language features are enabled, but exactly one of `cpp`, `haskell`, `python` and `kotlin` must be");
+
+mod auth;
+#[cfg(feature = "client")]
+pub mod client;
+pub mod config;
+mod echo;
 mod error;
+mod idempotency;
 pub mod log;
 pub mod model;
+mod openapi;
 pub mod response;
+mod retry;
 mod runner;
+mod signature;
+pub mod state;
 mod timeout;
+pub mod tls;
+mod validation;
+
+/// The response header containing the wall-clock time in milliseconds it took to check a submission,
+/// including compilation and execution.
+pub const TOTAL_TIME_HEADER: &str = "x-total-ms";
+
+/// The request header containing a key that identifies a submission, such that retrying it does not check it again.
+pub const IDEMPOTENCY_KEY_HEADER: &str = "idempotency-key";
+
+/// The request header containing the test cases of a solution posted to `/submit/raw`, as a JSON array.
+pub const TEST_CASES_HEADER: &str = "x-test-cases";
 
-/// The parent directory of all test runner jobs.
-const PARENT_DIR: &str = "/mozart";
+/// The response header containing the signature of a submission result, if a signing key is configured.
+pub const SIGNATURE_HEADER: &str = "x-mozart-signature";
+
+/// The time in-flight requests are given to finish after mozart has been told to shut down.
+const SHUTDOWN_GRACE_PERIOD: Duration = Duration::from_secs(30);
+
+/// The name of the environment variable containing the numeric user id of the `restricted` user,
+/// e.g. for container images that create it with a fixed id.
+const RESTRICTED_UID_ENV_VAR: &str = "MOZART_RESTRICTED_UID";
+
+/// The name of the environment variable containing the numeric id of the group solutions are executed as,
+/// like [`RESTRICTED_UID_ENV_VAR`].
+const RESTRICTED_GID_ENV_VAR: &str = "MOZART_RESTRICTED_GID";
 
 /// The user id of the `restricted` user which is applied to solution execution to restrict its
 /// permissions.
+///
+/// It is read from [`RESTRICTED_UID_ENV_VAR`] if it is set, and looked up by the name of the user otherwise.
 pub static RESTRICTED_USER_ID: LazyLock<u32> = LazyLock::new(|| {
+    restricted_id(
+        RESTRICTED_UID_ENV_VAR,
+        env::var(RESTRICTED_UID_ENV_VAR).ok().as_deref(),
+        || lookup_restricted_id("-u"),
+    )
+});
+
+/// The id of the primary group of the `restricted` user, which solution execution is applied as well,
+/// such that it does not keep the group of mozart.
+///
+/// It is read from [`RESTRICTED_GID_ENV_VAR`] if it is set, and looked up by the name of the user otherwise.
+pub static RESTRICTED_GROUP_ID: LazyLock<u32> = LazyLock::new(|| {
+    restricted_id(
+        RESTRICTED_GID_ENV_VAR,
+        env::var(RESTRICTED_GID_ENV_VAR).ok().as_deref(),
+        || lookup_restricted_id("-g"),
+    )
+});
+
+/// Gets a user or group id of the `restricted` user from the `id` read from the `env_var`,
+/// and calls the `lookup` only if it is absent or not a valid id.
+fn restricted_id(env_var: &str, id: Option<&str>, lookup: impl FnOnce() -> u32) -> u32 {
+    match id.map(str::parse) {
+        Some(Ok(id)) => id,
+        Some(Err(err)) => {
+            warn!(
+                "ignoring invalid {} '{}': {}",
+                env_var,
+                id.unwrap_or_default(),
+                err
+            );
+            lookup()
+        }
+        None => lookup(),
+    }
+}
+
+/// Looks up an id of the `restricted` user with `id`, e.g. its user id with the `-u` option, which requires the user
+/// to have that exact name.
+///
+/// # Panics
+/// Panics if the id could not be looked up, as solutions cannot be executed in the sandbox without it.
+fn lookup_restricted_id(option: &str) -> u32 {
     /// The name of the linux user that will be restricted from creating files, and therefore used to
     /// call the solution execution process.
     const RESTRICTED_USER_NAME: &str = "restricted";
 
     let id_process = Command::new("id")
-        .args(["-u", RESTRICTED_USER_NAME])
+        .args([option, RESTRICTED_USER_NAME])
         .stdin(Stdio::null())
         .stderr(Stdio::piped())
         .stdout(Stdio::piped())
@@ -52,7 +183,10 @@ pub static RESTRICTED_USER_ID: LazyLock<u32> = LazyLock::new(|| {
     match String::from_utf8_lossy(&output.stdout).trim().parse() {
         Ok(id) => id,
         Err(err) => {
-            error!("failed to parse restricted user id: {}", err);
+            error!(
+                "failed to parse restricted user id from `id {}`: {}",
+                option, err
+            );
             info!("stdout: {}", String::from_utf8_lossy(&output.stdout));
             info!("stderr: {}", String::from_utf8_lossy(&output.stderr));
 
@@ -61,16 +195,43 @@ pub static RESTRICTED_USER_ID: LazyLock<u32> = LazyLock::new(|| {
             )
         }
     }
-});
+}
 
-/// Defines the routing of mozart.
+/// Defines the routing of mozart, where the handlers share the supplied `state`.
 ///
 /// Mainly exists as a standalone function due to logical reasoning,
-/// and to make it easier to write test cases that 'ping' the router.
-pub fn app() -> Router {
-    Router::new()
+/// and to make it easier to write test cases that 'ping' the router, e.g. with a shorter timeout.
+///
+/// If an API key is configured, every route except `/status`, `/ready` and `/health` requires it.
+///
+/// If a signing key is configured, the body of every response of the `/submit` routes, i.e. those responding with a
+/// verdict, is signed, and its signature is responded with in the [`SIGNATURE_HEADER`] header.
+///
+/// A request that takes longer than the configured request timeout is responded to with `504 Gateway Timeout`.
+pub fn app(state: AppState) -> Router {
+    let request_timeout = state.config.request_timeout;
+    let api_key = state.config.api_key.as_deref().map(Arc::from);
+    let signing_key = state.config.signing_key.as_deref().map(Arc::from);
+    let verdicts = Router::new()
         .route("/submit", post(submit))
+        .route("/submit/batch", post(submit_batch))
+        .route("/submit/quick", post(submit_quick))
+        .route("/submit/raw", post(submit_raw))
+        .route_layer(from_fn_with_state(signing_key, signature::sign));
+    let authorized = Router::new()
+        .merge(verdicts)
+        .route("/workdir/:uuid", delete(delete_workdir))
+        .route("/capabilities", get(capabilities))
+        .route("/selftest", get(selftest))
+        .route("/openapi.json", get(openapi))
+        .route_layer(from_fn_with_state(api_key, authorize));
+
+    Router::new()
+        .merge(authorized)
         .route("/status", get(status))
+        .route("/ready", get(ready))
+        .route("/health", get(health))
+        .with_state(state)
         .layer(
             TraceLayer::new_for_http()
                 .make_span_with(|_: &Request<Body>| {
@@ -88,18 +249,164 @@ pub fn app() -> Router {
                 .await
                 .expect("should always be able to spawn new task")
         }))
+        // the deadline is outside of the spawned task, which keeps running to clean up after the deadline is exceeded
+        .route_layer(from_fn_with_state(request_timeout, deadline))
+}
+
+/// A middleware that responds with `504 Gateway Timeout` if the request is not responded to within the
+/// `request_timeout`, regardless of how long it waited before being checked.
+async fn deadline(
+    State(request_timeout): State<Duration>,
+    req: Request<Body>,
+    next: Next,
+) -> Response {
+    match tokio::time::timeout(request_timeout, next.run(req)).await {
+        Ok(response) => response,
+        Err(_) => {
+            warn!("request exceeded the timeout of {:?}", request_timeout);
+            StatusCode::GATEWAY_TIMEOUT.into_response()
+        }
+    }
 }
 
 /// This functions starts the mozart server and will not return for as long as the server is running.
+///
+/// The server is served over HTTPS if TLS is configured, and over HTTP otherwise.
+///
+/// The server shuts down gracefully when receiving either a SIGTERM or SIGINT signal.
 #[tokio::main]
 pub async fn mozart() {
-    let mozart = app();
+    let tls_config = match tls::tls_config() {
+        Ok(tls_config) => tls_config,
+        Err(err) => {
+            error!("could not load tls configuration: {}", err);
+            panic!("failed to load tls configuration: {err}");
+        }
+    };
+
+    let state = AppState::new(Config::from_env());
+    if let Err(err) = warmup(state.clone()).await {
+        panic!("failed to warm up the toolchain: {err}");
+    }
+
     let listener = TcpListener::bind("0.0.0.0:8080")
         .await
         .expect("failed to bind to localhost:8080");
-    serve(listener, mozart)
-        .await
-        .expect("failed to start mozart");
+    match tls_config {
+        Some(tls_config) => serve_tls_until(listener, tls_config, state, shutdown_signal()).await,
+        None => serve_until(listener, state, shutdown_signal()).await,
+    }
+}
+
+/// Serves mozart with the `state` on the supplied `listener` until the `shutdown` future completes.
+///
+/// Once shutting down, no new connections are accepted, but in-flight requests are given up to
+/// [`SHUTDOWN_GRACE_PERIOD`] to finish. If they do not finish in time, the working directories
+/// they leave behind are removed before returning.
+pub async fn serve_until<F>(listener: TcpListener, state: AppState, shutdown: F)
+where
+    F: Future<Output = ()> + Send + 'static,
+{
+    serve_with_grace_period(listener, None, state, shutdown).await;
+}
+
+/// Serves mozart with the `state` over TLS using the `tls_config` on the supplied `listener` until the `shutdown`
+/// future completes.
+///
+/// It shuts down like [`serve_until`].
+pub async fn serve_tls_until<F>(
+    listener: TcpListener,
+    tls_config: Arc<ServerConfig>,
+    state: AppState,
+    shutdown: F,
+) where
+    F: Future<Output = ()> + Send + 'static,
+{
+    serve_with_grace_period(listener, Some(tls_config), state, shutdown).await;
+}
+
+/// Serves mozart with the `state` on the supplied `listener`, over TLS if a `tls_config` is supplied, until the
+/// `shutdown` future completes.
+///
+/// In-flight requests are given up to [`SHUTDOWN_GRACE_PERIOD`] to finish after shutting down.
+async fn serve_with_grace_period<F>(
+    listener: TcpListener,
+    tls_config: Option<Arc<ServerConfig>>,
+    state: AppState,
+    shutdown: F,
+) where
+    F: Future<Output = ()> + Send + 'static,
+{
+    let (shutting_down, shutting_down_rx) = oneshot::channel();
+    let shutdown = async move {
+        shutdown.await;
+        info!("shutting down, waiting for in-flight requests to finish");
+        let _ = shutting_down.send(());
+    };
+
+    let workdir = state.config.workdir.clone();
+    let server = async move {
+        match tls_config {
+            Some(tls_config) => tls::serve_tls(listener, tls_config, app(state), shutdown).await,
+            None => serve(listener, app(state))
+                .with_graceful_shutdown(shutdown)
+                .await
+                .expect("failed to start mozart"),
+        }
+    };
+
+    let grace_period = async {
+        match shutting_down_rx.await {
+            Ok(()) => sleep(SHUTDOWN_GRACE_PERIOD).await,
+            Err(_) => pending().await,
+        }
+    };
+
+    select! {
+        () = server => {}
+        () = grace_period => {
+            warn!("in-flight requests did not finish within {:?}", SHUTDOWN_GRACE_PERIOD);
+            remove_working_directories(&workdir);
+        }
+    }
+
+    info!("mozart has shut down");
+}
+
+/// Completes once mozart receives either a SIGTERM or SIGINT signal.
+async fn shutdown_signal() {
+    let mut terminate = signal(SignalKind::terminate()).expect("failed to install SIGTERM handler");
+
+    select! {
+        result = ctrl_c() => {
+            result.expect("failed to install SIGINT handler");
+            info!("received SIGINT");
+        }
+        _ = terminate.recv() => info!("received SIGTERM"),
+    }
+}
+
+/// Removes every working directory inside the `workdir`, which are left behind by unfinished submissions.
+///
+/// The shards of the working directories are removed along with them, as they are recreated when needed.
+fn remove_working_directories(workdir: &FsPath) {
+    let entries = match fs::read_dir(workdir) {
+        Ok(entries) => entries,
+        Err(err) => {
+            error!("could not read working directories: {}", err);
+            return;
+        }
+    };
+
+    for entry in entries.flatten() {
+        if let Err(err) = fs::remove_dir_all(entry.path()) {
+            error!(
+                "could not delete working directory {:?}: {}",
+                entry.path(),
+                err
+            );
+        }
+    }
 }
 
 /// An endpoint that exists to quickly assert whether mozart is still healthy.
@@ -111,33 +418,579 @@ async fn status() -> StatusCode {
     StatusCode::OK
 }
 
+/// An endpoint that reports whether mozart is ready to check submissions, i.e. whether the toolchain of the enabled
+/// language can be run.
+///
+/// Unlike `/status`, it fails for a misconfigured deployment, e.g. one where the toolchain is not installed,
+/// in which case it responds with a `503` status code and the reason as its body.
+async fn ready(State(state): State<AppState>) -> Response {
+    info!("performed readiness check");
+    match TestRunner::check_toolchain(&state.config).await {
+        Ok(()) => StatusCode::OK.into_response(),
+        Err(err) => (StatusCode::SERVICE_UNAVAILABLE, err.to_string()).into_response(),
+    }
+}
+
+/// An endpoint that reports the current load of mozart, i.e. the number of submissions being checked right now.
+///
+/// Unlike `/status`, it exists for autoscaling decisions rather than liveness checks.
+async fn health(State(state): State<AppState>) -> Json<Health> {
+    info!("performed health check");
+    Json(Health {
+        active_submissions: state.active_submissions.load(Ordering::Relaxed),
+        concurrency_limit: state.config.concurrency_limit,
+        skipped_test_cases: state.skipped_test_cases.load(Ordering::Relaxed),
+    })
+}
+
+/// An endpoint that checks a trivial submission of the enabled language end-to-end, i.e. compiled and executed in
+/// the jail of its own working directory, and responds with whether it passed and how long it took.
+///
+/// Unlike `/ready`, it exercises the whole pipeline a submission goes through, and exists for deployment verification.
+/// It responds with a `503` status code if the submission did not pass, along with the same body.
+async fn selftest(State(state): State<AppState>) -> Response {
+    let start = Instant::now();
+    let result = check_submission(TestRunner::warmup_submission(), state).await;
+    let elapsed_ms = start.elapsed().as_millis() as u64;
+
+    let passed = result == SubmissionResult::Pass;
+    let status = if passed {
+        info!("self-test passed in {} ms", elapsed_ms);
+        StatusCode::OK
+    } else {
+        error!("self-test submission did not pass: {:?}", result);
+        StatusCode::SERVICE_UNAVAILABLE
+    };
+    (status, Json(SelfTest { passed, elapsed_ms })).into_response()
+}
+
+/// An endpoint that exists so that clients can discover the language, parameter types and timeouts of mozart.
+async fn capabilities(State(state): State<AppState>) -> Json<Capabilities> {
+    info!("performed capabilities check");
+    Json(TestRunner::capabilities(&state.config))
+}
+
+/// An endpoint that serves the OpenAPI description of mozart, so that integrators have a formal contract of its interface.
+async fn openapi() -> Json<Value> {
+    info!("served openapi description");
+    Json(openapi::openapi())
+}
+
+/// An endpoint that removes the working directory with the given `uuid` inside the configured parent directory.
+///
+/// It exists for operational recovery, when the automatic cleanup of a working directory has failed.
+/// The `uuid` must be well-formed, which prevents it from referring to anything outside the parent directory.
+async fn delete_workdir(State(state): State<AppState>, Path(uuid): Path<String>) -> StatusCode {
+    let Ok(uuid) = Uuid::parse_str(&uuid) else {
+        info!(
+            "rejected deletion of working directory with invalid uuid '{}'",
+            uuid
+        );
+        return StatusCode::BAD_REQUEST;
+    };
+
+    let workdir = state.config.working_directory(&uuid);
+    match fs::remove_dir_all(workdir.as_path()) {
+        Ok(()) => {
+            info!("deleted working directory: {:?}", workdir);
+            StatusCode::NO_CONTENT
+        }
+        Err(err) if err.kind() == ErrorKind::NotFound => {
+            info!("working directory to delete does not exist: {:?}", workdir);
+            StatusCode::NOT_FOUND
+        }
+        Err(err) => {
+            error!("could not delete working directory {:?}: {}", workdir, err);
+            StatusCode::INTERNAL_SERVER_ERROR
+        }
+    }
+}
+
+/// The options of the `/submit` endpoint, supplied as query parameters.
+#[derive(Deserialize, Serialize, Debug, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct SubmitOptions {
+    /// Whether to skip compilation and execution, and instead check the submission deterministically.
+    #[serde(default)]
+    pub dry_run: bool,
+
+    /// The order of the test case results in the response, which is the order of the test cases by default.
+    #[serde(default)]
+    pub order: ResultOrder,
+}
+
 /// The endpoint used to check a given submission against a set of test cases.
-pub async fn submit(Json(submission): Json<Submission>) -> SubmissionResult {
+///
+/// A request body that cannot be parsed as a submission is responded to with the status code of the rejection,
+/// but with the same body shape as a [`SubmissionResult::Error`].
+///
+/// If the `dryRun` query parameter is `true`, the submission is not compiled nor executed,
+/// and a test case passes if its input parameters equal its output parameters.
+///
+/// The test case results are responded with in the order of the `order` query parameter, see [`ResultOrder`].
+///
+/// If the solution wrote to stdout, e.g. to debug it, what it wrote is responded with alongside the result,
+/// as an [`AnnotatedSubmissionResult`], along with the metadata of the submission, if it supplied any.
+///
+/// If the submission requests debugging and the server allows it, the generated test file is responded with
+/// alongside the result, as a [`DebugSubmissionResult`].
+///
+/// If the [`IDEMPOTENCY_KEY_HEADER`] header is supplied, and a submission with the same key is in-flight or was
+/// recently checked, it is responded to with the result, stdout and generated test file of that submission instead of
+/// being checked again. A key that was used with a different submission, or with different query parameters, is
+/// responded to with `422 Unprocessable Entity`.
+///
+/// The wall-clock time it took to check the submission is responded with in the [`TOTAL_TIME_HEADER`] header.
+pub async fn submit(
+    State(state): State<AppState>,
+    headers: HeaderMap,
+    Query(options): Query<SubmitOptions>,
+    payload: Result<Json<Submission>, JsonRejection>,
+) -> Response {
+    let mut submission = match payload {
+        Ok(Json(submission)) => submission,
+        Err(rejection) => return reject(rejection),
+    };
+
+    let idempotency_key = headers
+        .get(IDEMPOTENCY_KEY_HEADER)
+        .and_then(|key| key.to_str().ok())
+        .filter(|key| !key.is_empty());
+    // the key is bound to the request as a whole, i.e. the submission including its metadata, and the options,
+    // as e.g. a dry run is responded to differently
+    let serialized = match idempotency_key {
+        Some(_) => match serde_json::to_vec(&(&submission, &options)) {
+            Ok(serialized) => serialized,
+            Err(err) => {
+                error!("could not serialize submission: {}", err);
+                return SubmissionResult::InternalError.into_response();
+            }
+        },
+        None => Vec::new(),
+    };
+
+    let metadata = submission.metadata.take();
+    // the generated test file contains the expected values of every test case, including the hidden ones
+    let has_hidden = submission
+        .test_cases
+        .iter()
+        .any(|test_case| test_case.hidden);
+    let debug = submission.debug && state.config.allow_debug && !has_hidden;
+    if submission.debug && !debug {
+        info!("ignored request for the generated test file, as debugging is not allowed or a test case is hidden");
+    }
+
+    let start = Instant::now();
+    let check = async {
+        if options.dry_run {
+            CheckedSubmission::from(echo::echo_submission(&submission))
+        } else {
+            check_in_workdir(submission, state.clone(), debug, false).await
+        }
+    };
+    let checked = match idempotency_key {
+        Some(key) => {
+            let checked = state
+                .idempotency_keys
+                .check_once(key.to_string(), &serialized, check)
+                .await;
+            match checked {
+                Ok(checked) => checked,
+                Err(err) => {
+                    return invalid_request(StatusCode::UNPROCESSABLE_ENTITY, err.to_string())
+                }
+            }
+        }
+        None => check.await,
+    };
+    let CheckedSubmission {
+        mut result,
+        test_file: generated_source,
+        stdout,
+    } = checked;
+    result.reorder(options.order);
+    let total_ms = start.elapsed().as_millis() as u64;
+    info!("submission took {} ms", total_ms);
+
+    let mut response = if debug {
+        DebugSubmissionResult {
+            result,
+            stdout,
+            metadata,
+            generated_source,
+        }
+        .into_response()
+    } else {
+        AnnotatedSubmissionResult {
+            result,
+            stdout,
+            metadata,
+        }
+        .into_response()
+    };
+    response
+        .headers_mut()
+        .insert(TOTAL_TIME_HEADER, HeaderValue::from(total_ms));
+    response
+}
+
+/// The endpoint used to check multiple independent submissions in a single request.
+///
+/// The results are returned in the same order as the submissions, along with their metadata,
+/// and each of them counts towards the configured concurrency limit, see [`Config::concurrency_limit`].
+///
+/// If any of the submissions results in an internal error, the entire batch is responded to with a `500` status code.
+async fn submit_batch(
+    State(state): State<AppState>,
+    payload: Result<Json<Box<[Submission]>>, JsonRejection>,
+) -> Response {
+    let submissions = match payload {
+        Ok(Json(submissions)) => submissions,
+        Err(rejection) => return reject(rejection),
+    };
+
+    info!("checking batch of {} submissions", submissions.len());
+    let handles = submissions
+        .into_vec()
+        .into_iter()
+        .map(|mut submission| {
+            let state = state.clone();
+            let metadata = submission.metadata.take();
+            // the spawned check keeps the span of the request, such that the spans of its phases are children of it
+            tokio::spawn(
+                async move { (check_submission(submission, state).await, metadata) }
+                    .in_current_span(),
+            )
+        })
+        .collect::<Vec<_>>();
+
+    let mut results = Vec::with_capacity(handles.len());
+    for handle in handles {
+        match handle.await {
+            Ok((SubmissionResult::InternalError, _)) => {
+                return SubmissionResult::InternalError.into_response()
+            }
+            Ok((result, metadata)) => results.push(AnnotatedSubmissionResult {
+                result,
+                stdout: None,
+                metadata,
+            }),
+            Err(err) => {
+                error!("could not join submission check of batch: {}", err);
+                return SubmissionResult::InternalError.into_response();
+            }
+        }
+    }
+
+    (StatusCode::OK, Json(results)).into_response()
+}
+
+/// The endpoint used to check whether a given submission passes every test case, without the results of the test cases.
+///
+/// It responds with a [`QuickResult`], or with the rejection of a request body that cannot be parsed like `/submit`.
+/// Unless it is configured otherwise, the test cases after the first one that does not pass are skipped,
+/// as they cannot change whether the submission passed.
+async fn submit_quick(
+    State(state): State<AppState>,
+    payload: Result<Json<Submission>, JsonRejection>,
+) -> Response {
+    let submission = match payload {
+        Ok(Json(submission)) => submission,
+        Err(rejection) => return reject(rejection),
+    };
+
+    let fail_fast = state.config.quick_fail_fast;
+    let result = check_in_workdir(submission, state, false, fail_fast)
+        .await
+        .result;
+    match result {
+        SubmissionResult::InternalError => result.into_response(),
+        result => Json(QuickResult {
+            passed: result == SubmissionResult::Pass,
+        })
+        .into_response(),
+    }
+}
+
+/// The endpoint used to check a solution supplied as the raw request body, e.g. with `curl --data-binary @solution.py`,
+/// which spares manual testing from escaping the solution inside a JSON submission.
+///
+/// The test cases are supplied as a JSON array in the [`TEST_CASES_HEADER`] header, and if it is absent,
+/// the solution is checked without test cases, i.e. it is only compiled and run.
+/// The submission is otherwise checked and responded to like by `/submit`, including its query parameters.
+async fn submit_raw(
+    State(state): State<AppState>,
+    headers: HeaderMap,
+    options: Query<SubmitOptions>,
+    payload: Result<String, StringRejection>,
+) -> Response {
+    let solution = match payload {
+        Ok(solution) => solution,
+        Err(rejection) => return invalid_request(rejection.status(), rejection.body_text()),
+    };
+
+    let test_cases = match headers.get(TEST_CASES_HEADER) {
+        Some(value) => {
+            let parsed = value
+                .to_str()
+                .map_err(|err| err.to_string())
+                .and_then(|value| {
+                    serde_json::from_str::<Box<[TestCase]>>(value).map_err(|err| err.to_string())
+                });
+            match parsed {
+                Ok(test_cases) => test_cases,
+                Err(err) => {
+                    return invalid_request(
+                        StatusCode::BAD_REQUEST,
+                        format!("Failed to parse the {TEST_CASES_HEADER} header: {err}"),
+                    )
+                }
+            }
+        }
+        None => Box::new([]),
+    };
+
+    let submission = Submission {
+        solution,
+        test_cases,
+        unordered_lists: false,
+        files: Box::new([]),
+        checker: None,
+        reference_solution: None,
+        only_ids: None,
+        compiler_flags: Box::new([]),
+        debug: false,
+        float_tolerance: None,
+        toolchain_version: None,
+        metadata: None,
+    };
+    submit(State(state), headers, options, Ok(Json(submission))).await
+}
+
+/// Responds to a request body that could not be parsed, using the body shape of a [`SubmissionResult::Error`].
+fn reject(rejection: JsonRejection) -> Response {
+    invalid_request(rejection.status(), rejection.body_text())
+}
+
+/// Responds to an invalid request with the `status` code, as a [`SubmissionResult::Error`] with the `message`.
+fn invalid_request(status: StatusCode, message: String) -> Response {
+    info!("rejected submission: {}", message);
+    let response = SubmissionResult::Error {
+        code: ErrorCode::InvalidRequest,
+        message,
+    };
+    (status, response).into_response()
+}
+
+/// Confirms that the toolchain of the enabled language works, and primes it by checking a trivial submission
+/// with the `state`.
+///
+/// It is run before mozart starts serving, such that a broken toolchain is detected at startup rather than
+/// by the first submission, and such that the first submission does not pay the cold start cost.
+///
+/// # Errors
+/// Returns a `SubmissionError::Internal` if the toolchain could not be run, or the trivial submission did not pass.
+pub async fn warmup(state: AppState) -> Result<(), SubmissionError> {
+    info!("warming up the toolchain");
+    TestRunner::check_toolchain(&state.config).await?;
+
+    let start = Instant::now();
+    match check_submission(TestRunner::warmup_submission(), state).await {
+        SubmissionResult::Pass => {
+            info!("warmed up in {:?}", start.elapsed());
+            Ok(())
+        }
+        result => {
+            error!("warmup submission did not pass: {:?}", result);
+            Err(SubmissionError::Internal)
+        }
+    }
+}
+
+/// Checks a given submission against its test cases with the `state`, without involving the HTTP layer.
+///
+/// The submission is checked in its own temporary working directory inside a shard of the configured parent
+/// directory, which is deleted afterwards.
+///
+/// This is the core of the `/submit` endpoint, and exists as a standalone function so that mozart
+/// can be embedded as a library.
+pub async fn check_submission(submission: Submission, state: AppState) -> SubmissionResult {
+    check_in_workdir(submission, state, false, false)
+        .await
+        .result
+}
+
+/// Generates the test code that calls the solution with the `test_cases` and checks what it returns, without writing,
+/// compiling or executing anything.
+///
+/// This is the part of checking a submission whose cost grows with the number of test cases, and exists as a
+/// standalone function so that it can be benchmarked on its own.
+pub fn generate_test_code(test_cases: &[TestCase], state: AppState) -> String {
+    TestRunner::new(PathBuf::new(), state).test_code(test_cases, false)
+}
+
+/// The outcome of checking a submission in its own working directory.
+#[derive(Clone)]
+pub(crate) struct CheckedSubmission {
+    /// The result of checking the submission.
+    result: SubmissionResult,
+
+    /// The generated test file, which is `None` if it was not kept, or if the submission was rejected before it was
+    /// generated.
+    test_file: Option<String>,
+
+    /// What the solution wrote to stdout, which is `None` if it was not executed or did not write anything.
+    stdout: Option<String>,
+}
+
+/// Checks a given submission like [`check_submission`], along with the generated test file if `keep_test_file` is set,
+/// and what the solution wrote to stdout.
+///
+/// If `fail_fast` is set, the test cases after the first one that does not pass are skipped, see [`TestRunner::fail_fast`].
+async fn check_in_workdir(
+    submission: Submission,
+    state: AppState,
+    keep_test_file: bool,
+    fail_fast: bool,
+) -> CheckedSubmission {
+    // the slot is held until the working directory is deleted, such that it bounds the working directories as well
+    let slot = state.slots.acquire().await;
     let uuid = Uuid::new_v4();
 
     debug!(?submission);
 
-    let temp_dir = PathBuf::from(format!("{}/{}", PARENT_DIR, uuid));
+    let temp_dir = state.config.working_directory(&uuid);
     info!("unique directory: {:?}", temp_dir);
 
-    if let Err(err) = fs::create_dir(temp_dir.as_path()) {
+    // the shard of the working directory is created alongside it, and kept for the next submissions in it
+    if let Err(err) = retry_io("create temporary working directory", || {
+        fs::create_dir_all(temp_dir.as_path())
+    })
+    .await
+    {
         error!("could not create temporary working directory: {}", err);
-        return SubmissionResult::from(SubmissionError::Internal);
+        return CheckedSubmission::internal_error();
     }
 
-    let runner = TestRunner::new(temp_dir.clone());
+    let active_submission = ActiveSubmission::start(&state.active_submissions);
+    let runner = TestRunner::new(temp_dir.clone(), state)
+        .fail_fast(fail_fast)
+        .slot(slot.number);
+    let test_file_path = runner.test_file_path();
 
     info!("checking submission");
-    let response = if let Err(err) = runner.check(submission).await {
+    let (check, stdout) = runner.check(submission).await;
+    let result = if let Err(err) = check {
         SubmissionResult::from(err)
     } else {
         SubmissionResult::Pass
     };
+    drop(active_submission);
+
+    let test_file = keep_test_file
+        .then(|| fs::read_to_string(&test_file_path).ok())
+        .flatten();
 
     if let Err(err) = fs::remove_dir_all(temp_dir.as_path()) {
         error!("could not delete temporary working directory: {}", err);
-        return SubmissionResult::from(SubmissionError::Internal);
+        return CheckedSubmission::internal_error();
     }
 
-    response
+    CheckedSubmission {
+        result,
+        test_file,
+        stdout,
+    }
+}
+
+impl From<SubmissionResult> for CheckedSubmission {
+    /// Creates the outcome of a check that did not run the solution, which has no test file nor stdout.
+    fn from(result: SubmissionResult) -> Self {
+        Self {
+            result,
+            test_file: None,
+            stdout: None,
+        }
+    }
+}
+
+impl Remember for CheckedSubmission {
+    fn is_remembered(&self) -> bool {
+        self.result.is_remembered()
+    }
+}
+
+impl CheckedSubmission {
+    /// Creates the outcome of a check that failed with an internal error, which has no test file nor stdout.
+    fn internal_error() -> Self {
+        Self {
+            result: SubmissionResult::from(SubmissionError::Internal),
+            test_file: None,
+            stdout: None,
+        }
+    }
+}
+
+/// Counts a submission as active in the active submissions of [`AppState`] for as long as it lives.
+///
+/// The count is decremented when it is dropped, so that it stays correct even if the check panics or is cancelled.
+struct ActiveSubmission(Arc<AtomicUsize>);
+
+impl ActiveSubmission {
+    /// Starts counting a submission as active in the `active_submissions`.
+    fn start(active_submissions: &Arc<AtomicUsize>) -> Self {
+        active_submissions.fetch_add(1, Ordering::Relaxed);
+        Self(Arc::clone(active_submissions))
+    }
+}
+
+impl Drop for ActiveSubmission {
+    fn drop(&mut self) {
+        self.0.fetch_sub(1, Ordering::Relaxed);
+    }
+}
+
+#[cfg(test)]
+mod restricted_id {
+    use super::{restricted_id, RESTRICTED_GID_ENV_VAR, RESTRICTED_UID_ENV_VAR};
+
+    #[test]
+    fn from_env_var() {
+        let expected = 1001;
+
+        let actual = restricted_id(RESTRICTED_UID_ENV_VAR, Some("1001"), || {
+            panic!("the user id should not be looked up")
+        });
+
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn without_env_var() {
+        let expected = 1002;
+
+        let actual = restricted_id(RESTRICTED_UID_ENV_VAR, None, || 1002);
+
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn invalid_env_var() {
+        let expected = 1002;
+
+        let actual = restricted_id(RESTRICTED_UID_ENV_VAR, Some("restricted"), || 1002);
+
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn group_from_env_var() {
+        let expected = 100;
+
+        let actual = restricted_id(RESTRICTED_GID_ENV_VAR, Some("100"), || {
+            panic!("the group id should not be looked up")
+        });
+
+        assert_eq!(actual, expected);
+    }
 }
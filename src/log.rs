@@ -1,20 +1,37 @@
 //! Contains objects related to setting up logging for mozart.
 
-use std::str::FromStr;
+use std::{env, str::FromStr};
 use tracing::level_filters::LevelFilter;
 use tracing_subscriber::fmt::time::OffsetTime;
 
 /// The default log level applied if nothing else is specified.
 const DEFAULT_LOG_LEVEL: LevelFilter = LevelFilter::INFO;
 
+/// The default log format applied if nothing else is specified.
+const DEFAULT_LOG_FORMAT: LogFormat = LogFormat::Text;
+
+/// The format of the log output.
+#[derive(Debug, PartialEq)]
+enum LogFormat {
+    /// Human readable lines of text.
+    Text,
+
+    /// A JSON object per line, for ingestion in log pipelines.
+    Json,
+}
+
 /// Initialises a global logging subscriber.
 ///
-/// The only configuration is compile time based on the environment variable
-/// `MOZART_LOG` which will determine the log level enabled.
+/// The configuration is read at startup from the environment variables `MOZART_LOG`
+/// which will determine the log level enabled, and `MOZART_LOG_FORMAT` which will
+/// determine whether the output is formatted as text or as JSON.
+///
+/// As they are read at runtime, they can be changed without rebuilding mozart.
 pub fn init() {
-    let level = level_filter(option_env!("MOZART_LOG"));
+    let level = level_filter(env::var("MOZART_LOG").ok().as_deref());
+    let format = log_format(env::var("MOZART_LOG_FORMAT").ok().as_deref());
     let time = OffsetTime::local_rfc_3339().expect("could not initialize time offset");
-    tracing_subscriber::fmt()
+    let subscriber = tracing_subscriber::fmt()
         .with_max_level(level)
         .with_timer(time)
         .with_ansi(false)
@@ -23,9 +40,23 @@ pub fn init() {
         .with_level(true)
         .with_thread_names(false)
         .with_thread_ids(false)
-        .with_target(false)
-        .try_init()
-        .expect("failed to initialize subscriber");
+        .with_target(false);
+
+    match format {
+        LogFormat::Text => subscriber.try_init(),
+        LogFormat::Json => subscriber.json().try_init(),
+    }
+    .expect("failed to initialize subscriber");
+}
+
+/// Determines the log format based on the supplied optional string slice.
+///
+/// The value `json` (case insensitive) selects JSON output, anything else selects the default format.
+fn log_format(env_var: Option<&str>) -> LogFormat {
+    match env_var {
+        Some(var) if var.eq_ignore_ascii_case("json") => LogFormat::Json,
+        _ => DEFAULT_LOG_FORMAT,
+    }
 }
 
 /// Determines the level filter based on the supplied optional string slice.
@@ -127,3 +158,58 @@ mod level_filter {
         assert_eq!(actual, expected);
     }
 }
+
+#[cfg(test)]
+mod log_format {
+    use super::{log_format, LogFormat, DEFAULT_LOG_FORMAT};
+
+    #[test]
+    fn none() {
+        let input = None;
+        let expected = DEFAULT_LOG_FORMAT;
+
+        let actual = log_format(input);
+
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn invalid_input() {
+        let input = Some("yaml");
+        let expected = DEFAULT_LOG_FORMAT;
+
+        let actual = log_format(input);
+
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn json() {
+        let input = Some("json");
+        let expected = LogFormat::Json;
+
+        let actual = log_format(input);
+
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn json_uppercase() {
+        let input = Some("JSON");
+        let expected = LogFormat::Json;
+
+        let actual = log_format(input);
+
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn text() {
+        let input = Some("text");
+        let expected = LogFormat::Text;
+
+        let actual = log_format(input);
+
+        assert_eq!(actual, expected);
+    }
+}
@@ -1,5 +1,10 @@
-use mozart::{log, mozart, RESTRICTED_USER_ID};
+use mozart::{log, mozart};
+#[cfg(not(feature = "no-sandbox"))]
+use mozart::{RESTRICTED_GROUP_ID, RESTRICTED_USER_ID};
+#[cfg(not(feature = "no-sandbox"))]
 use tracing::info;
+#[cfg(feature = "no-sandbox")]
+use tracing::warn;
 
 /// We need to initilize the logger before multithreading happens
 /// otherwise local time offset cannot be determined.
@@ -9,9 +14,16 @@ use tracing::info;
 fn main() {
     log::init();
 
-    // this log is both for information, but also to force the user id of the restricted user to be
+    // this log is both for information, but also to force the user and group id of the restricted user to be
     // computed before mozart starts, making a failure to do so a panic condition
-    info!("restricted user id is '{}'", *RESTRICTED_USER_ID);
+    #[cfg(not(feature = "no-sandbox"))]
+    info!(
+        "restricted user id is '{}' and group id is '{}'",
+        *RESTRICTED_USER_ID, *RESTRICTED_GROUP_ID
+    );
+
+    #[cfg(feature = "no-sandbox")]
+    warn!("the sandbox is disabled, so solutions are executed unjailed as the current user");
 
     mozart();
 }
@@ -3,6 +3,8 @@
 //! The models are agnostic both in terms of the underlying programming language, and the exercise being 'checked' against.
 
 use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use std::collections::BTreeMap;
 
 /// A submission provided by the backend.
 #[derive(Deserialize, Serialize, Debug, Clone)]
@@ -13,6 +15,126 @@ pub struct Submission {
 
     /// The test cases that must be checked for the submitted solution.
     pub test_cases: Box<[TestCase]>,
+
+    /// Whether a [`ParameterType::List`] returned by the solution is compared with the expected list regardless of
+    /// the order of their elements, e.g. for a problem whose answer is a set of values in any order.
+    ///
+    /// The elements of both lists are sorted before they are compared. It is optional in a request, and lists are
+    /// compared in order if it is absent.
+    #[serde(default)]
+    pub unordered_lists: bool,
+
+    /// The auxiliary files supplied alongside the solution, e.g. a scaffold or a module the solution depends on.
+    ///
+    /// They are written next to the solution before it is compiled or executed, and are optional in a request.
+    #[serde(default)]
+    pub files: Box<[SourceFile]>,
+
+    /// The custom checker that decides whether the actual value of a test case is accepted, instead of equality.
+    ///
+    /// It is code in the language of the solution, defining a `checker` function that receives the actual and expected
+    /// values and returns whether the test case passed. A Haskell checker must be declared as the `Checker` module.
+    /// It is optional in a request, and test cases are checked by equality if it is absent.
+    #[serde(default)]
+    pub checker: Option<String>,
+
+    /// The reference solution that computes the expected value of every test case without output parameters.
+    ///
+    /// It is code in the language of the solution, defining a `solution` function like the solution itself, which is
+    /// called with the input parameters of such a test case, e.g. for randomly generated test cases. A Haskell
+    /// reference solution must be declared as the `Reference` module. It is optional in a request.
+    #[serde(default)]
+    pub reference_solution: Option<String>,
+
+    /// The ids of the test cases to run, e.g. to rerun a single failing test case while debugging.
+    ///
+    /// Every id must be that of a test case. It is optional in a request, and every test case is run if it is absent.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub only_ids: Option<Box<[u64]>>,
+
+    /// The flags passed to the toolchain of the language, e.g. to enable a language extension of the compiler.
+    ///
+    /// Every flag must be in the allowlist configured by the server. It is optional in a request, and no flags are
+    /// passed if it is absent.
+    #[serde(default)]
+    pub compiler_flags: Box<[String]>,
+
+    /// Whether to respond with the generated test file alongside the result, e.g. to debug an unexpected compilation error.
+    ///
+    /// It is only honoured if the server allows debugging and no test case is hidden, and is optional in a request.
+    #[serde(default)]
+    pub debug: bool,
+
+    /// The tolerances within which a float returned by the solution is accepted as equal to the expected float.
+    ///
+    /// The floats are compared for equality if it is absent, and it cannot be combined with a checker.
+    #[serde(default)]
+    pub float_tolerance: Option<FloatTolerance>,
+
+    /// The version of the compiler, or of the interpreter, to check the solution with, e.g. `9.4.7` for `ghc-9.4.7`.
+    ///
+    /// It must be one of the versions configured by the server, and the default toolchain is used if it is absent.
+    #[serde(default)]
+    pub toolchain_version: Option<String>,
+
+    /// An opaque object that is echoed verbatim in the response, e.g. the id of the student and the attempt number,
+    /// such that the result can be correlated with the submission without mozart interpreting it.
+    ///
+    /// It is optional in a request, and is not echoed if it is absent.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub metadata: Option<Value>,
+}
+
+impl Submission {
+    /// Gets the test cases that should be run, i.e. those with an id in [`Submission::only_ids`] if it is present,
+    /// and every test case otherwise.
+    pub fn selected_test_cases(&self) -> Box<[TestCase]> {
+        match &self.only_ids {
+            Some(only_ids) => self
+                .test_cases
+                .iter()
+                .filter(|test_case| only_ids.contains(&test_case.id))
+                .cloned()
+                .collect(),
+            None => self.test_cases.clone(),
+        }
+    }
+}
+
+/// An auxiliary file supplied alongside the solution.
+#[derive(Deserialize, Serialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct SourceFile {
+    /// The name of the file, including its extension, e.g. `Helper.hs`.
+    ///
+    /// It must be a plain file name, i.e. it cannot contain a path.
+    pub name: String,
+
+    /// The contents of the file.
+    pub contents: String,
+}
+
+/// The tolerances within which an actual float is accepted as equal to the expected float.
+///
+/// The actual float `a` is accepted for the expected float `b` if `|a - b| <= max(absolute, relative * |b|)`,
+/// which also applies to the floats inside multiple output parameters and the values of maps.
+#[derive(Deserialize, Serialize, Debug, Clone, Copy, PartialEq, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct FloatTolerance {
+    /// The difference that is accepted regardless of the magnitude of the expected float, e.g. `1e-9`.
+    #[serde(default)]
+    pub absolute: f64,
+
+    /// The difference that is accepted relative to the magnitude of the expected float, e.g. `1e-9` for a difference
+    /// of one billionth of it, which is meaningful for floats of any magnitude, unlike an absolute tolerance.
+    #[serde(default)]
+    pub relative: f64,
+
+    /// Whether an expected NaN accepts an actual NaN, which is never equal to anything otherwise.
+    ///
+    /// An expected infinity only accepts the same infinity regardless, as no tolerance is meaningful for it.
+    #[serde(default)]
+    pub match_nan: bool,
 }
 
 /// A test case for a given exercise.
@@ -32,6 +154,52 @@ pub struct TestCase {
     ///
     /// This is a slice so as to not limit the amount of input arguments a given exercise can supply.
     pub output_parameters: Box<[Parameter]>,
+
+    /// The data the solution can read from stdin while the test case runs.
+    ///
+    /// It is optional in a request, and stdin is empty if it is absent.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub stdin: Option<String>,
+
+    /// The runtime error the solution should raise for the input parameters, instead of returning a value.
+    ///
+    /// If it is present, the output parameters are ignored, and the test case passes if the solution raises an error
+    /// matching it, while returning any value fails it.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub expected_error: Option<ExpectedError>,
+
+    /// The category of the test case, e.g. `edge case` or `hidden`, which is echoed in its result unchanged,
+    /// so that the frontend can group or hide the results by it.
+    ///
+    /// It is optional in a request, and has no effect on how the test case is checked.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub label: Option<String>,
+
+    /// Whether the test case is hidden from the student, in which case its result only tells whether it passed,
+    /// i.e. the failure reason is [`TestCaseFailureReason::Hidden`] instead of the input, actual and expected values.
+    ///
+    /// It is optional in a request, and a test case is visible if it is absent.
+    #[serde(default)]
+    pub hidden: bool,
+}
+
+/// The runtime error a test case expects the solution to raise.
+#[derive(Deserialize, Serialize, Debug, Clone, PartialEq, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct ExpectedError {
+    /// The text the description of the raised error must contain, e.g. `division by zero`,
+    /// where any runtime error is accepted if it is absent.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub message: Option<String>,
+}
+
+impl ExpectedError {
+    /// Whether the runtime error described by `msg` matches the expected error.
+    pub fn matches(&self, msg: &str) -> bool {
+        self.message
+            .as_deref()
+            .is_none_or(|message| msg.contains(message))
+    }
 }
 
 /// A parameter.
@@ -45,6 +213,72 @@ pub struct Parameter {
     pub value: String,
 }
 
+impl Parameter {
+    /// Gets the entries of a [`ParameterType::Map`] parameter as pairs of key and value parameters, sorted by key.
+    ///
+    /// Returns `None` if the parameter is not a map, or its value is not a JSON object of strings.
+    pub fn entries(&self) -> Option<Vec<(Parameter, Parameter)>> {
+        let ParameterType::Map { key, value } = &self.value_type else {
+            return None;
+        };
+
+        let entries = serde_json::from_str::<BTreeMap<String, String>>(&self.value).ok()?;
+        let entries = entries
+            .into_iter()
+            .map(|(key_value, value_value)| {
+                (
+                    Parameter {
+                        value_type: key.as_ref().clone(),
+                        value: key_value,
+                    },
+                    Parameter {
+                        value_type: value.as_ref().clone(),
+                        value: value_value,
+                    },
+                )
+            })
+            .collect();
+
+        Some(entries)
+    }
+
+    /// Gets the elements of a [`ParameterType::List`] parameter as parameters of the contained type, in order.
+    ///
+    /// Returns `None` if the parameter is not a list, or its value is not a JSON array of strings.
+    pub fn elements(&self) -> Option<Vec<Parameter>> {
+        let ParameterType::List(value_type) = &self.value_type else {
+            return None;
+        };
+
+        let elements = serde_json::from_str::<Vec<String>>(&self.value).ok()?;
+        let elements = elements
+            .into_iter()
+            .map(|value| Parameter {
+                value_type: value_type.as_ref().clone(),
+                value,
+            })
+            .collect();
+
+        Some(elements)
+    }
+
+    /// Gets the value of a [`ParameterType::Optional`] parameter as a parameter of the contained type,
+    /// which is `None` if the value is absent.
+    ///
+    /// Returns `None` if the parameter is not optional, or its value is neither `null` nor a JSON string.
+    pub fn optional_value(&self) -> Option<Option<Parameter>> {
+        let ParameterType::Optional(value_type) = &self.value_type else {
+            return None;
+        };
+
+        let value = serde_json::from_str::<Option<String>>(&self.value).ok()?;
+        Some(value.map(|value| Parameter {
+            value_type: value_type.as_ref().clone(),
+            value,
+        }))
+    }
+}
+
 /// The allowed types of a parameter.
 ///
 /// During JSON deserialization of a request it is a parse error to not use one of these types as the parameter type.
@@ -57,20 +291,98 @@ pub enum ParameterType {
     Bool,
 
     /// A signed 64-bit integer.
+    ///
+    /// Depending on the language, this may be the default integer type, whose width is platform dependent.
+    /// Use [`ParameterType::Int32`] or [`ParameterType::Int64`] for problems that depend on the width.
+    ///
+    /// A value outside of the range of a signed 64-bit integer is rejected, even in languages whose integers are
+    /// unbounded, as C++ and Kotlin write it as a `long long` or `Long` literal, e.g. `5LL` or `5L`, which could not
+    /// hold it. Such values used to be accepted, and are now a [`ParameterType::BigInt`].
+    ///
+    /// As for every integer type, the value must be in canonical form, i.e. without a `+` sign or leading zeros,
+    /// and `0` rather than `-0`.
     Int,
 
+    /// A signed integer of exactly 32 bits, which wraps around on overflow in languages with fixed-width integers.
+    Int32,
+
+    /// A signed integer of exactly 64 bits, which wraps around on overflow in languages with fixed-width integers.
+    Int64,
+
+    /// An arbitrary-precision signed integer.
+    ///
+    /// Should be used for values that may exceed the range of [`ParameterType::Int`], e.g. factorials.
+    BigInt,
+
     /// A double precision floating point value (64-bit precision).
     Float,
 
+    /// An exact decimal number, e.g. `10.25`, which is compared exactly rather than within a float tolerance.
+    ///
+    /// Should be used for values that a float cannot represent exactly, e.g. amounts of money,
+    /// such that `0.1 + 0.2` equals `0.3`. The value may have a sign, but no exponent.
+    Decimal,
+
     /// A character, or a single character string (depending on the language).
     Char,
 
     /// A string or character array (depending on the language).
     String,
+
+    /// The absence of a value, e.g. `()` or `None` (depending on the language).
+    ///
+    /// Should be used as the output parameter of solutions that do not return a value,
+    /// in which case it is only checked that the solution did not fail. The value must be empty.
+    Unit,
+
+    /// A map or dictionary (depending on the language) from values of the `key` type to values of the `value` type.
+    ///
+    /// The value must be a JSON object, whose keys and values are the values of the entries as strings,
+    /// e.g. `{"apple": "3", "pear": "5"}` for a map from [`ParameterType::String`] to [`ParameterType::Int`].
+    /// Two maps are equal if they have the same entries, regardless of their order. The key cannot be a map or a list.
+    Map {
+        /// The type of the keys of the map.
+        key: Box<ParameterType>,
+
+        /// The type of the values of the map.
+        value: Box<ParameterType>,
+    },
+
+    /// A list, vector or array (depending on the language) of values of the contained type.
+    ///
+    /// The value must be a JSON array, whose elements are the values of the elements as strings,
+    /// e.g. `["1", "2"]` for a list of [`ParameterType::Int`]. Two lists are equal if they have the same elements in the
+    /// same order, unless the submission compares lists regardless of their order. The contained type cannot be a
+    /// [`ParameterType::Unit`].
+    List(Box<ParameterType>),
+
+    /// A value of the contained type that may be absent, e.g. a `Maybe` or a value that may be `None`
+    /// (depending on the language).
+    ///
+    /// The value must be JSON, which is `null` if the value is absent, and otherwise the present value as a string,
+    /// e.g. `"\"5\""` for an optional [`ParameterType::Int`]. The contained type cannot be optional itself,
+    /// nor a [`ParameterType::Unit`], as some languages cannot tell their absence apart from an absent value.
+    Optional(Box<ParameterType>),
+}
+
+impl ParameterType {
+    /// Every parameter type that does not contain other types, in the order they are declared.
+    pub const ALL: [ParameterType; 10] = [
+        ParameterType::Bool,
+        ParameterType::Int,
+        ParameterType::Int32,
+        ParameterType::Int64,
+        ParameterType::BigInt,
+        ParameterType::Float,
+        ParameterType::Decimal,
+        ParameterType::Char,
+        ParameterType::String,
+        ParameterType::Unit,
+    ];
 }
 
 /// A test case result, indicating how a solution handled a given test case.
-#[derive(Deserialize, Serialize, Debug, PartialEq)]
+#[derive(Deserialize, Serialize, Debug, PartialEq, Clone)]
 #[serde(rename_all = "camelCase")]
 pub struct TestCaseResult {
     /// The id of the test case.
@@ -79,10 +391,25 @@ pub struct TestCaseResult {
     /// The result of the test case.
     #[serde(flatten)]
     pub test_result: TestResult,
+
+    /// The label of the test case, if it has one.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub label: Option<String>,
+}
+
+impl TestCaseResult {
+    /// Creates the result of the `test_case`, carrying over its id and label.
+    pub fn new(test_case: &TestCase, test_result: TestResult) -> Self {
+        Self {
+            id: test_case.id,
+            test_result,
+            label: test_case.label.clone(),
+        }
+    }
 }
 
 /// The different outcomes of a test case.
-#[derive(Deserialize, Serialize, PartialEq, Debug)]
+#[derive(Deserialize, Serialize, PartialEq, Clone, Debug)]
 #[serde(rename_all = "camelCase", tag = "testResult")]
 pub enum TestResult {
     /// The test case passed.
@@ -90,10 +417,13 @@ pub enum TestResult {
 
     /// The test case did not pass.
     Failure(TestCaseFailureReason),
+
+    /// Whether the test case passed could not be determined, e.g. because the execution ended before it was run.
+    Unknown,
 }
 
 /// The reason why a given test case failed.
-#[derive(Deserialize, Serialize, PartialEq, Debug)]
+#[derive(Deserialize, Serialize, PartialEq, Clone, Debug)]
 #[serde(rename_all = "camelCase", tag = "cause", content = "details")]
 pub enum TestCaseFailureReason {
     /// The answer to the test case was incorrect.
@@ -103,12 +433,111 @@ pub enum TestCaseFailureReason {
         input_parameters: Box<[Parameter]>,
 
         /// The value(s) produced by the submitted solution.
+        ///
+        /// A value longer than the configured maximum length is truncated, and ends with `...`.
         actual: String,
 
         /// The value(s) the submitted solution should have produced.
+        ///
+        /// A value longer than the configured maximum length is truncated, and ends with `...`.
         expected: String,
+
+        /// The value(s) produced by the submitted solution as typed JSON, which is only present if the expected value
+        /// is structured, i.e. multiple output parameters or a map, so that the frontend can diff their elements.
+        ///
+        /// Multiple output parameters are an array of their values, a map is an object keyed by the text of its keys,
+        /// e.g. `"1"` for the integer 1, the unit and a float that is not finite are `null`,
+        /// and any other value is a boolean, number or string.
+        /// It is absent if its JSON is longer than the configured maximum length, as it cannot be truncated.
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        actual_value: Option<serde_json::Value>,
+
+        /// The value(s) the submitted solution should have produced as typed JSON, in the same form as `actual_value`.
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        expected_value: Option<serde_json::Value>,
     },
 
     /// A runtime error occured during the test case.
+    ///
+    /// The provided `String` describes the error, which for Python includes its type and the traceback of the solution.
     RuntimeError(String),
+
+    /// The test case was still running when the execution exceeded the timeout.
+    Timeout,
+
+    /// The test case expected a runtime error, but the solution returned a value.
+    #[serde(rename_all = "camelCase")]
+    MissingError {
+        /// The input parameters of the test case, this is provided as error feedback for the frontend.
+        input_parameters: Box<[Parameter]>,
+
+        /// The value(s) produced by the submitted solution.
+        ///
+        /// A value longer than the configured maximum length is truncated, and ends with `...`.
+        actual: String,
+    },
+
+    /// The test case is hidden and did not pass, so why it failed is not disclosed.
+    Hidden,
+}
+
+/// A single diagnostic extracted from the output of a failed compilation.
+///
+/// It exists so that the frontend can highlight the offending location without parsing the compiler output itself.
+#[derive(Deserialize, Serialize, PartialEq, Clone, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct CompilationDiagnostic {
+    /// The line of the solution the diagnostic refers to, starting from 1.
+    pub line: u32,
+
+    /// The column of the solution the diagnostic refers to, starting from 1.
+    pub column: u32,
+
+    /// The message of the diagnostic.
+    pub message: String,
+}
+
+/// The capabilities of mozart, which are determined at compile time by its feature flags and constants.
+///
+/// It exists so that clients can discover what mozart supports, instead of assuming it.
+#[derive(Deserialize, Serialize, PartialEq, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct Capabilities {
+    /// The languages that solutions can be written in.
+    pub languages: Box<[String]>,
+
+    /// The parameter types that test cases can use, which can also be combined into a [`ParameterType::Map`] or a
+    /// [`ParameterType::List`].
+    pub parameter_types: Box<[ParameterType]>,
+
+    /// The timeout of the compilation and the execution of a solution, in milliseconds.
+    pub timeout_ms: u64,
+
+    /// The timeout of a single test case, in milliseconds.
+    pub test_case_timeout_ms: u64,
+}
+
+/// The outcome of checking a trivial submission end-to-end, which verifies that a deployment can check submissions.
+#[derive(Deserialize, Serialize, PartialEq, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct SelfTest {
+    /// Whether the trivial submission passed.
+    pub passed: bool,
+
+    /// The wall-clock time in milliseconds it took to check the trivial submission, including compilation.
+    pub elapsed_ms: u64,
+}
+
+/// The current load of mozart, which exists so that e.g. autoscaling decisions can be based on it.
+#[derive(Deserialize, Serialize, PartialEq, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct Health {
+    /// The number of submissions that are being checked right now.
+    pub active_submissions: usize,
+
+    /// The maximum number of submissions that are checked at the same time, where the others wait for their turn.
+    pub concurrency_limit: usize,
+
+    /// The number of test cases that were skipped by quick submissions failing fast, since mozart was started.
+    pub skipped_test_cases: u64,
 }
@@ -0,0 +1,649 @@
+//! Contains the OpenAPI description of the HTTP interface of mozart, served by the `/openapi.json` endpoint.
+//!
+//! The description is written by hand, as the serialization of a [`SubmissionResult`](crate::response::SubmissionResult)
+//! is hand-written as well. It must therefore be kept consistent with the models and responses whenever they change.
+
+use crate::{
+    model::ParameterType,
+    response::{RESPONSE_VERSION, RESULTS},
+    IDEMPOTENCY_KEY_HEADER, SIGNATURE_HEADER, TEST_CASES_HEADER, TOTAL_TIME_HEADER,
+};
+use serde_json::{json, Value};
+
+/// The version of the OpenAPI specification the description conforms to.
+const OPENAPI_VERSION: &str = "3.0.3";
+
+/// The codes of a [`SubmissionResult::Error`](crate::response::SubmissionResult::Error), as they are serialized.
+const ERROR_CODES: &[&str] = &[
+    "invalidRequest",
+    "compilation",
+    "compileTimeout",
+    "executeTimeout",
+    "execution",
+    "emptySolution",
+    "solutionTooLong",
+    "emptyChecker",
+    "invalidSolution",
+    "invalidParameter",
+    "duplicateTestCaseId",
+    "unknownTestCaseId",
+    "disallowedCompilerFlag",
+    "unknownToolchainVersion",
+    "invalidFloatTolerance",
+    "invalidFileName",
+    "outputLimitExceeded",
+];
+
+/// Gets the OpenAPI description of the endpoints of mozart, and of the shapes of their requests and responses.
+pub fn openapi() -> Value {
+    json!({
+        "openapi": OPENAPI_VERSION,
+        "info": {
+            "title": "mozart",
+            "description": "Checks solutions to programming exercises against a set of test cases.",
+            "version": env!("CARGO_PKG_VERSION"),
+        },
+        "paths": paths(),
+        "components": {
+            "schemas": schemas(),
+            "securitySchemes": {
+                "apiKey": {
+                    "type": "http",
+                    "scheme": "bearer",
+                    "description": "Only required if an API key is configured.",
+                },
+            },
+        },
+    })
+}
+
+/// Gets the description of every endpoint.
+fn paths() -> Value {
+    let submission_result = json!({
+        "application/json": { "schema": { "$ref": "#/components/schemas/SubmissionResult" } },
+    });
+    let internal_error = json!({ "description": "An internal error occurred, with no body." });
+
+    json!({
+        "/submit": {
+            "post": {
+                "summary": "Checks a submission against its test cases.",
+                "security": [{ "apiKey": [] }],
+                "parameters": [{
+                    "name": "dryRun",
+                    "in": "query",
+                    "required": false,
+                    "description": "Whether to check the submission without compiling or executing it.",
+                    "schema": { "type": "boolean", "default": false },
+                }, {
+                    "name": "order",
+                    "in": "query",
+                    "required": false,
+                    "description": "The order of the test case results.",
+                    "schema": {
+                        "type": "string",
+                        "enum": ["input", "failuresFirst", "reason"],
+                        "default": "input",
+                    },
+                }, {
+                    "name": IDEMPOTENCY_KEY_HEADER,
+                    "in": "header",
+                    "required": false,
+                    "description": "A key identifying the submission, such that a retry with the same key is not checked again.",
+                    "schema": { "type": "string" },
+                }],
+                "requestBody": {
+                    "required": true,
+                    "content": {
+                        "application/json": { "schema": { "$ref": "#/components/schemas/Submission" } },
+                    },
+                },
+                "responses": {
+                    "200": {
+                        "description": "The submission was checked.",
+                        "headers": {
+                            TOTAL_TIME_HEADER: {
+                                "description": "The wall-clock time it took to check the submission, in milliseconds.",
+                                "schema": { "type": "integer", "format": "int64" },
+                            },
+                            SIGNATURE_HEADER: {
+                                "description": "The lowercase hexadecimal HMAC-SHA256 of the body with the signing key, if one is configured.",
+                                "schema": { "type": "string" },
+                            },
+                        },
+                        "content": submission_result,
+                    },
+                    "4XX": {
+                        "description": "The request body could not be parsed, responded to as an `invalidRequest` error.",
+                        "content": submission_result,
+                    },
+                    "401": { "description": "The API key is missing or invalid." },
+                    "500": internal_error,
+                    "504": { "description": "The request was not responded to within the request timeout." },
+                },
+            },
+        },
+        "/submit/batch": {
+            "post": {
+                "summary": "Checks multiple independent submissions, responding with their results in the same order.",
+                "security": [{ "apiKey": [] }],
+                "requestBody": {
+                    "required": true,
+                    "content": {
+                        "application/json": {
+                            "schema": { "type": "array", "items": { "$ref": "#/components/schemas/Submission" } },
+                        },
+                    },
+                },
+                "responses": {
+                    "200": {
+                        "description": "Every submission was checked.",
+                        "content": {
+                            "application/json": {
+                                "schema": {
+                                    "type": "array",
+                                    "items": { "$ref": "#/components/schemas/SubmissionResult" },
+                                },
+                            },
+                        },
+                    },
+                    "4XX": {
+                        "description": "The request body could not be parsed, responded to as an `invalidRequest` error.",
+                        "content": submission_result,
+                    },
+                    "401": { "description": "The API key is missing or invalid." },
+                    "500": internal_error,
+                    "504": { "description": "The request was not responded to within the request timeout." },
+                },
+            },
+        },
+        "/submit/quick": {
+            "post": {
+                "summary": "Checks a submission, responding only with whether it passed every test case.",
+                "security": [{ "apiKey": [] }],
+                "requestBody": {
+                    "required": true,
+                    "content": {
+                        "application/json": { "schema": { "$ref": "#/components/schemas/Submission" } },
+                    },
+                },
+                "responses": {
+                    "200": {
+                        "description": "The submission was checked, stopping at the first test case that did not pass unless configured otherwise.",
+                        "content": {
+                            "application/json": { "schema": { "$ref": "#/components/schemas/QuickResult" } },
+                        },
+                    },
+                    "4XX": {
+                        "description": "The request body could not be parsed, responded to as an `invalidRequest` error.",
+                        "content": submission_result,
+                    },
+                    "401": { "description": "The API key is missing or invalid." },
+                    "500": internal_error,
+                    "504": { "description": "The request was not responded to within the request timeout." },
+                },
+            },
+        },
+        "/submit/raw": {
+            "post": {
+                "summary": "Checks a solution supplied as the raw request body, e.g. for manual testing with curl.",
+                "security": [{ "apiKey": [] }],
+                "parameters": [{
+                    "name": TEST_CASES_HEADER,
+                    "in": "header",
+                    "required": false,
+                    "description": "The test cases as a JSON array, without which the solution is only compiled and run.",
+                    "schema": { "type": "string" },
+                }, {
+                    "name": "dryRun",
+                    "in": "query",
+                    "required": false,
+                    "description": "Whether to check the submission without compiling or executing it.",
+                    "schema": { "type": "boolean", "default": false },
+                }, {
+                    "name": "order",
+                    "in": "query",
+                    "required": false,
+                    "description": "The order of the test case results.",
+                    "schema": {
+                        "type": "string",
+                        "enum": ["input", "failuresFirst", "reason"],
+                        "default": "input",
+                    },
+                }],
+                "requestBody": {
+                    "required": true,
+                    "content": {
+                        "text/plain": { "schema": { "type": "string" } },
+                    },
+                },
+                "responses": {
+                    "200": {
+                        "description": "The solution was checked.",
+                        "headers": {
+                            TOTAL_TIME_HEADER: {
+                                "description": "The wall-clock time it took to check the submission, in milliseconds.",
+                                "schema": { "type": "integer", "format": "int64" },
+                            },
+                            SIGNATURE_HEADER: {
+                                "description": "The lowercase hexadecimal HMAC-SHA256 of the body with the signing key, if one is configured.",
+                                "schema": { "type": "string" },
+                            },
+                        },
+                        "content": submission_result,
+                    },
+                    "4XX": {
+                        "description": "The request body or the test cases could not be parsed, responded to as an `invalidRequest` error.",
+                        "content": submission_result,
+                    },
+                    "401": { "description": "The API key is missing or invalid." },
+                    "500": internal_error,
+                    "504": { "description": "The request was not responded to within the request timeout." },
+                },
+            },
+        },
+        "/workdir/{uuid}": {
+            "delete": {
+                "summary": "Removes a working directory that was left behind.",
+                "security": [{ "apiKey": [] }],
+                "parameters": [{
+                    "name": "uuid",
+                    "in": "path",
+                    "required": true,
+                    "schema": { "type": "string", "format": "uuid" },
+                }],
+                "responses": {
+                    "204": { "description": "The working directory was removed." },
+                    "400": { "description": "The uuid is not well-formed." },
+                    "401": { "description": "The API key is missing or invalid." },
+                    "404": { "description": "The working directory does not exist." },
+                    "500": { "description": "The working directory could not be removed." },
+                },
+            },
+        },
+        "/capabilities": {
+            "get": {
+                "summary": "Gets the language, parameter types and timeouts of mozart.",
+                "security": [{ "apiKey": [] }],
+                "responses": {
+                    "200": {
+                        "description": "The capabilities of mozart.",
+                        "content": {
+                            "application/json": { "schema": { "$ref": "#/components/schemas/Capabilities" } },
+                        },
+                    },
+                    "401": { "description": "The API key is missing or invalid." },
+                },
+            },
+        },
+        "/status": {
+            "get": {
+                "summary": "Checks whether mozart is alive.",
+                "responses": { "200": { "description": "mozart is alive, with no body." } },
+            },
+        },
+        "/ready": {
+            "get": {
+                "summary": "Checks whether the toolchain of the enabled language can be run.",
+                "responses": {
+                    "200": { "description": "mozart is ready to check submissions, with no body." },
+                    "503": {
+                        "description": "The toolchain cannot be run, e.g. because it is not installed.",
+                        "content": { "text/plain": { "schema": { "type": "string" } } },
+                    },
+                },
+            },
+        },
+        "/health": {
+            "get": {
+                "summary": "Gets the current load of mozart.",
+                "responses": {
+                    "200": {
+                        "description": "The current load of mozart.",
+                        "content": {
+                            "application/json": { "schema": { "$ref": "#/components/schemas/Health" } },
+                        },
+                    },
+                },
+            },
+        },
+        "/selftest": {
+            "get": {
+                "summary": "Checks a trivial submission end-to-end, to verify that the deployment can check submissions.",
+                "security": [{ "apiKey": [] }],
+                "responses": {
+                    "200": {
+                        "description": "The trivial submission passed.",
+                        "content": {
+                            "application/json": { "schema": { "$ref": "#/components/schemas/SelfTest" } },
+                        },
+                    },
+                    "401": { "description": "The API key is missing or invalid." },
+                    "503": {
+                        "description": "The trivial submission did not pass.",
+                        "content": {
+                            "application/json": { "schema": { "$ref": "#/components/schemas/SelfTest" } },
+                        },
+                    },
+                },
+            },
+        },
+        "/openapi.json": {
+            "get": {
+                "summary": "Gets this description.",
+                "security": [{ "apiKey": [] }],
+                "responses": {
+                    "200": { "description": "The OpenAPI description of mozart." },
+                    "401": { "description": "The API key is missing or invalid." },
+                },
+            },
+        },
+    })
+}
+
+/// Gets the schemas of the requests and responses.
+fn schemas() -> Value {
+    json!({
+        "Submission": {
+            "type": "object",
+            "required": ["solution", "testCases"],
+            "properties": {
+                "solution": { "type": "string" },
+                "testCases": { "type": "array", "items": { "$ref": "#/components/schemas/TestCase" } },
+                "unorderedLists": { "type": "boolean", "default": false },
+                "files": { "type": "array", "items": { "$ref": "#/components/schemas/SourceFile" } },
+                "checker": { "type": "string", "nullable": true },
+                "referenceSolution": { "type": "string", "nullable": true },
+                "onlyIds": {
+                    "type": "array",
+                    "items": { "type": "integer", "format": "int64", "minimum": 0 },
+                    "nullable": true,
+                },
+                "compilerFlags": { "type": "array", "items": { "type": "string" } },
+                "debug": { "type": "boolean" },
+                "toolchainVersion": { "type": "string", "nullable": true },
+                "metadata": {
+                    "description": "An opaque object that is echoed verbatim in the result.",
+                    "type": "object",
+                    "nullable": true,
+                },
+                "floatTolerance": {
+                    "type": "object",
+                    "nullable": true,
+                    "properties": {
+                        "absolute": { "type": "number", "minimum": 0, "default": 0 },
+                        "relative": { "type": "number", "minimum": 0, "default": 0 },
+                        "matchNan": { "type": "boolean", "default": false },
+                    },
+                },
+            },
+        },
+        "SourceFile": {
+            "type": "object",
+            "required": ["name", "contents"],
+            "properties": {
+                "name": { "type": "string" },
+                "contents": { "type": "string" },
+            },
+        },
+        "TestCase": {
+            "type": "object",
+            "required": ["id", "inputParameters", "outputParameters"],
+            "properties": {
+                "id": { "type": "integer", "format": "int64", "minimum": 0 },
+                "inputParameters": { "type": "array", "items": { "$ref": "#/components/schemas/Parameter" } },
+                "outputParameters": { "type": "array", "items": { "$ref": "#/components/schemas/Parameter" } },
+                "stdin": { "type": "string", "nullable": true },
+                "expectedError": { "$ref": "#/components/schemas/ExpectedError" },
+                "label": { "type": "string", "nullable": true },
+                "hidden": { "type": "boolean", "default": false },
+            },
+        },
+        "ExpectedError": {
+            "type": "object",
+            "properties": {
+                "message": { "type": "string", "nullable": true },
+            },
+        },
+        "Parameter": {
+            "type": "object",
+            "required": ["valueType", "value"],
+            "properties": {
+                "valueType": { "$ref": "#/components/schemas/ParameterType" },
+                "value": { "type": "string" },
+            },
+        },
+        "ParameterType": {
+            "oneOf": [
+                { "type": "string", "enum": ParameterType::ALL },
+                {
+                    "type": "object",
+                    "required": ["map"],
+                    "properties": {
+                        "map": {
+                            "type": "object",
+                            "required": ["key", "value"],
+                            "properties": {
+                                "key": { "$ref": "#/components/schemas/ParameterType" },
+                                "value": { "$ref": "#/components/schemas/ParameterType" },
+                            },
+                        },
+                    },
+                },
+                {
+                    "type": "object",
+                    "required": ["list"],
+                    "properties": {
+                        "list": { "$ref": "#/components/schemas/ParameterType" },
+                    },
+                },
+                {
+                    "type": "object",
+                    "required": ["optional"],
+                    "properties": {
+                        "optional": { "$ref": "#/components/schemas/ParameterType" },
+                    },
+                },
+            ],
+        },
+        "SubmissionResult": {
+            "type": "object",
+            "required": ["version", "result"],
+            "properties": {
+                "version": { "type": "integer", "enum": [RESPONSE_VERSION] },
+                "result": { "type": "string", "enum": RESULTS },
+                "testCaseResults": {
+                    "description": "Only present when `result` is `failure` or `inconclusive`.",
+                    "type": "array",
+                    "items": { "$ref": "#/components/schemas/TestCaseResult" },
+                },
+                "code": {
+                    "description": "Only present when `result` is `error`.",
+                    "$ref": "#/components/schemas/ErrorCode",
+                },
+                "message": {
+                    "description": "Only present when `result` is `error`.",
+                    "type": "string",
+                },
+                "diagnostics": {
+                    "description": "Only present when `result` is `error` and the locations of a compilation error were determined.",
+                    "type": "array",
+                    "items": { "$ref": "#/components/schemas/CompilationDiagnostic" },
+                },
+                "stdout": {
+                    "description": "Only present when the solution wrote to stdout, truncated to the configured maximum length.",
+                    "type": "string",
+                },
+                "metadata": {
+                    "description": "Only present when the submission supplied metadata, which is echoed verbatim.",
+                },
+                "generatedSource": {
+                    "description": "Only present when the submission requested debugging and the server allows it.",
+                    "type": "string",
+                    "nullable": true,
+                },
+            },
+        },
+        "ErrorCode": { "type": "string", "enum": ERROR_CODES },
+        "CompilationDiagnostic": {
+            "type": "object",
+            "required": ["line", "column", "message"],
+            "properties": {
+                "line": { "type": "integer", "minimum": 1 },
+                "column": { "type": "integer", "minimum": 1 },
+                "message": { "type": "string" },
+            },
+        },
+        "TestCaseResult": {
+            "type": "object",
+            "required": ["id", "testResult"],
+            "properties": {
+                "id": { "type": "integer", "format": "int64", "minimum": 0 },
+                "testResult": { "type": "string", "enum": ["pass", "failure", "unknown"] },
+                "cause": {
+                    "description": "Only present when `testResult` is `failure`.",
+                    "type": "string",
+                    "enum": ["wrongAnswer", "runtimeError", "timeout", "missingError", "hidden"],
+                },
+                "details": {
+                    "description": "Only present when `cause` is `wrongAnswer`, `missingError` or `runtimeError`, where it is a string.",
+                    "oneOf": [
+                        { "$ref": "#/components/schemas/WrongAnswer" },
+                        { "$ref": "#/components/schemas/MissingError" },
+                        { "type": "string" },
+                    ],
+                },
+                "label": {
+                    "description": "Only present when the test case has a label.",
+                    "type": "string",
+                },
+            },
+        },
+        "WrongAnswer": {
+            "type": "object",
+            "required": ["inputParameters", "actual", "expected"],
+            "properties": {
+                "inputParameters": { "type": "array", "items": { "$ref": "#/components/schemas/Parameter" } },
+                "actual": { "type": "string" },
+                "expected": { "type": "string" },
+                "actualValue": {},
+                "expectedValue": {},
+            },
+        },
+        "MissingError": {
+            "type": "object",
+            "required": ["inputParameters", "actual"],
+            "properties": {
+                "inputParameters": { "type": "array", "items": { "$ref": "#/components/schemas/Parameter" } },
+                "actual": { "type": "string" },
+            },
+        },
+        "Capabilities": {
+            "type": "object",
+            "required": ["languages", "parameterTypes", "timeoutMs", "testCaseTimeoutMs"],
+            "properties": {
+                "languages": { "type": "array", "items": { "type": "string" } },
+                "parameterTypes": { "type": "array", "items": { "$ref": "#/components/schemas/ParameterType" } },
+                "timeoutMs": { "type": "integer", "format": "int64" },
+                "testCaseTimeoutMs": { "type": "integer", "format": "int64" },
+            },
+        },
+        "Health": {
+            "type": "object",
+            "required": ["activeSubmissions", "concurrencyLimit", "skippedTestCases"],
+            "properties": {
+                "activeSubmissions": { "type": "integer", "minimum": 0 },
+                "concurrencyLimit": { "type": "integer", "minimum": 1 },
+                "skippedTestCases": { "type": "integer", "format": "int64", "minimum": 0 },
+            },
+        },
+        "SelfTest": {
+            "type": "object",
+            "required": ["passed", "elapsedMs"],
+            "properties": {
+                "passed": { "type": "boolean" },
+                "elapsedMs": { "type": "integer", "format": "int64", "minimum": 0 },
+            },
+        },
+        "QuickResult": {
+            "type": "object",
+            "required": ["passed"],
+            "properties": {
+                "passed": { "type": "boolean" },
+            },
+        },
+    })
+}
+
+#[cfg(test)]
+mod schemas {
+    use super::{openapi, ERROR_CODES};
+    use crate::{
+        model::{Parameter, ParameterType, TestCaseFailureReason, TestCaseResult, TestResult},
+        response::{ErrorCode, SubmissionResult},
+    };
+
+    #[test]
+    fn error_codes_deserialize() {
+        for code in ERROR_CODES {
+            let actual = serde_json::from_value::<ErrorCode>(serde_json::json!(code));
+
+            assert!(actual.is_ok(), "'{code}' is not an error code");
+        }
+    }
+
+    #[test]
+    fn submission_result_fields() {
+        let input = SubmissionResult::Failure(Box::new([TestCaseResult {
+            id: 0,
+            test_result: TestResult::Failure(TestCaseFailureReason::WrongAnswer {
+                input_parameters: Box::new([]),
+                actual: String::from("1"),
+                expected: String::from("2"),
+                actual_value: None,
+                expected_value: None,
+            }),
+            label: None,
+        }]));
+        let schema = openapi();
+        let properties = &schema["components"]["schemas"]["SubmissionResult"]["properties"];
+
+        let actual = serde_json::to_value(input).expect("failed to serialize submission result");
+
+        for field in actual.as_object().expect("should be an object").keys() {
+            assert!(
+                properties.get(field).is_some(),
+                "'{field}' is not described"
+            );
+        }
+    }
+
+    #[test]
+    fn test_case_result_fields() {
+        let input = TestCaseResult {
+            id: 0,
+            test_result: TestResult::Failure(TestCaseFailureReason::WrongAnswer {
+                input_parameters: Box::new([Parameter {
+                    value_type: ParameterType::Int,
+                    value: String::from("1"),
+                }]),
+                actual: String::from("1"),
+                expected: String::from("2"),
+                actual_value: None,
+                expected_value: None,
+            }),
+            label: None,
+        };
+        let schema = openapi();
+        let properties = &schema["components"]["schemas"]["TestCaseResult"]["properties"];
+
+        let actual = serde_json::to_value(input).expect("failed to serialize test case result");
+
+        for field in actual.as_object().expect("should be an object").keys() {
+            assert!(
+                properties.get(field).is_some(),
+                "'{field}' is not described"
+            );
+        }
+    }
+}
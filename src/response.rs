@@ -1,22 +1,48 @@
 //! Contains objects in relation to how responses are produced based on how the submission check went.
 
-use crate::{error::SubmissionError, model::TestCaseResult};
+use crate::{
+    error::SubmissionError,
+    model::{CompilationDiagnostic, TestCaseFailureReason, TestCaseResult, TestResult},
+};
 use axum::{
     http::StatusCode,
     response::{IntoResponse, Response},
     Json,
 };
 use serde::{
-    de::{Error, MapAccess, Visitor},
+    de::{Error, IgnoredAny, MapAccess, Visitor},
     ser::SerializeStruct,
     Deserialize, Deserializer, Serialize,
 };
+use serde_json::Value;
 use std::fmt::Formatter;
 
+/// The version of the serialized shape of a [`SubmissionResult`].
+///
+/// It must be incremented whenever the shape changes in a way that is not backwards compatible.
+pub const RESPONSE_VERSION: u32 = 1;
+
+/// The values of the `result` field of a serialized [`SubmissionResult`].
+pub(crate) const RESULTS: &[&str] = &["pass", "failure", "inconclusive", "error", "internalError"];
+
 /// A submission result indicates the result of checking a given submission.
 ///
 /// This is an outward facing object, as it is serialized to JSON in the HTTP response for a given request.
-#[derive(Debug, PartialEq)]
+///
+/// The serialized shape is a JSON object with the following fields:
+/// - `version`: always present, and equal to [`RESPONSE_VERSION`]
+/// - `result`: always present, and one of `pass`, `failure`, `inconclusive`, `error` or `internalError`
+/// - `testCaseResults`: only present when `result` is `failure` or `inconclusive`
+/// - `code`: only present when `result` is `error`, and one of the [`ErrorCode`] values
+/// - `message`: only present when `result` is `error`
+/// - `diagnostics`: only present when `result` is `error` and the error is a [`SubmissionResult::CompilationError`]
+/// - `stdout`: only present when the solution wrote to stdout, see [`AnnotatedSubmissionResult`]
+/// - `metadata`: only present when the submission supplied it, see [`AnnotatedSubmissionResult`]
+/// - `generatedSource`: only present when the submission requested it, see [`DebugSubmissionResult`]
+///
+/// Deserializing a serialized submission result always produces the original value,
+/// and fields it does not know are skipped, such that adding a field does not break existing clients.
+#[derive(Clone, Debug, PartialEq)]
 pub enum SubmissionResult {
     /// A submission successfully passed all test cases.
     Pass,
@@ -28,25 +54,161 @@ pub enum SubmissionResult {
     /// correctly identify which test cases failed, and why they failed.
     Failure(Box<[TestCaseResult]>),
 
+    /// A submission did not fail any test case, but some test cases have an unknown result.
+    ///
+    /// It lets the frontend show the check as inconclusive rather than failed, as the solution may well be correct.
+    /// The `Box<[TestCaseResult]>` contains the results of every test case, like that of [`SubmissionResult::Failure`].
+    Inconclusive(Box<[TestCaseResult]>),
+
     /// An error occured at some point during the check of the submission.
     ///
     /// This error is user facing, in that it represents errors that the user
     /// is responsible for, such at compilation errors, timeouts and the like.
+    Error {
+        /// The kind of the underlying [`SubmissionError`], which clients can branch on.
+        code: ErrorCode,
+
+        /// The underlying [`SubmissionError`] in string format.
+        message: String,
+    },
+
+    /// A compilation error for which the location of the errors could be determined.
     ///
-    /// The `String` is the underlying [`SubmissionError`] in string format.
-    Error(String),
+    /// It is serialized like [`SubmissionResult::Error`] with the [`ErrorCode::Compilation`] code,
+    /// but with an additional `diagnostics` field.
+    CompilationError {
+        /// The underlying [`SubmissionError`] in string format.
+        message: String,
+
+        /// The diagnostics extracted from the compilation error.
+        diagnostics: Box<[CompilationDiagnostic]>,
+    },
 
     /// An internal error represents something that the user is not at fault for,
     /// for example, not being able to spawn a compilation process, or creating a file.
+    ///
+    /// It is responded to with a `500` status code and no body, but can still be serialized.
     InternalError,
 }
 
+/// The order of the test case results of a [`SubmissionResult`], which clients request with the `order` query parameter.
+///
+/// It is purely presentational, as the results are computed in the order of the test cases regardless.
+#[derive(Deserialize, Serialize, PartialEq, Clone, Copy, Debug, Default)]
+#[serde(rename_all = "camelCase")]
+pub enum ResultOrder {
+    /// The order of the test cases of the submission.
+    #[default]
+    Input,
+
+    /// The failed test cases first, followed by those with an unknown result and then the passed ones.
+    FailuresFirst,
+
+    /// Grouped by the reason of the failure, i.e. wrong answers and missing errors, runtime errors, timeouts and failed
+    /// hidden test cases, followed by the test cases with an unknown result and then the passed ones.
+    Reason,
+}
+
+impl ResultOrder {
+    /// Gets the rank of the `test_result` in this order, where a lower rank comes first.
+    fn rank(self, test_result: &TestResult) -> u8 {
+        match (self, test_result) {
+            (ResultOrder::Input, _) => 0,
+            (ResultOrder::FailuresFirst, TestResult::Failure(_)) => 0,
+            (
+                ResultOrder::Reason,
+                TestResult::Failure(
+                    TestCaseFailureReason::WrongAnswer { .. }
+                    | TestCaseFailureReason::MissingError { .. },
+                ),
+            ) => 0,
+            (ResultOrder::Reason, TestResult::Failure(TestCaseFailureReason::RuntimeError(_))) => 1,
+            (ResultOrder::Reason, TestResult::Failure(TestCaseFailureReason::Timeout)) => 2,
+            (ResultOrder::Reason, TestResult::Failure(TestCaseFailureReason::Hidden)) => 3,
+            (_, TestResult::Unknown) => 4,
+            (_, TestResult::Pass) => 5,
+        }
+    }
+}
+
+/// A stable, machine-readable code identifying the kind of a [`SubmissionResult::Error`].
+///
+/// Unlike the message of the error, the codes never change, so clients can branch on them.
+#[derive(Deserialize, Serialize, PartialEq, Clone, Copy, Debug)]
+#[serde(rename_all = "camelCase")]
+pub enum ErrorCode {
+    /// The request body could not be parsed as a submission.
+    InvalidRequest,
+
+    /// The solution did not compile.
+    Compilation,
+
+    /// The compilation exceeded the timeout.
+    CompileTimeout,
+
+    /// The execution exceeded the timeout before any test case was started.
+    ExecuteTimeout,
+
+    /// The execution stopped due to an error before any test case finished.
+    Execution,
+
+    /// The solution is empty.
+    EmptySolution,
+
+    /// The solution is longer than the maximum length configured by the server.
+    SolutionTooLong,
+
+    /// The checker is empty.
+    EmptyChecker,
+
+    /// The solution does not meet a requirement of the language.
+    InvalidSolution,
+
+    /// A parameter has a value that does not match its type.
+    InvalidParameter,
+
+    /// Multiple test cases share the same id.
+    DuplicateTestCaseId,
+
+    /// A test case that should be run does not exist.
+    UnknownTestCaseId,
+
+    /// A compiler flag is not in the allowlist configured by the server.
+    DisallowedCompilerFlag,
+
+    /// The toolchain version is not in the allowlist configured by the server.
+    UnknownToolchainVersion,
+
+    /// The float tolerance is not valid.
+    InvalidFloatTolerance,
+
+    /// An auxiliary file has a name that is not allowed.
+    InvalidFileName,
+
+    /// The execution wrote more output than is captured.
+    OutputLimitExceeded,
+}
+
+impl SubmissionResult {
+    /// Reorders the test case results by the `order`, where results of the same rank keep the order of the test cases.
+    ///
+    /// A result without test case results is left as is.
+    pub fn reorder(&mut self, order: ResultOrder) {
+        if let SubmissionResult::Failure(test_cases) | SubmissionResult::Inconclusive(test_cases) =
+            self
+        {
+            test_cases.sort_by_key(|test_case| order.rank(&test_case.test_result));
+        }
+    }
+}
+
 impl Serialize for SubmissionResult {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
     where
         S: serde::Serializer,
     {
-        let mut json = serializer.serialize_struct("SubmissionResult", 2)?;
+        let mut json = serializer.serialize_struct("SubmissionResult", 5)?;
+        json.serialize_field("version", &RESPONSE_VERSION)?;
         match self {
             SubmissionResult::Pass => {
                 json.serialize_field("result", "pass")?;
@@ -55,12 +217,26 @@ impl Serialize for SubmissionResult {
                 json.serialize_field("result", "failure")?;
                 json.serialize_field("testCaseResults", test_cases)?;
             }
-            SubmissionResult::Error(error) => {
+            SubmissionResult::Inconclusive(test_cases) => {
+                json.serialize_field("result", "inconclusive")?;
+                json.serialize_field("testCaseResults", test_cases)?;
+            }
+            SubmissionResult::Error { code, message } => {
                 json.serialize_field("result", "error")?;
-                json.serialize_field("message", error)?;
+                json.serialize_field("code", code)?;
+                json.serialize_field("message", message)?;
+            }
+            SubmissionResult::CompilationError {
+                message,
+                diagnostics,
+            } => {
+                json.serialize_field("result", "error")?;
+                json.serialize_field("code", &ErrorCode::Compilation)?;
+                json.serialize_field("message", message)?;
+                json.serialize_field("diagnostics", diagnostics)?;
             }
             SubmissionResult::InternalError => {
-                unreachable!("cannot happen because internal server error is not parsed to json")
+                json.serialize_field("result", "internalError")?;
             }
         }
         json.end()
@@ -77,12 +253,127 @@ impl IntoResponse for SubmissionResult {
     }
 }
 
+/// A submission result along with what the solution wrote to stdout, e.g. its debug prints, and the metadata of the
+/// submission, which is responded to a submission that does not request debugging.
+///
+/// It is serialized like its [`SubmissionResult`], with an additional `stdout` field if the solution wrote to stdout,
+/// and an additional `metadata` field if the submission supplied it.
+#[derive(Serialize, Debug)]
+pub struct AnnotatedSubmissionResult {
+    /// The result of checking the submission.
+    #[serde(flatten)]
+    pub result: SubmissionResult,
+
+    /// What the solution wrote to stdout, truncated to the configured maximum length, if it wrote anything and no test
+    /// case is hidden.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub stdout: Option<String>,
+
+    /// The metadata of the submission, echoed verbatim.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub metadata: Option<Value>,
+}
+
+impl IntoResponse for AnnotatedSubmissionResult {
+    fn into_response(self) -> Response {
+        if let SubmissionResult::InternalError = self.result {
+            StatusCode::INTERNAL_SERVER_ERROR.into_response()
+        } else {
+            (StatusCode::OK, Json(self)).into_response()
+        }
+    }
+}
+
+/// A submission result along with the generated test file, which is responded to a submission requesting debugging.
+///
+/// It is serialized like its [`SubmissionResult`], with an additional `generatedSource` field containing the test
+/// file, which is `null` if the submission was rejected before the test file was generated,
+/// and the `stdout` and `metadata` fields of an [`AnnotatedSubmissionResult`].
+#[derive(Serialize, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct DebugSubmissionResult {
+    /// The result of checking the submission.
+    #[serde(flatten)]
+    pub result: SubmissionResult,
+
+    /// What the solution wrote to stdout, if it wrote anything.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub stdout: Option<String>,
+
+    /// The metadata of the submission, echoed verbatim.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub metadata: Option<Value>,
+
+    /// The generated test file that was compiled or executed.
+    pub generated_source: Option<String>,
+}
+
+impl IntoResponse for DebugSubmissionResult {
+    fn into_response(self) -> Response {
+        if let SubmissionResult::InternalError = self.result {
+            StatusCode::INTERNAL_SERVER_ERROR.into_response()
+        } else {
+            (StatusCode::OK, Json(self)).into_response()
+        }
+    }
+}
+
+/// The result of a quick submission, which only tells whether the submission passed every test case.
+///
+/// It is responded to by the `/submit/quick` endpoint, e.g. `{"passed":false}`, except for an internal error,
+/// which is responded to with a `500` status code and no body like a [`SubmissionResult::InternalError`].
+#[derive(Deserialize, Serialize, PartialEq, Debug)]
+pub struct QuickResult {
+    /// Whether the submission passed every test case, which is `false` if it failed for any reason.
+    pub passed: bool,
+}
+
 impl From<SubmissionError> for SubmissionResult {
     fn from(err: SubmissionError) -> Self {
         match err {
-            SubmissionError::Internal => SubmissionResult::InternalError,
+            SubmissionError::Internal | SubmissionError::ToolchainNotFound(_) => {
+                SubmissionResult::InternalError
+            }
             SubmissionError::Failure(tcr) => SubmissionResult::Failure(tcr),
-            other => SubmissionResult::Error(other.to_string()),
+            SubmissionError::Inconclusive(tcr) => SubmissionResult::Inconclusive(tcr),
+            SubmissionError::Compilation(_, ref diagnostics) if !diagnostics.is_empty() => {
+                SubmissionResult::CompilationError {
+                    message: err.to_string(),
+                    diagnostics: diagnostics.clone(),
+                }
+            }
+            other => SubmissionResult::Error {
+                code: error_code(&other),
+                message: other.to_string(),
+            },
+        }
+    }
+}
+
+/// Gets the code of a `err` that is responded to as a [`SubmissionResult::Error`].
+fn error_code(err: &SubmissionError) -> ErrorCode {
+    match err {
+        SubmissionError::Compilation(..) => ErrorCode::Compilation,
+        SubmissionError::CompileTimeout(_) => ErrorCode::CompileTimeout,
+        SubmissionError::ExecuteTimeout(_) => ErrorCode::ExecuteTimeout,
+        SubmissionError::Execution(_) => ErrorCode::Execution,
+        SubmissionError::EmptySolution => ErrorCode::EmptySolution,
+        SubmissionError::SolutionTooLong(_) => ErrorCode::SolutionTooLong,
+        SubmissionError::EmptyChecker => ErrorCode::EmptyChecker,
+        SubmissionError::InvalidSolution(_) => ErrorCode::InvalidSolution,
+        SubmissionError::InvalidParameter(_) => ErrorCode::InvalidParameter,
+        SubmissionError::DuplicateTestCaseId(_) => ErrorCode::DuplicateTestCaseId,
+        SubmissionError::UnknownTestCaseId(_) => ErrorCode::UnknownTestCaseId,
+        SubmissionError::DisallowedCompilerFlag(_) => ErrorCode::DisallowedCompilerFlag,
+        SubmissionError::UnknownToolchainVersion(_) => ErrorCode::UnknownToolchainVersion,
+        SubmissionError::InvalidFloatTolerance(_) => ErrorCode::InvalidFloatTolerance,
+        SubmissionError::InvalidFileName(_) => ErrorCode::InvalidFileName,
+        SubmissionError::OutputLimitExceeded(_) => ErrorCode::OutputLimitExceeded,
+        SubmissionError::Internal
+        | SubmissionError::ToolchainNotFound(_)
+        | SubmissionError::Failure(_)
+        | SubmissionError::Inconclusive(_) => {
+            unreachable!("{err:?} is not responded to as an error")
         }
     }
 }
@@ -105,31 +396,62 @@ impl<'de> Deserialize<'de> for SubmissionResult {
             where
                 V: MapAccess<'de>,
             {
-                match map.next_entry::<&str, &str>()? {
-                    Some(("result", "pass")) => Ok(SubmissionResult::Pass),
-                    Some(("result", "failure")) => {
-                        if map
-                            .next_key()
-                            .is_ok_and(|o| o.is_some_and(|k: &str| k == "testCaseResults"))
-                        {
-                            let test_case_results = map.next_value()?;
-                            Ok(SubmissionResult::Failure(test_case_results))
-                        } else {
-                            Err(Error::missing_field("testCaseResults"))
+                let mut result: Option<&str> = None;
+                let mut test_case_results = None;
+                let mut code = None;
+                let mut message = None;
+                let mut diagnostics = None;
+
+                while let Some(key) = map.next_key::<&str>()? {
+                    match key {
+                        "version" => {
+                            let version: u32 = map.next_value()?;
+                            if version != RESPONSE_VERSION {
+                                return Err(Error::custom(format!(
+                                    "unsupported version {version}, expected {RESPONSE_VERSION}"
+                                )));
+                            }
                         }
+                        "result" => result = Some(map.next_value()?),
+                        "testCaseResults" => test_case_results = Some(map.next_value()?),
+                        "code" => code = Some(map.next_value()?),
+                        "message" => message = Some(map.next_value()?),
+                        "diagnostics" => diagnostics = Some(map.next_value()?),
+                        // the stdout of the solution, the metadata of the submission and the generated test file
+                        // are not part of the result itself, and any other field was added by a later version,
+                        // which only increments the version if the result cannot be read without it
+                        _ => {
+                            map.next_value::<IgnoredAny>()?;
+                        }
+                    }
+                }
+
+                match result {
+                    Some("pass") => Ok(SubmissionResult::Pass),
+                    Some("failure") => {
+                        let test_case_results =
+                            test_case_results.ok_or(Error::missing_field("testCaseResults"))?;
+                        Ok(SubmissionResult::Failure(test_case_results))
+                    }
+                    Some("inconclusive") => {
+                        let test_case_results =
+                            test_case_results.ok_or(Error::missing_field("testCaseResults"))?;
+                        Ok(SubmissionResult::Inconclusive(test_case_results))
                     }
-                    Some(("result", "error")) => {
-                        if map
-                            .next_key()
-                            .is_ok_and(|o| o.is_some_and(|k: &str| k == "message"))
-                        {
-                            let message = map.next_value()?;
-                            Ok(SubmissionResult::Error(message))
-                        } else {
-                            Err(Error::missing_field("message"))
+                    Some("error") => {
+                        let code = code.ok_or(Error::missing_field("code"))?;
+                        let message = message.ok_or(Error::missing_field("message"))?;
+                        match diagnostics {
+                            Some(diagnostics) => Ok(SubmissionResult::CompilationError {
+                                message,
+                                diagnostics,
+                            }),
+                            None => Ok(SubmissionResult::Error { code, message }),
                         }
                     }
-                    _ => Err(Error::custom("mission result field or invalid value")),
+                    Some("internalError") => Ok(SubmissionResult::InternalError),
+                    Some(unknown) => Err(Error::unknown_variant(unknown, RESULTS)),
+                    None => Err(Error::missing_field("result")),
                 }
             }
         }
@@ -137,3 +459,370 @@ impl<'de> Deserialize<'de> for SubmissionResult {
         deserializer.deserialize_map(SubmissionResultVisitor)
     }
 }
+
+#[cfg(test)]
+mod round_trip {
+    use super::{AnnotatedSubmissionResult, DebugSubmissionResult, ErrorCode, SubmissionResult};
+    use crate::model::{
+        CompilationDiagnostic, Parameter, ParameterType, TestCaseFailureReason, TestCaseResult,
+        TestResult,
+    };
+    use serde_json::json;
+
+    /// A test util function that serializes and then deserializes the supplied `result`.
+    fn round_trip(result: &SubmissionResult) -> SubmissionResult {
+        let json = serde_json::to_string(result).expect("failed to serialize submission result");
+        serde_json::from_str(&json).expect("failed to deserialize submission result")
+    }
+
+    #[test]
+    fn pass() {
+        let input = SubmissionResult::Pass;
+
+        let actual = round_trip(&input);
+
+        assert_eq!(actual, input);
+    }
+
+    #[test]
+    fn failure() {
+        let input = SubmissionResult::Failure(Box::new([
+            TestCaseResult {
+                id: 0,
+                test_result: TestResult::Pass,
+                label: None,
+            },
+            TestCaseResult {
+                id: 1,
+                test_result: TestResult::Failure(TestCaseFailureReason::WrongAnswer {
+                    input_parameters: Box::new([Parameter {
+                        value_type: ParameterType::Int,
+                        value: String::from("5"),
+                    }]),
+                    actual: String::from("5"),
+                    expected: String::from("-5"),
+                    actual_value: None,
+                    expected_value: None,
+                }),
+                label: None,
+            },
+            TestCaseResult {
+                id: 2,
+                test_result: TestResult::Failure(TestCaseFailureReason::RuntimeError(
+                    String::from("divide by zero"),
+                )),
+                label: None,
+            },
+        ]));
+
+        let actual = round_trip(&input);
+
+        assert_eq!(actual, input);
+    }
+
+    #[test]
+    fn inconclusive() {
+        let input = SubmissionResult::Inconclusive(Box::new([
+            TestCaseResult {
+                id: 0,
+                test_result: TestResult::Pass,
+                label: None,
+            },
+            TestCaseResult {
+                id: 1,
+                test_result: TestResult::Unknown,
+                label: None,
+            },
+        ]));
+
+        let actual = round_trip(&input);
+
+        assert_eq!(actual, input);
+    }
+
+    #[test]
+    fn labelled_failure() {
+        let input = SubmissionResult::Failure(Box::new([
+            TestCaseResult {
+                id: 0,
+                test_result: TestResult::Pass,
+                label: Some(String::from("edge case")),
+            },
+            TestCaseResult {
+                id: 1,
+                test_result: TestResult::Failure(TestCaseFailureReason::Timeout),
+                label: Some(String::from("performance")),
+            },
+        ]));
+
+        let actual = round_trip(&input);
+
+        assert_eq!(actual, input);
+    }
+
+    #[test]
+    fn error() {
+        let input = SubmissionResult::Error {
+            code: ErrorCode::Execution,
+            message: String::from("an error \"occurred\"\non two lines"),
+        };
+
+        let actual = round_trip(&input);
+
+        assert_eq!(actual, input);
+    }
+
+    #[test]
+    fn compilation_error() {
+        let input = SubmissionResult::CompilationError {
+            message: String::from("an error occurred during compilation"),
+            diagnostics: Box::new([CompilationDiagnostic {
+                line: 4,
+                column: 14,
+                message: String::from("Variable not in scope: y :: Int"),
+            }]),
+        };
+
+        let actual = round_trip(&input);
+
+        assert_eq!(actual, input);
+    }
+
+    #[test]
+    fn internal_error() {
+        let input = SubmissionResult::InternalError;
+
+        let actual = round_trip(&input);
+
+        assert_eq!(actual, input);
+    }
+
+    #[test]
+    fn internal_error_shape() {
+        let input = SubmissionResult::InternalError;
+        let expected = r#"{"version":1,"result":"internalError"}"#;
+
+        let actual = serde_json::to_string(&input).expect("failed to serialize submission result");
+
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn error_shape() {
+        let input = SubmissionResult::Error {
+            code: ErrorCode::CompileTimeout,
+            message: String::from("compilation exceeded the timeout limit of 5s"),
+        };
+        let expected = r#"{"version":1,"result":"error","code":"compileTimeout","message":"compilation exceeded the timeout limit of 5s"}"#;
+
+        let actual = serde_json::to_string(&input).expect("failed to serialize submission result");
+
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn version_is_serialized() {
+        let input = SubmissionResult::Pass;
+        let expected = r#"{"version":1,"result":"pass"}"#;
+
+        let actual = serde_json::to_string(&input).expect("failed to serialize submission result");
+
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn unsupported_version() {
+        let input = r#"{"version":0,"result":"pass"}"#;
+
+        let actual = serde_json::from_str::<SubmissionResult>(input);
+
+        assert!(actual.is_err());
+    }
+
+    #[test]
+    fn field_order_is_irrelevant() {
+        let input = r#"{"message":"bad","code":"execution","result":"error","version":1}"#;
+        let expected = SubmissionResult::Error {
+            code: ErrorCode::Execution,
+            message: String::from("bad"),
+        };
+
+        let actual = serde_json::from_str::<SubmissionResult>(input)
+            .expect("failed to deserialize submission result");
+
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn debug_shape() {
+        let input = DebugSubmissionResult {
+            result: SubmissionResult::Pass,
+            stdout: None,
+            metadata: None,
+            generated_source: Some(String::from("main = pure ()")),
+        };
+        let expected = r#"{"version":1,"result":"pass","generatedSource":"main = pure ()"}"#;
+
+        let actual = serde_json::to_string(&input).expect("failed to serialize submission result");
+
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn stdout_shape() {
+        let input = AnnotatedSubmissionResult {
+            result: SubmissionResult::Pass,
+            stdout: Some(String::from("debug\n")),
+            metadata: None,
+        };
+        let expected = r#"{"version":1,"result":"pass","stdout":"debug\n"}"#;
+
+        let actual = serde_json::to_string(&input).expect("failed to serialize submission result");
+
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn stdout_is_ignored() {
+        let input = r#"{"version":1,"result":"pass","stdout":"debug\n"}"#;
+        let expected = SubmissionResult::Pass;
+
+        let actual = serde_json::from_str::<SubmissionResult>(input)
+            .expect("failed to deserialize submission result");
+
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn metadata_shape() {
+        let input = AnnotatedSubmissionResult {
+            result: SubmissionResult::Pass,
+            stdout: None,
+            metadata: Some(json!({"studentId": "s1", "attempt": 2})),
+        };
+        let expected = r#"{"version":1,"result":"pass","metadata":{"attempt":2,"studentId":"s1"}}"#;
+
+        let actual = serde_json::to_string(&input).expect("failed to serialize submission result");
+
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn hidden_failure_shape() {
+        let input = SubmissionResult::Failure(Box::new([TestCaseResult {
+            id: 0,
+            test_result: TestResult::Failure(TestCaseFailureReason::Hidden),
+            label: None,
+        }]));
+        let expected = r#"{"version":1,"result":"failure","testCaseResults":[{"id":0,"testResult":"failure","cause":"hidden"}]}"#;
+
+        let actual = serde_json::to_string(&input).expect("failed to serialize submission result");
+
+        assert_eq!(actual, expected);
+        assert_eq!(round_trip(&input), input);
+    }
+
+    #[test]
+    fn metadata_is_ignored() {
+        let input = r#"{"version":1,"result":"pass","metadata":{"attempt":2}}"#;
+        let expected = SubmissionResult::Pass;
+
+        let actual = serde_json::from_str::<SubmissionResult>(input)
+            .expect("failed to deserialize submission result");
+
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn unknown_field_is_ignored() {
+        let input = r#"{"version":1,"result":"pass","durationMs":12,"runner":{"host":"a"}}"#;
+        let expected = SubmissionResult::Pass;
+
+        let actual = serde_json::from_str::<SubmissionResult>(input)
+            .expect("failed to deserialize submission result");
+
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn generated_source_is_ignored() {
+        let input = r#"{"version":1,"result":"pass","generatedSource":"main = pure ()"}"#;
+        let expected = SubmissionResult::Pass;
+
+        let actual = serde_json::from_str::<SubmissionResult>(input)
+            .expect("failed to deserialize submission result");
+
+        assert_eq!(actual, expected);
+    }
+}
+
+#[cfg(test)]
+mod reorder {
+    use super::{ResultOrder, SubmissionResult};
+    use crate::model::{TestCaseFailureReason, TestCaseResult, TestResult};
+
+    /// A test util function to make the results of a pass, a timeout, an unknown result and a wrong answer,
+    /// with the ids of the supplied order.
+    fn results(ids: [u64; 4]) -> Box<[TestCaseResult]> {
+        let test_results = [
+            TestResult::Pass,
+            TestResult::Failure(TestCaseFailureReason::Timeout),
+            TestResult::Unknown,
+            TestResult::Failure(TestCaseFailureReason::WrongAnswer {
+                input_parameters: Box::new([]),
+                actual: String::from("1"),
+                expected: String::from("2"),
+                actual_value: None,
+                expected_value: None,
+            }),
+        ];
+
+        ids.into_iter()
+            .map(|id| TestCaseResult {
+                id,
+                test_result: test_results[id as usize].clone(),
+                label: None,
+            })
+            .collect()
+    }
+
+    #[test]
+    fn input() {
+        let mut actual = SubmissionResult::Failure(results([0, 1, 2, 3]));
+        let expected = SubmissionResult::Failure(results([0, 1, 2, 3]));
+
+        actual.reorder(ResultOrder::Input);
+
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn failures_first() {
+        let mut actual = SubmissionResult::Failure(results([0, 1, 2, 3]));
+        let expected = SubmissionResult::Failure(results([1, 3, 2, 0]));
+
+        actual.reorder(ResultOrder::FailuresFirst);
+
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn reason() {
+        let mut actual = SubmissionResult::Failure(results([0, 1, 2, 3]));
+        let expected = SubmissionResult::Failure(results([3, 1, 2, 0]));
+
+        actual.reorder(ResultOrder::Reason);
+
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn without_test_case_results() {
+        let mut actual = SubmissionResult::Pass;
+        let expected = SubmissionResult::Pass;
+
+        actual.reorder(ResultOrder::FailuresFirst);
+
+        assert_eq!(actual, expected);
+    }
+}
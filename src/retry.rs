@@ -0,0 +1,105 @@
+//! Contains the retrying of filesystem operations that fail transiently, e.g. under heavy disk load.
+
+use std::{io, time::Duration};
+use tokio::time::sleep;
+use tracing::warn;
+
+/// The maximum number of times a failed operation is retried.
+const MAX_RETRIES: u32 = 3;
+
+/// The time waited before the first retry, which is doubled for every subsequent retry.
+const INITIAL_BACKOFF: Duration = Duration::from_millis(10);
+
+/// Runs the filesystem `operation`, retrying it with an exponential backoff if it fails transiently.
+///
+/// The `description` of the operation is used when logging a retry.
+///
+/// # Errors
+/// Returns the error of the operation if it is not transient, or if it still fails after [`MAX_RETRIES`] retries.
+pub async fn retry_io<T, F>(description: &str, mut operation: F) -> io::Result<T>
+where
+    F: FnMut() -> io::Result<T>,
+{
+    let mut backoff = INITIAL_BACKOFF;
+    let mut retries = 0;
+
+    loop {
+        match operation() {
+            Err(err) if retries < MAX_RETRIES && is_transient(&err) => {
+                retries += 1;
+                warn!(
+                    "could not {}, retrying in {:?} ({}/{}): {}",
+                    description, backoff, retries, MAX_RETRIES, err
+                );
+                sleep(backoff).await;
+                backoff *= 2;
+            }
+            result => return result,
+        }
+    }
+}
+
+/// Determines whether the `err` is likely to be transient, i.e. whether the operation may succeed if retried.
+///
+/// This is the case for running out of file descriptors or disk space, and for interrupted or busy operations.
+fn is_transient(err: &io::Error) -> bool {
+    if err.kind() == io::ErrorKind::Interrupted {
+        return true;
+    }
+
+    matches!(
+        err.raw_os_error(),
+        Some(libc::EMFILE | libc::ENFILE | libc::ENOSPC | libc::EAGAIN | libc::EBUSY)
+    )
+}
+
+#[cfg(test)]
+mod retry_io {
+    use super::{retry_io, MAX_RETRIES};
+    use std::io;
+
+    #[tokio::test]
+    async fn transient_failure() {
+        let mut attempts = 0;
+
+        let actual = retry_io("test", || {
+            attempts += 1;
+            if attempts < 3 {
+                Err(io::Error::from_raw_os_error(libc::EMFILE))
+            } else {
+                Ok(attempts)
+            }
+        })
+        .await;
+
+        assert_eq!(actual.ok(), Some(3));
+    }
+
+    #[tokio::test]
+    async fn persistent_transient_failure() {
+        let mut attempts = 0;
+
+        let actual: io::Result<()> = retry_io("test", || {
+            attempts += 1;
+            Err(io::Error::from_raw_os_error(libc::ENOSPC))
+        })
+        .await;
+
+        assert!(actual.is_err());
+        assert_eq!(attempts, MAX_RETRIES + 1);
+    }
+
+    #[tokio::test]
+    async fn permanent_failure() {
+        let mut attempts = 0;
+
+        let actual: io::Result<()> = retry_io("test", || {
+            attempts += 1;
+            Err(io::Error::from(io::ErrorKind::PermissionDenied))
+        })
+        .await;
+
+        assert!(actual.is_err());
+        assert_eq!(attempts, 1);
+    }
+}
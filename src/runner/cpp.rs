@@ -0,0 +1,1229 @@
+//! Contains the language specific implementation for the C++ programming language.
+
+use super::LanguageHandler;
+use crate::{
+    config::Config,
+    error::{SubmissionError, UUID_SHOULD_BE_VALID_STR},
+    model::{CompilationDiagnostic, Parameter, ParameterType, TestCase},
+    runner::{
+        crash_error, format_float, has_started_test_case, insert_test_runner_constants,
+        jail::{jail, jailed_path},
+        read_test_output, remove_mozart_path, restrict_environment, restrict_resources,
+        spawn_failure, TestOutput, ENDED_OUTCOME, STARTED_OUTCOME, TEST_CASE_ID_TARGET,
+    },
+    timeout::{run_process, timeout_process, ProcessOutcome},
+};
+use std::{path::PathBuf, process::Stdio, sync::Arc};
+use tokio::process::Command;
+use tracing::{debug, error, info};
+
+/// The base test code for C++.
+///
+/// The solution is included after the test runner, so that it can use the standard headers included by it,
+/// and before the checker, so that a custom checker can use the definitions of the solution.
+/// The reference solution is included after the solution, with its definitions inside the `reference` namespace.
+const CPP_BASE_TEST_CODE: &str = r###"
+#include "test_runner.hpp"
+#include "solution.cpp"
+#include "reference.cpp"
+#include "checker.hpp"
+
+int main() {
+TEST_CASES
+    return 0;
+}
+"###;
+
+/// The test runner for the C++ implementation.
+///
+/// Every outcome is written as a JSON object on its own line, so that values containing delimiters cannot break it.
+/// Characters outside of printable ASCII are escaped, so that the output does not depend on the locale.
+///
+/// Its definitions are inside the `mozart` namespace, so that they cannot clash with those of the solution.
+///
+/// A test case runs in a forked process, which reports its outcome through a pipe, such that a test case that
+/// crashes, e.g. with a segmentation fault, is reported as a runtime error without affecting the remaining test cases,
+/// and a test case that exceeds its timeout can be killed. Before it is run, stdin is redirected to its file inside
+/// `STDIN_DIR`, relative to the jail root. The common signals are described the same way by every C library.
+///
+/// The values of a wrong answer are truncated to `MAX_VALUE_LENGTH` characters, followed by `TRUNCATED_VALUE_MARKER`.
+/// If the expected value is a tuple, a vector or a map, they are also written as typed JSON,
+/// unless it is longer than that.
+/// A character or string is reported as its text, unless `QUOTE_VALUES` is 1, in which case it is quoted and escaped.
+///
+/// If `UNORDERED_LISTS` is 1, the actual and expected vectors, including those among multiple output parameters, are
+/// sorted before they are checked, with `unordered`, so that the order of their elements does not matter. The values of
+/// a wrong answer are reported in their original order.
+///
+/// A test case that expects a runtime error reports the value the solution returned instead, with `returned`.
+///
+/// Every outcome is appended to `RESULTS_FILE`, relative to the jail root, so that what the solution prints cannot
+/// break it. Stdout is flushed before the forked process exits, as `_exit` would discard what the solution printed.
+///
+/// If `FAIL_FAST` is 1, the process exits right after the first outcome that is not a pass.
+const CPP_TEST_RUNNER: &str = r###"
+#include <algorithm>
+#include <charconv>
+#include <chrono>
+#include <cmath>
+#include <cerrno>
+#include <cstdio>
+#include <cstdlib>
+#include <cstring>
+#include <exception>
+#include <functional>
+#include <limits>
+#include <map>
+#include <string>
+#include <tuple>
+#include <type_traits>
+#include <utility>
+#include <variant>
+#include <vector>
+
+#include <poll.h>
+#include <signal.h>
+#include <sys/prctl.h>
+#include <sys/wait.h>
+#include <unistd.h>
+
+namespace mozart {
+
+struct Outcome {
+    std::string kind;
+    std::vector<std::pair<std::string, std::string>> fields;
+};
+
+std::string show(bool value);
+std::string show(int value);
+std::string show(long long value);
+std::string show(double value);
+std::string show(char value);
+std::string show(const std::string& value);
+std::string show(std::monostate value);
+template <typename T>
+std::string show(const std::vector<T>& value);
+template <typename K, typename V>
+std::string show(const std::map<K, V>& value);
+template <typename... T>
+std::string show(const std::tuple<T...>& value);
+
+std::string json(bool value);
+std::string json(int value);
+std::string json(long long value);
+std::string json(double value);
+std::string json(char value);
+std::string json(const std::string& value);
+std::string json(std::monostate value);
+template <typename T>
+std::string json(const std::vector<T>& value);
+template <typename K, typename V>
+std::string json(const std::map<K, V>& value);
+template <typename... T>
+std::string json(const std::tuple<T...>& value);
+template <typename T>
+std::string json(const T& value);
+
+template <typename T>
+struct is_structured : std::false_type {};
+
+template <typename T>
+struct is_structured<std::vector<T>> : std::true_type {};
+
+template <typename K, typename V>
+struct is_structured<std::map<K, V>> : std::true_type {};
+
+template <typename... T>
+struct is_structured<std::tuple<T...>> : std::true_type {};
+
+inline std::string escape(const std::string& text, char quote) {
+    std::string escaped;
+    for (char c : text) {
+        if (c == quote || c == '\\') {
+            escaped += '\\';
+            escaped += c;
+        } else if (c == '\n') {
+            escaped += "\\n";
+        } else if (c == '\t') {
+            escaped += "\\t";
+        } else {
+            escaped += c;
+        }
+    }
+    return escaped;
+}
+
+inline std::string show(bool value) { return value ? "true" : "false"; }
+
+inline std::string show(int value) { return std::to_string(value); }
+
+inline std::string show(long long value) { return std::to_string(value); }
+
+inline std::string show(double value) {
+    char buffer[64];
+    auto result = std::to_chars(buffer, buffer + sizeof(buffer), value);
+    std::string text(buffer, result.ptr);
+    if (text.find_first_of(".en") == std::string::npos) {
+        text += ".0";
+    }
+    return text;
+}
+
+inline std::string show(char value) { return "'" + escape(std::string(1, value), '\'') + "'"; }
+
+inline std::string show(const std::string& value) { return "\"" + escape(value, '"') + "\""; }
+
+inline std::string show(std::monostate) { return "()"; }
+
+template <typename T>
+std::string show(const std::vector<T>& value) {
+    std::string text = "[";
+    for (std::size_t i = 0; i < value.size(); i++) {
+        if (i > 0) {
+            text += ", ";
+        }
+        text += show(value[i]);
+    }
+    return text + "]";
+}
+
+template <typename K, typename V>
+std::string show(const std::map<K, V>& value) {
+    std::string text = "{";
+    for (auto entry = value.begin(); entry != value.end(); ++entry) {
+        if (entry != value.begin()) {
+            text += ", ";
+        }
+        text += show(entry->first) + ": " + show(entry->second);
+    }
+    return text + "}";
+}
+
+template <typename... T>
+std::string show(const std::tuple<T...>& value) {
+    std::string text = "(";
+    std::apply([&text](const auto&... element) {
+        bool first = true;
+        ((text += (first ? "" : ", ") + show(element), first = false), ...);
+    }, value);
+    return text + ")";
+}
+
+template <typename T>
+std::string display(const T& value) { return show(value); }
+
+inline std::string display(char value) { return QUOTE_VALUES ? show(value) : std::string(1, value); }
+
+inline std::string display(const std::string& value) { return QUOTE_VALUES ? show(value) : value; }
+
+inline std::string render(const std::string& text) {
+    if (text.size() <= MAX_VALUE_LENGTH) {
+        return text;
+    }
+    return text.substr(0, MAX_VALUE_LENGTH) + "TRUNCATED_VALUE_MARKER";
+}
+
+inline void append_unicode(std::string& json, unsigned int code) {
+    char buffer[8];
+    std::snprintf(buffer, sizeof(buffer), "\\u%04x", code);
+    json += buffer;
+}
+
+inline std::string json_string(const std::string& text) {
+    std::string json = "\"";
+    std::size_t i = 0;
+    while (i < text.size()) {
+        unsigned char c = text[i];
+        if (c == '"' || c == '\\') {
+            json += '\\';
+            json += static_cast<char>(c);
+            i++;
+        } else if (c >= 0x20 && c <= 0x7e) {
+            json += static_cast<char>(c);
+            i++;
+        } else if (c < 0x80) {
+            append_unicode(json, c);
+            i++;
+        } else {
+            std::size_t length = c >= 0xf0 ? 4 : c >= 0xe0 ? 3 : c >= 0xc0 ? 2 : 0;
+            unsigned int code = c & (0x7f >> length);
+            bool valid = length > 0 && i + length <= text.size();
+            for (std::size_t j = 1; valid && j < length; j++) {
+                unsigned char continuation = text[i + j];
+                valid = (continuation & 0xc0) == 0x80;
+                code = (code << 6) | (continuation & 0x3f);
+            }
+            if (!valid) {
+                append_unicode(json, 0xfffd);
+                i++;
+                continue;
+            }
+            if (code > 0xffff) {
+                code -= 0x10000;
+                append_unicode(json, 0xd800 + (code >> 10));
+                append_unicode(json, 0xdc00 + (code & 0x3ff));
+            } else {
+                append_unicode(json, code);
+            }
+            i += length;
+        }
+    }
+    return json + "\"";
+}
+
+inline std::string json(bool value) { return show(value); }
+
+inline std::string json(int value) { return show(value); }
+
+inline std::string json(long long value) { return show(value); }
+
+inline std::string json(double value) { return std::isfinite(value) ? show(value) : "null"; }
+
+inline std::string json(char value) { return json_string(std::string(1, value)); }
+
+inline std::string json(const std::string& value) { return json_string(value); }
+
+inline std::string json(std::monostate) { return "null"; }
+
+template <typename T>
+std::string json(const std::vector<T>& value) {
+    std::string text = "[";
+    for (std::size_t i = 0; i < value.size(); i++) {
+        if (i > 0) {
+            text += ",";
+        }
+        text += json(value[i]);
+    }
+    return text + "]";
+}
+
+template <typename K, typename V>
+std::string json(const std::map<K, V>& value) {
+    std::string text = "{";
+    for (auto entry = value.begin(); entry != value.end(); ++entry) {
+        if (entry != value.begin()) {
+            text += ",";
+        }
+        std::string key = json(entry->first);
+        text += (key[0] == '"' ? key : json_string(key)) + ":" + json(entry->second);
+    }
+    return text + "}";
+}
+
+template <typename... T>
+std::string json(const std::tuple<T...>& value) {
+    std::string text = "[";
+    std::apply([&text](const auto&... element) {
+        bool first = true;
+        ((text += (first ? "" : ",") + json(element), first = false), ...);
+    }, value);
+    return text + "]";
+}
+
+template <typename T>
+std::string json(const T&) { return "null"; }
+
+inline std::string outcome_line(const Outcome& outcome) {
+    std::string line = "{\"r\":" + json_string(outcome.kind);
+    for (const auto& [key, value] : outcome.fields) {
+        line += "," + json_string(key) + ":" + value;
+    }
+    return line + "}\n";
+}
+
+inline void append_result(const std::string& line) {
+    std::fflush(stdout);
+    std::FILE* results = std::fopen("RESULTS_FILE", "a");
+    if (results != nullptr) {
+        std::fwrite(line.data(), 1, line.size(), results);
+        std::fclose(results);
+    }
+}
+
+inline void report_outcome(const Outcome& outcome) {
+    append_result(outcome_line(outcome));
+    if (FAIL_FAST && outcome.kind != "STARTED_OUTCOME" && outcome.kind != "ENDED_OUTCOME" && outcome.kind != "p") {
+        std::exit(0);
+    }
+}
+
+inline std::string signal_description(int signal) {
+    switch (signal) {
+        case SIGABRT: return "Aborted";
+        case SIGBUS: return "Bus error";
+        case SIGFPE: return "Floating point exception";
+        case SIGILL: return "Illegal instruction";
+        case SIGSEGV: return "Segmentation fault";
+        default: return strsignal(signal);
+    }
+}
+
+inline Outcome error_outcome(const std::string& message) {
+    return {"err", {{"msg", json_string(message)}}};
+}
+
+template <typename T>
+const T& unordered(const T& value);
+template <typename T>
+std::vector<T> unordered(const std::vector<T>& value);
+template <typename... T>
+std::tuple<T...> unordered(const std::tuple<T...>& value);
+
+template <typename T>
+const T& unordered(const T& value) { return value; }
+
+template <typename T>
+std::vector<T> unordered(const std::vector<T>& value) {
+    std::vector<T> sorted = value;
+    if (UNORDERED_LISTS) {
+        std::sort(sorted.begin(), sorted.end());
+    }
+    return sorted;
+}
+
+template <typename... T>
+std::tuple<T...> unordered(const std::tuple<T...>& value) {
+    return std::apply([](const auto&... element) { return std::tuple<T...>{unordered(element)...}; }, value);
+}
+
+template <typename T>
+Outcome test_checker(bool passed, const T& actual, const T& expected) {
+    if (passed) {
+        return {"p", {}};
+    }
+    Outcome outcome{"f", {{"actual", json_string(render(display(actual)))}, {"expected", json_string(render(display(expected)))}}};
+    if constexpr (is_structured<T>::value) {
+        for (auto [field, value] : {std::pair{"actualValue", json(actual)}, std::pair{"expectedValue", json(expected)}}) {
+            if (value.size() <= MAX_VALUE_LENGTH) {
+                outcome.fields.emplace_back(field, value);
+            }
+        }
+    }
+    return outcome;
+}
+
+template <typename F>
+Outcome returned(const F& solution) {
+    if constexpr (std::is_void_v<std::invoke_result_t<F>>) {
+        solution();
+        return {"v", {{"actual", json_string(render(display(std::monostate{})))}}};
+    } else {
+        auto actual = solution();
+        return {"v", {{"actual", json_string(render(display(actual)))}}};
+    }
+}
+
+inline void run_test_case(const std::string& id, int timeout_millis, const std::function<Outcome()>& test_case) {
+    int channel[2];
+    if (pipe(channel) != 0) {
+        report_outcome(error_outcome("the test case could not be started"));
+        return;
+    }
+
+    std::fflush(stdout);
+    pid_t parent = getpid();
+    pid_t child = fork();
+    if (child == 0) {
+        // the test case must not outlive the execution, if it is killed while the test case is running
+        if (prctl(PR_SET_PDEATHSIG, SIGKILL) != 0 || getppid() != parent) {
+            _exit(1);
+        }
+        close(channel[0]);
+        Outcome outcome;
+        if (std::freopen(("STDIN_DIR/" + id).c_str(), "r", stdin) == nullptr) {
+            outcome = error_outcome("stdin could not be opened");
+        } else {
+            try {
+                outcome = test_case();
+            } catch (const std::exception& e) {
+                outcome = error_outcome(e.what());
+            } catch (...) {
+                outcome = error_outcome("an exception that is not a std::exception was thrown");
+            }
+        }
+        std::fflush(stdout);
+        std::string line = outcome_line(outcome);
+        std::size_t written = 0;
+        while (written < line.size()) {
+            ssize_t count = write(channel[1], line.data() + written, line.size() - written);
+            if (count <= 0) {
+                break;
+            }
+            written += count;
+        }
+        _exit(0);
+    }
+
+    close(channel[1]);
+    if (child < 0) {
+        close(channel[0]);
+        report_outcome(error_outcome("the test case could not be started"));
+        return;
+    }
+
+    auto deadline = std::chrono::steady_clock::now() + std::chrono::milliseconds(timeout_millis);
+    std::string line;
+    bool timed_out = false;
+    char buffer[4096];
+    while (true) {
+        auto remaining = std::chrono::duration_cast<std::chrono::milliseconds>(deadline - std::chrono::steady_clock::now()).count();
+        if (remaining <= 0) {
+            timed_out = true;
+            break;
+        }
+        pollfd descriptor{channel[0], POLLIN, 0};
+        int ready = poll(&descriptor, 1, static_cast<int>(remaining));
+        if (ready < 0 && errno == EINTR) {
+            continue;
+        }
+        if (ready <= 0) {
+            timed_out = ready == 0;
+            break;
+        }
+        ssize_t count = read(channel[0], buffer, sizeof(buffer));
+        if (count <= 0) {
+            break;
+        }
+        line.append(buffer, count);
+    }
+    close(channel[0]);
+
+    if (timed_out) {
+        kill(child, SIGKILL);
+    }
+    int status = 0;
+    waitpid(child, &status, 0);
+
+    if (timed_out) {
+        report_outcome({"t", {}});
+    } else if (WIFSIGNALED(status)) {
+        report_outcome(error_outcome(signal_description(WTERMSIG(status))));
+    } else if (line.empty() || !WIFEXITED(status) || WEXITSTATUS(status) != 0) {
+        report_outcome(error_outcome("the test case exited with status " + std::to_string(WEXITSTATUS(status))));
+    } else {
+        append_result(line);
+        if (FAIL_FAST && line != outcome_line({"p", {}})) {
+            std::exit(0);
+        }
+    }
+}
+
+}
+"###;
+
+/// The checker for C++ used if the submission does not supply one, which accepts a value equal to the expected value.
+const CPP_DEFAULT_CHECKER: &str = r###"
+template <typename T>
+bool checker(const T& actual, const T& expected) {
+    return actual == expected;
+}
+"###;
+
+/// The checker for C++ used if the submission supplies a float tolerance.
+///
+/// Its overloads are declared before they are defined, so that the overloads for vectors, maps and tuples compare their
+/// elements with whichever overload fits, e.g. a `double` within the tolerance.
+const CPP_TOLERANT_CHECKER: &str = r###"
+#include <algorithm>
+#include <cmath>
+#include <map>
+#include <tuple>
+#include <vector>
+
+template <typename T>
+bool checker(const T& actual, const T& expected);
+inline bool checker(const double& actual, const double& expected);
+template <typename T>
+bool checker(const std::vector<T>& actual, const std::vector<T>& expected);
+template <typename K, typename V>
+bool checker(const std::map<K, V>& actual, const std::map<K, V>& expected);
+template <typename... T>
+bool checker(const std::tuple<T...>& actual, const std::tuple<T...>& expected);
+
+template <typename T>
+bool checker(const T& actual, const T& expected) {
+    return actual == expected;
+}
+
+inline bool checker(const double& actual, const double& expected) {
+    if (std::isnan(expected)) {
+        return MATCH_NAN && std::isnan(actual);
+    }
+    if (std::isinf(expected)) {
+        return actual == expected;
+    }
+    return std::abs(actual - expected) <= std::max(ABSOLUTE_TOLERANCE, RELATIVE_TOLERANCE * std::abs(expected));
+}
+
+template <typename T>
+bool checker(const std::vector<T>& actual, const std::vector<T>& expected) {
+    if (actual.size() != expected.size()) {
+        return false;
+    }
+    for (std::size_t i = 0; i < expected.size(); i++) {
+        if (!checker(actual[i], expected[i])) {
+            return false;
+        }
+    }
+    return true;
+}
+
+template <typename K, typename V>
+bool checker(const std::map<K, V>& actual, const std::map<K, V>& expected) {
+    if (actual.size() != expected.size()) {
+        return false;
+    }
+    auto entry = actual.begin();
+    for (const auto& [key, value] : expected) {
+        if (entry->first != key || !checker(entry->second, value)) {
+            return false;
+        }
+        ++entry;
+    }
+    return true;
+}
+
+template <typename... T>
+bool checker(const std::tuple<T...>& actual, const std::tuple<T...>& expected) {
+    return std::apply([&expected](const auto&... a) {
+        return std::apply([&a...](const auto&... e) { return (checker(a, e) && ...); }, expected);
+    }, actual);
+}
+"###;
+
+/// The exception handling code snippet for C++.
+///
+/// The `TEST_CASE` is being replace with a call to the actual test case.
+/// This is done for all test cases.
+///
+/// The `STARTED_OUTCOME` and `TEST_CASE_ID` are replaced to form the started marker of the test case,
+/// which is flushed such that it is part of the output even if the execution is killed,
+/// and the `ENDED_OUTCOME` and `TEST_CASE_ID` to form its ended marker, which delimits its results.
+///
+/// The `TEST_CASE_TIMEOUT` is replaced with the time in milliseconds the test case may run for.
+const CPP_EXCEPTION_SNIPPET: &str = r###"
+    mozart::report_outcome({"STARTED_OUTCOME", {{"id", "TEST_CASE_ID"}}});
+    mozart::run_test_case("TEST_CASE_ID", TEST_CASE_TIMEOUT, [&]() -> mozart::Outcome {
+TEST_CASE
+    });
+    mozart::report_outcome({"ENDED_OUTCOME", {{"id", "TEST_CASE_ID"}}});
+"###;
+
+/// The language handler for C++.
+pub struct Cpp {
+    /// A path buffer to the current working directory of a given request.
+    temp_dir: PathBuf,
+
+    /// The configuration the submission is checked with.
+    config: Arc<Config>,
+
+    /// The test runner code, with the truncation of reported values inserted.
+    test_runner_code: String,
+}
+
+impl Cpp {
+    /// Gets the path to the executable the test code is compiled into.
+    fn executable_path(&self) -> PathBuf {
+        let mut executable_path = self.temp_dir.clone();
+        executable_path.push("test");
+        executable_path
+    }
+}
+
+/// Parses the errors of a failed compilation, written by `g++` to its `stderr`.
+///
+/// An error is a line like `solution.cpp:3:12: error: 'y' was not declared in this scope`.
+/// Lines that are not errors, e.g. the source code excerpt following it or notes, are skipped.
+fn parse_compilation_diagnostics(stderr: &str) -> Box<[CompilationDiagnostic]> {
+    stderr
+        .lines()
+        .filter_map(|line| {
+            let (location, message) = line.split_once(": error: ")?;
+            let mut position = location.rsplitn(3, ':');
+            let column = position.next()?.parse().ok()?;
+            let line = position.next()?.parse().ok()?;
+
+            Some(CompilationDiagnostic {
+                line,
+                column,
+                message: message.trim().to_string(),
+            })
+        })
+        .collect()
+}
+
+/// Gets the C++ type that values of the `parameter_type` have.
+fn cpp_type(parameter_type: &ParameterType) -> String {
+    match parameter_type {
+        ParameterType::Bool => String::from("bool"),
+        ParameterType::Int | ParameterType::Int64 => String::from("long long"),
+        ParameterType::Int32 => String::from("int"),
+        ParameterType::BigInt => unreachable!("big integers should have been rejected"),
+        ParameterType::Float => String::from("double"),
+        ParameterType::Decimal => unreachable!("decimals should have been rejected"),
+        ParameterType::Char => String::from("char"),
+        ParameterType::String => String::from("std::string"),
+        ParameterType::Unit => String::from("std::monostate"),
+        ParameterType::Map { key, value } => {
+            format!("std::map<{}, {}>", cpp_type(key), cpp_type(value))
+        }
+        ParameterType::List(value_type) => format!("std::vector<{}>", cpp_type(value_type)),
+        ParameterType::Optional(_) => unreachable!("optionals should have been rejected"),
+    }
+}
+
+/// Places the definitions of the `reference_solution` inside the `reference` namespace, so that they cannot clash with
+/// those of the solution.
+///
+/// Its `#include` directives are kept outside of the namespace, as the standard headers must be included globally.
+fn reference_namespace(reference_solution: &str) -> String {
+    let (includes, definitions): (Vec<&str>, Vec<&str>) = reference_solution
+        .lines()
+        .partition(|line| line.trim_start().starts_with("#include"));
+
+    format!(
+        "{}\nnamespace reference {{\n{}\n}}\n",
+        includes.join("\n"),
+        definitions.join("\n")
+    )
+}
+
+/// Escapes the characters of `value` that cannot appear verbatim inside a C++ string or character literal.
+///
+/// Other control characters are escaped as octal, as a hexadecimal escape would consume any hexadecimal digit after it.
+fn escape(value: &str) -> String {
+    value
+        .chars()
+        .map(|c| match c {
+            '"' => String::from("\\\""),
+            '\'' => String::from("\\'"),
+            '\\' => String::from("\\\\"),
+            '\n' => String::from("\\n"),
+            '\t' => String::from("\\t"),
+            '\r' => String::from("\\r"),
+            c if c.is_ascii_control() => format!("\\{:03o}", c as u32),
+            c => c.to_string(),
+        })
+        .collect()
+}
+
+impl LanguageHandler for Cpp {
+    fn new(temp_dir: PathBuf, config: Arc<Config>) -> Self {
+        Self {
+            temp_dir,
+            test_runner_code: insert_test_runner_constants(CPP_TEST_RUNNER, &config),
+            config,
+        }
+    }
+
+    fn test_file_path(&self) -> PathBuf {
+        let mut path = self.temp_dir.clone();
+        path.push("main.cpp");
+
+        path
+    }
+
+    fn base_test_code(&self) -> &str {
+        CPP_BASE_TEST_CODE
+    }
+
+    fn solution_file_path(&self) -> PathBuf {
+        let mut path = self.temp_dir.clone();
+        path.push("solution.cpp");
+
+        path
+    }
+
+    fn test_runner_file_path(&self) -> PathBuf {
+        let mut path = self.temp_dir.clone();
+        path.push("test_runner.hpp");
+
+        path
+    }
+
+    fn checker_file_path(&self) -> PathBuf {
+        let mut path = self.temp_dir.clone();
+        path.push("checker.hpp");
+
+        path
+    }
+
+    fn default_checker_code(&self) -> &str {
+        CPP_DEFAULT_CHECKER
+    }
+
+    fn tolerant_checker_code(&self) -> &str {
+        CPP_TOLERANT_CHECKER
+    }
+
+    fn reference_file_path(&self) -> PathBuf {
+        let mut path = self.temp_dir.clone();
+        path.push("reference.cpp");
+
+        path
+    }
+
+    fn reference_code(&self, reference_solution: Option<&str>) -> String {
+        reference_namespace(reference_solution.unwrap_or_default())
+    }
+
+    fn test_runner_code(&self) -> &str {
+        &self.test_runner_code
+    }
+
+    fn generate_test_cases(
+        &self,
+        test_code: &mut String,
+        test_cases: &[TestCase],
+        with_reference: bool,
+    ) {
+        for (index, test_case) in test_cases.iter().enumerate() {
+            let formatted_input_parameters = test_case
+                .input_parameters
+                .iter()
+                .map(|ip| self.format_parameter(ip))
+                .collect::<Vec<String>>()
+                .join(", ");
+            let call = format!("solution({formatted_input_parameters})");
+
+            // multiple output parameters are expected to be returned as a tuple
+            let (output_type, formatted_output_parameters, actual) =
+                match &*test_case.output_parameters {
+                    // the expected value is converted to the type of the actual value, as the checker compares
+                    // values of the same type
+                    [] if with_reference => (
+                        String::from("auto"),
+                        format!(
+                            "static_cast<decltype(actual)>(reference::solution({formatted_input_parameters}))"
+                        ),
+                        call,
+                    ),
+                    // a solution without a value to return is declared as void, which cannot be stored
+                    [output_parameter] if output_parameter.value_type == ParameterType::Unit => (
+                        cpp_type(&output_parameter.value_type),
+                        self.format_parameter(output_parameter),
+                        format!("({call}, std::monostate{{}})"),
+                    ),
+                    [output_parameter] => (
+                        cpp_type(&output_parameter.value_type),
+                        self.format_parameter(output_parameter),
+                        call,
+                    ),
+                    output_parameters => {
+                        let output_type = format!(
+                            "std::tuple<{}>",
+                            output_parameters
+                                .iter()
+                                .map(|op| cpp_type(&op.value_type))
+                                .collect::<Vec<String>>()
+                                .join(", ")
+                        );
+                        let formatted_output_parameters = format!(
+                            "{output_type}{{{}}}",
+                            output_parameters
+                                .iter()
+                                .map(|op| self.format_parameter(op))
+                                .collect::<Vec<String>>()
+                                .join(", ")
+                        );
+                        (output_type, formatted_output_parameters, call)
+                    }
+                };
+
+            let test_case_call = if test_case.expected_error.is_some() {
+                format!(
+                    "        return mozart::returned([&]() {{ return solution({formatted_input_parameters}); }});"
+                )
+            } else {
+                [
+                format!("        {output_type} actual = {actual};"),
+                format!("        {output_type} expected = {formatted_output_parameters};"),
+                String::from(
+                    "        return mozart::test_checker(checker(mozart::unordered(actual), mozart::unordered(expected)), actual, expected);",
+                ),
+            ]
+            .join("\n")
+            };
+            let generated_test_case = CPP_EXCEPTION_SNIPPET
+                .replace("STARTED_OUTCOME", STARTED_OUTCOME)
+                .replace("ENDED_OUTCOME", ENDED_OUTCOME)
+                .replace(TEST_CASE_ID_TARGET, &test_case.id.to_string())
+                .replace(
+                    "TEST_CASE_TIMEOUT",
+                    &self.config.test_case_timeout.as_millis().to_string(),
+                )
+                .replace("TEST_CASE", &test_case_call);
+            if index > 0 {
+                test_code.push('\n');
+            }
+            test_code.push_str(&generated_test_case);
+        }
+    }
+
+    fn format_parameter(&self, parameter: &Parameter) -> String {
+        match &parameter.value_type {
+            ParameterType::Int | ParameterType::Int64
+                if parameter.value == i64::MIN.to_string() =>
+            {
+                String::from("std::numeric_limits<long long>::min()")
+            }
+            ParameterType::Int | ParameterType::Int64 => format!("{}LL", parameter.value),
+            ParameterType::Int32 if parameter.value == i32::MIN.to_string() => {
+                String::from("std::numeric_limits<int>::min()")
+            }
+            ParameterType::Int32 => parameter.value.clone(),
+            ParameterType::BigInt => unreachable!("big integers should have been rejected"),
+            ParameterType::Float => format_float(
+                &parameter.value,
+                "std::numeric_limits<double>::quiet_NaN()",
+                "std::numeric_limits<double>::infinity()",
+            ),
+            ParameterType::Decimal => unreachable!("decimals should have been rejected"),
+            ParameterType::Bool => parameter.value.clone(),
+            ParameterType::Char => format!("'{}'", escape(&parameter.value)),
+            ParameterType::String => format!(r#"std::string("{}")"#, escape(&parameter.value)),
+            ParameterType::Unit => String::from("std::monostate{}"),
+            ParameterType::List(_) => {
+                let elements = parameter
+                    .elements()
+                    .expect("the value of a list should have been validated")
+                    .iter()
+                    .map(|element| self.format_parameter(element))
+                    .collect::<Vec<_>>();
+                format!(
+                    "{}{{{}}}",
+                    cpp_type(&parameter.value_type),
+                    elements.join(", ")
+                )
+            }
+            ParameterType::Optional(_) => unreachable!("optionals should have been rejected"),
+            ParameterType::Map { .. } => {
+                let entries = parameter
+                    .entries()
+                    .expect("the value of a map should have been validated")
+                    .iter()
+                    .map(|(key, value)| {
+                        format!(
+                            "{{{}, {}}}",
+                            self.format_parameter(key),
+                            self.format_parameter(value)
+                        )
+                    })
+                    .collect::<Vec<_>>();
+                format!(
+                    "{}{{{}}}",
+                    cpp_type(&parameter.value_type),
+                    entries.join(", ")
+                )
+            }
+        }
+    }
+
+    /// Compiles the test file, which includes the solution, test runner and checker, into a single executable,
+    /// passing the `compiler_flags` of the submission to the `toolchain`, e.g. `g++`.
+    async fn compile(
+        &self,
+        toolchain: &str,
+        compiler_flags: &[String],
+    ) -> Result<(), SubmissionError> {
+        let executable_path = self.executable_path();
+        let executable_str = executable_path.to_str().expect(UUID_SHOULD_BE_VALID_STR);
+        let test_file_path = self.test_file_path();
+        let test_file_str = test_file_path.to_str().expect(UUID_SHOULD_BE_VALID_STR);
+
+        info!("spawning compilation process");
+        let compile_process = Command::new(toolchain)
+            .arg("-O2") // the optimization level solutions are commonly judged at
+            .arg("-std=c++20")
+            // the inputs are literals, so a division by zero or null dereference they cause would otherwise be
+            // compiled into a trap, which is reported as an illegal instruction instead of its actual cause
+            .arg("-fno-isolate-erroneous-paths-dereference")
+            .args(compiler_flags)
+            .args(["-o", executable_str])
+            .arg(test_file_str)
+            .stdin(Stdio::piped())
+            .stdout(Stdio::piped())
+            .stderr(Stdio::piped())
+            .spawn();
+        let compile_handle = match compile_process {
+            Ok(ch) => ch,
+            Err(err) => return Err(spawn_failure(toolchain, &err)),
+        };
+
+        info!("starting timeout of compilation process");
+        let Some((compile_exit_status, compile_output)) =
+            timeout_process(self.config.timeout, compile_handle).await?
+        else {
+            error!(
+                "compilation process exceeded allowed time limit of {:?}",
+                self.config.timeout
+            );
+            return Err(SubmissionError::CompileTimeout(self.config.timeout));
+        };
+
+        if compile_exit_status.success() {
+            info!("no compile errors");
+            return Ok(());
+        }
+
+        let stderr = remove_mozart_path(&String::from_utf8_lossy(&compile_output.stderr));
+        if compile_exit_status.code() != Some(1) || stderr.trim().is_empty() {
+            error!(
+                "compilation process failed with {}: {}",
+                compile_exit_status, stderr
+            );
+            return Err(SubmissionError::Internal);
+        }
+
+        info!("compile error");
+        debug!("compile error: {}", stderr);
+        let diagnostics = parse_compilation_diagnostics(&stderr);
+        Err(SubmissionError::Compilation(stderr, diagnostics))
+    }
+    async fn execute(
+        &self,
+        _toolchain: &str,
+        _compiler_flags: &[String],
+        slot: usize,
+    ) -> Result<(TestOutput, String), SubmissionError> {
+        let executable_path = self.executable_path();
+
+        info!("spawning execution process");
+        let execution_process = jail(
+            restrict_resources(
+                restrict_environment(
+                    Command::new(jailed_path(&executable_path, &self.temp_dir))
+                        .stdin(Stdio::piped())
+                        .stdout(Stdio::piped())
+                        .stderr(Stdio::piped()),
+                    &self.config.execution_env_vars,
+                ),
+                self.config.max_file_size,
+            ),
+            &self.temp_dir,
+            slot,
+        )
+        .and_then(Command::spawn);
+        let execution_handle = match execution_process {
+            Ok(eh) => eh,
+            Err(err) => {
+                error!("could not spawn execution process: {}", err);
+                return Err(SubmissionError::Internal);
+            }
+        };
+
+        info!("starting execution process timeout");
+        match run_process(
+            self.config.timeout,
+            self.config.kill_grace_period,
+            execution_handle,
+        )
+        .await?
+        {
+            ProcessOutcome::Exited(output) => {
+                let es = output.status;
+                info!(?es);
+                info!("stdout: {}", String::from_utf8_lossy(&output.stdout));
+                info!("stderr: {}", String::from_utf8_lossy(&output.stderr));
+
+                let test_output = read_test_output(&self.temp_dir).await?;
+                let stdout = String::from_utf8_lossy(&output.stdout).to_string();
+
+                if es.success() {
+                    Ok((TestOutput::Finished(test_output), stdout))
+                } else if has_started_test_case(&test_output) {
+                    info!("execution process crashed");
+                    Ok((
+                        TestOutput::Crashed(test_output, crash_error(&output)),
+                        stdout,
+                    ))
+                } else {
+                    Err(SubmissionError::Execution(crash_error(&output)))
+                }
+            }
+            ProcessOutcome::TimedOut(output) => {
+                error!(
+                    "execution process exceeded allowed time limit of {:?}",
+                    self.config.timeout
+                );
+                let test_output = read_test_output(&self.temp_dir).await?;
+                let stdout = String::from_utf8_lossy(&output.stdout).to_string();
+
+                Ok((TestOutput::TimedOut(test_output), stdout))
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod parse_compilation_diagnostics {
+    use super::parse_compilation_diagnostics;
+    use crate::model::CompilationDiagnostic;
+
+    #[test]
+    fn undeclared_name() {
+        let input = [
+            "solution.cpp: In function 'long long int solution(long long int)':",
+            "solution.cpp:2:12: error: 'y' was not declared in this scope",
+            "    2 |     return y;",
+            "      |            ^",
+        ]
+        .join("\n");
+        let expected = Box::new([CompilationDiagnostic {
+            line: 2,
+            column: 12,
+            message: String::from("'y' was not declared in this scope"),
+        }]);
+
+        let actual = parse_compilation_diagnostics(&input);
+
+        assert_eq!(*actual, *expected);
+    }
+
+    #[test]
+    fn notes_are_skipped() {
+        let input = [
+            "main.cpp:10:34: error: no matching function for call to 'solution(long long int)'",
+            "solution.cpp:1:11: note: candidate: 'int solution(std::string)'",
+        ]
+        .join("\n");
+        let expected = Box::new([CompilationDiagnostic {
+            line: 10,
+            column: 34,
+            message: String::from("no matching function for call to 'solution(long long int)'"),
+        }]);
+
+        let actual = parse_compilation_diagnostics(&input);
+
+        assert_eq!(*actual, *expected);
+    }
+}
+
+#[cfg(test)]
+mod format_parameter {
+    use super::Cpp;
+    use crate::{
+        config::Config,
+        model::{Parameter, ParameterType},
+        runner::LanguageHandler,
+    };
+    use std::{path::PathBuf, sync::Arc};
+
+    #[test]
+    fn int() {
+        let cpp = Cpp::new(PathBuf::new(), Arc::new(Config::default()));
+        let input = Parameter {
+            value_type: ParameterType::Int,
+            value: String::from("-5"),
+        };
+        let expected = String::from("-5LL");
+
+        let actual = cpp.format_parameter(&input);
+
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn int64_min() {
+        let cpp = Cpp::new(PathBuf::new(), Arc::new(Config::default()));
+        let input = Parameter {
+            value_type: ParameterType::Int64,
+            value: String::from("-9223372036854775808"),
+        };
+        let expected = String::from("std::numeric_limits<long long>::min()");
+
+        let actual = cpp.format_parameter(&input);
+
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn float() {
+        let cpp = Cpp::new(PathBuf::new(), Arc::new(Config::default()));
+        let input = Parameter {
+            value_type: ParameterType::Float,
+            value: String::from("5"),
+        };
+        let expected = String::from("5.0");
+
+        let actual = cpp.format_parameter(&input);
+
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn char_backslash() {
+        let cpp = Cpp::new(PathBuf::new(), Arc::new(Config::default()));
+        let input = Parameter {
+            value_type: ParameterType::Char,
+            value: String::from("\\"),
+        };
+        let expected = String::from(r"'\\'");
+
+        let actual = cpp.format_parameter(&input);
+
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn string_with_control_character() {
+        let cpp = Cpp::new(PathBuf::new(), Arc::new(Config::default()));
+        let input = Parameter {
+            value_type: ParameterType::String,
+            value: String::from("say \"hi\"\u{1}1"),
+        };
+        let expected = String::from(r#"std::string("say \"hi\"\0011")"#);
+
+        let actual = cpp.format_parameter(&input);
+
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn unit() {
+        let cpp = Cpp::new(PathBuf::new(), Arc::new(Config::default()));
+        let input = Parameter {
+            value_type: ParameterType::Unit,
+            value: String::new(),
+        };
+        let expected = String::from("std::monostate{}");
+
+        let actual = cpp.format_parameter(&input);
+
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn map() {
+        let cpp = Cpp::new(PathBuf::new(), Arc::new(Config::default()));
+        let input = Parameter {
+            value_type: ParameterType::Map {
+                key: Box::new(ParameterType::String),
+                value: Box::new(ParameterType::Int),
+            },
+            value: String::from(r#"{"pear": "5", "apple": "3"}"#),
+        };
+        let expected = String::from(
+            r#"std::map<std::string, long long>{{std::string("apple"), 3LL}, {std::string("pear"), 5LL}}"#,
+        );
+
+        let actual = cpp.format_parameter(&input);
+
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn list() {
+        let cpp = Cpp::new(PathBuf::new(), Arc::new(Config::default()));
+        let input = Parameter {
+            value_type: ParameterType::List(Box::new(ParameterType::String)),
+            value: String::from(r#"["pear", "apple"]"#),
+        };
+        let expected =
+            String::from(r#"std::vector<std::string>{std::string("pear"), std::string("apple")}"#);
+
+        let actual = cpp.format_parameter(&input);
+
+        assert_eq!(actual, expected);
+    }
+}
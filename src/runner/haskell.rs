@@ -2,119 +2,629 @@
 
 use super::LanguageHandler;
 use crate::{
+    config::Config,
     error::{SubmissionError, UUID_SHOULD_BE_VALID_STR},
-    model::{Parameter, ParameterType, TestCase},
-    runner::{remove_mozart_path, TIMEOUT},
-    timeout::timeout_process,
-    RESTRICTED_USER_ID,
+    model::{CompilationDiagnostic, Parameter, ParameterType, TestCase},
+    runner::{
+        crash_error, format_float, has_started_test_case, insert_test_runner_constants,
+        jail::{jail, jailed_path},
+        read_test_output, remove_mozart_path, restrict_environment, restrict_resources,
+        spawn_failure, TestOutput, ENDED_OUTCOME, STARTED_OUTCOME, TEST_CASE_ID_TARGET,
+    },
+    timeout::{run_process, timeout_process, ProcessOutcome},
+};
+use std::{
+    iter,
+    path::PathBuf,
+    process::{ExitStatus, Stdio},
+    sync::Arc,
 };
-use std::{path::PathBuf, process::Stdio};
 use tokio::process::Command;
-use tracing::{debug, error, info};
+use tracing::{debug, error, info, warn};
+
+/// The name of the module the solution must be declared as, which the base test code imports.
+const SOLUTION_MODULE: &str = "Solution";
 
 /// The base test code for Haskell.
+///
+/// Only `solution` is imported from the solution, so that other definitions, e.g. a `main`, cannot clash with it.
+/// The reference solution is imported qualified, so that its `solution` does not clash with that of the solution.
+/// The test cases are followed by a `return ()`, so that the `do` block is not empty if there are no test cases.
 const HASKELL_BASE_TEST_CODE: &str = r###"
 module Main where
 
-import Solution
+import Solution (solution)
+import qualified Reference
 import TestRunner
 import Control.Exception
+import Data.Int
 import Data.List
+import qualified Data.Map
+import System.IO
+import System.Timeout
 
 main = do
 TEST_CASES
+  return ()
 "###;
 
 /// The test runner for the Haskell implementation.
+///
+/// Every outcome is written as a JSON object on its own line, so that values containing delimiters cannot break it.
+/// Characters outside of printable ASCII are escaped, so that the output does not depend on the locale.
+///
+/// The actual value is accepted if the `checker` of the `Checker` module returns `True` for it and the expected value.
+///
+/// Before a test case is run, stdin is redirected to its file inside `STDIN_DIR`, relative to the jail root.
+///
+/// The outcome is fully evaluated before it is appended to `RESULTS_FILE`, relative to the jail root, so that a timeout
+/// cannot interrupt it halfway through, and what the solution prints cannot break it. Stdout is flushed after it,
+/// so that what the solution printed is not lost if the execution crashes or is killed.
+///
+/// The values of a wrong answer are truncated to `MAX_VALUE_LENGTH` characters, followed by `TRUNCATED_VALUE_MARKER`.
+/// If the expected value is a tuple of up to four elements, a list or a map, they are also written as typed JSON,
+/// unless it is longer than that. A string is not a list in that regard, even though it is a list of characters.
+/// A value of an unknown type, e.g. a larger tuple, is written as `null`, as the instance for any value is overlapped
+/// by those of the known types.
+/// A character or string is reported as its text, unless `QUOTE_VALUES` is 1, in which case it is reported like `show`
+/// does. They are told apart from other values by casting, as `show` cannot be specialized for them.
+/// A `Rational` is reported as its decimal digits, e.g. `0.3` rather than `3 % 10`, if it has a finite expansion.
+///
+/// If `UNORDERED_LISTS` is 1, the actual and expected lists, including those among multiple output parameters, are
+/// sorted before they are checked, with `unordered`, so that the order of their elements does not matter. A string is
+/// never sorted, so a list of characters is compared in order. The values of a wrong answer are reported in their
+/// original order.
+///
+/// A test case that expects a runtime error reports the value the solution returned instead, with `reportReturned`,
+/// which evaluates it like a wrong answer, so that an error raised lazily while rendering it is still caught.
+///
+/// If `FAIL_FAST` is 1, the process exits right after the first outcome that is not a pass. It exits through the
+/// C library, as the exception thrown by `exitWith` would be caught as a runtime error of the test case.
 const HASKELL_TEST_RUNNER: &str = r###"
+{-# LANGUAGE FlexibleInstances #-}
 module TestRunner where
 
-testChecker actual expected = do
-  if actual == expected
-    then putStrLn "p"
-    else putStrLn ("f" ++ "," ++ show actual ++ "," ++ show expected)
+import Checker
+import Control.Exception
+import Control.Monad
+import Data.Char
+import Data.Int
+import Data.List
+import qualified Data.Map
+import Data.Ratio
+import Data.Typeable
+import Foreign.C.Types
+import GHC.IO.Handle
+import Numeric
+import System.IO
+
+display value
+  | QUOTE_VALUES == (0 :: Int), Just text <- cast value = text
+  | QUOTE_VALUES == (0 :: Int), Just c <- cast value = [c :: Char]
+  | Just decimal <- cast value = showDecimal decimal
+  | otherwise = show value
+
+showDecimal :: Rational -> String
+showDecimal value
+  | rest /= 1 = show value
+  | otherwise = sign ++ whole ++ (if places == 0 then "" else "." ++ fraction)
+  where
+    (twos, withoutTwos) = factorOut 2 (denominator value)
+    (fives, rest) = factorOut 5 withoutTwos
+    places = max twos fives
+    digits = show (abs (numerator value) * 10 ^ places `div` denominator value)
+    padded = replicate (places + 1 - length digits) '0' ++ digits
+    (whole, fraction) = splitAt (length padded - places) padded
+    sign = if value < 0 then "-" else ""
+
+factorOut :: Integer -> Integer -> (Int, Integer)
+factorOut factor n
+  | n `mod` factor == 0 = let (count, rest) = factorOut factor (n `div` factor) in (count + 1, rest)
+  | otherwise = (0, n)
+
+render value =
+  let text = display value
+  in if null (drop MAX_VALUE_LENGTH text)
+       then text
+       else take MAX_VALUE_LENGTH text ++ "TRUNCATED_VALUE_MARKER"
+
+jsonString text = "\"" ++ concatMap escape text ++ "\""
+  where
+    escape '"' = "\\\""
+    escape '\\' = "\\\\"
+    escape c
+      | ord c > 0xffff = let n = ord c - 0x10000 in unicode (0xd800 + div n 0x400) ++ unicode (0xdc00 + mod n 0x400)
+      | ord c < 0x20 || ord c > 0x7e = unicode (ord c)
+      | otherwise = [c]
+    unicode n = let hex = showHex n "" in "\\u" ++ replicate (4 - length hex) '0' ++ hex
+
+redirectStdin testCaseId = do
+  handle <- openFile ("STDIN_DIR/" ++ testCaseId) ReadMode
+  hDuplicateTo handle stdin
+  hClose handle
+
+reportOutcome outcome fields = do
+  let line = "{" ++ intercalate "," [jsonString key ++ ":" ++ value | (key, value) <- ("r", jsonString outcome) : fields] ++ "}"
+  evaluate (length line)
+  appendFile "RESULTS_FILE" (line ++ "\n")
+  hFlush stdout
+  when (FAIL_FAST /= (0 :: Int) && outcome `notElem` ["STARTED_OUTCOME", "ENDED_OUTCOME", "p"]) (exitProcess 0)
+
+foreign import ccall "exit" exitProcess :: CInt -> IO ()
+
+jsonArray elements = "[" ++ intercalate "," elements ++ "]"
+
+class ToJson a where
+  toJson :: a -> String
+  toJsonList :: [a] -> String
+  toJsonList values = jsonArray (map toJson values)
+  structured :: a -> Bool
+  structured _ = False
+  structuredList :: [a] -> Bool
+  structuredList _ = True
+
+instance {-# OVERLAPPABLE #-} ToJson a where
+  toJson _ = "null"
+
+instance ToJson Bool where
+  toJson value = if value then "true" else "false"
+
+instance ToJson Int where
+  toJson = show
+
+instance ToJson Int32 where
+  toJson = show
+
+instance ToJson Int64 where
+  toJson = show
+
+instance ToJson Integer where
+  toJson = show
+
+instance ToJson Double where
+  toJson value = if isNaN value || isInfinite value then "null" else show value
+
+instance ToJson Char where
+  toJson value = jsonString [value]
+  toJsonList = jsonString
+  structuredList _ = False
+
+instance ToJson a => ToJson [a] where
+  toJson = toJsonList
+  structured = structuredList
+
+instance ToJson () where
+  toJson () = "null"
+
+instance (ToJson a, ToJson b) => ToJson (a, b) where
+  toJson (a, b) = jsonArray [toJson a, toJson b]
+  structured _ = True
+
+instance (ToJson a, ToJson b, ToJson c) => ToJson (a, b, c) where
+  toJson (a, b, c) = jsonArray [toJson a, toJson b, toJson c]
+  structured _ = True
+
+instance (ToJson a, ToJson b, ToJson c, ToJson d) => ToJson (a, b, c, d) where
+  toJson (a, b, c, d) = jsonArray [toJson a, toJson b, toJson c, toJson d]
+  structured _ = True
+
+instance ToJson a => ToJson (Maybe a) where
+  toJson = maybe "null" toJson
+
+instance (ToJson k, ToJson v) => ToJson (Data.Map.Map k v) where
+  toJson value = "{" ++ intercalate "," [jsonKey (toJson k) ++ ":" ++ toJson v | (k, v) <- Data.Map.toList value] ++ "}"
+    where
+      jsonKey key = if "\"" `isPrefixOf` key then key else jsonString key
+  structured _ = True
+
+structuredValues actual expected
+  | structured expected = [(field, value) | (field, value) <- [("actualValue", toJson actual), ("expectedValue", toJson expected)], null (drop MAX_VALUE_LENGTH value)]
+  | otherwise = []
+
+class Unordered a where
+  unordered :: a -> a
+
+instance {-# OVERLAPPABLE #-} Unordered a where
+  unordered = id
+
+instance {-# OVERLAPPING #-} (Ord a, Typeable a) => Unordered [a] where
+  unordered values
+    | UNORDERED_LISTS == (0 :: Int) = values
+    | Just _ <- (cast values :: Maybe String) = values
+    | otherwise = sort values
+
+instance {-# OVERLAPPING #-} (Unordered a, Unordered b) => Unordered (a, b) where
+  unordered (a, b) = (unordered a, unordered b)
+
+instance {-# OVERLAPPING #-} (Unordered a, Unordered b, Unordered c) => Unordered (a, b, c) where
+  unordered (a, b, c) = (unordered a, unordered b, unordered c)
+
+instance {-# OVERLAPPING #-} (Unordered a, Unordered b, Unordered c, Unordered d) => Unordered (a, b, c, d) where
+  unordered (a, b, c, d) = (unordered a, unordered b, unordered c, unordered d)
+
+testChecker actual expected =
+  if checker (unordered actual) (unordered expected)
+    then reportOutcome "p" []
+    else reportOutcome "f" ([("actual", jsonString (render actual)), ("expected", jsonString (render expected))] ++ structuredValues actual expected)
+
+reportReturned actual = reportOutcome "v" [("actual", jsonString (render actual))]
+"###;
+
+/// The checker for Haskell used if the submission does not supply one, which accepts a value equal to the expected value.
+const HASKELL_DEFAULT_CHECKER: &str = r###"
+module Checker where
+
+checker actual expected = actual == expected
+"###;
+
+/// The checker for Haskell used if the submission supplies a float tolerance.
+///
+/// Any value is compared for equality, except for a `Double`, and the tuples of up to four multiple output parameters,
+/// the lists, the maps and the `Maybe`s whose elements are compared within the tolerance, as their instances overlap the
+/// one for any value.
+const HASKELL_TOLERANT_CHECKER: &str = r###"
+{-# LANGUAGE FlexibleInstances #-}
+{-# LANGUAGE UndecidableInstances #-}
+module Checker where
+
+import qualified Data.Map
+
+class WithinTolerance a where
+  withinTolerance :: a -> a -> Bool
+
+instance {-# OVERLAPPABLE #-} Eq a => WithinTolerance a where
+  withinTolerance = (==)
+
+instance {-# OVERLAPPING #-} WithinTolerance Double where
+  withinTolerance actual expected
+    | isNaN expected = MATCH_NAN /= (0 :: Int) && isNaN actual
+    | isInfinite expected = actual == expected
+    | otherwise = abs (actual - expected) <= max ABSOLUTE_TOLERANCE (RELATIVE_TOLERANCE * abs expected)
+
+instance {-# OVERLAPPING #-} (WithinTolerance a, WithinTolerance b) => WithinTolerance (a, b) where
+  withinTolerance (a1, b1) (a2, b2) = withinTolerance a1 a2 && withinTolerance b1 b2
+
+instance {-# OVERLAPPING #-} (WithinTolerance a, WithinTolerance b, WithinTolerance c) => WithinTolerance (a, b, c) where
+  withinTolerance (a1, b1, c1) (a2, b2, c2) = withinTolerance a1 a2 && withinTolerance b1 b2 && withinTolerance c1 c2
+
+instance {-# OVERLAPPING #-} (WithinTolerance a, WithinTolerance b, WithinTolerance c, WithinTolerance d) => WithinTolerance (a, b, c, d) where
+  withinTolerance (a1, b1, c1, d1) (a2, b2, c2, d2) =
+    withinTolerance a1 a2 && withinTolerance b1 b2 && withinTolerance c1 c2 && withinTolerance d1 d2
+
+instance {-# OVERLAPPING #-} WithinTolerance a => WithinTolerance [a] where
+  withinTolerance actual expected =
+    length actual == length expected && and (zipWith withinTolerance actual expected)
+
+instance {-# OVERLAPPING #-} WithinTolerance a => WithinTolerance (Maybe a) where
+  withinTolerance (Just actual) (Just expected) = withinTolerance actual expected
+  withinTolerance actual expected = null actual && null expected
+
+instance {-# OVERLAPPING #-} (Eq k, WithinTolerance v) => WithinTolerance (Data.Map.Map k v) where
+  withinTolerance actual expected =
+    Data.Map.keys actual == Data.Map.keys expected
+      && and (zipWith withinTolerance (Data.Map.elems actual) (Data.Map.elems expected))
+
+checker actual expected = withinTolerance actual expected
+"###;
+
+/// The reference module for Haskell used if the submission does not supply a reference solution.
+const HASKELL_DEFAULT_REFERENCE: &str = r###"
+module Reference where
 "###;
 
 /// The exception handling code snippet for Haskell.
 ///
 /// The `TEST_CASE` is being replace with a call to the actual test case.
 /// This is done for all test cases.
+///
+/// The `STARTED_OUTCOME` and `TEST_CASE_ID` are replaced to form the started marker of the test case,
+/// which is flushed such that it is part of the output even if the execution is killed,
+/// and the `ENDED_OUTCOME` and `TEST_CASE_ID` to form its ended marker, which delimits its results.
+///
+/// The `TEST_CASE_TIMEOUT` is replaced with the time in microseconds the test case may run for.
 const HASKELL_EXCEPTION_SNIPPET: &str = r###"
-  catch (TEST_CASE) (\(e :: SomeException) -> putStrLn ("r" ++ "," ++ intercalate "\\n" (lines (show e))))
+  reportOutcome "STARTED_OUTCOME" [("id", "TEST_CASE_ID")]
+  redirectStdin "TEST_CASE_ID"
+  catch (timeout TEST_CASE_TIMEOUT (TEST_CASE) >>= maybe (reportOutcome "t" []) return) (\(e :: SomeException) -> reportOutcome "err" [("msg", jsonString (show e))])
+  reportOutcome "ENDED_OUTCOME" [("id", "TEST_CASE_ID")]
 "###;
 
 /// The language handler for Haskell.
 pub struct Haskell {
     /// A path buffer to the current working directory of a given request.
     temp_dir: PathBuf,
+
+    /// The configuration the submission is checked with.
+    config: Arc<Config>,
+
+    /// The test runner code, with the truncation of reported values inserted.
+    test_runner_code: String,
 }
 
 impl Haskell {
-    async fn compile(&self, args: &[&str]) -> Result<(), SubmissionError> {
+    /// Gets the path to the executable the test code is compiled into.
+    fn executable_path(&self) -> PathBuf {
+        let mut executable_path = self.temp_dir.clone();
+        executable_path.push("test");
+        executable_path
+    }
+
+    /// Compiles with the `toolchain`, e.g. `ghc`, passing it the `args` followed by the `compiler_flags` of the submission.
+    ///
+    /// The flags are passed to every compilation, as `ghc` recompiles a module whose flags changed.
+    async fn compile_with(
+        &self,
+        toolchain: &str,
+        args: &[&str],
+        compiler_flags: &[String],
+    ) -> Result<(), SubmissionError> {
         info!("spawning compilation process");
-        let compile_process = Command::new("ghc")
+        let compile_process = Command::new(toolchain)
             .args(args)
+            .args(compiler_flags)
             .arg("-O2") // best optimization level for fast vs. safe trade-off
+            .arg("-fno-omit-yields") // makes non-allocating loops interruptible by the test case timeout
             .stdin(Stdio::piped())
             .stdout(Stdio::piped())
             .stderr(Stdio::piped())
             .spawn();
         let compile_handle = match compile_process {
             Ok(ch) => ch,
-            Err(err) => {
-                error!("could not spawn compile process: {}", err);
-                return Err(SubmissionError::Internal);
-            }
+            Err(err) => return Err(spawn_failure(toolchain, &err)),
         };
 
         info!("starting timeout of compilation process");
         let (compile_exit_status, compile_output) =
-            match timeout_process(TIMEOUT, compile_handle).await? {
+            match timeout_process(self.config.timeout, compile_handle).await? {
                 Some((ces, co)) => (ces, co),
                 None => {
                     error!(
                         "compilation process exceeded allowed time limit of {:?}",
-                        TIMEOUT
+                        self.config.timeout
                     );
-                    return Err(SubmissionError::CompileTimeout(TIMEOUT));
+                    return Err(SubmissionError::CompileTimeout(self.config.timeout));
                 }
             };
 
         info!("checking compilation exit status");
-        match compile_exit_status
-            .code()
-            .expect("ghc should always return exit status code")
-        {
-            // 0 means success
-            0 => {
-                info!("no compile errors");
-                // if we want to return warnings from successful compilations
-                // then this is the place to check stderr
-            }
-            // 1 means compilation error
-            1 => {
-                info!("compile error");
-                let stderr = String::from_utf8_lossy(&compile_output.stderr);
-                let stripped = remove_mozart_path(&stderr, self.temp_dir.clone());
-
-                debug!("compile error: {}", stripped);
-                return Err(SubmissionError::Compilation(stripped));
+        if compile_exit_status.success() {
+            info!("no compile errors");
+            // if we want to return warnings from successful compilations
+            // then this is the place to check stderr
+            return Ok(());
+        }
+
+        let stderr = String::from_utf8_lossy(&compile_output.stderr);
+        Err(compilation_failure(
+            compile_exit_status,
+            &remove_mozart_path(&stderr),
+        ))
+    }
+}
+
+/// Determines the error of a compilation that exited with the unsuccessful `status`, using its `stderr`.
+///
+/// Exit status 1 is an ordinary compilation error.
+/// Any other status, e.g. a GHC panic or running out of memory, is also reported as a compilation error
+/// if GHC explained it in stderr, as it is most likely caused by the solution and the user benefits from the message.
+/// If nothing was written to stderr, there is nothing to report, and it is an internal error.
+fn compilation_failure(status: ExitStatus, stderr: &str) -> SubmissionError {
+    if status.code() == Some(1) {
+        info!("compile error");
+        debug!("compile error: {}", stderr);
+        let diagnostics = parse_compilation_diagnostics(stderr);
+        return SubmissionError::Compilation(stderr.to_string(), diagnostics);
+    }
+
+    if stderr.trim().is_empty() {
+        error!(
+            "compilation terminated abnormally ({}) without output",
+            status
+        );
+        return SubmissionError::Internal;
+    }
+
+    warn!("compilation terminated abnormally ({})", status);
+    debug!("compile error: {}", stderr);
+    SubmissionError::Compilation(
+        format!("the compiler terminated abnormally ({status}): {stderr}"),
+        Box::new([]),
+    )
+}
+
+/// Extracts the location and message of each error in the output of a failed GHC compilation.
+///
+/// An error starts with a header line like `Solution.hs:7:5: error: [GHC-88464]`, where the location may also be a
+/// span like `Solution.hs:7:5-10` or `Solution.hs:(7,5)-(8,10)`, and is followed by the indented message lines.
+/// The source code excerpt GHC appends to the message is not included.
+///
+/// Errors whose header cannot be parsed are skipped, meaning that an empty slice is returned if nothing could be extracted.
+fn parse_compilation_diagnostics(stderr: &str) -> Box<[CompilationDiagnostic]> {
+    let mut diagnostics = Vec::new();
+    let mut lines = stderr.lines().peekable();
+
+    while let Some(line) = lines.next() {
+        let Some((location, header_message)) = line.split_once(": error:") else {
+            continue;
+        };
+        let Some((line_number, column)) = parse_location(location) else {
+            continue;
+        };
+
+        let mut message_lines = Vec::new();
+        let header_message = header_message.trim();
+        // the header may only contain the error code, e.g. `[GHC-88464]`, which is not part of the message
+        if !header_message.is_empty() && !header_message.starts_with("[GHC-") {
+            message_lines.push(header_message);
+        }
+
+        while let Some(next) = lines.next_if(|l| l.starts_with(char::is_whitespace)) {
+            let next = next.trim();
+            if !next.is_empty() && !next.starts_with('|') {
+                message_lines.push(next);
             }
-            unknown => {
-                error!(
-                    "compilation returned unexpected exit status '{:?}'",
-                    unknown
-                );
-                return Err(SubmissionError::Internal);
+        }
+
+        diagnostics.push(CompilationDiagnostic {
+            line: line_number,
+            column,
+            message: message_lines.join("\n"),
+        });
+    }
+
+    diagnostics.into_boxed_slice()
+}
+
+/// Parses the line and column of a GHC error location, e.g. `Solution.hs:7:5`.
+fn parse_location(location: &str) -> Option<(u32, u32)> {
+    let (_, position) = location.split_once(".hs:")?;
+
+    let (line, column) = match position.strip_prefix('(') {
+        // a multiline span like `(7,5)-(8,10)`
+        Some(span) => span.split_once(')')?.0.split_once(',')?,
+        // a single position like `7:5`, or a span on a single line like `7:5-10`
+        None => {
+            let (line, columns) = position.split_once(':')?;
+            (line, columns.split('-').next()?)
+        }
+    };
+
+    Some((line.parse().ok()?, column.parse().ok()?))
+}
+
+/// Gets the name of the module the Haskell `source` is declared as, if it starts with a module declaration.
+///
+/// Whitespace, comments and pragmas before the declaration are skipped.
+fn declared_module(source: &str) -> Option<&str> {
+    let mut rest = source;
+    loop {
+        rest = rest.trim_start();
+        if let Some(comment) = rest.strip_prefix("--") {
+            rest = comment.split_once('\n').map_or("", |(_, after)| after);
+        } else if rest.starts_with("{-") {
+            rest = skip_block_comment(rest)?;
+        } else {
+            break;
+        }
+    }
+
+    let declaration = rest.strip_prefix("module")?;
+    if !declaration.starts_with(char::is_whitespace) {
+        return None;
+    }
+
+    declaration
+        .trim_start()
+        .split(|c: char| c.is_whitespace() || c == '(')
+        .next()
+        .filter(|name| !name.is_empty())
+}
+
+/// Gets the number of arguments the `solution` declares in the type signature of `solution`, i.e. the number of arrows
+/// in the type that are not nested inside parentheses or brackets, ignoring its context.
+///
+/// Returns `None` if there is no type signature, or if its type has no arrows, as it may be a synonym of a function.
+fn declared_arity(solution: &str) -> Option<usize> {
+    let mut lines = solution.lines();
+    let first_line = lines.find_map(|line| {
+        line.strip_prefix("solution")?
+            .trim_start()
+            .strip_prefix("::")
+    })?;
+    // the signature may continue on the indented lines that follow it
+    let signature = iter::once(first_line)
+        .chain(lines.take_while(|line| line.starts_with(char::is_whitespace)))
+        .map(|line| line.split("--").next().unwrap_or_default())
+        .collect::<Vec<_>>()
+        .join(" ");
+
+    let mut depth = 0;
+    let mut arrows = 0;
+    let mut previous = ' ';
+    for c in signature.chars() {
+        match c {
+            '(' | '[' => depth += 1,
+            ')' | ']' => depth -= 1,
+            '>' if depth == 0 && previous == '-' => arrows += 1,
+            // the arrows of the context, e.g. of a functional dependency, are not arguments
+            '>' if depth == 0 && previous == '=' => arrows = 0,
+            _ => {}
+        }
+        previous = c;
+    }
+
+    (arrows > 0).then_some(arrows)
+}
+
+/// Skips the possibly nested block comment that `source` starts with, returning what follows it.
+///
+/// Returns `None` if the block comment is not closed.
+fn skip_block_comment(source: &str) -> Option<&str> {
+    let mut depth = 0;
+    let mut rest = source;
+    loop {
+        if let Some(after) = rest.strip_prefix("{-") {
+            depth += 1;
+            rest = after;
+        } else if let Some(after) = rest.strip_prefix("-}") {
+            depth -= 1;
+            rest = after;
+            if depth == 0 {
+                return Some(rest);
             }
+        } else {
+            let mut chars = rest.chars();
+            chars.next()?;
+            rest = chars.as_str();
         }
-        Ok(())
     }
 }
 
+/// Escapes the characters of `value` that cannot appear verbatim inside a Haskell character literal.
+///
+/// A character outside of printable ASCII is written as its hexadecimal code point, e.g. `\x1f680`,
+/// which is a single Haskell `Char` no matter how many bytes it is encoded as.
+fn escape_char(value: &str) -> String {
+    value
+        .chars()
+        .map(|c| match c {
+            '\'' => String::from("\\'"),
+            '\\' => String::from("\\\\"),
+            '\n' => String::from("\\n"),
+            '\t' => String::from("\\t"),
+            '\r' => String::from("\\r"),
+            c if c.is_control() || !c.is_ascii() => format!("\\x{:x}", c as u32),
+            c => c.to_string(),
+        })
+        .collect()
+}
+
+/// Escapes the characters of `value` that cannot appear verbatim inside a Haskell string literal.
+///
+/// A character outside of printable ASCII is written as its hexadecimal code point followed by the empty escape `\&`,
+/// such that a digit after it is not consumed as part of the code point.
+fn escape_string(value: &str) -> String {
+    value
+        .chars()
+        .map(|c| match c {
+            '"' => String::from("\\\""),
+            '\\' => String::from("\\\\"),
+            '\n' => String::from("\\n"),
+            '\t' => String::from("\\t"),
+            '\r' => String::from("\\r"),
+            c if c.is_control() || !c.is_ascii() => format!("\\x{:x}\\&", c as u32),
+            c => c.to_string(),
+        })
+        .collect()
+}
+
 impl LanguageHandler for Haskell {
-    fn new(temp_dir: PathBuf) -> Self {
-        Self { temp_dir }
+    fn new(temp_dir: PathBuf, config: Arc<Config>) -> Self {
+        Self {
+            temp_dir,
+            test_runner_code: insert_test_runner_constants(HASKELL_TEST_RUNNER, &config),
+            config,
+        }
     }
 
     fn test_file_path(&self) -> PathBuf {
@@ -142,14 +652,63 @@ impl LanguageHandler for Haskell {
         path
     }
 
+    fn checker_file_path(&self) -> PathBuf {
+        let mut path = self.temp_dir.clone();
+        path.push("Checker.hs");
+
+        path
+    }
+
+    fn default_checker_code(&self) -> &str {
+        HASKELL_DEFAULT_CHECKER
+    }
+
+    fn tolerant_checker_code(&self) -> &str {
+        HASKELL_TOLERANT_CHECKER
+    }
+
+    fn reference_file_path(&self) -> PathBuf {
+        let mut path = self.temp_dir.clone();
+        path.push("Reference.hs");
+
+        path
+    }
+
+    fn reference_code(&self, reference_solution: Option<&str>) -> String {
+        reference_solution
+            .unwrap_or(HASKELL_DEFAULT_REFERENCE)
+            .to_string()
+    }
+
     fn test_runner_code(&self) -> &str {
-        HASKELL_TEST_RUNNER
+        &self.test_runner_code
     }
 
-    fn generate_test_cases(&self, test_cases: &[TestCase]) -> String {
-        let mut generated_test_cases = Vec::with_capacity(test_cases.len());
+    fn validate_solution(&self, solution: &str) -> Result<(), SubmissionError> {
+        match declared_module(solution) {
+            Some(SOLUTION_MODULE) => Ok(()),
+            Some(name) => {
+                info!("solution is declared as module '{}'", name);
+                Err(SubmissionError::InvalidSolution(format!(
+                    "the solution must be declared as `module {SOLUTION_MODULE} where`, not as module `{name}`"
+                )))
+            }
+            None => {
+                info!("solution has no module declaration");
+                Err(SubmissionError::InvalidSolution(format!(
+                    "the solution must start with `module {SOLUTION_MODULE} where`"
+                )))
+            }
+        }
+    }
 
-        for test_case in test_cases {
+    fn generate_test_cases(
+        &self,
+        test_code: &mut String,
+        test_cases: &[TestCase],
+        with_reference: bool,
+    ) {
+        for (index, test_case) in test_cases.iter().enumerate() {
             let formatted_input_parameters = test_case
                 .input_parameters
                 .iter()
@@ -157,29 +716,91 @@ impl LanguageHandler for Haskell {
                 .collect::<Vec<String>>()
                 .join(" ");
 
-            let formatted_output_parameters = test_case
-                .output_parameters
-                .iter()
-                .map(|op| self.format_parameter(op))
-                .collect::<Vec<String>>()
-                .join(",");
+            let formatted_output_parameters =
+                if with_reference && test_case.output_parameters.is_empty() {
+                    format!("Reference.solution {formatted_input_parameters}")
+                } else {
+                    test_case
+                        .output_parameters
+                        .iter()
+                        .map(|op| self.format_parameter(op))
+                        .collect::<Vec<String>>()
+                        .join(",")
+                };
 
-            let test_case = format!(
-                "testChecker (solution {formatted_input_parameters}) ({formatted_output_parameters})"
-            );
-            let generated_test_case = HASKELL_EXCEPTION_SNIPPET.replace("TEST_CASE", &test_case);
-            generated_test_cases.push(generated_test_case);
+            let test_case_call = if test_case.expected_error.is_some() {
+                format!("reportReturned (solution {formatted_input_parameters})")
+            } else {
+                format!(
+                    "testChecker (solution {formatted_input_parameters}) ({formatted_output_parameters})"
+                )
+            };
+            let generated_test_case = HASKELL_EXCEPTION_SNIPPET
+                .replace("STARTED_OUTCOME", STARTED_OUTCOME)
+                .replace("ENDED_OUTCOME", ENDED_OUTCOME)
+                .replace(TEST_CASE_ID_TARGET, &test_case.id.to_string())
+                .replace(
+                    "TEST_CASE_TIMEOUT",
+                    &self.config.test_case_timeout.as_micros().to_string(),
+                )
+                .replace("TEST_CASE", &test_case_call);
+            if index > 0 {
+                test_code.push('\n');
+            }
+            test_code.push_str(&generated_test_case);
         }
-
-        generated_test_cases.join("\n")
     }
 
     fn format_parameter(&self, parameter: &Parameter) -> String {
-        match parameter.value_type {
+        match &parameter.value_type {
             ParameterType::Int => format!("({} :: Int)", parameter.value),
-            ParameterType::Float => format!("({} :: Double)", parameter.value),
-            ParameterType::Char => format!("('{}' :: Char)", parameter.value),
-            ParameterType::String => format!(r#"("{}" :: String)"#, parameter.value),
+            ParameterType::Int32 => format!("({} :: Int32)", parameter.value),
+            ParameterType::Int64 => format!("({} :: Int64)", parameter.value),
+            ParameterType::BigInt => format!("({} :: Integer)", parameter.value),
+            ParameterType::Float => format!(
+                "({} :: Double)",
+                format_float(&parameter.value, "(0 / 0)", "(1 / 0)")
+            ),
+            // a fractional literal is an exact rational, e.g. `0.1` is `1 % 10`
+            ParameterType::Decimal => format!("({} :: Rational)", parameter.value),
+            ParameterType::Char => format!("('{}' :: Char)", escape_char(&parameter.value)),
+            ParameterType::String => {
+                format!(r#"("{}" :: String)"#, escape_string(&parameter.value))
+            }
+            ParameterType::Unit => String::from("()"),
+            // the type of an empty list is inferred from the solution, like that of an empty map
+            ParameterType::List(_) => {
+                let elements = parameter
+                    .elements()
+                    .expect("the value of a list should have been validated")
+                    .iter()
+                    .map(|element| self.format_parameter(element))
+                    .collect::<Vec<_>>();
+                format!("[{}]", elements.join(", "))
+            }
+            // the type of an absent value is inferred from the solution, like that of an empty map
+            ParameterType::Optional(_) => match parameter
+                .optional_value()
+                .expect("the value of an optional should have been validated")
+            {
+                Some(value) => format!("(Just {})", self.format_parameter(&value)),
+                None => String::from("Nothing"),
+            },
+            ParameterType::Map { .. } => {
+                let entries = parameter
+                    .entries()
+                    .expect("the value of a map should have been validated")
+                    .iter()
+                    .map(|(key, value)| {
+                        format!(
+                            "({}, {})",
+                            self.format_parameter(key),
+                            self.format_parameter(value)
+                        )
+                    })
+                    .collect::<Vec<_>>();
+                format!("(Data.Map.fromList [{}])", entries.join(", "))
+            }
             ParameterType::Bool => {
                 let mut chars = parameter.value.chars();
                 match chars.next() {
@@ -195,49 +816,140 @@ impl LanguageHandler for Haskell {
         }
     }
 
-    async fn run(&self) -> Result<String, SubmissionError> {
+    /// A compilation error of the test code, which calls the solution, is explained if the type signature of the
+    /// solution declares another number of arguments, as GHC reports it as a type error of the generated code.
+    fn explain_arity_mismatch(
+        &self,
+        err: SubmissionError,
+        solution: &str,
+        arguments: usize,
+    ) -> SubmissionError {
+        let SubmissionError::Compilation(stderr, _) = &err else {
+            return err;
+        };
+        let test_file_name = self.test_file_path();
+        let test_file_name = test_file_name
+            .file_name()
+            .and_then(|name| name.to_str())
+            .expect(UUID_SHOULD_BE_VALID_STR);
+        // the solution compiled on its own, so an error of the test code involving it is most likely a mismatch
+        if !stderr.contains(&format!("{test_file_name}:")) || !stderr.contains("solution") {
+            return err;
+        }
+
+        match declared_arity(solution) {
+            Some(expected) if expected != arguments => {
+                info!(
+                    "solution declares {} arguments, but the test cases provide {}",
+                    expected, arguments
+                );
+                let noun = if expected == 1 {
+                    "argument"
+                } else {
+                    "arguments"
+                };
+                SubmissionError::Compilation(
+                    format!(
+                        "solution expects {expected} {noun} but test case provides {arguments}"
+                    ),
+                    Box::new([]),
+                )
+            }
+            _ => err,
+        }
+    }
+
+    async fn compile(
+        &self,
+        toolchain: &str,
+        compiler_flags: &[String],
+    ) -> Result<(), SubmissionError> {
         info!("compiling solution");
         let solution_file_path = self.solution_file_path();
         let solution_file_str = solution_file_path.to_str().expect(UUID_SHOULD_BE_VALID_STR);
-        self.compile(&[solution_file_str]).await?;
+        let base_path = self
+            .temp_dir
+            .as_path()
+            .to_str()
+            .expect(UUID_SHOULD_BE_VALID_STR);
+        let import_path = &format!("-i{base_path}");
+        let mut solution_args = vec![
+            solution_file_str, // the absolute path of Solution.hs
+            import_path,       // where to look for modules supplied alongside the solution
+        ];
+        // only the solution is compiled with it, as the warnings of the generated code are not the fault of the user
+        if self.config.warnings_as_errors {
+            solution_args.push("-Werror");
+        }
+        self.compile_with(toolchain, &solution_args, compiler_flags)
+            .await?;
+
+        info!("compiling checker");
+        let checker_file_path = self.checker_file_path();
+        let checker_file_str = checker_file_path.to_str().expect(UUID_SHOULD_BE_VALID_STR);
+        // the error of a checker supplied by the submission is reported like that of the solution
+        self.compile_with(toolchain, &[checker_file_str, import_path], compiler_flags)
+            .await?;
 
         info!("compiling test runner");
         let test_runner_file_path = self.test_runner_file_path();
         let test_runner_file_str = test_runner_file_path
             .to_str()
             .expect(UUID_SHOULD_BE_VALID_STR);
-        if self.compile(&[test_runner_file_str]).await.is_err() {
+        if self
+            .compile_with(
+                toolchain,
+                &[test_runner_file_str, import_path],
+                compiler_flags,
+            )
+            .await
+            .is_err()
+        {
             return Err(SubmissionError::Internal);
         }
 
         info!("compiling test code");
-        let mut executable_path = self.temp_dir.clone();
-        executable_path.push("test");
+        let executable_path = self.executable_path();
         let executable_str = executable_path.to_str().expect(UUID_SHOULD_BE_VALID_STR);
         let test_file_path = self.test_file_path();
         let test_file_str = test_file_path.to_str().expect(UUID_SHOULD_BE_VALID_STR);
-        let base_path = self
-            .temp_dir
-            .as_path()
-            .to_str()
-            .expect(UUID_SHOULD_BE_VALID_STR);
+        self.compile_with(
+            toolchain,
+            &[
+                "-o",           // flag to set the output path
+                executable_str, // the path to output executable
+                test_file_str,  // the absolute path of Main.hs
+                import_path,    // where to look for Solution and TestRunner modules
+            ],
+            compiler_flags,
+        )
+        .await
+    }
 
-        let import_path = &format!("-i{base_path}");
-        self.compile(&[
-            "-o",           // flag to set the output path
-            executable_str, // the path to output executable
-            test_file_str,  // the absolute path of Main.hs
-            import_path,    // where to look for Solution and TestRunner modules
-        ])
-        .await?;
+    async fn execute(
+        &self,
+        _toolchain: &str,
+        _compiler_flags: &[String],
+        slot: usize,
+    ) -> Result<(TestOutput, String), SubmissionError> {
+        let executable_path = self.executable_path();
 
         info!("spawning execution process");
-        let execution_process = Command::new(executable_path)
-            .stdin(Stdio::piped())
-            .stdout(Stdio::piped())
-            .stderr(Stdio::piped())
-            .uid(*RESTRICTED_USER_ID)
-            .spawn();
+        let execution_process = jail(
+            restrict_resources(
+                restrict_environment(
+                    Command::new(jailed_path(&executable_path, &self.temp_dir))
+                        .stdin(Stdio::piped())
+                        .stdout(Stdio::piped())
+                        .stderr(Stdio::piped()),
+                    &self.config.execution_env_vars,
+                ),
+                self.config.max_file_size,
+            ),
+            &self.temp_dir,
+            slot,
+        )
+        .and_then(Command::spawn);
         let execution_handle = match execution_process {
             Ok(eh) => eh,
             Err(err) => {
@@ -247,109 +959,491 @@ impl LanguageHandler for Haskell {
         };
 
         info!("starting execution process timeout");
-        match timeout_process(TIMEOUT, execution_handle).await? {
-            Some((es, output)) => {
+        match run_process(
+            self.config.timeout,
+            self.config.kill_grace_period,
+            execution_handle,
+        )
+        .await?
+        {
+            ProcessOutcome::Exited(output) => {
+                let es = output.status;
                 info!(?es);
                 info!("stdout: {}", String::from_utf8_lossy(&output.stdout));
                 info!("stderr: {}", String::from_utf8_lossy(&output.stderr));
+                let test_output = read_test_output(&self.temp_dir).await?;
                 let stdout = String::from_utf8_lossy(&output.stdout).to_string();
-                let stripped = remove_mozart_path(&stdout, self.temp_dir.clone());
 
-                Ok(stripped)
+                // exceptions of the test cases are caught, so without any output the process crashed before the first
+                // test case was started, in which case the output would otherwise be reported as an internal error
+                if !es.success() && !has_started_test_case(&test_output) {
+                    info!("execution process crashed without output");
+                    return Err(SubmissionError::Execution(crash_error(&output)));
+                }
+
+                if !es.success() {
+                    info!("execution process crashed");
+                    return Ok((
+                        TestOutput::Crashed(test_output, crash_error(&output)),
+                        stdout,
+                    ));
+                }
+
+                Ok((TestOutput::Finished(test_output), stdout))
             }
-            None => {
+            ProcessOutcome::TimedOut(output) => {
                 error!(
                     "execution process exceeded allowed time limit of {:?}",
-                    TIMEOUT
+                    self.config.timeout
                 );
-                Err(SubmissionError::ExecuteTimeout(TIMEOUT))
+                let test_output = read_test_output(&self.temp_dir).await?;
+                let stdout = String::from_utf8_lossy(&output.stdout).to_string();
+
+                Ok((TestOutput::TimedOut(test_output), stdout))
             }
         }
     }
 }
 
 #[cfg(test)]
-mod format_parameter {
-    use super::Haskell;
-    use crate::{
-        model::{Parameter, ParameterType},
-        runner::LanguageHandler,
-    };
-    use std::path::PathBuf;
+mod parse_compilation_diagnostics {
+    use super::parse_compilation_diagnostics;
+    use crate::model::CompilationDiagnostic;
 
     #[test]
-    fn bool_false() {
-        let haskell = Haskell::new(PathBuf::new());
-        let input = Parameter {
-            value_type: ParameterType::Bool,
-            value: String::from("false"),
-        };
-        let expected = String::from("(False :: Bool)");
+    fn variable_not_in_scope() {
+        let input = [
+            "[1 of 1] Compiling Solution         ( Solution.hs, Solution.o )",
+            "",
+            "Solution.hs:4:14: error: [GHC-88464]",
+            "    Variable not in scope: y :: Int",
+            "  |",
+            "4 | solution x = y",
+            "  |              ^",
+        ]
+        .join("\n");
+        let expected = Box::new([CompilationDiagnostic {
+            line: 4,
+            column: 14,
+            message: String::from("Variable not in scope: y :: Int"),
+        }]);
 
-        let actual = haskell.format_parameter(&input);
+        let actual = parse_compilation_diagnostics(&input);
 
-        assert_eq!(actual, expected);
+        assert_eq!(*actual, *expected);
     }
 
     #[test]
-    fn bool_true() {
-        let haskell = Haskell::new(PathBuf::new());
-        let input = Parameter {
-            value_type: ParameterType::Bool,
-            value: String::from("true"),
-        };
-        let expected = String::from("(True :: Bool)");
+    fn multiple_errors_with_spans() {
+        let input = [
+            "Solution.hs:3:12-17: error: [GHC-83865]",
+            "    \u{2022} Couldn't match expected type \u{2018}Int\u{2019} with actual type \u{2018}Bool\u{2019}",
+            "    \u{2022} In the expression: True",
+            "  |",
+            "3 | solution x = True",
+            "  |              ^^^^",
+            "",
+            "Solution.hs:(5,1)-(6,10): error: [GHC-62161]",
+            "    Pattern match(es) are non-exhaustive",
+        ]
+        .join("\n");
+        let expected = Box::new([
+            CompilationDiagnostic {
+                line: 3,
+                column: 12,
+                message: String::from(
+                    "\u{2022} Couldn't match expected type \u{2018}Int\u{2019} with actual type \u{2018}Bool\u{2019}\n\u{2022} In the expression: True",
+                ),
+            },
+            CompilationDiagnostic {
+                line: 5,
+                column: 1,
+                message: String::from("Pattern match(es) are non-exhaustive"),
+            },
+        ]);
 
-        let actual = haskell.format_parameter(&input);
+        let actual = parse_compilation_diagnostics(&input);
 
-        assert_eq!(actual, expected);
+        assert_eq!(*actual, *expected);
     }
 
     #[test]
-    fn int_positive() {
-        let haskell = Haskell::new(PathBuf::new());
-        let input = Parameter {
-            value_type: ParameterType::Int,
-            value: String::from("100"),
-        };
-        let expected = String::from("(100 :: Int)");
+    fn warning_as_error() {
+        let input = [
+            "Solution.hs:5:1: error: [GHC-53633] [-Woverlapping-patterns, Werror=overlapping-patterns]",
+            "    Pattern match is redundant",
+            "    In an equation for \u{2018}solution\u{2019}: solution _ = ...",
+            "  |",
+            "5 | solution _ = 0",
+            "  | ^^^^^^^^^^^^^^",
+            "",
+            "<no location info>: error: [GHC-95874]",
+            "Failing due to -Werror.",
+        ]
+        .join("\n");
+        let expected = Box::new([CompilationDiagnostic {
+            line: 5,
+            column: 1,
+            message: String::from(
+                "Pattern match is redundant\nIn an equation for \u{2018}solution\u{2019}: solution _ = ...",
+            ),
+        }]);
 
-        let actual = haskell.format_parameter(&input);
+        let actual = parse_compilation_diagnostics(&input);
 
-        assert_eq!(actual, expected);
+        assert_eq!(*actual, *expected);
     }
 
     #[test]
-    fn int_negative() {
-        let haskell = Haskell::new(PathBuf::new());
-        let input = Parameter {
-            value_type: ParameterType::Int,
-            value: String::from("-100"),
-        };
-        let expected = String::from("(-100 :: Int)");
+    fn message_on_header_line() {
+        let input = "Solution.hs:6:1: error: parse error (possibly incorrect indentation or mismatched brackets)";
+        let expected = Box::new([CompilationDiagnostic {
+            line: 6,
+            column: 1,
+            message: String::from(
+                "parse error (possibly incorrect indentation or mismatched brackets)",
+            ),
+        }]);
 
-        let actual = haskell.format_parameter(&input);
+        let actual = parse_compilation_diagnostics(input);
 
-        assert_eq!(actual, expected);
+        assert_eq!(*actual, *expected);
     }
 
     #[test]
-    fn float_positive() {
-        let haskell = Haskell::new(PathBuf::new());
-        let input = Parameter {
-            value_type: ParameterType::Float,
-            value: String::from("10.0"),
-        };
-        let expected = String::from("(10.0 :: Double)");
+    fn unparseable_output() {
+        let input = "ghc: panic! (the 'impossible' happened)";
 
-        let actual = haskell.format_parameter(&input);
+        let actual = parse_compilation_diagnostics(input);
 
-        assert_eq!(actual, expected);
+        assert!(actual.is_empty());
+    }
+}
+
+#[cfg(test)]
+mod compilation_failure {
+    use super::compilation_failure;
+    use crate::error::SubmissionError;
+    use std::{os::unix::process::ExitStatusExt, process::ExitStatus};
+
+    #[test]
+    fn compile_error() {
+        let status = ExitStatus::from_raw(1 << 8);
+        let stderr = "Solution.hs:4:14: error: [GHC-88464]\n    Variable not in scope: y :: Int";
+
+        let actual = compilation_failure(status, stderr);
+
+        assert!(
+            matches!(actual, SubmissionError::Compilation(message, diagnostics) if message == stderr && diagnostics.len() == 1)
+        );
+    }
+
+    #[test]
+    fn panic() {
+        let status = ExitStatus::from_raw(2 << 8);
+        let stderr = "ghc: panic! (the 'impossible' happened)";
+        let expected = SubmissionError::Compilation(
+            String::from(
+                "the compiler terminated abnormally (exit status: 2): ghc: panic! (the 'impossible' happened)",
+            ),
+            Box::new([]),
+        );
+
+        let actual = compilation_failure(status, stderr);
+
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn killed_without_output() {
+        let status = ExitStatus::from_raw(libc::SIGKILL);
+
+        let actual = compilation_failure(status, "");
+
+        assert_eq!(actual, SubmissionError::Internal);
+    }
+}
+
+#[cfg(test)]
+mod declared_arity {
+    use super::declared_arity;
+
+    #[test]
+    fn single_argument() {
+        let input = "module Solution where\n\nsolution :: Int -> Int\nsolution x = x";
+        let expected = Some(1);
+
+        let actual = declared_arity(input);
+
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn nested_function_types() {
+        let input = "module Solution where\n\nsolution :: (Int -> Int) -> [Int -> Int] -> Int\nsolution f fs = 0";
+        let expected = Some(2);
+
+        let actual = declared_arity(input);
+
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn context() {
+        let input =
+            "module Solution where\n\nsolution :: (Num a, Ord a) => a -> a -> a\nsolution = max";
+        let expected = Some(2);
+
+        let actual = declared_arity(input);
+
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn multiline_signature_with_comments() {
+        let input = "module Solution where\n\nsolution :: Int -- the first\n         -> Int -- the second\n         -> Int\nsolution x y = x + y";
+        let expected = Some(2);
+
+        let actual = declared_arity(input);
+
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn without_signature() {
+        let input = "module Solution where\n\nsolution x = x";
+        let expected = None;
+
+        let actual = declared_arity(input);
+
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn without_arrows() {
+        let input = "module Solution where\n\ntype Binary = Int -> Int -> Int\n\nsolution :: Binary\nsolution = (+)";
+        let expected = None;
+
+        let actual = declared_arity(input);
+
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn other_function_with_prefix() {
+        let input = "module Solution where\n\nsolutions :: Int -> [Int]\nsolutions x = [x]";
+        let expected = None;
+
+        let actual = declared_arity(input);
+
+        assert_eq!(actual, expected);
+    }
+}
+
+#[cfg(test)]
+mod declared_module {
+    use super::declared_module;
+
+    #[test]
+    fn declaration() {
+        let input = "module Solution where\n\nsolution x = x";
+        let expected = Some("Solution");
+
+        let actual = declared_module(input);
+
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn declaration_with_exports() {
+        let input = "module Solution(solution) where";
+        let expected = Some("Solution");
+
+        let actual = declared_module(input);
+
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn comments_and_pragmas_before_declaration() {
+        let input = "{-# LANGUAGE LambdaCase #-}\n-- a comment\n{- a {- nested -} comment -}\nmodule Main where";
+        let expected = Some("Main");
+
+        let actual = declared_module(input);
+
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn missing_declaration() {
+        let input = "solution :: Int -> Int\nsolution x = x";
+        let expected = None;
+
+        let actual = declared_module(input);
+
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn unclosed_block_comment() {
+        let input = "{- module Solution where";
+        let expected = None;
+
+        let actual = declared_module(input);
+
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn identifier_starting_with_module() {
+        let input = "moduleName = 1";
+        let expected = None;
+
+        let actual = declared_module(input);
+
+        assert_eq!(actual, expected);
+    }
+}
+
+#[cfg(test)]
+mod format_parameter {
+    use super::Haskell;
+    use crate::{
+        config::Config,
+        model::{Parameter, ParameterType},
+        runner::LanguageHandler,
+    };
+    use std::{path::PathBuf, sync::Arc};
+
+    #[test]
+    fn bool_false() {
+        let haskell = Haskell::new(PathBuf::new(), Arc::new(Config::default()));
+        let input = Parameter {
+            value_type: ParameterType::Bool,
+            value: String::from("false"),
+        };
+        let expected = String::from("(False :: Bool)");
+
+        let actual = haskell.format_parameter(&input);
+
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn bool_true() {
+        let haskell = Haskell::new(PathBuf::new(), Arc::new(Config::default()));
+        let input = Parameter {
+            value_type: ParameterType::Bool,
+            value: String::from("true"),
+        };
+        let expected = String::from("(True :: Bool)");
+
+        let actual = haskell.format_parameter(&input);
+
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn int_positive() {
+        let haskell = Haskell::new(PathBuf::new(), Arc::new(Config::default()));
+        let input = Parameter {
+            value_type: ParameterType::Int,
+            value: String::from("100"),
+        };
+        let expected = String::from("(100 :: Int)");
+
+        let actual = haskell.format_parameter(&input);
+
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn int_negative() {
+        let haskell = Haskell::new(PathBuf::new(), Arc::new(Config::default()));
+        let input = Parameter {
+            value_type: ParameterType::Int,
+            value: String::from("-100"),
+        };
+        let expected = String::from("(-100 :: Int)");
+
+        let actual = haskell.format_parameter(&input);
+
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn int32() {
+        let haskell = Haskell::new(PathBuf::new(), Arc::new(Config::default()));
+        let input = Parameter {
+            value_type: ParameterType::Int32,
+            value: String::from("-2147483648"),
+        };
+        let expected = String::from("(-2147483648 :: Int32)");
+
+        let actual = haskell.format_parameter(&input);
+
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn int64() {
+        let haskell = Haskell::new(PathBuf::new(), Arc::new(Config::default()));
+        let input = Parameter {
+            value_type: ParameterType::Int64,
+            value: String::from("2147483648"),
+        };
+        let expected = String::from("(2147483648 :: Int64)");
+
+        let actual = haskell.format_parameter(&input);
+
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn big_int() {
+        let haskell = Haskell::new(PathBuf::new(), Arc::new(Config::default()));
+        let input = Parameter {
+            value_type: ParameterType::BigInt,
+            value: String::from("15511210043330985984000000"),
+        };
+        let expected = String::from("(15511210043330985984000000 :: Integer)");
+
+        let actual = haskell.format_parameter(&input);
+
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn decimal() {
+        let haskell = Haskell::new(PathBuf::new(), Arc::new(Config::default()));
+        let input = Parameter {
+            value_type: ParameterType::Decimal,
+            value: String::from("-10.25"),
+        };
+        let expected = String::from("(-10.25 :: Rational)");
+
+        let actual = haskell.format_parameter(&input);
+
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn float_positive() {
+        let haskell = Haskell::new(PathBuf::new(), Arc::new(Config::default()));
+        let input = Parameter {
+            value_type: ParameterType::Float,
+            value: String::from("10.0"),
+        };
+        let expected = String::from("(10.0 :: Double)");
+
+        let actual = haskell.format_parameter(&input);
+
+        assert_eq!(actual, expected);
     }
 
     #[test]
     fn float_negative() {
-        let haskell = Haskell::new(PathBuf::new());
+        let haskell = Haskell::new(PathBuf::new(), Arc::new(Config::default()));
         let input = Parameter {
             value_type: ParameterType::Float,
             value: String::from("-10.0"),
@@ -361,9 +1455,23 @@ mod format_parameter {
         assert_eq!(actual, expected);
     }
 
+    #[test]
+    fn float_whole_number() {
+        let haskell = Haskell::new(PathBuf::new(), Arc::new(Config::default()));
+        let input = Parameter {
+            value_type: ParameterType::Float,
+            value: String::from("5"),
+        };
+        let expected = String::from("(5.0 :: Double)");
+
+        let actual = haskell.format_parameter(&input);
+
+        assert_eq!(actual, expected);
+    }
+
     #[test]
     fn char() {
-        let haskell = Haskell::new(PathBuf::new());
+        let haskell = Haskell::new(PathBuf::new(), Arc::new(Config::default()));
         let input = Parameter {
             value_type: ParameterType::Char,
             value: String::from("a"),
@@ -375,9 +1483,93 @@ mod format_parameter {
         assert_eq!(actual, expected);
     }
 
+    #[test]
+    fn char_single_quote() {
+        let haskell = Haskell::new(PathBuf::new(), Arc::new(Config::default()));
+        let input = Parameter {
+            value_type: ParameterType::Char,
+            value: String::from("'"),
+        };
+        let expected = String::from(r"('\'' :: Char)");
+
+        let actual = haskell.format_parameter(&input);
+
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn char_backslash() {
+        let haskell = Haskell::new(PathBuf::new(), Arc::new(Config::default()));
+        let input = Parameter {
+            value_type: ParameterType::Char,
+            value: String::from("\\"),
+        };
+        let expected = String::from(r"('\\' :: Char)");
+
+        let actual = haskell.format_parameter(&input);
+
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn char_newline() {
+        let haskell = Haskell::new(PathBuf::new(), Arc::new(Config::default()));
+        let input = Parameter {
+            value_type: ParameterType::Char,
+            value: String::from("\n"),
+        };
+        let expected = String::from(r"('\n' :: Char)");
+
+        let actual = haskell.format_parameter(&input);
+
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn char_non_ascii() {
+        let haskell = Haskell::new(PathBuf::new(), Arc::new(Config::default()));
+        let input = Parameter {
+            value_type: ParameterType::Char,
+            value: String::from("é"),
+        };
+        let expected = String::from(r"('\xe9' :: Char)");
+
+        let actual = haskell.format_parameter(&input);
+
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn char_emoji() {
+        let haskell = Haskell::new(PathBuf::new(), Arc::new(Config::default()));
+        let input = Parameter {
+            value_type: ParameterType::Char,
+            value: String::from("🚀"),
+        };
+        let expected = String::from(r"('\x1f680' :: Char)");
+
+        let actual = haskell.format_parameter(&input);
+
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn char_control() {
+        let haskell = Haskell::new(PathBuf::new(), Arc::new(Config::default()));
+        let input = Parameter {
+            value_type: ParameterType::Char,
+            value: String::from("\u{7}"),
+        };
+        let expected = String::from(r"('\x7' :: Char)");
+
+        let actual = haskell.format_parameter(&input);
+
+        assert_eq!(actual, expected);
+    }
+
     #[test]
     fn string() {
-        let haskell = Haskell::new(PathBuf::new());
+        let haskell = Haskell::new(PathBuf::new(), Arc::new(Config::default()));
         let input = Parameter {
             value_type: ParameterType::String,
             value: String::from("hello"),
@@ -388,4 +1580,121 @@ mod format_parameter {
 
         assert_eq!(actual, expected);
     }
+
+    #[test]
+    fn string_quote_and_backslash() {
+        let haskell = Haskell::new(PathBuf::new(), Arc::new(Config::default()));
+        let input = Parameter {
+            value_type: ParameterType::String,
+            value: String::from("a\"b\\c"),
+        };
+        let expected = String::from(r#"("a\"b\\c" :: String)"#);
+
+        let actual = haskell.format_parameter(&input);
+
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn string_newline() {
+        let haskell = Haskell::new(PathBuf::new(), Arc::new(Config::default()));
+        let input = Parameter {
+            value_type: ParameterType::String,
+            value: String::from("x\ny"),
+        };
+        let expected = String::from(r#"("x\ny" :: String)"#);
+
+        let actual = haskell.format_parameter(&input);
+
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn string_non_ascii_before_digit() {
+        let haskell = Haskell::new(PathBuf::new(), Arc::new(Config::default()));
+        let input = Parameter {
+            value_type: ParameterType::String,
+            value: String::from("é1"),
+        };
+        let expected = String::from(r#"("\xe9\&1" :: String)"#);
+
+        let actual = haskell.format_parameter(&input);
+
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn unit() {
+        let haskell = Haskell::new(PathBuf::new(), Arc::new(Config::default()));
+        let input = Parameter {
+            value_type: ParameterType::Unit,
+            value: String::new(),
+        };
+        let expected = String::from("()");
+
+        let actual = haskell.format_parameter(&input);
+
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn map() {
+        let haskell = Haskell::new(PathBuf::new(), Arc::new(Config::default()));
+        let input = Parameter {
+            value_type: ParameterType::Map {
+                key: Box::new(ParameterType::String),
+                value: Box::new(ParameterType::Int),
+            },
+            value: String::from(r#"{"pear": "5", "apple": "3"}"#),
+        };
+        let expected = String::from(
+            r#"(Data.Map.fromList [(("apple" :: String), (3 :: Int)), (("pear" :: String), (5 :: Int))])"#,
+        );
+
+        let actual = haskell.format_parameter(&input);
+
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn list() {
+        let haskell = Haskell::new(PathBuf::new(), Arc::new(Config::default()));
+        let input = Parameter {
+            value_type: ParameterType::List(Box::new(ParameterType::Int)),
+            value: String::from(r#"["3", "1"]"#),
+        };
+        let expected = String::from("[(3 :: Int), (1 :: Int)]");
+
+        let actual = haskell.format_parameter(&input);
+
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn optional_present() {
+        let haskell = Haskell::new(PathBuf::new(), Arc::new(Config::default()));
+        let input = Parameter {
+            value_type: ParameterType::Optional(Box::new(ParameterType::Int)),
+            value: String::from(r#""5""#),
+        };
+        let expected = String::from("(Just (5 :: Int))");
+
+        let actual = haskell.format_parameter(&input);
+
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn optional_absent() {
+        let haskell = Haskell::new(PathBuf::new(), Arc::new(Config::default()));
+        let input = Parameter {
+            value_type: ParameterType::Optional(Box::new(ParameterType::Int)),
+            value: String::from("null"),
+        };
+        let expected = String::from("Nothing");
+
+        let actual = haskell.format_parameter(&input);
+
+        assert_eq!(actual, expected);
+    }
 }
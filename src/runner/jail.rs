@@ -0,0 +1,218 @@
+//! Contains the jail of the solution execution, which restricts the filesystem it can see to its working directory.
+//!
+//! The execution process gets its own mount namespace, in which the directories of the minimal runtime are bind-mounted
+//! read-only into the working directory, before it is made the root directory of the process via `chroot`.
+//! Anything else, e.g. `/etc` or the working directories of other submissions, does not exist from within the jail.
+//!
+//! With the `no-sandbox` feature, which is only for local development, the jail is skipped entirely.
+
+use crate::{RESTRICTED_GROUP_ID, RESTRICTED_USER_ID};
+use std::{
+    env,
+    ffi::CString,
+    fs, io,
+    os::unix::{ffi::OsStrExt, fs::symlink},
+    path::{Path, PathBuf},
+    ptr,
+    sync::LazyLock,
+};
+use tokio::process::Command;
+use tracing::{debug, info};
+
+/// The name of the environment variable containing the comma separated directories of the minimal runtime.
+const RUNTIME_PATHS_ENV_VAR: &str = "MOZART_JAIL_PATHS";
+
+/// The directories of the minimal runtime, if [`RUNTIME_PATHS_ENV_VAR`] is not set.
+const DEFAULT_RUNTIME_PATHS: &[&str] = &["/bin", "/lib", "/lib64", "/usr"];
+
+/// The directories of the minimal runtime that exist, which are made available inside the jail.
+///
+/// It is read from [`RUNTIME_PATHS_ENV_VAR`] the first time it is used.
+static RUNTIME_PATHS: LazyLock<Box<[PathBuf]>> = LazyLock::new(|| {
+    let paths = match env::var(RUNTIME_PATHS_ENV_VAR) {
+        Ok(paths) => paths
+            .split(',')
+            .map(str::trim)
+            .filter(|path| !path.is_empty())
+            .map(PathBuf::from)
+            .collect::<Vec<_>>(),
+        Err(_) => DEFAULT_RUNTIME_PATHS.iter().map(PathBuf::from).collect(),
+    };
+
+    let paths = paths
+        .into_iter()
+        .filter(|path| path.is_absolute() && path.exists())
+        .collect::<Box<[_]>>();
+    info!("jailed executions can see: {:?}", paths);
+
+    paths
+});
+
+/// Gets the path that `path` inside the jail `root` has from within the jail.
+///
+/// Without the sandbox, the path is the same from within the execution.
+pub fn jailed_path(path: &Path, root: &Path) -> PathBuf {
+    if cfg!(feature = "no-sandbox") {
+        return path.to_path_buf();
+    }
+
+    Path::new("/").join(path.strip_prefix(root).unwrap_or(path))
+}
+
+/// Checks whether `path` inside the jail `root` is, or contains, the mount point of a runtime directory,
+/// such that a file of the submission written to it would collide with the jail.
+///
+/// It is checked regardless of the `no-sandbox` feature, so the same files are accepted with and without the sandbox.
+pub fn is_mount_point(path: &Path, root: &Path) -> bool {
+    RUNTIME_PATHS.iter().any(|runtime_path| {
+        root.join(runtime_path.strip_prefix("/").unwrap_or(runtime_path))
+            .starts_with(path)
+    })
+}
+
+/// Makes the `command` execute in a jail of the `root` directory, as the restricted user of the `slot` and the group of
+/// the restricted user, see [`restricted_user_id`].
+///
+/// The mount points of the runtime directories are created inside `root` before returning, and the runtime directories
+/// are mounted onto them in the mount namespace of the execution process only, so they never appear outside of it.
+/// A runtime directory that is a symbolic link, e.g. `/bin` pointing to `usr/bin`, is recreated as the same link.
+///
+/// Without the sandbox, the `command` is only made to execute in `root`, as the user running mozart.
+///
+/// # Errors
+/// Returns an `io::Error` if the mount points could not be created.
+pub fn jail<'a>(command: &'a mut Command, root: &Path, slot: usize) -> io::Result<&'a mut Command> {
+    if cfg!(feature = "no-sandbox") {
+        return Ok(command.current_dir(root));
+    }
+
+    let mut mounts = Vec::with_capacity(RUNTIME_PATHS.len());
+    for path in RUNTIME_PATHS.iter() {
+        let target = root.join(path.strip_prefix("/").unwrap_or(path));
+        if let Some(parent) = target.parent() {
+            fs::create_dir_all(parent)?;
+        }
+
+        if path.is_symlink() {
+            symlink(fs::read_link(path)?, &target)?;
+        } else {
+            fs::create_dir(&target)?;
+            mounts.push((c_path(path)?, c_path(&target)?));
+        }
+    }
+    debug!("prepared {} mount points in {:?}", mounts.len(), root);
+
+    let root = c_path(root)?;
+    let uid = restricted_user_id(slot);
+    let gid = *RESTRICTED_GROUP_ID;
+
+    // SAFETY: the closure only calls `unshare`, `mount`, `chroot`, `chdir`, `setgroups`, `setgid` and `setuid`,
+    // which are async-signal-safe, and does not allocate, as the paths are converted beforehand
+    unsafe {
+        Ok(command.pre_exec(move || {
+            check(libc::unshare(libc::CLONE_NEWNS))?;
+            // without this the mounts below would propagate to, and remain in, the namespace of mozart
+            check(libc::mount(
+                ptr::null(),
+                c"/".as_ptr(),
+                ptr::null(),
+                libc::MS_REC | libc::MS_PRIVATE,
+                ptr::null(),
+            ))?;
+
+            for (source, target) in &mounts {
+                check(libc::mount(
+                    source.as_ptr(),
+                    target.as_ptr(),
+                    ptr::null(),
+                    libc::MS_BIND | libc::MS_REC,
+                    ptr::null(),
+                ))?;
+                check(libc::mount(
+                    ptr::null(),
+                    target.as_ptr(),
+                    ptr::null(),
+                    libc::MS_BIND | libc::MS_REMOUNT | libc::MS_RDONLY,
+                    ptr::null(),
+                ))?;
+            }
+
+            check(libc::chroot(root.as_ptr()))?;
+            check(libc::chdir(c"/".as_ptr()))?;
+            check(libc::setgroups(0, ptr::null()))?;
+            // the group must be changed while still privileged, as the restricted user cannot change it afterwards
+            check(libc::setgid(gid))?;
+            check(libc::setuid(uid))?;
+
+            Ok(())
+        }))
+    }
+}
+
+/// Gets the id of the restricted user of the `slot`, which is the `slot`-th user id after [`RESTRICTED_USER_ID`],
+/// such that concurrent executions, which hold distinct slots, run as distinct users.
+///
+/// The user ids need not belong to named users, but must not be used by anything else.
+pub fn restricted_user_id(slot: usize) -> u32 {
+    u32::try_from(slot)
+        .ok()
+        .and_then(|slot| RESTRICTED_USER_ID.checked_add(slot))
+        .expect("the user id of every slot should fit in a user id")
+}
+
+/// Converts the `path` to a C string, such that it can be passed to libc.
+fn c_path(path: &Path) -> io::Result<CString> {
+    CString::new(path.as_os_str().as_bytes()).map_err(io::Error::from)
+}
+
+/// Converts the `result` of a libc call into an `io::Error` if it indicates failure.
+fn check(result: libc::c_int) -> io::Result<()> {
+    if result == -1 {
+        Err(io::Error::last_os_error())
+    } else {
+        Ok(())
+    }
+}
+
+#[cfg(all(test, not(feature = "no-sandbox")))]
+mod jailed_path {
+    use super::jailed_path;
+    use std::path::{Path, PathBuf};
+
+    #[test]
+    fn inside_root() {
+        let root = Path::new("/mozart/2e7f3fc1-8b0e-4ee1-9e2f-3c7e8d2d1f0a");
+        let input = root.join("main.py");
+        let expected = PathBuf::from("/main.py");
+
+        let actual = jailed_path(&input, root);
+
+        assert_eq!(actual, expected);
+    }
+}
+
+#[cfg(test)]
+mod is_mount_point {
+    use super::is_mount_point;
+    use std::path::Path;
+
+    #[test]
+    fn runtime_directory() {
+        let root = Path::new("/mozart/2e7f3fc1-8b0e-4ee1-9e2f-3c7e8d2d1f0a");
+        let input = root.join("usr");
+
+        let actual = is_mount_point(&input, root);
+
+        assert!(actual);
+    }
+
+    #[test]
+    fn plain_file() {
+        let root = Path::new("/mozart/2e7f3fc1-8b0e-4ee1-9e2f-3c7e8d2d1f0a");
+        let input = root.join("helper.py");
+
+        let actual = is_mount_point(&input, root);
+
+        assert!(!actual);
+    }
+}
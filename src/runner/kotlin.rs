@@ -0,0 +1,908 @@
+//! Contains the language specific implementation for the Kotlin programming language.
+
+use super::LanguageHandler;
+use crate::{
+    config::Config,
+    error::{SubmissionError, UUID_SHOULD_BE_VALID_STR},
+    model::{CompilationDiagnostic, Parameter, ParameterType, TestCase},
+    runner::{
+        crash_error, format_float, has_started_test_case, insert_test_runner_constants,
+        jail::{jail, jailed_path},
+        read_test_output, remove_mozart_path, restrict_environment, restrict_resources,
+        spawn_failure, TestOutput, ENDED_OUTCOME, STARTED_OUTCOME, TEST_CASE_ID_TARGET,
+    },
+    timeout::{run_process, timeout_process, ProcessOutcome},
+};
+use std::{
+    env, fs,
+    path::PathBuf,
+    process::Stdio,
+    sync::{Arc, LazyLock},
+    time::Duration,
+};
+use tokio::process::Command;
+use tracing::{debug, error, info};
+
+#[cfg(not(feature = "ci"))]
+/// The timeout duration for the compilation, which is longer than the configured timeout as the Kotlin compiler is slow to start.
+const COMPILE_TIMEOUT: Duration = Duration::from_secs(30);
+
+#[cfg(feature = "ci")]
+/// The timeout duration for the compilation used during pipeline workflows.
+const COMPILE_TIMEOUT: Duration = Duration::from_secs(120);
+
+/// The path of the Java runtime that executes the compiled solution, with every symbolic link resolved.
+///
+/// It is resolved outside of the jail, as the `java` found on the `PATH` is commonly a link through `/etc`,
+/// which does not exist inside the jail. It is resolved the first time it is used, and falls back to `java`.
+static JAVA: LazyLock<PathBuf> = LazyLock::new(|| {
+    let java = env::var_os("PATH")
+        .iter()
+        .flat_map(env::split_paths)
+        .map(|directory| directory.join("java"))
+        .find(|path| path.is_file())
+        .and_then(|path| fs::canonicalize(path).ok())
+        .unwrap_or_else(|| PathBuf::from("java"));
+    info!("kotlin solutions are executed by {:?}", java);
+
+    java
+});
+
+/// The base test code for Kotlin.
+///
+/// The process exits explicitly after the test cases, so that a test case that timed out cannot keep it running.
+const KOTLIN_BASE_TEST_CODE: &str = r###"
+fun main() {
+TEST_CASES
+    System.out.flush()
+    kotlin.system.exitProcess(0)
+}
+"###;
+
+/// The test runner for the Kotlin implementation.
+///
+/// Every outcome is written as a JSON object on its own line, so that values containing delimiters cannot break it.
+/// Characters outside of printable ASCII are escaped, so that the output does not depend on the locale.
+///
+/// Its functions are members of the `TestRunner` object, so that they cannot clash with those of the solution.
+///
+/// A test case runs on its own daemon thread, and is reported as timed out if it has not finished within its timeout,
+/// as a thread cannot be stopped. Its outcome is only reported by whichever of the two happens first.
+///
+/// Before a test case is run, stdin is redirected to its file inside `STDIN_DIR`, relative to the jail root.
+///
+/// Every outcome is appended to `RESULTS_FILE`, relative to the jail root, so that what the solution prints cannot
+/// break it, and stdout is flushed after it, so that what the solution printed is not lost if the execution is killed.
+///
+/// The values of a wrong answer are truncated to `MAX_VALUE_LENGTH` characters, followed by `TRUNCATED_VALUE_MARKER`.
+/// If the expected value is a list or a map, they are also written as typed JSON, unless it is longer than that.
+/// A character or string is reported as its text, unless `QUOTE_VALUES` is 1, in which case it is quoted and escaped.
+///
+/// If `UNORDERED_LISTS` is 1, the actual and expected lists are sorted before they are checked, with `unordered`, or
+/// with `unorderedOutputs` for the lists among multiple output parameters, so that the order of their elements does
+/// not matter. Elements that are not comparable, e.g. maps, are sorted by how they are shown. The values of a wrong
+/// answer are reported in their original order.
+///
+/// A test case that expects a runtime error reports the value the solution returned instead, with `returned`.
+///
+/// If `FAIL_FAST` is 1, the process exits right after the first outcome that is not a pass.
+const KOTLIN_TEST_RUNNER: &str = r###"
+import java.io.File
+import java.io.FileInputStream
+import java.io.InputStream
+import java.util.concurrent.atomic.AtomicReference
+import kotlin.system.exitProcess
+
+object TestRunner {
+    class Outcome(val kind: String, vararg val fields: Pair<String, String>)
+
+    private var stdin: InputStream? = null
+
+    private fun show(value: Any?): String = when (value) {
+        is String -> "\"" + value.replace("\\", "\\\\").replace("\"", "\\\"").replace("\n", "\\n") + "\""
+        is Char -> "'" + value + "'"
+        is Map<*, *> -> value.entries.joinToString(", ", "{", "}") { show(it.key) + "=" + show(it.value) }
+        is List<*> -> value.joinToString(", ", "[", "]") { show(it) }
+        else -> value.toString()
+    }
+
+    private fun render(value: Any?): String {
+        val text = if (QUOTE_VALUES == 0 && (value is String || value is Char)) value.toString() else show(value)
+        return if (text.length <= MAX_VALUE_LENGTH) text else text.take(MAX_VALUE_LENGTH) + "TRUNCATED_VALUE_MARKER"
+    }
+
+    fun jsonString(text: String): String {
+        val json = StringBuilder("\"")
+        for (c in text) {
+            when {
+                c == '"' -> json.append("\\\"")
+                c == '\\' -> json.append("\\\\")
+                c < ' ' || c > '~' -> json.append("\\u").append(c.code.toString(16).padStart(4, '0'))
+                else -> json.append(c)
+            }
+        }
+        return json.append('"').toString()
+    }
+
+    private fun json(value: Any?): String = when (value) {
+        is Boolean, is Int, is Long, is java.math.BigInteger -> value.toString()
+        is Double -> if (value.isFinite()) value.toString() else "null"
+        is Char -> jsonString(value.toString())
+        is String -> jsonString(value)
+        is Map<*, *> -> value.entries.joinToString(",", "{", "}") {
+            val key = json(it.key)
+            (if (key.startsWith("\"")) key else jsonString(key)) + ":" + json(it.value)
+        }
+        is List<*> -> value.joinToString(",", "[", "]") { json(it) }
+        else -> "null"
+    }
+
+    private fun structuredValues(actual: Any?, expected: Any?): List<Pair<String, String>> =
+        if (expected is Map<*, *> || expected is List<*>) {
+            listOf("actualValue" to json(actual), "expectedValue" to json(expected)).filter { it.second.length <= MAX_VALUE_LENGTH }
+        } else {
+            listOf()
+        }
+
+    fun redirectStdin(testCaseId: String) {
+        stdin?.close()
+        val testCaseStdin = FileInputStream("STDIN_DIR/" + testCaseId)
+        stdin = testCaseStdin
+        System.setIn(testCaseStdin)
+    }
+
+    fun reportOutcome(outcome: String, vararg fields: Pair<String, String>) {
+        val line = (listOf("r" to jsonString(outcome)) + fields).joinToString(",", "{", "}") { jsonString(it.first) + ":" + it.second }
+        File("RESULTS_FILE").appendText(line + "\n")
+        System.out.flush()
+        if (FAIL_FAST != 0 && outcome != "STARTED_OUTCOME" && outcome != "ENDED_OUTCOME" && outcome != "p") {
+            exitProcess(0)
+        }
+    }
+
+    @Suppress("UNCHECKED_CAST")
+    private fun sortedElements(value: List<*>): List<*> =
+        try {
+            value.sortedWith(Comparator<Any?> { a, b -> compareValues(a as Comparable<Any>?, b as Comparable<Any>?) })
+        } catch (e: ClassCastException) {
+            value.sortedBy { show(it) }
+        }
+
+    @Suppress("UNCHECKED_CAST")
+    fun <T> unordered(value: T): T = if (UNORDERED_LISTS != 0 && value is List<*>) sortedElements(value) as T else value
+
+    fun unorderedOutputs(outputs: List<Any>): List<Any> = outputs.map { unordered(it) }
+
+    fun <T> testChecker(passed: Boolean, actual: T, expected: T): Outcome =
+        if (passed) Outcome("p")
+        else Outcome("f", "actual" to jsonString(render(actual)), "expected" to jsonString(render(expected)), *structuredValues(actual, expected).toTypedArray())
+
+    fun returned(actual: Any?): Outcome = Outcome("v", "actual" to jsonString(render(actual)))
+
+    fun runTestCase(timeoutMillis: Long, testCase: () -> Outcome) {
+        val outcome = AtomicReference<Outcome?>()
+        val thread = Thread {
+            val result = try {
+                testCase()
+            } catch (e: Throwable) {
+                Outcome("err", "msg" to jsonString(e.toString()))
+            }
+            outcome.compareAndSet(null, result)
+        }
+        thread.isDaemon = true
+        thread.start()
+        thread.join(timeoutMillis)
+        outcome.compareAndSet(null, Outcome("t"))
+        val reported = outcome.get()!!
+        reportOutcome(reported.kind, *reported.fields)
+    }
+}
+"###;
+
+/// The checker for Kotlin used if the submission does not supply one, which accepts a value equal to the expected value.
+const KOTLIN_DEFAULT_CHECKER: &str = r###"
+fun <T> checker(actual: T, expected: T): Boolean = actual == expected
+"###;
+
+/// The checker for Kotlin used if the submission supplies a float tolerance.
+///
+/// The values of multiple output parameters, lists and maps are compared element-wise, such that the doubles among them
+/// are also compared within the tolerance. Its helper is private, so that it cannot clash with the solution.
+const KOTLIN_TOLERANT_CHECKER: &str = r###"
+import kotlin.math.abs
+import kotlin.math.max
+
+private fun withinTolerance(actual: Any?, expected: Any?): Boolean = when {
+    actual is Double && expected is Double && expected.isNaN() -> MATCH_NAN != 0 && actual.isNaN()
+    actual is Double && expected is Double && expected.isInfinite() -> actual == expected
+    actual is Double && expected is Double ->
+        abs(actual - expected) <= max(ABSOLUTE_TOLERANCE, RELATIVE_TOLERANCE * abs(expected))
+    actual is List<*> && expected is List<*> ->
+        actual.size == expected.size && actual.zip(expected).all { (a, e) -> withinTolerance(a, e) }
+    actual is Map<*, *> && expected is Map<*, *> ->
+        actual.keys == expected.keys && expected.all { (key, value) -> withinTolerance(actual[key], value) }
+    else -> actual == expected
+}
+
+fun <T> checker(actual: T, expected: T): Boolean = withinTolerance(actual, expected)
+"###;
+
+/// The package the reference solution is declared in, so that its `solution` does not clash with that of the solution.
+const REFERENCE_PACKAGE: &str = "reference";
+
+/// The exception handling code snippet for Kotlin.
+///
+/// The `TEST_CASE` is being replace with a call to the actual test case.
+/// This is done for all test cases.
+///
+/// The `STARTED_OUTCOME` and `TEST_CASE_ID` are replaced to form the started marker of the test case,
+/// which is flushed such that it is part of the output even if the execution is killed,
+/// and the `ENDED_OUTCOME` and `TEST_CASE_ID` to form its ended marker, which delimits its results.
+///
+/// The `TEST_CASE_TIMEOUT` is replaced with the time in milliseconds the test case may run for.
+const KOTLIN_EXCEPTION_SNIPPET: &str = r###"
+    TestRunner.reportOutcome("STARTED_OUTCOME", "id" to "TEST_CASE_ID")
+    TestRunner.redirectStdin("TEST_CASE_ID")
+    TestRunner.runTestCase(TEST_CASE_TIMEOUT) {
+TEST_CASE
+    }
+    TestRunner.reportOutcome("ENDED_OUTCOME", "id" to "TEST_CASE_ID")
+"###;
+
+/// The language handler for Kotlin.
+pub struct Kotlin {
+    /// A path buffer to the current working directory of a given request.
+    temp_dir: PathBuf,
+
+    /// The configuration the submission is checked with.
+    config: Arc<Config>,
+
+    /// The test runner code, with the truncation of reported values inserted.
+    test_runner_code: String,
+}
+
+impl Kotlin {
+    /// Gets the path to the jar the Kotlin files are compiled into.
+    fn jar_path(&self) -> PathBuf {
+        let mut jar_path = self.temp_dir.clone();
+        jar_path.push("test.jar");
+        jar_path
+    }
+}
+
+/// Parses the errors of a failed compilation, written by `kotlinc` to its `stderr`.
+///
+/// An error is a line like `Solution.kt:3:5: error: unresolved reference: y`.
+/// Lines that are not errors, e.g. the source code excerpt following it, are skipped.
+fn parse_compilation_diagnostics(stderr: &str) -> Box<[CompilationDiagnostic]> {
+    stderr
+        .lines()
+        .filter_map(|line| {
+            let (location, message) = line.split_once(": error: ")?;
+            let (_, position) = location.split_once(".kt:")?;
+            let (line, column) = position.split_once(':')?;
+
+            Some(CompilationDiagnostic {
+                line: line.parse().ok()?,
+                column: column.parse().ok()?,
+                message: message.trim().to_string(),
+            })
+        })
+        .collect()
+}
+
+/// Gets the Kotlin type that values of the `parameter_type` have.
+fn kotlin_type(parameter_type: &ParameterType) -> String {
+    match parameter_type {
+        ParameterType::Bool => String::from("Boolean"),
+        ParameterType::Int | ParameterType::Int64 => String::from("Long"),
+        ParameterType::Int32 => String::from("Int"),
+        ParameterType::BigInt => String::from("java.math.BigInteger"),
+        ParameterType::Float => String::from("Double"),
+        ParameterType::Decimal => unreachable!("decimals should have been rejected"),
+        ParameterType::Char => String::from("Char"),
+        ParameterType::String => String::from("String"),
+        ParameterType::Unit => String::from("Unit"),
+        ParameterType::Map { key, value } => {
+            format!("Map<{}, {}>", kotlin_type(key), kotlin_type(value))
+        }
+        ParameterType::List(value_type) => format!("List<{}>", kotlin_type(value_type)),
+        ParameterType::Optional(_) => unreachable!("optionals should have been rejected"),
+    }
+}
+
+/// Escapes the characters of `value` that cannot appear verbatim inside a Kotlin string or character literal.
+///
+/// The `$` is escaped as well, as it would otherwise start a string template.
+fn escape(value: &str) -> String {
+    value
+        .chars()
+        .map(|c| match c {
+            '"' => String::from("\\\""),
+            '\'' => String::from("\\'"),
+            '\\' => String::from("\\\\"),
+            '$' => String::from("\\$"),
+            '\n' => String::from("\\n"),
+            '\t' => String::from("\\t"),
+            '\r' => String::from("\\r"),
+            c if c.is_control() => format!("\\u{:04x}", c as u32),
+            c => c.to_string(),
+        })
+        .collect()
+}
+
+impl LanguageHandler for Kotlin {
+    fn new(temp_dir: PathBuf, config: Arc<Config>) -> Self {
+        Self {
+            temp_dir,
+            test_runner_code: insert_test_runner_constants(KOTLIN_TEST_RUNNER, &config),
+            config,
+        }
+    }
+
+    fn test_file_path(&self) -> PathBuf {
+        let mut path = self.temp_dir.clone();
+        path.push("Main.kt");
+
+        path
+    }
+
+    fn base_test_code(&self) -> &str {
+        KOTLIN_BASE_TEST_CODE
+    }
+
+    fn solution_file_path(&self) -> PathBuf {
+        let mut path = self.temp_dir.clone();
+        path.push("Solution.kt");
+
+        path
+    }
+
+    fn test_runner_file_path(&self) -> PathBuf {
+        let mut path = self.temp_dir.clone();
+        path.push("TestRunner.kt");
+
+        path
+    }
+
+    fn checker_file_path(&self) -> PathBuf {
+        let mut path = self.temp_dir.clone();
+        path.push("Checker.kt");
+
+        path
+    }
+
+    fn default_checker_code(&self) -> &str {
+        KOTLIN_DEFAULT_CHECKER
+    }
+
+    fn tolerant_checker_code(&self) -> &str {
+        KOTLIN_TOLERANT_CHECKER
+    }
+
+    fn reference_file_path(&self) -> PathBuf {
+        let mut path = self.temp_dir.clone();
+        path.push("Reference.kt");
+
+        path
+    }
+
+    fn reference_code(&self, reference_solution: Option<&str>) -> String {
+        format!(
+            "package {REFERENCE_PACKAGE}\n\n{}",
+            reference_solution.unwrap_or_default()
+        )
+    }
+
+    fn test_runner_code(&self) -> &str {
+        &self.test_runner_code
+    }
+
+    fn validate_solution(&self, solution: &str) -> Result<(), SubmissionError> {
+        if solution
+            .lines()
+            .any(|line| line.trim_start().starts_with("package "))
+        {
+            info!("solution declares a package");
+            return Err(SubmissionError::InvalidSolution(String::from(
+                "the solution cannot declare a package",
+            )));
+        }
+
+        Ok(())
+    }
+
+    fn generate_test_cases(
+        &self,
+        test_code: &mut String,
+        test_cases: &[TestCase],
+        with_reference: bool,
+    ) {
+        for (index, test_case) in test_cases.iter().enumerate() {
+            let formatted_input_parameters = test_case
+                .input_parameters
+                .iter()
+                .map(|ip| self.format_parameter(ip))
+                .collect::<Vec<String>>()
+                .join(", ");
+
+            // multiple output parameters are expected to be returned as a list, which is not sorted itself
+            let (output_type, formatted_output_parameters, unordered) =
+                match &*test_case.output_parameters {
+                    // the return type of the solutions is unknown, so their values are compared as any value
+                    [] if with_reference => (
+                        String::from("Any?"),
+                        format!("{REFERENCE_PACKAGE}.solution({formatted_input_parameters})"),
+                        "unordered",
+                    ),
+                    [output_parameter] => (
+                        kotlin_type(&output_parameter.value_type),
+                        self.format_parameter(output_parameter),
+                        "unordered",
+                    ),
+                    output_parameters => (
+                        String::from("List<Any>"),
+                        format!(
+                            "listOf({})",
+                            output_parameters
+                                .iter()
+                                .map(|op| self.format_parameter(op))
+                                .collect::<Vec<String>>()
+                                .join(", ")
+                        ),
+                        "unorderedOutputs",
+                    ),
+                };
+
+            let test_case_call = if test_case.expected_error.is_some() {
+                format!("        TestRunner.returned(solution({formatted_input_parameters}))")
+            } else {
+                format!(
+                    "        val actual: {output_type} = solution({formatted_input_parameters})\n        val expected: {output_type} = {formatted_output_parameters}\n        TestRunner.testChecker(checker(TestRunner.{unordered}(actual), TestRunner.{unordered}(expected)), actual, expected)"
+                )
+            };
+            let generated_test_case = KOTLIN_EXCEPTION_SNIPPET
+                .replace("STARTED_OUTCOME", STARTED_OUTCOME)
+                .replace("ENDED_OUTCOME", ENDED_OUTCOME)
+                .replace(TEST_CASE_ID_TARGET, &test_case.id.to_string())
+                .replace(
+                    "TEST_CASE_TIMEOUT",
+                    &self.config.test_case_timeout.as_millis().to_string(),
+                )
+                .replace("TEST_CASE", &test_case_call);
+            if index > 0 {
+                test_code.push('\n');
+            }
+            test_code.push_str(&generated_test_case);
+        }
+    }
+
+    fn format_parameter(&self, parameter: &Parameter) -> String {
+        match &parameter.value_type {
+            ParameterType::Int | ParameterType::Int64
+                if parameter.value == i64::MIN.to_string() =>
+            {
+                String::from("Long.MIN_VALUE")
+            }
+            ParameterType::Int | ParameterType::Int64 => format!("{}L", parameter.value),
+            ParameterType::Int32 if parameter.value == i32::MIN.to_string() => {
+                String::from("Int.MIN_VALUE")
+            }
+            ParameterType::Int32 => parameter.value.clone(),
+            ParameterType::BigInt => format!(r#"java.math.BigInteger("{}")"#, parameter.value),
+            ParameterType::Float => {
+                format_float(&parameter.value, "Double.NaN", "Double.POSITIVE_INFINITY")
+            }
+            ParameterType::Decimal => unreachable!("decimals should have been rejected"),
+            ParameterType::Bool => parameter.value.clone(),
+            ParameterType::Char => format!("'{}'", escape(&parameter.value)),
+            ParameterType::String => format!(r#""{}""#, escape(&parameter.value)),
+            ParameterType::Unit => String::from("Unit"),
+            ParameterType::List(value_type) => {
+                let elements = parameter
+                    .elements()
+                    .expect("the value of a list should have been validated")
+                    .iter()
+                    .map(|element| self.format_parameter(element))
+                    .collect::<Vec<_>>();
+                format!(
+                    "listOf<{}>({})",
+                    kotlin_type(value_type),
+                    elements.join(", ")
+                )
+            }
+            ParameterType::Optional(_) => unreachable!("optionals should have been rejected"),
+            ParameterType::Map { key, value } => {
+                let entries = parameter
+                    .entries()
+                    .expect("the value of a map should have been validated")
+                    .iter()
+                    .map(|(key, value)| {
+                        format!(
+                            "{} to {}",
+                            self.format_parameter(key),
+                            self.format_parameter(value)
+                        )
+                    })
+                    .collect::<Vec<_>>();
+                format!(
+                    "mapOf<{}, {}>({})",
+                    kotlin_type(key),
+                    kotlin_type(value),
+                    entries.join(", ")
+                )
+            }
+        }
+    }
+
+    /// Compiles every Kotlin file in the working directory into a single jar,
+    /// passing the `compiler_flags` of the submission to the `toolchain`, e.g. `kotlinc`.
+    ///
+    /// Compiling is limited by [`COMPILE_TIMEOUT`] instead of the configured timeout.
+    async fn compile(
+        &self,
+        toolchain: &str,
+        compiler_flags: &[String],
+    ) -> Result<(), SubmissionError> {
+        let jar_path = self.jar_path();
+        let jar_str = jar_path.to_str().expect(UUID_SHOULD_BE_VALID_STR);
+        let base_path = self
+            .temp_dir
+            .as_path()
+            .to_str()
+            .expect(UUID_SHOULD_BE_VALID_STR);
+
+        info!("spawning compilation process");
+        let compile_process = Command::new(toolchain)
+            .arg(base_path) // compiles the solution along with the files supplied alongside it
+            .arg("-include-runtime") // makes the jar runnable without the kotlin standard library installed
+            .arg("-nowarn") // leaves only the errors in stderr
+            .args(["-d", jar_str])
+            .args(compiler_flags)
+            .stdin(Stdio::piped())
+            .stdout(Stdio::piped())
+            .stderr(Stdio::piped())
+            .spawn();
+        let compile_handle = match compile_process {
+            Ok(ch) => ch,
+            Err(err) => return Err(spawn_failure(toolchain, &err)),
+        };
+
+        info!("starting timeout of compilation process");
+        let Some((compile_exit_status, compile_output)) =
+            timeout_process(COMPILE_TIMEOUT, compile_handle).await?
+        else {
+            error!(
+                "compilation process exceeded allowed time limit of {:?}",
+                COMPILE_TIMEOUT
+            );
+            return Err(SubmissionError::CompileTimeout(COMPILE_TIMEOUT));
+        };
+
+        if compile_exit_status.success() {
+            info!("no compile errors");
+            return Ok(());
+        }
+
+        let stderr = remove_mozart_path(&String::from_utf8_lossy(&compile_output.stderr));
+        if compile_exit_status.code() != Some(1) || stderr.trim().is_empty() {
+            error!(
+                "compilation process failed with {}: {}",
+                compile_exit_status, stderr
+            );
+            return Err(SubmissionError::Internal);
+        }
+
+        info!("compile error");
+        debug!("compile error: {}", stderr);
+        let diagnostics = parse_compilation_diagnostics(&stderr);
+        Err(SubmissionError::Compilation(stderr, diagnostics))
+    }
+    async fn execute(
+        &self,
+        _toolchain: &str,
+        _compiler_flags: &[String],
+        slot: usize,
+    ) -> Result<(TestOutput, String), SubmissionError> {
+        let jailed_jar_path = jailed_path(&self.jar_path(), &self.temp_dir);
+        let jailed_jar_str = jailed_jar_path.to_str().expect(UUID_SHOULD_BE_VALID_STR);
+
+        info!("spawning execution process");
+        let execution_process = jail(
+            restrict_resources(
+                restrict_environment(
+                    Command::new(JAVA.as_path())
+                        .arg("-XX:+UseSerialGC") // keeps the number of threads low, as they count towards the process limit
+                        .arg("-XX:TieredStopAtLevel=1") // speeds up the startup of short executions
+                        .arg("-XX:-UsePerfData") // the jail has no writable temporary directory for it
+                        .args(["-jar", jailed_jar_str])
+                        .stdin(Stdio::piped())
+                        .stdout(Stdio::piped())
+                        .stderr(Stdio::piped()),
+                    &self.config.execution_env_vars,
+                ),
+                self.config.max_file_size,
+            ),
+            &self.temp_dir,
+            slot,
+        )
+        .and_then(Command::spawn);
+        let execution_handle = match execution_process {
+            Ok(eh) => eh,
+            Err(err) => {
+                error!("could not spawn execution process: {}", err);
+                return Err(SubmissionError::Internal);
+            }
+        };
+
+        info!("starting execution process timeout");
+        match run_process(
+            self.config.timeout,
+            self.config.kill_grace_period,
+            execution_handle,
+        )
+        .await?
+        {
+            ProcessOutcome::Exited(output) => {
+                let es = output.status;
+                info!(?es);
+                info!("stdout: {}", String::from_utf8_lossy(&output.stdout));
+                info!("stderr: {}", String::from_utf8_lossy(&output.stderr));
+
+                let test_output = read_test_output(&self.temp_dir).await?;
+                let stdout = String::from_utf8_lossy(&output.stdout).to_string();
+
+                if es.success() {
+                    Ok((TestOutput::Finished(test_output), stdout))
+                } else if has_started_test_case(&test_output) {
+                    info!("execution process crashed");
+                    Ok((
+                        TestOutput::Crashed(test_output, crash_error(&output)),
+                        stdout,
+                    ))
+                } else {
+                    Err(SubmissionError::Execution(crash_error(&output)))
+                }
+            }
+            ProcessOutcome::TimedOut(output) => {
+                error!(
+                    "execution process exceeded allowed time limit of {:?}",
+                    self.config.timeout
+                );
+                let test_output = read_test_output(&self.temp_dir).await?;
+                let stdout = String::from_utf8_lossy(&output.stdout).to_string();
+
+                Ok((TestOutput::TimedOut(test_output), stdout))
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod parse_compilation_diagnostics {
+    use super::parse_compilation_diagnostics;
+    use crate::model::CompilationDiagnostic;
+
+    #[test]
+    fn unresolved_reference() {
+        let input = [
+            "Solution.kt:2:12: error: unresolved reference: y",
+            "    return y",
+            "           ^",
+        ]
+        .join("\n");
+        let expected = Box::new([CompilationDiagnostic {
+            line: 2,
+            column: 12,
+            message: String::from("unresolved reference: y"),
+        }]);
+
+        let actual = parse_compilation_diagnostics(&input);
+
+        assert_eq!(*actual, *expected);
+    }
+
+    #[test]
+    fn multiple_errors() {
+        let input = [
+            "Solution.kt:1:30: error: type mismatch: inferred type is String but Long was expected",
+            "Main.kt:4:23: error: unresolved reference: solution",
+        ]
+        .join("\n");
+        let expected = Box::new([
+            CompilationDiagnostic {
+                line: 1,
+                column: 30,
+                message: String::from(
+                    "type mismatch: inferred type is String but Long was expected",
+                ),
+            },
+            CompilationDiagnostic {
+                line: 4,
+                column: 23,
+                message: String::from("unresolved reference: solution"),
+            },
+        ]);
+
+        let actual = parse_compilation_diagnostics(&input);
+
+        assert_eq!(*actual, *expected);
+    }
+
+    #[test]
+    fn no_errors() {
+        let input = "warning: some warning without a location";
+
+        let actual = parse_compilation_diagnostics(input);
+
+        assert!(actual.is_empty());
+    }
+}
+
+#[cfg(test)]
+mod format_parameter {
+    use super::Kotlin;
+    use crate::{
+        config::Config,
+        model::{Parameter, ParameterType},
+        runner::LanguageHandler,
+    };
+    use std::{path::PathBuf, sync::Arc};
+
+    #[test]
+    fn int() {
+        let kotlin = Kotlin::new(PathBuf::new(), Arc::new(Config::default()));
+        let input = Parameter {
+            value_type: ParameterType::Int,
+            value: String::from("-5"),
+        };
+        let expected = String::from("-5L");
+
+        let actual = kotlin.format_parameter(&input);
+
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn int64_min() {
+        let kotlin = Kotlin::new(PathBuf::new(), Arc::new(Config::default()));
+        let input = Parameter {
+            value_type: ParameterType::Int64,
+            value: String::from("-9223372036854775808"),
+        };
+        let expected = String::from("Long.MIN_VALUE");
+
+        let actual = kotlin.format_parameter(&input);
+
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn int32() {
+        let kotlin = Kotlin::new(PathBuf::new(), Arc::new(Config::default()));
+        let input = Parameter {
+            value_type: ParameterType::Int32,
+            value: String::from("7"),
+        };
+        let expected = String::from("7");
+
+        let actual = kotlin.format_parameter(&input);
+
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn big_int() {
+        let kotlin = Kotlin::new(PathBuf::new(), Arc::new(Config::default()));
+        let input = Parameter {
+            value_type: ParameterType::BigInt,
+            value: String::from("15511210043330985984000000"),
+        };
+        let expected = String::from(r#"java.math.BigInteger("15511210043330985984000000")"#);
+
+        let actual = kotlin.format_parameter(&input);
+
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn float() {
+        let kotlin = Kotlin::new(PathBuf::new(), Arc::new(Config::default()));
+        let input = Parameter {
+            value_type: ParameterType::Float,
+            value: String::from("5"),
+        };
+        let expected = String::from("5.0");
+
+        let actual = kotlin.format_parameter(&input);
+
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn bool() {
+        let kotlin = Kotlin::new(PathBuf::new(), Arc::new(Config::default()));
+        let input = Parameter {
+            value_type: ParameterType::Bool,
+            value: String::from("true"),
+        };
+        let expected = String::from("true");
+
+        let actual = kotlin.format_parameter(&input);
+
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn char_single_quote() {
+        let kotlin = Kotlin::new(PathBuf::new(), Arc::new(Config::default()));
+        let input = Parameter {
+            value_type: ParameterType::Char,
+            value: String::from("'"),
+        };
+        let expected = String::from(r"'\''");
+
+        let actual = kotlin.format_parameter(&input);
+
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn string_with_template() {
+        let kotlin = Kotlin::new(PathBuf::new(), Arc::new(Config::default()));
+        let input = Parameter {
+            value_type: ParameterType::String,
+            value: String::from(r#"say "$name""#),
+        };
+        let expected = String::from(r#""say \"\$name\"""#);
+
+        let actual = kotlin.format_parameter(&input);
+
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn unit() {
+        let kotlin = Kotlin::new(PathBuf::new(), Arc::new(Config::default()));
+        let input = Parameter {
+            value_type: ParameterType::Unit,
+            value: String::new(),
+        };
+        let expected = String::from("Unit");
+
+        let actual = kotlin.format_parameter(&input);
+
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn map() {
+        let kotlin = Kotlin::new(PathBuf::new(), Arc::new(Config::default()));
+        let input = Parameter {
+            value_type: ParameterType::Map {
+                key: Box::new(ParameterType::String),
+                value: Box::new(ParameterType::Int),
+            },
+            value: String::from(r#"{"pear": "5", "apple": "3"}"#),
+        };
+        let expected = String::from(r#"mapOf<String, Long>("apple" to 3L, "pear" to 5L)"#);
+
+        let actual = kotlin.format_parameter(&input);
+
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn list() {
+        let kotlin = Kotlin::new(PathBuf::new(), Arc::new(Config::default()));
+        let input = Parameter {
+            value_type: ParameterType::List(Box::new(ParameterType::Int)),
+            value: String::from(r#"["3", "1"]"#),
+        };
+        let expected = String::from("listOf<Long>(3L, 1L)");
+
+        let actual = kotlin.format_parameter(&input);
+
+        assert_eq!(actual, expected);
+    }
+}
@@ -1,41 +1,251 @@
 //! Defines the components necessary for the language agnostic test runner to exist.
 
 use crate::{
-    error::{SubmissionError, UUID_SHOULD_BE_VALID_STR},
-    model::{Parameter, Submission, TestCase, TestCaseFailureReason, TestCaseResult, TestResult},
+    config::Config,
+    error::SubmissionError,
+    model::{
+        Capabilities, FloatTolerance, Parameter, ParameterType, SourceFile, Submission, TestCase,
+        TestCaseFailureReason, TestCaseResult, TestResult,
+    },
+    retry::retry_io,
+    state::AppState,
+    timeout::MAX_OUTPUT_SIZE,
+    validation::{
+        validate_char_range, validate_compiler_flags, validate_parameter_types,
+        validate_submission, validate_toolchain_version,
+    },
 };
-use std::{fs::File, io::Write, path::PathBuf, time::Duration};
-use tracing::{debug, error, info};
+use regex::Regex;
+use serde::Deserialize;
+use std::{
+    env, fs,
+    fs::Permissions,
+    future::Future,
+    io, iter,
+    os::unix::fs::PermissionsExt,
+    path::{Path, PathBuf},
+    process::{Output, Stdio},
+    sync::{atomic::Ordering, Arc, LazyLock},
+    time::{Duration, Instant},
+};
+use tokio::process::Command;
+use tracing::{debug, error, field, info, info_span, Instrument, Span};
+
+#[cfg(language = "cpp")]
+use cpp::Cpp;
+#[cfg(language = "cpp")]
+mod cpp;
 
-#[cfg(feature = "haskell")]
+#[cfg(language = "haskell")]
 use haskell::Haskell;
-#[cfg(feature = "haskell")]
+#[cfg(language = "haskell")]
 mod haskell;
 
-#[cfg(feature = "python")]
+use jail::is_mount_point;
+mod jail;
+
+#[cfg(language = "kotlin")]
+use kotlin::Kotlin;
+#[cfg(language = "kotlin")]
+mod kotlin;
+
+#[cfg(language = "python")]
 use python::Python;
-#[cfg(feature = "python")]
+#[cfg(language = "python")]
 mod python;
 
-#[cfg(not(feature = "ci"))]
-/// The timeout duration for the compilation and execution process.
-const TIMEOUT: Duration = Duration::from_secs(5);
+#[cfg(language = "cpp")]
+/// The name of the language enabled via feature flags.
+const LANGUAGE: &str = "cpp";
+
+#[cfg(language = "haskell")]
+/// The name of the language enabled via feature flags.
+const LANGUAGE: &str = "haskell";
+
+#[cfg(language = "python")]
+/// The name of the language enabled via feature flags.
+const LANGUAGE: &str = "python";
+
+#[cfg(language = "kotlin")]
+/// The name of the language enabled via feature flags.
+const LANGUAGE: &str = "kotlin";
+
+#[cfg(language = "cpp")]
+/// The program used to compile solutions of the language enabled via feature flags.
+const TOOLCHAIN: &str = "g++";
+
+#[cfg(language = "haskell")]
+/// The program used to compile solutions of the language enabled via feature flags.
+const TOOLCHAIN: &str = "ghc";
+
+#[cfg(language = "python")]
+/// The program used to execute solutions of the language enabled via feature flags.
+const TOOLCHAIN: &str = "python";
+
+#[cfg(language = "kotlin")]
+/// The program used to compile solutions of the language enabled via feature flags.
+const TOOLCHAIN: &str = "kotlinc";
+
+#[cfg(not(language = "python"))]
+/// The separator between the toolchain and a version of it, e.g. `ghc-9.4.7`.
+const TOOLCHAIN_VERSION_SEPARATOR: &str = "-";
+
+#[cfg(language = "python")]
+/// The separator between the toolchain and a version of it, which Python omits, e.g. `python3.12`.
+const TOOLCHAIN_VERSION_SEPARATOR: &str = "";
+
+#[cfg(not(language = "kotlin"))]
+/// The argument that makes the toolchain print its version.
+const TOOLCHAIN_VERSION_ARG: &str = "--version";
+
+#[cfg(language = "kotlin")]
+/// The argument that makes the toolchain print its version, as `kotlinc` only accepts a single dash.
+const TOOLCHAIN_VERSION_ARG: &str = "-version";
+
+#[cfg(language = "cpp")]
+/// A trivial solution of the language enabled via feature flags, returning its input.
+const WARMUP_SOLUTION: &str = "long long solution(long long x) {\n    return x;\n}\n";
+
+#[cfg(any(language = "haskell", language = "python"))]
+/// The parameter types that the language enabled via feature flags cannot represent.
+const UNSUPPORTED_PARAMETER_TYPES: &[ParameterType] = &[];
+
+#[cfg(language = "cpp")]
+/// The parameter types that the language enabled via feature flags cannot represent,
+/// as C++ has neither an arbitrary precision integer nor an exact decimal in its standard library.
+const UNSUPPORTED_PARAMETER_TYPES: &[ParameterType] =
+    &[ParameterType::BigInt, ParameterType::Decimal];
 
-#[cfg(feature = "ci")]
-/// The timeout duration used during pipeline workflows.
-const TIMEOUT: Duration = Duration::from_secs(30);
+#[cfg(language = "kotlin")]
+/// The parameter types that the language enabled via feature flags cannot represent,
+/// as the equality of a `java.math.BigDecimal` depends on its scale, e.g. `0.30` is not equal to `0.3`.
+const UNSUPPORTED_PARAMETER_TYPES: &[ParameterType] = &[ParameterType::Decimal];
+
+#[cfg(any(language = "haskell", language = "python"))]
+/// Whether the language enabled via feature flags can represent a [`ParameterType::Optional`] value.
+const SUPPORTS_OPTIONAL: bool = true;
+
+#[cfg(any(language = "cpp", language = "kotlin"))]
+/// Whether the language enabled via feature flags can represent a [`ParameterType::Optional`] value,
+/// which its test runner cannot display or compare yet.
+const SUPPORTS_OPTIONAL: bool = false;
+
+#[cfg(any(language = "haskell", language = "python"))]
+/// The largest character a [`ParameterType::Char`] of the language enabled via feature flags can hold.
+const MAX_CHAR: char = char::MAX;
+
+#[cfg(language = "kotlin")]
+/// The largest character a [`ParameterType::Char`] of the language enabled via feature flags can hold,
+/// as a `Char` is a single UTF-16 code unit, such that a character beyond the basic multilingual plane does not fit.
+const MAX_CHAR: char = '\u{ffff}';
+
+#[cfg(language = "cpp")]
+/// The largest character a [`ParameterType::Char`] of the language enabled via feature flags can hold,
+/// as a `char` is a single byte, such that any other character would be a multi-character literal.
+const MAX_CHAR: char = '\x7f';
+
+#[cfg(language = "haskell")]
+/// A trivial solution of the language enabled via feature flags, returning its input.
+const WARMUP_SOLUTION: &str = "module Solution where\n\nsolution :: Int -> Int\nsolution x = x\n";
+
+#[cfg(language = "python")]
+/// A trivial solution of the language enabled via feature flags, returning its input.
+const WARMUP_SOLUTION: &str = "def solution(x: int) -> int:\n    return x\n";
+
+#[cfg(language = "kotlin")]
+/// A trivial solution of the language enabled via feature flags, returning its input.
+const WARMUP_SOLUTION: &str = "fun solution(x: Long): Long = x\n";
+
+#[cfg(not(language = "python"))]
+/// The quote around a string of the language enabled via feature flags, which a character is quoted with `'` instead.
+const STRING_QUOTE: char = '"';
+
+#[cfg(language = "python")]
+/// The quote around a string of the language enabled via feature flags, which is the one `repr` prefers.
+const STRING_QUOTE: char = '\'';
 
 /// The replacement target for inserting test cases.
 const TEST_CASES_TARGET: &str = "TEST_CASES";
 
+/// The number of bytes preallocated for each generated test case when building the test code,
+/// which most test cases fit into.
+const GENERATED_TEST_CASE_CAPACITY: usize = 512;
+
+/// The replacement target for inserting the id of a test case, e.g. in its started marker.
+const TEST_CASE_ID_TARGET: &str = "TEST_CASE_ID";
+
+/// The name of the directory inside the working directory containing the stdin of each test case,
+/// in a file named after the id of the test case.
+const STDIN_DIR: &str = "stdin";
+
+/// The replacement target for inserting [`STDIN_DIR`].
+const STDIN_DIR_TARGET: &str = "STDIN_DIR";
+
+/// The name of the file inside the working directory the test runner appends the outcome of each test case to,
+/// such that the outcomes are kept apart from what the solution itself writes to stdout.
+const RESULTS_FILE: &str = "results";
+
+/// The replacement target for inserting [`RESULTS_FILE`].
+const RESULTS_FILE_TARGET: &str = "RESULTS_FILE";
+
+/// The outcome written and flushed before a test case is started, along with the id of the test case.
+///
+/// It makes it possible to attribute a timeout to the test case that was running when the execution was killed.
+const STARTED_OUTCOME: &str = "s";
+
+/// The outcome written after a test case has reported all of its results, along with the id of the test case.
+///
+/// Along with [`STARTED_OUTCOME`], it delimits the run of results of a test case, e.g. one per assertion.
+const ENDED_OUTCOME: &str = "e";
+
+/// The replacement target for inserting the absolute tolerance of a float.
+const ABSOLUTE_TOLERANCE_TARGET: &str = "ABSOLUTE_TOLERANCE";
+
+/// The replacement target for inserting the relative tolerance of a float.
+const RELATIVE_TOLERANCE_TARGET: &str = "RELATIVE_TOLERANCE";
+
+/// The replacement target for inserting whether an expected NaN accepts an actual NaN,
+/// which is replaced by `1` if it does and by `0` otherwise, like [`FAIL_FAST_TARGET`].
+const MATCH_NAN_TARGET: &str = "MATCH_NAN";
+
+/// The replacement target for inserting the maximum length of a value reported for a wrong answer.
+const MAX_VALUE_LENGTH_TARGET: &str = "MAX_VALUE_LENGTH";
+
+/// The replacement target for inserting whether the text of a character or string reported for a wrong answer is
+/// quoted, which is replaced by `1` if it is and by `0` otherwise, like [`FAIL_FAST_TARGET`].
+const QUOTE_VALUES_TARGET: &str = "QUOTE_VALUES";
+
+/// The replacement target for inserting whether the test runner stops after the first test case that did not pass,
+/// which is replaced by `1` if it does and by `0` otherwise, as that is a valid condition in every supported language.
+const FAIL_FAST_TARGET: &str = "FAIL_FAST";
+
+/// The replacement target for inserting whether the lists returned by the solution are compared regardless of the
+/// order of their elements, which is replaced by `1` if they are and by `0` otherwise, like [`FAIL_FAST_TARGET`].
+const UNORDERED_LISTS_TARGET: &str = "UNORDERED_LISTS";
+
+/// The marker appended to a value reported for a wrong answer, if it was truncated to the maximum length.
+const TRUNCATED_VALUE_MARKER: &str = "...";
+
+/// The environment variables every solution execution is given, regardless of the environment of mozart.
+///
+/// They make the execution deterministic across runs, e.g. the iteration order of a set of strings in Python depends on
+/// the seed of its string hashing, which is otherwise random, and the locale affects formatting and collation.
+const EXECUTION_ENV: &[(&str, &str)] = &[("LC_ALL", "C"), ("PYTHONHASHSEED", "0")];
+
+/// The maximum number of processes (and threads) the solution execution may have, which contains e.g. fork bombs.
+///
+/// The limit is counted across all processes of the restricted user, which is distinct for every concurrent execution,
+/// see [`jail::restricted_user_id`], and the processes an execution leaves behind are killed along with it, see [`restrict_resources`].
+const MAX_PROCESSES: libc::rlim_t = 64;
+
 pub trait LanguageHandler {
-    /// Creates a new `LanguageHandler`.
-    fn new(temp_dir: PathBuf) -> Self;
+    /// Creates a new `LanguageHandler`, working inside `temp_dir` and limited by the `config`.
+    fn new(temp_dir: PathBuf, config: Arc<Config>) -> Self;
 
     /// Gets the path to the test file, the path should contain the file extension.
     fn test_file_path(&self) -> PathBuf;
 
-    /// Gets the basic test runner before generated test cases, the solution, and the output file path are inserted.
+    /// Gets the basic test runner before generated test cases and the solution are inserted.
     ///
     /// The test cases are inserted in place of the value in [`TEST_CASES_TARGET`].
     fn base_test_code(&self) -> &str;
@@ -46,194 +256,830 @@ pub trait LanguageHandler {
     /// Gets the path to the test runner file, the path should contain the file extension.
     fn test_runner_file_path(&self) -> PathBuf;
 
+    /// Gets the path to the checker file, the path should contain the file extension.
+    fn checker_file_path(&self) -> PathBuf;
+
+    /// Gets the checker used if the submission does not supply one, which accepts a value equal to the expected value.
+    ///
+    /// The checker defines the `checker` function called by the test runner for every test case.
+    fn default_checker_code(&self) -> &str;
+
+    /// Gets the checker used if the submission supplies a float tolerance, which accepts a float within the tolerance
+    /// of the expected float, and any other value equal to the expected value.
+    ///
+    /// The tolerances are inserted in place of [`ABSOLUTE_TOLERANCE_TARGET`] and [`RELATIVE_TOLERANCE_TARGET`],
+    /// and whether an expected NaN accepts an actual NaN in place of [`MATCH_NAN_TARGET`].
+    /// An expected NaN or infinity is not compared within the tolerance.
+    fn tolerant_checker_code(&self) -> &str;
+
+    /// Gets the path to the reference file, the path should contain the file extension.
+    fn reference_file_path(&self) -> PathBuf;
+
+    /// Gets the code of the reference file for the `reference_solution` of the submission.
+    ///
+    /// The reference file is written even if the submission does not supply a reference solution,
+    /// in which case it defines nothing, but can still be imported by the test code.
+    fn reference_code(&self, reference_solution: Option<&str>) -> String;
+
     /// Gets the test runner for the given language.
     ///
     /// The test runner is the code that provides a custom assert function, such that solution
     /// answers can be checked up against the expected output for a given test case.
     ///
-    /// The output file path is inserted in place of the value in [`OUTPUT_FILE_PATH_TARGET`].
+    /// The outcomes are appended to the file inserted in place of [`RESULTS_FILE_TARGET`], relative to the jail root.
     fn test_runner_code(&self) -> &str;
 
-    /// Generates the language specific test cases.
-    fn generate_test_cases(&self, test_cases: &[TestCase]) -> String;
+    /// Validates the language specific requirements of the `solution`, which there are none of by default.
+    ///
+    /// # Errors
+    /// Returns a `SubmissionError::InvalidSolution` describing the requirement that is not met.
+    fn validate_solution(&self, _solution: &str) -> Result<(), SubmissionError> {
+        Ok(())
+    }
+
+    /// Generates the language specific test cases, appending them to the `test_code` being built.
+    ///
+    /// If `with_reference` is set, the expected value of a test case without output parameters is the value the
+    /// reference solution returns for its input parameters.
+    fn generate_test_cases(
+        &self,
+        test_code: &mut String,
+        test_cases: &[TestCase],
+        with_reference: bool,
+    );
 
     /// Formats a parameter to the necessary language specific syntax.
     fn format_parameter(&self, parameter: &Parameter) -> String;
 
-    /// Runs the submission against the test cases.
+    /// Explains the `err` of compiling the `solution` along with test cases that call it with `arguments` arguments,
+    /// if it was caused by the solution expecting another number of arguments, which the toolchain may report
+    /// cryptically, e.g. as a type error of the test code. The error is kept as is by default.
+    fn explain_arity_mismatch(
+        &self,
+        err: SubmissionError,
+        _solution: &str,
+        _arguments: usize,
+    ) -> SubmissionError {
+        err
+    }
+
+    /// Compiles the submission, i.e. the solution, checker, test runner and test code, passing the `compiler_flags`
+    /// of the submission to the `toolchain`, e.g. `ghc-9.4.7`.
+    ///
+    /// It does nothing by default, as an interpreted language has nothing to compile before it is executed.
+    ///
+    /// # Errors
+    /// Returns a `SubmissionError::Compilation` if the submission does not compile,
+    /// and a `SubmissionError::CompileTimeout` if compiling it exceeded the timeout.
+    async fn compile(
+        &self,
+        _toolchain: &str,
+        _compiler_flags: &[String],
+    ) -> Result<(), SubmissionError> {
+        Ok(())
+    }
+
+    /// Executes the compiled submission against the test cases.
+    ///
+    /// The `toolchain` is the program of the interpreter that is invoked, which is passed the `compiler_flags` of the
+    /// submission, e.g. `python3.11`. A compiled language executes its compiled program instead, ignoring both.
+    ///
+    /// The execution runs as the restricted user of the `slot` the submission holds, see [`jail::restricted_user_id`].
+    ///
+    /// Returns the test output along with what the solution wrote to stdout.
+    async fn execute(
+        &self,
+        toolchain: &str,
+        compiler_flags: &[String],
+        slot: usize,
+    ) -> Result<(TestOutput, String), SubmissionError>;
+}
+
+/// The output of running the test cases against a solution, read from [`RESULTS_FILE`].
+pub enum TestOutput {
+    /// The execution finished running all of the test cases.
+    Finished(String),
+
+    /// The execution exceeded the timeout, so the output only contains the test cases started before it was killed.
+    TimedOut(String),
+
+    /// The execution terminated abnormally, so the output only contains the test cases started before it crashed.
+    ///
+    /// The second `String` contains the error describing the crash.
+    Crashed(String, String),
+}
+
+/// The outcome of a test case, written by the test runner as a JSON object on its own line.
+///
+/// The kind of outcome is given by the `r` field, e.g. `{"r":"f","actual":"5","expected":"-5"}` for a wrong answer.
+#[derive(Debug, Deserialize)]
+#[serde(tag = "r")]
+enum Outcome {
+    /// Written and flushed before a test case is started, see [`STARTED_OUTCOME`].
+    #[serde(rename = "s")]
+    Started { id: u64 },
+
+    /// Written after a test case reported all of its results, see [`ENDED_OUTCOME`].
+    #[serde(rename = "e")]
+    Ended { id: u64 },
+
+    /// The test case passed.
+    #[serde(rename = "p")]
+    Pass,
+
+    /// The solution returned the rendered `actual` value, instead of the rendered `expected` value.
     ///
-    /// If the programming language is compiled, then this step **also** includes compilation of the source code.
-    async fn run(&self) -> Result<String, SubmissionError>;
+    /// If the expected value is structured, they are also written as typed JSON in `actualValue` and `expectedValue`,
+    /// unless it is too long.
+    #[serde(rename = "f", rename_all = "camelCase")]
+    WrongAnswer {
+        actual: String,
+        expected: String,
+        actual_value: Option<serde_json::Value>,
+        expected_value: Option<serde_json::Value>,
+    },
+
+    /// The test case exceeded its timeout.
+    #[serde(rename = "t")]
+    Timeout,
+
+    /// The solution raised an error, described by `msg`.
+    #[serde(rename = "err")]
+    RuntimeError { msg: String },
+
+    /// The solution returned the rendered `actual` value, for a test case that expects a runtime error.
+    #[serde(rename = "v")]
+    Returned { actual: String },
+}
+
+/// The reason why the execution stopped before finishing all of the test cases.
+#[derive(Debug)]
+enum Interruption {
+    /// The execution exceeded the contained timeout.
+    Timeout(Duration),
+
+    /// The execution terminated abnormally, with the contained error.
+    Crash(String),
+
+    /// The execution stopped after the first test case that did not pass, as it was asked to fail fast.
+    FailedFast,
 }
 
 /// The runner responsible for testing a solution against a set of test cases.
 ///
 /// The underlying language being tested is determined at compile time via feature flags.
 pub struct TestRunner {
-    #[cfg(feature = "haskell")]
+    #[cfg(language = "cpp")]
+    handler: Cpp,
+    #[cfg(language = "haskell")]
     handler: Haskell,
-    #[cfg(feature = "python")]
+    #[cfg(language = "python")]
     handler: Python,
+    #[cfg(language = "kotlin")]
+    handler: Kotlin,
+
+    /// The shared state, including the configuration, the submission is checked with.
+    state: AppState,
+
+    /// Whether the execution stops after the first test case that did not pass, skipping the remaining test cases.
+    fail_fast: bool,
+
+    /// The slot the submission holds, which selects the restricted user the execution runs as.
+    slot: usize,
 }
 
 impl TestRunner {
-    /// Create a new test runner, based on the enabled feature flag for toggling languages.
-    pub fn new(temp_dir: PathBuf) -> Self {
+    /// Create a new test runner with the shared `state`, based on the enabled feature flag for toggling languages.
+    pub fn new(temp_dir: PathBuf, state: AppState) -> Self {
         Self {
-            #[cfg(feature = "haskell")]
-            handler: Haskell::new(temp_dir),
-            #[cfg(feature = "python")]
-            handler: Python::new(temp_dir),
+            #[cfg(language = "cpp")]
+            handler: Cpp::new(temp_dir, Arc::clone(&state.config)),
+            #[cfg(language = "haskell")]
+            handler: Haskell::new(temp_dir, Arc::clone(&state.config)),
+            #[cfg(language = "python")]
+            handler: Python::new(temp_dir, Arc::clone(&state.config)),
+            #[cfg(language = "kotlin")]
+            handler: Kotlin::new(temp_dir, Arc::clone(&state.config)),
+            state,
+            fail_fast: false,
+            slot: 0,
         }
     }
 
-    /// Checks a given submissmion against the provided test cases.
+    /// Makes the execution run as the restricted user of the `slot`, which must not be held by another submission
+    /// at the same time, such that the resource limits counted per user apply to this execution alone.
+    pub fn slot(mut self, slot: usize) -> Self {
+        self.slot = slot;
+        self
+    }
+
+    /// Makes the execution stop after the first test case that did not pass, if `fail_fast` is true,
+    /// such that the remaining test cases are skipped and get an unknown result.
+    ///
+    /// It is only useful if it does not matter which test cases fail, but just whether any of them does.
+    /// It has no effect on a submission with a test case that expects a runtime error.
+    pub fn fail_fast(mut self, fail_fast: bool) -> Self {
+        self.fail_fast = fail_fast;
+        self
+    }
+
+    /// Gets the path of the test file that is generated for the submission, and then compiled or executed.
+    pub fn test_file_path(&self) -> PathBuf {
+        self.handler.test_file_path()
+    }
+
+    /// Confirms that the toolchain of the enabled language can be run, by asking it for its version.
     ///
     /// # Errors
+    /// Returns a `SubmissionError::ToolchainNotFound` if the toolchain is not installed,
+    /// and a `SubmissionError::Internal` if it could not be run within the timeout of the `config`.
+    pub async fn check_toolchain(config: &Config) -> Result<(), SubmissionError> {
+        check_program(TOOLCHAIN, TOOLCHAIN_VERSION_ARG, config.timeout).await
+    }
+
+    /// Gets a trivial submission that passes if the toolchain of the enabled language works.
+    pub fn warmup_submission() -> Submission {
+        let parameter = Parameter {
+            value_type: ParameterType::Int,
+            value: String::from("1"),
+        };
+
+        Submission {
+            solution: String::from(WARMUP_SOLUTION),
+            test_cases: Box::new([TestCase {
+                id: 0,
+                input_parameters: Box::new([parameter.clone()]),
+                output_parameters: Box::new([parameter]),
+                stdin: None,
+                expected_error: None,
+                label: None,
+                hidden: false,
+            }]),
+            unordered_lists: false,
+            files: Box::new([]),
+            checker: None,
+            reference_solution: None,
+            only_ids: None,
+            compiler_flags: Box::new([]),
+            debug: false,
+            float_tolerance: None,
+            toolchain_version: None,
+            metadata: None,
+        }
+    }
+
+    /// Gets the capabilities of the test runner, i.e. its language, supported parameter types and the timeouts of the `config`.
+    pub fn capabilities(config: &Config) -> Capabilities {
+        Capabilities {
+            languages: Box::new([String::from(LANGUAGE)]),
+            parameter_types: ParameterType::ALL
+                .into_iter()
+                .filter(|parameter_type| !UNSUPPORTED_PARAMETER_TYPES.contains(parameter_type))
+                .collect(),
+            timeout_ms: config.timeout.as_millis() as u64,
+            test_case_timeout_ms: config.test_case_timeout.as_millis() as u64,
+        }
+    }
+
+    /// Checks a given submissmion against the provided test cases.
+    ///
+    /// Returns the result of the check along with what the solution wrote to stdout, if it was executed and wrote
+    /// anything, truncated to the maximum length of the configuration.
     /// An `Ok` result indicates that all test cases were passed.
     /// An `Err` result can indicate a number of things specified in the variants of `[SubmissionError]`.
-    pub async fn check(self, submission: Submission) -> Result<(), SubmissionError> {
-        info!("creating solution file");
-        let mut solution_file = match File::create(self.handler.solution_file_path()) {
-            Ok(tf) => tf,
-            Err(err) => {
-                error!("could not create solution file: {}", err);
-                return Err(SubmissionError::Internal);
-            }
-        };
+    pub async fn check(
+        self,
+        submission: Submission,
+    ) -> (Result<(), SubmissionError>, Option<String>) {
+        let mut stdout = None;
+        let result = self.check_submission(submission, &mut stdout).await;
+        (result, stdout)
+    }
+
+    /// Checks a given submission like [`TestRunner::check`], setting `stdout` to what the solution wrote to it.
+    async fn check_submission(
+        &self,
+        submission: Submission,
+        stdout: &mut Option<String>,
+    ) -> Result<(), SubmissionError> {
+        if submission.solution.trim().is_empty() {
+            info!("solution is empty");
+            return Err(SubmissionError::EmptySolution);
+        }
+
+        if submission.solution.len() > self.state.config.max_solution_length {
+            info!(
+                "solution is {} bytes long, which exceeds the limit",
+                submission.solution.len()
+            );
+            return Err(SubmissionError::SolutionTooLong(
+                self.state.config.max_solution_length,
+            ));
+        }
+
+        if submission
+            .checker
+            .as_ref()
+            .is_some_and(|checker| checker.trim().is_empty())
+        {
+            info!("checker is empty");
+            return Err(SubmissionError::EmptyChecker);
+        }
+
+        validate_submission(&submission)?;
+        validate_parameter_types(&submission, UNSUPPORTED_PARAMETER_TYPES, SUPPORTS_OPTIONAL)?;
+        validate_char_range(&submission, MAX_CHAR)?;
+        validate_compiler_flags(
+            &submission.compiler_flags,
+            &self.state.config.allowed_compiler_flags,
+        )?;
+        validate_toolchain_version(
+            submission.toolchain_version.as_deref(),
+            &self.state.config.toolchain_versions,
+        )?;
+        self.handler.validate_solution(&submission.solution)?;
+
+        info!("writing auxiliary files");
+        for file in &submission.files {
+            self.write_auxiliary_file(file).await?;
+        }
 
         info!("writing solution to file");
         debug!(?submission.solution);
-        if let Err(err) = solution_file.write_all(submission.solution.as_bytes()) {
+        let solution_file_path = self.handler.solution_file_path();
+        if let Err(err) = retry_io("write solution file", || {
+            fs::write(&solution_file_path, submission.solution.as_bytes())
+        })
+        .await
+        {
             error!("could not write solution to file: {}", err);
             return Err(SubmissionError::Internal);
         }
 
-        info!("creating test runner file");
-        let mut test_runner_file = match File::create(self.handler.test_runner_file_path()) {
-            Ok(tf) => tf,
-            Err(err) => {
-                error!("could not create test runner file: {}", err);
-                return Err(SubmissionError::Internal);
-            }
-        };
+        // a test case expecting a runtime error passes with the outcome the test runner fails fast after
+        let fail_fast = self.fail_fast
+            && submission
+                .test_cases
+                .iter()
+                .all(|test_case| test_case.expected_error.is_none());
 
         info!("writing test runner to file");
-        if let Err(err) = test_runner_file.write_all(self.handler.test_runner_code().as_bytes()) {
+        let test_runner_file_path = self.handler.test_runner_file_path();
+        let test_runner_code = self
+            .handler
+            .test_runner_code()
+            .replace(FAIL_FAST_TARGET, if fail_fast { "1" } else { "0" })
+            .replace(
+                UNORDERED_LISTS_TARGET,
+                if submission.unordered_lists { "1" } else { "0" },
+            );
+        if let Err(err) = retry_io("write test runner file", || {
+            fs::write(&test_runner_file_path, test_runner_code.as_bytes())
+        })
+        .await
+        {
             error!("could not write test runner to file: {}", err);
             return Err(SubmissionError::Internal);
         }
 
-        info!("generating language specific test cases");
-        let generated_test_cases = self.handler.generate_test_cases(&submission.test_cases);
-        debug!(?generated_test_cases);
-
-        let test_code = self
-            .handler
-            .base_test_code()
-            .replace(TEST_CASES_TARGET, &generated_test_cases);
-
-        info!("creating test file");
-        let mut test_file = match File::create(self.handler.test_file_path().as_path()) {
-            Ok(tf) => tf,
-            Err(err) => {
-                error!("could not create test file: {}", err);
-                return Err(SubmissionError::Internal);
+        info!("writing checker to file");
+        let checker_file_path = self.handler.checker_file_path();
+        let checker = match (&submission.checker, submission.float_tolerance) {
+            (Some(checker), _) => checker.clone(),
+            (None, Some(tolerance)) => {
+                insert_float_tolerance(self.handler.tolerant_checker_code(), tolerance)
             }
+            (None, None) => self.handler.default_checker_code().to_string(),
         };
+        debug!(?checker);
+        if let Err(err) = retry_io("write checker file", || {
+            fs::write(&checker_file_path, checker.as_bytes())
+        })
+        .await
+        {
+            error!("could not write checker to file: {}", err);
+            return Err(SubmissionError::Internal);
+        }
+
+        info!("writing reference solution to file");
+        let reference_file_path = self.handler.reference_file_path();
+        let reference_code = self
+            .handler
+            .reference_code(submission.reference_solution.as_deref());
+        debug!(?reference_code);
+        if let Err(err) = retry_io("write reference file", || {
+            fs::write(&reference_file_path, reference_code.as_bytes())
+        })
+        .await
+        {
+            error!("could not write reference solution to file: {}", err);
+            return Err(SubmissionError::Internal);
+        }
+
+        let test_cases = submission.selected_test_cases();
+        if submission.only_ids.is_some() {
+            info!(
+                "running {} of {} test cases",
+                test_cases.len(),
+                submission.test_cases.len()
+            );
+        }
+
+        self.write_stdin_files(&test_cases).await?;
+        self.create_results_file().await?;
+
+        info!("generating language specific test cases");
+        let test_code = self.test_code(&test_cases, submission.reference_solution.is_some());
+        debug!(?test_code);
 
         info!("writing to test file");
-        if let Err(err) = test_file.write_all(test_code.as_bytes()) {
+        let test_file_path = self.handler.test_file_path();
+        if let Err(err) = retry_io("write test file", || {
+            fs::write(&test_file_path, test_code.as_bytes())
+        })
+        .await
+        {
             error!("failed to write test case: {}", err);
             return Err(SubmissionError::Internal);
         }
 
-        let test_output = self.handler.run().await?;
+        let toolchain = toolchain_program(submission.toolchain_version.as_deref());
+        if let Err(err) = timed(
+            compile_span(),
+            self.handler.compile(&toolchain, &submission.compiler_flags),
+        )
+        .await
+        {
+            // every test case is expected to call the solution with the same number of arguments
+            return Err(match test_cases.first() {
+                Some(test_case) => self.handler.explain_arity_mismatch(
+                    err,
+                    &submission.solution,
+                    test_case.input_parameters.len(),
+                ),
+                None => err,
+            });
+        }
 
-        let test_case_results =
-            TestRunner::parse_test_output(&test_output, &submission.test_cases)?;
+        let (test_output, solution_stdout) = timed(
+            execute_span(),
+            self.handler
+                .execute(&toolchain, &submission.compiler_flags, self.slot),
+        )
+        .await?;
+        // the captured stdout is bounded while it is read, so a solution printing too much keeps its verdict,
+        // and it is not responded with if any test case is hidden, as the solution could print the hidden inputs
+        let has_hidden = test_cases.iter().any(|test_case| test_case.hidden);
+        if !solution_stdout.is_empty() && !has_hidden {
+            *stdout = Some(truncate_stdout(
+                &solution_stdout,
+                self.state.config.max_stdout_length,
+            ));
+        }
 
-        if test_case_results
-            .iter()
-            .all(|tc| tc.test_result == TestResult::Pass)
+        let (test_output, interruption) = match test_output {
+            TestOutput::Finished(test_output) => {
+                (test_output, fail_fast.then_some(Interruption::FailedFast))
+            }
+            TestOutput::TimedOut(test_output) => (
+                test_output,
+                Some(Interruption::Timeout(self.state.config.timeout)),
+            ),
+            TestOutput::Crashed(test_output, error) => {
+                (test_output, Some(Interruption::Crash(error)))
+            }
+        };
+        let failed_fast = matches!(interruption, Some(Interruption::FailedFast));
+        let mut test_case_results = TestRunner::parse_test_output(
+            &test_output,
+            &test_cases,
+            interruption,
+            self.state.config.max_results_per_test_case,
+        )?;
+        if self.state.config.quote_values {
+            quote_input_parameters(&mut test_case_results);
+        }
+        hide_failure_reasons(&mut test_case_results, &test_cases);
+        if failed_fast {
+            let skipped = test_case_results
+                .iter()
+                .filter(|result| result.test_result == TestResult::Unknown)
+                .count();
+            self.state
+                .skipped_test_cases
+                .fetch_add(skipped as u64, Ordering::Relaxed);
+        }
+
+        verdict(test_case_results)
+    }
+
+    /// Builds the test code, i.e. the base test code with the generated `test_cases` in place of [`TEST_CASES_TARGET`].
+    ///
+    /// It is built in a single string, preallocated for [`GENERATED_TEST_CASE_CAPACITY`] bytes per test case,
+    /// such that a submission with thousands of test cases is not copied over and over again.
+    pub fn test_code(&self, test_cases: &[TestCase], with_reference: bool) -> String {
+        let base_test_code = self.handler.base_test_code();
+        let (before, after) = base_test_code
+            .split_once(TEST_CASES_TARGET)
+            .expect("base test code should contain the test cases target");
+
+        let mut test_code = String::with_capacity(
+            base_test_code.len() + test_cases.len() * GENERATED_TEST_CASE_CAPACITY,
+        );
+        test_code.push_str(before);
+        self.handler
+            .generate_test_cases(&mut test_code, test_cases, with_reference);
+        test_code.push_str(after);
+
+        test_code
+    }
+
+    /// Writes an auxiliary `file` of the submission next to the solution.
+    ///
+    /// # Errors
+    /// Returns a `SubmissionError::InvalidFileName` if the file would overwrite the solution, test runner, checker,
+    /// reference or test file, the stdin directory, the results file or a mount point of the jail.
+    async fn write_auxiliary_file(&self, file: &SourceFile) -> Result<(), SubmissionError> {
+        let reserved_paths = [
+            self.handler.solution_file_path(),
+            self.handler.test_runner_file_path(),
+            self.handler.checker_file_path(),
+            self.handler.reference_file_path(),
+            self.handler.test_file_path(),
+            self.handler.solution_file_path().with_file_name(STDIN_DIR),
+            self.handler
+                .solution_file_path()
+                .with_file_name(RESULTS_FILE),
+        ];
+        let solution_path = self.handler.solution_file_path();
+        let root = solution_path.parent().unwrap_or(&solution_path);
+        let path = solution_path.with_file_name(&file.name);
+        if reserved_paths.contains(&path) || is_mount_point(&path, root) {
+            debug!("file name '{}' is reserved", file.name);
+            return Err(SubmissionError::InvalidFileName(format!(
+                "'{}' is reserved",
+                file.name
+            )));
+        }
+
+        debug!("writing auxiliary file '{}'", file.name);
+        if let Err(err) = retry_io("write auxiliary file", || {
+            fs::write(&path, file.contents.as_bytes())
+        })
+        .await
         {
-            info!("passed all test cases");
-            Ok(())
-        } else {
-            info!("did not pass all test cases");
-            Err(SubmissionError::Failure(test_case_results))
+            error!("could not write auxiliary file: {}", err);
+            return Err(SubmissionError::Internal);
+        }
+
+        Ok(())
+    }
+
+    /// Writes the stdin of every test case to its file inside [`STDIN_DIR`], which is empty if it has no stdin.
+    ///
+    /// The test code redirects stdin to the file of a test case before running it.
+    async fn write_stdin_files(&self, test_cases: &[TestCase]) -> Result<(), SubmissionError> {
+        info!("writing stdin of test cases to files");
+        let stdin_dir = self.handler.solution_file_path().with_file_name(STDIN_DIR);
+        if let Err(err) = retry_io("create stdin directory", || fs::create_dir(&stdin_dir)).await {
+            error!("could not create stdin directory: {}", err);
+            return Err(SubmissionError::Internal);
+        }
+
+        for test_case in test_cases {
+            let path = stdin_dir.join(test_case.id.to_string());
+            let stdin = test_case.stdin.as_deref().unwrap_or_default();
+            if let Err(err) =
+                retry_io("write stdin file", || fs::write(&path, stdin.as_bytes())).await
+            {
+                error!(
+                    "could not write stdin of test case '{}': {}",
+                    test_case.id, err
+                );
+                return Err(SubmissionError::Internal);
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Creates the empty [`RESULTS_FILE`], which anyone may write to, as the restricted user executing the test runner
+    /// cannot create files in the working directory.
+    async fn create_results_file(&self) -> Result<(), SubmissionError> {
+        info!("creating results file");
+        let path = self
+            .handler
+            .solution_file_path()
+            .with_file_name(RESULTS_FILE);
+        if let Err(err) = retry_io("create results file", || {
+            fs::write(&path, b"")?;
+            fs::set_permissions(&path, Permissions::from_mode(0o666))
+        })
+        .await
+        {
+            error!("could not create results file: {}", err);
+            return Err(SubmissionError::Internal);
         }
+
+        Ok(())
     }
 
     /// Parses the internal format produces by running test cases against a solution.
     ///
+    /// A test case that is delimited by its started and ended markers may report a run of up to `max_results` results,
+    /// e.g. one per assertion, and only passes if all of them did. A result outside of the markers is the only result
+    /// of the next test case.
+    ///
+    /// If the execution had an `interruption`, the test case that was started but did not finish is marked as
+    /// having failed due to it, and the test cases that were never started are marked as unknown,
+    /// such that e.g. a solution that exits the process still gets the results of the test cases before it.
+    /// If it failed fast, no test case was interrupted, so only the test cases that were never started are marked.
+    ///
     /// # Errors
     /// An `Ok` result indicates that the test output was correctly parsed.
-    /// An `Err` result indicates that the output file was formatted in a wrong way, and was unparseable.
+    /// An `Err` result indicates that the output file was formatted in a wrong way, and was unparseable,
+    /// that the number of results does not match the number of test cases even though the execution was not interrupted,
+    /// or that the execution was interrupted without any test case running.
     fn parse_test_output(
         test_output: &str,
         test_cases: &[TestCase],
+        interruption: Option<Interruption>,
+        max_results: usize,
     ) -> Result<Box<[TestCaseResult]>, SubmissionError> {
         info!("parsing test output");
 
-        if test_output.trim().is_empty() {
+        // without test cases, e.g. to only compile and run a solution, there is nothing to output
+        if interruption.is_none() && !test_cases.is_empty() && test_output.trim().is_empty() {
             error!("test output is empty");
             return Err(SubmissionError::Internal);
         }
 
         let mut test_case_results = Vec::new();
-        for (index, line) in test_output.lines().enumerate() {
-            let test_case = &test_cases[index];
+        // the test case that was started but has not ended yet, along with the results it reported so far
+        let mut started: Option<(&TestCase, Vec<TestResult>)> = None;
+        for line in test_output.lines() {
+            let Some(test_case) = test_cases.get(test_case_results.len()) else {
+                error!(
+                    "test output contains more than {} results, but there are {} test cases",
+                    test_case_results.len(),
+                    test_cases.len()
+                );
+                return Err(SubmissionError::Internal);
+            };
 
             if line.trim().is_empty() {
                 error!("empty line in output file for test case '{}'", test_case.id);
                 return Err(SubmissionError::Internal);
             }
 
-            let mut split = line.split(',');
-            let result = match split.next().expect("line should not be empty") {
-                "p" => TestCaseResult {
-                    id: test_case.id,
-                    test_result: TestResult::Pass,
-                },
-                "f" => {
-                    let (Some(actual), Some(expected)) = (split.next(), split.next()) else {
+            let outcome = match serde_json::from_str::<Outcome>(line) {
+                Ok(outcome) => outcome,
+                Err(err) => {
+                    error!(
+                        "unknown test outcome '{}' for test case '{}': {}",
+                        line, test_case.id, err
+                    );
+                    return Err(SubmissionError::Internal);
+                }
+            };
+
+            let test_result = match outcome {
+                Outcome::Started { id } => {
+                    if started.is_some() {
                         error!(
-                            "test case '{}' failure did not provide actual and expected values",
+                            "test case '{}' was started before the previous one ended",
                             test_case.id
                         );
                         return Err(SubmissionError::Internal);
-                    };
-
-                    TestCaseResult {
-                        id: test_case.id,
-                        test_result: TestResult::Failure(TestCaseFailureReason::WrongAnswer {
-                            input_parameters: test_case.input_parameters.clone(),
-                            actual: actual.to_string(),
-                            expected: expected.to_string(),
-                        }),
                     }
+                    if id != test_case.id {
+                        error!("test case '{}' was started with a wrong id", test_case.id);
+                        return Err(SubmissionError::Internal);
+                    }
+
+                    started = Some((test_case, Vec::new()));
+                    continue;
                 }
-                "r" => {
-                    let error = split.collect::<String>().replace("\\n", "\n");
-
-                    TestCaseResult {
-                        id: test_case.id,
-                        test_result: TestResult::Failure(TestCaseFailureReason::RuntimeError(
-                            error.to_string(),
-                        )),
+                Outcome::Ended { id } => {
+                    let Some((_, results)) = started.take() else {
+                        error!("test case '{}' ended without being started", test_case.id);
+                        return Err(SubmissionError::Internal);
+                    };
+                    if id != test_case.id {
+                        error!("test case '{}' was ended with a wrong id", test_case.id);
+                        return Err(SubmissionError::Internal);
+                    }
+                    if results.is_empty() {
+                        error!("test case '{}' ended without any result", test_case.id);
+                        return Err(SubmissionError::Internal);
                     }
+
+                    test_case_results.push(TestCaseResult::new(
+                        test_case,
+                        combine_test_results(results),
+                    ));
+                    continue;
+                }
+                Outcome::Pass => TestResult::Pass,
+                Outcome::WrongAnswer {
+                    actual,
+                    expected,
+                    actual_value,
+                    expected_value,
+                } => TestResult::Failure(TestCaseFailureReason::WrongAnswer {
+                    input_parameters: test_case.input_parameters.clone(),
+                    actual,
+                    expected,
+                    actual_value,
+                    expected_value,
+                }),
+                Outcome::Timeout => TestResult::Failure(TestCaseFailureReason::Timeout),
+                Outcome::RuntimeError { msg } => runtime_error_result(test_case, msg),
+                Outcome::Returned { actual } => {
+                    TestResult::Failure(TestCaseFailureReason::MissingError {
+                        input_parameters: test_case.input_parameters.clone(),
+                        actual,
+                    })
                 }
-                unknown => {
+            };
+
+            match &mut started {
+                Some((_, results)) if results.len() >= max_results => {
                     error!(
-                        "unknown test outcome '{}' for test case '{}'",
-                        unknown, test_case.id
+                        "test case '{}' reported more than {} results",
+                        test_case.id, max_results
                     );
                     return Err(SubmissionError::Internal);
                 }
+                Some((_, results)) => results.push(test_result),
+                None => test_case_results.push(TestCaseResult::new(test_case, test_result)),
+            }
+        }
+
+        if interruption.is_none() && test_case_results.len() != test_cases.len() {
+            error!(
+                "test output contains {} results, but there are {} test cases",
+                test_case_results.len(),
+                test_cases.len()
+            );
+            return Err(SubmissionError::Internal);
+        }
+
+        if let Some(interruption) = interruption {
+            let interrupted = match (started, interruption) {
+                (None, Interruption::FailedFast) => None,
+                (None, Interruption::Timeout(timeout)) if test_case_results.is_empty() => {
+                    info!("timeout could not be attributed to a test case");
+                    return Err(SubmissionError::ExecuteTimeout(timeout));
+                }
+                // the results of the finished test cases are kept, and the timeout is attributed to the test case
+                // that would have been started next, as it was killed in between two test cases
+                (None, Interruption::Timeout(_)) => {
+                    test_cases.get(test_case_results.len()).map(|test_case| {
+                        (
+                            test_case,
+                            TestResult::Failure(TestCaseFailureReason::Timeout),
+                        )
+                    })
+                }
+                (None, Interruption::Crash(error)) => {
+                    info!("crash could not be attributed to a test case");
+                    return Err(SubmissionError::Execution(error));
+                }
+                // the execution exits right after the result that did not pass, before the test case is ended
+                (Some((test_case, results)), Interruption::FailedFast) => {
+                    if results.iter().all(|result| *result == TestResult::Pass) {
+                        error!(
+                            "test case '{}' did not finish, even though the execution failed fast",
+                            test_case.id
+                        );
+                        return Err(SubmissionError::Internal);
+                    }
+                    Some((test_case, combine_test_results(results)))
+                }
+                (Some((test_case, mut results)), Interruption::Timeout(_)) => {
+                    results.push(TestResult::Failure(TestCaseFailureReason::Timeout));
+                    Some((test_case, combine_test_results(results)))
+                }
+                (Some((test_case, mut results)), Interruption::Crash(error)) => {
+                    results.push(runtime_error_result(test_case, error));
+                    Some((test_case, combine_test_results(results)))
+                }
             };
+            if let Some((test_case, test_result)) = interrupted {
+                info!("test case '{}' was interrupted", test_case.id);
+                test_case_results.push(TestCaseResult::new(test_case, test_result));
+            }
 
-            test_case_results.push(result);
+            let unstarted = &test_cases[test_case_results.len()..];
+            if !unstarted.is_empty() {
+                info!("{} test cases were never started", unstarted.len());
+            }
+            test_case_results.extend(
+                unstarted
+                    .iter()
+                    .map(|test_case| TestCaseResult::new(test_case, TestResult::Unknown)),
+            );
         }
 
         debug!(?test_case_results);
@@ -241,111 +1087,588 @@ impl TestRunner {
     }
 }
 
-/// Removes the instances of the PathBuf p in s.
-fn remove_mozart_path(s: &str, mut p: PathBuf) -> String {
-    p.push("");
-    let path = p.to_str().expect(UUID_SHOULD_BE_VALID_STR);
-    s.replace(path, "")
+/// Combines the `results` reported by a single test case into its result, which is the first one that did not pass,
+/// such that a test case with multiple assertions only passes if all of them did.
+fn combine_test_results(results: Vec<TestResult>) -> TestResult {
+    results
+        .into_iter()
+        .find(|result| *result != TestResult::Pass)
+        .unwrap_or(TestResult::Pass)
 }
 
-#[cfg(test)]
-mod parse_output_file {
-    use super::TestRunner;
-    use crate::{
-        error::SubmissionError,
-        model::{
-            Parameter, ParameterType, TestCase, TestCaseFailureReason, TestCaseResult, TestResult,
-        },
-    };
+/// Gets the result of the `test_case` in which the solution raised the runtime error described by `msg`,
+/// which passes if the test case expects a matching error.
+fn runtime_error_result(test_case: &TestCase, msg: String) -> TestResult {
+    match &test_case.expected_error {
+        Some(expected_error) if expected_error.matches(&msg) => TestResult::Pass,
+        _ => TestResult::Failure(TestCaseFailureReason::RuntimeError(msg)),
+    }
+}
 
-    /// A test util function to make a test case with the supplied `id` and empty parameters.
-    fn empty_test_case(id: u64) -> TestCase {
-        TestCase {
-            id,
-            input_parameters: Box::new([]),
-            output_parameters: Box::new([]),
+/// Quotes the characters and strings among the input parameters of every wrong answer in the `test_case_results`,
+/// such that they are displayed like the actual and expected values, which the test runner quotes if values are quoted.
+fn quote_input_parameters(test_case_results: &mut [TestCaseResult]) {
+    for result in test_case_results {
+        let TestResult::Failure(TestCaseFailureReason::WrongAnswer {
+            input_parameters, ..
+        }) = &mut result.test_result
+        else {
+            continue;
+        };
+
+        for parameter in input_parameters.iter_mut() {
+            let quote = match parameter.value_type {
+                ParameterType::Char => '\'',
+                ParameterType::String => STRING_QUOTE,
+                _ => continue,
+            };
+            parameter.value = quote_text(&parameter.value, quote);
         }
     }
+}
 
-    #[test]
-    fn empty_test_output() {
-        let test_output = "";
-        // the parameters are not necessary for this test, only the test case id
-        let test_cases = [empty_test_case(0), empty_test_case(1), empty_test_case(2)];
+/// Replaces the failure reason of every hidden test case among the `test_cases` with [`TestCaseFailureReason::Hidden`],
+/// such that their input parameters, actual and expected values are not disclosed.
+///
+/// The `test_case_results` are those of the `test_cases`, in the same order.
+fn hide_failure_reasons(test_case_results: &mut [TestCaseResult], test_cases: &[TestCase]) {
+    for (result, test_case) in test_case_results.iter_mut().zip(test_cases) {
+        if test_case.hidden && matches!(result.test_result, TestResult::Failure(_)) {
+            result.test_result = TestResult::Failure(TestCaseFailureReason::Hidden);
+        }
+    }
+}
+
+/// Surrounds the `text` with the `quote`, escaping backslashes, newlines and the quote itself.
+fn quote_text(text: &str, quote: char) -> String {
+    let mut quoted = String::from(quote);
+    for c in text.chars() {
+        match c {
+            '\\' => quoted.push_str("\\\\"),
+            '\n' => quoted.push_str("\\n"),
+            c if c == quote => {
+                quoted.push('\\');
+                quoted.push(c);
+            }
+            c => quoted.push(c),
+        }
+    }
+    quoted.push(quote);
+    quoted
+}
+
+/// Decides whether a submission passed, based on the `test_case_results` of every test case that was run.
+///
+/// # Errors
+/// Returns a `SubmissionError::Failure` if any test case failed,
+/// and a `SubmissionError::Inconclusive` if none failed but some have an unknown result.
+fn verdict(test_case_results: Box<[TestCaseResult]>) -> Result<(), SubmissionError> {
+    let has_result = |result: fn(&TestResult) -> bool| {
+        test_case_results
+            .iter()
+            .any(|test_case_result| result(&test_case_result.test_result))
+    };
+
+    if has_result(|result| matches!(result, TestResult::Failure(_))) {
+        info!("did not pass all test cases");
+        Err(SubmissionError::Failure(test_case_results))
+    } else if has_result(|result| *result == TestResult::Unknown) {
+        info!("did not fail any test case, but some results are unknown");
+        Err(SubmissionError::Inconclusive(test_case_results))
+    } else {
+        info!("passed all test cases");
+        Ok(())
+    }
+}
+
+/// Creates the span of compiling a submission, whose `elapsed_ms` field is recorded by [`timed`].
+fn compile_span() -> Span {
+    info_span!("compile", elapsed_ms = field::Empty)
+}
+
+/// Creates the span of executing the test cases of a submission, whose `elapsed_ms` field is recorded by [`timed`].
+fn execute_span() -> Span {
+    info_span!("execute", elapsed_ms = field::Empty)
+}
+
+/// Awaits the `phase` of checking a submission inside the `span`, and records the milliseconds it took
+/// in the `elapsed_ms` field of the span, such that the latency of every phase can be queried.
+///
+/// The phase is boxed, as its future would otherwise be nested inline in the already large future of the check.
+async fn timed<T>(span: Span, phase: impl Future<Output = T>) -> T {
+    let start = Instant::now();
+    let output = Box::pin(phase).instrument(span.clone()).await;
+    span.record("elapsed_ms", start.elapsed().as_millis() as u64);
+
+    output
+}
+
+/// Gets the program of the toolchain with the `version`, e.g. `ghc-9.4.7`, or of the default toolchain if it is `None`.
+fn toolchain_program(version: Option<&str>) -> String {
+    match version {
+        Some(version) => format!("{TOOLCHAIN}{TOOLCHAIN_VERSION_SEPARATOR}{version}"),
+        None => String::from(TOOLCHAIN),
+    }
+}
+
+/// Determines the error of a `program` that could not be spawned because of `err`.
+///
+/// A program that does not exist is reported as a `SubmissionError::ToolchainNotFound`, as it means that the
+/// deployment is misconfigured, while any other failure is a `SubmissionError::Internal`.
+fn spawn_failure(program: &str, err: &io::Error) -> SubmissionError {
+    if err.kind() == io::ErrorKind::NotFound {
+        error!("{} is not installed: {}", program, err);
+        return SubmissionError::ToolchainNotFound(program.to_string());
+    }
+
+    error!("could not spawn {}: {}", program, err);
+    SubmissionError::Internal
+}
+
+/// Runs the `program` with its `version_arg` to confirm that it exists and can be run.
+///
+/// # Errors
+/// Returns a `SubmissionError::ToolchainNotFound` if the program does not exist,
+/// and a `SubmissionError::Internal` if it could not be spawned, or did not exit successfully within `timeout`.
+async fn check_program(
+    program: &str,
+    version_arg: &str,
+    timeout: Duration,
+) -> Result<(), SubmissionError> {
+    let output = Command::new(program)
+        .arg(version_arg)
+        .stdin(Stdio::null())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .kill_on_drop(true)
+        .output();
+
+    match tokio::time::timeout(timeout, output).await {
+        Ok(Ok(output)) if output.status.success() => {
+            info!(
+                "found {}: {}",
+                program,
+                String::from_utf8_lossy(&output.stdout).trim()
+            );
+            Ok(())
+        }
+        Ok(Ok(output)) => {
+            error!("{} exited with {}", program, output.status);
+            Err(SubmissionError::Internal)
+        }
+        Ok(Err(err)) => Err(spawn_failure(program, &err)),
+        Err(_) => {
+            error!("{} did not respond within {:?}", program, timeout);
+            Err(SubmissionError::Internal)
+        }
+    }
+}
+
+/// Applies the resource limits of the sandbox to the solution execution `command`,
+/// where a file it writes is limited to `max_file_size` bytes.
+///
+/// The limits are applied in the spawned process before the solution is executed,
+/// and failing to apply them makes the spawn fail.
+///
+/// The execution also leads a process group of its own, which [`run_process`](crate::timeout::run_process) kills when the execution has ended,
+/// such that the processes it spawned, e.g. by a fork bomb, neither outlive it nor count against later executions.
+/// A process that moves itself to another group, e.g. with `setsid`, escapes this.
+fn restrict_resources(command: &mut Command, max_file_size: u64) -> &mut Command {
+    let max_file_size = max_file_size as libc::rlim_t;
+    command.process_group(0);
+
+    // SAFETY: the closure only calls `setrlimit`, which is async-signal-safe, and does not allocate
+    unsafe {
+        command.pre_exec(move || {
+            let limits = [
+                (libc::RLIMIT_NPROC, MAX_PROCESSES),
+                (libc::RLIMIT_FSIZE, max_file_size),
+            ];
+            for (resource, max) in limits {
+                let limit = libc::rlimit {
+                    rlim_cur: max,
+                    rlim_max: max,
+                };
+                if libc::setrlimit(resource, &limit) != 0 {
+                    return Err(io::Error::last_os_error());
+                }
+            }
+
+            Ok(())
+        })
+    }
+}
+
+/// Restricts the environment of the solution execution `command` to [`EXECUTION_ENV`], along with `PATH` and the
+/// environment variables of mozart named in `passed_env_vars`, such that the execution is reproducible,
+/// and does not see e.g. the API key of mozart.
+///
+/// The variables of [`EXECUTION_ENV`] take precedence over those passed on.
+fn restrict_environment<'a>(
+    command: &'a mut Command,
+    passed_env_vars: &[String],
+) -> &'a mut Command {
+    command.env_clear();
+    for name in iter::once("PATH").chain(passed_env_vars.iter().map(String::as_str)) {
+        if let Some(value) = env::var_os(name) {
+            command.env(name, value);
+        }
+    }
+
+    command.envs(EXECUTION_ENV.iter().copied())
+}
+
+/// Inserts the maximum length of a value reported for a wrong answer and whether its text is quoted from the `config`,
+/// the marker of a truncated value, the started outcome, the stdin directory and the results file into the
+/// `test_runner_code`.
+fn insert_test_runner_constants(test_runner_code: &str, config: &Config) -> String {
+    test_runner_code
+        .replace(
+            MAX_VALUE_LENGTH_TARGET,
+            &config.max_value_length.to_string(),
+        )
+        .replace(
+            QUOTE_VALUES_TARGET,
+            if config.quote_values { "1" } else { "0" },
+        )
+        .replace("TRUNCATED_VALUE_MARKER", TRUNCATED_VALUE_MARKER)
+        .replace("STARTED_OUTCOME", STARTED_OUTCOME)
+        .replace("ENDED_OUTCOME", ENDED_OUTCOME)
+        .replace(STDIN_DIR_TARGET, STDIN_DIR)
+        .replace(RESULTS_FILE_TARGET, RESULTS_FILE)
+}
+
+/// Inserts the absolute and relative tolerances of the `tolerance`, and whether it matches NaN, into the `checker_code`.
+///
+/// They are inserted in the shortest form that parses back to the same float, e.g. `1e-9`,
+/// which is a valid float literal in every supported language.
+fn insert_float_tolerance(checker_code: &str, tolerance: FloatTolerance) -> String {
+    checker_code
+        .replace(
+            ABSOLUTE_TOLERANCE_TARGET,
+            &format!("{:?}", tolerance.absolute),
+        )
+        .replace(
+            RELATIVE_TOLERANCE_TARGET,
+            &format!("{:?}", tolerance.relative),
+        )
+        .replace(
+            MATCH_NAN_TARGET,
+            if tolerance.match_nan { "1" } else { "0" },
+        )
+}
+
+/// Describes why the execution process terminated abnormally, using its stderr if it wrote any.
+fn crash_error(output: &Output) -> String {
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    let error = if stderr.trim().is_empty() {
+        format!("the process terminated abnormally ({})", output.status)
+    } else {
+        stderr.to_string()
+    };
+
+    remove_mozart_path(&error)
+}
+
+/// Reads the test output the test runner appended to [`RESULTS_FILE`] inside `temp_dir`,
+/// with the paths of the working directory removed.
+///
+/// # Errors
+/// Returns a `SubmissionError::OutputLimitExceeded` if it is longer than [`MAX_OUTPUT_SIZE`],
+/// and a `SubmissionError::Internal` if it could not be read.
+async fn read_test_output(temp_dir: &Path) -> Result<String, SubmissionError> {
+    let path = temp_dir.join(RESULTS_FILE);
+    let test_output = match retry_io("read results file", || fs::read(&path)).await {
+        Ok(test_output) => test_output,
+        Err(err) => {
+            error!("could not read results file: {}", err);
+            return Err(SubmissionError::Internal);
+        }
+    };
+    if test_output.len() > MAX_OUTPUT_SIZE {
+        info!("test output is {} bytes long", test_output.len());
+        return Err(SubmissionError::OutputLimitExceeded(MAX_OUTPUT_SIZE));
+    }
+
+    Ok(remove_mozart_path(&String::from_utf8_lossy(&test_output)))
+}
+
+/// Truncates the `stdout` of a solution to `max_length` characters, followed by [`TRUNCATED_VALUE_MARKER`]
+/// if it was truncated.
+fn truncate_stdout(stdout: &str, max_length: usize) -> String {
+    match stdout.char_indices().nth(max_length) {
+        Some((end, _)) => format!("{}{TRUNCATED_VALUE_MARKER}", &stdout[..end]),
+        None => stdout.to_string(),
+    }
+}
+
+/// Determines whether the `test_output` shows that any test case was started, i.e. whether it has any line,
+/// as every test case writes its started marker before anything else.
+///
+/// A process that terminated abnormally after it started a test case can have the termination attributed to it.
+fn has_started_test_case(test_output: &str) -> bool {
+    test_output.lines().any(|line| !line.trim().is_empty())
+}
+
+/// Normalizes the formatting of a floating point `value`, such that e.g. `5` and `5.0` are formatted identically.
+///
+/// If the value cannot be parsed as a floating point value, it is returned unchanged.
+fn normalize_float(value: &str) -> String {
+    match value.parse::<f64>() {
+        Ok(float) => format!("{float:?}"),
+        Err(_) => value.to_string(),
+    }
+}
+
+/// Formats a floating point `value` as a literal, where NaN is formatted as `nan` and an infinity as `infinity`,
+/// preceded by `-` if it is negative, as most languages have no literal for them.
+///
+/// A finite value is normalized like [`normalize_float`] does.
+fn format_float(value: &str, nan: &str, infinity: &str) -> String {
+    match value.parse::<f64>() {
+        Ok(float) if float.is_nan() => nan.to_string(),
+        Ok(float) if float == f64::INFINITY => infinity.to_string(),
+        Ok(float) if float == f64::NEG_INFINITY => format!("-{infinity}"),
+        _ => normalize_float(value),
+    }
+}
+
+/// Matches the path of any working directory, i.e. an absolute path whose last directory is named by a uuid,
+/// including a trailing slash if present.
+///
+/// It does not depend on the configured parent directory of the working directories, so it matches inside any of them.
+static MOZART_PATH: LazyLock<Regex> = LazyLock::new(|| {
+    let uuid = "[0-9a-fA-F]{8}-[0-9a-fA-F]{4}-[0-9a-fA-F]{4}-[0-9a-fA-F]{4}-[0-9a-fA-F]{12}";
+    Regex::new(&format!(r#"(?:/[^\s/"']+)*/{uuid}/?"#)).expect("mozart path regex should be valid")
+});
+
+/// Removes every occurrence of the path of a working directory in `s`, such that paths are shown relative to it.
+fn remove_mozart_path(s: &str) -> String {
+    MOZART_PATH.replace_all(s, "").into_owned()
+}
+
+#[cfg(test)]
+mod normalize_float {
+    use super::normalize_float;
+
+    #[test]
+    fn whole_number() {
+        let input = "5";
+        let expected = String::from("5.0");
+
+        let actual = normalize_float(input);
+
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn already_normalized() {
+        let input = "5.0";
+        let expected = String::from("5.0");
+
+        let actual = normalize_float(input);
+
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn trailing_zeroes() {
+        let input = "-2.500";
+        let expected = String::from("-2.5");
+
+        let actual = normalize_float(input);
+
+        assert_eq!(actual, expected);
+    }
+}
+
+#[cfg(test)]
+mod format_float {
+    use super::format_float;
+
+    #[test]
+    fn finite() {
+        let actual = format_float("5", "nan", "inf");
+
+        assert_eq!(actual, "5.0");
+    }
+
+    #[test]
+    fn nan() {
+        let actual = format_float("NaN", "Double.NaN", "Double.POSITIVE_INFINITY");
+
+        assert_eq!(actual, "Double.NaN");
+    }
+
+    #[test]
+    fn infinity() {
+        let actual = format_float("Infinity", "Double.NaN", "Double.POSITIVE_INFINITY");
+
+        assert_eq!(actual, "Double.POSITIVE_INFINITY");
+    }
+
+    #[test]
+    fn negative_infinity() {
+        let actual = format_float("-Infinity", "Double.NaN", "Double.POSITIVE_INFINITY");
+
+        assert_eq!(actual, "-Double.POSITIVE_INFINITY");
+    }
+}
+
+#[cfg(test)]
+mod parse_output_file {
+    use super::{Interruption, TestRunner};
+    use crate::{
+        error::SubmissionError,
+        model::{
+            ExpectedError, Parameter, ParameterType, TestCase, TestCaseFailureReason,
+            TestCaseResult, TestResult,
+        },
+    };
+    use serde_json::json;
+    use std::time::Duration;
+
+    /// The timeout the execution is interrupted by.
+    const TIMEOUT: Duration = Duration::from_secs(5);
+
+    /// The maximum number of results a test case may report.
+    const MAX_RESULTS: usize = 16;
+
+    /// A test util function to make a test case with the supplied `id` and empty parameters.
+    fn empty_test_case(id: u64) -> TestCase {
+        TestCase {
+            id,
+            input_parameters: Box::new([]),
+            output_parameters: Box::new([]),
+            stdin: None,
+            expected_error: None,
+            label: None,
+            hidden: false,
+        }
+    }
+
+    #[test]
+    fn empty_test_output() {
+        let test_output = "";
+        // the parameters are not necessary for this test, only the test case id
+        let test_cases = [empty_test_case(0), empty_test_case(1), empty_test_case(2)];
         let expected = Err(SubmissionError::Internal);
 
-        let actual = TestRunner::parse_test_output(test_output, &test_cases);
+        let actual = TestRunner::parse_test_output(test_output, &test_cases, None, MAX_RESULTS);
+
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn empty_test_output_without_test_cases() {
+        let test_output = "";
+        let expected = Ok(Box::from([]));
+
+        let actual = TestRunner::parse_test_output(test_output, &[], None, MAX_RESULTS);
+
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn labels_are_echoed() {
+        let test_output = [r#"{"r":"p"}"#, r#"{"r":"t"}"#].join("\n");
+        let test_cases = [
+            TestCase {
+                label: Some(String::from("edge case")),
+                ..empty_test_case(0)
+            },
+            TestCase {
+                label: Some(String::from("hidden")),
+                ..empty_test_case(1)
+            },
+        ];
+        let expected = Ok(Box::from([
+            TestCaseResult {
+                id: 0,
+                test_result: TestResult::Pass,
+                label: Some(String::from("edge case")),
+            },
+            TestCaseResult {
+                id: 1,
+                test_result: TestResult::Failure(TestCaseFailureReason::Timeout),
+                label: Some(String::from("hidden")),
+            },
+        ]));
+
+        let actual = TestRunner::parse_test_output(&test_output, &test_cases, None, MAX_RESULTS);
 
         assert_eq!(actual, expected);
     }
 
     #[test]
     fn empty_line() {
-        let test_output = ["p", "", "p"].join("\n");
+        let test_output = [r#"{"r":"p"}"#, "", r#"{"r":"p"}"#].join("\n");
         // the parameters are not necessary for this test, only the test case id
         let test_cases = [empty_test_case(0), empty_test_case(1), empty_test_case(2)];
         let expected = Err(SubmissionError::Internal);
 
-        let actual = TestRunner::parse_test_output(&test_output, &test_cases);
+        let actual = TestRunner::parse_test_output(&test_output, &test_cases, None, MAX_RESULTS);
 
         assert_eq!(actual, expected);
     }
 
     #[test]
     fn failure_outcome_without_actual_and_expected() {
-        let test_output = ["f"].join("\n");
+        let test_output = [r#"{"r":"f"}"#].join("\n");
         // the parameters are not necessary for this test, only the test case id
         let test_cases = [empty_test_case(0)];
         let expected = Err(SubmissionError::Internal);
 
-        let actual = TestRunner::parse_test_output(&test_output, &test_cases);
+        let actual = TestRunner::parse_test_output(&test_output, &test_cases, None, MAX_RESULTS);
 
         assert_eq!(actual, expected);
     }
 
     #[test]
     fn failure_outcome_with_actual_but_without_expected() {
-        let test_output = ["f,5"].join("\n");
+        let test_output = [r#"{"r":"f","actual":"5"}"#].join("\n");
         // the parameters are not necessary for this test, only the test case id
         let test_cases = [empty_test_case(0)];
         let expected = Err(SubmissionError::Internal);
 
-        let actual = TestRunner::parse_test_output(&test_output, &test_cases);
+        let actual = TestRunner::parse_test_output(&test_output, &test_cases, None, MAX_RESULTS);
 
         assert_eq!(actual, expected);
     }
 
     #[test]
     fn unknown_test_output() {
-        let test_output = ["p", "s"].join("\n");
+        let test_output = [r#"{"r":"p"}"#, r#"{"r":"u"}"#].join("\n");
         // the parameters are not necessary for this test, only the test case id
         let test_cases = [empty_test_case(0), empty_test_case(1)];
         let expected = Err(SubmissionError::Internal);
 
-        let actual = TestRunner::parse_test_output(&test_output, &test_cases);
+        let actual = TestRunner::parse_test_output(&test_output, &test_cases, None, MAX_RESULTS);
 
         assert_eq!(actual, expected);
     }
 
     #[test]
     fn runtime_error_in_last_test_case() -> Result<(), SubmissionError> {
-        let test_output = ["p", "r,did something bad"].join("\n");
+        let test_output = [r#"{"r":"p"}"#, r#"{"r":"err","msg":"did something bad"}"#].join("\n");
         // the parameters are not necessary for this test, only the test case id
         let test_cases = [empty_test_case(0), empty_test_case(1)];
         let expected = Box::new([
             TestCaseResult {
                 id: 0,
                 test_result: TestResult::Pass,
+                label: None,
             },
             TestCaseResult {
                 id: 1,
                 test_result: TestResult::Failure(TestCaseFailureReason::RuntimeError(
                     String::from("did something bad"),
                 )),
+                label: None,
             },
         ]);
 
-        let actual = TestRunner::parse_test_output(&test_output, &test_cases)?;
+        let actual = TestRunner::parse_test_output(&test_output, &test_cases, None, MAX_RESULTS)?;
 
         assert_eq!(*actual, *expected);
 
@@ -354,7 +1677,14 @@ mod parse_output_file {
 
     #[test]
     fn runtime_error_in_first_test_case() -> Result<(), SubmissionError> {
-        let test_output = ["r,not allowed", "p", "p", "p", "p"].join("\n");
+        let test_output = [
+            r#"{"r":"err","msg":"not allowed"}"#,
+            r#"{"r":"p"}"#,
+            r#"{"r":"p"}"#,
+            r#"{"r":"p"}"#,
+            r#"{"r":"p"}"#,
+        ]
+        .join("\n");
         let test_cases = [
             empty_test_case(0),
             empty_test_case(1),
@@ -368,26 +1698,31 @@ mod parse_output_file {
                 test_result: TestResult::Failure(TestCaseFailureReason::RuntimeError(
                     String::from("not allowed"),
                 )),
+                label: None,
             },
             TestCaseResult {
                 id: 1,
                 test_result: TestResult::Pass,
+                label: None,
             },
             TestCaseResult {
                 id: 2,
                 test_result: TestResult::Pass,
+                label: None,
             },
             TestCaseResult {
                 id: 3,
                 test_result: TestResult::Pass,
+                label: None,
             },
             TestCaseResult {
                 id: 4,
                 test_result: TestResult::Pass,
+                label: None,
             },
         ]);
 
-        let actual = TestRunner::parse_test_output(&test_output, &test_cases)?;
+        let actual = TestRunner::parse_test_output(&test_output, &test_cases, None, MAX_RESULTS)?;
 
         assert_eq!(*actual, *expected);
 
@@ -396,7 +1731,14 @@ mod parse_output_file {
 
     #[test]
     fn all_test_cases_passed() -> Result<(), SubmissionError> {
-        let test_output = ["p", "p", "p", "p", "p"].join("\n");
+        let test_output = [
+            r#"{"r":"p"}"#,
+            r#"{"r":"p"}"#,
+            r#"{"r":"p"}"#,
+            r#"{"r":"p"}"#,
+            r#"{"r":"p"}"#,
+        ]
+        .join("\n");
         // the parameters are not necessary for this test, only the test case id
         let test_cases = [
             empty_test_case(0),
@@ -409,26 +1751,31 @@ mod parse_output_file {
             TestCaseResult {
                 id: 0,
                 test_result: TestResult::Pass,
+                label: None,
             },
             TestCaseResult {
                 id: 1,
                 test_result: TestResult::Pass,
+                label: None,
             },
             TestCaseResult {
                 id: 2,
                 test_result: TestResult::Pass,
+                label: None,
             },
             TestCaseResult {
                 id: 3,
                 test_result: TestResult::Pass,
+                label: None,
             },
             TestCaseResult {
                 id: 4,
                 test_result: TestResult::Pass,
+                label: None,
             },
         ]);
 
-        let actual = TestRunner::parse_test_output(&test_output, &test_cases)?;
+        let actual = TestRunner::parse_test_output(&test_output, &test_cases, None, MAX_RESULTS)?;
 
         assert_eq!(*actual, *expected);
 
@@ -437,7 +1784,14 @@ mod parse_output_file {
 
     #[test]
     fn all_test_cases_wrong_answer() -> Result<(), SubmissionError> {
-        let test_output = ["f,5,-5", "f,10,-10", "f,7,-7", "f,-10,10", "f,-5,5"].join("\n");
+        let test_output = [
+            r#"{"r":"f","actual":"5","expected":"-5"}"#,
+            r#"{"r":"f","actual":"10","expected":"-10"}"#,
+            r#"{"r":"f","actual":"7","expected":"-7"}"#,
+            r#"{"r":"f","actual":"-10","expected":"10"}"#,
+            r#"{"r":"f","actual":"-5","expected":"5"}"#,
+        ]
+        .join("\n");
         let test_cases = [
             TestCase {
                 id: 0,
@@ -449,6 +1803,10 @@ mod parse_output_file {
                     value_type: ParameterType::Int,
                     value: String::from("-5"),
                 }]),
+                stdin: None,
+                expected_error: None,
+                label: None,
+                hidden: false,
             },
             TestCase {
                 id: 1,
@@ -460,6 +1818,10 @@ mod parse_output_file {
                     value_type: ParameterType::Int,
                     value: String::from("-10"),
                 }]),
+                stdin: None,
+                expected_error: None,
+                label: None,
+                hidden: false,
             },
             TestCase {
                 id: 2,
@@ -471,6 +1833,10 @@ mod parse_output_file {
                     value_type: ParameterType::Int,
                     value: String::from("-7"),
                 }]),
+                stdin: None,
+                expected_error: None,
+                label: None,
+                hidden: false,
             },
             TestCase {
                 id: 3,
@@ -482,6 +1848,10 @@ mod parse_output_file {
                     value_type: ParameterType::Int,
                     value: String::from("10"),
                 }]),
+                stdin: None,
+                expected_error: None,
+                label: None,
+                hidden: false,
             },
             TestCase {
                 id: 4,
@@ -493,6 +1863,10 @@ mod parse_output_file {
                     value_type: ParameterType::Int,
                     value: String::from("5"),
                 }]),
+                stdin: None,
+                expected_error: None,
+                label: None,
+                hidden: false,
             },
         ];
         let expected = Box::new([
@@ -505,7 +1879,10 @@ mod parse_output_file {
                     }]),
                     actual: String::from("5"),
                     expected: String::from("-5"),
+                    actual_value: None,
+                    expected_value: None,
                 }),
+                label: None,
             },
             TestCaseResult {
                 id: 1,
@@ -516,7 +1893,10 @@ mod parse_output_file {
                     }]),
                     actual: String::from("10"),
                     expected: String::from("-10"),
+                    actual_value: None,
+                    expected_value: None,
                 }),
+                label: None,
             },
             TestCaseResult {
                 id: 2,
@@ -527,7 +1907,10 @@ mod parse_output_file {
                     }]),
                     actual: String::from("7"),
                     expected: String::from("-7"),
+                    actual_value: None,
+                    expected_value: None,
                 }),
+                label: None,
             },
             TestCaseResult {
                 id: 3,
@@ -538,7 +1921,10 @@ mod parse_output_file {
                     }]),
                     actual: String::from("-10"),
                     expected: String::from("10"),
+                    actual_value: None,
+                    expected_value: None,
                 }),
+                label: None,
             },
             TestCaseResult {
                 id: 4,
@@ -549,11 +1935,14 @@ mod parse_output_file {
                     }]),
                     actual: String::from("-5"),
                     expected: String::from("5"),
+                    actual_value: None,
+                    expected_value: None,
                 }),
+                label: None,
             },
         ]);
 
-        let actual = TestRunner::parse_test_output(&test_output, &test_cases)?;
+        let actual = TestRunner::parse_test_output(&test_output, &test_cases, None, MAX_RESULTS)?;
 
         assert_eq!(*actual, *expected);
 
@@ -562,7 +1951,14 @@ mod parse_output_file {
 
     #[test]
     fn mixed_pass_and_failure_with_runtime_error() -> Result<(), SubmissionError> {
-        let test_output = ["p", "f,10,-10", "p", "r,bad", "p"].join("\n");
+        let test_output = [
+            r#"{"r":"p"}"#,
+            r#"{"r":"f","actual":"10","expected":"-10"}"#,
+            r#"{"r":"p"}"#,
+            r#"{"r":"err","msg":"bad"}"#,
+            r#"{"r":"p"}"#,
+        ]
+        .join("\n");
         let test_cases = [
             TestCase {
                 id: 0,
@@ -574,6 +1970,10 @@ mod parse_output_file {
                     value_type: ParameterType::Int,
                     value: String::from("-5"),
                 }]),
+                stdin: None,
+                expected_error: None,
+                label: None,
+                hidden: false,
             },
             TestCase {
                 id: 1,
@@ -585,6 +1985,10 @@ mod parse_output_file {
                     value_type: ParameterType::Int,
                     value: String::from("-10"),
                 }]),
+                stdin: None,
+                expected_error: None,
+                label: None,
+                hidden: false,
             },
             TestCase {
                 id: 2,
@@ -596,6 +2000,10 @@ mod parse_output_file {
                     value_type: ParameterType::Int,
                     value: String::from("-7"),
                 }]),
+                stdin: None,
+                expected_error: None,
+                label: None,
+                hidden: false,
             },
             TestCase {
                 id: 3,
@@ -607,6 +2015,10 @@ mod parse_output_file {
                     value_type: ParameterType::Int,
                     value: String::from("10"),
                 }]),
+                stdin: None,
+                expected_error: None,
+                label: None,
+                hidden: false,
             },
             TestCase {
                 id: 4,
@@ -618,12 +2030,17 @@ mod parse_output_file {
                     value_type: ParameterType::Int,
                     value: String::from("5"),
                 }]),
+                stdin: None,
+                expected_error: None,
+                label: None,
+                hidden: false,
             },
         ];
         let expected = Box::new([
             TestCaseResult {
                 id: 0,
                 test_result: TestResult::Pass,
+                label: None,
             },
             TestCaseResult {
                 id: 1,
@@ -634,28 +2051,1304 @@ mod parse_output_file {
                     }]),
                     actual: String::from("10"),
                     expected: String::from("-10"),
+                    actual_value: None,
+                    expected_value: None,
                 }),
+                label: None,
             },
             TestCaseResult {
                 id: 2,
                 test_result: TestResult::Pass,
+                label: None,
             },
             TestCaseResult {
                 id: 3,
                 test_result: TestResult::Failure(TestCaseFailureReason::RuntimeError(
                     String::from("bad"),
                 )),
+                label: None,
             },
             TestCaseResult {
                 id: 4,
                 test_result: TestResult::Pass,
+                label: None,
+            },
+        ]);
+
+        let actual = TestRunner::parse_test_output(&test_output, &test_cases, None, MAX_RESULTS)?;
+
+        assert_eq!(*actual, *expected);
+
+        Ok(())
+    }
+
+    #[test]
+    fn started_markers() -> Result<(), SubmissionError> {
+        let test_output = [
+            r#"{"r":"s","id":0}"#,
+            r#"{"r":"p"}"#,
+            r#"{"r":"e","id":0}"#,
+            r#"{"r":"s","id":1}"#,
+            r#"{"r":"p"}"#,
+            r#"{"r":"e","id":1}"#,
+        ]
+        .join("\n");
+        // the parameters are not necessary for this test, only the test case id
+        let test_cases = [empty_test_case(0), empty_test_case(1)];
+        let expected = Box::new([
+            TestCaseResult {
+                id: 0,
+                test_result: TestResult::Pass,
+                label: None,
+            },
+            TestCaseResult {
+                id: 1,
+                test_result: TestResult::Pass,
+                label: None,
+            },
+        ]);
+
+        let actual = TestRunner::parse_test_output(&test_output, &test_cases, None, MAX_RESULTS)?;
+
+        assert_eq!(*actual, *expected);
+
+        Ok(())
+    }
+
+    #[test]
+    fn started_marker_with_wrong_id() {
+        let test_output = [
+            r#"{"r":"s","id":0}"#,
+            r#"{"r":"p"}"#,
+            r#"{"r":"e","id":0}"#,
+            r#"{"r":"s","id":0}"#,
+            r#"{"r":"p"}"#,
+            r#"{"r":"e","id":0}"#,
+        ]
+        .join("\n");
+        // the parameters are not necessary for this test, only the test case id
+        let test_cases = [empty_test_case(0), empty_test_case(1)];
+        let expected = Err(SubmissionError::Internal);
+
+        let actual = TestRunner::parse_test_output(&test_output, &test_cases, None, MAX_RESULTS);
+
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn timeout_in_started_test_case() -> Result<(), SubmissionError> {
+        let test_output = [
+            r#"{"r":"s","id":0}"#,
+            r#"{"r":"p"}"#,
+            r#"{"r":"e","id":0}"#,
+            r#"{"r":"s","id":1}"#,
+            r#"{"r":"err","msg":"wrong"}"#,
+            r#"{"r":"e","id":1}"#,
+            r#"{"r":"s","id":2}"#,
+        ]
+        .join("\n");
+        // the parameters are not necessary for this test, only the test case id
+        let test_cases = [
+            empty_test_case(0),
+            empty_test_case(1),
+            empty_test_case(2),
+            empty_test_case(3),
+        ];
+        let expected = Box::new([
+            TestCaseResult {
+                id: 0,
+                test_result: TestResult::Pass,
+                label: None,
+            },
+            TestCaseResult {
+                id: 1,
+                test_result: TestResult::Failure(TestCaseFailureReason::RuntimeError(
+                    String::from("wrong"),
+                )),
+                label: None,
+            },
+            TestCaseResult {
+                id: 2,
+                test_result: TestResult::Failure(TestCaseFailureReason::Timeout),
+                label: None,
+            },
+            TestCaseResult {
+                id: 3,
+                test_result: TestResult::Unknown,
+                label: None,
             },
         ]);
 
-        let actual = TestRunner::parse_test_output(&test_output, &test_cases)?;
+        let actual = TestRunner::parse_test_output(
+            &test_output,
+            &test_cases,
+            Some(Interruption::Timeout(TIMEOUT)),
+            MAX_RESULTS,
+        )?;
 
         assert_eq!(*actual, *expected);
 
         Ok(())
     }
+
+    #[test]
+    fn timeout_without_started_test_case() {
+        let test_output = "";
+        // the parameters are not necessary for this test, only the test case id
+        let test_cases = [empty_test_case(0)];
+        let expected = Err(SubmissionError::ExecuteTimeout(TIMEOUT));
+
+        let actual = TestRunner::parse_test_output(
+            test_output,
+            &test_cases,
+            Some(Interruption::Timeout(TIMEOUT)),
+            MAX_RESULTS,
+        );
+
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn timeout_between_test_cases() -> Result<(), SubmissionError> {
+        let test_output = [
+            r#"{"r":"s","id":0}"#,
+            r#"{"r":"p"}"#,
+            r#"{"r":"e","id":0}"#,
+            r#"{"r":"s","id":1}"#,
+            r#"{"r":"f","actual":"1","expected":"2"}"#,
+            r#"{"r":"e","id":1}"#,
+        ]
+        .join("\n");
+        // the parameters are not necessary for this test, only the test case id
+        let test_cases = [
+            empty_test_case(0),
+            empty_test_case(1),
+            empty_test_case(2),
+            empty_test_case(3),
+        ];
+        let expected = Box::new([
+            TestCaseResult {
+                id: 0,
+                test_result: TestResult::Pass,
+                label: None,
+            },
+            TestCaseResult {
+                id: 1,
+                test_result: TestResult::Failure(TestCaseFailureReason::WrongAnswer {
+                    input_parameters: Box::new([]),
+                    actual: String::from("1"),
+                    expected: String::from("2"),
+                    actual_value: None,
+                    expected_value: None,
+                }),
+                label: None,
+            },
+            TestCaseResult {
+                id: 2,
+                test_result: TestResult::Failure(TestCaseFailureReason::Timeout),
+                label: None,
+            },
+            TestCaseResult {
+                id: 3,
+                test_result: TestResult::Unknown,
+                label: None,
+            },
+        ]);
+
+        let actual = TestRunner::parse_test_output(
+            &test_output,
+            &test_cases,
+            Some(Interruption::Timeout(TIMEOUT)),
+            MAX_RESULTS,
+        )?;
+
+        assert_eq!(*actual, *expected);
+
+        Ok(())
+    }
+
+    #[test]
+    fn timeout_after_last_test_case() -> Result<(), SubmissionError> {
+        let test_output = [r#"{"r":"s","id":0}"#, r#"{"r":"p"}"#, r#"{"r":"e","id":0}"#].join("\n");
+        // the parameters are not necessary for this test, only the test case id
+        let test_cases = [empty_test_case(0)];
+        let expected = Box::new([TestCaseResult {
+            id: 0,
+            test_result: TestResult::Pass,
+            label: None,
+        }]);
+
+        let actual = TestRunner::parse_test_output(
+            &test_output,
+            &test_cases,
+            Some(Interruption::Timeout(TIMEOUT)),
+            MAX_RESULTS,
+        )?;
+
+        assert_eq!(*actual, *expected);
+
+        Ok(())
+    }
+
+    #[test]
+    fn timeout_outcome() -> Result<(), SubmissionError> {
+        let test_output = [
+            r#"{"r":"s","id":0}"#,
+            r#"{"r":"t"}"#,
+            r#"{"r":"e","id":0}"#,
+            r#"{"r":"s","id":1}"#,
+            r#"{"r":"p"}"#,
+            r#"{"r":"e","id":1}"#,
+        ]
+        .join("\n");
+        // the parameters are not necessary for this test, only the test case id
+        let test_cases = [empty_test_case(0), empty_test_case(1)];
+        let expected = Box::new([
+            TestCaseResult {
+                id: 0,
+                test_result: TestResult::Failure(TestCaseFailureReason::Timeout),
+                label: None,
+            },
+            TestCaseResult {
+                id: 1,
+                test_result: TestResult::Pass,
+                label: None,
+            },
+        ]);
+
+        let actual = TestRunner::parse_test_output(&test_output, &test_cases, None, MAX_RESULTS)?;
+
+        assert_eq!(*actual, *expected);
+
+        Ok(())
+    }
+
+    #[test]
+    fn crash_in_started_test_case() -> Result<(), SubmissionError> {
+        let test_output = [
+            r#"{"r":"s","id":0}"#,
+            r#"{"r":"p"}"#,
+            r#"{"r":"e","id":0}"#,
+            r#"{"r":"s","id":1}"#,
+        ]
+        .join("\n");
+        // the parameters are not necessary for this test, only the test case id
+        let test_cases = [empty_test_case(0), empty_test_case(1), empty_test_case(2)];
+        let expected = Box::new([
+            TestCaseResult {
+                id: 0,
+                test_result: TestResult::Pass,
+                label: None,
+            },
+            TestCaseResult {
+                id: 1,
+                test_result: TestResult::Failure(TestCaseFailureReason::RuntimeError(
+                    String::from("segmentation fault"),
+                )),
+                label: None,
+            },
+            TestCaseResult {
+                id: 2,
+                test_result: TestResult::Unknown,
+                label: None,
+            },
+        ]);
+        let interruption = Interruption::Crash(String::from("segmentation fault"));
+
+        let actual = TestRunner::parse_test_output(
+            &test_output,
+            &test_cases,
+            Some(interruption),
+            MAX_RESULTS,
+        )?;
+
+        assert_eq!(*actual, *expected);
+
+        Ok(())
+    }
+
+    /// A test util function to make a test case with the supplied `id` expecting an error containing the `message`.
+    fn expecting_error_test_case(id: u64, message: Option<&str>) -> TestCase {
+        TestCase {
+            expected_error: Some(ExpectedError {
+                message: message.map(String::from),
+            }),
+            ..empty_test_case(id)
+        }
+    }
+
+    #[test]
+    fn expected_runtime_error() -> Result<(), SubmissionError> {
+        let test_output = [
+            r#"{"r":"err","msg":"ZeroDivisionError: division by zero"}"#,
+            r#"{"r":"err","msg":"ValueError: bad input"}"#,
+        ]
+        .join("\n");
+        let test_cases = [
+            expecting_error_test_case(0, Some("division by zero")),
+            expecting_error_test_case(1, None),
+        ];
+        let expected = Box::new([
+            TestCaseResult {
+                id: 0,
+                test_result: TestResult::Pass,
+                label: None,
+            },
+            TestCaseResult {
+                id: 1,
+                test_result: TestResult::Pass,
+                label: None,
+            },
+        ]);
+
+        let actual = TestRunner::parse_test_output(&test_output, &test_cases, None, MAX_RESULTS)?;
+
+        assert_eq!(*actual, *expected);
+
+        Ok(())
+    }
+
+    #[test]
+    fn expected_runtime_error_with_other_message() -> Result<(), SubmissionError> {
+        let test_output = r#"{"r":"err","msg":"ValueError: bad input"}"#;
+        let test_cases = [expecting_error_test_case(0, Some("division by zero"))];
+        let expected = Box::new([TestCaseResult {
+            id: 0,
+            test_result: TestResult::Failure(TestCaseFailureReason::RuntimeError(String::from(
+                "ValueError: bad input",
+            ))),
+            label: None,
+        }]);
+
+        let actual = TestRunner::parse_test_output(test_output, &test_cases, None, MAX_RESULTS)?;
+
+        assert_eq!(*actual, *expected);
+
+        Ok(())
+    }
+
+    #[test]
+    fn expected_crash() -> Result<(), SubmissionError> {
+        let test_output = r#"{"r":"s","id":0}"#;
+        let test_cases = [expecting_error_test_case(0, Some("segmentation"))];
+        let expected = Box::new([TestCaseResult {
+            id: 0,
+            test_result: TestResult::Pass,
+            label: None,
+        }]);
+        let interruption = Interruption::Crash(String::from("segmentation fault"));
+
+        let actual = TestRunner::parse_test_output(
+            test_output,
+            &test_cases,
+            Some(interruption),
+            MAX_RESULTS,
+        )?;
+
+        assert_eq!(*actual, *expected);
+
+        Ok(())
+    }
+
+    #[test]
+    fn missing_error() -> Result<(), SubmissionError> {
+        let test_output = r#"{"r":"v","actual":"5"}"#;
+        let test_cases = [expecting_error_test_case(0, None)];
+        let expected = Box::new([TestCaseResult {
+            id: 0,
+            test_result: TestResult::Failure(TestCaseFailureReason::MissingError {
+                input_parameters: Box::new([]),
+                actual: String::from("5"),
+            }),
+            label: None,
+        }]);
+
+        let actual = TestRunner::parse_test_output(test_output, &test_cases, None, MAX_RESULTS)?;
+
+        assert_eq!(*actual, *expected);
+
+        Ok(())
+    }
+
+    #[test]
+    fn failed_fast() -> Result<(), SubmissionError> {
+        let test_output = [
+            r#"{"r":"s","id":0}"#,
+            r#"{"r":"p"}"#,
+            r#"{"r":"e","id":0}"#,
+            r#"{"r":"s","id":1}"#,
+            r#"{"r":"t"}"#,
+        ]
+        .join("\n");
+        // the parameters are not necessary for this test, only the test case id
+        let test_cases = [empty_test_case(0), empty_test_case(1), empty_test_case(2)];
+        let expected = Box::new([
+            TestCaseResult {
+                id: 0,
+                test_result: TestResult::Pass,
+                label: None,
+            },
+            TestCaseResult {
+                id: 1,
+                test_result: TestResult::Failure(TestCaseFailureReason::Timeout),
+                label: None,
+            },
+            TestCaseResult {
+                id: 2,
+                test_result: TestResult::Unknown,
+                label: None,
+            },
+        ]);
+
+        let actual = TestRunner::parse_test_output(
+            &test_output,
+            &test_cases,
+            Some(Interruption::FailedFast),
+            MAX_RESULTS,
+        )?;
+
+        assert_eq!(*actual, *expected);
+
+        Ok(())
+    }
+
+    #[test]
+    fn failed_fast_in_started_test_case() {
+        let test_output = [
+            r#"{"r":"s","id":0}"#,
+            r#"{"r":"p"}"#,
+            r#"{"r":"e","id":0}"#,
+            r#"{"r":"s","id":1}"#,
+        ]
+        .join("\n");
+        // the parameters are not necessary for this test, only the test case id
+        let test_cases = [empty_test_case(0), empty_test_case(1), empty_test_case(2)];
+        let expected = Err(SubmissionError::Internal);
+
+        let actual = TestRunner::parse_test_output(
+            &test_output,
+            &test_cases,
+            Some(Interruption::FailedFast),
+            MAX_RESULTS,
+        );
+
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn fewer_results_than_test_cases() {
+        let test_output = [
+            r#"{"r":"s","id":0}"#,
+            r#"{"r":"p"}"#,
+            r#"{"r":"e","id":0}"#,
+            r#"{"r":"s","id":1}"#,
+        ]
+        .join("\n");
+        // the parameters are not necessary for this test, only the test case id
+        let test_cases = [empty_test_case(0), empty_test_case(1), empty_test_case(2)];
+        let expected = Err(SubmissionError::Internal);
+
+        let actual = TestRunner::parse_test_output(&test_output, &test_cases, None, MAX_RESULTS);
+
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn more_results_than_test_cases() {
+        let test_output = [r#"{"r":"p"}"#, r#"{"r":"p"}"#, r#"{"r":"p"}"#].join("\n");
+        // the parameters are not necessary for this test, only the test case id
+        let test_cases = [empty_test_case(0), empty_test_case(1)];
+        let expected = Err(SubmissionError::Internal);
+
+        let actual = TestRunner::parse_test_output(&test_output, &test_cases, None, MAX_RESULTS);
+
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn multiple_results_in_test_case() -> Result<(), SubmissionError> {
+        let test_output = [
+            r#"{"r":"s","id":0}"#,
+            r#"{"r":"p"}"#,
+            r#"{"r":"p"}"#,
+            r#"{"r":"e","id":0}"#,
+            r#"{"r":"s","id":1}"#,
+            r#"{"r":"p"}"#,
+            r#"{"r":"f","actual":"1","expected":"2"}"#,
+            r#"{"r":"e","id":1}"#,
+        ]
+        .join("\n");
+        // the parameters are not necessary for this test, only the test case id
+        let test_cases = [empty_test_case(0), empty_test_case(1)];
+        let expected = Box::new([
+            TestCaseResult {
+                id: 0,
+                test_result: TestResult::Pass,
+                label: None,
+            },
+            TestCaseResult {
+                id: 1,
+                test_result: TestResult::Failure(TestCaseFailureReason::WrongAnswer {
+                    input_parameters: Box::new([]),
+                    actual: String::from("1"),
+                    expected: String::from("2"),
+                    actual_value: None,
+                    expected_value: None,
+                }),
+                label: None,
+            },
+        ]);
+
+        let actual = TestRunner::parse_test_output(&test_output, &test_cases, None, MAX_RESULTS)?;
+
+        assert_eq!(*actual, *expected);
+
+        Ok(())
+    }
+
+    #[test]
+    fn more_results_than_allowed() {
+        let test_output = [
+            r#"{"r":"s","id":0}"#,
+            r#"{"r":"p"}"#,
+            r#"{"r":"p"}"#,
+            r#"{"r":"e","id":0}"#,
+        ]
+        .join("\n");
+        // the parameters are not necessary for this test, only the test case id
+        let test_cases = [empty_test_case(0)];
+        let expected = Err(SubmissionError::Internal);
+
+        let actual = TestRunner::parse_test_output(&test_output, &test_cases, None, 1);
+
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn ended_without_results() {
+        let test_output = [r#"{"r":"s","id":0}"#, r#"{"r":"e","id":0}"#].join("\n");
+        // the parameters are not necessary for this test, only the test case id
+        let test_cases = [empty_test_case(0)];
+        let expected = Err(SubmissionError::Internal);
+
+        let actual = TestRunner::parse_test_output(&test_output, &test_cases, None, MAX_RESULTS);
+
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn started_before_previous_ended() {
+        let test_output = [
+            r#"{"r":"s","id":0}"#,
+            r#"{"r":"p"}"#,
+            r#"{"r":"s","id":1}"#,
+            r#"{"r":"p"}"#,
+            r#"{"r":"e","id":1}"#,
+        ]
+        .join("\n");
+        // the parameters are not necessary for this test, only the test case id
+        let test_cases = [empty_test_case(0), empty_test_case(1)];
+        let expected = Err(SubmissionError::Internal);
+
+        let actual = TestRunner::parse_test_output(&test_output, &test_cases, None, MAX_RESULTS);
+
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn timeout_after_passed_result() -> Result<(), SubmissionError> {
+        let test_output = [r#"{"r":"s","id":0}"#, r#"{"r":"p"}"#].join("\n");
+        // the parameters are not necessary for this test, only the test case id
+        let test_cases = [empty_test_case(0), empty_test_case(1)];
+        let expected = Box::new([
+            TestCaseResult {
+                id: 0,
+                test_result: TestResult::Failure(TestCaseFailureReason::Timeout),
+                label: None,
+            },
+            TestCaseResult {
+                id: 1,
+                test_result: TestResult::Unknown,
+                label: None,
+            },
+        ]);
+
+        let actual = TestRunner::parse_test_output(
+            &test_output,
+            &test_cases,
+            Some(Interruption::Timeout(TIMEOUT)),
+            MAX_RESULTS,
+        )?;
+
+        assert_eq!(*actual, *expected);
+
+        Ok(())
+    }
+
+    #[test]
+    fn wrong_answer_with_delimiters() -> Result<(), SubmissionError> {
+        let test_output = [
+            r#"{"r":"f","actual":"\"a,b\"","expected":"\"line\\nbreak\""}"#,
+            r#"{"r":"f","actual":"'\",{\"r\":\"p\"}","expected":"\"\n\""}"#,
+        ]
+        .join("\n");
+        // the parameters are not necessary for this test, only the test case id
+        let test_cases = [empty_test_case(0), empty_test_case(1)];
+        let expected = Box::new([
+            TestCaseResult {
+                id: 0,
+                test_result: TestResult::Failure(TestCaseFailureReason::WrongAnswer {
+                    input_parameters: Box::new([]),
+                    actual: String::from(r#""a,b""#),
+                    expected: String::from(r#""line\nbreak""#),
+                    actual_value: None,
+                    expected_value: None,
+                }),
+                label: None,
+            },
+            TestCaseResult {
+                id: 1,
+                test_result: TestResult::Failure(TestCaseFailureReason::WrongAnswer {
+                    input_parameters: Box::new([]),
+                    actual: String::from(r#"'",{"r":"p"}"#),
+                    expected: String::from("\"\n\""),
+                    actual_value: None,
+                    expected_value: None,
+                }),
+                label: None,
+            },
+        ]);
+
+        let actual = TestRunner::parse_test_output(&test_output, &test_cases, None, MAX_RESULTS)?;
+
+        assert_eq!(*actual, *expected);
+
+        Ok(())
+    }
+
+    #[test]
+    fn wrong_answer_with_structured_values() -> Result<(), SubmissionError> {
+        let test_output = r#"{"r":"f","actual":"(1, {'a': 2})","expected":"(1, {'a': 3})","actualValue":[1,{"a":2}],"expectedValue":[1,{"a":3}]}"#;
+        // the parameters are not necessary for this test, only the test case id
+        let test_cases = [empty_test_case(0)];
+        let expected = Box::new([TestCaseResult {
+            id: 0,
+            test_result: TestResult::Failure(TestCaseFailureReason::WrongAnswer {
+                input_parameters: Box::new([]),
+                actual: String::from("(1, {'a': 2})"),
+                expected: String::from("(1, {'a': 3})"),
+                actual_value: Some(json!([1, { "a": 2 }])),
+                expected_value: Some(json!([1, { "a": 3 }])),
+            }),
+            label: None,
+        }]);
+
+        let actual = TestRunner::parse_test_output(test_output, &test_cases, None, MAX_RESULTS)?;
+
+        assert_eq!(*actual, *expected);
+
+        Ok(())
+    }
+
+    #[test]
+    fn runtime_error_with_delimiters() -> Result<(), SubmissionError> {
+        let test_output = r#"{"r":"err","msg":"invalid literal, got \"x\"\nin line 2"}"#;
+        // the parameters are not necessary for this test, only the test case id
+        let test_cases = [empty_test_case(0)];
+        let expected = Box::new([TestCaseResult {
+            id: 0,
+            test_result: TestResult::Failure(TestCaseFailureReason::RuntimeError(String::from(
+                "invalid literal, got \"x\"\nin line 2",
+            ))),
+            label: None,
+        }]);
+
+        let actual = TestRunner::parse_test_output(test_output, &test_cases, None, MAX_RESULTS)?;
+
+        assert_eq!(*actual, *expected);
+
+        Ok(())
+    }
+
+    #[test]
+    fn non_json_outcome() {
+        let test_output = ["p"].join("\n");
+        // the parameters are not necessary for this test, only the test case id
+        let test_cases = [empty_test_case(0)];
+        let expected = Err(SubmissionError::Internal);
+
+        let actual = TestRunner::parse_test_output(&test_output, &test_cases, None, MAX_RESULTS);
+
+        assert_eq!(actual, expected);
+    }
+}
+
+#[cfg(test)]
+mod verdict {
+    use super::verdict;
+    use crate::{
+        error::SubmissionError,
+        model::{TestCaseFailureReason, TestCaseResult, TestResult},
+    };
+
+    /// A test util function to make a result of the test case with the supplied `id`.
+    fn result(id: u64, test_result: TestResult) -> TestCaseResult {
+        TestCaseResult {
+            id,
+            test_result,
+            label: None,
+        }
+    }
+
+    #[test]
+    fn all_pass() {
+        let input = Box::new([result(0, TestResult::Pass), result(1, TestResult::Pass)]);
+
+        let actual = verdict(input);
+
+        assert_eq!(actual, Ok(()));
+    }
+
+    #[test]
+    fn pass_and_unknown() {
+        let input = Box::new([result(0, TestResult::Pass), result(1, TestResult::Unknown)]);
+        let expected = Err(SubmissionError::Inconclusive(input.clone()));
+
+        let actual = verdict(input);
+
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn only_unknown() {
+        let input = Box::new([result(0, TestResult::Unknown)]);
+        let expected = Err(SubmissionError::Inconclusive(input.clone()));
+
+        let actual = verdict(input);
+
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn failure_and_unknown() {
+        let input = Box::new([
+            result(0, TestResult::Pass),
+            result(1, TestResult::Failure(TestCaseFailureReason::Timeout)),
+            result(2, TestResult::Unknown),
+        ]);
+        let expected = Err(SubmissionError::Failure(input.clone()));
+
+        let actual = verdict(input);
+
+        assert_eq!(actual, expected);
+    }
+}
+
+#[cfg(test)]
+mod quote_input_parameters {
+    use super::{quote_input_parameters, quote_text, STRING_QUOTE};
+    use crate::model::{
+        Parameter, ParameterType, TestCaseFailureReason, TestCaseResult, TestResult,
+    };
+
+    /// A test util function to make a wrong answer of the test case with the `input_parameters`.
+    fn wrong_answer(input_parameters: Box<[Parameter]>) -> TestCaseResult {
+        TestCaseResult {
+            id: 0,
+            test_result: TestResult::Failure(TestCaseFailureReason::WrongAnswer {
+                input_parameters,
+                actual: String::from("1"),
+                expected: String::from("2"),
+                actual_value: None,
+                expected_value: None,
+            }),
+            label: None,
+        }
+    }
+
+    /// A test util function to make a parameter of the `value_type` with the `value`.
+    fn parameter(value_type: ParameterType, value: &str) -> Parameter {
+        Parameter {
+            value_type,
+            value: String::from(value),
+        }
+    }
+
+    #[test]
+    fn char_and_string() {
+        let mut input = [wrong_answer(Box::new([
+            parameter(ParameterType::Char, "a"),
+            parameter(ParameterType::String, "hello"),
+            parameter(ParameterType::Int, "5"),
+        ]))];
+        let expected = [wrong_answer(Box::new([
+            parameter(ParameterType::Char, "'a'"),
+            parameter(
+                ParameterType::String,
+                &format!("{STRING_QUOTE}hello{STRING_QUOTE}"),
+            ),
+            parameter(ParameterType::Int, "5"),
+        ]))];
+
+        quote_input_parameters(&mut input);
+
+        assert_eq!(input, expected);
+    }
+
+    #[test]
+    fn other_results_are_untouched() {
+        let mut input = [TestCaseResult {
+            id: 0,
+            test_result: TestResult::Pass,
+            label: None,
+        }];
+        let expected = input.clone();
+
+        quote_input_parameters(&mut input);
+
+        assert_eq!(input, expected);
+    }
+
+    #[test]
+    fn escaped() {
+        let input = "it's a \\ and a\nline";
+        let expected = r"'it\'s a \\ and a\nline'";
+
+        let actual = quote_text(input, '\'');
+
+        assert_eq!(actual, expected);
+    }
+}
+
+#[cfg(test)]
+mod hide_failure_reasons {
+    use super::hide_failure_reasons;
+    use crate::model::{TestCase, TestCaseFailureReason, TestCaseResult, TestResult};
+
+    /// A test util function to make a test case with the `id` and empty parameters, which is hidden if `hidden` is set.
+    fn test_case(id: u64, hidden: bool) -> TestCase {
+        TestCase {
+            id,
+            input_parameters: Box::new([]),
+            output_parameters: Box::new([]),
+            stdin: None,
+            expected_error: None,
+            label: None,
+            hidden,
+        }
+    }
+
+    /// A test util function to make a result of the test case with the `id`.
+    fn result(id: u64, test_result: TestResult) -> TestCaseResult {
+        TestCaseResult {
+            id,
+            test_result,
+            label: None,
+        }
+    }
+
+    /// A test util function to make a wrong answer.
+    fn wrong_answer() -> TestResult {
+        TestResult::Failure(TestCaseFailureReason::WrongAnswer {
+            input_parameters: Box::new([]),
+            actual: String::from("1"),
+            expected: String::from("2"),
+            actual_value: None,
+            expected_value: None,
+        })
+    }
+
+    #[test]
+    fn hidden_failures() {
+        let test_cases = [test_case(0, true), test_case(1, true), test_case(2, true)];
+        let mut input = [
+            result(0, wrong_answer()),
+            result(
+                1,
+                TestResult::Failure(TestCaseFailureReason::RuntimeError(String::from(
+                    "ZeroDivisionError: division by zero",
+                ))),
+            ),
+            result(2, TestResult::Failure(TestCaseFailureReason::Timeout)),
+        ];
+        let expected = [
+            result(0, TestResult::Failure(TestCaseFailureReason::Hidden)),
+            result(1, TestResult::Failure(TestCaseFailureReason::Hidden)),
+            result(2, TestResult::Failure(TestCaseFailureReason::Hidden)),
+        ];
+
+        hide_failure_reasons(&mut input, &test_cases);
+
+        assert_eq!(input, expected);
+    }
+
+    #[test]
+    fn visible_failures_are_untouched() {
+        let test_cases = [test_case(0, false), test_case(1, true)];
+        let mut input = [result(0, wrong_answer()), result(1, wrong_answer())];
+        let expected = [
+            result(0, wrong_answer()),
+            result(1, TestResult::Failure(TestCaseFailureReason::Hidden)),
+        ];
+
+        hide_failure_reasons(&mut input, &test_cases);
+
+        assert_eq!(input, expected);
+    }
+
+    #[test]
+    fn hidden_pass_and_unknown_are_untouched() {
+        let test_cases = [test_case(0, true), test_case(1, true)];
+        let mut input = [result(0, TestResult::Pass), result(1, TestResult::Unknown)];
+        let expected = input.clone();
+
+        hide_failure_reasons(&mut input, &test_cases);
+
+        assert_eq!(input, expected);
+    }
+}
+
+#[cfg(test)]
+mod truncate_stdout {
+    use super::truncate_stdout;
+
+    #[test]
+    fn within_max_length() {
+        let input = "debug 2\n";
+        let expected = String::from("debug 2\n");
+
+        let actual = truncate_stdout(input, 8);
+
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn exceeds_max_length() {
+        let input = "debug 2\ndebug 5\n";
+        let expected = String::from("debug 2\n...");
+
+        let actual = truncate_stdout(input, 8);
+
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn multi_byte_characters() {
+        let input = "æøå";
+        let expected = String::from("æø...");
+
+        let actual = truncate_stdout(input, 2);
+
+        assert_eq!(actual, expected);
+    }
+}
+
+#[cfg(test)]
+mod remove_mozart_path {
+    use super::remove_mozart_path;
+
+    #[test]
+    fn trailing_slash() {
+        let input = "/mozart/67e55044-10b1-426f-9247-bb680e5fe0c8/Solution.hs:3:5: error:";
+        let expected = String::from("Solution.hs:3:5: error:");
+
+        let actual = remove_mozart_path(input);
+
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn no_slash() {
+        let input = "could not open /mozart/67e55044-10b1-426f-9247-bb680e5fe0c8";
+        let expected = String::from("could not open ");
+
+        let actual = remove_mozart_path(input);
+
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn multiple_occurrences() {
+        let input = [
+            r#"File "/mozart/67e55044-10b1-426f-9247-bb680e5fe0c8/main.py", line 9"#,
+            r#"File "/mozart/67e55044-10b1-426f-9247-bb680e5fe0c8/solution.py", line 2"#,
+        ]
+        .join("\n");
+        let expected = [r#"File "main.py", line 9"#, r#"File "solution.py", line 2"#].join("\n");
+
+        let actual = remove_mozart_path(&input);
+
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn embedded_in_token() {
+        let input = "-i/mozart/67e55044-10b1-426f-9247-bb680e5fe0c8/";
+        let expected = String::from("-i");
+
+        let actual = remove_mozart_path(input);
+
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn configured_parent_directory() {
+        let input = "/tmp/work/67e55044-10b1-426f-9247-bb680e5fe0c8/main.py";
+        let expected = String::from("main.py");
+
+        let actual = remove_mozart_path(input);
+
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn other_paths_are_kept() {
+        let input = "/mozart/not-a-uuid/main.py";
+        let expected = String::from("/mozart/not-a-uuid/main.py");
+
+        let actual = remove_mozart_path(input);
+
+        assert_eq!(actual, expected);
+    }
+}
+
+#[cfg(test)]
+mod restrict_resources {
+    use super::restrict_resources;
+    use std::{env, fs};
+    use tokio::process::Command;
+    use uuid::Uuid;
+
+    /// The maximum size in bytes of a file the command may write.
+    const MAX_FILE_SIZE: u64 = 64 * 1024;
+
+    #[tokio::test]
+    async fn huge_file() {
+        let path = env::temp_dir().join(Uuid::new_v4().to_string());
+        let size = MAX_FILE_SIZE + 1;
+
+        let actual = restrict_resources(
+            Command::new("head").args(["-c", &size.to_string(), "/dev/zero"]),
+            MAX_FILE_SIZE,
+        )
+        .stdout(fs::File::create(&path).expect("failed to create file"))
+        .status()
+        .await
+        .expect("failed to run head");
+        let written = fs::metadata(&path)
+            .expect("failed to read file metadata")
+            .len();
+        fs::remove_file(&path).expect("failed to remove file");
+
+        assert!(!actual.success());
+        assert_eq!(written, MAX_FILE_SIZE);
+    }
+}
+
+#[cfg(test)]
+mod restrict_environment {
+    use super::{restrict_environment, EXECUTION_ENV};
+    use std::{collections::HashMap, env, ffi::OsStr};
+    use tokio::process::Command;
+
+    /// Gets the environment variables the `command` is given.
+    fn envs(command: &Command) -> HashMap<&OsStr, Option<&OsStr>> {
+        command.as_std().get_envs().collect()
+    }
+
+    #[test]
+    fn fixed_env_vars() {
+        let mut command = Command::new("true");
+
+        restrict_environment(&mut command, &[]);
+
+        let actual = envs(&command);
+        for (name, value) in EXECUTION_ENV {
+            assert_eq!(actual[OsStr::new(name)], Some(OsStr::new(value)));
+        }
+    }
+
+    #[test]
+    fn only_path_is_passed_on() {
+        let mut command = Command::new("true");
+
+        restrict_environment(&mut command, &[]);
+
+        let actual = envs(&command);
+        assert_eq!(
+            actual.get(OsStr::new("PATH")).copied().flatten(),
+            env::var_os("PATH").as_deref()
+        );
+        assert!(!actual.contains_key(OsStr::new("HOME")));
+    }
+
+    #[test]
+    fn passed_env_vars() {
+        let mut command = Command::new("true");
+
+        restrict_environment(
+            &mut command,
+            &[String::from("HOME"), String::from("LC_ALL")],
+        );
+
+        let actual = envs(&command);
+        assert_eq!(
+            actual.get(OsStr::new("HOME")).copied().flatten(),
+            env::var_os("HOME").as_deref()
+        );
+        // the fixed environment variables take precedence over those passed on
+        assert_eq!(actual[OsStr::new("LC_ALL")], Some(OsStr::new("C")));
+    }
+}
+
+#[cfg(test)]
+mod check_program {
+    use super::check_program;
+    use crate::error::SubmissionError;
+    use std::time::Duration;
+
+    /// The time the program is given to respond.
+    const TIMEOUT: Duration = Duration::from_secs(5);
+
+    #[tokio::test]
+    async fn missing_program() {
+        let actual = check_program("mozart-nonexistent-program", "--version", TIMEOUT).await;
+
+        assert_eq!(
+            actual,
+            Err(SubmissionError::ToolchainNotFound(String::from(
+                "mozart-nonexistent-program"
+            )))
+        );
+    }
+
+    #[tokio::test]
+    async fn unsuccessful_program() {
+        let actual = check_program("false", "--version", TIMEOUT).await;
+
+        assert_eq!(actual, Err(SubmissionError::Internal));
+    }
+}
+
+#[cfg(test)]
+mod spawn_failure {
+    use super::spawn_failure;
+    use crate::error::SubmissionError;
+    use std::io;
+
+    #[test]
+    fn not_found() {
+        let input = io::Error::from(io::ErrorKind::NotFound);
+        let expected = SubmissionError::ToolchainNotFound(String::from("ghc"));
+
+        let actual = spawn_failure("ghc", &input);
+
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn permission_denied() {
+        let input = io::Error::from(io::ErrorKind::PermissionDenied);
+        let expected = SubmissionError::Internal;
+
+        let actual = spawn_failure("ghc", &input);
+
+        assert_eq!(actual, expected);
+    }
+}
+
+#[cfg(test)]
+mod insert_float_tolerance {
+    use super::insert_float_tolerance;
+    use crate::model::FloatTolerance;
+
+    #[test]
+    fn tolerances() {
+        let input = FloatTolerance {
+            absolute: 1e-9,
+            relative: 0.5,
+            match_nan: false,
+        };
+        let expected = "max(1e-9, 0.5 * abs(expected))";
+
+        let actual = insert_float_tolerance(
+            "max(ABSOLUTE_TOLERANCE, RELATIVE_TOLERANCE * abs(expected))",
+            input,
+        );
+
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn zero_tolerances() {
+        let input = FloatTolerance::default();
+        let expected = "max(0.0, 0.0 * abs(expected))";
+
+        let actual = insert_float_tolerance(
+            "max(ABSOLUTE_TOLERANCE, RELATIVE_TOLERANCE * abs(expected))",
+            input,
+        );
+
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn match_nan() {
+        let input = FloatTolerance {
+            absolute: 0.0,
+            relative: 0.0,
+            match_nan: true,
+        };
+        let expected = "isnan(expected) and 1";
+
+        let actual = insert_float_tolerance("isnan(expected) and MATCH_NAN", input);
+
+        assert_eq!(actual, expected);
+    }
+}
+
+#[cfg(test)]
+mod toolchain_program {
+    use super::toolchain_program;
+
+    #[test]
+    fn default_version() {
+        let expected = super::TOOLCHAIN;
+
+        let actual = toolchain_program(None);
+
+        assert_eq!(actual, expected);
+    }
+
+    #[cfg(language = "haskell")]
+    #[test]
+    fn versioned() {
+        let actual = toolchain_program(Some("9.4.7"));
+
+        assert_eq!(actual, "ghc-9.4.7");
+    }
+
+    #[cfg(language = "python")]
+    #[test]
+    fn versioned() {
+        let actual = toolchain_program(Some("3.12"));
+
+        assert_eq!(actual, "python3.12");
+    }
+
+    #[cfg(language = "cpp")]
+    #[test]
+    fn versioned() {
+        let actual = toolchain_program(Some("12"));
+
+        assert_eq!(actual, "g++-12");
+    }
+
+    #[cfg(language = "kotlin")]
+    #[test]
+    fn versioned() {
+        let actual = toolchain_program(Some("2.0.0"));
+
+        assert_eq!(actual, "kotlinc-2.0.0");
+    }
 }
@@ -2,57 +2,383 @@
 
 use super::LanguageHandler;
 use crate::{
+    config::Config,
     error::{SubmissionError, UUID_SHOULD_BE_VALID_STR},
-    model::{Parameter, ParameterType, TestCase},
-    runner::{remove_mozart_path, TIMEOUT},
-    timeout::timeout_process,
-    RESTRICTED_USER_ID,
+    model::{CompilationDiagnostic, Parameter, ParameterType, TestCase},
+    runner::{
+        crash_error, format_float, has_started_test_case, insert_test_runner_constants,
+        jail::{jail, jailed_path},
+        read_test_output, remove_mozart_path, restrict_environment, restrict_resources,
+        spawn_failure, TestOutput, ENDED_OUTCOME, STARTED_OUTCOME, TEST_CASE_ID_TARGET,
+    },
+    timeout::{run_process, timeout_process, ProcessOutcome},
 };
-use std::{path::PathBuf, process::Stdio};
+use std::{path::PathBuf, process::Stdio, sync::Arc};
 use tokio::process::Command;
 use tracing::{error, info};
 
-/// The base test code for Haskell.
+/// The replacement target for inserting the modules that solutions may import, i.e. [`Config::python_modules`].
+const ALLOWED_MODULES_TARGET: &str = "ALLOWED_MODULES";
+
+/// The replacement target for inserting [`MAX_TRACEBACK_FRAMES`].
+const MAX_TRACEBACK_FRAMES_TARGET: &str = "MAX_TRACEBACK_FRAMES";
+
+/// The maximum number of frames included in the traceback of a runtime error, which keeps e.g. a deep recursion short.
+const MAX_TRACEBACK_FRAMES: usize = 10;
+
+/// The replacement target for inserting the number of arguments a test case calls the solution with.
+const ARGUMENT_COUNT_TARGET: &str = "ARGUMENT_COUNT";
+
+/// The base test code for Python.
+///
+/// Before the solution is imported, an import hook is installed that makes importing a module not in
+/// `ALLOWED_MODULES` from the solution raise an `ImportError`.
+/// The modules supplied alongside the solution may be imported, but are restricted like the solution itself.
+/// The checker and the reference solution are restricted like the solution as well, but cannot be imported by it,
+/// so that they cannot be tampered with.
+///
+/// The import hook is reachable from the restricted modules as `__import__`, so it is defined in a namespace of its own
+/// rather than in the globals of the test code, which would expose `os` and `sys`, and only takes the real import
+/// from a keyword-only default. An audit hook keeps the restricted modules from reading that default, or any other
+/// audited attribute, e.g. the frame of a traceback, which would lead back to the globals of the test code.
+///
+/// Neither hook is a security boundary, as most attributes are not audited, e.g. the `__globals__` of a function of
+/// the standard library, or `object.__subclasses__()`, which lead to `os` and `sys` all the same. They keep solutions
+/// from using other modules by accident, while the jail and the restricted user contain the execution either way.
+///
+/// The test cases are followed by a `return`, so that `main` is not empty if there are no test cases.
 const PYTHON_BASE_TEST_CODE: &str = r###"
+import builtins
+import os
+import sys
+
+local_modules = set(
+    file[:-3] for file in os.listdir(os.path.dirname(os.path.abspath(__file__))) if file.endswith(".py")
+) - {"main", "test_runner", "checker", "reference"}
+restricted_modules = frozenset(local_modules | {"checker", "reference"})
+allowed_modules = frozenset(set([ALLOWED_MODULES]) | local_modules)
+
+import_hook_namespace = {
+    "restricted_modules": restricted_modules,
+    "allowed_modules": allowed_modules,
+    "import_internals": (builtins.__import__, sys._getframe),
+}
+# it is compiled as part of this file, such that it is left out of the tracebacks reported to students
+exec(compile("""
+def restricted_import(name, globals=None, locals=None, fromlist=(), level=0, *, internals=import_internals):
+    original_import, getframe = internals
+    # the globals are supplied by the caller, so only the frame of the caller tells who is importing
+    importer = getframe(1).f_globals.get("__name__")
+    if importer in restricted_modules and level == 0 and name.split(".")[0] not in allowed_modules:
+        raise ImportError("importing '" + name + "' is not allowed")
+    return original_import(name, globals, locals, fromlist, level)
+""", __file__, "exec"), import_hook_namespace)
+del import_hook_namespace["import_internals"]
+
+def restrict_audited_attributes(event, args):
+    if event == "object.__getattr__" and sys._getframe(1).f_globals.get("__name__") in restricted_modules:
+        raise RuntimeError("accessing '" + str(args[1]) + "' is not allowed")
+
+sys.addaudithook(restrict_audited_attributes)
+builtins.__import__ = import_hook_namespace["restricted_import"]
+
+from decimal import Decimal
 from solution import solution
-from test_runner import test_checker
+import reference
+from test_runner import test_checker, report_returned, run_with_timeout, describe_error, redirect_stdin, report_outcome, TestCaseTimeout
 
 def main():
 TEST_CASES
+    return 0
 
 if __name__ == "__main__":
     exit(main())
 "###;
 
 /// The test runner for the Python implementation.
+///
+/// Every outcome is written as a JSON object on its own line, so that values containing delimiters cannot break it.
+///
+/// The actual value is accepted if the `checker` of the checker file returns `True` for it and the expected value.
+///
+/// A runtime error is described by its type and message, preceded by the traceback of the solution if it has any.
+/// A `TypeError` raised by calling the solution with another number of arguments than it expects is instead described
+/// as such, by comparing them with the positional parameters of the solution, as the message of Python is cryptic.
+/// Only the innermost `MAX_TRACEBACK_FRAMES` frames of the solution and the modules supplied alongside it are included,
+/// with paths relative to the working directory, such that neither mozart itself, the checker nor the reference solution
+/// is exposed.
+///
+/// Before a test case is run, stdin is redirected to its file inside `STDIN_DIR`.
+///
+/// The timeout of a test case is raised as a `BaseException`, so that it is not caught by the solution.
+///
+/// Every outcome is appended to `RESULTS_FILE`, so that what the solution prints cannot break it, and is flushed
+/// along with stdout, so that neither is lost if the execution crashes or is killed.
+///
+/// The values of a wrong answer are truncated to `MAX_VALUE_LENGTH` characters, followed by `TRUNCATED_VALUE_MARKER`.
+/// If the expected value is a tuple, a list or a dict, they are also written as typed JSON,
+/// unless it is longer than that.
+/// A string is reported as its text, unless `QUOTE_VALUES` is 1, in which case it is reported like `repr` does.
+/// A `Decimal` is reported as its digits, e.g. `0.3` rather than `Decimal('0.3')`.
+///
+/// If `UNORDERED_LISTS` is 1, the actual and expected lists, including those among multiple output parameters, are
+/// sorted before they are checked, so that the order of their elements does not matter. Elements that cannot be
+/// ordered, e.g. dicts, are sorted by their `repr`. The values of a wrong answer are reported in their original order.
+///
+/// A test case that expects a runtime error reports the value the solution returned instead, with `report_returned`.
+///
+/// If `FAIL_FAST` is 1, the process exits right after the first outcome that is not a pass.
 const PYTHON_TEST_RUNNER: &str = r###"
+import inspect
+import json
+import math
+import os
+import signal
+import sys
+import traceback
+
+from checker import checker
+from decimal import Decimal
+
+class TestCaseTimeout(BaseException):
+    pass
+
+def raise_timeout(signum, frame):
+    raise TestCaseTimeout()
+
+def run_with_timeout(f, seconds):
+    signal.signal(signal.SIGALRM, raise_timeout)
+    signal.setitimer(signal.ITIMER_REAL, seconds)
+    try:
+        return f()
+    finally:
+        signal.setitimer(signal.ITIMER_REAL, 0)
+
+def render(value):
+    if isinstance(value, str) and not QUOTE_VALUES:
+        text = value
+    elif isinstance(value, Decimal):
+        text = str(value)
+    else:
+        text = repr(value)
+    if len(text) > MAX_VALUE_LENGTH:
+        return text[:MAX_VALUE_LENGTH] + "TRUNCATED_VALUE_MARKER"
+    return text
+
+def redirect_stdin(test_case_id):
+    if sys.stdin is not sys.__stdin__:
+        sys.stdin.close()
+    sys.stdin = open(os.path.join(os.path.dirname(os.path.abspath(__file__)), "STDIN_DIR", str(test_case_id)))
+
+def arity_mismatch(error, function, arguments):
+    if not isinstance(error, TypeError) or not str(error).startswith(("solution() takes ", "solution() missing ")):
+        return None
+    try:
+        parameters = list(inspect.signature(function).parameters.values())
+    except (TypeError, ValueError):
+        return None
+    if any(parameter.kind == parameter.VAR_POSITIONAL for parameter in parameters):
+        return None
+    positional = [parameter for parameter in parameters if parameter.kind in (parameter.POSITIONAL_ONLY, parameter.POSITIONAL_OR_KEYWORD)]
+    required = len([parameter for parameter in positional if parameter.default is parameter.empty])
+    if required <= arguments <= len(positional):
+        return None
+    expected = str(required) if required == len(positional) else "{} to {}".format(required, len(positional))
+    noun = "argument" if expected == "1" else "arguments"
+    return "solution expects {} {} but test case provides {}".format(expected, noun, arguments)
+
+def describe_error(error, function, arguments):
+    directory = os.path.dirname(os.path.abspath(__file__))
+    frames = [
+        frame for frame in traceback.extract_tb(error.__traceback__)
+        if os.path.dirname(os.path.abspath(frame.filename)) == directory
+        and os.path.basename(frame.filename) not in ("main.py", "test_runner.py", "checker.py", "reference.py")
+    ][-MAX_TRACEBACK_FRAMES:]
+    lines = []
+    for frame in frames:
+        lines.append('  File "{}", line {}, in {}'.format(os.path.basename(frame.filename), frame.lineno, frame.name))
+        if frame.line:
+            lines.append("    " + frame.line.strip())
+    message = str(error)
+    description = type(error).__name__ + (": " + message if message else "")
+    if not lines:
+        return arity_mismatch(error, function, arguments) or description
+    return "\n".join(["Traceback (most recent call last):"] + lines + [description])
+
+def report_outcome(outcome, **fields):
+    with open(os.path.join(os.path.dirname(os.path.abspath(__file__)), "RESULTS_FILE"), "a") as results:
+        results.write(json.dumps({"r": outcome, **fields}) + "\n")
+    sys.stdout.flush()
+    if FAIL_FAST and outcome not in ("STARTED_OUTCOME", "ENDED_OUTCOME", "p"):
+        os._exit(0)
+
+def structured(value):
+    if isinstance(value, (tuple, list)):
+        return [structured(element) for element in value]
+    if isinstance(value, dict):
+        return {structured_key(key): structured(element) for key, element in value.items()}
+    if isinstance(value, float) and not math.isfinite(value):
+        return None
+    if isinstance(value, (bool, int, float, str)):
+        return value
+    return None
+
+def structured_key(key):
+    return key if isinstance(key, str) else json.dumps(structured(key))
+
+def structured_values(actual, expected):
+    if not isinstance(expected, (tuple, list, dict)):
+        return {}
+    values = {"actualValue": structured(actual), "expectedValue": structured(expected)}
+    return {field: value for field, value in values.items() if len(json.dumps(value)) <= MAX_VALUE_LENGTH}
+
+def unordered(value):
+    if isinstance(value, tuple):
+        return tuple(unordered(element) for element in value)
+    if not isinstance(value, list):
+        return value
+    try:
+        return sorted(value)
+    except TypeError:
+        return sorted(value, key=repr)
+
 def test_checker(actual, expected):
-    if actual == expected:
-        print("p")
+    if checker(*((unordered(actual), unordered(expected)) if UNORDERED_LISTS else (actual, expected))):
+        report_outcome("p")
     else:
-        print("f" + "," + repr(actual) + "," + repr(expected))
+        report_outcome("f", actual=render(actual), expected=render(expected), **structured_values(actual, expected))
+
+def report_returned(actual):
+    report_outcome("v", actual=render(actual))
+"###;
+
+/// The checker for Python used if the submission does not supply one, which accepts a value equal to the expected value.
+const PYTHON_DEFAULT_CHECKER: &str = r###"
+def checker(actual, expected):
+    return actual == expected
+"###;
+
+/// The checker for Python used if the submission supplies a float tolerance.
+///
+/// The values of multiple output parameters, lists and maps are compared element-wise, such that the floats among them
+/// are also compared within the tolerance.
+///
+/// NaN is told apart by not being equal to itself, as the checker may not be allowed to import `math`.
+const PYTHON_TOLERANT_CHECKER: &str = r###"
+def within_tolerance(actual, expected):
+    if isinstance(expected, float) and isinstance(actual, (int, float)) and not isinstance(actual, bool):
+        if expected != expected:
+            return bool(MATCH_NAN) and actual != actual
+        if expected in (float("inf"), float("-inf")):
+            return actual == expected
+        return abs(actual - expected) <= max(ABSOLUTE_TOLERANCE, RELATIVE_TOLERANCE * abs(expected))
+    if isinstance(expected, tuple) and isinstance(actual, tuple) and len(actual) == len(expected):
+        return all(within_tolerance(a, e) for a, e in zip(actual, expected))
+    if isinstance(expected, list) and isinstance(actual, list) and len(actual) == len(expected):
+        return all(within_tolerance(a, e) for a, e in zip(actual, expected))
+    if isinstance(expected, dict) and isinstance(actual, dict) and actual.keys() == expected.keys():
+        return all(within_tolerance(actual[key], expected[key]) for key in expected)
+    return actual == expected
+
+def checker(actual, expected):
+    return within_tolerance(actual, expected)
 "###;
 
 /// The exception handling code snippet for Python.
 ///
 /// The `TEST_CASE` is being replace with a call to the actual test case.
 /// This is done for all test cases.
+///
+/// The `STARTED_OUTCOME` and `TEST_CASE_ID` are replaced to form the started marker of the test case,
+/// which is flushed such that it is part of the output even if the execution is killed,
+/// and the `ENDED_OUTCOME` and `TEST_CASE_ID` to form its ended marker, which delimits its results.
+///
+/// The `ARGUMENT_COUNT` is replaced with the number of input parameters, which a runtime error is described with.
 const PYTHON_EXCEPTION_SNIPPET: &str = r###"
+    report_outcome("STARTED_OUTCOME", id=TEST_CASE_ID)
+    redirect_stdin(TEST_CASE_ID)
     try:
         TEST_CASE
+    except TestCaseTimeout:
+        report_outcome("t")
     except Exception as e:
-        print("r," + str(e).replace('\n', '\\n'))
+        report_outcome("err", msg=describe_error(e, solution, ARGUMENT_COUNT))
+    report_outcome("ENDED_OUTCOME", id=TEST_CASE_ID)
+"###;
+
+/// The script that compiles the solution without executing it, receiving the path to the solution as its argument.
+///
+/// A syntax error is written to stderr like Python reports it, and its location is written to stdout as a JSON object
+/// with the fields of a [`CompilationDiagnostic`], after which it exits with status 1.
+const PYTHON_COMPILE_SCRIPT: &str = r###"
+import json, sys, traceback
+
+try:
+    with open(sys.argv[1], "rb") as source:
+        compile(source.read(), "solution.py", "exec")
+except SyntaxError as e:
+    sys.stderr.write("".join(traceback.format_exception_only(type(e), e)))
+    print(json.dumps({"line": e.lineno or 1, "column": e.offset or 1, "message": e.msg}))
+    sys.exit(1)
+except ValueError as e:
+    sys.stderr.write(f"{type(e).__name__}: {e}\n")
+    sys.exit(1)
 "###;
 
 /// The language handler for Python.
 pub struct Python {
     /// A path buffer to the current working directory of a given request.
     temp_dir: PathBuf,
+
+    /// The configuration the submission is checked with.
+    config: Arc<Config>,
+
+    /// The base test code, with the modules that solutions may import inserted.
+    base_test_code: String,
+
+    /// The test runner code, with the truncation of reported values inserted.
+    test_runner_code: String,
+}
+
+/// Escapes the characters of `value` that cannot appear verbatim inside a Python string literal.
+///
+/// Other control characters are escaped as hexadecimal, which in Python always consumes exactly two digits.
+fn escape(value: &str) -> String {
+    value
+        .chars()
+        .map(|c| match c {
+            '"' => String::from("\\\""),
+            '\\' => String::from("\\\\"),
+            '\n' => String::from("\\n"),
+            '\t' => String::from("\\t"),
+            '\r' => String::from("\\r"),
+            c if c.is_ascii_control() => format!("\\x{:02x}", c as u32),
+            c => c.to_string(),
+        })
+        .collect()
 }
 
 impl LanguageHandler for Python {
-    fn new(temp_dir: PathBuf) -> Self {
-        Self { temp_dir }
+    fn new(temp_dir: PathBuf, config: Arc<Config>) -> Self {
+        let allowed_modules = config
+            .python_modules
+            .iter()
+            .map(|module| format!("{module:?}"))
+            .collect::<Vec<_>>()
+            .join(", ");
+        let base_test_code =
+            PYTHON_BASE_TEST_CODE.replace(ALLOWED_MODULES_TARGET, &allowed_modules);
+        let test_runner_code = insert_test_runner_constants(PYTHON_TEST_RUNNER, &config).replace(
+            MAX_TRACEBACK_FRAMES_TARGET,
+            &MAX_TRACEBACK_FRAMES.to_string(),
+        );
+
+        Self {
+            temp_dir,
+            config,
+            base_test_code,
+            test_runner_code,
+        }
     }
 
     fn test_file_path(&self) -> PathBuf {
@@ -63,7 +389,7 @@ impl LanguageHandler for Python {
     }
 
     fn base_test_code(&self) -> &str {
-        PYTHON_BASE_TEST_CODE
+        &self.base_test_code
     }
 
     fn solution_file_path(&self) -> PathBuf {
@@ -80,14 +406,43 @@ impl LanguageHandler for Python {
         path
     }
 
-    fn test_runner_code(&self) -> &str {
-        PYTHON_TEST_RUNNER
+    fn checker_file_path(&self) -> PathBuf {
+        let mut path = self.temp_dir.clone();
+        path.push("checker.py");
+
+        path
     }
 
-    fn generate_test_cases(&self, test_cases: &[TestCase]) -> String {
-        let mut generated_test_cases = Vec::with_capacity(test_cases.len());
+    fn default_checker_code(&self) -> &str {
+        PYTHON_DEFAULT_CHECKER
+    }
+
+    fn tolerant_checker_code(&self) -> &str {
+        PYTHON_TOLERANT_CHECKER
+    }
 
-        for test_case in test_cases {
+    fn reference_file_path(&self) -> PathBuf {
+        let mut path = self.temp_dir.clone();
+        path.push("reference.py");
+
+        path
+    }
+
+    fn reference_code(&self, reference_solution: Option<&str>) -> String {
+        reference_solution.unwrap_or_default().to_string()
+    }
+
+    fn test_runner_code(&self) -> &str {
+        &self.test_runner_code
+    }
+
+    fn generate_test_cases(
+        &self,
+        test_code: &mut String,
+        test_cases: &[TestCase],
+        with_reference: bool,
+    ) {
+        for (index, test_case) in test_cases.iter().enumerate() {
             let formatted_input_parameters = test_case
                 .input_parameters
                 .iter()
@@ -95,26 +450,87 @@ impl LanguageHandler for Python {
                 .collect::<Vec<String>>()
                 .join(",");
 
-            let formatted_output_parameters = test_case
-                .output_parameters
-                .iter()
-                .map(|op| self.format_parameter(op))
-                .collect::<Vec<String>>()
-                .join(",");
+            let formatted_output_parameters =
+                if with_reference && test_case.output_parameters.is_empty() {
+                    format!("reference.solution({formatted_input_parameters})")
+                } else {
+                    test_case
+                        .output_parameters
+                        .iter()
+                        .map(|op| self.format_parameter(op))
+                        .collect::<Vec<String>>()
+                        .join(",")
+                };
 
             // You could easily combine this into a single format! call, I am splitting it for readability.
-            let test_case = format!("        test_checker(solution({formatted_input_parameters}), ({formatted_output_parameters}))\n");
-            let generated_test_case = PYTHON_EXCEPTION_SNIPPET.replace("TEST_CASE", &test_case);
-            generated_test_cases.push(generated_test_case);
+            let timeout = self.config.test_case_timeout.as_secs_f64();
+            let test_case_call = if test_case.expected_error.is_some() {
+                format!("        report_returned(run_with_timeout(lambda: solution({formatted_input_parameters}), {timeout}))\n")
+            } else {
+                format!("        test_checker(run_with_timeout(lambda: solution({formatted_input_parameters}), {timeout}), ({formatted_output_parameters}))\n")
+            };
+            let generated_test_case = PYTHON_EXCEPTION_SNIPPET
+                .replace("STARTED_OUTCOME", STARTED_OUTCOME)
+                .replace("ENDED_OUTCOME", ENDED_OUTCOME)
+                .replace(TEST_CASE_ID_TARGET, &test_case.id.to_string())
+                .replace(
+                    ARGUMENT_COUNT_TARGET,
+                    &test_case.input_parameters.len().to_string(),
+                )
+                .replace("TEST_CASE", &test_case_call);
+            if index > 0 {
+                test_code.push('\n');
+            }
+            test_code.push_str(&generated_test_case);
         }
-
-        generated_test_cases.join("\n")
     }
 
     fn format_parameter(&self, parameter: &Parameter) -> String {
-        match parameter.value_type {
-            ParameterType::Int | ParameterType::Float => parameter.value.clone(),
-            ParameterType::Char | ParameterType::String => format!(r#""{}""#, parameter.value),
+        match &parameter.value_type {
+            // python integers are arbitrary-precision, so the width of an integer is only enforced by validation
+            ParameterType::Int
+            | ParameterType::Int32
+            | ParameterType::Int64
+            | ParameterType::BigInt => parameter.value.clone(),
+            ParameterType::Float => {
+                format_float(&parameter.value, r#"float("nan")"#, r#"float("inf")"#)
+            }
+            ParameterType::Decimal => format!(r#"Decimal("{}")"#, parameter.value),
+            ParameterType::Char | ParameterType::String => {
+                format!(r#""{}""#, escape(&parameter.value))
+            }
+            ParameterType::Unit => String::from("None"),
+            ParameterType::Optional(_) => match parameter
+                .optional_value()
+                .expect("the value of an optional should have been validated")
+            {
+                Some(value) => self.format_parameter(&value),
+                None => String::from("None"),
+            },
+            ParameterType::Map { .. } => {
+                let entries = parameter
+                    .entries()
+                    .expect("the value of a map should have been validated")
+                    .iter()
+                    .map(|(key, value)| {
+                        format!(
+                            "{}: {}",
+                            self.format_parameter(key),
+                            self.format_parameter(value)
+                        )
+                    })
+                    .collect::<Vec<_>>();
+                format!("{{{}}}", entries.join(", "))
+            }
+            ParameterType::List(_) => {
+                let elements = parameter
+                    .elements()
+                    .expect("the value of a list should have been validated")
+                    .iter()
+                    .map(|element| self.format_parameter(element))
+                    .collect::<Vec<_>>();
+                format!("[{}]", elements.join(", "))
+            }
             ParameterType::Bool => {
                 let mut chars = parameter.value.chars();
                 match chars.next() {
@@ -125,18 +541,95 @@ impl LanguageHandler for Python {
         }
     }
 
-    async fn run(&self) -> Result<String, SubmissionError> {
-        let test_file_path = self.test_file_path();
-        let test_file_str = test_file_path.to_str().expect(UUID_SHOULD_BE_VALID_STR);
-
-        info!("spawning execution process");
-        let execution_process = Command::new("python")
-            .arg(test_file_str)
+    /// Compiles the solution with the `toolchain` without executing it, such that a syntax error is reported as a compilation error
+    /// like it is for compiled languages, rather than as an error of the execution.
+    ///
+    /// The `compiler_flags` are passed to the interpreter when the solution is executed instead.
+    async fn compile(
+        &self,
+        toolchain: &str,
+        _compiler_flags: &[String],
+    ) -> Result<(), SubmissionError> {
+        let solution_file_path = self.solution_file_path();
+        let solution_file_str = solution_file_path.to_str().expect(UUID_SHOULD_BE_VALID_STR);
+
+        info!("spawning compilation process");
+        let compile_process = Command::new(toolchain)
+            .arg("-c")
+            .arg(PYTHON_COMPILE_SCRIPT)
+            .arg(solution_file_str)
             .stdin(Stdio::piped())
             .stdout(Stdio::piped())
             .stderr(Stdio::piped())
-            .uid(*RESTRICTED_USER_ID)
             .spawn();
+        let compile_handle = match compile_process {
+            Ok(ch) => ch,
+            Err(err) => return Err(spawn_failure(toolchain, &err)),
+        };
+
+        info!("starting timeout of compilation process");
+        let Some((compile_exit_status, compile_output)) =
+            timeout_process(self.config.timeout, compile_handle).await?
+        else {
+            error!(
+                "compilation process exceeded allowed time limit of {:?}",
+                self.config.timeout
+            );
+            return Err(SubmissionError::CompileTimeout(self.config.timeout));
+        };
+
+        if compile_exit_status.success() {
+            info!("no compile errors");
+            return Ok(());
+        }
+
+        let stderr = String::from_utf8_lossy(&compile_output.stderr);
+        if compile_exit_status.code() != Some(1) {
+            error!(
+                "compilation process failed with {}: {}",
+                compile_exit_status, stderr
+            );
+            return Err(SubmissionError::Internal);
+        }
+
+        info!("compile error");
+        let diagnostics = String::from_utf8_lossy(&compile_output.stdout)
+            .lines()
+            .filter_map(|line| serde_json::from_str::<CompilationDiagnostic>(line).ok())
+            .collect();
+        Err(SubmissionError::Compilation(
+            remove_mozart_path(&stderr),
+            diagnostics,
+        ))
+    }
+
+    async fn execute(
+        &self,
+        toolchain: &str,
+        compiler_flags: &[String],
+        slot: usize,
+    ) -> Result<(TestOutput, String), SubmissionError> {
+        let test_file_path = jailed_path(&self.test_file_path(), &self.temp_dir);
+        let test_file_str = test_file_path.to_str().expect(UUID_SHOULD_BE_VALID_STR);
+
+        info!("spawning execution process");
+        let execution_process = jail(
+            restrict_resources(
+                restrict_environment(
+                    Command::new(toolchain)
+                        .args(compiler_flags) // as python is interpreted, they are passed to the interpreter
+                        .arg(test_file_str)
+                        .stdin(Stdio::piped())
+                        .stdout(Stdio::piped())
+                        .stderr(Stdio::piped()),
+                    &self.config.execution_env_vars,
+                ),
+                self.config.max_file_size,
+            ),
+            &self.temp_dir,
+            slot,
+        )
+        .and_then(Command::spawn);
         let execution_handle = match execution_process {
             Ok(eh) => eh,
             Err(err) => {
@@ -146,30 +639,43 @@ impl LanguageHandler for Python {
         };
 
         info!("starting execution process timeout");
-        match timeout_process(TIMEOUT, execution_handle).await? {
-            Some((es, output)) => {
+        match run_process(
+            self.config.timeout,
+            self.config.kill_grace_period,
+            execution_handle,
+        )
+        .await?
+        {
+            ProcessOutcome::Exited(output) => {
+                let es = output.status;
                 info!(?es);
                 info!("stdout: {}", String::from_utf8_lossy(&output.stdout));
                 info!("stderr: {}", String::from_utf8_lossy(&output.stderr));
 
-                if es.success() {
-                    let stdout = String::from_utf8_lossy(&output.stdout).to_string();
-                    let stripped = remove_mozart_path(&stdout, self.temp_dir.clone());
+                let test_output = read_test_output(&self.temp_dir).await?;
+                let stdout = String::from_utf8_lossy(&output.stdout).to_string();
 
-                    Ok(stripped)
+                if es.success() {
+                    Ok((TestOutput::Finished(test_output), stdout))
+                } else if has_started_test_case(&test_output) {
+                    info!("execution process crashed");
+                    Ok((
+                        TestOutput::Crashed(test_output, crash_error(&output)),
+                        stdout,
+                    ))
                 } else {
-                    let stderr = String::from_utf8_lossy(&output.stderr);
-                    let stripped = remove_mozart_path(&stderr, self.temp_dir.clone());
-
-                    Err(SubmissionError::Execution(stripped))
+                    Err(SubmissionError::Execution(crash_error(&output)))
                 }
             }
-            None => {
+            ProcessOutcome::TimedOut(output) => {
                 error!(
                     "execution process exceeded allowed time limit of {:?}",
-                    TIMEOUT
+                    self.config.timeout
                 );
-                Err(SubmissionError::ExecuteTimeout(TIMEOUT))
+                let test_output = read_test_output(&self.temp_dir).await?;
+                let stdout = String::from_utf8_lossy(&output.stdout).to_string();
+
+                Ok((TestOutput::TimedOut(test_output), stdout))
             }
         }
     }
@@ -179,14 +685,15 @@ impl LanguageHandler for Python {
 mod format_parameter {
     use super::Python;
     use crate::{
+        config::Config,
         model::{Parameter, ParameterType},
         runner::LanguageHandler,
     };
-    use std::path::PathBuf;
+    use std::{path::PathBuf, sync::Arc};
 
     #[test]
     fn bool_false() {
-        let haskell = Python::new(PathBuf::new());
+        let haskell = Python::new(PathBuf::new(), Arc::new(Config::default()));
         let input = Parameter {
             value_type: ParameterType::Bool,
             value: String::from("false"),
@@ -200,7 +707,7 @@ mod format_parameter {
 
     #[test]
     fn bool_true() {
-        let haskell = Python::new(PathBuf::new());
+        let haskell = Python::new(PathBuf::new(), Arc::new(Config::default()));
         let input = Parameter {
             value_type: ParameterType::Bool,
             value: String::from("true"),
@@ -214,7 +721,7 @@ mod format_parameter {
 
     #[test]
     fn int_positive() {
-        let haskell = Python::new(PathBuf::new());
+        let haskell = Python::new(PathBuf::new(), Arc::new(Config::default()));
         let input = Parameter {
             value_type: ParameterType::Int,
             value: String::from("100"),
@@ -228,7 +735,7 @@ mod format_parameter {
 
     #[test]
     fn int_negative() {
-        let haskell = Python::new(PathBuf::new());
+        let haskell = Python::new(PathBuf::new(), Arc::new(Config::default()));
         let input = Parameter {
             value_type: ParameterType::Int,
             value: String::from("-100"),
@@ -240,9 +747,51 @@ mod format_parameter {
         assert_eq!(actual, expected);
     }
 
+    #[test]
+    fn int32() {
+        let python = Python::new(PathBuf::new(), Arc::new(Config::default()));
+        let input = Parameter {
+            value_type: ParameterType::Int32,
+            value: String::from("-2147483648"),
+        };
+        let expected = String::from("-2147483648");
+
+        let actual = python.format_parameter(&input);
+
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn big_int() {
+        let haskell = Python::new(PathBuf::new(), Arc::new(Config::default()));
+        let input = Parameter {
+            value_type: ParameterType::BigInt,
+            value: String::from("15511210043330985984000000"),
+        };
+        let expected = String::from("15511210043330985984000000");
+
+        let actual = haskell.format_parameter(&input);
+
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn decimal() {
+        let python = Python::new(PathBuf::new(), Arc::new(Config::default()));
+        let input = Parameter {
+            value_type: ParameterType::Decimal,
+            value: String::from("-10.25"),
+        };
+        let expected = String::from(r#"Decimal("-10.25")"#);
+
+        let actual = python.format_parameter(&input);
+
+        assert_eq!(actual, expected);
+    }
+
     #[test]
     fn float_positive() {
-        let haskell = Python::new(PathBuf::new());
+        let haskell = Python::new(PathBuf::new(), Arc::new(Config::default()));
         let input = Parameter {
             value_type: ParameterType::Float,
             value: String::from("10.0"),
@@ -256,7 +805,7 @@ mod format_parameter {
 
     #[test]
     fn float_negative() {
-        let haskell = Python::new(PathBuf::new());
+        let haskell = Python::new(PathBuf::new(), Arc::new(Config::default()));
         let input = Parameter {
             value_type: ParameterType::Float,
             value: String::from("-10.0"),
@@ -268,9 +817,23 @@ mod format_parameter {
         assert_eq!(actual, expected);
     }
 
+    #[test]
+    fn float_whole_number() {
+        let haskell = Python::new(PathBuf::new(), Arc::new(Config::default()));
+        let input = Parameter {
+            value_type: ParameterType::Float,
+            value: String::from("5"),
+        };
+        let expected = String::from("5.0");
+
+        let actual = haskell.format_parameter(&input);
+
+        assert_eq!(actual, expected);
+    }
+
     #[test]
     fn char() {
-        let haskell = Python::new(PathBuf::new());
+        let haskell = Python::new(PathBuf::new(), Arc::new(Config::default()));
         let input = Parameter {
             value_type: ParameterType::Char,
             value: String::from("a"),
@@ -284,7 +847,7 @@ mod format_parameter {
 
     #[test]
     fn string() {
-        let haskell = Python::new(PathBuf::new());
+        let haskell = Python::new(PathBuf::new(), Arc::new(Config::default()));
         let input = Parameter {
             value_type: ParameterType::String,
             value: String::from("hello"),
@@ -295,4 +858,119 @@ mod format_parameter {
 
         assert_eq!(actual, expected);
     }
+
+    #[test]
+    fn char_quote() {
+        let haskell = Python::new(PathBuf::new(), Arc::new(Config::default()));
+        let input = Parameter {
+            value_type: ParameterType::Char,
+            value: String::from("\""),
+        };
+        let expected = String::from(r#""\"""#);
+
+        let actual = haskell.format_parameter(&input);
+
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn char_backslash() {
+        let haskell = Python::new(PathBuf::new(), Arc::new(Config::default()));
+        let input = Parameter {
+            value_type: ParameterType::Char,
+            value: String::from("\\"),
+        };
+        let expected = String::from(r#""\\""#);
+
+        let actual = haskell.format_parameter(&input);
+
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn string_escaped() {
+        let haskell = Python::new(PathBuf::new(), Arc::new(Config::default()));
+        let input = Parameter {
+            value_type: ParameterType::String,
+            value: String::from("a\"b\\c\nd\u{0}"),
+        };
+        let expected = String::from(r#""a\"b\\c\nd\x00""#);
+
+        let actual = haskell.format_parameter(&input);
+
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn unit() {
+        let haskell = Python::new(PathBuf::new(), Arc::new(Config::default()));
+        let input = Parameter {
+            value_type: ParameterType::Unit,
+            value: String::new(),
+        };
+        let expected = String::from("None");
+
+        let actual = haskell.format_parameter(&input);
+
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn map() {
+        let haskell = Python::new(PathBuf::new(), Arc::new(Config::default()));
+        let input = Parameter {
+            value_type: ParameterType::Map {
+                key: Box::new(ParameterType::String),
+                value: Box::new(ParameterType::Int),
+            },
+            value: String::from(r#"{"pear": "5", "apple": "3"}"#),
+        };
+        let expected = String::from(r#"{"apple": 3, "pear": 5}"#);
+
+        let actual = haskell.format_parameter(&input);
+
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn list() {
+        let python = Python::new(PathBuf::new(), Arc::new(Config::default()));
+        let input = Parameter {
+            value_type: ParameterType::List(Box::new(ParameterType::Int)),
+            value: String::from(r#"["3", "1", "2"]"#),
+        };
+        let expected = String::from("[3, 1, 2]");
+
+        let actual = python.format_parameter(&input);
+
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn optional_present() {
+        let python = Python::new(PathBuf::new(), Arc::new(Config::default()));
+        let input = Parameter {
+            value_type: ParameterType::Optional(Box::new(ParameterType::String)),
+            value: String::from(r#""apple""#),
+        };
+        let expected = String::from(r#""apple""#);
+
+        let actual = python.format_parameter(&input);
+
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn optional_absent() {
+        let python = Python::new(PathBuf::new(), Arc::new(Config::default()));
+        let input = Parameter {
+            value_type: ParameterType::Optional(Box::new(ParameterType::Int)),
+            value: String::from("null"),
+        };
+        let expected = String::from("None");
+
+        let actual = python.format_parameter(&input);
+
+        assert_eq!(actual, expected);
+    }
 }
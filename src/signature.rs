@@ -0,0 +1,92 @@
+//! Contains the optional signing of submission results.
+//!
+//! Signing is only enabled if the `MOZART_SIGNING_KEY` environment variable is set, in which case the body of a
+//! submission result is signed with an HMAC-SHA256 of the key, which is responded with in the `X-Mozart-Signature`
+//! header as lowercase hexadecimal.
+
+use crate::SIGNATURE_HEADER;
+use axum::{
+    body::{to_bytes, Body},
+    extract::State,
+    http::{HeaderValue, Request, StatusCode},
+    middleware::Next,
+    response::{IntoResponse, Response},
+};
+use ring::hmac;
+use std::{fmt::Write, sync::Arc};
+use tracing::error;
+
+/// Computes the lowercase hexadecimal HMAC-SHA256 of the `body` with the `key`.
+pub fn sign_body(key: &str, body: &[u8]) -> String {
+    let key = hmac::Key::new(hmac::HMAC_SHA256, key.as_bytes());
+    let tag = hmac::sign(&key, body);
+
+    tag.as_ref()
+        .iter()
+        .fold(String::with_capacity(64), |mut hex, byte| {
+            let _ = write!(hex, "{byte:02x}");
+            hex
+        })
+}
+
+/// A middleware that signs the body of every response with the configured signing key, see [`sign_response`].
+///
+/// If no signing key is configured, every response is left as is.
+pub async fn sign(
+    State(signing_key): State<Option<Arc<str>>>,
+    req: Request<Body>,
+    next: Next,
+) -> Response {
+    let response = next.run(req).await;
+    match signing_key {
+        Some(key) => sign_response(&key, response).await,
+        None => response,
+    }
+}
+
+/// Signs the body of the `response` with the `key`, adding the signature to it in the [`SIGNATURE_HEADER`] header.
+///
+/// As the body is buffered to be signed, this is only meant for responses whose body is already in memory.
+pub async fn sign_response(key: &str, response: Response) -> Response {
+    let (mut parts, body) = response.into_parts();
+    let body = match to_bytes(body, usize::MAX).await {
+        Ok(body) => body,
+        Err(err) => {
+            error!("could not buffer the response body to sign it: {}", err);
+            return StatusCode::INTERNAL_SERVER_ERROR.into_response();
+        }
+    };
+
+    let signature = sign_body(key, &body);
+    parts.headers.insert(
+        SIGNATURE_HEADER,
+        HeaderValue::from_str(&signature)
+            .expect("hexadecimal should always be a valid header value"),
+    );
+    Response::from_parts(parts, Body::from(body))
+}
+
+#[cfg(test)]
+mod sign_body {
+    use super::sign_body;
+
+    #[test]
+    fn known_vector() {
+        // test case 2 of RFC 4231
+        let expected = "5bdcc146bf60754e6a042426089575c75a003f089d2739839dec58b964ec3843";
+
+        let actual = sign_body("Jefe", b"what do ya want for nothing?");
+
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn different_keys() {
+        let body = br#"{"result":"pass"}"#;
+
+        let actual = sign_body("secret", body);
+
+        assert_ne!(actual, sign_body("other secret", body));
+        assert_eq!(actual.len(), 64);
+    }
+}
@@ -0,0 +1,144 @@
+//! Contains the state shared by the handlers of mozart, which is constructed once and cloned into every request.
+
+use crate::{config::Config, idempotency::IdempotencyKeys, CheckedSubmission};
+use std::sync::{
+    atomic::{AtomicU64, AtomicUsize},
+    Arc, Mutex,
+};
+use tokio::sync::{OwnedSemaphorePermit, Semaphore};
+
+/// The components shared by every submission checked by mozart.
+///
+/// Cloning it is cheap, and every clone shares the same components, such that e.g. the active submissions are
+/// counted across every request. [`AppState::default`] uses the default configuration.
+#[derive(Clone)]
+pub struct AppState {
+    /// The configuration that submissions are checked with.
+    pub config: Arc<Config>,
+
+    /// The number of submissions that are being checked right now, reported by the `/health` endpoint.
+    pub active_submissions: Arc<AtomicUsize>,
+
+    /// The number of test cases that were skipped by quick submissions failing fast, reported by the `/health` endpoint.
+    pub skipped_test_cases: Arc<AtomicU64>,
+
+    /// The idempotency keys that are in-flight or were recently used, along with the checked submissions they were used
+    /// with.
+    pub(crate) idempotency_keys: Arc<IdempotencyKeys<CheckedSubmission>>,
+
+    /// The slots of the submissions that may be checked at the same time, see [`Config::concurrency_limit`].
+    pub(crate) slots: Arc<Slots>,
+}
+
+impl AppState {
+    /// Creates the state of mozart with the supplied `config`, where every other component starts out empty.
+    ///
+    /// A [`Config::concurrency_limit`] of zero is raised to one.
+    pub fn new(mut config: Config) -> Self {
+        config.concurrency_limit = config.concurrency_limit.max(1);
+        Self {
+            slots: Arc::new(Slots::new(config.concurrency_limit)),
+            config: Arc::new(config),
+            active_submissions: Arc::default(),
+            skipped_test_cases: Arc::default(),
+            idempotency_keys: Arc::default(),
+        }
+    }
+}
+
+impl Default for AppState {
+    fn default() -> Self {
+        Self::new(Config::default())
+    }
+}
+
+/// A fixed number of numbered slots, each of which is held by at most one submission being checked at a time.
+///
+/// The number of a slot selects the restricted user its execution runs as, such that the resource limits counted per
+/// user, e.g. the number of processes, apply to each execution separately.
+pub(crate) struct Slots {
+    /// The permits of the slots, which submissions wait for if every slot is held.
+    permits: Arc<Semaphore>,
+
+    /// The numbers of the slots that are not held.
+    free: Mutex<Vec<usize>>,
+}
+
+impl Slots {
+    /// Creates `count` slots, numbered from 0.
+    fn new(count: usize) -> Self {
+        Self {
+            permits: Arc::new(Semaphore::new(count)),
+            free: Mutex::new((0..count).rev().collect()),
+        }
+    }
+
+    /// Waits for a slot to be free, and holds it until the returned [`Slot`] is dropped.
+    pub(crate) async fn acquire(self: &Arc<Self>) -> Slot {
+        let permit = Arc::clone(&self.permits)
+            .acquire_owned()
+            .await
+            .expect("semaphore should never be closed");
+        let number = self
+            .free
+            .lock()
+            .expect("slots should never be poisoned")
+            .pop()
+            .expect("a slot should be free for every permit");
+
+        Slot {
+            number,
+            slots: Arc::clone(self),
+            _permit: permit,
+        }
+    }
+}
+
+/// A held slot, which is freed when it is dropped.
+pub(crate) struct Slot {
+    /// The number of the slot.
+    pub(crate) number: usize,
+
+    /// The slots it belongs to.
+    slots: Arc<Slots>,
+
+    /// The permit of the slot, which is released after its number is freed, as fields are dropped after [`Drop::drop`].
+    _permit: OwnedSemaphorePermit,
+}
+
+impl Drop for Slot {
+    fn drop(&mut self) {
+        self.slots
+            .free
+            .lock()
+            .expect("slots should never be poisoned")
+            .push(self.number);
+    }
+}
+
+#[cfg(test)]
+mod slots {
+    use super::Slots;
+    use std::sync::Arc;
+
+    #[tokio::test]
+    async fn distinct_numbers() {
+        let slots = Arc::new(Slots::new(2));
+
+        let first = slots.acquire().await;
+        let second = slots.acquire().await;
+
+        assert_ne!(first.number, second.number);
+        assert!(first.number < 2 && second.number < 2);
+    }
+
+    #[tokio::test]
+    async fn freed_number_reused() {
+        let slots = Arc::new(Slots::new(1));
+        let first = slots.acquire().await.number;
+
+        let actual = slots.acquire().await.number;
+
+        assert_eq!(actual, first);
+    }
+}
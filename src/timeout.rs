@@ -2,65 +2,336 @@
 
 use crate::error::SubmissionError;
 use std::{
+    future::pending,
+    io,
     process::{ExitStatus, Output},
     time::Duration,
 };
 use tokio::{
+    io::{AsyncRead, AsyncReadExt},
+    join,
     process::Child,
-    time::{sleep, Instant},
+    select,
+    sync::oneshot,
+    task::JoinHandle,
+    time::{self, sleep, Instant},
 };
-use tracing::{debug, error, info};
+use tracing::{debug, error, info, warn};
+
+/// The maximum number of bytes that are captured from each of the stdout and stderr of a process.
+pub const MAX_OUTPUT_SIZE: usize = 1024 * 1024;
+
+/// The marker appended to captured output that exceeded [`MAX_OUTPUT_SIZE`], and therefore was truncated.
+pub const TRUNCATION_MARKER: &str = "\n[output truncated]";
+
+/// The size of the chunks output is read in.
+const CHUNK_SIZE: usize = 8192;
+
+/// The interval the exit of a process is first polled with, such that a short-lived process is detected quickly.
+const MIN_POLL_INTERVAL: Duration = Duration::from_millis(1);
+
+/// The interval the polling of the exit of a process grows toward, such that a long-lived process is not polled needlessly often.
+const MAX_POLL_INTERVAL: Duration = Duration::from_millis(100);
+
+/// The time the remaining output of a killed process is read for, as it may be kept open by processes it spawned
+/// outside of its process group.
+const KILLED_DRAIN_PERIOD: Duration = Duration::from_millis(100);
+
+/// The outcome of running a process with a timeout.
+#[derive(Debug)]
+pub enum ProcessOutcome {
+    /// The process exited on its own, producing the contained output.
+    Exited(Output),
+
+    /// The process exceeded the timeout and was killed, after producing the contained output.
+    TimedOut(Output),
+}
 
 /// Calls the supplied `process` with the provided `timeout`.
 ///
-/// If the timeout is exceeded the process is killed as part of this function call.
+/// If the timeout is exceeded the process is killed right away as part of this function call,
+/// as its output is discarded anyway.
 ///
 /// No matter if the process finished on its own or was killed after the timeout an `Ok` is returned.
 /// The `Option` inside the `Ok` indicates whether the process exited naturally or was killed.
 /// If the process exited naturally the `Some` will contain the processes exit status.
 /// If the process was killed a `None` is returned as no exit status could be determined.
 ///
+/// See [`run_process`] for how the output of the process is captured.
+///
 /// # Errors
 /// An error can occur while attempting to wait on process, which returns a `SubmissionError::Internal`.
 pub async fn timeout_process(
     timeout: Duration,
-    mut process: Child,
+    process: Child,
 ) -> Result<Option<(ExitStatus, Output)>, SubmissionError> {
+    match run_process(timeout, Duration::ZERO, process).await? {
+        ProcessOutcome::Exited(output) => Ok(Some((output.status, output))),
+        ProcessOutcome::TimedOut(_) => Ok(None),
+    }
+}
+
+/// Calls the supplied `process` with the provided `timeout`, like [`timeout_process`],
+/// but keeps the output the process produced before being killed.
+///
+/// If the timeout is exceeded, the process is sent `SIGTERM` first, and only killed with `SIGKILL` if it has not exited
+/// within the `grace_period`, so that it may flush its buffered output. It is killed right away if the period is zero.
+///
+/// The exit of the process is polled with an interval that starts at [`MIN_POLL_INTERVAL`] and doubles with every poll,
+/// up to [`MAX_POLL_INTERVAL`].
+///
+/// The stdout and stderr of the process are read on their own tasks while waiting on it, so a process that fills
+/// a pipe is never blocked by it, no matter how long the interval is.
+///
+/// If the process leads a process group, i.e. it was spawned with `process_group(0)`, the group is killed as soon as
+/// the process has exited or been killed, such that no process it spawned outlives it.
+/// At most [`MAX_OUTPUT_SIZE`] bytes are captured from each of them, and any output beyond that is discarded,
+/// in which case the captured output ends with the [`TRUNCATION_MARKER`].
+///
+/// # Errors
+/// An error can occur while attempting to wait on process, which returns a `SubmissionError::Internal`.
+pub async fn run_process(
+    timeout: Duration,
+    grace_period: Duration,
+    mut process: Child,
+) -> Result<ProcessOutcome, SubmissionError> {
     let start = Instant::now();
+    let group = process.id();
+    let stdout = CaptureTask::spawn(process.stdout.take());
+    let stderr = CaptureTask::spawn(process.stderr.take());
+    let mut poll_interval = MIN_POLL_INTERVAL;
 
-    while process.try_wait().is_ok_and(|es| es.is_none()) && start.elapsed() < timeout {
-        sleep(Duration::from_millis(100)).await;
-    }
+    let exit_status = loop {
+        match process.try_wait() {
+            Ok(Some(exit_status)) => break exit_status,
+            Ok(None) if start.elapsed() >= timeout => {
+                info!("stopping process after exceeding timeout");
+                let exit_status = match terminate(&mut process, grace_period).await {
+                    Ok(exit_status) => exit_status,
+                    Err(err) => {
+                        error!("could not get exit status of killed process: {}", err);
+                        kill_group(group);
+                        return Err(SubmissionError::Internal);
+                    }
+                };
+                kill_group(group);
+
+                let (stdout, stderr) = join!(
+                    stdout.finish(KILLED_DRAIN_PERIOD),
+                    stderr.finish(KILLED_DRAIN_PERIOD)
+                );
+                let output = Output {
+                    status: exit_status,
+                    stdout,
+                    stderr,
+                };
+
+                return Ok(ProcessOutcome::TimedOut(output));
+            }
+            Ok(None) => {}
+            Err(err) => {
+                error!("unknown error from waiting on process timeout: {}", err);
+                return Err(SubmissionError::Internal);
+            }
+        }
+
+        sleep(poll_interval).await;
+        poll_interval = (poll_interval * 2).min(MAX_POLL_INTERVAL);
+    };
 
+    kill_group(group);
     debug!("finished waiting on process after {:?}", start.elapsed());
+    info!("process exited before exceeding timeout");
+    debug!(?exit_status);
 
-    match process.try_wait() {
-        Ok(Some(exit_status)) => {
-            info!("process exited before exceeding timeout");
-            debug!(?exit_status);
-            let output = process
-                .wait_with_output()
-                .await
-                .expect("guarded expect due to match statement");
-            Ok(Some((exit_status, output)))
+    // the pipes may be kept open by processes spawned by the process outside of its group, so the remaining output
+    // is only read for as long as the timeout allows
+    let remaining = timeout.saturating_sub(start.elapsed());
+    let (stdout, stderr) = join!(stdout.finish(remaining), stderr.finish(remaining));
+    let output = Output {
+        status: exit_status,
+        stdout,
+        stderr,
+    };
+
+    Ok(ProcessOutcome::Exited(output))
+}
+
+/// Sends `SIGTERM` to the `process`, and kills it with `SIGKILL` if it has not exited within the `grace_period`,
+/// returning its exit status. It is killed right away if the period is zero.
+async fn terminate(process: &mut Child, grace_period: Duration) -> io::Result<ExitStatus> {
+    if let Some(pid) = process.id().filter(|_| !grace_period.is_zero()) {
+        // SAFETY: the process has not been waited on, so its id cannot have been reused by another process
+        unsafe { libc::kill(pid as libc::pid_t, libc::SIGTERM) };
+        if let Ok(exit_status) = time::timeout(grace_period, process.wait()).await {
+            return exit_status;
         }
-        Ok(None) => {
-            info!("killing process after exceeding timeout");
-            process.kill().await.expect("should be able to kill child");
-            Ok(None)
+        info!(
+            "killing process that did not exit within {:?}",
+            grace_period
+        );
+    }
+
+    process.kill().await?;
+    process.wait().await
+}
+
+/// Kills the process group with the id of the `leader`, including processes it spawned that are still running.
+///
+/// A process that does not lead a group has no group with its id, in which case nothing is killed.
+fn kill_group(leader: Option<u32>) {
+    let Some(leader) = leader else {
+        return;
+    };
+
+    // SAFETY: the group is killed right after its leader is waited on, and as process ids are allocated cyclically,
+    // its id cannot have been reused by another group in between
+    if unsafe { libc::killpg(leader as libc::pid_t, libc::SIGKILL) } == 0 {
+        debug!("killed process group {}", leader);
+        return;
+    }
+
+    let err = io::Error::last_os_error();
+    if err.raw_os_error() != Some(libc::ESRCH) {
+        error!("could not kill process group {}: {}", leader, err);
+    }
+}
+
+/// A stream, e.g. stdout, that is captured on its own task until it is closed or stopped.
+struct CaptureTask<R> {
+    /// Stops reading the stream when sent to, or when dropped.
+    stop: oneshot::Sender<()>,
+
+    /// The task reading the stream, which completes with its capture.
+    handle: JoinHandle<Capture<R>>,
+}
+
+impl<R: AsyncRead + Unpin + Send + 'static> CaptureTask<R> {
+    /// Spawns a task capturing the supplied `reader`, where `None` means that there is nothing to capture.
+    fn spawn(reader: Option<R>) -> Self {
+        let (stop, stopped) = oneshot::channel();
+        let handle = tokio::spawn(async move {
+            let mut capture = Capture::new(reader);
+            select! {
+                _ = capture.read_to_end() => {}
+                _ = stopped => {}
+            }
+            capture
+        });
+
+        Self { stop, handle }
+    }
+
+    /// Waits for the stream to be closed for at most the `period`, after which reading it is stopped,
+    /// and gets the captured output.
+    async fn finish(self, period: Duration) -> Vec<u8> {
+        let Self { stop, mut handle } = self;
+        let joined = match time::timeout(period, &mut handle).await {
+            Ok(joined) => joined,
+            Err(_) => {
+                warn!("output of process was still open after {:?}", period);
+                // the task may have stopped on its own in the meantime, in which case there is no one to notify
+                let _ = stop.send(());
+                handle.await
+            }
+        };
+
+        match joined {
+            Ok(capture) => capture.into_bytes(),
+            Err(err) => {
+                error!(
+                    "could not join the capture of the output of process: {}",
+                    err
+                );
+                Vec::new()
+            }
+        }
+    }
+}
+
+/// The captured output of a stream, e.g. stdout, bounded by [`MAX_OUTPUT_SIZE`].
+struct Capture<R> {
+    /// The stream being read from, which is `None` once it is closed.
+    reader: Option<R>,
+
+    /// The output captured so far.
+    buffer: Vec<u8>,
+
+    /// Whether output was discarded due to exceeding [`MAX_OUTPUT_SIZE`].
+    truncated: bool,
+}
+
+impl<R: AsyncRead + Unpin> Capture<R> {
+    /// Creates a new capture of the supplied `reader`, where `None` means that there is nothing to capture.
+    fn new(reader: Option<R>) -> Self {
+        Self {
+            reader,
+            buffer: Vec::new(),
+            truncated: false,
+        }
+    }
+
+    /// Whether the stream is closed, meaning that there is no more output to read.
+    fn is_closed(&self) -> bool {
+        self.reader.is_none()
+    }
+
+    /// Reads the output until the stream is closed.
+    async fn read_to_end(&mut self) {
+        while !self.is_closed() {
+            self.read_chunk().await;
+        }
+    }
+
+    /// Reads the next chunk of output, discarding it if [`MAX_OUTPUT_SIZE`] has been reached.
+    ///
+    /// If the stream is closed this never completes, making it safe to use in a `select!`.
+    async fn read_chunk(&mut self) {
+        let Some(reader) = self.reader.as_mut() else {
+            return pending().await;
+        };
+
+        let mut chunk = [0; CHUNK_SIZE];
+        match reader.read(&mut chunk).await {
+            Ok(0) => self.reader = None,
+            Ok(read) => {
+                let capacity = MAX_OUTPUT_SIZE - self.buffer.len();
+                if read > capacity && !self.truncated {
+                    warn!("truncating output exceeding {} bytes", MAX_OUTPUT_SIZE);
+                    self.truncated = true;
+                }
+                self.buffer.extend_from_slice(&chunk[..read.min(capacity)]);
+            }
+            Err(err) => {
+                error!("could not read output of process: {}", err);
+                self.reader = None;
+            }
         }
-        Err(err) => {
-            error!("unknown error from waiting on process timeout: {}", err);
-            Err(SubmissionError::Internal)
+    }
+
+    /// Gets the captured output, ending with the [`TRUNCATION_MARKER`] if it was truncated.
+    fn into_bytes(mut self) -> Vec<u8> {
+        if self.truncated {
+            self.buffer.extend_from_slice(TRUNCATION_MARKER.as_bytes());
         }
+
+        self.buffer
     }
 }
 
 #[cfg(test)]
 mod timeout_process {
-    use crate::{error::SubmissionError, timeout::timeout_process};
-    use std::time::Duration;
-    use tokio::process::Command;
+    use crate::{
+        error::SubmissionError,
+        timeout::{
+            run_process, timeout_process, ProcessOutcome, MAX_OUTPUT_SIZE, TRUNCATION_MARKER,
+        },
+    };
+    use std::{fs, process::Stdio, time::Duration};
+    use tokio::{
+        process::{Child, Command},
+        time::{sleep, Instant},
+    };
 
     #[tokio::test]
     async fn exceed_timeout() -> Result<(), SubmissionError> {
@@ -94,4 +365,242 @@ mod timeout_process {
 
         Ok(())
     }
+
+    #[tokio::test]
+    async fn short_process_is_detected_quickly() -> Result<(), SubmissionError> {
+        let process = Command::new("sleep")
+            .arg("0.01")
+            .spawn()
+            .expect("failed to spawn process");
+        let duration = Duration::from_secs(1);
+        let start = Instant::now();
+
+        let result = timeout_process(duration, process).await?;
+
+        // the exit is detected well before the maximum poll interval has passed
+        assert!(result.is_some());
+        assert!(start.elapsed() < Duration::from_millis(75));
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn output_is_captured() -> Result<(), SubmissionError> {
+        let process = Command::new("echo")
+            .arg("hello")
+            .stdout(Stdio::piped())
+            .spawn()
+            .expect("failed to spawn process");
+        let duration = Duration::from_secs(1);
+        let expected = b"hello\n";
+
+        let (_, output) = timeout_process(duration, process)
+            .await?
+            .expect("process should exit before timeout");
+
+        assert_eq!(output.stdout, expected);
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn output_is_truncated() -> Result<(), SubmissionError> {
+        let process = Command::new("head")
+            .args(["-c", "5000000", "/dev/zero"])
+            .stdout(Stdio::piped())
+            .spawn()
+            .expect("failed to spawn process");
+        let duration = Duration::from_secs(5);
+
+        let (_, output) = timeout_process(duration, process)
+            .await?
+            .expect("process should exit before timeout");
+
+        assert_eq!(
+            output.stdout.len(),
+            MAX_OUTPUT_SIZE + TRUNCATION_MARKER.len()
+        );
+        assert!(output.stdout.ends_with(TRUNCATION_MARKER.as_bytes()));
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn full_pipes_do_not_block() -> Result<(), SubmissionError> {
+        // both outputs exceed the buffer of a pipe many times over, so the process only exits if they are read
+        let process = Command::new("sh")
+            .args([
+                "-c",
+                "head -c 1000000 /dev/zero >&2; head -c 1000000 /dev/zero",
+            ])
+            .stdout(Stdio::piped())
+            .stderr(Stdio::piped())
+            .spawn()
+            .expect("failed to spawn process");
+        let duration = Duration::from_secs(5);
+        let start = Instant::now();
+
+        let (_, output) = timeout_process(duration, process)
+            .await?
+            .expect("process should exit before timeout");
+
+        assert!(start.elapsed() < Duration::from_secs(1));
+        assert_eq!(output.stdout.len(), 1000000);
+        assert_eq!(output.stderr.len(), 1000000);
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn output_is_kept_after_timeout() -> Result<(), SubmissionError> {
+        let process = Command::new("sh")
+            .args(["-c", "echo hello; sleep 2"])
+            .stdout(Stdio::piped())
+            .spawn()
+            .expect("failed to spawn process");
+        let duration = Duration::from_millis(500);
+        let expected = b"hello\n";
+
+        let actual = run_process(duration, Duration::ZERO, process).await?;
+
+        let ProcessOutcome::TimedOut(output) = actual else {
+            panic!("process should exceed the timeout");
+        };
+        assert_eq!(output.stdout, expected);
+
+        Ok(())
+    }
+
+    /// Spawns a python process that writes `partial` to its buffered stdout, which it only flushes on `SIGTERM`.
+    fn spawn_flushing_on_sigterm() -> Child {
+        let script = [
+            "import signal, sys, time",
+            "def flush(signum, frame):",
+            "    sys.stdout.flush()",
+            "    sys.exit(0)",
+            "signal.signal(signal.SIGTERM, flush)",
+            "sys.stdout.write('partial')",
+            "time.sleep(10)",
+        ]
+        .join("\n");
+
+        Command::new("python3")
+            .args(["-c", &script])
+            .env_remove("PYTHONUNBUFFERED")
+            .stdout(Stdio::piped())
+            .spawn()
+            .expect("failed to spawn process")
+    }
+
+    #[tokio::test]
+    async fn output_is_flushed_within_grace_period() -> Result<(), SubmissionError> {
+        let process = spawn_flushing_on_sigterm();
+        let duration = Duration::from_millis(500);
+        let grace_period = Duration::from_secs(1);
+        let expected = b"partial";
+
+        let actual = run_process(duration, grace_period, process).await?;
+
+        let ProcessOutcome::TimedOut(output) = actual else {
+            panic!("process should exceed the timeout");
+        };
+        assert_eq!(output.stdout, expected);
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn output_is_lost_without_grace_period() -> Result<(), SubmissionError> {
+        let process = spawn_flushing_on_sigterm();
+        let duration = Duration::from_millis(500);
+
+        let actual = run_process(duration, Duration::ZERO, process).await?;
+
+        let ProcessOutcome::TimedOut(output) = actual else {
+            panic!("process should exceed the timeout");
+        };
+        assert!(output.stdout.is_empty());
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn killed_after_grace_period() -> Result<(), SubmissionError> {
+        // the process ignores SIGTERM, so it is only stopped by SIGKILL
+        let process = Command::new("sh")
+            .args(["-c", "trap '' TERM; sleep 10"])
+            .spawn()
+            .expect("failed to spawn process");
+        let duration = Duration::from_millis(100);
+        let grace_period = Duration::from_millis(100);
+        let start = Instant::now();
+
+        let actual = run_process(duration, grace_period, process).await?;
+
+        assert!(matches!(actual, ProcessOutcome::TimedOut(_)));
+        assert!(start.elapsed() < Duration::from_secs(2));
+
+        Ok(())
+    }
+
+    /// Spawns a shell leading its own process group, which starts a `sleep` that runs for as long as the `script` lets it,
+    /// and writes the id of the `sleep` to its stderr.
+    fn spawn_group_with_sleep(script: &str) -> Child {
+        Command::new("sh")
+            .args(["-c", &format!("sleep 60 & echo $! >&2; {}", script)])
+            .stderr(Stdio::piped())
+            .process_group(0)
+            .spawn()
+            .expect("failed to spawn process")
+    }
+
+    /// Checks whether the process with the id written to the `stderr` exits within a second, as a killed process may
+    /// take a moment to do so, where a process that has not been reaped yet has exited.
+    async fn exits(stderr: &[u8]) -> bool {
+        let pid = String::from_utf8_lossy(stderr);
+        let pid = pid.trim();
+        assert!(!pid.is_empty(), "the id of the spawned process is missing");
+
+        let start = Instant::now();
+        while start.elapsed() < Duration::from_secs(1) {
+            match fs::read_to_string(format!("/proc/{}/stat", pid)) {
+                Ok(stat) if !stat.contains(") Z ") => sleep(Duration::from_millis(10)).await,
+                _ => return true,
+            }
+        }
+
+        false
+    }
+
+    #[tokio::test]
+    async fn group_is_killed_on_exit() -> Result<(), SubmissionError> {
+        let process = spawn_group_with_sleep("exit 0");
+        let duration = Duration::from_secs(5);
+        let start = Instant::now();
+
+        let (_, output) = timeout_process(duration, process)
+            .await?
+            .expect("process should exit before timeout");
+
+        // the output of the sleep is closed once it is killed, rather than at the timeout
+        assert!(start.elapsed() < Duration::from_secs(1));
+        assert!(exits(&output.stderr).await);
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn group_is_killed_on_timeout() -> Result<(), SubmissionError> {
+        let process = spawn_group_with_sleep("sleep 60");
+        let duration = Duration::from_millis(100);
+
+        let actual = run_process(duration, Duration::ZERO, process).await?;
+
+        let ProcessOutcome::TimedOut(output) = actual else {
+            panic!("process should exceed the timeout");
+        };
+        assert!(exits(&output.stderr).await);
+
+        Ok(())
+    }
 }
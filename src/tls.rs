@@ -0,0 +1,203 @@
+//! Contains the optional TLS support of mozart.
+//!
+//! TLS is only enabled if both the `MOZART_TLS_CERT` and `MOZART_TLS_KEY` environment variables are set,
+//! in which case they must point to the PEM encoded certificate chain and private key to serve HTTPS with.
+
+use axum::Router;
+use hyper_util::{
+    rt::{TokioExecutor, TokioIo},
+    server::{conn::auto::Builder, graceful::GracefulShutdown},
+    service::TowerToHyperService,
+};
+use std::{env, future::Future, sync::Arc};
+use thiserror::Error;
+use tokio::{net::TcpListener, pin, select};
+use tokio_rustls::{
+    rustls::{
+        pki_types::{pem::PemObject, CertificateDer, PrivateKeyDer},
+        ServerConfig,
+    },
+    TlsAcceptor,
+};
+use tracing::{debug, error, info};
+
+/// The name of the environment variable containing the path to the PEM encoded certificate chain.
+const CERT_ENV_VAR: &str = "MOZART_TLS_CERT";
+
+/// The name of the environment variable containing the path to the PEM encoded private key.
+const KEY_ENV_VAR: &str = "MOZART_TLS_KEY";
+
+/// An error that occurs in relation to loading the TLS configuration.
+#[derive(Debug, Error)]
+pub enum TlsError {
+    /// Only one of the certificate and private key environment variables is set.
+    ///
+    /// The provided `&str` should contain the name of the environment variable that is not set.
+    #[error("{0} must also be set to enable TLS")]
+    MissingVariable(&'static str),
+
+    /// A PEM file could not be read or parsed.
+    ///
+    /// The provided `String` should contain the path to the file.
+    #[error("could not read {0}: {1}")]
+    Pem(String, tokio_rustls::rustls::pki_types::pem::Error),
+
+    /// The certificate chain and private key do not form a valid configuration, e.g. because they do not match.
+    #[error("invalid certificate or private key: {0}")]
+    Config(#[from] tokio_rustls::rustls::Error),
+}
+
+/// Gets the TLS configuration to serve mozart with, if any.
+///
+/// Unset or empty environment variables mean that TLS is disabled.
+///
+/// # Errors
+/// Returns a `TlsError` if only one of the environment variables is set, or the files they point to are not valid.
+pub fn tls_config() -> Result<Option<Arc<ServerConfig>>, TlsError> {
+    let cert_path = env::var(CERT_ENV_VAR).ok().filter(|path| !path.is_empty());
+    let key_path = env::var(KEY_ENV_VAR).ok().filter(|path| !path.is_empty());
+
+    load_tls_config(cert_path.as_deref(), key_path.as_deref())
+}
+
+/// Loads the TLS configuration from the certificate chain at `cert_path` and the private key at `key_path`.
+///
+/// If neither path is supplied, TLS is disabled and `None` is returned.
+///
+/// # Errors
+/// Returns a `TlsError` if only one of the paths is supplied, or the files they point to are not valid.
+fn load_tls_config(
+    cert_path: Option<&str>,
+    key_path: Option<&str>,
+) -> Result<Option<Arc<ServerConfig>>, TlsError> {
+    let (cert_path, key_path) = match (cert_path, key_path) {
+        (None, None) => return Ok(None),
+        (Some(_), None) => return Err(TlsError::MissingVariable(KEY_ENV_VAR)),
+        (None, Some(_)) => return Err(TlsError::MissingVariable(CERT_ENV_VAR)),
+        (Some(cert_path), Some(key_path)) => (cert_path, key_path),
+    };
+
+    let certs = CertificateDer::pem_file_iter(cert_path)
+        .and_then(|certs| certs.collect::<Result<Vec<_>, _>>())
+        .map_err(|err| TlsError::Pem(cert_path.to_string(), err))?;
+    let key = PrivateKeyDer::from_pem_file(key_path)
+        .map_err(|err| TlsError::Pem(key_path.to_string(), err))?;
+
+    let config = ServerConfig::builder()
+        .with_no_client_auth()
+        .with_single_cert(certs, key)?;
+    info!("loaded tls certificate from {}", cert_path);
+
+    Ok(Some(Arc::new(config)))
+}
+
+/// Serves the `app` over TLS using the `config` on the supplied `listener`, until the `shutdown` future completes.
+///
+/// Once shutting down, no new connections are accepted, and it returns when the open connections have finished.
+pub(crate) async fn serve_tls<F>(
+    listener: TcpListener,
+    config: Arc<ServerConfig>,
+    app: Router,
+    shutdown: F,
+) where
+    F: Future<Output = ()>,
+{
+    let acceptor = TlsAcceptor::from(config);
+    let builder = Builder::new(TokioExecutor::new());
+    let graceful = GracefulShutdown::new();
+    pin!(shutdown);
+
+    loop {
+        let stream = select! {
+            accepted = listener.accept() => match accepted {
+                Ok((stream, _)) => stream,
+                Err(err) => {
+                    error!("could not accept connection: {}", err);
+                    continue;
+                }
+            },
+            () = &mut shutdown => break,
+        };
+
+        let acceptor = acceptor.clone();
+        let builder = builder.clone();
+        let service = TowerToHyperService::new(app.clone());
+        let watcher = graceful.watcher();
+        tokio::spawn(async move {
+            let stream = match acceptor.accept(stream).await {
+                Ok(stream) => stream,
+                Err(err) => {
+                    debug!("tls handshake failed: {}", err);
+                    return;
+                }
+            };
+
+            let connection = builder.serve_connection(TokioIo::new(stream), service);
+            if let Err(err) = watcher.watch(connection.into_owned()).await {
+                debug!("connection closed with error: {}", err);
+            }
+        });
+    }
+
+    drop(listener);
+    graceful.shutdown().await;
+}
+
+#[cfg(test)]
+mod load_tls_config {
+    use super::{load_tls_config, TlsError, CERT_ENV_VAR, KEY_ENV_VAR};
+
+    /// The path to a self-signed certificate for `localhost`, only used for testing.
+    const CERT_PATH: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/tests/tls/cert.pem");
+
+    /// The path to the private key of the certificate at [`CERT_PATH`].
+    const KEY_PATH: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/tests/tls/key.pem");
+
+    #[test]
+    fn absent_paths() {
+        let actual = load_tls_config(None, None);
+
+        assert!(matches!(actual, Ok(None)));
+    }
+
+    #[test]
+    fn present_paths() {
+        let actual = load_tls_config(Some(CERT_PATH), Some(KEY_PATH));
+
+        assert!(matches!(actual, Ok(Some(_))));
+    }
+
+    #[test]
+    fn absent_key_path() {
+        let actual = load_tls_config(Some(CERT_PATH), None);
+
+        assert!(matches!(
+            actual,
+            Err(TlsError::MissingVariable(KEY_ENV_VAR))
+        ));
+    }
+
+    #[test]
+    fn absent_cert_path() {
+        let actual = load_tls_config(None, Some(KEY_PATH));
+
+        assert!(matches!(
+            actual,
+            Err(TlsError::MissingVariable(CERT_ENV_VAR))
+        ));
+    }
+
+    #[test]
+    fn nonexistent_cert_file() {
+        let actual = load_tls_config(Some("/nonexistent/cert.pem"), Some(KEY_PATH));
+
+        assert!(matches!(actual, Err(TlsError::Pem(..))));
+    }
+
+    #[test]
+    fn key_as_certificate() {
+        let actual = load_tls_config(Some(KEY_PATH), Some(KEY_PATH));
+
+        assert!(actual.is_err());
+    }
+}
@@ -0,0 +1,1717 @@
+//! Contains the validation of submissions, performed before any code is generated.
+//!
+//! Validation exists to reject malformed input with a descriptive error, instead of letting it
+//! surface as a confusing compilation or runtime error of the generated test code.
+
+use crate::{
+    error::SubmissionError,
+    model::{FloatTolerance, Parameter, ParameterType, Submission, TestCase},
+};
+use std::collections::HashSet;
+use tracing::{debug, info};
+
+/// The maximum length of the name of an auxiliary file.
+const MAX_FILE_NAME_LENGTH: usize = 255;
+
+/// Validates the submission, i.e. that the test case ids are unique, the ids of the test cases to run exist,
+/// the parameters are valid, the float tolerance is valid, and the names of the auxiliary files are valid.
+///
+/// # Errors
+/// Returns a `SubmissionError::DuplicateTestCaseId` for the first id that is not unique,
+/// a `SubmissionError::UnknownTestCaseId` for the first id to run that no test case has,
+/// a `SubmissionError::InvalidParameter` for the first parameter that is not valid, prefixed with its location,
+/// a `SubmissionError::InvalidFloatTolerance` if the float tolerance is negative or combined with a checker,
+/// and a `SubmissionError::InvalidFileName` for the first file name that is not valid.
+pub fn validate_submission(submission: &Submission) -> Result<(), SubmissionError> {
+    info!("validating submission");
+
+    let mut ids = HashSet::with_capacity(submission.test_cases.len());
+    for test_case in &submission.test_cases {
+        if !ids.insert(test_case.id) {
+            debug!("test case id '{}' is not unique", test_case.id);
+            return Err(SubmissionError::DuplicateTestCaseId(test_case.id));
+        }
+    }
+
+    for id in submission.only_ids.iter().flatten() {
+        if !ids.contains(id) {
+            debug!("no test case has the requested id '{}'", id);
+            return Err(SubmissionError::UnknownTestCaseId(*id));
+        }
+    }
+
+    for test_case in &submission.test_cases {
+        for (location, parameter) in located_parameters(test_case) {
+            if let Err(err) = validate_parameter(parameter) {
+                debug!("test case '{}' has invalid parameter", test_case.id);
+                return Err(location.locate(err));
+            }
+        }
+    }
+
+    if let Some(tolerance) = submission.float_tolerance {
+        validate_float_tolerance(tolerance, submission.checker.is_some())?;
+    }
+
+    let mut names = HashSet::with_capacity(submission.files.len());
+    for file in &submission.files {
+        validate_file_name(&file.name)?;
+        if !names.insert(file.name.as_str()) {
+            debug!("file name '{}' is not unique", file.name);
+            return Err(SubmissionError::InvalidFileName(format!(
+                "multiple files are named '{}'",
+                file.name
+            )));
+        }
+    }
+
+    Ok(())
+}
+
+/// Validates that the `tolerance` is neither negative nor combined with a checker, i.e. if `has_checker` is set,
+/// as a checker decides whether a test case passed on its own.
+///
+/// # Errors
+/// Returns a `SubmissionError::InvalidFloatTolerance` describing why the tolerance is not valid.
+fn validate_float_tolerance(
+    tolerance: FloatTolerance,
+    has_checker: bool,
+) -> Result<(), SubmissionError> {
+    if has_checker {
+        debug!("float tolerance is combined with a checker");
+        return Err(SubmissionError::InvalidFloatTolerance(String::from(
+            "it cannot be combined with a checker",
+        )));
+    }
+
+    for (name, value) in [
+        ("absolute", tolerance.absolute),
+        ("relative", tolerance.relative),
+    ] {
+        if !(value.is_finite() && value >= 0.0) {
+            debug!("{} float tolerance '{}' is not valid", name, value);
+            return Err(SubmissionError::InvalidFloatTolerance(format!(
+                "the {name} tolerance '{value}' must be a finite, non-negative number"
+            )));
+        }
+    }
+
+    Ok(())
+}
+
+/// Validates that the `name` of an auxiliary file is a plain file name,
+/// which prevents it from referring to anything outside the working directory.
+///
+/// A valid name only consists of ASCII letters, digits, `_`, `-` and `.`, and does not start with a `.`.
+///
+/// # Errors
+/// Returns a `SubmissionError::InvalidFileName` describing why the name is not valid.
+pub fn validate_file_name(name: &str) -> Result<(), SubmissionError> {
+    let is_valid = !name.is_empty()
+        && name.len() <= MAX_FILE_NAME_LENGTH
+        && !name.starts_with('.')
+        && name
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || matches!(c, '_' | '-' | '.'));
+
+    if is_valid {
+        Ok(())
+    } else {
+        debug!("file name '{}' is not valid", name);
+        Err(SubmissionError::InvalidFileName(format!(
+            "'{name}' is not a plain file name"
+        )))
+    }
+}
+
+/// Validates that the value of the `parameter` is valid for its type.
+///
+/// # Errors
+/// Returns a `SubmissionError::InvalidParameter` describing why the value is not valid.
+pub fn validate_parameter(parameter: &Parameter) -> Result<(), SubmissionError> {
+    let value = parameter.value.as_str();
+
+    let is_valid = match &parameter.value_type {
+        // C++ and Kotlin write an Int as a 64-bit literal, so a larger value would fail to compile there
+        // the value is pasted into the generated code as is, so it must be canonical, e.g. `010` is octal in C++
+        ParameterType::Int | ParameterType::Int64 => {
+            is_canonical_integer(value) && value.parse::<i64>().is_ok()
+        }
+        ParameterType::Int32 => is_canonical_integer(value) && value.parse::<i32>().is_ok(),
+        ParameterType::BigInt => is_canonical_integer(value),
+        // NaN and the infinities are allowed, e.g. `NaN` or `-Infinity`, as a solution may have to return them
+        ParameterType::Float => value.parse::<f64>().is_ok(),
+        ParameterType::Decimal => is_decimal(value),
+        ParameterType::Bool => matches!(value, "true" | "false"),
+        // a character is a single unicode scalar value, so a combining sequence is not one
+        ParameterType::Char => value.chars().count() == 1,
+        ParameterType::String => true,
+        ParameterType::Unit => value.is_empty(),
+        ParameterType::Map { key, .. } => {
+            if matches!(
+                key.as_ref(),
+                ParameterType::Map { .. } | ParameterType::List(_)
+            ) {
+                return Err(SubmissionError::InvalidParameter(String::from(
+                    "the key of a Map cannot be a Map or a List",
+                )));
+            }
+
+            match parameter.entries() {
+                Some(entries) => {
+                    for (key, value) in &entries {
+                        validate_parameter(key)?;
+                        validate_parameter(value)?;
+                    }
+                    true
+                }
+                None => false,
+            }
+        }
+        ParameterType::List(value_type) => {
+            if matches!(value_type.as_ref(), ParameterType::Unit) {
+                return Err(SubmissionError::InvalidParameter(String::from(
+                    "a List cannot contain a Unit",
+                )));
+            }
+
+            match parameter.elements() {
+                Some(elements) => {
+                    for element in &elements {
+                        validate_parameter(element)?;
+                    }
+                    true
+                }
+                None => false,
+            }
+        }
+        ParameterType::Optional(value_type) => {
+            if matches!(
+                value_type.as_ref(),
+                ParameterType::Optional(_) | ParameterType::Unit
+            ) {
+                return Err(SubmissionError::InvalidParameter(String::from(
+                    "an Optional cannot contain an Optional or a Unit",
+                )));
+            }
+
+            match parameter.optional_value() {
+                Some(Some(value)) => {
+                    validate_parameter(&value)?;
+                    true
+                }
+                Some(None) => true,
+                None => false,
+            }
+        }
+    };
+
+    if is_valid {
+        Ok(())
+    } else {
+        Err(SubmissionError::InvalidParameter(format!(
+            "'{}' is not a valid {:?}",
+            value, parameter.value_type
+        )))
+    }
+}
+
+/// Validates that every flag in `compiler_flags` is one of the `allowed` flags configured by the server.
+///
+/// # Errors
+/// Returns a `SubmissionError::DisallowedCompilerFlag` for the first flag that is not allowed.
+pub fn validate_compiler_flags(
+    compiler_flags: &[String],
+    allowed: &[String],
+) -> Result<(), SubmissionError> {
+    for flag in compiler_flags {
+        if !allowed.contains(flag) {
+            debug!("compiler flag '{}' is not allowed", flag);
+            return Err(SubmissionError::DisallowedCompilerFlag(flag.clone()));
+        }
+    }
+
+    Ok(())
+}
+
+/// Validates that the `toolchain_version` requested by a submission, if any, is one of the `allowed` versions
+/// configured by the server.
+///
+/// # Errors
+/// Returns a `SubmissionError::UnknownToolchainVersion` if the version is not allowed.
+pub fn validate_toolchain_version(
+    toolchain_version: Option<&str>,
+    allowed: &[String],
+) -> Result<(), SubmissionError> {
+    match toolchain_version {
+        Some(version) if !allowed.iter().any(|allowed| allowed == version) => {
+            debug!("toolchain version '{}' is not available", version);
+            Err(SubmissionError::UnknownToolchainVersion(
+                version.to_string(),
+            ))
+        }
+        _ => Ok(()),
+    }
+}
+
+/// Validates that no parameter of the submission has a type, or contains a type, in `unsupported`,
+/// which the language enabled via feature flags cannot represent, nor is optional unless `supports_optional` is set.
+///
+/// # Errors
+/// Returns a `SubmissionError::InvalidParameter` naming the first unsupported type, prefixed with its location.
+pub fn validate_parameter_types(
+    submission: &Submission,
+    unsupported: &[ParameterType],
+    supports_optional: bool,
+) -> Result<(), SubmissionError> {
+    for test_case in &submission.test_cases {
+        for (location, parameter) in located_parameters(test_case) {
+            if let Some(parameter_type) =
+                unsupported_type(&parameter.value_type, unsupported, supports_optional)
+            {
+                debug!("test case '{}' has unsupported parameter", test_case.id);
+                return Err(location.locate(SubmissionError::InvalidParameter(format!(
+                    "the {parameter_type:?} type is not supported"
+                ))));
+            }
+        }
+    }
+
+    Ok(())
+}
+
+/// Validates that no character of the submission, including those nested in other values, is beyond `max_char`,
+/// which is the largest character a `Char` of the language enabled via feature flags can hold.
+///
+/// # Errors
+/// Returns a `SubmissionError::InvalidParameter` naming the first character beyond it, prefixed with its location.
+pub fn validate_char_range(submission: &Submission, max_char: char) -> Result<(), SubmissionError> {
+    for test_case in &submission.test_cases {
+        for (location, parameter) in located_parameters(test_case) {
+            if let Some(c) = char_beyond(parameter, max_char) {
+                debug!("test case '{}' has unsupported character", test_case.id);
+                return Err(location.locate(SubmissionError::InvalidParameter(format!(
+                    "'{c}' is not a supported Char, as only characters up to U+{:04X} are",
+                    max_char as u32
+                ))));
+            }
+        }
+    }
+
+    Ok(())
+}
+
+/// The location of a parameter inside a submission, which prefixes the error of an invalid parameter, e.g.
+/// `test case 3, input parameter 1: 'abc' is not a valid Int`, so that it can be found among many test cases.
+struct ParameterLocation {
+    /// The id of the test case the parameter belongs to.
+    test_case_id: u64,
+
+    /// Whether the parameter is an input parameter, rather than an output parameter.
+    input: bool,
+
+    /// The index of the parameter among the input or output parameters of its test case, starting from 0.
+    index: usize,
+}
+
+impl ParameterLocation {
+    /// Prefixes the message of `err` with the location, if it is a `SubmissionError::InvalidParameter`.
+    fn locate(&self, err: SubmissionError) -> SubmissionError {
+        match err {
+            SubmissionError::InvalidParameter(message) => {
+                SubmissionError::InvalidParameter(format!(
+                    "test case {}, {} parameter {}: {message}",
+                    self.test_case_id,
+                    if self.input { "input" } else { "output" },
+                    self.index
+                ))
+            }
+            err => err,
+        }
+    }
+}
+
+/// Gets the input parameters followed by the output parameters of the `test_case`, along with their location.
+fn located_parameters(
+    test_case: &TestCase,
+) -> impl Iterator<Item = (ParameterLocation, &Parameter)> {
+    [
+        (true, &test_case.input_parameters),
+        (false, &test_case.output_parameters),
+    ]
+    .into_iter()
+    .flat_map(move |(input, parameters)| {
+        parameters
+            .iter()
+            .enumerate()
+            .map(move |(index, parameter)| {
+                let location = ParameterLocation {
+                    test_case_id: test_case.id,
+                    input,
+                    index,
+                };
+                (location, parameter)
+            })
+    })
+}
+
+/// Finds the first character beyond `max_char` that the validated `parameter` is or contains.
+fn char_beyond(parameter: &Parameter, max_char: char) -> Option<char> {
+    match &parameter.value_type {
+        ParameterType::Char => parameter.value.chars().next().filter(|&c| c > max_char),
+        ParameterType::Map { .. } => parameter.entries()?.iter().find_map(|(key, value)| {
+            char_beyond(key, max_char).or_else(|| char_beyond(value, max_char))
+        }),
+        ParameterType::List(_) => parameter
+            .elements()?
+            .iter()
+            .find_map(|element| char_beyond(element, max_char)),
+        ParameterType::Optional(_) => parameter
+            .optional_value()
+            .flatten()
+            .and_then(|value| char_beyond(&value, max_char)),
+        _ => None,
+    }
+}
+
+/// Finds the first type in `unsupported` that `parameter_type` is or contains,
+/// or the first optional type it is or contains unless `supports_optional` is set.
+fn unsupported_type<'a>(
+    parameter_type: &'a ParameterType,
+    unsupported: &[ParameterType],
+    supports_optional: bool,
+) -> Option<&'a ParameterType> {
+    if unsupported.contains(parameter_type) {
+        return Some(parameter_type);
+    }
+
+    match parameter_type {
+        ParameterType::Map { key, value } => unsupported_type(key, unsupported, supports_optional)
+            .or_else(|| unsupported_type(value, unsupported, supports_optional)),
+        ParameterType::Optional(_) if !supports_optional => Some(parameter_type),
+        ParameterType::Optional(value_type) | ParameterType::List(value_type) => {
+            unsupported_type(value_type, unsupported, supports_optional)
+        }
+        _ => None,
+    }
+}
+
+/// Determines whether `value` is an integer of arbitrary length, i.e. an optional sign followed by digits.
+fn is_integer(value: &str) -> bool {
+    let digits = value.strip_prefix('-').unwrap_or(value);
+
+    !digits.is_empty() && digits.chars().all(|c| c.is_ascii_digit())
+}
+
+/// Determines whether `value` is an integer in canonical form,
+/// i.e. without a leading zero unless it is `0` itself, which is not negative.
+fn is_canonical_integer(value: &str) -> bool {
+    let digits = value.strip_prefix('-').unwrap_or(value);
+
+    is_integer(value) && (!digits.starts_with('0') || value == "0")
+}
+
+/// Determines whether `value` is a decimal number, i.e. an integer optionally followed by a `.` and fractional digits.
+fn is_decimal(value: &str) -> bool {
+    match value.split_once('.') {
+        Some((integer, fraction)) => {
+            is_integer(integer)
+                && !fraction.is_empty()
+                && fraction.chars().all(|c| c.is_ascii_digit())
+        }
+        None => is_integer(value),
+    }
+}
+
+#[cfg(test)]
+mod validate_submission {
+    use super::validate_submission;
+    use crate::{
+        error::SubmissionError,
+        model::{Parameter, ParameterType, SourceFile, Submission, TestCase},
+    };
+
+    /// A test util function to make a test case with the supplied `id` and empty parameters.
+    fn empty_test_case(id: u64) -> TestCase {
+        TestCase {
+            id,
+            input_parameters: Box::new([]),
+            output_parameters: Box::new([]),
+            stdin: None,
+            expected_error: None,
+            label: None,
+            hidden: false,
+        }
+    }
+
+    #[test]
+    fn unique_ids() {
+        let input = Submission {
+            solution: String::new(),
+            test_cases: Box::new([empty_test_case(0), empty_test_case(1)]),
+            unordered_lists: false,
+            files: Box::new([]),
+            checker: None,
+            reference_solution: None,
+            only_ids: None,
+            compiler_flags: Box::new([]),
+            debug: false,
+            float_tolerance: None,
+            toolchain_version: None,
+            metadata: None,
+        };
+
+        let actual = validate_submission(&input);
+
+        assert_eq!(actual, Ok(()));
+    }
+
+    #[test]
+    fn duplicate_ids() {
+        let input = Submission {
+            solution: String::new(),
+            test_cases: Box::new([empty_test_case(0), empty_test_case(0)]),
+            unordered_lists: false,
+            files: Box::new([]),
+            checker: None,
+            reference_solution: None,
+            only_ids: None,
+            compiler_flags: Box::new([]),
+            debug: false,
+            float_tolerance: None,
+            toolchain_version: None,
+            metadata: None,
+        };
+        let expected = Err(SubmissionError::DuplicateTestCaseId(0));
+
+        let actual = validate_submission(&input);
+
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn known_only_ids() {
+        let input = Submission {
+            solution: String::new(),
+            test_cases: Box::new([empty_test_case(0), empty_test_case(1)]),
+            unordered_lists: false,
+            files: Box::new([]),
+            checker: None,
+            reference_solution: None,
+            only_ids: Some(Box::new([1])),
+            compiler_flags: Box::new([]),
+            debug: false,
+            float_tolerance: None,
+            toolchain_version: None,
+            metadata: None,
+        };
+
+        let actual = validate_submission(&input);
+
+        assert_eq!(actual, Ok(()));
+    }
+
+    #[test]
+    fn unknown_only_ids() {
+        let input = Submission {
+            solution: String::new(),
+            test_cases: Box::new([empty_test_case(0), empty_test_case(1)]),
+            unordered_lists: false,
+            files: Box::new([]),
+            checker: None,
+            reference_solution: None,
+            only_ids: Some(Box::new([1, 7])),
+            compiler_flags: Box::new([]),
+            debug: false,
+            float_tolerance: None,
+            toolchain_version: None,
+            metadata: None,
+        };
+        let expected = Err(SubmissionError::UnknownTestCaseId(7));
+
+        let actual = validate_submission(&input);
+
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn invalid_input_parameter_location() {
+        let input = Submission {
+            solution: String::new(),
+            test_cases: Box::new([
+                empty_test_case(0),
+                TestCase {
+                    input_parameters: Box::new([
+                        Parameter {
+                            value_type: ParameterType::Int,
+                            value: String::from("1"),
+                        },
+                        Parameter {
+                            value_type: ParameterType::Int,
+                            value: String::from("abc"),
+                        },
+                    ]),
+                    ..empty_test_case(3)
+                },
+            ]),
+            unordered_lists: false,
+            files: Box::new([]),
+            checker: None,
+            reference_solution: None,
+            only_ids: None,
+            compiler_flags: Box::new([]),
+            debug: false,
+            float_tolerance: None,
+            toolchain_version: None,
+            metadata: None,
+        };
+        let expected = Err(SubmissionError::InvalidParameter(String::from(
+            "test case 3, input parameter 1: 'abc' is not a valid Int",
+        )));
+
+        let actual = validate_submission(&input);
+
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn invalid_output_parameter_location() {
+        let input = Submission {
+            solution: String::new(),
+            test_cases: Box::new([TestCase {
+                input_parameters: Box::new([Parameter {
+                    value_type: ParameterType::Bool,
+                    value: String::from("true"),
+                }]),
+                output_parameters: Box::new([Parameter {
+                    value_type: ParameterType::Bool,
+                    value: String::from("True"),
+                }]),
+                ..empty_test_case(5)
+            }]),
+            unordered_lists: false,
+            files: Box::new([]),
+            checker: None,
+            reference_solution: None,
+            only_ids: None,
+            compiler_flags: Box::new([]),
+            debug: false,
+            float_tolerance: None,
+            toolchain_version: None,
+            metadata: None,
+        };
+        let expected = Err(SubmissionError::InvalidParameter(String::from(
+            "test case 5, output parameter 0: 'True' is not a valid Bool",
+        )));
+
+        let actual = validate_submission(&input);
+
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn duplicate_file_names() {
+        let file = SourceFile {
+            name: String::from("Helper.hs"),
+            contents: String::new(),
+        };
+        let input = Submission {
+            solution: String::new(),
+            test_cases: Box::new([]),
+            unordered_lists: false,
+            files: Box::new([file.clone(), file]),
+            checker: None,
+            reference_solution: None,
+            only_ids: None,
+            compiler_flags: Box::new([]),
+            debug: false,
+            float_tolerance: None,
+            toolchain_version: None,
+            metadata: None,
+        };
+
+        let actual = validate_submission(&input);
+
+        assert!(matches!(actual, Err(SubmissionError::InvalidFileName(_))));
+    }
+}
+
+#[cfg(test)]
+mod validate_compiler_flags {
+    use super::validate_compiler_flags;
+    use crate::error::SubmissionError;
+
+    #[test]
+    fn allowed_flags() {
+        let input = [String::from("-XTupleSections")];
+        let allowed = [
+            String::from("-XLambdaCase"),
+            String::from("-XTupleSections"),
+        ];
+
+        let actual = validate_compiler_flags(&input, &allowed);
+
+        assert_eq!(actual, Ok(()));
+    }
+
+    #[test]
+    fn disallowed_flag() {
+        let input = [
+            String::from("-XTupleSections"),
+            String::from("-fplugin=Evil"),
+        ];
+        let allowed = [String::from("-XTupleSections")];
+        let expected = Err(SubmissionError::DisallowedCompilerFlag(String::from(
+            "-fplugin=Evil",
+        )));
+
+        let actual = validate_compiler_flags(&input, &allowed);
+
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn nothing_allowed_by_default() {
+        let input = [String::from("-O0")];
+        let expected = Err(SubmissionError::DisallowedCompilerFlag(String::from("-O0")));
+
+        let actual = validate_compiler_flags(&input, &[]);
+
+        assert_eq!(actual, expected);
+    }
+}
+
+#[cfg(test)]
+mod validate_toolchain_version {
+    use super::validate_toolchain_version;
+    use crate::error::SubmissionError;
+
+    #[test]
+    fn default_version() {
+        let actual = validate_toolchain_version(None, &[]);
+
+        assert_eq!(actual, Ok(()));
+    }
+
+    #[test]
+    fn allowed_version() {
+        let allowed = [String::from("9.4.7"), String::from("9.8.2")];
+
+        let actual = validate_toolchain_version(Some("9.8.2"), &allowed);
+
+        assert_eq!(actual, Ok(()));
+    }
+
+    #[test]
+    fn unknown_version() {
+        let allowed = [String::from("9.4.7")];
+        let expected = Err(SubmissionError::UnknownToolchainVersion(String::from(
+            "8.10.7",
+        )));
+
+        let actual = validate_toolchain_version(Some("8.10.7"), &allowed);
+
+        assert_eq!(actual, expected);
+    }
+}
+
+#[cfg(test)]
+mod validate_parameter_types {
+    use super::validate_parameter_types;
+    use crate::{
+        error::SubmissionError,
+        model::{Parameter, ParameterType, Submission, TestCase},
+    };
+
+    /// A test util function to make a submission with a single test case, whose only input has the `value_type`.
+    fn submission_with_input(value_type: ParameterType) -> Submission {
+        Submission {
+            solution: String::new(),
+            test_cases: Box::new([TestCase {
+                id: 0,
+                input_parameters: Box::new([Parameter {
+                    value_type,
+                    value: String::new(),
+                }]),
+                output_parameters: Box::new([]),
+                stdin: None,
+                expected_error: None,
+                label: None,
+                hidden: false,
+            }]),
+            unordered_lists: false,
+            files: Box::new([]),
+            checker: None,
+            reference_solution: None,
+            only_ids: None,
+            compiler_flags: Box::new([]),
+            debug: false,
+            float_tolerance: None,
+            toolchain_version: None,
+            metadata: None,
+        }
+    }
+
+    #[test]
+    fn supported_type() {
+        let input = submission_with_input(ParameterType::Int);
+
+        let actual = validate_parameter_types(&input, &[ParameterType::BigInt], true);
+
+        assert_eq!(actual, Ok(()));
+    }
+
+    #[test]
+    fn unsupported_type() {
+        let input = submission_with_input(ParameterType::BigInt);
+        let expected = Err(SubmissionError::InvalidParameter(String::from(
+            "test case 0, input parameter 0: the BigInt type is not supported",
+        )));
+
+        let actual = validate_parameter_types(&input, &[ParameterType::BigInt], true);
+
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn unsupported_map_value() {
+        let input = submission_with_input(ParameterType::Map {
+            key: Box::new(ParameterType::String),
+            value: Box::new(ParameterType::BigInt),
+        });
+        let expected = Err(SubmissionError::InvalidParameter(String::from(
+            "test case 0, input parameter 0: the BigInt type is not supported",
+        )));
+
+        let actual = validate_parameter_types(&input, &[ParameterType::BigInt], true);
+
+        assert_eq!(actual, expected);
+    }
+    #[test]
+    fn unsupported_optional() {
+        let input = submission_with_input(ParameterType::Optional(Box::new(ParameterType::Int)));
+        let expected = Err(SubmissionError::InvalidParameter(String::from(
+            "test case 0, input parameter 0: the Optional(Int) type is not supported",
+        )));
+
+        let actual = validate_parameter_types(&input, &[], false);
+
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn unsupported_optional_value() {
+        let input = submission_with_input(ParameterType::Optional(Box::new(ParameterType::BigInt)));
+        let expected = Err(SubmissionError::InvalidParameter(String::from(
+            "test case 0, input parameter 0: the BigInt type is not supported",
+        )));
+
+        let actual = validate_parameter_types(&input, &[ParameterType::BigInt], true);
+
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn unsupported_list_element() {
+        let input = submission_with_input(ParameterType::List(Box::new(ParameterType::BigInt)));
+        let expected = Err(SubmissionError::InvalidParameter(String::from(
+            "test case 0, input parameter 0: the BigInt type is not supported",
+        )));
+
+        let actual = validate_parameter_types(&input, &[ParameterType::BigInt], true);
+
+        assert_eq!(actual, expected);
+    }
+}
+
+#[cfg(test)]
+mod validate_char_range {
+    use super::validate_char_range;
+    use crate::{
+        error::SubmissionError,
+        model::{Parameter, ParameterType, Submission, TestCase},
+    };
+
+    /// A test util function to make a submission with a single test case, whose only output is the `parameter`.
+    fn submission_with_output(parameter: Parameter) -> Submission {
+        Submission {
+            solution: String::new(),
+            test_cases: Box::new([TestCase {
+                id: 0,
+                input_parameters: Box::new([]),
+                output_parameters: Box::new([parameter]),
+                stdin: None,
+                expected_error: None,
+                label: None,
+                hidden: false,
+            }]),
+            unordered_lists: false,
+            files: Box::new([]),
+            checker: None,
+            reference_solution: None,
+            only_ids: None,
+            compiler_flags: Box::new([]),
+            debug: false,
+            float_tolerance: None,
+            toolchain_version: None,
+            metadata: None,
+        }
+    }
+
+    #[test]
+    fn ascii_within_ascii() {
+        let input = submission_with_output(Parameter {
+            value_type: ParameterType::Char,
+            value: String::from("a"),
+        });
+
+        let actual = validate_char_range(&input, '\x7f');
+
+        assert_eq!(actual, Ok(()));
+    }
+
+    #[test]
+    fn non_ascii_beyond_ascii() {
+        let input = submission_with_output(Parameter {
+            value_type: ParameterType::Char,
+            value: String::from("é"),
+        });
+        let expected = Err(SubmissionError::InvalidParameter(String::from(
+            "test case 0, output parameter 0: 'é' is not a supported Char, as only characters up to U+007F are",
+        )));
+
+        let actual = validate_char_range(&input, '\x7f');
+
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn supplementary_beyond_basic_multilingual_plane() {
+        let input = submission_with_output(Parameter {
+            value_type: ParameterType::Char,
+            value: String::from("😀"),
+        });
+        let expected = Err(SubmissionError::InvalidParameter(String::from(
+            "test case 0, output parameter 0: '😀' is not a supported Char, as only characters up to U+FFFF are",
+        )));
+
+        let actual = validate_char_range(&input, '\u{ffff}');
+
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn non_ascii_within_basic_multilingual_plane() {
+        let input = submission_with_output(Parameter {
+            value_type: ParameterType::Char,
+            value: String::from("é"),
+        });
+
+        let actual = validate_char_range(&input, '\u{ffff}');
+
+        assert_eq!(actual, Ok(()));
+    }
+
+    #[test]
+    fn non_ascii_list_element_beyond_ascii() {
+        let input = submission_with_output(Parameter {
+            value_type: ParameterType::List(Box::new(ParameterType::Char)),
+            value: String::from(r#"["a", "é"]"#),
+        });
+
+        let actual = validate_char_range(&input, '\x7f');
+
+        assert!(matches!(actual, Err(SubmissionError::InvalidParameter(_))));
+    }
+
+    #[test]
+    fn non_ascii_string_beyond_ascii() {
+        let input = submission_with_output(Parameter {
+            value_type: ParameterType::String,
+            value: String::from("é"),
+        });
+
+        let actual = validate_char_range(&input, '\x7f');
+
+        assert_eq!(actual, Ok(()));
+    }
+}
+
+#[cfg(test)]
+mod validate_float_tolerance {
+    use super::validate_float_tolerance;
+    use crate::{error::SubmissionError, model::FloatTolerance};
+
+    #[test]
+    fn valid() {
+        let input = FloatTolerance {
+            absolute: 1e-9,
+            relative: 1e-6,
+            match_nan: false,
+        };
+
+        let actual = validate_float_tolerance(input, false);
+
+        assert_eq!(actual, Ok(()));
+    }
+
+    #[test]
+    fn negative() {
+        let input = FloatTolerance {
+            absolute: 0.0,
+            relative: -1e-6,
+            match_nan: false,
+        };
+
+        let actual = validate_float_tolerance(input, false);
+
+        assert!(matches!(
+            actual,
+            Err(SubmissionError::InvalidFloatTolerance(_))
+        ));
+    }
+
+    #[test]
+    fn with_checker() {
+        let input = FloatTolerance::default();
+
+        let actual = validate_float_tolerance(input, true);
+
+        assert!(matches!(
+            actual,
+            Err(SubmissionError::InvalidFloatTolerance(_))
+        ));
+    }
+}
+
+#[cfg(test)]
+mod validate_file_name {
+    use super::validate_file_name;
+    use crate::error::SubmissionError;
+
+    #[test]
+    fn plain_name() {
+        let input = "Helper.hs";
+
+        let actual = validate_file_name(input);
+
+        assert_eq!(actual, Ok(()));
+    }
+
+    #[test]
+    fn path_traversal() {
+        let input = "../Helper.hs";
+
+        let actual = validate_file_name(input);
+
+        assert!(matches!(actual, Err(SubmissionError::InvalidFileName(_))));
+    }
+
+    #[test]
+    fn absolute_path() {
+        let input = "/etc/passwd";
+
+        let actual = validate_file_name(input);
+
+        assert!(matches!(actual, Err(SubmissionError::InvalidFileName(_))));
+    }
+
+    #[test]
+    fn hidden_file() {
+        let input = ".bashrc";
+
+        let actual = validate_file_name(input);
+
+        assert!(matches!(actual, Err(SubmissionError::InvalidFileName(_))));
+    }
+
+    #[test]
+    fn empty() {
+        let input = "";
+
+        let actual = validate_file_name(input);
+
+        assert!(matches!(actual, Err(SubmissionError::InvalidFileName(_))));
+    }
+}
+
+#[cfg(test)]
+mod validate_parameter {
+    use super::validate_parameter;
+    use crate::{
+        error::SubmissionError,
+        model::{Parameter, ParameterType},
+    };
+
+    #[test]
+    fn int_valid() {
+        let input = Parameter {
+            value_type: ParameterType::Int,
+            value: String::from("-100"),
+        };
+
+        let actual = validate_parameter(&input);
+
+        assert_eq!(actual, Ok(()));
+    }
+
+    #[test]
+    fn int_overflow() {
+        let input = Parameter {
+            value_type: ParameterType::Int,
+            value: String::from("9223372036854775808"),
+        };
+
+        let actual = validate_parameter(&input);
+
+        assert!(matches!(actual, Err(SubmissionError::InvalidParameter(_))));
+    }
+
+    #[test]
+    fn int_bounds() {
+        for value in ["9223372036854775807", "-9223372036854775808"] {
+            let input = Parameter {
+                value_type: ParameterType::Int,
+                value: String::from(value),
+            };
+
+            let actual = validate_parameter(&input);
+
+            assert_eq!(actual, Ok(()), "{value} should be a valid Int");
+        }
+    }
+
+    #[test]
+    fn int_underflow() {
+        let input = Parameter {
+            value_type: ParameterType::Int,
+            value: String::from("-9223372036854775809"),
+        };
+        let expected = SubmissionError::InvalidParameter(String::from(
+            "'-9223372036854775809' is not a valid Int",
+        ));
+
+        let actual = validate_parameter(&input);
+
+        assert_eq!(actual, Err(expected));
+    }
+
+    #[test]
+    fn int_non_canonical() {
+        for value in ["+5", "010", "-0", "-09223372036854775808"] {
+            let input = Parameter {
+                value_type: ParameterType::Int,
+                value: String::from(value),
+            };
+            let expected =
+                SubmissionError::InvalidParameter(format!("'{value}' is not a valid Int"));
+
+            let actual = validate_parameter(&input);
+
+            assert_eq!(actual, Err(expected), "{value} should not be a valid Int");
+        }
+    }
+
+    #[test]
+    fn int_zero() {
+        let input = Parameter {
+            value_type: ParameterType::Int,
+            value: String::from("0"),
+        };
+
+        let actual = validate_parameter(&input);
+
+        assert_eq!(actual, Ok(()));
+    }
+
+    #[test]
+    fn int32_non_canonical() {
+        for value in ["+5", "010", "-0"] {
+            let input = Parameter {
+                value_type: ParameterType::Int32,
+                value: String::from(value),
+            };
+
+            let actual = validate_parameter(&input);
+
+            assert!(
+                matches!(actual, Err(SubmissionError::InvalidParameter(_))),
+                "{value} should not be a valid Int32"
+            );
+        }
+    }
+
+    #[test]
+    fn int32_overflow() {
+        let input = Parameter {
+            value_type: ParameterType::Int32,
+            value: String::from("2147483648"),
+        };
+
+        let actual = validate_parameter(&input);
+
+        assert!(matches!(actual, Err(SubmissionError::InvalidParameter(_))));
+    }
+
+    #[test]
+    fn int64_beyond_32_bits() {
+        let input = Parameter {
+            value_type: ParameterType::Int64,
+            value: String::from("2147483648"),
+        };
+
+        let actual = validate_parameter(&input);
+
+        assert_eq!(actual, Ok(()));
+    }
+
+    #[test]
+    fn float_whole_number() {
+        let input = Parameter {
+            value_type: ParameterType::Float,
+            value: String::from("5"),
+        };
+
+        let actual = validate_parameter(&input);
+
+        assert_eq!(actual, Ok(()));
+    }
+
+    #[test]
+    fn float_not_a_number() {
+        let input = Parameter {
+            value_type: ParameterType::Float,
+            value: String::from("five"),
+        };
+
+        let actual = validate_parameter(&input);
+
+        assert!(matches!(actual, Err(SubmissionError::InvalidParameter(_))));
+    }
+
+    #[test]
+    fn float_nan() {
+        let input = Parameter {
+            value_type: ParameterType::Float,
+            value: String::from("NaN"),
+        };
+
+        let actual = validate_parameter(&input);
+
+        assert_eq!(actual, Ok(()));
+    }
+
+    #[test]
+    fn float_negative_infinity() {
+        let input = Parameter {
+            value_type: ParameterType::Float,
+            value: String::from("-Infinity"),
+        };
+
+        let actual = validate_parameter(&input);
+
+        assert_eq!(actual, Ok(()));
+    }
+
+    #[test]
+    fn bool_valid() {
+        let input = Parameter {
+            value_type: ParameterType::Bool,
+            value: String::from("false"),
+        };
+
+        let actual = validate_parameter(&input);
+
+        assert_eq!(actual, Ok(()));
+    }
+
+    #[test]
+    fn bool_capitalized() {
+        let input = Parameter {
+            value_type: ParameterType::Bool,
+            value: String::from("True"),
+        };
+
+        let actual = validate_parameter(&input);
+
+        assert!(matches!(actual, Err(SubmissionError::InvalidParameter(_))));
+    }
+
+    #[test]
+    fn bool_numeric() {
+        let input = Parameter {
+            value_type: ParameterType::Bool,
+            value: String::from("1"),
+        };
+
+        let actual = validate_parameter(&input);
+
+        assert!(matches!(actual, Err(SubmissionError::InvalidParameter(_))));
+    }
+
+    #[test]
+    fn bool_empty() {
+        let input = Parameter {
+            value_type: ParameterType::Bool,
+            value: String::from(""),
+        };
+
+        let actual = validate_parameter(&input);
+
+        assert!(matches!(actual, Err(SubmissionError::InvalidParameter(_))));
+    }
+
+    #[test]
+    fn char_valid() {
+        let input = Parameter {
+            value_type: ParameterType::Char,
+            value: String::from("a"),
+        };
+
+        let actual = validate_parameter(&input);
+
+        assert_eq!(actual, Ok(()));
+    }
+
+    #[test]
+    fn char_multiple_characters() {
+        let input = Parameter {
+            value_type: ParameterType::Char,
+            value: String::from("ab"),
+        };
+
+        let actual = validate_parameter(&input);
+
+        assert!(matches!(actual, Err(SubmissionError::InvalidParameter(_))));
+    }
+
+    #[test]
+    fn char_non_ascii() {
+        let input = Parameter {
+            value_type: ParameterType::Char,
+            value: String::from("é"),
+        };
+
+        let actual = validate_parameter(&input);
+
+        assert_eq!(actual, Ok(()));
+    }
+
+    #[test]
+    fn char_emoji() {
+        let input = Parameter {
+            value_type: ParameterType::Char,
+            value: String::from("🚀"),
+        };
+
+        let actual = validate_parameter(&input);
+
+        assert_eq!(actual, Ok(()));
+    }
+
+    #[test]
+    fn char_combining_sequence() {
+        // an 'e' followed by a combining acute accent looks like a single character, but is two scalar values
+        let input = Parameter {
+            value_type: ParameterType::Char,
+            value: String::from("e\u{301}"),
+        };
+
+        let actual = validate_parameter(&input);
+
+        assert!(matches!(actual, Err(SubmissionError::InvalidParameter(_))));
+    }
+
+    #[test]
+    fn char_empty() {
+        let input = Parameter {
+            value_type: ParameterType::Char,
+            value: String::from(""),
+        };
+
+        let actual = validate_parameter(&input);
+
+        assert!(matches!(actual, Err(SubmissionError::InvalidParameter(_))));
+    }
+
+    #[test]
+    fn unit_valid() {
+        let input = Parameter {
+            value_type: ParameterType::Unit,
+            value: String::new(),
+        };
+
+        let actual = validate_parameter(&input);
+
+        assert_eq!(actual, Ok(()));
+    }
+
+    #[test]
+    fn unit_with_value() {
+        let input = Parameter {
+            value_type: ParameterType::Unit,
+            value: String::from("()"),
+        };
+
+        let actual = validate_parameter(&input);
+
+        assert!(matches!(actual, Err(SubmissionError::InvalidParameter(_))));
+    }
+
+    #[test]
+    fn big_int_beyond_64_bits() {
+        let input = Parameter {
+            value_type: ParameterType::BigInt,
+            value: String::from("15511210043330985984000000"),
+        };
+
+        let actual = validate_parameter(&input);
+
+        assert_eq!(actual, Ok(()));
+    }
+
+    #[test]
+    fn big_int_negative() {
+        let input = Parameter {
+            value_type: ParameterType::BigInt,
+            value: String::from("-15511210043330985984000000"),
+        };
+
+        let actual = validate_parameter(&input);
+
+        assert_eq!(actual, Ok(()));
+    }
+
+    #[test]
+    fn big_int_not_digits() {
+        let input = Parameter {
+            value_type: ParameterType::BigInt,
+            value: String::from("12a4"),
+        };
+
+        let actual = validate_parameter(&input);
+
+        assert!(matches!(actual, Err(SubmissionError::InvalidParameter(_))));
+    }
+
+    #[test]
+    fn big_int_non_canonical() {
+        for value in ["+5", "010", "-0", "-007"] {
+            let input = Parameter {
+                value_type: ParameterType::BigInt,
+                value: String::from(value),
+            };
+
+            let actual = validate_parameter(&input);
+
+            assert!(
+                matches!(actual, Err(SubmissionError::InvalidParameter(_))),
+                "{value} should not be a valid BigInt"
+            );
+        }
+    }
+
+    #[test]
+    fn big_int_only_sign() {
+        let input = Parameter {
+            value_type: ParameterType::BigInt,
+            value: String::from("-"),
+        };
+
+        let actual = validate_parameter(&input);
+
+        assert!(matches!(actual, Err(SubmissionError::InvalidParameter(_))));
+    }
+
+    #[test]
+    fn decimal() {
+        let input = Parameter {
+            value_type: ParameterType::Decimal,
+            value: String::from("10.25"),
+        };
+
+        let actual = validate_parameter(&input);
+
+        assert_eq!(actual, Ok(()));
+    }
+
+    #[test]
+    fn decimal_negative() {
+        let input = Parameter {
+            value_type: ParameterType::Decimal,
+            value: String::from("-0.05"),
+        };
+
+        let actual = validate_parameter(&input);
+
+        assert_eq!(actual, Ok(()));
+    }
+
+    #[test]
+    fn decimal_without_fraction() {
+        let input = Parameter {
+            value_type: ParameterType::Decimal,
+            value: String::from("10"),
+        };
+
+        let actual = validate_parameter(&input);
+
+        assert_eq!(actual, Ok(()));
+    }
+
+    #[test]
+    fn decimal_without_fraction_digits() {
+        let input = Parameter {
+            value_type: ParameterType::Decimal,
+            value: String::from("10."),
+        };
+
+        let actual = validate_parameter(&input);
+
+        assert!(matches!(actual, Err(SubmissionError::InvalidParameter(_))));
+    }
+
+    #[test]
+    fn decimal_without_integer_digits() {
+        let input = Parameter {
+            value_type: ParameterType::Decimal,
+            value: String::from(".25"),
+        };
+
+        let actual = validate_parameter(&input);
+
+        assert!(matches!(actual, Err(SubmissionError::InvalidParameter(_))));
+    }
+
+    #[test]
+    fn decimal_exponent() {
+        let input = Parameter {
+            value_type: ParameterType::Decimal,
+            value: String::from("1e3"),
+        };
+
+        let actual = validate_parameter(&input);
+
+        assert!(matches!(actual, Err(SubmissionError::InvalidParameter(_))));
+    }
+
+    /// A test util function to make a map type from the `key` type to the `value` type.
+    fn map(key: ParameterType, value: ParameterType) -> ParameterType {
+        ParameterType::Map {
+            key: Box::new(key),
+            value: Box::new(value),
+        }
+    }
+
+    #[test]
+    fn map_valid() {
+        let input = Parameter {
+            value_type: map(ParameterType::String, ParameterType::Int),
+            value: String::from(r#"{"apple": "3", "pear": "5"}"#),
+        };
+
+        let actual = validate_parameter(&input);
+
+        assert_eq!(actual, Ok(()));
+    }
+
+    #[test]
+    fn map_empty() {
+        let input = Parameter {
+            value_type: map(ParameterType::String, ParameterType::Int),
+            value: String::from("{}"),
+        };
+
+        let actual = validate_parameter(&input);
+
+        assert_eq!(actual, Ok(()));
+    }
+
+    #[test]
+    fn map_invalid_value() {
+        let input = Parameter {
+            value_type: map(ParameterType::String, ParameterType::Int),
+            value: String::from(r#"{"apple": "three"}"#),
+        };
+
+        let actual = validate_parameter(&input);
+
+        assert!(matches!(actual, Err(SubmissionError::InvalidParameter(_))));
+    }
+
+    #[test]
+    fn map_invalid_key() {
+        let input = Parameter {
+            value_type: map(ParameterType::Int, ParameterType::Int),
+            value: String::from(r#"{"one": "1"}"#),
+        };
+
+        let actual = validate_parameter(&input);
+
+        assert!(matches!(actual, Err(SubmissionError::InvalidParameter(_))));
+    }
+
+    #[test]
+    fn map_not_object() {
+        let input = Parameter {
+            value_type: map(ParameterType::String, ParameterType::Int),
+            value: String::from(r#"[["apple", "3"]]"#),
+        };
+
+        let actual = validate_parameter(&input);
+
+        assert!(matches!(actual, Err(SubmissionError::InvalidParameter(_))));
+    }
+
+    #[test]
+    fn map_with_map_key() {
+        let input = Parameter {
+            value_type: map(
+                map(ParameterType::String, ParameterType::Int),
+                ParameterType::Int,
+            ),
+            value: String::from("{}"),
+        };
+
+        let actual = validate_parameter(&input);
+
+        assert!(matches!(actual, Err(SubmissionError::InvalidParameter(_))));
+    }
+
+    #[test]
+    fn map_with_list_key() {
+        let input = Parameter {
+            value_type: map(
+                ParameterType::List(Box::new(ParameterType::Int)),
+                ParameterType::Int,
+            ),
+            value: String::from("{}"),
+        };
+
+        let actual = validate_parameter(&input);
+
+        assert!(matches!(actual, Err(SubmissionError::InvalidParameter(_))));
+    }
+
+    #[test]
+    fn list() {
+        let input = Parameter {
+            value_type: ParameterType::List(Box::new(ParameterType::Int)),
+            value: String::from(r#"["1", "2", "3"]"#),
+        };
+
+        let actual = validate_parameter(&input);
+
+        assert_eq!(actual, Ok(()));
+    }
+
+    #[test]
+    fn list_empty() {
+        let input = Parameter {
+            value_type: ParameterType::List(Box::new(ParameterType::String)),
+            value: String::from("[]"),
+        };
+
+        let actual = validate_parameter(&input);
+
+        assert_eq!(actual, Ok(()));
+    }
+
+    #[test]
+    fn list_invalid_element() {
+        let input = Parameter {
+            value_type: ParameterType::List(Box::new(ParameterType::Int)),
+            value: String::from(r#"["1", "two"]"#),
+        };
+
+        let actual = validate_parameter(&input);
+
+        assert!(matches!(actual, Err(SubmissionError::InvalidParameter(_))));
+    }
+
+    #[test]
+    fn list_not_json() {
+        let input = Parameter {
+            value_type: ParameterType::List(Box::new(ParameterType::Int)),
+            value: String::from("1, 2"),
+        };
+
+        let actual = validate_parameter(&input);
+
+        assert!(matches!(actual, Err(SubmissionError::InvalidParameter(_))));
+    }
+
+    #[test]
+    fn list_of_unit() {
+        let input = Parameter {
+            value_type: ParameterType::List(Box::new(ParameterType::Unit)),
+            value: String::from(r#"[""]"#),
+        };
+
+        let actual = validate_parameter(&input);
+
+        assert!(matches!(actual, Err(SubmissionError::InvalidParameter(_))));
+    }
+
+    #[test]
+    fn optional_present() {
+        let input = Parameter {
+            value_type: ParameterType::Optional(Box::new(ParameterType::Int)),
+            value: String::from(r#""5""#),
+        };
+
+        let actual = validate_parameter(&input);
+
+        assert_eq!(actual, Ok(()));
+    }
+
+    #[test]
+    fn optional_absent() {
+        let input = Parameter {
+            value_type: ParameterType::Optional(Box::new(ParameterType::Int)),
+            value: String::from("null"),
+        };
+
+        let actual = validate_parameter(&input);
+
+        assert_eq!(actual, Ok(()));
+    }
+
+    #[test]
+    fn optional_invalid_value() {
+        let input = Parameter {
+            value_type: ParameterType::Optional(Box::new(ParameterType::Int)),
+            value: String::from(r#""five""#),
+        };
+
+        let actual = validate_parameter(&input);
+
+        assert!(matches!(actual, Err(SubmissionError::InvalidParameter(_))));
+    }
+
+    #[test]
+    fn optional_not_json() {
+        let input = Parameter {
+            value_type: ParameterType::Optional(Box::new(ParameterType::Int)),
+            value: String::from("5"),
+        };
+
+        let actual = validate_parameter(&input);
+
+        assert!(matches!(actual, Err(SubmissionError::InvalidParameter(_))));
+    }
+
+    #[test]
+    fn optional_of_optional() {
+        let input = Parameter {
+            value_type: ParameterType::Optional(Box::new(ParameterType::Optional(Box::new(
+                ParameterType::Int,
+            )))),
+            value: String::from("null"),
+        };
+
+        let actual = validate_parameter(&input);
+
+        assert!(matches!(actual, Err(SubmissionError::InvalidParameter(_))));
+    }
+}
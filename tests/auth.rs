@@ -0,0 +1,69 @@
+use axum::{
+    body::Body,
+    http::{request::Builder, Method, StatusCode},
+    Router,
+};
+use mozart::{app, config::Config, state::AppState};
+use tower::ServiceExt;
+
+/// Makes a router that requires the `api_key`.
+fn mozart(api_key: &str) -> Router {
+    app(AppState::new(Config {
+        api_key: Some(String::from(api_key)),
+        ..Config::default()
+    }))
+}
+
+#[tokio::test]
+async fn configured_key_is_required() {
+    let request = Builder::new()
+        .header("Content-Type", "application/json")
+        .method(Method::POST)
+        .uri("/submit")
+        .body(Body::from("{}"))
+        .expect("failed to build request");
+    let expected = StatusCode::UNAUTHORIZED;
+
+    let actual = mozart("secret")
+        .oneshot(request)
+        .await
+        .expect("failed to await oneshot");
+
+    assert_eq!(actual.status(), expected);
+}
+
+#[tokio::test]
+async fn configured_key_is_accepted() {
+    // the body is not a submission, so getting past the key is told apart by the request being rejected instead
+    let request = Builder::new()
+        .header("Content-Type", "application/json")
+        .header("Authorization", "Bearer secret")
+        .method(Method::POST)
+        .uri("/submit")
+        .body(Body::from("{}"))
+        .expect("failed to build request");
+
+    let actual = mozart("secret")
+        .oneshot(request)
+        .await
+        .expect("failed to await oneshot");
+
+    assert_ne!(actual.status(), StatusCode::UNAUTHORIZED);
+}
+
+#[tokio::test]
+async fn status_does_not_require_key() {
+    let request = Builder::new()
+        .method(Method::GET)
+        .uri("/status")
+        .body(Body::empty())
+        .expect("failed to build request");
+    let expected = StatusCode::OK;
+
+    let actual = mozart("secret")
+        .oneshot(request)
+        .await
+        .expect("failed to await oneshot");
+
+    assert_eq!(actual.status(), expected);
+}
@@ -0,0 +1,98 @@
+use axum::{
+    body::{to_bytes, Body},
+    http::{request::Builder, Method, StatusCode},
+};
+use mozart::{
+    app,
+    config::Config,
+    model::{Capabilities, ParameterType},
+    state::AppState,
+};
+use std::time::Duration;
+use tower::ServiceExt;
+
+#[cfg(feature = "cpp")]
+const EXPECTED_LANGUAGE: &str = "cpp";
+
+#[cfg(feature = "haskell")]
+const EXPECTED_LANGUAGE: &str = "haskell";
+
+#[cfg(feature = "python")]
+const EXPECTED_LANGUAGE: &str = "python";
+
+#[cfg(feature = "kotlin")]
+const EXPECTED_LANGUAGE: &str = "kotlin";
+
+#[cfg(any(feature = "haskell", feature = "python"))]
+const UNSUPPORTED_PARAMETER_TYPES: &[ParameterType] = &[];
+
+#[cfg(feature = "cpp")]
+const UNSUPPORTED_PARAMETER_TYPES: &[ParameterType] =
+    &[ParameterType::BigInt, ParameterType::Decimal];
+
+#[cfg(feature = "kotlin")]
+const UNSUPPORTED_PARAMETER_TYPES: &[ParameterType] = &[ParameterType::Decimal];
+
+#[tokio::test]
+async fn active_language() {
+    let mozart = app(AppState::default());
+    let request = Builder::new()
+        .method(Method::GET)
+        .uri("/capabilities")
+        .body(Body::empty())
+        .expect("failed to build request");
+    let expected_status_code = StatusCode::OK;
+    let expected_parameter_types = ParameterType::ALL
+        .into_iter()
+        .filter(|parameter_type| !UNSUPPORTED_PARAMETER_TYPES.contains(parameter_type))
+        .collect::<Vec<_>>();
+
+    let actual = mozart
+        .oneshot(request)
+        .await
+        .expect("failed to await oneshot");
+
+    let actual_status_code = actual.status();
+    let body_bytes = to_bytes(actual.into_body(), usize::MAX)
+        .await
+        .expect("failed to convert body to bytes");
+    let actual_body: Capabilities =
+        serde_json::from_slice(&body_bytes).expect("failed to deserialize response body");
+
+    assert_eq!(actual_status_code, expected_status_code);
+    assert_eq!(*actual_body.languages, [String::from(EXPECTED_LANGUAGE)]);
+    assert_eq!(*actual_body.parameter_types, *expected_parameter_types);
+}
+
+#[tokio::test]
+async fn configured_timeouts() {
+    let mozart = app(AppState::new(Config {
+        timeout: Duration::from_secs(2),
+        test_case_timeout: Duration::from_millis(250),
+        ..Config::default()
+    }));
+    let request = Builder::new()
+        .method(Method::GET)
+        .uri("/capabilities")
+        .body(Body::empty())
+        .expect("failed to build request");
+    let expected_timeout_ms = 2000;
+    let expected_test_case_timeout_ms = 250;
+
+    let actual = mozart
+        .oneshot(request)
+        .await
+        .expect("failed to await oneshot");
+
+    let body_bytes = to_bytes(actual.into_body(), usize::MAX)
+        .await
+        .expect("failed to convert body to bytes");
+    let actual_body: Capabilities =
+        serde_json::from_slice(&body_bytes).expect("failed to deserialize response body");
+
+    assert_eq!(actual_body.timeout_ms, expected_timeout_ms);
+    assert_eq!(
+        actual_body.test_case_timeout_ms,
+        expected_test_case_timeout_ms
+    );
+}
@@ -0,0 +1,134 @@
+#![cfg(feature = "client")]
+
+use mozart::{
+    client::{submit, ClientError},
+    config::Config,
+    model::{Parameter, ParameterType, Submission, TestCase},
+    response::{ErrorCode, SubmissionResult},
+    serve_until,
+    state::AppState,
+};
+use reqwest::StatusCode;
+use std::{future::pending, net::SocketAddr};
+use tokio::net::TcpListener;
+
+/// A test util function to serve mozart on a random local port, returning the address it is served at.
+async fn serve() -> SocketAddr {
+    serve_with(AppState::default()).await
+}
+
+/// A test util function to serve mozart with the `state` on a random local port, returning the address it is served at.
+async fn serve_with(state: AppState) -> SocketAddr {
+    let listener = TcpListener::bind("127.0.0.1:0")
+        .await
+        .expect("failed to bind listener");
+    let address = listener.local_addr().expect("failed to get local address");
+    tokio::spawn(serve_until(listener, state, pending()));
+
+    address
+}
+
+/// A test util function to serve mozart requiring the `api_key` on a random local port, returning the address it is
+/// served at.
+async fn serve_with_api_key(api_key: &str) -> SocketAddr {
+    serve_with(AppState::new(Config {
+        api_key: Some(String::from(api_key)),
+        ..Config::default()
+    }))
+    .await
+}
+
+/// A test util function to make a submission with an empty solution, which is rejected by every language.
+fn empty_submission() -> Submission {
+    Submission {
+        solution: String::new(),
+        test_cases: Box::new([TestCase {
+            id: 0,
+            input_parameters: Box::new([Parameter {
+                value_type: ParameterType::Int,
+                value: String::from("2"),
+            }]),
+            output_parameters: Box::new([Parameter {
+                value_type: ParameterType::Int,
+                value: String::from("4"),
+            }]),
+            stdin: None,
+            expected_error: None,
+            label: None,
+            hidden: false,
+        }]),
+        unordered_lists: false,
+        files: Box::new([]),
+        checker: None,
+        reference_solution: None,
+        only_ids: None,
+        compiler_flags: Box::new([]),
+        debug: false,
+        float_tolerance: None,
+        toolchain_version: None,
+        metadata: None,
+    }
+}
+
+#[tokio::test]
+async fn round_trip() {
+    let address = serve().await;
+    let expected = SubmissionResult::Error {
+        code: ErrorCode::EmptySolution,
+        message: String::from("solution is empty"),
+    };
+
+    let actual = submit(&format!("http://{address}"), None, &empty_submission())
+        .await
+        .expect("failed to submit");
+
+    assert_eq!(actual, expected);
+}
+
+#[tokio::test]
+async fn unsuccessful_status() {
+    let address = serve().await;
+
+    let actual = submit(
+        &format!("http://{address}/nonexistent"),
+        None,
+        &empty_submission(),
+    )
+    .await;
+
+    assert!(matches!(
+        actual,
+        Err(ClientError::Status(StatusCode::NOT_FOUND))
+    ));
+}
+
+#[tokio::test]
+async fn api_key_sent() {
+    let address = serve_with_api_key("correct horse battery staple").await;
+    let expected = SubmissionResult::Error {
+        code: ErrorCode::EmptySolution,
+        message: String::from("solution is empty"),
+    };
+
+    let actual = submit(
+        &format!("http://{address}"),
+        Some("correct horse battery staple"),
+        &empty_submission(),
+    )
+    .await
+    .expect("failed to submit");
+
+    assert_eq!(actual, expected);
+}
+
+#[tokio::test]
+async fn api_key_missing() {
+    let address = serve_with_api_key("correct horse battery staple").await;
+
+    let actual = submit(&format!("http://{address}"), None, &empty_submission()).await;
+
+    assert!(matches!(
+        actual,
+        Err(ClientError::Status(StatusCode::UNAUTHORIZED))
+    ));
+}
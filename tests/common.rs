@@ -0,0 +1,94 @@
+//! Fixtures shared by the integration tests that check a submission in whichever language mozart is built for.
+
+use axum::{
+    body::{to_bytes, Body},
+    http::{request::Builder, Method, Request, StatusCode},
+};
+use mozart::{
+    app,
+    model::{Parameter, ParameterType, Submission, TestCase},
+    state::AppState,
+};
+use serde::{de::DeserializeOwned, Serialize};
+use tower::ServiceExt;
+
+/// A solution that doubles its integer input.
+#[cfg(feature = "cpp")]
+pub const SOLUTION: &str = "long long solution(long long x) {\n    return x + x;\n}\n";
+
+/// A solution that doubles its integer input.
+#[cfg(feature = "haskell")]
+pub const SOLUTION: &str = "module Solution where\n\nsolution :: Int -> Int\nsolution x = x + x\n";
+
+/// A solution that doubles its integer input.
+#[cfg(feature = "python")]
+pub const SOLUTION: &str = "def solution(x: int) -> int:\n    return x + x\n";
+
+/// A solution that doubles its integer input.
+#[cfg(feature = "kotlin")]
+pub const SOLUTION: &str = "fun solution(x: Long): Long = x + x\n";
+
+/// Creates a test case with the `id`, which expects the integer `input` to be doubled into the `output`.
+pub fn test_case(id: u64, input: &str, output: &str) -> TestCase {
+    TestCase {
+        id,
+        input_parameters: Box::new([Parameter {
+            value_type: ParameterType::Int,
+            value: String::from(input),
+        }]),
+        output_parameters: Box::new([Parameter {
+            value_type: ParameterType::Int,
+            value: String::from(output),
+        }]),
+        stdin: None,
+        expected_error: None,
+        label: None,
+        hidden: false,
+    }
+}
+
+/// Creates a submission of the `solution` with the `test_cases`, where every option is left at its default.
+pub fn submission(solution: &str, test_cases: Box<[TestCase]>) -> Submission {
+    Submission {
+        solution: String::from(solution),
+        test_cases,
+        unordered_lists: false,
+        files: Box::new([]),
+        checker: None,
+        reference_solution: None,
+        only_ids: None,
+        compiler_flags: Box::new([]),
+        debug: false,
+        float_tolerance: None,
+        toolchain_version: None,
+        metadata: None,
+    }
+}
+
+/// Builds a request that posts the `body` as JSON to the `uri`.
+pub fn post_request(uri: &str, body: &impl Serialize) -> Request<Body> {
+    let body = serde_json::to_string(body).expect("failed to serialize request body");
+
+    Builder::new()
+        .header("Content-Type", "application/json")
+        .method(Method::POST)
+        .uri(uri)
+        .body(Body::from(body))
+        .expect("failed to build request")
+}
+
+/// Sends the `request` to a router with the `state`, and responds with the status code and the deserialized body.
+pub async fn send<T: DeserializeOwned>(state: AppState, request: Request<Body>) -> (StatusCode, T) {
+    let actual = app(state)
+        .oneshot(request)
+        .await
+        .expect("failed to execute oneshot request");
+
+    let status = actual.status();
+    let body_bytes = to_bytes(actual.into_body(), usize::MAX)
+        .await
+        .expect("failed to convert body to bytes");
+
+    let body = serde_json::from_slice(&body_bytes).expect("failed to deserialize response body");
+    (status, body)
+}
@@ -0,0 +1,250 @@
+use axum::{
+    body::{to_bytes, Body},
+    http::{request::Builder, Method, StatusCode},
+};
+use mozart::{
+    app,
+    model::{
+        Parameter, ParameterType, Submission, TestCase, TestCaseFailureReason, TestCaseResult,
+        TestResult,
+    },
+    response::SubmissionResult,
+    state::AppState,
+};
+use tower::ServiceExt;
+
+#[tokio::test]
+async fn dry_run_pass() {
+    let mozart = app(AppState::default());
+    let solution = ["this is not valid in any language"].join("\n");
+    // the solution is never compiled nor executed
+    let test_cases = Box::new([TestCase {
+        id: 0,
+        input_parameters: Box::new([Parameter {
+            value_type: ParameterType::Int,
+            value: String::from("1"),
+        }]),
+        output_parameters: Box::new([Parameter {
+            value_type: ParameterType::Int,
+            value: String::from("1"),
+        }]),
+        stdin: None,
+        expected_error: None,
+        label: None,
+        hidden: false,
+    }]);
+    let submission = Submission {
+        solution,
+        test_cases,
+        unordered_lists: false,
+        files: Box::new([]),
+        checker: None,
+        reference_solution: None,
+        only_ids: None,
+        compiler_flags: Box::new([]),
+        debug: false,
+        float_tolerance: None,
+        toolchain_version: None,
+        metadata: None,
+    };
+    let body = serde_json::to_string(&submission).expect("failed to serialize submission");
+    let request = Builder::new()
+        .header("Content-Type", "application/json")
+        .method(Method::POST)
+        .uri("/submit?dryRun=true")
+        .body(Body::from(body))
+        .expect("failed to build request");
+    let expected_status = StatusCode::OK;
+    let expected_body = SubmissionResult::Pass;
+
+    let actual = mozart
+        .oneshot(request)
+        .await
+        .expect("failed to execute oneshot request");
+
+    let actual_status = actual.status();
+    let body_bytes = to_bytes(actual.into_body(), usize::MAX)
+        .await
+        .expect("failed to convert body to bytes");
+
+    let actual_body: SubmissionResult =
+        serde_json::from_slice(&body_bytes).expect("failed to deserialize response body");
+
+    assert_eq!(actual_status, expected_status);
+    assert_eq!(actual_body, expected_body);
+}
+
+#[tokio::test]
+async fn dry_run_failure() {
+    let mozart = app(AppState::default());
+    let solution = ["this is not valid in any language"].join("\n");
+    // the solution is never compiled nor executed
+    let test_cases = Box::new([TestCase {
+        id: 0,
+        input_parameters: Box::new([Parameter {
+            value_type: ParameterType::Int,
+            value: String::from("1"),
+        }]),
+        output_parameters: Box::new([Parameter {
+            value_type: ParameterType::Int,
+            value: String::from("2"),
+        }]),
+        stdin: None,
+        expected_error: None,
+        label: None,
+        hidden: false,
+    }]);
+    let submission = Submission {
+        solution,
+        test_cases,
+        unordered_lists: false,
+        files: Box::new([]),
+        checker: None,
+        reference_solution: None,
+        only_ids: None,
+        compiler_flags: Box::new([]),
+        debug: false,
+        float_tolerance: None,
+        toolchain_version: None,
+        metadata: None,
+    };
+    let body = serde_json::to_string(&submission).expect("failed to serialize submission");
+    let request = Builder::new()
+        .header("Content-Type", "application/json")
+        .method(Method::POST)
+        .uri("/submit?dryRun=true")
+        .body(Body::from(body))
+        .expect("failed to build request");
+    let expected_status = StatusCode::OK;
+    let expected_body = SubmissionResult::Failure(Box::new([TestCaseResult {
+        id: 0,
+        test_result: TestResult::Failure(TestCaseFailureReason::WrongAnswer {
+            input_parameters: Box::new([Parameter {
+                value_type: ParameterType::Int,
+                value: String::from("1"),
+            }]),
+            actual: String::from("1"),
+            expected: String::from("2"),
+            actual_value: None,
+            expected_value: None,
+        }),
+        label: None,
+    }]));
+
+    let actual = mozart
+        .oneshot(request)
+        .await
+        .expect("failed to execute oneshot request");
+
+    let actual_status = actual.status();
+    let body_bytes = to_bytes(actual.into_body(), usize::MAX)
+        .await
+        .expect("failed to convert body to bytes");
+
+    let actual_body: SubmissionResult =
+        serde_json::from_slice(&body_bytes).expect("failed to deserialize response body");
+
+    assert_eq!(actual_status, expected_status);
+    assert_eq!(actual_body, expected_body);
+}
+
+#[tokio::test]
+async fn dry_run_failures_first() {
+    let mozart = app(AppState::default());
+    let solution = ["this is not valid in any language"].join("\n");
+    // the solution is never compiled nor executed
+    let parameter = |value: &str| Parameter {
+        value_type: ParameterType::Int,
+        value: String::from(value),
+    };
+    let test_cases = Box::new([
+        TestCase {
+            id: 0,
+            input_parameters: Box::new([parameter("1")]),
+            output_parameters: Box::new([parameter("1")]),
+            stdin: None,
+            expected_error: None,
+            label: None,
+            hidden: false,
+        },
+        TestCase {
+            id: 1,
+            input_parameters: Box::new([parameter("1")]),
+            output_parameters: Box::new([parameter("2")]),
+            stdin: None,
+            expected_error: None,
+            label: None,
+            hidden: false,
+        },
+        TestCase {
+            id: 2,
+            input_parameters: Box::new([parameter("3")]),
+            output_parameters: Box::new([parameter("3")]),
+            stdin: None,
+            expected_error: None,
+            label: None,
+            hidden: false,
+        },
+    ]);
+    let submission = Submission {
+        solution,
+        test_cases,
+        unordered_lists: false,
+        files: Box::new([]),
+        checker: None,
+        reference_solution: None,
+        only_ids: None,
+        compiler_flags: Box::new([]),
+        debug: false,
+        float_tolerance: None,
+        toolchain_version: None,
+        metadata: None,
+    };
+    let body = serde_json::to_string(&submission).expect("failed to serialize submission");
+    let request = Builder::new()
+        .header("Content-Type", "application/json")
+        .method(Method::POST)
+        .uri("/submit?dryRun=true&order=failuresFirst")
+        .body(Body::from(body))
+        .expect("failed to build request");
+    let expected_status = StatusCode::OK;
+    let expected_body = SubmissionResult::Failure(Box::new([
+        TestCaseResult {
+            id: 1,
+            test_result: TestResult::Failure(TestCaseFailureReason::WrongAnswer {
+                input_parameters: Box::new([parameter("1")]),
+                actual: String::from("1"),
+                expected: String::from("2"),
+                actual_value: None,
+                expected_value: None,
+            }),
+            label: None,
+        },
+        TestCaseResult {
+            id: 0,
+            test_result: TestResult::Pass,
+            label: None,
+        },
+        TestCaseResult {
+            id: 2,
+            test_result: TestResult::Pass,
+            label: None,
+        },
+    ]));
+
+    let actual = mozart
+        .oneshot(request)
+        .await
+        .expect("failed to execute oneshot request");
+
+    let actual_status = actual.status();
+    let body_bytes = to_bytes(actual.into_body(), usize::MAX)
+        .await
+        .expect("failed to convert body to bytes");
+
+    let actual_body: SubmissionResult =
+        serde_json::from_slice(&body_bytes).expect("failed to deserialize response body");
+
+    assert_eq!(actual_status, expected_status);
+    assert_eq!(actual_body, expected_body);
+}
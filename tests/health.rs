@@ -0,0 +1,185 @@
+use axum::{
+    body::{to_bytes, Body},
+    http::{request::Builder, Method, Request, StatusCode},
+};
+use mozart::{
+    app,
+    config::Config,
+    model::{Health, Parameter, ParameterType, Submission, TestCase},
+    state::AppState,
+};
+use std::{sync::atomic::Ordering, time::Duration};
+use tokio::time::sleep;
+use tower::ServiceExt;
+
+#[cfg(feature = "cpp")]
+/// A solution that never returns, such that its submission stays in-flight until the test case times out.
+const NON_TERMINATING_SOLUTION: &str =
+    "long long solution(long long x) {\n    while (true) {\n        x = *(volatile long long*)&x;\n    }\n}\n";
+
+#[cfg(feature = "haskell")]
+/// A solution that never returns, such that its submission stays in-flight until the test case times out.
+const NON_TERMINATING_SOLUTION: &str =
+    "module Solution where\n\nsolution :: Int -> Int\nsolution x = solution x\n";
+
+#[cfg(feature = "python")]
+/// A solution that never returns, such that its submission stays in-flight until the test case times out.
+const NON_TERMINATING_SOLUTION: &str =
+    "def solution(x: int) -> int:\n    while True:\n        pass\n";
+
+#[cfg(feature = "kotlin")]
+/// A solution that never returns, such that its submission stays in-flight until the test case times out.
+const NON_TERMINATING_SOLUTION: &str =
+    "fun solution(x: Long): Long {\n    while (x == x) {}\n    return x\n}\n";
+
+/// Requests the health of mozart with the `state`.
+async fn health(state: AppState) -> Health {
+    let request = Builder::new()
+        .method(Method::GET)
+        .uri("/health")
+        .body(Body::empty())
+        .expect("failed to build request");
+
+    let actual = app(state)
+        .oneshot(request)
+        .await
+        .expect("failed to await oneshot");
+
+    assert_eq!(actual.status(), StatusCode::OK);
+    let body_bytes = to_bytes(actual.into_body(), usize::MAX)
+        .await
+        .expect("failed to convert body to bytes");
+
+    serde_json::from_slice(&body_bytes).expect("failed to deserialize response body")
+}
+
+#[tokio::test]
+async fn concurrency_limit() {
+    let actual = health(AppState::default()).await;
+
+    assert!(actual.concurrency_limit > 0);
+}
+
+#[tokio::test]
+async fn zero_concurrency_limit() {
+    let state = AppState::new(Config {
+        concurrency_limit: 0,
+        ..Config::default()
+    });
+
+    let actual = health(state).await;
+
+    assert_eq!(actual.concurrency_limit, 1);
+}
+
+#[tokio::test]
+async fn custom_state() {
+    let state = AppState::new(Config {
+        concurrency_limit: 7,
+        ..Config::default()
+    });
+    state.active_submissions.store(3, Ordering::Relaxed);
+    state.skipped_test_cases.store(5, Ordering::Relaxed);
+    let expected = Health {
+        active_submissions: 3,
+        concurrency_limit: 7,
+        skipped_test_cases: 5,
+    };
+
+    let actual = health(state).await;
+
+    assert_eq!(actual, expected);
+}
+
+/// A test util function to build a request of a submission that stays in-flight until its test case times out.
+fn non_terminating_request() -> Request<Body> {
+    let parameter = Parameter {
+        value_type: ParameterType::Int,
+        value: String::from("1"),
+    };
+    let submission = Submission {
+        solution: String::from(NON_TERMINATING_SOLUTION),
+        test_cases: Box::new([TestCase {
+            id: 0,
+            input_parameters: Box::new([parameter.clone()]),
+            output_parameters: Box::new([parameter]),
+            stdin: None,
+            expected_error: None,
+            label: None,
+            hidden: false,
+        }]),
+        unordered_lists: false,
+        files: Box::new([]),
+        checker: None,
+        reference_solution: None,
+        only_ids: None,
+        compiler_flags: Box::new([]),
+        debug: false,
+        float_tolerance: None,
+        toolchain_version: None,
+        metadata: None,
+    };
+    let body = serde_json::to_string(&submission).expect("failed to serialize submission");
+    Builder::new()
+        .header("Content-Type", "application/json")
+        .method(Method::POST)
+        .uri("/submit")
+        .body(Body::from(body))
+        .expect("failed to build request")
+}
+
+#[tokio::test]
+async fn in_flight_submission() {
+    let state = AppState::default();
+    let in_flight = tokio::spawn(app(state.clone()).oneshot(non_terminating_request()));
+
+    // the submission may not have reached the test runner yet, so it is polled for a while
+    let mut actual = health(state.clone()).await;
+    for _ in 0..50 {
+        if actual.active_submissions > 0 {
+            break;
+        }
+        sleep(Duration::from_millis(20)).await;
+        actual = health(state.clone()).await;
+    }
+
+    assert!(actual.active_submissions > 0);
+
+    in_flight
+        .await
+        .expect("failed to join submission")
+        .expect("failed to await oneshot");
+}
+
+#[tokio::test]
+async fn submissions_beyond_concurrency_limit_wait() {
+    let state = AppState::new(Config {
+        concurrency_limit: 1,
+        ..Config::default()
+    });
+    let in_flight = [
+        tokio::spawn(app(state.clone()).oneshot(non_terminating_request())),
+        tokio::spawn(app(state.clone()).oneshot(non_terminating_request())),
+    ];
+
+    let mut actual = health(state.clone()).await;
+    for _ in 0..50 {
+        if actual.active_submissions > 0 {
+            break;
+        }
+        sleep(Duration::from_millis(20)).await;
+        actual = health(state.clone()).await;
+    }
+    // the second submission would have started by now if it did not wait for the first one
+    sleep(Duration::from_millis(200)).await;
+    actual = health(state.clone()).await;
+
+    assert_eq!(actual.active_submissions, 1);
+
+    for handle in in_flight {
+        handle
+            .await
+            .expect("failed to join submission")
+            .expect("failed to await oneshot");
+    }
+}
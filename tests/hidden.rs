@@ -0,0 +1,152 @@
+use crate::common::{post_request, send, submission, test_case, SOLUTION};
+use axum::http::StatusCode;
+use mozart::{
+    config::Config,
+    model::{Submission, TestCase},
+    state::AppState,
+};
+use serde_json::{json, Value};
+
+#[cfg(feature = "cpp")]
+/// A solution like [`SOLUTION`] that prints its input.
+const PRINTING_SOLUTION: &str = "#include <iostream>\n\nlong long solution(long long x) {\n    std::cout << \"input: \" << x << std::endl;\n    return x + x;\n}\n";
+
+#[cfg(feature = "haskell")]
+/// A solution like [`SOLUTION`] that prints its input.
+const PRINTING_SOLUTION: &str = "module Solution where\n\nimport System.IO.Unsafe (unsafePerformIO)\n\nsolution :: Int -> Int\nsolution x = unsafePerformIO (putStrLn (\"input: \" ++ show x) >> return (x + x))\n";
+
+#[cfg(feature = "python")]
+/// A solution like [`SOLUTION`] that prints its input.
+const PRINTING_SOLUTION: &str =
+    "def solution(x: int) -> int:\n    print(\"input:\", x)\n    return x + x\n";
+
+#[cfg(feature = "kotlin")]
+/// A solution like [`SOLUTION`] that prints its input.
+const PRINTING_SOLUTION: &str =
+    "fun solution(x: Long): Long {\n    println(\"input: $x\")\n    return x + x\n}\n";
+
+/// Hides the `test_case`.
+fn hidden(test_case: TestCase) -> TestCase {
+    TestCase {
+        hidden: true,
+        ..test_case
+    }
+}
+
+/// Posts a submission of [`SOLUTION`] with the `test_cases` to `/submit`, and responds with the body as JSON.
+async fn submit(test_cases: Box<[TestCase]>) -> Value {
+    submit_with(AppState::default(), SOLUTION, test_cases, false).await
+}
+
+/// Posts a submission of the `solution` with the `test_cases` to `/submit` of a router with the `state`,
+/// which requests the generated source if `debug` is set, and responds with the body as JSON.
+async fn submit_with(
+    state: AppState,
+    solution: &str,
+    test_cases: Box<[TestCase]>,
+    debug: bool,
+) -> Value {
+    let submission = Submission {
+        debug,
+        ..submission(solution, test_cases)
+    };
+
+    let (actual_status, actual_body) = send(state, post_request("/submit", &submission)).await;
+
+    assert_eq!(actual_status, StatusCode::OK);
+    actual_body
+}
+
+#[tokio::test]
+async fn hidden_failure_omits_details() {
+    let actual = submit(Box::new([hidden(test_case(0, "2", "5"))])).await;
+
+    assert_eq!(actual["result"], "failure");
+    assert_eq!(
+        actual["testCaseResults"][0],
+        json!({ "id": 0, "testResult": "failure", "cause": "hidden" })
+    );
+    let body = actual.to_string();
+    assert!(!body.contains("actual"));
+    assert!(!body.contains("expected"));
+    assert!(!body.contains("inputParameters"));
+}
+
+#[tokio::test]
+async fn visible_and_hidden_test_cases() {
+    let actual = submit(Box::new([
+        test_case(0, "2", "5"),
+        hidden(test_case(1, "3", "7")),
+        hidden(test_case(2, "4", "8")),
+    ]))
+    .await;
+
+    assert_eq!(actual["result"], "failure");
+    let results = &actual["testCaseResults"];
+    assert_eq!(results[0]["cause"], "wrongAnswer");
+    assert_eq!(results[0]["details"]["actual"], "4");
+    assert_eq!(results[0]["details"]["expected"], "5");
+    assert_eq!(
+        results[1],
+        json!({ "id": 1, "testResult": "failure", "cause": "hidden" })
+    );
+    assert_eq!(results[2], json!({ "id": 2, "testResult": "pass" }));
+}
+
+#[tokio::test]
+async fn hidden_pass() {
+    let actual = submit(Box::new([hidden(test_case(0, "2", "4"))])).await;
+
+    assert_eq!(actual["result"], "pass");
+}
+
+#[tokio::test]
+async fn hidden_input_not_printed() {
+    let actual = submit_with(
+        AppState::default(),
+        PRINTING_SOLUTION,
+        Box::new([hidden(test_case(0, "1234", "0"))]),
+        false,
+    )
+    .await;
+
+    assert_eq!(actual["result"], "failure");
+    assert!(actual.get("stdout").is_none());
+    assert!(!actual.to_string().contains("1234"));
+}
+
+#[tokio::test]
+async fn visible_input_printed() {
+    let actual = submit_with(
+        AppState::default(),
+        PRINTING_SOLUTION,
+        Box::new([test_case(0, "1234", "2468")]),
+        false,
+    )
+    .await;
+
+    assert_eq!(actual["result"], "pass");
+    assert!(actual["stdout"]
+        .as_str()
+        .is_some_and(|stdout| stdout.contains("1234")));
+}
+
+#[tokio::test]
+async fn hidden_without_generated_source() {
+    let state = AppState::new(Config {
+        allow_debug: true,
+        ..Config::default()
+    });
+
+    let actual = submit_with(
+        state,
+        SOLUTION,
+        Box::new([hidden(test_case(0, "2", "98765"))]),
+        true,
+    )
+    .await;
+
+    assert_eq!(actual["result"], "failure");
+    assert!(actual.get("generatedSource").is_none());
+    assert!(!actual.to_string().contains("98765"));
+}
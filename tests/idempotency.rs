@@ -0,0 +1,212 @@
+use axum::{
+    body::{to_bytes, Body, Bytes},
+    http::{request::Builder, Method, StatusCode},
+};
+use mozart::{
+    app,
+    config::Config,
+    model::{Parameter, ParameterType, Submission, TestCase},
+    response::{ErrorCode, SubmissionResult},
+    state::AppState,
+    IDEMPOTENCY_KEY_HEADER,
+};
+use serde_json::Value;
+use tower::ServiceExt;
+use uuid::Uuid;
+
+#[cfg(feature = "cpp")]
+/// A solution that returns a different value every time it runs, such that rerunning it changes the result.
+const NONDETERMINISTIC_SOLUTION: &str = "#include <chrono>\n\nlong long solution(long long x) {\n    return x + std::chrono::steady_clock::now().time_since_epoch().count();\n}\n";
+
+#[cfg(feature = "haskell")]
+/// A solution that returns a different value every time it runs, such that rerunning it changes the result.
+const NONDETERMINISTIC_SOLUTION: &str = "module Solution where\n\nimport GHC.Clock (getMonotonicTimeNSec)\nimport System.IO.Unsafe (unsafePerformIO)\n\nsolution :: Int -> Int\nsolution x = x + fromIntegral (unsafePerformIO getMonotonicTimeNSec)\n";
+
+#[cfg(feature = "python")]
+/// A solution that returns a different value every time it runs, such that rerunning it changes the result.
+const NONDETERMINISTIC_SOLUTION: &str =
+    "import time\n\ndef solution(x: int) -> int:\n    return x + time.perf_counter_ns()\n";
+
+#[cfg(feature = "kotlin")]
+/// A solution that returns a different value every time it runs, such that rerunning it changes the result.
+const NONDETERMINISTIC_SOLUTION: &str = "fun solution(x: Long): Long = x + System.nanoTime()\n";
+
+/// Makes a submission of the [`NONDETERMINISTIC_SOLUTION`], whose single test case fails with a different actual
+/// value every time it is checked.
+fn nondeterministic_submission() -> Submission {
+    let parameter = Parameter {
+        value_type: ParameterType::Int,
+        value: String::from("0"),
+    };
+    Submission {
+        solution: String::from(NONDETERMINISTIC_SOLUTION),
+        test_cases: Box::new([TestCase {
+            id: 0,
+            input_parameters: Box::new([parameter.clone()]),
+            output_parameters: Box::new([parameter]),
+            stdin: None,
+            expected_error: None,
+            label: None,
+            hidden: false,
+        }]),
+        unordered_lists: false,
+        files: Box::new([]),
+        checker: None,
+        reference_solution: None,
+        only_ids: None,
+        compiler_flags: Box::new([]),
+        debug: false,
+        float_tolerance: None,
+        toolchain_version: None,
+        metadata: None,
+    }
+}
+
+/// Submits the `submission` with the idempotency `key` to the `uri` of mozart with the `state`, responding with the
+/// status code and the body of the response.
+async fn submit_raw(
+    state: &AppState,
+    uri: &str,
+    submission: &Submission,
+    key: &str,
+) -> (StatusCode, Bytes) {
+    let body = serde_json::to_string(submission).expect("failed to serialize submission");
+    let request = Builder::new()
+        .header("Content-Type", "application/json")
+        .header(IDEMPOTENCY_KEY_HEADER, key)
+        .method(Method::POST)
+        .uri(uri)
+        .body(Body::from(body))
+        .expect("failed to build request");
+
+    let actual = app(state.clone())
+        .oneshot(request)
+        .await
+        .expect("failed to await oneshot");
+
+    let status = actual.status();
+    let body_bytes = to_bytes(actual.into_body(), usize::MAX)
+        .await
+        .expect("failed to convert body to bytes");
+
+    (status, body_bytes)
+}
+
+/// Submits the `submission` with the idempotency `key` to mozart with the `state`.
+async fn submit(state: &AppState, submission: &Submission, key: &str) -> SubmissionResult {
+    let (status, body_bytes) = submit_raw(state, "/submit", submission, key).await;
+
+    assert_eq!(status, StatusCode::OK);
+    serde_json::from_slice(&body_bytes).expect("failed to deserialize response body")
+}
+
+#[tokio::test]
+async fn same_key_checked_once() {
+    let key = Uuid::new_v4().to_string();
+    let submission = nondeterministic_submission();
+
+    let state = AppState::default();
+
+    let first = submit(&state, &submission, &key).await;
+    let second = submit(&state, &submission, &key).await;
+
+    // a rerun of the solution would have produced another actual value
+    assert!(matches!(first, SubmissionResult::Failure(_)));
+    assert_eq!(first, second);
+}
+
+#[tokio::test]
+async fn same_key_in_separate_states() {
+    let key = Uuid::new_v4().to_string();
+    let submission = nondeterministic_submission();
+    let other_submission = Submission {
+        solution: format!("{NONDETERMINISTIC_SOLUTION}\n"),
+        ..submission.clone()
+    };
+
+    let first = submit(&AppState::default(), &submission, &key).await;
+    let (second_status, _) =
+        submit_raw(&AppState::default(), "/submit", &other_submission, &key).await;
+
+    // the keys are part of the state, so the key is not bound to the first submission in another state
+    assert!(matches!(first, SubmissionResult::Failure(_)));
+    assert_eq!(second_status, StatusCode::OK);
+}
+
+#[tokio::test]
+async fn same_key_keeps_generated_source() {
+    let key = Uuid::new_v4().to_string();
+    let submission = Submission {
+        debug: true,
+        ..nondeterministic_submission()
+    };
+
+    let state = AppState::new(Config {
+        allow_debug: true,
+        ..Config::default()
+    });
+
+    let (first_status, first_bytes) = submit_raw(&state, "/submit", &submission, &key).await;
+    let (second_status, second_bytes) = submit_raw(&state, "/submit", &submission, &key).await;
+    let first: Value =
+        serde_json::from_slice(&first_bytes).expect("failed to deserialize response body");
+    let second: Value =
+        serde_json::from_slice(&second_bytes).expect("failed to deserialize response body");
+
+    assert_eq!(first_status, StatusCode::OK);
+    assert_eq!(second_status, StatusCode::OK);
+    assert!(first["generatedSource"].is_string());
+    assert_eq!(first, second);
+}
+
+#[tokio::test]
+async fn same_key_different_submission() {
+    let key = Uuid::new_v4().to_string();
+    let submission = nondeterministic_submission();
+    let other_submission = Submission {
+        solution: format!("{NONDETERMINISTIC_SOLUTION}\n"),
+        ..submission.clone()
+    };
+
+    let state = AppState::default();
+
+    let first = submit(&state, &submission, &key).await;
+    let (second_status, second_bytes) =
+        submit_raw(&state, "/submit", &other_submission, &key).await;
+    let second: SubmissionResult =
+        serde_json::from_slice(&second_bytes).expect("failed to deserialize response body");
+
+    assert!(matches!(first, SubmissionResult::Failure(_)));
+    assert_eq!(second_status, StatusCode::UNPROCESSABLE_ENTITY);
+    assert!(matches!(
+        second,
+        SubmissionResult::Error {
+            code: ErrorCode::InvalidRequest,
+            ..
+        }
+    ));
+}
+
+#[tokio::test]
+async fn same_key_different_options() {
+    let key = Uuid::new_v4().to_string();
+    let submission = nondeterministic_submission();
+
+    let state = AppState::default();
+
+    let (first_status, _) = submit_raw(&state, "/submit?dryRun=true", &submission, &key).await;
+    let (second_status, second_bytes) = submit_raw(&state, "/submit", &submission, &key).await;
+    let second: SubmissionResult =
+        serde_json::from_slice(&second_bytes).expect("failed to deserialize response body");
+
+    // the dry run is responded to differently, so its response cannot be reused for the actual check
+    assert_eq!(first_status, StatusCode::OK);
+    assert_eq!(second_status, StatusCode::UNPROCESSABLE_ENTITY);
+    assert!(matches!(
+        second,
+        SubmissionResult::Error {
+            code: ErrorCode::InvalidRequest,
+            ..
+        }
+    ));
+}
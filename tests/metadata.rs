@@ -0,0 +1,77 @@
+use crate::common::{post_request, send, submission, test_case, SOLUTION};
+use axum::http::StatusCode;
+use mozart::{model::Submission, state::AppState};
+use serde::Serialize;
+use serde_json::{json, Value};
+
+/// Creates a submission of [`SOLUTION`] with the `metadata`, whose single test case expects `2` to become the `output`.
+fn submission_with(output: &str, metadata: Option<Value>) -> Submission {
+    Submission {
+        metadata,
+        ..submission(SOLUTION, Box::new([test_case(0, "2", output)]))
+    }
+}
+
+/// Posts the `body` as JSON to the `uri`, and responds with the status code and the body as JSON.
+async fn post(uri: &str, body: &impl Serialize) -> (StatusCode, Value) {
+    send(AppState::default(), post_request(uri, body)).await
+}
+
+/// Arbitrary metadata, including values that must not be interpreted, e.g. a nested object and a `null`.
+fn metadata() -> Value {
+    json!({
+        "studentId": "s123456",
+        "attempt": 3,
+        "tags": ["week 1", { "nested": true }],
+        "comment": null,
+    })
+}
+
+#[tokio::test]
+async fn metadata_with_pass() {
+    let body = submission_with("4", Some(metadata()));
+
+    let (actual_status, actual_body) = post("/submit", &body).await;
+
+    assert_eq!(actual_status, StatusCode::OK);
+    assert_eq!(actual_body["result"], "pass");
+    assert_eq!(actual_body["metadata"], metadata());
+}
+
+#[tokio::test]
+async fn metadata_with_failure() {
+    let body = submission_with("5", Some(metadata()));
+
+    let (actual_status, actual_body) = post("/submit", &body).await;
+
+    assert_eq!(actual_status, StatusCode::OK);
+    assert_eq!(actual_body["result"], "failure");
+    assert_eq!(actual_body["metadata"], metadata());
+}
+
+#[tokio::test]
+async fn without_metadata() {
+    let body = submission_with("4", None);
+
+    let (actual_status, actual_body) = post("/submit", &body).await;
+
+    assert_eq!(actual_status, StatusCode::OK);
+    assert!(actual_body.get("metadata").is_none());
+}
+
+#[tokio::test]
+async fn metadata_in_batch() {
+    let body = [
+        submission_with("4", Some(json!({ "attempt": 1 }))),
+        submission_with("5", None),
+        submission_with("5", Some(json!("opaque"))),
+    ];
+
+    let (actual_status, actual_body) = post("/submit/batch", &body).await;
+
+    assert_eq!(actual_status, StatusCode::OK);
+    assert_eq!(actual_body[0]["metadata"], json!({ "attempt": 1 }));
+    assert!(actual_body[1].get("metadata").is_none());
+    assert_eq!(actual_body[2]["result"], "failure");
+    assert_eq!(actual_body[2]["metadata"], "opaque");
+}
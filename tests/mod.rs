@@ -1,2 +1,23 @@
+mod auth;
+mod capabilities;
+mod client;
+mod common;
+mod dry_run;
+mod health;
+mod hidden;
+mod idempotency;
+mod metadata;
+#[cfg(feature = "no-sandbox")]
+mod no_sandbox;
+mod openapi;
+mod quick;
+mod raw;
+mod ready;
+mod request_timeout;
+mod selftest;
+mod signature;
+mod spans;
 mod status;
 mod submit;
+mod warmup;
+mod workdir;
@@ -0,0 +1,19 @@
+use crate::common::{post_request, send, submission, test_case, SOLUTION};
+use axum::http::StatusCode;
+use mozart::{response::SubmissionResult, state::AppState};
+
+#[tokio::test]
+async fn unjailed_submission() {
+    let request = post_request(
+        "/submit",
+        &submission(SOLUTION, Box::new([test_case(0, "2", "4")])),
+    );
+    let expected_status = StatusCode::OK;
+    let expected_body = SubmissionResult::Pass;
+
+    let (actual_status, actual_body): (_, SubmissionResult) =
+        send(AppState::default(), request).await;
+
+    assert_eq!(actual_status, expected_status);
+    assert_eq!(actual_body, expected_body);
+}
@@ -0,0 +1,38 @@
+use axum::{
+    body::{to_bytes, Body},
+    http::{request::Builder, Method, StatusCode},
+};
+use mozart::{app, state::AppState};
+use serde_json::Value;
+use tower::ServiceExt;
+
+#[tokio::test]
+async fn result_discriminator() {
+    let mozart = app(AppState::default());
+    let request = Builder::new()
+        .method(Method::GET)
+        .uri("/openapi.json")
+        .body(Body::empty())
+        .expect("failed to build request");
+    let expected_status_code = StatusCode::OK;
+
+    let actual = mozart
+        .oneshot(request)
+        .await
+        .expect("failed to await oneshot");
+
+    let actual_status_code = actual.status();
+    let body_bytes = to_bytes(actual.into_body(), usize::MAX)
+        .await
+        .expect("failed to convert body to bytes");
+    let actual_body: Value =
+        serde_json::from_slice(&body_bytes).expect("failed to deserialize response body");
+    let submission_result = &actual_body["components"]["schemas"]["SubmissionResult"];
+
+    assert_eq!(actual_status_code, expected_status_code);
+    assert!(actual_body["paths"]["/submit"]["post"].is_object());
+    assert!(submission_result["properties"]["result"].is_object());
+    assert!(submission_result["required"]
+        .as_array()
+        .is_some_and(|required| required.contains(&Value::from("result"))));
+}
@@ -0,0 +1,61 @@
+use crate::common::{post_request, send, submission, test_case, SOLUTION};
+use axum::http::StatusCode;
+use mozart::{config::Config, model::TestCase, response::QuickResult, state::AppState};
+use std::sync::atomic::Ordering;
+
+/// Submits the `test_cases` against [`SOLUTION`] to `/submit/quick` with the `state`.
+async fn submit_quick(state: AppState, test_cases: Box<[TestCase]>) -> QuickResult {
+    let request = post_request("/submit/quick", &submission(SOLUTION, test_cases));
+
+    let (actual_status, actual_body) = send(state, request).await;
+
+    assert_eq!(actual_status, StatusCode::OK);
+    actual_body
+}
+
+#[tokio::test]
+async fn all_pass() {
+    let state = AppState::default();
+    let test_cases = Box::new([test_case(0, "2", "4"), test_case(1, "5", "10")]);
+    let expected = QuickResult { passed: true };
+
+    let actual = submit_quick(state.clone(), test_cases).await;
+
+    assert_eq!(actual, expected);
+    assert_eq!(state.skipped_test_cases.load(Ordering::Relaxed), 0);
+}
+
+#[tokio::test]
+async fn early_fail() {
+    let state = AppState::default();
+    let test_cases = Box::new([
+        test_case(0, "2", "5"),
+        test_case(1, "5", "10"),
+        test_case(2, "7", "14"),
+    ]);
+    let expected = QuickResult { passed: false };
+
+    let actual = submit_quick(state.clone(), test_cases).await;
+
+    assert_eq!(actual, expected);
+    assert_eq!(state.skipped_test_cases.load(Ordering::Relaxed), 2);
+}
+
+#[tokio::test]
+async fn early_fail_without_fail_fast() {
+    let state = AppState::new(Config {
+        quick_fail_fast: false,
+        ..Config::default()
+    });
+    let test_cases = Box::new([
+        test_case(0, "2", "5"),
+        test_case(1, "5", "10"),
+        test_case(2, "7", "14"),
+    ]);
+    let expected = QuickResult { passed: false };
+
+    let actual = submit_quick(state.clone(), test_cases).await;
+
+    assert_eq!(actual, expected);
+    assert_eq!(state.skipped_test_cases.load(Ordering::Relaxed), 0);
+}
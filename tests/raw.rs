@@ -0,0 +1,98 @@
+use crate::common::{send, test_case, SOLUTION};
+use axum::{
+    body::Body,
+    http::{request::Builder, Method, StatusCode},
+};
+use mozart::{
+    model::{Parameter, ParameterType, TestCaseFailureReason, TestCaseResult, TestResult},
+    response::{ErrorCode, SubmissionResult},
+    state::AppState,
+    TEST_CASES_HEADER,
+};
+
+/// Posts [`SOLUTION`] as the raw body to `/submit/raw`, with the `test_cases` header if it is supplied.
+async fn submit_raw(test_cases: Option<&str>) -> (StatusCode, SubmissionResult) {
+    let mut builder = Builder::new()
+        .header("Content-Type", "text/plain")
+        .method(Method::POST)
+        .uri("/submit/raw");
+    if let Some(test_cases) = test_cases {
+        builder = builder.header(TEST_CASES_HEADER, test_cases);
+    }
+    let request = builder
+        .body(Body::from(SOLUTION))
+        .expect("failed to build request");
+
+    send(AppState::default(), request).await
+}
+
+#[tokio::test]
+async fn raw_solution() {
+    let test_cases = serde_json::to_string(&[test_case(0, "2", "4"), test_case(1, "5", "10")])
+        .expect("failed to serialize test cases");
+    let expected_status = StatusCode::OK;
+    let expected_body = SubmissionResult::Pass;
+
+    let (actual_status, actual_body) = submit_raw(Some(&test_cases)).await;
+
+    assert_eq!(actual_status, expected_status);
+    assert_eq!(actual_body, expected_body);
+}
+
+#[tokio::test]
+async fn raw_solution_wrong_answer() {
+    let test_cases = serde_json::to_string(&[test_case(0, "2", "4"), test_case(1, "5", "11")])
+        .expect("failed to serialize test cases");
+    let expected_status = StatusCode::OK;
+    let expected_body = SubmissionResult::Failure(Box::new([
+        TestCaseResult {
+            id: 0,
+            test_result: TestResult::Pass,
+            label: None,
+        },
+        TestCaseResult {
+            id: 1,
+            test_result: TestResult::Failure(TestCaseFailureReason::WrongAnswer {
+                input_parameters: Box::new([Parameter {
+                    value_type: ParameterType::Int,
+                    value: String::from("5"),
+                }]),
+                actual: String::from("10"),
+                expected: String::from("11"),
+                actual_value: None,
+                expected_value: None,
+            }),
+            label: None,
+        },
+    ]));
+
+    let (actual_status, actual_body) = submit_raw(Some(&test_cases)).await;
+
+    assert_eq!(actual_status, expected_status);
+    assert_eq!(actual_body, expected_body);
+}
+
+#[tokio::test]
+async fn raw_solution_without_test_cases() {
+    let expected_status = StatusCode::OK;
+    let expected_body = SubmissionResult::Pass;
+
+    let (actual_status, actual_body) = submit_raw(None).await;
+
+    assert_eq!(actual_status, expected_status);
+    assert_eq!(actual_body, expected_body);
+}
+
+#[tokio::test]
+async fn raw_solution_invalid_test_cases() {
+    let expected_status = StatusCode::BAD_REQUEST;
+
+    let (actual_status, actual_body) = submit_raw(Some(r#"[{"id": 0}]"#)).await;
+
+    assert_eq!(actual_status, expected_status);
+    let SubmissionResult::Error { code, message } = actual_body else {
+        panic!("response body was not of error variant");
+    };
+    assert_eq!(code, ErrorCode::InvalidRequest);
+    assert!(message.starts_with(&format!("Failed to parse the {TEST_CASES_HEADER} header")));
+}
@@ -0,0 +1,25 @@
+use axum::{
+    body::{Body, HttpBody},
+    http::{request::Builder, Method, StatusCode},
+};
+use mozart::{app, state::AppState};
+use tower::ServiceExt;
+
+#[tokio::test]
+async fn installed_toolchain() {
+    let mozart = app(AppState::default());
+    let expected_status_code = StatusCode::OK;
+    let request = Builder::new()
+        .method(Method::GET)
+        .uri("/ready")
+        .body(Body::empty())
+        .expect("failed to build request");
+
+    let actual = mozart
+        .oneshot(request)
+        .await
+        .expect("failed to await oneshot");
+
+    assert_eq!(actual.status(), expected_status_code);
+    assert!(actual.body().is_end_stream());
+}
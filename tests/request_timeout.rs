@@ -0,0 +1,98 @@
+use axum::{
+    body::Body,
+    http::{request::Builder, Method, StatusCode},
+};
+use mozart::{
+    app,
+    config::Config,
+    model::{Parameter, ParameterType, Submission, TestCase},
+    state::AppState,
+};
+use std::time::Duration;
+use tower::ServiceExt;
+
+#[cfg(feature = "cpp")]
+const SLOW_SOLUTION: &str = "#include <chrono>\n#include <thread>\n\nlong long solution(long long x) {\n    std::this_thread::sleep_for(std::chrono::milliseconds(500));\n    return x;\n}\n";
+
+#[cfg(feature = "haskell")]
+const SLOW_SOLUTION: &str = "module Solution where\n\nimport Control.Concurrent\nimport System.IO.Unsafe\n\nsolution :: Int -> Int\nsolution x = unsafePerformIO (threadDelay 500000 >> return x)\n";
+
+#[cfg(feature = "python")]
+const SLOW_SOLUTION: &str =
+    "import time\n\ndef solution(x: int) -> int:\n    time.sleep(0.5)\n    return x\n";
+
+#[cfg(feature = "kotlin")]
+const SLOW_SOLUTION: &str =
+    "fun solution(x: Long): Long {\n    Thread.sleep(500)\n    return x\n}\n";
+
+#[tokio::test]
+async fn request_exceeds_timeout() {
+    let mozart = app(AppState::new(Config {
+        request_timeout: Duration::from_millis(100),
+        ..Config::default()
+    }));
+    let submission = Submission {
+        solution: String::from(SLOW_SOLUTION),
+        test_cases: Box::new([TestCase {
+            id: 0,
+            input_parameters: Box::new([Parameter {
+                value_type: ParameterType::Int,
+                value: String::from("2"),
+            }]),
+            output_parameters: Box::new([Parameter {
+                value_type: ParameterType::Int,
+                value: String::from("2"),
+            }]),
+            stdin: None,
+            expected_error: None,
+            label: None,
+            hidden: false,
+        }]),
+        unordered_lists: false,
+        files: Box::new([]),
+        checker: None,
+        reference_solution: None,
+        only_ids: None,
+        compiler_flags: Box::new([]),
+        debug: false,
+        float_tolerance: None,
+        toolchain_version: None,
+        metadata: None,
+    };
+    let body = serde_json::to_string(&submission).expect("failed to serialize submission");
+    let request = Builder::new()
+        .header("Content-Type", "application/json")
+        .method(Method::POST)
+        .uri("/submit")
+        .body(Body::from(body))
+        .expect("failed to build request");
+    let expected_status = StatusCode::GATEWAY_TIMEOUT;
+
+    let actual = mozart
+        .oneshot(request)
+        .await
+        .expect("failed to execute oneshot request");
+
+    assert_eq!(actual.status(), expected_status);
+}
+
+#[tokio::test]
+async fn request_within_timeout() {
+    let mozart = app(AppState::new(Config {
+        request_timeout: Duration::from_millis(100),
+        ..Config::default()
+    }));
+    let request = Builder::new()
+        .method(Method::GET)
+        .uri("/status")
+        .body(Body::empty())
+        .expect("failed to build request");
+    let expected_status = StatusCode::OK;
+
+    let actual = mozart
+        .oneshot(request)
+        .await
+        .expect("failed to execute oneshot request");
+
+    assert_eq!(actual.status(), expected_status);
+}
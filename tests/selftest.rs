@@ -0,0 +1,28 @@
+use axum::{
+    body::{to_bytes, Body},
+    http::{request::Builder, Method, StatusCode},
+};
+use mozart::{app, model::SelfTest, state::AppState};
+use tower::ServiceExt;
+
+#[tokio::test]
+async fn selftest_passes() {
+    let request = Builder::new()
+        .method(Method::GET)
+        .uri("/selftest")
+        .body(Body::empty())
+        .expect("failed to build request");
+
+    let actual = app(AppState::default())
+        .oneshot(request)
+        .await
+        .expect("failed to await oneshot");
+
+    assert_eq!(actual.status(), StatusCode::OK);
+    let body_bytes = to_bytes(actual.into_body(), usize::MAX)
+        .await
+        .expect("failed to convert body to bytes");
+    let actual_body: SelfTest =
+        serde_json::from_slice(&body_bytes).expect("failed to deserialize response body");
+    assert!(actual_body.passed);
+}
@@ -0,0 +1,91 @@
+use crate::common::{post_request, submission, test_case, SOLUTION};
+use axum::{
+    body::{to_bytes, Body},
+    http::{Response, StatusCode},
+};
+use mozart::{app, config::Config, model::Submission, state::AppState, SIGNATURE_HEADER};
+use ring::hmac;
+use serde::Serialize;
+use tower::ServiceExt;
+
+/// Creates a submission of [`SOLUTION`] with a test case it passes.
+fn passing_submission() -> Submission {
+    submission(SOLUTION, Box::new([test_case(0, "2", "4")]))
+}
+
+/// Posts the `body` as JSON to the `uri` of a router configured with the `signing_key`.
+async fn post(uri: &str, body: &impl Serialize, signing_key: Option<&str>) -> Response<Body> {
+    let mozart = app(AppState::new(Config {
+        signing_key: signing_key.map(String::from),
+        ..Config::default()
+    }));
+
+    mozart
+        .oneshot(post_request(uri, body))
+        .await
+        .expect("failed to execute oneshot request")
+}
+
+/// Posts [`SOLUTION`] to `/submit` of a router configured with the `signing_key`.
+async fn submit(signing_key: Option<&str>) -> Response<Body> {
+    post("/submit", &passing_submission(), signing_key).await
+}
+
+/// Asserts that the `response` is signed with the `key`, i.e. that its signature matches its body.
+async fn assert_signed(response: Response<Body>, key: &str) {
+    assert_eq!(response.status(), StatusCode::OK);
+    let signature = response
+        .headers()
+        .get(SIGNATURE_HEADER)
+        .expect("response was not signed")
+        .to_str()
+        .expect("signature was not ascii")
+        .to_string();
+    let body = to_bytes(response.into_body(), usize::MAX)
+        .await
+        .expect("failed to convert body to bytes");
+    let signature = (0..signature.len())
+        .step_by(2)
+        .map(|i| u8::from_str_radix(&signature[i..i + 2], 16))
+        .collect::<Result<Vec<_>, _>>()
+        .expect("signature was not hexadecimal");
+    let key = hmac::Key::new(hmac::HMAC_SHA256, key.as_bytes());
+    assert!(hmac::verify(&key, &body, &signature).is_ok());
+}
+
+#[tokio::test]
+async fn signature_matches_body() {
+    let key = "correct horse battery staple";
+
+    let actual = submit(Some(key)).await;
+
+    assert_signed(actual, key).await;
+}
+
+#[tokio::test]
+async fn batch_signed() {
+    let key = "correct horse battery staple";
+    let body = [passing_submission(), passing_submission()];
+
+    let actual = post("/submit/batch", &body, Some(key)).await;
+
+    assert_signed(actual, key).await;
+}
+
+#[tokio::test]
+async fn quick_signed() {
+    let key = "correct horse battery staple";
+    let body = passing_submission();
+
+    let actual = post("/submit/quick", &body, Some(key)).await;
+
+    assert_signed(actual, key).await;
+}
+
+#[tokio::test]
+async fn unsigned_without_key() {
+    let actual = submit(None).await;
+
+    assert_eq!(actual.status(), StatusCode::OK);
+    assert!(actual.headers().get(SIGNATURE_HEADER).is_none());
+}
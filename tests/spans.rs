@@ -0,0 +1,115 @@
+use crate::common::{post_request, submission, test_case, SOLUTION};
+use axum::http::StatusCode;
+use mozart::{app, state::AppState};
+use std::{
+    fmt::Debug,
+    sync::{Arc, Mutex},
+};
+use tower::ServiceExt;
+use tracing::{
+    field::{Field, Visit},
+    span::{Attributes, Id, Record},
+    Subscriber,
+};
+use tracing_subscriber::{layer::Context, prelude::*, registry::LookupSpan, Layer};
+
+/// A span that was emitted while checking a submission.
+#[derive(Debug, Clone)]
+struct CapturedSpan {
+    /// The name of the span.
+    name: &'static str,
+
+    /// Whether the parent of the span has a `request_id` field, i.e. whether it is the span of the request.
+    child_of_request: bool,
+
+    /// The recorded `elapsed_ms` field of the span.
+    elapsed_ms: Option<u64>,
+}
+
+/// A layer that captures the spans that are emitted, and records their `elapsed_ms` field.
+#[derive(Clone, Default)]
+struct CaptureLayer {
+    spans: Arc<Mutex<Vec<CapturedSpan>>>,
+}
+
+/// The index of a span in the captured spans, which is stored in the extensions of the span.
+struct CaptureIndex(usize);
+
+/// A visitor that extracts the `elapsed_ms` field of a span.
+struct ElapsedVisitor(Option<u64>);
+
+impl Visit for ElapsedVisitor {
+    fn record_u64(&mut self, field: &Field, value: u64) {
+        if field.name() == "elapsed_ms" {
+            self.0 = Some(value);
+        }
+    }
+
+    fn record_debug(&mut self, _: &Field, _: &dyn Debug) {}
+}
+
+impl<S> Layer<S> for CaptureLayer
+where
+    S: Subscriber + for<'a> LookupSpan<'a>,
+{
+    fn on_new_span(&self, attrs: &Attributes<'_>, id: &Id, ctx: Context<'_, S>) {
+        let span = ctx.span(id).expect("span should exist");
+        let child_of_request = span
+            .parent()
+            .is_some_and(|parent| parent.fields().field("request_id").is_some());
+
+        let mut spans = self.spans.lock().expect("lock should not be poisoned");
+        spans.push(CapturedSpan {
+            name: attrs.metadata().name(),
+            child_of_request,
+            elapsed_ms: None,
+        });
+        span.extensions_mut().insert(CaptureIndex(spans.len() - 1));
+    }
+
+    fn on_record(&self, id: &Id, values: &Record<'_>, ctx: Context<'_, S>) {
+        let span = ctx.span(id).expect("span should exist");
+        let mut visitor = ElapsedVisitor(None);
+        values.record(&mut visitor);
+
+        let index = span.extensions().get::<CaptureIndex>().map(|index| index.0);
+        if let (Some(elapsed_ms), Some(index)) = (visitor.0, index) {
+            self.spans.lock().expect("lock should not be poisoned")[index].elapsed_ms =
+                Some(elapsed_ms);
+        }
+    }
+}
+
+#[tokio::test]
+async fn compile_and_execute_spans() {
+    let layer = CaptureLayer::default();
+    let _guard =
+        tracing::subscriber::set_default(tracing_subscriber::registry().with(layer.clone()));
+    let mozart = app(AppState::default());
+    let request = post_request(
+        "/submit",
+        &submission(SOLUTION, Box::new([test_case(0, "2", "4")])),
+    );
+
+    let actual = mozart
+        .oneshot(request)
+        .await
+        .expect("failed to execute oneshot request");
+
+    assert_eq!(actual.status(), StatusCode::OK);
+    let spans = layer.spans.lock().expect("lock should not be poisoned");
+    for name in ["compile", "execute"] {
+        let span = spans
+            .iter()
+            .find(|span| span.name == name)
+            .unwrap_or_else(|| panic!("no {name} span was emitted"));
+        assert!(
+            span.child_of_request,
+            "{name} span is not a child of the request span"
+        );
+        assert!(
+            span.elapsed_ms.is_some(),
+            "{name} span did not record its elapsed time"
+        );
+    }
+}
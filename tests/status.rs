@@ -2,12 +2,12 @@ use axum::{
     body::{Body, HttpBody},
     http::{request::Builder, Method, StatusCode},
 };
-use mozart::app;
+use mozart::{app, state::AppState};
 use tower::ServiceExt;
 
 #[tokio::test]
 async fn invalid_http_method() {
-    let mozart = app();
+    let mozart = app(AppState::default());
     let expected_status_code = StatusCode::METHOD_NOT_ALLOWED;
     let request = Builder::new()
         .method(Method::POST)
@@ -25,7 +25,7 @@ async fn invalid_http_method() {
 
 #[tokio::test]
 async fn body_content_does_not_affect_request() {
-    let mozart = app();
+    let mozart = app(AppState::default());
     let expected_status_code = StatusCode::OK;
     let request = Builder::new()
         .method(Method::GET)
@@ -43,7 +43,7 @@ async fn body_content_does_not_affect_request() {
 
 #[tokio::test]
 async fn valid() {
-    let mozart = app();
+    let mozart = app(AppState::default());
     let expected_status_code = StatusCode::OK;
     let request = Builder::new()
         .method(Method::GET)
@@ -0,0 +1,4834 @@
+use axum::{
+    body::{to_bytes, Body},
+    http::{request::Builder, Method, StatusCode},
+};
+use mozart::{
+    app,
+    config::Config,
+    model::{
+        ExpectedError, FloatTolerance, Parameter, ParameterType, Submission, TestCase,
+        TestCaseFailureReason, TestCaseResult, TestResult,
+    },
+    response::{ErrorCode, SubmissionResult},
+    state::AppState,
+};
+use serde_json::{json, Value};
+use std::time::{Duration, Instant};
+use tower::ServiceExt;
+
+#[tokio::test]
+async fn invalid_http_method() {
+    let mozart = app(AppState::default());
+    let expected_status_code = StatusCode::METHOD_NOT_ALLOWED;
+    let request = Builder::new()
+        .method(Method::GET)
+        .uri("/submit")
+        .body(Body::empty())
+        .expect("failed to build request");
+
+    let actual = mozart
+        .oneshot(request)
+        .await
+        .expect("failed to await oneshot");
+
+    assert_eq!(actual.status(), expected_status_code);
+}
+
+#[tokio::test]
+async fn no_json_header() {
+    let mozart = app(AppState::default());
+    let expected_status_code = StatusCode::UNSUPPORTED_MEDIA_TYPE;
+    let request = Builder::new()
+        .method(Method::POST)
+        .uri("/submit")
+        .body(Body::empty())
+        .expect("failed to build request");
+
+    let actual = mozart
+        .oneshot(request)
+        .await
+        .expect("failed to await oneshot");
+
+    assert_eq!(actual.status(), expected_status_code);
+}
+
+#[tokio::test]
+async fn empty_request_body() {
+    let mozart = app(AppState::default());
+    let expected_status_code = StatusCode::BAD_REQUEST;
+    let request = Builder::new()
+        .method(Method::POST)
+        .header("Content-Type", "application/json")
+        .uri("/submit")
+        .body(Body::empty())
+        .expect("failed to build request");
+
+    let actual = mozart
+        .oneshot(request)
+        .await
+        .expect("failed to await oneshot");
+
+    let actual_status = actual.status();
+    let body_bytes = to_bytes(actual.into_body(), usize::MAX)
+        .await
+        .expect("failed to convert body to bytes");
+
+    let actual_body: SubmissionResult =
+        serde_json::from_slice(&body_bytes).expect("failed to deserialize response body");
+
+    assert_eq!(actual_status, expected_status_code);
+
+    if let SubmissionResult::Error { code, message } = actual_body {
+        assert_eq!(code, ErrorCode::InvalidRequest);
+        assert!(message.starts_with("Failed to parse the request body as JSON"));
+    } else {
+        panic!("response body was not of error variant");
+    }
+}
+
+#[tokio::test]
+async fn invalid_json() {
+    let mozart = app(AppState::default());
+    let expected_status_code = StatusCode::UNPROCESSABLE_ENTITY;
+    let body = serde_json::to_string(&ParameterType::Int).expect("failed to serialize body");
+    let request = Builder::new()
+        .method(Method::POST)
+        .header("Content-Type", "application/json")
+        .uri("/submit")
+        .body(body)
+        .expect("failed to build request");
+
+    let actual = mozart
+        .oneshot(request)
+        .await
+        .expect("failed to await oneshot");
+
+    let actual_status = actual.status();
+    let body_bytes = to_bytes(actual.into_body(), usize::MAX)
+        .await
+        .expect("failed to convert body to bytes");
+
+    let actual_body: SubmissionResult =
+        serde_json::from_slice(&body_bytes).expect("failed to deserialize response body");
+
+    assert_eq!(actual_status, expected_status_code);
+
+    if let SubmissionResult::Error { code, message } = actual_body {
+        assert_eq!(code, ErrorCode::InvalidRequest);
+        assert!(message.starts_with("Failed to deserialize the JSON body into the target type"));
+    } else {
+        panic!("response body was not of error variant");
+    }
+}
+
+#[tokio::test]
+async fn compilation_error() {
+    let mozart = app(AppState::default());
+    let solution = [
+        "long long solution(long long x) {",
+        "    if (x < 0) {",
+        "        return y * -1;",
+        "    }",
+        "    return x;",
+        "}",
+    ]
+    .join("\n");
+    let test_cases = Box::new([
+        TestCase {
+            id: 0,
+            input_parameters: Box::new([Parameter {
+                value_type: ParameterType::Int,
+                value: String::from("10"),
+            }]),
+            output_parameters: Box::new([Parameter {
+                value_type: ParameterType::Int,
+                value: String::from("10"),
+            }]),
+            stdin: None,
+            expected_error: None,
+            label: None,
+            hidden: false,
+        },
+        TestCase {
+            id: 1,
+            input_parameters: Box::new([Parameter {
+                value_type: ParameterType::Int,
+                value: String::from("-10"),
+            }]),
+            output_parameters: Box::new([Parameter {
+                value_type: ParameterType::Int,
+                value: String::from("10"),
+            }]),
+            stdin: None,
+            expected_error: None,
+            label: None,
+            hidden: false,
+        },
+    ]);
+    let submission = Submission {
+        solution,
+        test_cases,
+        unordered_lists: false,
+        files: Box::new([]),
+        checker: None,
+        reference_solution: None,
+        only_ids: None,
+        compiler_flags: Box::new([]),
+        debug: false,
+        float_tolerance: None,
+        toolchain_version: None,
+        metadata: None,
+    };
+    let body = serde_json::to_string(&submission).expect("failed to serialize submission");
+    let request = Builder::new()
+        .header("Content-Type", "application/json")
+        .method(Method::POST)
+        .uri("/submit")
+        .body(Body::from(body))
+        .expect("failed to build request");
+    let expected_status = StatusCode::OK;
+
+    let actual = mozart
+        .oneshot(request)
+        .await
+        .expect("failed to execute oneshot request");
+
+    let actual_status = actual.status();
+    let body_bytes = to_bytes(actual.into_body(), usize::MAX)
+        .await
+        .expect("failed to convert body to bytes");
+
+    let actual_body: SubmissionResult =
+        serde_json::from_slice(&body_bytes).expect("failed to deserialize response body");
+
+    assert_eq!(actual_status, expected_status);
+
+    if let SubmissionResult::CompilationError {
+        message,
+        diagnostics,
+    } = actual_body
+    {
+        assert!(message.starts_with("an error occurred during compilation:"));
+        assert!(!diagnostics.is_empty());
+    } else {
+        panic!("response body was not of compilation error variant");
+    }
+}
+
+#[tokio::test]
+async fn execution_timeout() {
+    let mozart = app(AppState::default());
+    let solution = [
+        "long long solution(long long x) {",
+        "    while (true) {",
+        "        x = *(volatile long long*)&x;",
+        "    }",
+        "}",
+    ]
+    .join("\n");
+    // the contents of the test cases are entirely irrelevant
+    let test_cases = Box::new([
+        TestCase {
+            id: 0,
+            input_parameters: Box::new([Parameter {
+                value_type: ParameterType::Int,
+                value: String::from("10"),
+            }]),
+            output_parameters: Box::new([Parameter {
+                value_type: ParameterType::Int,
+                value: String::from("10"),
+            }]),
+            stdin: None,
+            expected_error: None,
+            label: None,
+            hidden: false,
+        },
+        TestCase {
+            id: 1,
+            input_parameters: Box::new([Parameter {
+                value_type: ParameterType::Int,
+                value: String::from("-10"),
+            }]),
+            output_parameters: Box::new([Parameter {
+                value_type: ParameterType::Int,
+                value: String::from("10"),
+            }]),
+            stdin: None,
+            expected_error: None,
+            label: None,
+            hidden: false,
+        },
+    ]);
+    let submission = Submission {
+        solution,
+        test_cases,
+        unordered_lists: false,
+        files: Box::new([]),
+        checker: None,
+        reference_solution: None,
+        only_ids: None,
+        compiler_flags: Box::new([]),
+        debug: false,
+        float_tolerance: None,
+        toolchain_version: None,
+        metadata: None,
+    };
+    let body = serde_json::to_string(&submission).expect("failed to serialize submission");
+    let request = Builder::new()
+        .header("Content-Type", "application/json")
+        .method(Method::POST)
+        .uri("/submit")
+        .body(Body::from(body))
+        .expect("failed to build request");
+    let expected_status = StatusCode::OK;
+    let expected_body = SubmissionResult::Failure(Box::new([
+        TestCaseResult {
+            id: 0,
+            test_result: TestResult::Failure(TestCaseFailureReason::Timeout),
+            label: None,
+        },
+        TestCaseResult {
+            id: 1,
+            test_result: TestResult::Failure(TestCaseFailureReason::Timeout),
+            label: None,
+        },
+    ]));
+
+    let actual = mozart
+        .oneshot(request)
+        .await
+        .expect("failed to execute oneshot request");
+
+    let actual_status = actual.status();
+    let body_bytes = to_bytes(actual.into_body(), usize::MAX)
+        .await
+        .expect("failed to convert body to bytes");
+
+    let actual_body: SubmissionResult =
+        serde_json::from_slice(&body_bytes).expect("failed to deserialize response body");
+
+    assert_eq!(actual_status, expected_status);
+    assert_eq!(actual_body, expected_body);
+}
+
+#[tokio::test]
+async fn all_test_cases_pass_int() {
+    let mozart = app(AppState::default());
+    let solution = [
+        "long long solution(long long x) {",
+        "    return x < 0 ? -x : x;",
+        "}",
+    ]
+    .join("\n");
+    let test_cases = Box::new([
+        TestCase {
+            id: 0,
+            input_parameters: Box::new([Parameter {
+                value_type: ParameterType::Int,
+                value: String::from("10"),
+            }]),
+            output_parameters: Box::new([Parameter {
+                value_type: ParameterType::Int,
+                value: String::from("10"),
+            }]),
+            stdin: None,
+            expected_error: None,
+            label: None,
+            hidden: false,
+        },
+        TestCase {
+            id: 1,
+            input_parameters: Box::new([Parameter {
+                value_type: ParameterType::Int,
+                value: String::from("-10"),
+            }]),
+            output_parameters: Box::new([Parameter {
+                value_type: ParameterType::Int,
+                value: String::from("10"),
+            }]),
+            stdin: None,
+            expected_error: None,
+            label: None,
+            hidden: false,
+        },
+    ]);
+    let submission = Submission {
+        solution,
+        test_cases,
+        unordered_lists: false,
+        files: Box::new([]),
+        checker: None,
+        reference_solution: None,
+        only_ids: None,
+        compiler_flags: Box::new([]),
+        debug: false,
+        float_tolerance: None,
+        toolchain_version: None,
+        metadata: None,
+    };
+    let body = serde_json::to_string(&submission).expect("failed to serialize submission");
+    let request = Builder::new()
+        .header("Content-Type", "application/json")
+        .method(Method::POST)
+        .uri("/submit")
+        .body(Body::from(body))
+        .expect("failed to build request");
+    let expected_status = StatusCode::OK;
+    let expected_body = SubmissionResult::Pass;
+
+    let actual = mozart
+        .oneshot(request)
+        .await
+        .expect("failed to execute oneshot request");
+
+    let actual_status = actual.status();
+    let body_bytes = to_bytes(actual.into_body(), usize::MAX)
+        .await
+        .expect("failed to convert body to bytes");
+
+    let actual_body: SubmissionResult =
+        serde_json::from_slice(&body_bytes).expect("failed to deserialize response body");
+
+    assert_eq!(actual_status, expected_status);
+    assert_eq!(actual_body, expected_body);
+}
+
+#[tokio::test]
+async fn all_test_cases_pass_bool() {
+    let mozart = app(AppState::default());
+    let solution = ["bool solution(bool x) {", "    return !x;", "}"].join("\n");
+    let test_cases = Box::new([
+        TestCase {
+            id: 0,
+            input_parameters: Box::new([Parameter {
+                value_type: ParameterType::Bool,
+                value: String::from("true"),
+            }]),
+            output_parameters: Box::new([Parameter {
+                value_type: ParameterType::Bool,
+                value: String::from("false"),
+            }]),
+            stdin: None,
+            expected_error: None,
+            label: None,
+            hidden: false,
+        },
+        TestCase {
+            id: 1,
+            input_parameters: Box::new([Parameter {
+                value_type: ParameterType::Bool,
+                value: String::from("false"),
+            }]),
+            output_parameters: Box::new([Parameter {
+                value_type: ParameterType::Bool,
+                value: String::from("true"),
+            }]),
+            stdin: None,
+            expected_error: None,
+            label: None,
+            hidden: false,
+        },
+    ]);
+    let submission = Submission {
+        solution,
+        test_cases,
+        unordered_lists: false,
+        files: Box::new([]),
+        checker: None,
+        reference_solution: None,
+        only_ids: None,
+        compiler_flags: Box::new([]),
+        debug: false,
+        float_tolerance: None,
+        toolchain_version: None,
+        metadata: None,
+    };
+    let body = serde_json::to_string(&submission).expect("failed to serialize submission");
+    let request = Builder::new()
+        .header("Content-Type", "application/json")
+        .method(Method::POST)
+        .uri("/submit")
+        .body(Body::from(body))
+        .expect("failed to build request");
+    let expected_status = StatusCode::OK;
+    let expected_body = SubmissionResult::Pass;
+
+    let actual = mozart
+        .oneshot(request)
+        .await
+        .expect("failed to execute oneshot request");
+
+    let actual_status = actual.status();
+    let body_bytes = to_bytes(actual.into_body(), usize::MAX)
+        .await
+        .expect("failed to convert body to bytes");
+
+    let actual_body: SubmissionResult =
+        serde_json::from_slice(&body_bytes).expect("failed to deserialize response body");
+
+    assert_eq!(actual_status, expected_status);
+    assert_eq!(actual_body, expected_body);
+}
+
+#[tokio::test]
+async fn all_test_cases_pass_float() {
+    let mozart = app(AppState::default());
+    let solution = ["double solution(double x) {", "    return x * 2;", "}"].join("\n");
+    let test_cases = Box::new([
+        TestCase {
+            id: 0,
+            input_parameters: Box::new([Parameter {
+                value_type: ParameterType::Float,
+                value: String::from("2.5"),
+            }]),
+            output_parameters: Box::new([Parameter {
+                value_type: ParameterType::Float,
+                value: String::from("5.0"),
+            }]),
+            stdin: None,
+            expected_error: None,
+            label: None,
+            hidden: false,
+        },
+        TestCase {
+            id: 1,
+            input_parameters: Box::new([Parameter {
+                value_type: ParameterType::Float,
+                value: String::from("-1"),
+            }]),
+            output_parameters: Box::new([Parameter {
+                value_type: ParameterType::Float,
+                value: String::from("-2"),
+            }]),
+            stdin: None,
+            expected_error: None,
+            label: None,
+            hidden: false,
+        },
+    ]);
+    let submission = Submission {
+        solution,
+        test_cases,
+        unordered_lists: false,
+        files: Box::new([]),
+        checker: None,
+        reference_solution: None,
+        only_ids: None,
+        compiler_flags: Box::new([]),
+        debug: false,
+        float_tolerance: None,
+        toolchain_version: None,
+        metadata: None,
+    };
+    let body = serde_json::to_string(&submission).expect("failed to serialize submission");
+    let request = Builder::new()
+        .header("Content-Type", "application/json")
+        .method(Method::POST)
+        .uri("/submit")
+        .body(Body::from(body))
+        .expect("failed to build request");
+    let expected_status = StatusCode::OK;
+    let expected_body = SubmissionResult::Pass;
+
+    let actual = mozart
+        .oneshot(request)
+        .await
+        .expect("failed to execute oneshot request");
+
+    let actual_status = actual.status();
+    let body_bytes = to_bytes(actual.into_body(), usize::MAX)
+        .await
+        .expect("failed to convert body to bytes");
+
+    let actual_body: SubmissionResult =
+        serde_json::from_slice(&body_bytes).expect("failed to deserialize response body");
+
+    assert_eq!(actual_status, expected_status);
+    assert_eq!(actual_body, expected_body);
+}
+
+#[tokio::test]
+async fn all_test_cases_pass_char() {
+    let mozart = app(AppState::default());
+    let solution = [
+        "#include <cctype>",
+        "",
+        "char solution(char x) {",
+        "    return static_cast<char>(std::toupper(x));",
+        "}",
+    ]
+    .join("\n");
+    let test_cases = Box::new([
+        TestCase {
+            id: 0,
+            input_parameters: Box::new([Parameter {
+                value_type: ParameterType::Char,
+                value: String::from("a"),
+            }]),
+            output_parameters: Box::new([Parameter {
+                value_type: ParameterType::Char,
+                value: String::from("A"),
+            }]),
+            stdin: None,
+            expected_error: None,
+            label: None,
+            hidden: false,
+        },
+        TestCase {
+            id: 1,
+            input_parameters: Box::new([Parameter {
+                value_type: ParameterType::Char,
+                value: String::from("z"),
+            }]),
+            output_parameters: Box::new([Parameter {
+                value_type: ParameterType::Char,
+                value: String::from("Z"),
+            }]),
+            stdin: None,
+            expected_error: None,
+            label: None,
+            hidden: false,
+        },
+    ]);
+    let submission = Submission {
+        solution,
+        test_cases,
+        unordered_lists: false,
+        files: Box::new([]),
+        checker: None,
+        reference_solution: None,
+        only_ids: None,
+        compiler_flags: Box::new([]),
+        debug: false,
+        float_tolerance: None,
+        toolchain_version: None,
+        metadata: None,
+    };
+    let body = serde_json::to_string(&submission).expect("failed to serialize submission");
+    let request = Builder::new()
+        .header("Content-Type", "application/json")
+        .method(Method::POST)
+        .uri("/submit")
+        .body(Body::from(body))
+        .expect("failed to build request");
+    let expected_status = StatusCode::OK;
+    let expected_body = SubmissionResult::Pass;
+
+    let actual = mozart
+        .oneshot(request)
+        .await
+        .expect("failed to execute oneshot request");
+
+    let actual_status = actual.status();
+    let body_bytes = to_bytes(actual.into_body(), usize::MAX)
+        .await
+        .expect("failed to convert body to bytes");
+
+    let actual_body: SubmissionResult =
+        serde_json::from_slice(&body_bytes).expect("failed to deserialize response body");
+
+    assert_eq!(actual_status, expected_status);
+    assert_eq!(actual_body, expected_body);
+}
+
+#[tokio::test]
+async fn all_test_cases_pass_string() {
+    let mozart = app(AppState::default());
+    let solution = [
+        "std::string solution(const std::string& x) {",
+        "    return std::string(x.rbegin(), x.rend());",
+        "}",
+    ]
+    .join("\n");
+    let test_cases = Box::new([
+        TestCase {
+            id: 0,
+            input_parameters: Box::new([Parameter {
+                value_type: ParameterType::String,
+                value: String::from("abc"),
+            }]),
+            output_parameters: Box::new([Parameter {
+                value_type: ParameterType::String,
+                value: String::from("cba"),
+            }]),
+            stdin: None,
+            expected_error: None,
+            label: None,
+            hidden: false,
+        },
+        TestCase {
+            id: 1,
+            input_parameters: Box::new([Parameter {
+                value_type: ParameterType::String,
+                value: String::from("racecar"),
+            }]),
+            output_parameters: Box::new([Parameter {
+                value_type: ParameterType::String,
+                value: String::from("racecar"),
+            }]),
+            stdin: None,
+            expected_error: None,
+            label: None,
+            hidden: false,
+        },
+    ]);
+    let submission = Submission {
+        solution,
+        test_cases,
+        unordered_lists: false,
+        files: Box::new([]),
+        checker: None,
+        reference_solution: None,
+        only_ids: None,
+        compiler_flags: Box::new([]),
+        debug: false,
+        float_tolerance: None,
+        toolchain_version: None,
+        metadata: None,
+    };
+    let body = serde_json::to_string(&submission).expect("failed to serialize submission");
+    let request = Builder::new()
+        .header("Content-Type", "application/json")
+        .method(Method::POST)
+        .uri("/submit")
+        .body(Body::from(body))
+        .expect("failed to build request");
+    let expected_status = StatusCode::OK;
+    let expected_body = SubmissionResult::Pass;
+
+    let actual = mozart
+        .oneshot(request)
+        .await
+        .expect("failed to execute oneshot request");
+
+    let actual_status = actual.status();
+    let body_bytes = to_bytes(actual.into_body(), usize::MAX)
+        .await
+        .expect("failed to convert body to bytes");
+
+    let actual_body: SubmissionResult =
+        serde_json::from_slice(&body_bytes).expect("failed to deserialize response body");
+
+    assert_eq!(actual_status, expected_status);
+    assert_eq!(actual_body, expected_body);
+}
+
+#[tokio::test]
+async fn all_test_cases_fail_int() {
+    let mozart = app(AppState::default());
+    let solution = ["long long solution(long long x) {", "    return x;", "}"].join("\n");
+    let test_cases = Box::new([
+        TestCase {
+            id: 0,
+            input_parameters: Box::new([Parameter {
+                value_type: ParameterType::Int,
+                value: String::from("10"),
+            }]),
+            output_parameters: Box::new([Parameter {
+                value_type: ParameterType::Int,
+                value: String::from("20"),
+            }]),
+            stdin: None,
+            expected_error: None,
+            label: None,
+            hidden: false,
+        },
+        TestCase {
+            id: 1,
+            input_parameters: Box::new([Parameter {
+                value_type: ParameterType::Int,
+                value: String::from("5"),
+            }]),
+            output_parameters: Box::new([Parameter {
+                value_type: ParameterType::Int,
+                value: String::from("10"),
+            }]),
+            stdin: None,
+            expected_error: None,
+            label: None,
+            hidden: false,
+        },
+    ]);
+    let submission = Submission {
+        solution,
+        test_cases,
+        unordered_lists: false,
+        files: Box::new([]),
+        checker: None,
+        reference_solution: None,
+        only_ids: None,
+        compiler_flags: Box::new([]),
+        debug: false,
+        float_tolerance: None,
+        toolchain_version: None,
+        metadata: None,
+    };
+    let body = serde_json::to_string(&submission).expect("failed to serialize submission");
+    let request = Builder::new()
+        .header("Content-Type", "application/json")
+        .method(Method::POST)
+        .uri("/submit")
+        .body(Body::from(body))
+        .expect("failed to build request");
+    let expected_status = StatusCode::OK;
+    let expected_body = SubmissionResult::Failure(Box::new([
+        TestCaseResult {
+            id: 0,
+            test_result: TestResult::Failure(TestCaseFailureReason::WrongAnswer {
+                input_parameters: Box::new([Parameter {
+                    value_type: ParameterType::Int,
+                    value: String::from("10"),
+                }]),
+                actual: String::from("10"),
+                expected: String::from("20"),
+                actual_value: None,
+                expected_value: None,
+            }),
+            label: None,
+        },
+        TestCaseResult {
+            id: 1,
+            test_result: TestResult::Failure(TestCaseFailureReason::WrongAnswer {
+                input_parameters: Box::new([Parameter {
+                    value_type: ParameterType::Int,
+                    value: String::from("5"),
+                }]),
+                actual: String::from("5"),
+                expected: String::from("10"),
+                actual_value: None,
+                expected_value: None,
+            }),
+            label: None,
+        },
+    ]));
+
+    let actual = mozart
+        .oneshot(request)
+        .await
+        .expect("failed to execute oneshot request");
+
+    let actual_status = actual.status();
+    let body_bytes = to_bytes(actual.into_body(), usize::MAX)
+        .await
+        .expect("failed to convert body to bytes");
+
+    let actual_body: SubmissionResult =
+        serde_json::from_slice(&body_bytes).expect("failed to deserialize response body");
+
+    assert_eq!(actual_status, expected_status);
+    assert_eq!(actual_body, expected_body);
+}
+
+#[tokio::test]
+async fn all_test_cases_fail_bool() {
+    let mozart = app(AppState::default());
+    let solution = ["bool solution(bool x) {", "    return x;", "}"].join("\n");
+    let test_cases = Box::new([
+        TestCase {
+            id: 0,
+            input_parameters: Box::new([Parameter {
+                value_type: ParameterType::Bool,
+                value: String::from("true"),
+            }]),
+            output_parameters: Box::new([Parameter {
+                value_type: ParameterType::Bool,
+                value: String::from("false"),
+            }]),
+            stdin: None,
+            expected_error: None,
+            label: None,
+            hidden: false,
+        },
+        TestCase {
+            id: 1,
+            input_parameters: Box::new([Parameter {
+                value_type: ParameterType::Bool,
+                value: String::from("false"),
+            }]),
+            output_parameters: Box::new([Parameter {
+                value_type: ParameterType::Bool,
+                value: String::from("true"),
+            }]),
+            stdin: None,
+            expected_error: None,
+            label: None,
+            hidden: false,
+        },
+    ]);
+    let submission = Submission {
+        solution,
+        test_cases,
+        unordered_lists: false,
+        files: Box::new([]),
+        checker: None,
+        reference_solution: None,
+        only_ids: None,
+        compiler_flags: Box::new([]),
+        debug: false,
+        float_tolerance: None,
+        toolchain_version: None,
+        metadata: None,
+    };
+    let body = serde_json::to_string(&submission).expect("failed to serialize submission");
+    let request = Builder::new()
+        .header("Content-Type", "application/json")
+        .method(Method::POST)
+        .uri("/submit")
+        .body(Body::from(body))
+        .expect("failed to build request");
+    let expected_status = StatusCode::OK;
+    let expected_body = SubmissionResult::Failure(Box::new([
+        TestCaseResult {
+            id: 0,
+            test_result: TestResult::Failure(TestCaseFailureReason::WrongAnswer {
+                input_parameters: Box::new([Parameter {
+                    value_type: ParameterType::Bool,
+                    value: String::from("true"),
+                }]),
+                actual: String::from("true"),
+                expected: String::from("false"),
+                actual_value: None,
+                expected_value: None,
+            }),
+            label: None,
+        },
+        TestCaseResult {
+            id: 1,
+            test_result: TestResult::Failure(TestCaseFailureReason::WrongAnswer {
+                input_parameters: Box::new([Parameter {
+                    value_type: ParameterType::Bool,
+                    value: String::from("false"),
+                }]),
+                actual: String::from("false"),
+                expected: String::from("true"),
+                actual_value: None,
+                expected_value: None,
+            }),
+            label: None,
+        },
+    ]));
+
+    let actual = mozart
+        .oneshot(request)
+        .await
+        .expect("failed to execute oneshot request");
+
+    let actual_status = actual.status();
+    let body_bytes = to_bytes(actual.into_body(), usize::MAX)
+        .await
+        .expect("failed to convert body to bytes");
+
+    let actual_body: SubmissionResult =
+        serde_json::from_slice(&body_bytes).expect("failed to deserialize response body");
+
+    assert_eq!(actual_status, expected_status);
+    assert_eq!(actual_body, expected_body);
+}
+
+#[tokio::test]
+async fn all_test_cases_fail_float() {
+    let mozart = app(AppState::default());
+    let solution = ["double solution(double x) {", "    return x;", "}"].join("\n");
+    let test_cases = Box::new([
+        TestCase {
+            id: 0,
+            input_parameters: Box::new([Parameter {
+                value_type: ParameterType::Float,
+                value: String::from("1.5"),
+            }]),
+            output_parameters: Box::new([Parameter {
+                value_type: ParameterType::Float,
+                value: String::from("2.5"),
+            }]),
+            stdin: None,
+            expected_error: None,
+            label: None,
+            hidden: false,
+        },
+        TestCase {
+            id: 1,
+            input_parameters: Box::new([Parameter {
+                value_type: ParameterType::Float,
+                value: String::from("3"),
+            }]),
+            output_parameters: Box::new([Parameter {
+                value_type: ParameterType::Float,
+                value: String::from("4"),
+            }]),
+            stdin: None,
+            expected_error: None,
+            label: None,
+            hidden: false,
+        },
+    ]);
+    let submission = Submission {
+        solution,
+        test_cases,
+        unordered_lists: false,
+        files: Box::new([]),
+        checker: None,
+        reference_solution: None,
+        only_ids: None,
+        compiler_flags: Box::new([]),
+        debug: false,
+        float_tolerance: None,
+        toolchain_version: None,
+        metadata: None,
+    };
+    let body = serde_json::to_string(&submission).expect("failed to serialize submission");
+    let request = Builder::new()
+        .header("Content-Type", "application/json")
+        .method(Method::POST)
+        .uri("/submit")
+        .body(Body::from(body))
+        .expect("failed to build request");
+    let expected_status = StatusCode::OK;
+    let expected_body = SubmissionResult::Failure(Box::new([
+        TestCaseResult {
+            id: 0,
+            test_result: TestResult::Failure(TestCaseFailureReason::WrongAnswer {
+                input_parameters: Box::new([Parameter {
+                    value_type: ParameterType::Float,
+                    value: String::from("1.5"),
+                }]),
+                actual: String::from("1.5"),
+                expected: String::from("2.5"),
+                actual_value: None,
+                expected_value: None,
+            }),
+            label: None,
+        },
+        TestCaseResult {
+            id: 1,
+            test_result: TestResult::Failure(TestCaseFailureReason::WrongAnswer {
+                input_parameters: Box::new([Parameter {
+                    value_type: ParameterType::Float,
+                    value: String::from("3"),
+                }]),
+                actual: String::from("3.0"),
+                expected: String::from("4.0"),
+                actual_value: None,
+                expected_value: None,
+            }),
+            label: None,
+        },
+    ]));
+
+    let actual = mozart
+        .oneshot(request)
+        .await
+        .expect("failed to execute oneshot request");
+
+    let actual_status = actual.status();
+    let body_bytes = to_bytes(actual.into_body(), usize::MAX)
+        .await
+        .expect("failed to convert body to bytes");
+
+    let actual_body: SubmissionResult =
+        serde_json::from_slice(&body_bytes).expect("failed to deserialize response body");
+
+    assert_eq!(actual_status, expected_status);
+    assert_eq!(actual_body, expected_body);
+}
+
+#[tokio::test]
+async fn all_test_cases_fail_char() {
+    let mozart = app(AppState::default());
+    let solution = ["char solution(char x) {", "    return x;", "}"].join("\n");
+    let test_cases = Box::new([
+        TestCase {
+            id: 0,
+            input_parameters: Box::new([Parameter {
+                value_type: ParameterType::Char,
+                value: String::from("a"),
+            }]),
+            output_parameters: Box::new([Parameter {
+                value_type: ParameterType::Char,
+                value: String::from("b"),
+            }]),
+            stdin: None,
+            expected_error: None,
+            label: None,
+            hidden: false,
+        },
+        TestCase {
+            id: 1,
+            input_parameters: Box::new([Parameter {
+                value_type: ParameterType::Char,
+                value: String::from("x"),
+            }]),
+            output_parameters: Box::new([Parameter {
+                value_type: ParameterType::Char,
+                value: String::from("y"),
+            }]),
+            stdin: None,
+            expected_error: None,
+            label: None,
+            hidden: false,
+        },
+    ]);
+    let submission = Submission {
+        solution,
+        test_cases,
+        unordered_lists: false,
+        files: Box::new([]),
+        checker: None,
+        reference_solution: None,
+        only_ids: None,
+        compiler_flags: Box::new([]),
+        debug: false,
+        float_tolerance: None,
+        toolchain_version: None,
+        metadata: None,
+    };
+    let body = serde_json::to_string(&submission).expect("failed to serialize submission");
+    let request = Builder::new()
+        .header("Content-Type", "application/json")
+        .method(Method::POST)
+        .uri("/submit")
+        .body(Body::from(body))
+        .expect("failed to build request");
+    let expected_status = StatusCode::OK;
+    let expected_body = SubmissionResult::Failure(Box::new([
+        TestCaseResult {
+            id: 0,
+            test_result: TestResult::Failure(TestCaseFailureReason::WrongAnswer {
+                input_parameters: Box::new([Parameter {
+                    value_type: ParameterType::Char,
+                    value: String::from("a"),
+                }]),
+                actual: String::from("a"),
+                expected: String::from("b"),
+                actual_value: None,
+                expected_value: None,
+            }),
+            label: None,
+        },
+        TestCaseResult {
+            id: 1,
+            test_result: TestResult::Failure(TestCaseFailureReason::WrongAnswer {
+                input_parameters: Box::new([Parameter {
+                    value_type: ParameterType::Char,
+                    value: String::from("x"),
+                }]),
+                actual: String::from("x"),
+                expected: String::from("y"),
+                actual_value: None,
+                expected_value: None,
+            }),
+            label: None,
+        },
+    ]));
+
+    let actual = mozart
+        .oneshot(request)
+        .await
+        .expect("failed to execute oneshot request");
+
+    let actual_status = actual.status();
+    let body_bytes = to_bytes(actual.into_body(), usize::MAX)
+        .await
+        .expect("failed to convert body to bytes");
+
+    let actual_body: SubmissionResult =
+        serde_json::from_slice(&body_bytes).expect("failed to deserialize response body");
+
+    assert_eq!(actual_status, expected_status);
+    assert_eq!(actual_body, expected_body);
+}
+
+#[tokio::test]
+async fn all_test_cases_fail_string() {
+    let mozart = app(AppState::default());
+    let solution = [
+        "std::string solution(std::string x) {",
+        "    return x;",
+        "}",
+    ]
+    .join("\n");
+    let test_cases = Box::new([
+        TestCase {
+            id: 0,
+            input_parameters: Box::new([Parameter {
+                value_type: ParameterType::String,
+                value: String::from("abc"),
+            }]),
+            output_parameters: Box::new([Parameter {
+                value_type: ParameterType::String,
+                value: String::from("xyz"),
+            }]),
+            stdin: None,
+            expected_error: None,
+            label: None,
+            hidden: false,
+        },
+        TestCase {
+            id: 1,
+            input_parameters: Box::new([Parameter {
+                value_type: ParameterType::String,
+                value: String::from("hello"),
+            }]),
+            output_parameters: Box::new([Parameter {
+                value_type: ParameterType::String,
+                value: String::from("world"),
+            }]),
+            stdin: None,
+            expected_error: None,
+            label: None,
+            hidden: false,
+        },
+    ]);
+    let submission = Submission {
+        solution,
+        test_cases,
+        unordered_lists: false,
+        files: Box::new([]),
+        checker: None,
+        reference_solution: None,
+        only_ids: None,
+        compiler_flags: Box::new([]),
+        debug: false,
+        float_tolerance: None,
+        toolchain_version: None,
+        metadata: None,
+    };
+    let body = serde_json::to_string(&submission).expect("failed to serialize submission");
+    let request = Builder::new()
+        .header("Content-Type", "application/json")
+        .method(Method::POST)
+        .uri("/submit")
+        .body(Body::from(body))
+        .expect("failed to build request");
+    let expected_status = StatusCode::OK;
+    let expected_body = SubmissionResult::Failure(Box::new([
+        TestCaseResult {
+            id: 0,
+            test_result: TestResult::Failure(TestCaseFailureReason::WrongAnswer {
+                input_parameters: Box::new([Parameter {
+                    value_type: ParameterType::String,
+                    value: String::from("abc"),
+                }]),
+                actual: String::from("abc"),
+                expected: String::from("xyz"),
+                actual_value: None,
+                expected_value: None,
+            }),
+            label: None,
+        },
+        TestCaseResult {
+            id: 1,
+            test_result: TestResult::Failure(TestCaseFailureReason::WrongAnswer {
+                input_parameters: Box::new([Parameter {
+                    value_type: ParameterType::String,
+                    value: String::from("hello"),
+                }]),
+                actual: String::from("hello"),
+                expected: String::from("world"),
+                actual_value: None,
+                expected_value: None,
+            }),
+            label: None,
+        },
+    ]));
+
+    let actual = mozart
+        .oneshot(request)
+        .await
+        .expect("failed to execute oneshot request");
+
+    let actual_status = actual.status();
+    let body_bytes = to_bytes(actual.into_body(), usize::MAX)
+        .await
+        .expect("failed to convert body to bytes");
+
+    let actual_body: SubmissionResult =
+        serde_json::from_slice(&body_bytes).expect("failed to deserialize response body");
+
+    assert_eq!(actual_status, expected_status);
+    assert_eq!(actual_body, expected_body);
+}
+
+#[tokio::test]
+async fn runtime_error_in_non_last_test_case() {
+    let mozart = app(AppState::default());
+    let solution = [
+        "long long solution(long long i) {",
+        "    return 10 / i;",
+        "}",
+    ]
+    .join("\n");
+    // dividing by zero raises a signal, which must not affect the other test cases
+    let test_cases = Box::new([
+        TestCase {
+            id: 0,
+            input_parameters: Box::new([Parameter {
+                value_type: ParameterType::Int,
+                value: String::from("2"),
+            }]),
+            output_parameters: Box::new([Parameter {
+                value_type: ParameterType::Int,
+                value: String::from("5"),
+            }]),
+            stdin: None,
+            expected_error: None,
+            label: None,
+            hidden: false,
+        },
+        TestCase {
+            id: 1,
+            input_parameters: Box::new([Parameter {
+                value_type: ParameterType::Int,
+                value: String::from("0"),
+            }]),
+            output_parameters: Box::new([Parameter {
+                value_type: ParameterType::Int,
+                value: String::from("0"),
+            }]),
+            stdin: None,
+            expected_error: None,
+            label: None,
+            hidden: false,
+        },
+        TestCase {
+            id: 2,
+            input_parameters: Box::new([Parameter {
+                value_type: ParameterType::Int,
+                value: String::from("2"),
+            }]),
+            output_parameters: Box::new([Parameter {
+                value_type: ParameterType::Int,
+                value: String::from("5"),
+            }]),
+            stdin: None,
+            expected_error: None,
+            label: None,
+            hidden: false,
+        },
+    ]);
+    let submission = Submission {
+        solution,
+        test_cases,
+        unordered_lists: false,
+        files: Box::new([]),
+        checker: None,
+        reference_solution: None,
+        only_ids: None,
+        compiler_flags: Box::new([]),
+        debug: false,
+        float_tolerance: None,
+        toolchain_version: None,
+        metadata: None,
+    };
+    let body = serde_json::to_string(&submission).expect("failed to serialize submission");
+    let request = Builder::new()
+        .header("Content-Type", "application/json")
+        .method(Method::POST)
+        .uri("/submit")
+        .body(Body::from(body))
+        .expect("failed to build request");
+    let expected_status = StatusCode::OK;
+    let expected_body = SubmissionResult::Failure(Box::new([
+        TestCaseResult {
+            id: 0,
+            test_result: TestResult::Pass,
+            label: None,
+        },
+        TestCaseResult {
+            id: 1,
+            test_result: TestResult::Failure(TestCaseFailureReason::RuntimeError(String::from(
+                "Floating point exception",
+            ))),
+            label: None,
+        },
+        TestCaseResult {
+            id: 2,
+            test_result: TestResult::Pass,
+            label: None,
+        },
+    ]));
+
+    let actual = mozart
+        .oneshot(request)
+        .await
+        .expect("failed to execute oneshot request");
+
+    let actual_status = actual.status();
+    let body_bytes = to_bytes(actual.into_body(), usize::MAX)
+        .await
+        .expect("failed to convert body to bytes");
+
+    let actual_body: SubmissionResult =
+        serde_json::from_slice(&body_bytes).expect("failed to deserialize response body");
+
+    assert_eq!(actual_status, expected_status);
+    assert_eq!(actual_body, expected_body);
+}
+
+#[tokio::test]
+async fn throwing_exception() {
+    let mozart = app(AppState::default());
+    let solution = [
+        "#include <stdexcept>",
+        "",
+        "long long solution(long long x) {",
+        "    if (x < 0) {",
+        "        throw std::invalid_argument(\"negative\");",
+        "    }",
+        "    if (x == 0) {",
+        "        throw 0;",
+        "    }",
+        "    return x;",
+        "}",
+    ]
+    .join("\n");
+    let test_cases = Box::new([
+        TestCase {
+            id: 0,
+            input_parameters: Box::new([Parameter {
+                value_type: ParameterType::Int,
+                value: String::from("-1"),
+            }]),
+            output_parameters: Box::new([Parameter {
+                value_type: ParameterType::Int,
+                value: String::from("1"),
+            }]),
+            stdin: None,
+            expected_error: None,
+            label: None,
+            hidden: false,
+        },
+        TestCase {
+            id: 1,
+            input_parameters: Box::new([Parameter {
+                value_type: ParameterType::Int,
+                value: String::from("0"),
+            }]),
+            output_parameters: Box::new([Parameter {
+                value_type: ParameterType::Int,
+                value: String::from("0"),
+            }]),
+            stdin: None,
+            expected_error: None,
+            label: None,
+            hidden: false,
+        },
+        TestCase {
+            id: 2,
+            input_parameters: Box::new([Parameter {
+                value_type: ParameterType::Int,
+                value: String::from("1"),
+            }]),
+            output_parameters: Box::new([Parameter {
+                value_type: ParameterType::Int,
+                value: String::from("1"),
+            }]),
+            stdin: None,
+            expected_error: None,
+            label: None,
+            hidden: false,
+        },
+    ]);
+    let submission = Submission {
+        solution,
+        test_cases,
+        unordered_lists: false,
+        files: Box::new([]),
+        checker: None,
+        reference_solution: None,
+        only_ids: None,
+        compiler_flags: Box::new([]),
+        debug: false,
+        float_tolerance: None,
+        toolchain_version: None,
+        metadata: None,
+    };
+    let body = serde_json::to_string(&submission).expect("failed to serialize submission");
+    let request = Builder::new()
+        .header("Content-Type", "application/json")
+        .method(Method::POST)
+        .uri("/submit")
+        .body(Body::from(body))
+        .expect("failed to build request");
+    let expected_status = StatusCode::OK;
+    let expected_body = SubmissionResult::Failure(Box::new([
+        TestCaseResult {
+            id: 0,
+            test_result: TestResult::Failure(TestCaseFailureReason::RuntimeError(String::from(
+                "negative",
+            ))),
+            label: None,
+        },
+        TestCaseResult {
+            id: 1,
+            test_result: TestResult::Failure(TestCaseFailureReason::RuntimeError(String::from(
+                "an exception that is not a std::exception was thrown",
+            ))),
+            label: None,
+        },
+        TestCaseResult {
+            id: 2,
+            test_result: TestResult::Pass,
+            label: None,
+        },
+    ]));
+
+    let actual = mozart
+        .oneshot(request)
+        .await
+        .expect("failed to execute oneshot request");
+
+    let actual_status = actual.status();
+    let body_bytes = to_bytes(actual.into_body(), usize::MAX)
+        .await
+        .expect("failed to convert body to bytes");
+
+    let actual_body: SubmissionResult =
+        serde_json::from_slice(&body_bytes).expect("failed to deserialize response body");
+
+    assert_eq!(actual_status, expected_status);
+    assert_eq!(actual_body, expected_body);
+}
+
+#[tokio::test]
+async fn segmentation_fault() {
+    let mozart = app(AppState::default());
+    let solution = [
+        "long long solution(long long x) {",
+        "    volatile long long* pointer = nullptr;",
+        "    return x == 0 ? *pointer : x;",
+        "}",
+    ]
+    .join("\n");
+    let test_cases = Box::new([
+        TestCase {
+            id: 0,
+            input_parameters: Box::new([Parameter {
+                value_type: ParameterType::Int,
+                value: String::from("0"),
+            }]),
+            output_parameters: Box::new([Parameter {
+                value_type: ParameterType::Int,
+                value: String::from("0"),
+            }]),
+            stdin: None,
+            expected_error: None,
+            label: None,
+            hidden: false,
+        },
+        TestCase {
+            id: 1,
+            input_parameters: Box::new([Parameter {
+                value_type: ParameterType::Int,
+                value: String::from("1"),
+            }]),
+            output_parameters: Box::new([Parameter {
+                value_type: ParameterType::Int,
+                value: String::from("1"),
+            }]),
+            stdin: None,
+            expected_error: None,
+            label: None,
+            hidden: false,
+        },
+    ]);
+    let submission = Submission {
+        solution,
+        test_cases,
+        unordered_lists: false,
+        files: Box::new([]),
+        checker: None,
+        reference_solution: None,
+        only_ids: None,
+        compiler_flags: Box::new([]),
+        debug: false,
+        float_tolerance: None,
+        toolchain_version: None,
+        metadata: None,
+    };
+    let body = serde_json::to_string(&submission).expect("failed to serialize submission");
+    let request = Builder::new()
+        .header("Content-Type", "application/json")
+        .method(Method::POST)
+        .uri("/submit")
+        .body(Body::from(body))
+        .expect("failed to build request");
+    let expected_status = StatusCode::OK;
+    let expected_body = SubmissionResult::Failure(Box::new([
+        TestCaseResult {
+            id: 0,
+            test_result: TestResult::Failure(TestCaseFailureReason::RuntimeError(String::from(
+                "Segmentation fault",
+            ))),
+            label: None,
+        },
+        TestCaseResult {
+            id: 1,
+            test_result: TestResult::Pass,
+            label: None,
+        },
+    ]));
+
+    let actual = mozart
+        .oneshot(request)
+        .await
+        .expect("failed to execute oneshot request");
+
+    let actual_status = actual.status();
+    let body_bytes = to_bytes(actual.into_body(), usize::MAX)
+        .await
+        .expect("failed to convert body to bytes");
+
+    let actual_body: SubmissionResult =
+        serde_json::from_slice(&body_bytes).expect("failed to deserialize response body");
+
+    assert_eq!(actual_status, expected_status);
+    assert_eq!(actual_body, expected_body);
+}
+
+#[tokio::test]
+async fn big_int_is_not_supported() {
+    let mozart = app(AppState::default());
+    let solution = ["long long solution(long long x) {", "    return x;", "}"].join("\n");
+    // C++ has no arbitrary precision integer to represent a BigInt
+    let test_cases = Box::new([TestCase {
+        id: 0,
+        input_parameters: Box::new([Parameter {
+            value_type: ParameterType::BigInt,
+            value: String::from("25"),
+        }]),
+        output_parameters: Box::new([Parameter {
+            value_type: ParameterType::BigInt,
+            value: String::from("25"),
+        }]),
+        stdin: None,
+        expected_error: None,
+        label: None,
+        hidden: false,
+    }]);
+    let submission = Submission {
+        solution,
+        test_cases,
+        unordered_lists: false,
+        files: Box::new([]),
+        checker: None,
+        reference_solution: None,
+        only_ids: None,
+        compiler_flags: Box::new([]),
+        debug: false,
+        float_tolerance: None,
+        toolchain_version: None,
+        metadata: None,
+    };
+    let body = serde_json::to_string(&submission).expect("failed to serialize submission");
+    let request = Builder::new()
+        .header("Content-Type", "application/json")
+        .method(Method::POST)
+        .uri("/submit")
+        .body(Body::from(body))
+        .expect("failed to build request");
+    let expected_status = StatusCode::OK;
+
+    let actual = mozart
+        .oneshot(request)
+        .await
+        .expect("failed to execute oneshot request");
+
+    let actual_status = actual.status();
+    let body_bytes = to_bytes(actual.into_body(), usize::MAX)
+        .await
+        .expect("failed to convert body to bytes");
+
+    let actual_body: SubmissionResult =
+        serde_json::from_slice(&body_bytes).expect("failed to deserialize response body");
+
+    assert_eq!(actual_status, expected_status);
+
+    if let SubmissionResult::Error { code, message } = actual_body {
+        assert_eq!(code, ErrorCode::InvalidParameter);
+        assert!(message.starts_with(
+            "invalid parameter: test case 0, input parameter 0: the BigInt type is not supported"
+        ));
+    } else {
+        panic!("response body was not of error variant");
+    }
+}
+
+#[tokio::test]
+async fn unit_output() {
+    let mozart = app(AppState::default());
+    let solution = [
+        "#include <stdexcept>",
+        "",
+        "void solution(long long x) {",
+        "    if (x < 0) {",
+        "        throw std::invalid_argument(\"negative\");",
+        "    }",
+        "}",
+    ]
+    .join("\n");
+    let test_cases = Box::new([TestCase {
+        id: 0,
+        input_parameters: Box::new([Parameter {
+            value_type: ParameterType::Int,
+            value: String::from("2"),
+        }]),
+        output_parameters: Box::new([Parameter {
+            value_type: ParameterType::Unit,
+            value: String::from(""),
+        }]),
+        stdin: None,
+        expected_error: None,
+        label: None,
+        hidden: false,
+    }]);
+    let submission = Submission {
+        solution,
+        test_cases,
+        unordered_lists: false,
+        files: Box::new([]),
+        checker: None,
+        reference_solution: None,
+        only_ids: None,
+        compiler_flags: Box::new([]),
+        debug: false,
+        float_tolerance: None,
+        toolchain_version: None,
+        metadata: None,
+    };
+    let body = serde_json::to_string(&submission).expect("failed to serialize submission");
+    let request = Builder::new()
+        .header("Content-Type", "application/json")
+        .method(Method::POST)
+        .uri("/submit")
+        .body(Body::from(body))
+        .expect("failed to build request");
+    let expected_status = StatusCode::OK;
+    let expected_body = SubmissionResult::Pass;
+
+    let actual = mozart
+        .oneshot(request)
+        .await
+        .expect("failed to execute oneshot request");
+
+    let actual_status = actual.status();
+    let body_bytes = to_bytes(actual.into_body(), usize::MAX)
+        .await
+        .expect("failed to convert body to bytes");
+
+    let actual_body: SubmissionResult =
+        serde_json::from_slice(&body_bytes).expect("failed to deserialize response body");
+
+    assert_eq!(actual_status, expected_status);
+    assert_eq!(actual_body, expected_body);
+}
+
+#[tokio::test]
+async fn multiple_output_parameters() {
+    let mozart = app(AppState::default());
+    let solution = [
+        "std::tuple<long long, bool> solution(long long x) {",
+        "    return {x / 2, x % 2 == 0};",
+        "}",
+    ]
+    .join("\n");
+    // multiple output parameters are returned as a tuple
+    let test_cases = Box::new([
+        TestCase {
+            id: 0,
+            input_parameters: Box::new([Parameter {
+                value_type: ParameterType::Int,
+                value: String::from("4"),
+            }]),
+            output_parameters: Box::new([
+                Parameter {
+                    value_type: ParameterType::Int,
+                    value: String::from("2"),
+                },
+                Parameter {
+                    value_type: ParameterType::Bool,
+                    value: String::from("true"),
+                },
+            ]),
+            stdin: None,
+            expected_error: None,
+            label: None,
+            hidden: false,
+        },
+        TestCase {
+            id: 1,
+            input_parameters: Box::new([Parameter {
+                value_type: ParameterType::Int,
+                value: String::from("5"),
+            }]),
+            output_parameters: Box::new([
+                Parameter {
+                    value_type: ParameterType::Int,
+                    value: String::from("2"),
+                },
+                Parameter {
+                    value_type: ParameterType::Bool,
+                    value: String::from("false"),
+                },
+            ]),
+            stdin: None,
+            expected_error: None,
+            label: None,
+            hidden: false,
+        },
+    ]);
+    let submission = Submission {
+        solution,
+        test_cases,
+        unordered_lists: false,
+        files: Box::new([]),
+        checker: None,
+        reference_solution: None,
+        only_ids: None,
+        compiler_flags: Box::new([]),
+        debug: false,
+        float_tolerance: None,
+        toolchain_version: None,
+        metadata: None,
+    };
+    let body = serde_json::to_string(&submission).expect("failed to serialize submission");
+    let request = Builder::new()
+        .header("Content-Type", "application/json")
+        .method(Method::POST)
+        .uri("/submit")
+        .body(Body::from(body))
+        .expect("failed to build request");
+    let expected_status = StatusCode::OK;
+    let expected_body = SubmissionResult::Pass;
+
+    let actual = mozart
+        .oneshot(request)
+        .await
+        .expect("failed to execute oneshot request");
+
+    let actual_status = actual.status();
+    let body_bytes = to_bytes(actual.into_body(), usize::MAX)
+        .await
+        .expect("failed to convert body to bytes");
+
+    let actual_body: SubmissionResult =
+        serde_json::from_slice(&body_bytes).expect("failed to deserialize response body");
+
+    assert_eq!(actual_status, expected_status);
+    assert_eq!(actual_body, expected_body);
+}
+
+#[tokio::test]
+async fn duplicate_test_case_ids() {
+    let mozart = app(AppState::default());
+    let solution = ["long long solution(long long x) {", "    return x;", "}"].join("\n");
+    let test_cases = Box::new([
+        TestCase {
+            id: 0,
+            input_parameters: Box::new([Parameter {
+                value_type: ParameterType::Int,
+                value: String::from("1"),
+            }]),
+            output_parameters: Box::new([Parameter {
+                value_type: ParameterType::Int,
+                value: String::from("1"),
+            }]),
+            stdin: None,
+            expected_error: None,
+            label: None,
+            hidden: false,
+        },
+        TestCase {
+            id: 0,
+            input_parameters: Box::new([Parameter {
+                value_type: ParameterType::Int,
+                value: String::from("2"),
+            }]),
+            output_parameters: Box::new([Parameter {
+                value_type: ParameterType::Int,
+                value: String::from("2"),
+            }]),
+            stdin: None,
+            expected_error: None,
+            label: None,
+            hidden: false,
+        },
+    ]);
+    let submission = Submission {
+        solution,
+        test_cases,
+        unordered_lists: false,
+        files: Box::new([]),
+        checker: None,
+        reference_solution: None,
+        only_ids: None,
+        compiler_flags: Box::new([]),
+        debug: false,
+        float_tolerance: None,
+        toolchain_version: None,
+        metadata: None,
+    };
+    let body = serde_json::to_string(&submission).expect("failed to serialize submission");
+    let request = Builder::new()
+        .header("Content-Type", "application/json")
+        .method(Method::POST)
+        .uri("/submit")
+        .body(Body::from(body))
+        .expect("failed to build request");
+    let expected_status = StatusCode::OK;
+
+    let actual = mozart
+        .oneshot(request)
+        .await
+        .expect("failed to execute oneshot request");
+
+    let actual_status = actual.status();
+    let body_bytes = to_bytes(actual.into_body(), usize::MAX)
+        .await
+        .expect("failed to convert body to bytes");
+
+    let actual_body: SubmissionResult =
+        serde_json::from_slice(&body_bytes).expect("failed to deserialize response body");
+
+    assert_eq!(actual_status, expected_status);
+
+    if let SubmissionResult::Error { code, message } = actual_body {
+        assert_eq!(code, ErrorCode::DuplicateTestCaseId);
+        assert!(message.starts_with("multiple test cases have the id 0"));
+    } else {
+        panic!("response body was not of error variant");
+    }
+}
+
+#[tokio::test]
+async fn values_with_delimiters() {
+    let mozart = app(AppState::default());
+    let solution = [
+        "#include <stdexcept>",
+        "",
+        "std::string solution(long long x) {",
+        "    if (x == 2) {",
+        r#"        throw std::invalid_argument("invalid value, got \"2\"");"#,
+        "    }",
+        r#"    return "a, \"b\"\nc";"#,
+        "}",
+    ]
+    .join("\n");
+    // commas, quotes and newlines must not break the reporting of the outcome
+    let test_cases = Box::new([
+        TestCase {
+            id: 0,
+            input_parameters: Box::new([Parameter {
+                value_type: ParameterType::Int,
+                value: String::from("1"),
+            }]),
+            output_parameters: Box::new([Parameter {
+                value_type: ParameterType::String,
+                value: String::from("x"),
+            }]),
+            stdin: None,
+            expected_error: None,
+            label: None,
+            hidden: false,
+        },
+        TestCase {
+            id: 1,
+            input_parameters: Box::new([Parameter {
+                value_type: ParameterType::Int,
+                value: String::from("2"),
+            }]),
+            output_parameters: Box::new([Parameter {
+                value_type: ParameterType::String,
+                value: String::from("x"),
+            }]),
+            stdin: None,
+            expected_error: None,
+            label: None,
+            hidden: false,
+        },
+    ]);
+    let submission = Submission {
+        solution,
+        test_cases,
+        unordered_lists: false,
+        files: Box::new([]),
+        checker: None,
+        reference_solution: None,
+        only_ids: None,
+        compiler_flags: Box::new([]),
+        debug: false,
+        float_tolerance: None,
+        toolchain_version: None,
+        metadata: None,
+    };
+    let body = serde_json::to_string(&submission).expect("failed to serialize submission");
+    let request = Builder::new()
+        .header("Content-Type", "application/json")
+        .method(Method::POST)
+        .uri("/submit")
+        .body(Body::from(body))
+        .expect("failed to build request");
+    let expected_status = StatusCode::OK;
+    let expected_body = SubmissionResult::Failure(Box::new([
+        TestCaseResult {
+            id: 0,
+            test_result: TestResult::Failure(TestCaseFailureReason::WrongAnswer {
+                input_parameters: Box::new([Parameter {
+                    value_type: ParameterType::Int,
+                    value: String::from("1"),
+                }]),
+                actual: String::from("a, \"b\"\nc"),
+                expected: String::from("x"),
+                actual_value: None,
+                expected_value: None,
+            }),
+            label: None,
+        },
+        TestCaseResult {
+            id: 1,
+            test_result: TestResult::Failure(TestCaseFailureReason::RuntimeError(String::from(
+                r#"invalid value, got "2""#,
+            ))),
+            label: None,
+        },
+    ]));
+
+    let actual = mozart
+        .oneshot(request)
+        .await
+        .expect("failed to execute oneshot request");
+
+    let actual_status = actual.status();
+    let body_bytes = to_bytes(actual.into_body(), usize::MAX)
+        .await
+        .expect("failed to convert body to bytes");
+
+    let actual_body: SubmissionResult =
+        serde_json::from_slice(&body_bytes).expect("failed to deserialize response body");
+
+    assert_eq!(actual_status, expected_status);
+    assert_eq!(actual_body, expected_body);
+}
+
+#[tokio::test]
+async fn custom_checker() {
+    let mozart = app(AppState::default());
+    let solution = [
+        "std::string solution(long long n) {",
+        "    return std::string(n, 'a');",
+        "}",
+    ]
+    .join("\n");
+    // any value with the length of the expected value is accepted
+    let test_cases = Box::new([
+        TestCase {
+            id: 0,
+            input_parameters: Box::new([Parameter {
+                value_type: ParameterType::Int,
+                value: String::from("3"),
+            }]),
+            output_parameters: Box::new([Parameter {
+                value_type: ParameterType::String,
+                value: String::from("xyz"),
+            }]),
+            stdin: None,
+            expected_error: None,
+            label: None,
+            hidden: false,
+        },
+        TestCase {
+            id: 1,
+            input_parameters: Box::new([Parameter {
+                value_type: ParameterType::Int,
+                value: String::from("2"),
+            }]),
+            output_parameters: Box::new([Parameter {
+                value_type: ParameterType::String,
+                value: String::from("xyz"),
+            }]),
+            stdin: None,
+            expected_error: None,
+            label: None,
+            hidden: false,
+        },
+    ]);
+    let submission = Submission {
+        solution,
+        test_cases,
+        unordered_lists: false,
+        files: Box::new([]),
+        checker: Some(String::from(
+            "bool checker(const std::string& actual, const std::string& expected) {\n    return actual.size() == expected.size();\n}\n",
+        )),
+        reference_solution: None,
+        only_ids: None,
+        compiler_flags: Box::new([]),
+        debug: false,
+        float_tolerance: None,
+        toolchain_version: None,
+        metadata: None,
+    };
+    let body = serde_json::to_string(&submission).expect("failed to serialize submission");
+    let request = Builder::new()
+        .header("Content-Type", "application/json")
+        .method(Method::POST)
+        .uri("/submit")
+        .body(Body::from(body))
+        .expect("failed to build request");
+    let expected_status = StatusCode::OK;
+    let expected_body = SubmissionResult::Failure(Box::new([
+        TestCaseResult {
+            id: 0,
+            test_result: TestResult::Pass,
+            label: None,
+        },
+        TestCaseResult {
+            id: 1,
+            test_result: TestResult::Failure(TestCaseFailureReason::WrongAnswer {
+                input_parameters: Box::new([Parameter {
+                    value_type: ParameterType::Int,
+                    value: String::from("2"),
+                }]),
+                actual: String::from("aa"),
+                expected: String::from("xyz"),
+                actual_value: None,
+                expected_value: None,
+            }),
+            label: None,
+        },
+    ]));
+
+    let actual = mozart
+        .oneshot(request)
+        .await
+        .expect("failed to execute oneshot request");
+
+    let actual_status = actual.status();
+    let body_bytes = to_bytes(actual.into_body(), usize::MAX)
+        .await
+        .expect("failed to convert body to bytes");
+
+    let actual_body: SubmissionResult =
+        serde_json::from_slice(&body_bytes).expect("failed to deserialize response body");
+
+    assert_eq!(actual_status, expected_status);
+    assert_eq!(actual_body, expected_body);
+}
+
+#[tokio::test]
+async fn read_stdin() {
+    let mozart = app(AppState::default());
+    let solution = [
+        "#include <iostream>",
+        "",
+        "std::string solution(long long n) {",
+        "    std::string line;",
+        "    std::getline(std::cin, line);",
+        "    return line;",
+        "}",
+    ]
+    .join("\n");
+    // every test case reads from its own stdin, which is empty if it is not supplied
+    let test_cases = Box::new([
+        TestCase {
+            id: 0,
+            input_parameters: Box::new([Parameter {
+                value_type: ParameterType::Int,
+                value: String::from("0"),
+            }]),
+            output_parameters: Box::new([Parameter {
+                value_type: ParameterType::String,
+                value: String::from("hello"),
+            }]),
+            stdin: Some(String::from("hello\nworld\n")),
+            expected_error: None,
+            label: None,
+            hidden: false,
+        },
+        TestCase {
+            id: 1,
+            input_parameters: Box::new([Parameter {
+                value_type: ParameterType::Int,
+                value: String::from("1"),
+            }]),
+            output_parameters: Box::new([Parameter {
+                value_type: ParameterType::String,
+                value: String::from("world"),
+            }]),
+            stdin: Some(String::from("world\n")),
+            expected_error: None,
+            label: None,
+            hidden: false,
+        },
+        TestCase {
+            id: 2,
+            input_parameters: Box::new([Parameter {
+                value_type: ParameterType::Int,
+                value: String::from("2"),
+            }]),
+            output_parameters: Box::new([Parameter {
+                value_type: ParameterType::String,
+                value: String::from(""),
+            }]),
+            stdin: None,
+            expected_error: None,
+            label: None,
+            hidden: false,
+        },
+    ]);
+    let submission = Submission {
+        solution,
+        test_cases,
+        unordered_lists: false,
+        files: Box::new([]),
+        checker: None,
+        reference_solution: None,
+        only_ids: None,
+        compiler_flags: Box::new([]),
+        debug: false,
+        float_tolerance: None,
+        toolchain_version: None,
+        metadata: None,
+    };
+    let body = serde_json::to_string(&submission).expect("failed to serialize submission");
+    let request = Builder::new()
+        .header("Content-Type", "application/json")
+        .method(Method::POST)
+        .uri("/submit")
+        .body(Body::from(body))
+        .expect("failed to build request");
+    let expected_status = StatusCode::OK;
+    let expected_body = SubmissionResult::Pass;
+
+    let actual = mozart
+        .oneshot(request)
+        .await
+        .expect("failed to execute oneshot request");
+
+    let actual_status = actual.status();
+    let body_bytes = to_bytes(actual.into_body(), usize::MAX)
+        .await
+        .expect("failed to convert body to bytes");
+
+    let actual_body: SubmissionResult =
+        serde_json::from_slice(&body_bytes).expect("failed to deserialize response body");
+
+    assert_eq!(actual_status, expected_status);
+    assert_eq!(actual_body, expected_body);
+}
+
+#[tokio::test]
+async fn return_map() {
+    let mozart = app(AppState::default());
+    let solution = [
+        "#include <sstream>",
+        "",
+        "std::map<std::string, long long> solution(const std::string& s) {",
+        "    std::map<std::string, long long> counts;",
+        "    std::istringstream words(s);",
+        "    for (std::string word; words >> word;) {",
+        "        counts[word]++;",
+        "    }",
+        "    return counts;",
+        "}",
+    ]
+    .join("\n");
+    // the entries are inserted in another order than they are listed, which must not matter
+    let test_cases = Box::new([
+        TestCase {
+            id: 0,
+            input_parameters: Box::new([Parameter {
+                value_type: ParameterType::String,
+                value: String::from("pear apple pear"),
+            }]),
+            output_parameters: Box::new([Parameter {
+                value_type: ParameterType::Map {
+                    key: Box::new(ParameterType::String),
+                    value: Box::new(ParameterType::Int),
+                },
+                value: String::from(r#"{"apple": "1", "pear": "2"}"#),
+            }]),
+            stdin: None,
+            expected_error: None,
+            label: None,
+            hidden: false,
+        },
+        TestCase {
+            id: 1,
+            input_parameters: Box::new([Parameter {
+                value_type: ParameterType::String,
+                value: String::from(""),
+            }]),
+            output_parameters: Box::new([Parameter {
+                value_type: ParameterType::Map {
+                    key: Box::new(ParameterType::String),
+                    value: Box::new(ParameterType::Int),
+                },
+                value: String::from("{}"),
+            }]),
+            stdin: None,
+            expected_error: None,
+            label: None,
+            hidden: false,
+        },
+    ]);
+    let submission = Submission {
+        solution,
+        test_cases,
+        unordered_lists: false,
+        files: Box::new([]),
+        checker: None,
+        reference_solution: None,
+        only_ids: None,
+        compiler_flags: Box::new([]),
+        debug: false,
+        float_tolerance: None,
+        toolchain_version: None,
+        metadata: None,
+    };
+    let body = serde_json::to_string(&submission).expect("failed to serialize submission");
+    let request = Builder::new()
+        .header("Content-Type", "application/json")
+        .method(Method::POST)
+        .uri("/submit")
+        .body(Body::from(body))
+        .expect("failed to build request");
+    let expected_status = StatusCode::OK;
+    let expected_body = SubmissionResult::Pass;
+
+    let actual = mozart
+        .oneshot(request)
+        .await
+        .expect("failed to execute oneshot request");
+
+    let actual_status = actual.status();
+    let body_bytes = to_bytes(actual.into_body(), usize::MAX)
+        .await
+        .expect("failed to convert body to bytes");
+
+    let actual_body: SubmissionResult =
+        serde_json::from_slice(&body_bytes).expect("failed to deserialize response body");
+
+    assert_eq!(actual_status, expected_status);
+    assert_eq!(actual_body, expected_body);
+}
+
+#[tokio::test]
+async fn only_ids() {
+    let mozart = app(AppState::default());
+    let solution = [
+        "long long solution(long long x) {",
+        "    return x * 2;",
+        "}",
+    ]
+    .join("\n");
+    // only the requested test case is run, so the failure of the others is not reported
+    let test_cases = Box::new([
+        TestCase {
+            id: 0,
+            input_parameters: Box::new([Parameter {
+                value_type: ParameterType::Int,
+                value: String::from("0"),
+            }]),
+            output_parameters: Box::new([Parameter {
+                value_type: ParameterType::Int,
+                value: String::from("0"),
+            }]),
+            stdin: None,
+            expected_error: None,
+            label: None,
+            hidden: false,
+        },
+        TestCase {
+            id: 1,
+            input_parameters: Box::new([Parameter {
+                value_type: ParameterType::Int,
+                value: String::from("1"),
+            }]),
+            output_parameters: Box::new([Parameter {
+                value_type: ParameterType::Int,
+                value: String::from("0"),
+            }]),
+            stdin: None,
+            expected_error: None,
+            label: None,
+            hidden: false,
+        },
+        TestCase {
+            id: 2,
+            input_parameters: Box::new([Parameter {
+                value_type: ParameterType::Int,
+                value: String::from("2"),
+            }]),
+            output_parameters: Box::new([Parameter {
+                value_type: ParameterType::Int,
+                value: String::from("4"),
+            }]),
+            stdin: None,
+            expected_error: None,
+            label: None,
+            hidden: false,
+        },
+        TestCase {
+            id: 3,
+            input_parameters: Box::new([Parameter {
+                value_type: ParameterType::Int,
+                value: String::from("3"),
+            }]),
+            output_parameters: Box::new([Parameter {
+                value_type: ParameterType::Int,
+                value: String::from("7"),
+            }]),
+            stdin: None,
+            expected_error: None,
+            label: None,
+            hidden: false,
+        },
+        TestCase {
+            id: 4,
+            input_parameters: Box::new([Parameter {
+                value_type: ParameterType::Int,
+                value: String::from("4"),
+            }]),
+            output_parameters: Box::new([Parameter {
+                value_type: ParameterType::Int,
+                value: String::from("8"),
+            }]),
+            stdin: None,
+            expected_error: None,
+            label: None,
+            hidden: false,
+        },
+    ]);
+    let submission = Submission {
+        solution,
+        test_cases,
+        unordered_lists: false,
+        files: Box::new([]),
+        checker: None,
+        reference_solution: None,
+        only_ids: Some(Box::new([3])),
+        compiler_flags: Box::new([]),
+        debug: false,
+        float_tolerance: None,
+        toolchain_version: None,
+        metadata: None,
+    };
+    let body = serde_json::to_string(&submission).expect("failed to serialize submission");
+    let request = Builder::new()
+        .header("Content-Type", "application/json")
+        .method(Method::POST)
+        .uri("/submit")
+        .body(Body::from(body))
+        .expect("failed to build request");
+    let expected_status = StatusCode::OK;
+    let expected_body = SubmissionResult::Failure(Box::new([TestCaseResult {
+        id: 3,
+        test_result: TestResult::Failure(TestCaseFailureReason::WrongAnswer {
+            input_parameters: Box::new([Parameter {
+                value_type: ParameterType::Int,
+                value: String::from("3"),
+            }]),
+            actual: String::from("6"),
+            expected: String::from("7"),
+            actual_value: None,
+            expected_value: None,
+        }),
+        label: None,
+    }]));
+
+    let actual = mozart
+        .oneshot(request)
+        .await
+        .expect("failed to execute oneshot request");
+
+    let actual_status = actual.status();
+    let body_bytes = to_bytes(actual.into_body(), usize::MAX)
+        .await
+        .expect("failed to convert body to bytes");
+
+    let actual_body: SubmissionResult =
+        serde_json::from_slice(&body_bytes).expect("failed to deserialize response body");
+
+    assert_eq!(actual_status, expected_status);
+    assert_eq!(actual_body, expected_body);
+}
+
+#[tokio::test]
+async fn unordered_list() {
+    let mozart = app(AppState::default());
+    let solution = [
+        "#include <set>",
+        "",
+        "std::vector<long long> solution(std::vector<long long> xs) {",
+        "    std::set<long long> unique(xs.begin(), xs.end());",
+        "    return {unique.rbegin(), unique.rend()};",
+        "}",
+    ]
+    .join("\n");
+    // the solution returns the elements in descending order, which is accepted as the order does not matter
+    let test_cases = Box::new([TestCase {
+        id: 0,
+        input_parameters: Box::new([Parameter {
+            value_type: ParameterType::List(Box::new(ParameterType::Int)),
+            value: String::from(r#"["3", "1", "3", "2"]"#),
+        }]),
+        output_parameters: Box::new([Parameter {
+            value_type: ParameterType::List(Box::new(ParameterType::Int)),
+            value: String::from(r#"["1", "2", "3"]"#),
+        }]),
+        stdin: None,
+        expected_error: None,
+        label: None,
+        hidden: false,
+    }]);
+    let submission = Submission {
+        solution,
+        test_cases,
+        unordered_lists: true,
+        files: Box::new([]),
+        checker: None,
+        reference_solution: None,
+        only_ids: None,
+        compiler_flags: Box::new([]),
+        debug: false,
+        float_tolerance: None,
+        toolchain_version: None,
+        metadata: None,
+    };
+    let body = serde_json::to_string(&submission).expect("failed to serialize submission");
+    let request = Builder::new()
+        .header("Content-Type", "application/json")
+        .method(Method::POST)
+        .uri("/submit")
+        .body(Body::from(body))
+        .expect("failed to build request");
+    let expected_status = StatusCode::OK;
+    let expected_body = SubmissionResult::Pass;
+
+    let actual = mozart
+        .oneshot(request)
+        .await
+        .expect("failed to execute oneshot request");
+
+    let actual_status = actual.status();
+    let body_bytes = to_bytes(actual.into_body(), usize::MAX)
+        .await
+        .expect("failed to convert body to bytes");
+
+    let actual_body: SubmissionResult =
+        serde_json::from_slice(&body_bytes).expect("failed to deserialize response body");
+
+    assert_eq!(actual_status, expected_status);
+    assert_eq!(actual_body, expected_body);
+}
+
+#[tokio::test]
+async fn ordered_list() {
+    let mozart = app(AppState::default());
+    let solution = [
+        "#include <set>",
+        "",
+        "std::vector<long long> solution(std::vector<long long> xs) {",
+        "    std::set<long long> unique(xs.begin(), xs.end());",
+        "    return {unique.rbegin(), unique.rend()};",
+        "}",
+    ]
+    .join("\n");
+    let test_cases = Box::new([TestCase {
+        id: 0,
+        input_parameters: Box::new([Parameter {
+            value_type: ParameterType::List(Box::new(ParameterType::Int)),
+            value: String::from(r#"["3", "1", "3", "2"]"#),
+        }]),
+        output_parameters: Box::new([Parameter {
+            value_type: ParameterType::List(Box::new(ParameterType::Int)),
+            value: String::from(r#"["1", "2", "3"]"#),
+        }]),
+        stdin: None,
+        expected_error: None,
+        label: None,
+        hidden: false,
+    }]);
+    let submission = Submission {
+        solution,
+        test_cases,
+        unordered_lists: false,
+        files: Box::new([]),
+        checker: None,
+        reference_solution: None,
+        only_ids: None,
+        compiler_flags: Box::new([]),
+        debug: false,
+        float_tolerance: None,
+        toolchain_version: None,
+        metadata: None,
+    };
+    let body = serde_json::to_string(&submission).expect("failed to serialize submission");
+    let request = Builder::new()
+        .header("Content-Type", "application/json")
+        .method(Method::POST)
+        .uri("/submit")
+        .body(Body::from(body))
+        .expect("failed to build request");
+    let expected_status = StatusCode::OK;
+    let expected_body = SubmissionResult::Failure(Box::new([TestCaseResult {
+        id: 0,
+        test_result: TestResult::Failure(TestCaseFailureReason::WrongAnswer {
+            input_parameters: Box::new([Parameter {
+                value_type: ParameterType::List(Box::new(ParameterType::Int)),
+                value: String::from(r#"["3", "1", "3", "2"]"#),
+            }]),
+            actual: String::from("[3, 2, 1]"),
+            expected: String::from("[1, 2, 3]"),
+            actual_value: Some(json!([3, 2, 1])),
+            expected_value: Some(json!([1, 2, 3])),
+        }),
+        label: None,
+    }]));
+
+    let actual = mozart
+        .oneshot(request)
+        .await
+        .expect("failed to execute oneshot request");
+
+    let actual_status = actual.status();
+    let body_bytes = to_bytes(actual.into_body(), usize::MAX)
+        .await
+        .expect("failed to convert body to bytes");
+
+    let actual_body: SubmissionResult =
+        serde_json::from_slice(&body_bytes).expect("failed to deserialize response body");
+
+    assert_eq!(actual_status, expected_status);
+    assert_eq!(actual_body, expected_body);
+}
+
+#[tokio::test]
+async fn allowed_compiler_flag() {
+    let mozart = app(AppState::new(Config {
+        allowed_compiler_flags: Box::new([String::from("-DOFFSET=1")]),
+        ..Config::default()
+    }));
+    let solution = [
+        "long long solution(long long x) {",
+        "    return x + OFFSET;",
+        "}",
+    ]
+    .join("\n");
+    // the solution only compiles if the flag is passed to the compiler
+    let test_cases = Box::new([TestCase {
+        id: 0,
+        input_parameters: Box::new([Parameter {
+            value_type: ParameterType::Int,
+            value: String::from("5"),
+        }]),
+        output_parameters: Box::new([Parameter {
+            value_type: ParameterType::Int,
+            value: String::from("6"),
+        }]),
+        stdin: None,
+        expected_error: None,
+        label: None,
+        hidden: false,
+    }]);
+    let submission = Submission {
+        solution,
+        test_cases,
+        unordered_lists: false,
+        files: Box::new([]),
+        checker: None,
+        reference_solution: None,
+        only_ids: None,
+        compiler_flags: Box::new([String::from("-DOFFSET=1")]),
+        debug: false,
+        float_tolerance: None,
+        toolchain_version: None,
+        metadata: None,
+    };
+    let body = serde_json::to_string(&submission).expect("failed to serialize submission");
+    let request = Builder::new()
+        .header("Content-Type", "application/json")
+        .method(Method::POST)
+        .uri("/submit")
+        .body(Body::from(body))
+        .expect("failed to build request");
+    let expected_status = StatusCode::OK;
+    let expected_body = SubmissionResult::Pass;
+
+    let actual = mozart
+        .oneshot(request)
+        .await
+        .expect("failed to execute oneshot request");
+
+    let actual_status = actual.status();
+    let body_bytes = to_bytes(actual.into_body(), usize::MAX)
+        .await
+        .expect("failed to convert body to bytes");
+
+    let actual_body: SubmissionResult =
+        serde_json::from_slice(&body_bytes).expect("failed to deserialize response body");
+
+    assert_eq!(actual_status, expected_status);
+    assert_eq!(actual_body, expected_body);
+}
+
+#[tokio::test]
+async fn disallowed_compiler_flag() {
+    let mozart = app(AppState::new(Config {
+        allowed_compiler_flags: Box::new([String::from("-DOFFSET=1")]),
+        ..Config::default()
+    }));
+    let solution = [
+        "long long solution(long long x) {",
+        "    return x + OFFSET;",
+        "}",
+    ]
+    .join("\n");
+    let test_cases = Box::new([TestCase {
+        id: 0,
+        input_parameters: Box::new([Parameter {
+            value_type: ParameterType::Int,
+            value: String::from("5"),
+        }]),
+        output_parameters: Box::new([Parameter {
+            value_type: ParameterType::Int,
+            value: String::from("6"),
+        }]),
+        stdin: None,
+        expected_error: None,
+        label: None,
+        hidden: false,
+    }]);
+    let submission = Submission {
+        solution,
+        test_cases,
+        unordered_lists: false,
+        files: Box::new([]),
+        checker: None,
+        reference_solution: None,
+        only_ids: None,
+        compiler_flags: Box::new([String::from("-fplugin=Evil")]),
+        debug: false,
+        float_tolerance: None,
+        toolchain_version: None,
+        metadata: None,
+    };
+    let body = serde_json::to_string(&submission).expect("failed to serialize submission");
+    let request = Builder::new()
+        .header("Content-Type", "application/json")
+        .method(Method::POST)
+        .uri("/submit")
+        .body(Body::from(body))
+        .expect("failed to build request");
+    let expected_status = StatusCode::OK;
+
+    let actual = mozart
+        .oneshot(request)
+        .await
+        .expect("failed to execute oneshot request");
+
+    let actual_status = actual.status();
+    let body_bytes = to_bytes(actual.into_body(), usize::MAX)
+        .await
+        .expect("failed to convert body to bytes");
+
+    let actual_body: SubmissionResult =
+        serde_json::from_slice(&body_bytes).expect("failed to deserialize response body");
+
+    assert_eq!(actual_status, expected_status);
+
+    if let SubmissionResult::Error { code, message } = actual_body {
+        assert_eq!(code, ErrorCode::DisallowedCompilerFlag);
+        assert!(message.starts_with("the compiler flag '-fplugin=Evil' is not allowed"));
+    } else {
+        panic!("response body was not of error variant");
+    }
+}
+
+#[tokio::test]
+async fn crash_without_output() {
+    let mozart = app(AppState::default());
+    // exiting while the static variables are initialized happens before any test case is started
+    let solution = [
+        "#include <cstdio>",
+        "#include <cstdlib>",
+        "",
+        "static int boom = (std::fputs(\"boom\\n\", stderr), std::exit(1), 0);",
+        "",
+        "long long solution(long long x) {",
+        "    return x;",
+        "}",
+    ]
+    .join("\n");
+    let test_cases = Box::new([TestCase {
+        id: 0,
+        input_parameters: Box::new([Parameter {
+            value_type: ParameterType::Int,
+            value: String::from("2"),
+        }]),
+        output_parameters: Box::new([Parameter {
+            value_type: ParameterType::Int,
+            value: String::from("2"),
+        }]),
+        stdin: None,
+        expected_error: None,
+        label: None,
+        hidden: false,
+    }]);
+    let submission = Submission {
+        solution,
+        test_cases,
+        unordered_lists: false,
+        files: Box::new([]),
+        checker: None,
+        reference_solution: None,
+        only_ids: None,
+        compiler_flags: Box::new([]),
+        debug: false,
+        float_tolerance: None,
+        toolchain_version: None,
+        metadata: None,
+    };
+    let body = serde_json::to_string(&submission).expect("failed to serialize submission");
+    let request = Builder::new()
+        .header("Content-Type", "application/json")
+        .method(Method::POST)
+        .uri("/submit")
+        .body(Body::from(body))
+        .expect("failed to build request");
+    let expected_status = StatusCode::OK;
+    let expected_body = SubmissionResult::Error {
+        code: ErrorCode::Execution,
+        message: String::from("an error occured during execution: boom\n"),
+    };
+
+    let actual = mozart
+        .oneshot(request)
+        .await
+        .expect("failed to execute oneshot request");
+
+    let actual_status = actual.status();
+    let body_bytes = to_bytes(actual.into_body(), usize::MAX)
+        .await
+        .expect("failed to convert body to bytes");
+
+    let actual_body: SubmissionResult =
+        serde_json::from_slice(&body_bytes).expect("failed to deserialize response body");
+
+    assert_eq!(actual_status, expected_status);
+    assert_eq!(actual_body, expected_body);
+}
+
+#[tokio::test]
+async fn reference_solution() {
+    let mozart = app(AppState::default());
+    let solution = [
+        "long long solution(long long x) {",
+        "    return x * x;",
+        "}",
+    ]
+    .join("\n");
+    let reference_solution = [
+        "long long solution(long long x) {",
+        "    long long square = 0;",
+        "    for (long long i = 0; i < x; i++) {",
+        "        square += x;",
+        "    }",
+        "    return square;",
+        "}",
+    ]
+    .join("\n");
+    // the first test case has no output parameters, so its expected value is computed by the reference solution
+    let test_cases = Box::new([
+        TestCase {
+            id: 0,
+            input_parameters: Box::new([Parameter {
+                value_type: ParameterType::Int,
+                value: String::from("3"),
+            }]),
+            output_parameters: Box::new([]),
+            stdin: None,
+            expected_error: None,
+            label: None,
+            hidden: false,
+        },
+        TestCase {
+            id: 1,
+            input_parameters: Box::new([Parameter {
+                value_type: ParameterType::Int,
+                value: String::from("2"),
+            }]),
+            output_parameters: Box::new([Parameter {
+                value_type: ParameterType::Int,
+                value: String::from("4"),
+            }]),
+            stdin: None,
+            expected_error: None,
+            label: None,
+            hidden: false,
+        },
+    ]);
+    let submission = Submission {
+        solution,
+        test_cases,
+        unordered_lists: false,
+        files: Box::new([]),
+        checker: None,
+        reference_solution: Some(reference_solution),
+        only_ids: None,
+        compiler_flags: Box::new([]),
+        debug: false,
+        float_tolerance: None,
+        toolchain_version: None,
+        metadata: None,
+    };
+    let body = serde_json::to_string(&submission).expect("failed to serialize submission");
+    let request = Builder::new()
+        .header("Content-Type", "application/json")
+        .method(Method::POST)
+        .uri("/submit")
+        .body(Body::from(body))
+        .expect("failed to build request");
+    let expected_status = StatusCode::OK;
+    let expected_body = SubmissionResult::Pass;
+
+    let actual = mozart
+        .oneshot(request)
+        .await
+        .expect("failed to execute oneshot request");
+
+    let actual_status = actual.status();
+    let body_bytes = to_bytes(actual.into_body(), usize::MAX)
+        .await
+        .expect("failed to convert body to bytes");
+
+    let actual_body: SubmissionResult =
+        serde_json::from_slice(&body_bytes).expect("failed to deserialize response body");
+
+    assert_eq!(actual_status, expected_status);
+    assert_eq!(actual_body, expected_body);
+}
+
+#[tokio::test]
+async fn reference_solution_disagrees() {
+    let mozart = app(AppState::default());
+    let solution = [
+        "long long solution(long long x) {",
+        "    return x * 2;",
+        "}",
+    ]
+    .join("\n");
+    let reference_solution = [
+        "long long solution(long long x) {",
+        "    long long square = 0;",
+        "    for (long long i = 0; i < x; i++) {",
+        "        square += x;",
+        "    }",
+        "    return square;",
+        "}",
+    ]
+    .join("\n");
+    // the first test case has no output parameters, so its expected value is computed by the reference solution
+    let test_cases = Box::new([
+        TestCase {
+            id: 0,
+            input_parameters: Box::new([Parameter {
+                value_type: ParameterType::Int,
+                value: String::from("3"),
+            }]),
+            output_parameters: Box::new([]),
+            stdin: None,
+            expected_error: None,
+            label: None,
+            hidden: false,
+        },
+        TestCase {
+            id: 1,
+            input_parameters: Box::new([Parameter {
+                value_type: ParameterType::Int,
+                value: String::from("2"),
+            }]),
+            output_parameters: Box::new([Parameter {
+                value_type: ParameterType::Int,
+                value: String::from("4"),
+            }]),
+            stdin: None,
+            expected_error: None,
+            label: None,
+            hidden: false,
+        },
+    ]);
+    let submission = Submission {
+        solution,
+        test_cases,
+        unordered_lists: false,
+        files: Box::new([]),
+        checker: None,
+        reference_solution: Some(reference_solution),
+        only_ids: None,
+        compiler_flags: Box::new([]),
+        debug: false,
+        float_tolerance: None,
+        toolchain_version: None,
+        metadata: None,
+    };
+    let body = serde_json::to_string(&submission).expect("failed to serialize submission");
+    let request = Builder::new()
+        .header("Content-Type", "application/json")
+        .method(Method::POST)
+        .uri("/submit")
+        .body(Body::from(body))
+        .expect("failed to build request");
+    let expected_status = StatusCode::OK;
+    let expected_body = SubmissionResult::Failure(Box::new([
+        TestCaseResult {
+            id: 0,
+            test_result: TestResult::Failure(TestCaseFailureReason::WrongAnswer {
+                input_parameters: Box::new([Parameter {
+                    value_type: ParameterType::Int,
+                    value: String::from("3"),
+                }]),
+                actual: String::from("6"),
+                expected: String::from("9"),
+                actual_value: None,
+                expected_value: None,
+            }),
+            label: None,
+        },
+        TestCaseResult {
+            id: 1,
+            test_result: TestResult::Pass,
+            label: None,
+        },
+    ]));
+
+    let actual = mozart
+        .oneshot(request)
+        .await
+        .expect("failed to execute oneshot request");
+
+    let actual_status = actual.status();
+    let body_bytes = to_bytes(actual.into_body(), usize::MAX)
+        .await
+        .expect("failed to convert body to bytes");
+
+    let actual_body: SubmissionResult =
+        serde_json::from_slice(&body_bytes).expect("failed to deserialize response body");
+
+    assert_eq!(actual_status, expected_status);
+    assert_eq!(actual_body, expected_body);
+}
+
+#[tokio::test]
+async fn float_tolerance_relative() {
+    let mozart = app(AppState::default());
+    let solution = [
+        "double solution(double x) {",
+        "    return x * 1e12 + 1;",
+        "}",
+    ]
+    .join("\n");
+    let test_cases = Box::new([TestCase {
+        id: 0,
+        input_parameters: Box::new([Parameter {
+            value_type: ParameterType::Float,
+            value: String::from("3.0"),
+        }]),
+        output_parameters: Box::new([Parameter {
+            value_type: ParameterType::Float,
+            value: String::from("3000000000000.0"),
+        }]),
+        stdin: None,
+        expected_error: None,
+        label: None,
+        hidden: false,
+    }]);
+    let submission = Submission {
+        solution,
+        test_cases,
+        unordered_lists: false,
+        files: Box::new([]),
+        checker: None,
+        reference_solution: None,
+        only_ids: None,
+        compiler_flags: Box::new([]),
+        debug: false,
+        float_tolerance: Some(FloatTolerance {
+            absolute: 1e-9,
+            relative: 1e-9,
+            match_nan: false,
+        }),
+        toolchain_version: None,
+        metadata: None,
+    };
+    let body = serde_json::to_string(&submission).expect("failed to serialize submission");
+    let request = Builder::new()
+        .header("Content-Type", "application/json")
+        .method(Method::POST)
+        .uri("/submit")
+        .body(Body::from(body))
+        .expect("failed to build request");
+    let expected_status = StatusCode::OK;
+    let expected_body = SubmissionResult::Pass;
+
+    let actual = mozart
+        .oneshot(request)
+        .await
+        .expect("failed to execute oneshot request");
+
+    let actual_status = actual.status();
+    let body_bytes = to_bytes(actual.into_body(), usize::MAX)
+        .await
+        .expect("failed to convert body to bytes");
+
+    let actual_body: SubmissionResult =
+        serde_json::from_slice(&body_bytes).expect("failed to deserialize response body");
+
+    assert_eq!(actual_status, expected_status);
+    assert_eq!(actual_body, expected_body);
+}
+
+#[tokio::test]
+async fn float_tolerance_absolute() {
+    let mozart = app(AppState::default());
+    let solution = [
+        "double solution(double x) {",
+        "    return x * 1e12 + 1;",
+        "}",
+    ]
+    .join("\n");
+    let test_cases = Box::new([TestCase {
+        id: 0,
+        input_parameters: Box::new([Parameter {
+            value_type: ParameterType::Float,
+            value: String::from("3.0"),
+        }]),
+        output_parameters: Box::new([Parameter {
+            value_type: ParameterType::Float,
+            value: String::from("3000000000000.0"),
+        }]),
+        stdin: None,
+        expected_error: None,
+        label: None,
+        hidden: false,
+    }]);
+    let submission = Submission {
+        solution,
+        test_cases,
+        unordered_lists: false,
+        files: Box::new([]),
+        checker: None,
+        reference_solution: None,
+        only_ids: None,
+        compiler_flags: Box::new([]),
+        debug: false,
+        float_tolerance: Some(FloatTolerance {
+            absolute: 1e-9,
+            relative: 0.0,
+            match_nan: false,
+        }),
+        toolchain_version: None,
+        metadata: None,
+    };
+    let body = serde_json::to_string(&submission).expect("failed to serialize submission");
+    let request = Builder::new()
+        .header("Content-Type", "application/json")
+        .method(Method::POST)
+        .uri("/submit")
+        .body(Body::from(body))
+        .expect("failed to build request");
+    let expected_status = StatusCode::OK;
+    let expected_body = SubmissionResult::Failure(Box::new([TestCaseResult {
+        id: 0,
+        test_result: TestResult::Failure(TestCaseFailureReason::WrongAnswer {
+            input_parameters: Box::new([Parameter {
+                value_type: ParameterType::Float,
+                value: String::from("3.0"),
+            }]),
+            actual: String::from("3000000000001.0"),
+            expected: String::from("3e+12"),
+            actual_value: None,
+            expected_value: None,
+        }),
+        label: None,
+    }]));
+
+    let actual = mozart
+        .oneshot(request)
+        .await
+        .expect("failed to execute oneshot request");
+
+    let actual_status = actual.status();
+    let body_bytes = to_bytes(actual.into_body(), usize::MAX)
+        .await
+        .expect("failed to convert body to bytes");
+
+    let actual_body: SubmissionResult =
+        serde_json::from_slice(&body_bytes).expect("failed to deserialize response body");
+
+    assert_eq!(actual_status, expected_status);
+    assert_eq!(actual_body, expected_body);
+}
+
+#[tokio::test]
+async fn float_tolerance_multiple_output_parameters() {
+    let mozart = app(AppState::default());
+    let solution = [
+        "std::tuple<double, long long> solution(double x) {",
+        "    return {x * 1e12 + 1, 3};",
+        "}",
+    ]
+    .join("\n");
+    let test_cases = Box::new([TestCase {
+        id: 0,
+        input_parameters: Box::new([Parameter {
+            value_type: ParameterType::Float,
+            value: String::from("3.0"),
+        }]),
+        output_parameters: Box::new([
+            Parameter {
+                value_type: ParameterType::Float,
+                value: String::from("3000000000000.0"),
+            },
+            Parameter {
+                value_type: ParameterType::Int,
+                value: String::from("3"),
+            },
+        ]),
+        stdin: None,
+        expected_error: None,
+        label: None,
+        hidden: false,
+    }]);
+    let submission = Submission {
+        solution,
+        test_cases,
+        unordered_lists: false,
+        files: Box::new([]),
+        checker: None,
+        reference_solution: None,
+        only_ids: None,
+        compiler_flags: Box::new([]),
+        debug: false,
+        float_tolerance: Some(FloatTolerance {
+            absolute: 1e-9,
+            relative: 1e-9,
+            match_nan: false,
+        }),
+        toolchain_version: None,
+        metadata: None,
+    };
+    let body = serde_json::to_string(&submission).expect("failed to serialize submission");
+    let request = Builder::new()
+        .header("Content-Type", "application/json")
+        .method(Method::POST)
+        .uri("/submit")
+        .body(Body::from(body))
+        .expect("failed to build request");
+    let expected_status = StatusCode::OK;
+    let expected_body = SubmissionResult::Pass;
+
+    let actual = mozart
+        .oneshot(request)
+        .await
+        .expect("failed to execute oneshot request");
+
+    let actual_status = actual.status();
+    let body_bytes = to_bytes(actual.into_body(), usize::MAX)
+        .await
+        .expect("failed to convert body to bytes");
+
+    let actual_body: SubmissionResult =
+        serde_json::from_slice(&body_bytes).expect("failed to deserialize response body");
+
+    assert_eq!(actual_status, expected_status);
+    assert_eq!(actual_body, expected_body);
+}
+
+#[tokio::test]
+async fn unordered_float_list_within_tolerance() {
+    let mozart = app(AppState::default());
+    let solution = [
+        "std::vector<double> solution(std::vector<double> xs) {",
+        "    for (double& x : xs) {",
+        "        x = 0.1 * x;",
+        "    }",
+        "    return xs;",
+        "}",
+    ]
+    .join("\n");
+    // the floats of the sorted lists are compared element-wise within the tolerance
+    let test_cases = Box::new([TestCase {
+        id: 0,
+        input_parameters: Box::new([Parameter {
+            value_type: ParameterType::List(Box::new(ParameterType::Float)),
+            value: String::from(r#"["3", "1", "2"]"#),
+        }]),
+        output_parameters: Box::new([Parameter {
+            value_type: ParameterType::List(Box::new(ParameterType::Float)),
+            value: String::from(r#"["0.1", "0.2", "0.3"]"#),
+        }]),
+        stdin: None,
+        expected_error: None,
+        label: None,
+        hidden: false,
+    }]);
+    let submission = Submission {
+        solution,
+        test_cases,
+        unordered_lists: true,
+        files: Box::new([]),
+        checker: None,
+        reference_solution: None,
+        only_ids: None,
+        compiler_flags: Box::new([]),
+        debug: false,
+        float_tolerance: Some(FloatTolerance {
+            absolute: 1e-9,
+            relative: 0.0,
+            match_nan: false,
+        }),
+        toolchain_version: None,
+        metadata: None,
+    };
+    let body = serde_json::to_string(&submission).expect("failed to serialize submission");
+    let request = Builder::new()
+        .header("Content-Type", "application/json")
+        .method(Method::POST)
+        .uri("/submit")
+        .body(Body::from(body))
+        .expect("failed to build request");
+    let expected_status = StatusCode::OK;
+    let expected_body = SubmissionResult::Pass;
+
+    let actual = mozart
+        .oneshot(request)
+        .await
+        .expect("failed to execute oneshot request");
+
+    let actual_status = actual.status();
+    let body_bytes = to_bytes(actual.into_body(), usize::MAX)
+        .await
+        .expect("failed to convert body to bytes");
+
+    let actual_body: SubmissionResult =
+        serde_json::from_slice(&body_bytes).expect("failed to deserialize response body");
+
+    assert_eq!(actual_status, expected_status);
+    assert_eq!(actual_body, expected_body);
+}
+
+#[tokio::test]
+async fn toolchain_version() {
+    let mozart = app(AppState::new(Config {
+        toolchain_versions: Box::new([String::from("12")]),
+        ..Config::default()
+    }));
+    let solution = [
+        "long long solution(long long x) {",
+        "    return x * 2;",
+        "}",
+    ]
+    .join("\n");
+    let test_cases = Box::new([TestCase {
+        id: 0,
+        input_parameters: Box::new([Parameter {
+            value_type: ParameterType::Int,
+            value: String::from("2"),
+        }]),
+        output_parameters: Box::new([Parameter {
+            value_type: ParameterType::Int,
+            value: String::from("4"),
+        }]),
+        stdin: None,
+        expected_error: None,
+        label: None,
+        hidden: false,
+    }]);
+    let submission = Submission {
+        solution,
+        test_cases,
+        unordered_lists: false,
+        files: Box::new([]),
+        checker: None,
+        reference_solution: None,
+        only_ids: None,
+        compiler_flags: Box::new([]),
+        debug: false,
+        float_tolerance: None,
+        toolchain_version: Some(String::from("12")),
+        metadata: None,
+    };
+    let body = serde_json::to_string(&submission).expect("failed to serialize submission");
+    let request = Builder::new()
+        .header("Content-Type", "application/json")
+        .method(Method::POST)
+        .uri("/submit")
+        .body(Body::from(body))
+        .expect("failed to build request");
+    let expected_status = StatusCode::OK;
+    let expected_body = SubmissionResult::Pass;
+
+    let actual = mozart
+        .oneshot(request)
+        .await
+        .expect("failed to execute oneshot request");
+
+    let actual_status = actual.status();
+    let body_bytes = to_bytes(actual.into_body(), usize::MAX)
+        .await
+        .expect("failed to convert body to bytes");
+
+    let actual_body: SubmissionResult =
+        serde_json::from_slice(&body_bytes).expect("failed to deserialize response body");
+
+    assert_eq!(actual_status, expected_status);
+    assert_eq!(actual_body, expected_body);
+}
+
+#[tokio::test]
+async fn unknown_toolchain_version() {
+    let mozart = app(AppState::new(Config {
+        toolchain_versions: Box::new([String::from("12")]),
+        ..Config::default()
+    }));
+    let solution = [
+        "long long solution(long long x) {",
+        "    return x * 2;",
+        "}",
+    ]
+    .join("\n");
+    let test_cases = Box::new([TestCase {
+        id: 0,
+        input_parameters: Box::new([Parameter {
+            value_type: ParameterType::Int,
+            value: String::from("2"),
+        }]),
+        output_parameters: Box::new([Parameter {
+            value_type: ParameterType::Int,
+            value: String::from("4"),
+        }]),
+        stdin: None,
+        expected_error: None,
+        label: None,
+        hidden: false,
+    }]);
+    let submission = Submission {
+        solution,
+        test_cases,
+        unordered_lists: false,
+        files: Box::new([]),
+        checker: None,
+        reference_solution: None,
+        only_ids: None,
+        compiler_flags: Box::new([]),
+        debug: false,
+        float_tolerance: None,
+        toolchain_version: Some(String::from("4.8")),
+        metadata: None,
+    };
+    let body = serde_json::to_string(&submission).expect("failed to serialize submission");
+    let request = Builder::new()
+        .header("Content-Type", "application/json")
+        .method(Method::POST)
+        .uri("/submit")
+        .body(Body::from(body))
+        .expect("failed to build request");
+    let expected_status = StatusCode::OK;
+
+    let actual = mozart
+        .oneshot(request)
+        .await
+        .expect("failed to execute oneshot request");
+
+    let actual_status = actual.status();
+    let body_bytes = to_bytes(actual.into_body(), usize::MAX)
+        .await
+        .expect("failed to convert body to bytes");
+
+    let actual_body: SubmissionResult =
+        serde_json::from_slice(&body_bytes).expect("failed to deserialize response body");
+
+    assert_eq!(actual_status, expected_status);
+
+    if let SubmissionResult::Error { code, message } = actual_body {
+        assert_eq!(code, ErrorCode::UnknownToolchainVersion);
+        assert!(message.starts_with("the toolchain version '4.8' is not available"));
+    } else {
+        panic!("response body was not of error variant");
+    }
+}
+
+#[tokio::test]
+async fn structured_wrong_answer_multiple_output_parameters() {
+    let mozart = app(AppState::default());
+    let solution = [
+        "std::tuple<long long, std::string> solution(long long x) {",
+        "    return {x + 1, std::to_string(x)};",
+        "}",
+    ]
+    .join("\n");
+    // the typed values of multiple output parameters are an array
+    let test_cases = Box::new([TestCase {
+        id: 0,
+        input_parameters: Box::new([Parameter {
+            value_type: ParameterType::Int,
+            value: String::from("2"),
+        }]),
+        output_parameters: Box::new([
+            Parameter {
+                value_type: ParameterType::Int,
+                value: String::from("2"),
+            },
+            Parameter {
+                value_type: ParameterType::String,
+                value: String::from("2"),
+            },
+        ]),
+        stdin: None,
+        expected_error: None,
+        label: None,
+        hidden: false,
+    }]);
+    let submission = Submission {
+        solution,
+        test_cases,
+        unordered_lists: false,
+        files: Box::new([]),
+        checker: None,
+        reference_solution: None,
+        only_ids: None,
+        compiler_flags: Box::new([]),
+        debug: false,
+        float_tolerance: None,
+        toolchain_version: None,
+        metadata: None,
+    };
+    let body = serde_json::to_string(&submission).expect("failed to serialize submission");
+    let request = Builder::new()
+        .header("Content-Type", "application/json")
+        .method(Method::POST)
+        .uri("/submit")
+        .body(Body::from(body))
+        .expect("failed to build request");
+    let expected_status = StatusCode::OK;
+    let expected_body = SubmissionResult::Failure(Box::new([TestCaseResult {
+        id: 0,
+        test_result: TestResult::Failure(TestCaseFailureReason::WrongAnswer {
+            input_parameters: Box::new([Parameter {
+                value_type: ParameterType::Int,
+                value: String::from("2"),
+            }]),
+            actual: String::from(r#"(3, "2")"#),
+            expected: String::from(r#"(2, "2")"#),
+            actual_value: Some(json!([3, "2"])),
+            expected_value: Some(json!([2, "2"])),
+        }),
+        label: None,
+    }]));
+
+    let actual = mozart
+        .oneshot(request)
+        .await
+        .expect("failed to execute oneshot request");
+
+    let actual_status = actual.status();
+    let body_bytes = to_bytes(actual.into_body(), usize::MAX)
+        .await
+        .expect("failed to convert body to bytes");
+
+    let actual_body: SubmissionResult =
+        serde_json::from_slice(&body_bytes).expect("failed to deserialize response body");
+
+    assert_eq!(actual_status, expected_status);
+    assert_eq!(actual_body, expected_body);
+}
+
+#[tokio::test]
+async fn structured_wrong_answer_map() {
+    let mozart = app(AppState::default());
+    let solution = [
+        "std::map<long long, bool> solution(long long n) {",
+        "    return {{n, n % 2 == 0}};",
+        "}",
+    ]
+    .join("\n");
+    // the keys of the typed value of a map are the text of the keys
+    let test_cases = Box::new([TestCase {
+        id: 0,
+        input_parameters: Box::new([Parameter {
+            value_type: ParameterType::Int,
+            value: String::from("3"),
+        }]),
+        output_parameters: Box::new([Parameter {
+            value_type: ParameterType::Map {
+                key: Box::new(ParameterType::Int),
+                value: Box::new(ParameterType::Bool),
+            },
+            value: String::from(r#"{"3": "true"}"#),
+        }]),
+        stdin: None,
+        expected_error: None,
+        label: None,
+        hidden: false,
+    }]);
+    let submission = Submission {
+        solution,
+        test_cases,
+        unordered_lists: false,
+        files: Box::new([]),
+        checker: None,
+        reference_solution: None,
+        only_ids: None,
+        compiler_flags: Box::new([]),
+        debug: false,
+        float_tolerance: None,
+        toolchain_version: None,
+        metadata: None,
+    };
+    let body = serde_json::to_string(&submission).expect("failed to serialize submission");
+    let request = Builder::new()
+        .header("Content-Type", "application/json")
+        .method(Method::POST)
+        .uri("/submit")
+        .body(Body::from(body))
+        .expect("failed to build request");
+    let expected_status = StatusCode::OK;
+    let expected_body = SubmissionResult::Failure(Box::new([TestCaseResult {
+        id: 0,
+        test_result: TestResult::Failure(TestCaseFailureReason::WrongAnswer {
+            input_parameters: Box::new([Parameter {
+                value_type: ParameterType::Int,
+                value: String::from("3"),
+            }]),
+            actual: String::from("{3: false}"),
+            expected: String::from("{3: true}"),
+            actual_value: Some(json!({ "3": false })),
+            expected_value: Some(json!({ "3": true })),
+        }),
+        label: None,
+    }]));
+
+    let actual = mozart
+        .oneshot(request)
+        .await
+        .expect("failed to execute oneshot request");
+
+    let actual_status = actual.status();
+    let body_bytes = to_bytes(actual.into_body(), usize::MAX)
+        .await
+        .expect("failed to convert body to bytes");
+
+    let actual_body: SubmissionResult =
+        serde_json::from_slice(&body_bytes).expect("failed to deserialize response body");
+
+    assert_eq!(actual_status, expected_status);
+    assert_eq!(actual_body, expected_body);
+}
+
+#[tokio::test]
+async fn solution_too_long() {
+    let mozart = app(AppState::new(Config {
+        max_solution_length: 10,
+        ..Config::default()
+    }));
+    let solution = [
+        "long long solution(long long x) {",
+        "    return x * 2;",
+        "}",
+    ]
+    .join("\n");
+    let test_cases = Box::new([TestCase {
+        id: 0,
+        input_parameters: Box::new([Parameter {
+            value_type: ParameterType::Int,
+            value: String::from("2"),
+        }]),
+        output_parameters: Box::new([Parameter {
+            value_type: ParameterType::Int,
+            value: String::from("4"),
+        }]),
+        stdin: None,
+        expected_error: None,
+        label: None,
+        hidden: false,
+    }]);
+    let submission = Submission {
+        solution,
+        test_cases,
+        unordered_lists: false,
+        files: Box::new([]),
+        checker: None,
+        reference_solution: None,
+        only_ids: None,
+        compiler_flags: Box::new([]),
+        debug: false,
+        float_tolerance: None,
+        toolchain_version: None,
+        metadata: None,
+    };
+    let body = serde_json::to_string(&submission).expect("failed to serialize submission");
+    let request = Builder::new()
+        .header("Content-Type", "application/json")
+        .method(Method::POST)
+        .uri("/submit")
+        .body(Body::from(body))
+        .expect("failed to build request");
+    let expected_status = StatusCode::OK;
+    let expected_body = SubmissionResult::Error {
+        code: ErrorCode::SolutionTooLong,
+        message: String::from("the solution exceeded the limit of 10 bytes"),
+    };
+
+    let actual = mozart
+        .oneshot(request)
+        .await
+        .expect("failed to execute oneshot request");
+
+    let actual_status = actual.status();
+    let body_bytes = to_bytes(actual.into_body(), usize::MAX)
+        .await
+        .expect("failed to convert body to bytes");
+
+    let actual_body: SubmissionResult =
+        serde_json::from_slice(&body_bytes).expect("failed to deserialize response body");
+
+    assert_eq!(actual_status, expected_status);
+    assert_eq!(actual_body, expected_body);
+}
+
+#[tokio::test]
+async fn quoted_char_and_string() {
+    let mozart = app(AppState::new(Config {
+        quote_values: true,
+        ..Config::default()
+    }));
+    let solution = [
+        "std::string solution(char c, std::string s) {",
+        "    return c + s;",
+        "}",
+    ]
+    .join("\n");
+    // the input parameters are quoted like the actual and expected values
+    let test_cases = Box::new([TestCase {
+        id: 0,
+        input_parameters: Box::new([
+            Parameter {
+                value_type: ParameterType::Char,
+                value: String::from("b"),
+            },
+            Parameter {
+                value_type: ParameterType::String,
+                value: String::from("say hi"),
+            },
+        ]),
+        output_parameters: Box::new([Parameter {
+            value_type: ParameterType::String,
+            value: String::from("x"),
+        }]),
+        stdin: None,
+        expected_error: None,
+        label: None,
+        hidden: false,
+    }]);
+    let submission = Submission {
+        solution,
+        test_cases,
+        unordered_lists: false,
+        files: Box::new([]),
+        checker: None,
+        reference_solution: None,
+        only_ids: None,
+        compiler_flags: Box::new([]),
+        debug: false,
+        float_tolerance: None,
+        toolchain_version: None,
+        metadata: None,
+    };
+    let body = serde_json::to_string(&submission).expect("failed to serialize submission");
+    let request = Builder::new()
+        .header("Content-Type", "application/json")
+        .method(Method::POST)
+        .uri("/submit")
+        .body(Body::from(body))
+        .expect("failed to build request");
+    let expected_status = StatusCode::OK;
+    let expected_body = SubmissionResult::Failure(Box::new([TestCaseResult {
+        id: 0,
+        test_result: TestResult::Failure(TestCaseFailureReason::WrongAnswer {
+            input_parameters: Box::new([
+                Parameter {
+                    value_type: ParameterType::Char,
+                    value: String::from("'b'"),
+                },
+                Parameter {
+                    value_type: ParameterType::String,
+                    value: String::from(r#""say hi""#),
+                },
+            ]),
+            actual: String::from(r#""bsay hi""#),
+            expected: String::from(r#""x""#),
+            actual_value: None,
+            expected_value: None,
+        }),
+        label: None,
+    }]));
+
+    let actual = mozart
+        .oneshot(request)
+        .await
+        .expect("failed to execute oneshot request");
+
+    let actual_status = actual.status();
+    let body_bytes = to_bytes(actual.into_body(), usize::MAX)
+        .await
+        .expect("failed to convert body to bytes");
+
+    let actual_body: SubmissionResult =
+        serde_json::from_slice(&body_bytes).expect("failed to deserialize response body");
+
+    assert_eq!(actual_status, expected_status);
+    assert_eq!(actual_body, expected_body);
+}
+
+#[tokio::test]
+async fn chatty_solution() {
+    let mozart = app(AppState::default());
+    let solution = [
+        "#include <iostream>",
+        "",
+        "long long solution(long long x) {",
+        "    std::cerr << std::string(500000, 'x');",
+        "    return x;",
+        "}",
+    ]
+    .join("\n");
+    // the solution writes far more than the buffer of a pipe, which must not block it until the timeout
+    let test_cases = Box::new([TestCase {
+        id: 0,
+        input_parameters: Box::new([Parameter {
+            value_type: ParameterType::Int,
+            value: String::from("2"),
+        }]),
+        output_parameters: Box::new([Parameter {
+            value_type: ParameterType::Int,
+            value: String::from("2"),
+        }]),
+        stdin: None,
+        expected_error: None,
+        label: None,
+        hidden: false,
+    }]);
+    let submission = Submission {
+        solution,
+        test_cases,
+        unordered_lists: false,
+        files: Box::new([]),
+        checker: None,
+        reference_solution: None,
+        only_ids: None,
+        compiler_flags: Box::new([]),
+        debug: false,
+        float_tolerance: None,
+        toolchain_version: None,
+        metadata: None,
+    };
+    let body = serde_json::to_string(&submission).expect("failed to serialize submission");
+    let request = Builder::new()
+        .header("Content-Type", "application/json")
+        .method(Method::POST)
+        .uri("/submit")
+        .body(Body::from(body))
+        .expect("failed to build request");
+    let expected_status = StatusCode::OK;
+    let expected_body = SubmissionResult::Pass;
+
+    let start = Instant::now();
+    let actual = mozart
+        .oneshot(request)
+        .await
+        .expect("failed to execute oneshot request");
+
+    let actual_status = actual.status();
+    let body_bytes = to_bytes(actual.into_body(), usize::MAX)
+        .await
+        .expect("failed to convert body to bytes");
+
+    let actual_body: SubmissionResult =
+        serde_json::from_slice(&body_bytes).expect("failed to deserialize response body");
+
+    assert_eq!(actual_status, expected_status);
+    assert_eq!(actual_body, expected_body);
+    assert!(start.elapsed() < Config::default().timeout);
+}
+
+#[tokio::test]
+async fn expected_error() {
+    let mozart = app(AppState::default());
+    let solution = [
+        "#include <stdexcept>",
+        "",
+        "long long solution(long long x) {",
+        "    if (x < 0) {",
+        "        throw std::invalid_argument(\"negative input\");",
+        "    }",
+        "    return x + x;",
+        "}",
+    ]
+    .join("\n");
+    // the solution should raise an error for a negative input
+    let test_cases = Box::new([
+        TestCase {
+            id: 0,
+            input_parameters: Box::new([Parameter {
+                value_type: ParameterType::Int,
+                value: String::from("2"),
+            }]),
+            output_parameters: Box::new([Parameter {
+                value_type: ParameterType::Int,
+                value: String::from("4"),
+            }]),
+            stdin: None,
+            expected_error: None,
+            label: None,
+            hidden: false,
+        },
+        TestCase {
+            id: 1,
+            input_parameters: Box::new([Parameter {
+                value_type: ParameterType::Int,
+                value: String::from("-1"),
+            }]),
+            output_parameters: Box::new([]),
+            stdin: None,
+            expected_error: Some(ExpectedError {
+                message: Some(String::from("negative input")),
+            }),
+            label: None,
+            hidden: false,
+        },
+        TestCase {
+            id: 2,
+            input_parameters: Box::new([Parameter {
+                value_type: ParameterType::Int,
+                value: String::from("-3"),
+            }]),
+            output_parameters: Box::new([]),
+            stdin: None,
+            expected_error: Some(ExpectedError { message: None }),
+            label: None,
+            hidden: false,
+        },
+    ]);
+    let submission = Submission {
+        solution,
+        test_cases,
+        unordered_lists: false,
+        files: Box::new([]),
+        checker: None,
+        reference_solution: None,
+        only_ids: None,
+        compiler_flags: Box::new([]),
+        debug: false,
+        float_tolerance: None,
+        toolchain_version: None,
+        metadata: None,
+    };
+    let body = serde_json::to_string(&submission).expect("failed to serialize submission");
+    let request = Builder::new()
+        .header("Content-Type", "application/json")
+        .method(Method::POST)
+        .uri("/submit")
+        .body(Body::from(body))
+        .expect("failed to build request");
+    let expected_status = StatusCode::OK;
+    let expected_body = SubmissionResult::Pass;
+
+    let actual = mozart
+        .oneshot(request)
+        .await
+        .expect("failed to execute oneshot request");
+
+    let actual_status = actual.status();
+    let body_bytes = to_bytes(actual.into_body(), usize::MAX)
+        .await
+        .expect("failed to convert body to bytes");
+
+    let actual_body: SubmissionResult =
+        serde_json::from_slice(&body_bytes).expect("failed to deserialize response body");
+
+    assert_eq!(actual_status, expected_status);
+    assert_eq!(actual_body, expected_body);
+}
+
+#[tokio::test]
+async fn missing_error() {
+    let mozart = app(AppState::default());
+    let solution = [
+        "#include <stdexcept>",
+        "",
+        "long long solution(long long x) {",
+        "    if (x < 0) {",
+        "        throw std::invalid_argument(\"negative input\");",
+        "    }",
+        "    return x + x;",
+        "}",
+    ]
+    .join("\n");
+    let test_cases = Box::new([TestCase {
+        id: 0,
+        input_parameters: Box::new([Parameter {
+            value_type: ParameterType::Int,
+            value: String::from("2"),
+        }]),
+        output_parameters: Box::new([]),
+        stdin: None,
+        expected_error: Some(ExpectedError { message: None }),
+        label: None,
+        hidden: false,
+    }]);
+    let submission = Submission {
+        solution,
+        test_cases,
+        unordered_lists: false,
+        files: Box::new([]),
+        checker: None,
+        reference_solution: None,
+        only_ids: None,
+        compiler_flags: Box::new([]),
+        debug: false,
+        float_tolerance: None,
+        toolchain_version: None,
+        metadata: None,
+    };
+    let body = serde_json::to_string(&submission).expect("failed to serialize submission");
+    let request = Builder::new()
+        .header("Content-Type", "application/json")
+        .method(Method::POST)
+        .uri("/submit")
+        .body(Body::from(body))
+        .expect("failed to build request");
+    let expected_status = StatusCode::OK;
+    let expected_body = SubmissionResult::Failure(Box::new([TestCaseResult {
+        id: 0,
+        test_result: TestResult::Failure(TestCaseFailureReason::MissingError {
+            input_parameters: Box::new([Parameter {
+                value_type: ParameterType::Int,
+                value: String::from("2"),
+            }]),
+            actual: String::from("4"),
+        }),
+        label: None,
+    }]));
+
+    let actual = mozart
+        .oneshot(request)
+        .await
+        .expect("failed to execute oneshot request");
+
+    let actual_status = actual.status();
+    let body_bytes = to_bytes(actual.into_body(), usize::MAX)
+        .await
+        .expect("failed to convert body to bytes");
+
+    let actual_body: SubmissionResult =
+        serde_json::from_slice(&body_bytes).expect("failed to deserialize response body");
+
+    assert_eq!(actual_status, expected_status);
+    assert_eq!(actual_body, expected_body);
+}
+
+#[tokio::test]
+async fn expected_nan() {
+    let mozart = app(AppState::default());
+    let solution = ["double solution(double x) {", "    return x * 0;", "}"].join("\n");
+    // an infinity multiplied by zero is NaN
+    let test_cases = Box::new([TestCase {
+        id: 0,
+        input_parameters: Box::new([Parameter {
+            value_type: ParameterType::Float,
+            value: String::from("Infinity"),
+        }]),
+        output_parameters: Box::new([Parameter {
+            value_type: ParameterType::Float,
+            value: String::from("NaN"),
+        }]),
+        stdin: None,
+        expected_error: None,
+        label: None,
+        hidden: false,
+    }]);
+    let submission = Submission {
+        solution,
+        test_cases,
+        unordered_lists: false,
+        files: Box::new([]),
+        checker: None,
+        reference_solution: None,
+        only_ids: None,
+        compiler_flags: Box::new([]),
+        debug: false,
+        float_tolerance: Some(FloatTolerance {
+            absolute: 0.0,
+            relative: 0.0,
+            match_nan: true,
+        }),
+        toolchain_version: None,
+        metadata: None,
+    };
+    let body = serde_json::to_string(&submission).expect("failed to serialize submission");
+    let request = Builder::new()
+        .header("Content-Type", "application/json")
+        .method(Method::POST)
+        .uri("/submit")
+        .body(Body::from(body))
+        .expect("failed to build request");
+    let expected_status = StatusCode::OK;
+    let expected_body = SubmissionResult::Pass;
+
+    let actual = mozart
+        .oneshot(request)
+        .await
+        .expect("failed to execute oneshot request");
+
+    let actual_status = actual.status();
+    let body_bytes = to_bytes(actual.into_body(), usize::MAX)
+        .await
+        .expect("failed to convert body to bytes");
+
+    let actual_body: SubmissionResult =
+        serde_json::from_slice(&body_bytes).expect("failed to deserialize response body");
+
+    assert_eq!(actual_status, expected_status);
+    assert_eq!(actual_body, expected_body);
+}
+
+#[tokio::test]
+async fn expected_infinity() {
+    let mozart = app(AppState::default());
+    let solution = ["double solution(double x) {", "    return x * 2;", "}"].join("\n");
+    let test_cases = Box::new([
+        TestCase {
+            id: 0,
+            input_parameters: Box::new([Parameter {
+                value_type: ParameterType::Float,
+                value: String::from("Infinity"),
+            }]),
+            output_parameters: Box::new([Parameter {
+                value_type: ParameterType::Float,
+                value: String::from("Infinity"),
+            }]),
+            stdin: None,
+            expected_error: None,
+            label: None,
+            hidden: false,
+        },
+        TestCase {
+            id: 1,
+            input_parameters: Box::new([Parameter {
+                value_type: ParameterType::Float,
+                value: String::from("-Infinity"),
+            }]),
+            output_parameters: Box::new([Parameter {
+                value_type: ParameterType::Float,
+                value: String::from("-Infinity"),
+            }]),
+            stdin: None,
+            expected_error: None,
+            label: None,
+            hidden: false,
+        },
+        TestCase {
+            id: 2,
+            input_parameters: Box::new([Parameter {
+                value_type: ParameterType::Float,
+                value: String::from("1.5"),
+            }]),
+            output_parameters: Box::new([Parameter {
+                value_type: ParameterType::Float,
+                value: String::from("3.0"),
+            }]),
+            stdin: None,
+            expected_error: None,
+            label: None,
+            hidden: false,
+        },
+    ]);
+    let submission = Submission {
+        solution,
+        test_cases,
+        unordered_lists: false,
+        files: Box::new([]),
+        checker: None,
+        reference_solution: None,
+        only_ids: None,
+        compiler_flags: Box::new([]),
+        debug: false,
+        float_tolerance: Some(FloatTolerance {
+            absolute: 0.0,
+            relative: 1e-9,
+            match_nan: false,
+        }),
+        toolchain_version: None,
+        metadata: None,
+    };
+    let body = serde_json::to_string(&submission).expect("failed to serialize submission");
+    let request = Builder::new()
+        .header("Content-Type", "application/json")
+        .method(Method::POST)
+        .uri("/submit")
+        .body(Body::from(body))
+        .expect("failed to build request");
+    let expected_status = StatusCode::OK;
+    let expected_body = SubmissionResult::Pass;
+
+    let actual = mozart
+        .oneshot(request)
+        .await
+        .expect("failed to execute oneshot request");
+
+    let actual_status = actual.status();
+    let body_bytes = to_bytes(actual.into_body(), usize::MAX)
+        .await
+        .expect("failed to convert body to bytes");
+
+    let actual_body: SubmissionResult =
+        serde_json::from_slice(&body_bytes).expect("failed to deserialize response body");
+
+    assert_eq!(actual_status, expected_status);
+    assert_eq!(actual_body, expected_body);
+}
+
+#[tokio::test]
+async fn infinite_loop_in_later_test_case() {
+    let mozart = app(AppState::new(Config {
+        timeout: Duration::from_secs(5),
+        test_case_timeout: Duration::from_secs(30),
+        ..Config::default()
+    }));
+    let solution = [
+        "long long solution(long long x) {",
+        "    // the loop reads a volatile, so that it cannot be optimized away",
+        "    volatile long long y = x;",
+        "    while (y == 0) {",
+        "    }",
+        "    return x;",
+        "}",
+    ]
+    .join("\n");
+    // the execution is killed before the third test case reaches its own timeout, which keeps the results of the first two
+    let test_cases = Box::new([
+        TestCase {
+            id: 0,
+            input_parameters: Box::new([Parameter {
+                value_type: ParameterType::Int,
+                value: String::from("1"),
+            }]),
+            output_parameters: Box::new([Parameter {
+                value_type: ParameterType::Int,
+                value: String::from("1"),
+            }]),
+            stdin: None,
+            expected_error: None,
+            label: None,
+            hidden: false,
+        },
+        TestCase {
+            id: 1,
+            input_parameters: Box::new([Parameter {
+                value_type: ParameterType::Int,
+                value: String::from("2"),
+            }]),
+            output_parameters: Box::new([Parameter {
+                value_type: ParameterType::Int,
+                value: String::from("5"),
+            }]),
+            stdin: None,
+            expected_error: None,
+            label: None,
+            hidden: false,
+        },
+        TestCase {
+            id: 2,
+            input_parameters: Box::new([Parameter {
+                value_type: ParameterType::Int,
+                value: String::from("0"),
+            }]),
+            output_parameters: Box::new([Parameter {
+                value_type: ParameterType::Int,
+                value: String::from("0"),
+            }]),
+            stdin: None,
+            expected_error: None,
+            label: None,
+            hidden: false,
+        },
+        TestCase {
+            id: 3,
+            input_parameters: Box::new([Parameter {
+                value_type: ParameterType::Int,
+                value: String::from("4"),
+            }]),
+            output_parameters: Box::new([Parameter {
+                value_type: ParameterType::Int,
+                value: String::from("4"),
+            }]),
+            stdin: None,
+            expected_error: None,
+            label: None,
+            hidden: false,
+        },
+    ]);
+    let submission = Submission {
+        solution,
+        test_cases,
+        unordered_lists: false,
+        files: Box::new([]),
+        checker: None,
+        reference_solution: None,
+        only_ids: None,
+        compiler_flags: Box::new([]),
+        debug: false,
+        float_tolerance: None,
+        toolchain_version: None,
+        metadata: None,
+    };
+    let body = serde_json::to_string(&submission).expect("failed to serialize submission");
+    let request = Builder::new()
+        .header("Content-Type", "application/json")
+        .method(Method::POST)
+        .uri("/submit")
+        .body(Body::from(body))
+        .expect("failed to build request");
+    let expected_status = StatusCode::OK;
+    let expected_body = SubmissionResult::Failure(Box::new([
+        TestCaseResult {
+            id: 0,
+            test_result: TestResult::Pass,
+            label: None,
+        },
+        TestCaseResult {
+            id: 1,
+            test_result: TestResult::Failure(TestCaseFailureReason::WrongAnswer {
+                input_parameters: Box::new([Parameter {
+                    value_type: ParameterType::Int,
+                    value: String::from("2"),
+                }]),
+                actual: String::from("2"),
+                expected: String::from("5"),
+                actual_value: None,
+                expected_value: None,
+            }),
+            label: None,
+        },
+        TestCaseResult {
+            id: 2,
+            test_result: TestResult::Failure(TestCaseFailureReason::Timeout),
+            label: None,
+        },
+        TestCaseResult {
+            id: 3,
+            test_result: TestResult::Unknown,
+            label: None,
+        },
+    ]));
+
+    let actual = mozart
+        .oneshot(request)
+        .await
+        .expect("failed to execute oneshot request");
+
+    let actual_status = actual.status();
+    let body_bytes = to_bytes(actual.into_body(), usize::MAX)
+        .await
+        .expect("failed to convert body to bytes");
+
+    let actual_body: SubmissionResult =
+        serde_json::from_slice(&body_bytes).expect("failed to deserialize response body");
+
+    assert_eq!(actual_status, expected_status);
+    assert_eq!(actual_body, expected_body);
+}
+
+#[tokio::test]
+async fn decimal_is_not_supported() {
+    let mozart = app(AppState::default());
+    let solution = ["long long solution(long long x) {", "    return x;", "}"].join("\n");
+    // C++ has no exact decimal to represent a Decimal
+    let test_cases = Box::new([TestCase {
+        id: 0,
+        input_parameters: Box::new([Parameter {
+            value_type: ParameterType::Decimal,
+            value: String::from("10.25"),
+        }]),
+        output_parameters: Box::new([Parameter {
+            value_type: ParameterType::Decimal,
+            value: String::from("10.25"),
+        }]),
+        stdin: None,
+        expected_error: None,
+        label: None,
+        hidden: false,
+    }]);
+    let submission = Submission {
+        solution,
+        test_cases,
+        unordered_lists: false,
+        files: Box::new([]),
+        checker: None,
+        reference_solution: None,
+        only_ids: None,
+        compiler_flags: Box::new([]),
+        debug: false,
+        float_tolerance: None,
+        toolchain_version: None,
+        metadata: None,
+    };
+    let body = serde_json::to_string(&submission).expect("failed to serialize submission");
+    let request = Builder::new()
+        .header("Content-Type", "application/json")
+        .method(Method::POST)
+        .uri("/submit")
+        .body(Body::from(body))
+        .expect("failed to build request");
+    let expected_status = StatusCode::OK;
+
+    let actual = mozart
+        .oneshot(request)
+        .await
+        .expect("failed to execute oneshot request");
+
+    let actual_status = actual.status();
+    let body_bytes = to_bytes(actual.into_body(), usize::MAX)
+        .await
+        .expect("failed to convert body to bytes");
+
+    let actual_body: SubmissionResult =
+        serde_json::from_slice(&body_bytes).expect("failed to deserialize response body");
+
+    assert_eq!(actual_status, expected_status);
+
+    if let SubmissionResult::Error { code, message } = actual_body {
+        assert_eq!(code, ErrorCode::InvalidParameter);
+        assert!(message.starts_with(
+            "invalid parameter: test case 0, input parameter 0: the Decimal type is not supported"
+        ));
+    } else {
+        panic!("response body was not of error variant");
+    }
+}
+
+#[tokio::test]
+async fn labels_are_echoed() {
+    let mozart = app(AppState::default());
+    let solution = [
+        "long long solution(long long x) {",
+        "    return x + x;",
+        "}",
+    ]
+    .join("\n");
+    let test_cases = Box::new([
+        TestCase {
+            id: 0,
+            input_parameters: Box::new([Parameter {
+                value_type: ParameterType::Int,
+                value: String::from("0"),
+            }]),
+            output_parameters: Box::new([Parameter {
+                value_type: ParameterType::Int,
+                value: String::from("0"),
+            }]),
+            stdin: None,
+            expected_error: None,
+            label: Some(String::from("edge case")),
+            hidden: false,
+        },
+        TestCase {
+            id: 1,
+            input_parameters: Box::new([Parameter {
+                value_type: ParameterType::Int,
+                value: String::from("5"),
+            }]),
+            output_parameters: Box::new([Parameter {
+                value_type: ParameterType::Int,
+                value: String::from("11"),
+            }]),
+            stdin: None,
+            expected_error: None,
+            label: Some(String::from("hidden")),
+            hidden: false,
+        },
+        TestCase {
+            id: 2,
+            input_parameters: Box::new([Parameter {
+                value_type: ParameterType::Int,
+                value: String::from("2"),
+            }]),
+            output_parameters: Box::new([Parameter {
+                value_type: ParameterType::Int,
+                value: String::from("4"),
+            }]),
+            stdin: None,
+            expected_error: None,
+            label: None,
+            hidden: false,
+        },
+    ]);
+    let submission = Submission {
+        solution,
+        test_cases,
+        unordered_lists: false,
+        files: Box::new([]),
+        checker: None,
+        reference_solution: None,
+        only_ids: None,
+        compiler_flags: Box::new([]),
+        debug: false,
+        float_tolerance: None,
+        toolchain_version: None,
+        metadata: None,
+    };
+    let body = serde_json::to_string(&submission).expect("failed to serialize submission");
+    let request = Builder::new()
+        .header("Content-Type", "application/json")
+        .method(Method::POST)
+        .uri("/submit")
+        .body(Body::from(body))
+        .expect("failed to build request");
+    let expected_status = StatusCode::OK;
+    let expected_body = SubmissionResult::Failure(Box::new([
+        TestCaseResult {
+            id: 0,
+            test_result: TestResult::Pass,
+            label: Some(String::from("edge case")),
+        },
+        TestCaseResult {
+            id: 1,
+            test_result: TestResult::Failure(TestCaseFailureReason::WrongAnswer {
+                input_parameters: Box::new([Parameter {
+                    value_type: ParameterType::Int,
+                    value: String::from("5"),
+                }]),
+                actual: String::from("10"),
+                expected: String::from("11"),
+                actual_value: None,
+                expected_value: None,
+            }),
+            label: Some(String::from("hidden")),
+        },
+        TestCaseResult {
+            id: 2,
+            test_result: TestResult::Pass,
+            label: None,
+        },
+    ]));
+
+    let actual = mozart
+        .oneshot(request)
+        .await
+        .expect("failed to execute oneshot request");
+
+    let actual_status = actual.status();
+    let body_bytes = to_bytes(actual.into_body(), usize::MAX)
+        .await
+        .expect("failed to convert body to bytes");
+
+    let actual_body: SubmissionResult =
+        serde_json::from_slice(&body_bytes).expect("failed to deserialize response body");
+
+    assert_eq!(actual_status, expected_status);
+    assert_eq!(actual_body, expected_body);
+}
+
+#[tokio::test]
+async fn stdout_is_returned() {
+    let mozart = app(AppState::default());
+    let solution = [
+        "#include <iostream>",
+        "",
+        "long long solution(long long x) {",
+        "    std::cout << \"debug \" << x << std::endl;",
+        "    return x + x;",
+        "}",
+    ]
+    .join("\n");
+    let test_cases = Box::new([
+        TestCase {
+            id: 0,
+            input_parameters: Box::new([Parameter {
+                value_type: ParameterType::Int,
+                value: String::from("2"),
+            }]),
+            output_parameters: Box::new([Parameter {
+                value_type: ParameterType::Int,
+                value: String::from("4"),
+            }]),
+            stdin: None,
+            expected_error: None,
+            label: None,
+            hidden: false,
+        },
+        TestCase {
+            id: 1,
+            input_parameters: Box::new([Parameter {
+                value_type: ParameterType::Int,
+                value: String::from("5"),
+            }]),
+            output_parameters: Box::new([Parameter {
+                value_type: ParameterType::Int,
+                value: String::from("10"),
+            }]),
+            stdin: None,
+            expected_error: None,
+            label: None,
+            hidden: false,
+        },
+    ]);
+    let submission = Submission {
+        solution,
+        test_cases,
+        unordered_lists: false,
+        files: Box::new([]),
+        checker: None,
+        reference_solution: None,
+        only_ids: None,
+        compiler_flags: Box::new([]),
+        debug: false,
+        float_tolerance: None,
+        toolchain_version: None,
+        metadata: None,
+    };
+    let body = serde_json::to_string(&submission).expect("failed to serialize submission");
+    let request = Builder::new()
+        .header("Content-Type", "application/json")
+        .method(Method::POST)
+        .uri("/submit")
+        .body(Body::from(body))
+        .expect("failed to build request");
+    let expected_status = StatusCode::OK;
+    let expected_result = SubmissionResult::Pass;
+    let expected_stdout = "debug 2\ndebug 5\n";
+
+    let actual = mozart
+        .oneshot(request)
+        .await
+        .expect("failed to execute oneshot request");
+
+    let actual_status = actual.status();
+    let body_bytes = to_bytes(actual.into_body(), usize::MAX)
+        .await
+        .expect("failed to convert body to bytes");
+
+    let actual_body: Value =
+        serde_json::from_slice(&body_bytes).expect("failed to deserialize response body");
+    let actual_result: SubmissionResult =
+        serde_json::from_slice(&body_bytes).expect("failed to deserialize result");
+
+    assert_eq!(actual_status, expected_status);
+    assert_eq!(actual_result, expected_result);
+    assert_eq!(actual_body["stdout"], expected_stdout);
+}
+
+#[tokio::test]
+async fn optional_is_not_supported() {
+    let mozart = app(AppState::default());
+    let solution = ["long long solution(long long x) {", "    return x;", "}"].join("\n");
+    // the C++ test runner cannot display or compare an optional value
+    let test_cases = Box::new([TestCase {
+        id: 0,
+        input_parameters: Box::new([Parameter {
+            value_type: ParameterType::Optional(Box::new(ParameterType::Int)),
+            value: String::from("null"),
+        }]),
+        output_parameters: Box::new([Parameter {
+            value_type: ParameterType::Optional(Box::new(ParameterType::Int)),
+            value: String::from("null"),
+        }]),
+        stdin: None,
+        expected_error: None,
+        label: None,
+        hidden: false,
+    }]);
+    let submission = Submission {
+        solution,
+        test_cases,
+        unordered_lists: false,
+        files: Box::new([]),
+        checker: None,
+        reference_solution: None,
+        only_ids: None,
+        compiler_flags: Box::new([]),
+        debug: false,
+        float_tolerance: None,
+        toolchain_version: None,
+        metadata: None,
+    };
+    let body = serde_json::to_string(&submission).expect("failed to serialize submission");
+    let request = Builder::new()
+        .header("Content-Type", "application/json")
+        .method(Method::POST)
+        .uri("/submit")
+        .body(Body::from(body))
+        .expect("failed to build request");
+    let expected_status = StatusCode::OK;
+
+    let actual = mozart
+        .oneshot(request)
+        .await
+        .expect("failed to execute oneshot request");
+
+    let actual_status = actual.status();
+    let body_bytes = to_bytes(actual.into_body(), usize::MAX)
+        .await
+        .expect("failed to convert body to bytes");
+
+    let actual_body: SubmissionResult =
+        serde_json::from_slice(&body_bytes).expect("failed to deserialize response body");
+
+    assert_eq!(actual_status, expected_status);
+
+    if let SubmissionResult::Error { code, message } = actual_body {
+        assert_eq!(code, ErrorCode::InvalidParameter);
+        assert!(message.starts_with(
+            "invalid parameter: test case 0, input parameter 0: the Optional(Int) type is not supported"
+        ));
+    } else {
+        panic!("response body was not of error variant");
+    }
+}
+
+#[tokio::test]
+async fn int_overflowing_value() {
+    let mozart = app(AppState::default());
+    let solution = String::from("long long solution(long long x) {\n    return x * 2;\n}");
+    // the value would be written as a literal that does not fit in 64 bits, which fails to compile
+    let test_cases = Box::new([TestCase {
+        id: 0,
+        input_parameters: Box::new([Parameter {
+            value_type: ParameterType::Int,
+            value: String::from("9223372036854775808"),
+        }]),
+        output_parameters: Box::new([Parameter {
+            value_type: ParameterType::Int,
+            value: String::from("0"),
+        }]),
+        stdin: None,
+        expected_error: None,
+        label: None,
+        hidden: false,
+    }]);
+    let submission = Submission {
+        solution,
+        test_cases,
+        unordered_lists: false,
+        files: Box::new([]),
+        checker: None,
+        reference_solution: None,
+        only_ids: None,
+        compiler_flags: Box::new([]),
+        debug: false,
+        float_tolerance: None,
+        toolchain_version: None,
+        metadata: None,
+    };
+    let body = serde_json::to_string(&submission).expect("failed to serialize submission");
+    let request = Builder::new()
+        .header("Content-Type", "application/json")
+        .method(Method::POST)
+        .uri("/submit")
+        .body(Body::from(body))
+        .expect("failed to build request");
+    let expected_status = StatusCode::OK;
+
+    let actual = mozart
+        .oneshot(request)
+        .await
+        .expect("failed to execute oneshot request");
+
+    let actual_status = actual.status();
+    let body_bytes = to_bytes(actual.into_body(), usize::MAX)
+        .await
+        .expect("failed to convert body to bytes");
+
+    let actual_body: SubmissionResult =
+        serde_json::from_slice(&body_bytes).expect("failed to deserialize response body");
+
+    assert_eq!(actual_status, expected_status);
+
+    if let SubmissionResult::Error { code, message } = actual_body {
+        assert_eq!(code, ErrorCode::InvalidParameter);
+        assert!(message.starts_with(
+            "invalid parameter: test case 0, input parameter 0: '9223372036854775808' is not a valid Int"
+        ));
+    } else {
+        panic!("response body was not of error variant");
+    }
+}
+
+#[tokio::test]
+async fn char_non_ascii_value() {
+    let mozart = app(AppState::default());
+    let solution = String::from("char solution(char c) {\n    return c;\n}");
+    // a C++ char is a single byte, so the value would be a multi-character literal narrowed to a garbage value
+    let test_cases = Box::new([TestCase {
+        id: 0,
+        input_parameters: Box::new([Parameter {
+            value_type: ParameterType::Char,
+            value: String::from("é"),
+        }]),
+        output_parameters: Box::new([Parameter {
+            value_type: ParameterType::Char,
+            value: String::from("é"),
+        }]),
+        stdin: None,
+        expected_error: None,
+        label: None,
+        hidden: false,
+    }]);
+    let submission = Submission {
+        solution,
+        test_cases,
+        unordered_lists: false,
+        files: Box::new([]),
+        checker: None,
+        reference_solution: None,
+        only_ids: None,
+        compiler_flags: Box::new([]),
+        debug: false,
+        float_tolerance: None,
+        toolchain_version: None,
+        metadata: None,
+    };
+    let body = serde_json::to_string(&submission).expect("failed to serialize submission");
+    let request = Builder::new()
+        .header("Content-Type", "application/json")
+        .method(Method::POST)
+        .uri("/submit")
+        .body(Body::from(body))
+        .expect("failed to build request");
+    let expected_status = StatusCode::OK;
+
+    let actual = mozart
+        .oneshot(request)
+        .await
+        .expect("failed to execute oneshot request");
+
+    let actual_status = actual.status();
+    let body_bytes = to_bytes(actual.into_body(), usize::MAX)
+        .await
+        .expect("failed to convert body to bytes");
+
+    let actual_body: SubmissionResult =
+        serde_json::from_slice(&body_bytes).expect("failed to deserialize response body");
+
+    assert_eq!(actual_status, expected_status);
+
+    if let SubmissionResult::Error { code, message } = actual_body {
+        assert_eq!(code, ErrorCode::InvalidParameter);
+        assert!(message.starts_with(
+            "invalid parameter: test case 0, input parameter 0: 'é' is not a supported Char"
+        ));
+    } else {
+        panic!("response body was not of error variant");
+    }
+}
@@ -3,18 +3,30 @@ use axum::{
     http::{request::Builder, Method, StatusCode},
 };
 use mozart::{
-    app,
+    app, check_submission,
+    config::Config,
     model::{
-        Parameter, ParameterType, Submission, TestCase, TestCaseFailureReason, TestCaseResult,
-        TestResult,
+        ExpectedError, FloatTolerance, Parameter, ParameterType, SourceFile, Submission, TestCase,
+        TestCaseFailureReason, TestCaseResult, TestResult,
     },
-    response::SubmissionResult,
+    response::{ErrorCode, SubmissionResult},
+    serve_until,
+    state::AppState,
+    TOTAL_TIME_HEADER,
+};
+use serde_json::{json, Value};
+use std::time::Duration;
+use tokio::{
+    io::{AsyncReadExt, AsyncWriteExt},
+    net::{TcpListener, TcpStream},
+    sync::oneshot,
+    time::sleep,
 };
 use tower::ServiceExt;
 
 #[tokio::test]
 async fn invalid_http_method() {
-    let mozart = app();
+    let mozart = app(AppState::default());
     let expected_status_code = StatusCode::METHOD_NOT_ALLOWED;
     let request = Builder::new()
         .method(Method::GET)
@@ -32,7 +44,7 @@ async fn invalid_http_method() {
 
 #[tokio::test]
 async fn no_json_header() {
-    let mozart = app();
+    let mozart = app(AppState::default());
     let expected_status_code = StatusCode::UNSUPPORTED_MEDIA_TYPE;
     let request = Builder::new()
         .method(Method::POST)
@@ -50,7 +62,7 @@ async fn no_json_header() {
 
 #[tokio::test]
 async fn empty_request_body() {
-    let mozart = app();
+    let mozart = app(AppState::default());
     let expected_status_code = StatusCode::BAD_REQUEST;
     let request = Builder::new()
         .method(Method::POST)
@@ -64,12 +76,27 @@ async fn empty_request_body() {
         .await
         .expect("failed to await oneshot");
 
-    assert_eq!(actual.status(), expected_status_code);
+    let actual_status = actual.status();
+    let body_bytes = to_bytes(actual.into_body(), usize::MAX)
+        .await
+        .expect("failed to convert body to bytes");
+
+    let actual_body: SubmissionResult =
+        serde_json::from_slice(&body_bytes).expect("failed to deserialize response body");
+
+    assert_eq!(actual_status, expected_status_code);
+
+    if let SubmissionResult::Error { code, message } = actual_body {
+        assert_eq!(code, ErrorCode::InvalidRequest);
+        assert!(message.starts_with("Failed to parse the request body as JSON"));
+    } else {
+        panic!("response body was not of error variant");
+    }
 }
 
 #[tokio::test]
 async fn invalid_json() {
-    let mozart = app();
+    let mozart = app(AppState::default());
     let expected_status_code = StatusCode::UNPROCESSABLE_ENTITY;
     let body = serde_json::to_string(&ParameterType::Int).expect("failed to serialize body");
     let request = Builder::new()
@@ -84,12 +111,27 @@ async fn invalid_json() {
         .await
         .expect("failed to await oneshot");
 
-    assert_eq!(actual.status(), expected_status_code);
+    let actual_status = actual.status();
+    let body_bytes = to_bytes(actual.into_body(), usize::MAX)
+        .await
+        .expect("failed to convert body to bytes");
+
+    let actual_body: SubmissionResult =
+        serde_json::from_slice(&body_bytes).expect("failed to deserialize response body");
+
+    assert_eq!(actual_status, expected_status_code);
+
+    if let SubmissionResult::Error { code, message } = actual_body {
+        assert_eq!(code, ErrorCode::InvalidRequest);
+        assert!(message.starts_with("Failed to deserialize the JSON body into the target type"));
+    } else {
+        panic!("response body was not of error variant");
+    }
 }
 
 #[tokio::test]
 async fn solution_with_all_data_types_as_input() {
-    let mozart = app();
+    let mozart = app(AppState::default());
     let solution = [
             "module Solution where",
             "",
@@ -124,10 +166,24 @@ async fn solution_with_all_data_types_as_input() {
             value_type: ParameterType::String,
             value: String::from("105.5Truefhello"),
         }]),
+        stdin: None,
+        expected_error: None,
+        label: None,
+        hidden: false,
     }]);
     let submission = Submission {
         solution,
         test_cases,
+        unordered_lists: false,
+        files: Box::new([]),
+        checker: None,
+        reference_solution: None,
+        only_ids: None,
+        compiler_flags: Box::new([]),
+        debug: false,
+        float_tolerance: None,
+        toolchain_version: None,
+        metadata: None,
     };
     let body = serde_json::to_string(&submission).expect("failed to serialize submission");
     let request = Builder::new()
@@ -158,7 +214,7 @@ async fn solution_with_all_data_types_as_input() {
 
 #[tokio::test]
 async fn solution_with_all_data_types_as_output_and_no_input() {
-    let mozart = app();
+    let mozart = app(AppState::default());
     let solution = [
         "module Solution where",
         "",
@@ -191,10 +247,24 @@ async fn solution_with_all_data_types_as_output_and_no_input() {
                 value: String::from("hhh"),
             },
         ]),
+        stdin: None,
+        expected_error: None,
+        label: None,
+        hidden: false,
     }]);
     let submission = Submission {
         solution,
         test_cases,
+        unordered_lists: false,
+        files: Box::new([]),
+        checker: None,
+        reference_solution: None,
+        only_ids: None,
+        compiler_flags: Box::new([]),
+        debug: false,
+        float_tolerance: None,
+        toolchain_version: None,
+        metadata: None,
     };
     let body = serde_json::to_string(&submission).expect("failed to serialize submission");
     let request = Builder::new()
@@ -225,7 +295,7 @@ async fn solution_with_all_data_types_as_output_and_no_input() {
 
 #[tokio::test]
 async fn compilation_error() {
-    let mozart = app();
+    let mozart = app(AppState::default());
     let solution = [
         "module Solution where",
         "",
@@ -247,6 +317,10 @@ async fn compilation_error() {
                 value_type: ParameterType::Int,
                 value: String::from("10"),
             }]),
+            stdin: None,
+            expected_error: None,
+            label: None,
+            hidden: false,
         },
         TestCase {
             id: 1,
@@ -258,11 +332,25 @@ async fn compilation_error() {
                 value_type: ParameterType::Int,
                 value: String::from("10"),
             }]),
+            stdin: None,
+            expected_error: None,
+            label: None,
+            hidden: false,
         },
     ]);
     let submission = Submission {
         solution,
         test_cases,
+        unordered_lists: false,
+        files: Box::new([]),
+        checker: None,
+        reference_solution: None,
+        only_ids: None,
+        compiler_flags: Box::new([]),
+        debug: false,
+        float_tolerance: None,
+        toolchain_version: None,
+        metadata: None,
     };
     let body = serde_json::to_string(&submission).expect("failed to serialize submission");
     let request = Builder::new()
@@ -288,16 +376,25 @@ async fn compilation_error() {
 
     assert_eq!(actual_status, expected_status);
 
-    if let SubmissionResult::Error(err) = actual_body {
-        assert!(err.starts_with("an error occurred during compilation:"));
+    if let SubmissionResult::CompilationError {
+        message,
+        diagnostics,
+    } = actual_body
+    {
+        assert!(message.starts_with("an error occurred during compilation:"));
+        assert!(!diagnostics.is_empty());
     } else {
-        panic!("response body was not of error variant");
+        panic!("response body was not of compilation error variant");
     }
 }
 
 #[tokio::test]
 async fn compile_timeout() {
-    let mozart = app();
+    // the solution is longer than the default maximum length, which would reject it before it is compiled
+    let mozart = app(AppState::new(Config {
+        max_solution_length: usize::MAX,
+        ..Config::default()
+    }));
     let repeated = "  + x\n".repeat(100000);
     let solution = [
         "module Solution where",
@@ -320,6 +417,10 @@ async fn compile_timeout() {
                 value_type: ParameterType::Int,
                 value: String::from("10"),
             }]),
+            stdin: None,
+            expected_error: None,
+            label: None,
+            hidden: false,
         },
         TestCase {
             id: 1,
@@ -331,11 +432,25 @@ async fn compile_timeout() {
                 value_type: ParameterType::Int,
                 value: String::from("10"),
             }]),
+            stdin: None,
+            expected_error: None,
+            label: None,
+            hidden: false,
         },
     ]);
     let submission = Submission {
         solution,
         test_cases,
+        unordered_lists: false,
+        files: Box::new([]),
+        checker: None,
+        reference_solution: None,
+        only_ids: None,
+        compiler_flags: Box::new([]),
+        debug: false,
+        float_tolerance: None,
+        toolchain_version: None,
+        metadata: None,
     };
     let body = serde_json::to_string(&submission).expect("failed to serialize submission");
     let request = Builder::new()
@@ -361,8 +476,8 @@ async fn compile_timeout() {
 
     assert_eq!(actual_status, expected_status);
 
-    if let SubmissionResult::Error(err) = actual_body {
-        assert!(err.starts_with("compilation exceeded the timeout limit of"));
+    if let SubmissionResult::Error { code, .. } = actual_body {
+        assert_eq!(code, ErrorCode::CompileTimeout);
     } else {
         panic!("response body was not of error variant");
     }
@@ -370,7 +485,7 @@ async fn compile_timeout() {
 
 #[tokio::test]
 async fn execution_timeout() {
-    let mozart = app();
+    let mozart = app(AppState::default());
     let solution = [
         "module Solution where",
         "",
@@ -390,6 +505,10 @@ async fn execution_timeout() {
                 value_type: ParameterType::Int,
                 value: String::from("10"),
             }]),
+            stdin: None,
+            expected_error: None,
+            label: None,
+            hidden: false,
         },
         TestCase {
             id: 1,
@@ -401,11 +520,25 @@ async fn execution_timeout() {
                 value_type: ParameterType::Int,
                 value: String::from("10"),
             }]),
+            stdin: None,
+            expected_error: None,
+            label: None,
+            hidden: false,
         },
     ]);
     let submission = Submission {
         solution,
         test_cases,
+        unordered_lists: false,
+        files: Box::new([]),
+        checker: None,
+        reference_solution: None,
+        only_ids: None,
+        compiler_flags: Box::new([]),
+        debug: false,
+        float_tolerance: None,
+        toolchain_version: None,
+        metadata: None,
     };
     let body = serde_json::to_string(&submission).expect("failed to serialize submission");
     let request = Builder::new()
@@ -415,6 +548,18 @@ async fn execution_timeout() {
         .body(Body::from(body))
         .expect("failed to build request");
     let expected_status = StatusCode::OK;
+    let expected_body = SubmissionResult::Failure(Box::new([
+        TestCaseResult {
+            id: 0,
+            test_result: TestResult::Failure(TestCaseFailureReason::Timeout),
+            label: None,
+        },
+        TestCaseResult {
+            id: 1,
+            test_result: TestResult::Failure(TestCaseFailureReason::Timeout),
+            label: None,
+        },
+    ]));
 
     let actual = mozart
         .oneshot(request)
@@ -430,17 +575,12 @@ async fn execution_timeout() {
         serde_json::from_slice(&body_bytes).expect("failed to deserialize response body");
 
     assert_eq!(actual_status, expected_status);
-
-    if let SubmissionResult::Error(err) = actual_body {
-        assert!(err.starts_with("execution exceeded the timeout limit of"));
-    } else {
-        panic!("response body was not of error variant");
-    }
+    assert_eq!(actual_body, expected_body);
 }
 
 #[tokio::test]
 async fn all_test_cases_pass_int() {
-    let mozart = app();
+    let mozart = app(AppState::default());
     let solution = [
         "module Solution where",
         "",
@@ -459,6 +599,10 @@ async fn all_test_cases_pass_int() {
                 value_type: ParameterType::Int,
                 value: String::from("20"),
             }]),
+            stdin: None,
+            expected_error: None,
+            label: None,
+            hidden: false,
         },
         TestCase {
             id: 1,
@@ -470,11 +614,25 @@ async fn all_test_cases_pass_int() {
                 value_type: ParameterType::Int,
                 value: String::from("10"),
             }]),
+            stdin: None,
+            expected_error: None,
+            label: None,
+            hidden: false,
         },
     ]);
     let submission = Submission {
         solution,
         test_cases,
+        unordered_lists: false,
+        files: Box::new([]),
+        checker: None,
+        reference_solution: None,
+        only_ids: None,
+        compiler_flags: Box::new([]),
+        debug: false,
+        float_tolerance: None,
+        toolchain_version: None,
+        metadata: None,
     };
     let body = serde_json::to_string(&submission).expect("failed to serialize submission");
     let request = Builder::new()
@@ -505,7 +663,7 @@ async fn all_test_cases_pass_int() {
 
 #[tokio::test]
 async fn all_test_cases_pass_bool() {
-    let mozart = app();
+    let mozart = app(AppState::default());
     let solution = [
         "module Solution where",
         "",
@@ -524,6 +682,10 @@ async fn all_test_cases_pass_bool() {
                 value_type: ParameterType::Bool,
                 value: String::from("false"),
             }]),
+            stdin: None,
+            expected_error: None,
+            label: None,
+            hidden: false,
         },
         TestCase {
             id: 1,
@@ -535,11 +697,25 @@ async fn all_test_cases_pass_bool() {
                 value_type: ParameterType::Bool,
                 value: String::from("true"),
             }]),
+            stdin: None,
+            expected_error: None,
+            label: None,
+            hidden: false,
         },
     ]);
     let submission = Submission {
         solution,
         test_cases,
+        unordered_lists: false,
+        files: Box::new([]),
+        checker: None,
+        reference_solution: None,
+        only_ids: None,
+        compiler_flags: Box::new([]),
+        debug: false,
+        float_tolerance: None,
+        toolchain_version: None,
+        metadata: None,
     };
     let body = serde_json::to_string(&submission).expect("failed to serialize submission");
     let request = Builder::new()
@@ -570,7 +746,7 @@ async fn all_test_cases_pass_bool() {
 
 #[tokio::test]
 async fn all_test_cases_pass_float() {
-    let mozart = app();
+    let mozart = app(AppState::default());
     let solution = [
         "module Solution where",
         "",
@@ -589,6 +765,10 @@ async fn all_test_cases_pass_float() {
                 value_type: ParameterType::Float,
                 value: String::from("5.0"),
             }]),
+            stdin: None,
+            expected_error: None,
+            label: None,
+            hidden: false,
         },
         TestCase {
             id: 1,
@@ -600,11 +780,25 @@ async fn all_test_cases_pass_float() {
                 value_type: ParameterType::Float,
                 value: String::from("6.6"),
             }]),
+            stdin: None,
+            expected_error: None,
+            label: None,
+            hidden: false,
         },
     ]);
     let submission = Submission {
         solution,
         test_cases,
+        unordered_lists: false,
+        files: Box::new([]),
+        checker: None,
+        reference_solution: None,
+        only_ids: None,
+        compiler_flags: Box::new([]),
+        debug: false,
+        float_tolerance: None,
+        toolchain_version: None,
+        metadata: None,
     };
     let body = serde_json::to_string(&submission).expect("failed to serialize submission");
     let request = Builder::new()
@@ -635,7 +829,7 @@ async fn all_test_cases_pass_float() {
 
 #[tokio::test]
 async fn all_test_cases_pass_char() {
-    let mozart = app();
+    let mozart = app(AppState::default());
     let solution = [
         "module Solution where",
         "",
@@ -654,6 +848,10 @@ async fn all_test_cases_pass_char() {
                 value_type: ParameterType::Char,
                 value: String::from("a"),
             }]),
+            stdin: None,
+            expected_error: None,
+            label: None,
+            hidden: false,
         },
         TestCase {
             id: 1,
@@ -665,11 +863,25 @@ async fn all_test_cases_pass_char() {
                 value_type: ParameterType::Char,
                 value: String::from("b"),
             }]),
+            stdin: None,
+            expected_error: None,
+            label: None,
+            hidden: false,
         },
     ]);
     let submission = Submission {
         solution,
         test_cases,
+        unordered_lists: false,
+        files: Box::new([]),
+        checker: None,
+        reference_solution: None,
+        only_ids: None,
+        compiler_flags: Box::new([]),
+        debug: false,
+        float_tolerance: None,
+        toolchain_version: None,
+        metadata: None,
     };
     let body = serde_json::to_string(&submission).expect("failed to serialize submission");
     let request = Builder::new()
@@ -700,7 +912,7 @@ async fn all_test_cases_pass_char() {
 
 #[tokio::test]
 async fn all_test_cases_pass_string() {
-    let mozart = app();
+    let mozart = app(AppState::default());
     let solution = [
         "module Solution where",
         "",
@@ -719,6 +931,10 @@ async fn all_test_cases_pass_string() {
                 value_type: ParameterType::String,
                 value: String::from("hellohello"),
             }]),
+            stdin: None,
+            expected_error: None,
+            label: None,
+            hidden: false,
         },
         TestCase {
             id: 1,
@@ -730,11 +946,25 @@ async fn all_test_cases_pass_string() {
                 value_type: ParameterType::String,
                 value: String::from("worldworld"),
             }]),
+            stdin: None,
+            expected_error: None,
+            label: None,
+            hidden: false,
         },
     ]);
     let submission = Submission {
         solution,
         test_cases,
+        unordered_lists: false,
+        files: Box::new([]),
+        checker: None,
+        reference_solution: None,
+        only_ids: None,
+        compiler_flags: Box::new([]),
+        debug: false,
+        float_tolerance: None,
+        toolchain_version: None,
+        metadata: None,
     };
     let body = serde_json::to_string(&submission).expect("failed to serialize submission");
     let request = Builder::new()
@@ -765,7 +995,7 @@ async fn all_test_cases_pass_string() {
 
 #[tokio::test]
 async fn all_test_cases_fail_int() {
-    let mozart = app();
+    let mozart = app(AppState::default());
     let solution = [
         "module Solution where",
         "",
@@ -784,6 +1014,10 @@ async fn all_test_cases_fail_int() {
                 value_type: ParameterType::Int,
                 value: String::from("20"),
             }]),
+            stdin: None,
+            expected_error: None,
+            label: None,
+            hidden: false,
         },
         TestCase {
             id: 1,
@@ -795,11 +1029,25 @@ async fn all_test_cases_fail_int() {
                 value_type: ParameterType::Int,
                 value: String::from("10"),
             }]),
+            stdin: None,
+            expected_error: None,
+            label: None,
+            hidden: false,
         },
     ]);
     let submission = Submission {
         solution,
         test_cases,
+        unordered_lists: false,
+        files: Box::new([]),
+        checker: None,
+        reference_solution: None,
+        only_ids: None,
+        compiler_flags: Box::new([]),
+        debug: false,
+        float_tolerance: None,
+        toolchain_version: None,
+        metadata: None,
     };
     let body = serde_json::to_string(&submission).expect("failed to serialize submission");
     let request = Builder::new()
@@ -819,7 +1067,10 @@ async fn all_test_cases_fail_int() {
                 }]),
                 actual: String::from("10"),
                 expected: String::from("20"),
+                actual_value: None,
+                expected_value: None,
             }),
+            label: None,
         },
         TestCaseResult {
             id: 1,
@@ -830,7 +1081,10 @@ async fn all_test_cases_fail_int() {
                 }]),
                 actual: String::from("5"),
                 expected: String::from("10"),
+                actual_value: None,
+                expected_value: None,
             }),
+            label: None,
         },
     ]));
 
@@ -853,7 +1107,7 @@ async fn all_test_cases_fail_int() {
 
 #[tokio::test]
 async fn all_test_cases_fail_bool() {
-    let mozart = app();
+    let mozart = app(AppState::default());
     let solution = [
         "module Solution where",
         "",
@@ -872,6 +1126,10 @@ async fn all_test_cases_fail_bool() {
                 value_type: ParameterType::Bool,
                 value: String::from("false"),
             }]),
+            stdin: None,
+            expected_error: None,
+            label: None,
+            hidden: false,
         },
         TestCase {
             id: 1,
@@ -883,11 +1141,25 @@ async fn all_test_cases_fail_bool() {
                 value_type: ParameterType::Bool,
                 value: String::from("true"),
             }]),
+            stdin: None,
+            expected_error: None,
+            label: None,
+            hidden: false,
         },
     ]);
     let submission = Submission {
         solution,
         test_cases,
+        unordered_lists: false,
+        files: Box::new([]),
+        checker: None,
+        reference_solution: None,
+        only_ids: None,
+        compiler_flags: Box::new([]),
+        debug: false,
+        float_tolerance: None,
+        toolchain_version: None,
+        metadata: None,
     };
     let body = serde_json::to_string(&submission).expect("failed to serialize submission");
     let request = Builder::new()
@@ -907,7 +1179,10 @@ async fn all_test_cases_fail_bool() {
                 }]),
                 actual: String::from("True"),
                 expected: String::from("False"),
+                actual_value: None,
+                expected_value: None,
             }),
+            label: None,
         },
         TestCaseResult {
             id: 1,
@@ -918,7 +1193,10 @@ async fn all_test_cases_fail_bool() {
                 }]),
                 actual: String::from("False"),
                 expected: String::from("True"),
+                actual_value: None,
+                expected_value: None,
             }),
+            label: None,
         },
     ]));
 
@@ -941,7 +1219,7 @@ async fn all_test_cases_fail_bool() {
 
 #[tokio::test]
 async fn all_test_cases_fail_float() {
-    let mozart = app();
+    let mozart = app(AppState::default());
     let solution = [
         "module Solution where",
         "",
@@ -960,6 +1238,10 @@ async fn all_test_cases_fail_float() {
                 value_type: ParameterType::Float,
                 value: String::from("4.4"),
             }]),
+            stdin: None,
+            expected_error: None,
+            label: None,
+            hidden: false,
         },
         TestCase {
             id: 1,
@@ -971,11 +1253,25 @@ async fn all_test_cases_fail_float() {
                 value_type: ParameterType::Float,
                 value: String::from("10.0"),
             }]),
+            stdin: None,
+            expected_error: None,
+            label: None,
+            hidden: false,
         },
     ]);
     let submission = Submission {
         solution,
         test_cases,
+        unordered_lists: false,
+        files: Box::new([]),
+        checker: None,
+        reference_solution: None,
+        only_ids: None,
+        compiler_flags: Box::new([]),
+        debug: false,
+        float_tolerance: None,
+        toolchain_version: None,
+        metadata: None,
     };
     let body = serde_json::to_string(&submission).expect("failed to serialize submission");
     let request = Builder::new()
@@ -995,7 +1291,10 @@ async fn all_test_cases_fail_float() {
                 }]),
                 actual: String::from("2.2"),
                 expected: String::from("4.4"),
+                actual_value: None,
+                expected_value: None,
             }),
+            label: None,
         },
         TestCaseResult {
             id: 1,
@@ -1006,7 +1305,10 @@ async fn all_test_cases_fail_float() {
                 }]),
                 actual: String::from("5.0"),
                 expected: String::from("10.0"),
+                actual_value: None,
+                expected_value: None,
             }),
+            label: None,
         },
     ]));
 
@@ -1029,7 +1331,7 @@ async fn all_test_cases_fail_float() {
 
 #[tokio::test]
 async fn all_test_cases_fail_char() {
-    let mozart = app();
+    let mozart = app(AppState::default());
     let solution = [
         "module Solution where",
         "",
@@ -1048,6 +1350,10 @@ async fn all_test_cases_fail_char() {
                 value_type: ParameterType::Char,
                 value: String::from("b"),
             }]),
+            stdin: None,
+            expected_error: None,
+            label: None,
+            hidden: false,
         },
         TestCase {
             id: 1,
@@ -1059,11 +1365,25 @@ async fn all_test_cases_fail_char() {
                 value_type: ParameterType::Char,
                 value: String::from("c"),
             }]),
+            stdin: None,
+            expected_error: None,
+            label: None,
+            hidden: false,
         },
     ]);
     let submission = Submission {
         solution,
         test_cases,
+        unordered_lists: false,
+        files: Box::new([]),
+        checker: None,
+        reference_solution: None,
+        only_ids: None,
+        compiler_flags: Box::new([]),
+        debug: false,
+        float_tolerance: None,
+        toolchain_version: None,
+        metadata: None,
     };
     let body = serde_json::to_string(&submission).expect("failed to serialize submission");
     let request = Builder::new()
@@ -1081,9 +1401,12 @@ async fn all_test_cases_fail_char() {
                     value_type: ParameterType::Char,
                     value: String::from("b"),
                 }]),
-                actual: String::from("'a'"),
-                expected: String::from("'b'"),
+                actual: String::from("a"),
+                expected: String::from("b"),
+                actual_value: None,
+                expected_value: None,
             }),
+            label: None,
         },
         TestCaseResult {
             id: 1,
@@ -1092,9 +1415,12 @@ async fn all_test_cases_fail_char() {
                     value_type: ParameterType::Char,
                     value: String::from("c"),
                 }]),
-                actual: String::from("'a'"),
-                expected: String::from("'c'"),
+                actual: String::from("a"),
+                expected: String::from("c"),
+                actual_value: None,
+                expected_value: None,
             }),
+            label: None,
         },
     ]));
 
@@ -1117,7 +1443,7 @@ async fn all_test_cases_fail_char() {
 
 #[tokio::test]
 async fn all_test_cases_fail_string() {
-    let mozart = app();
+    let mozart = app(AppState::default());
     let solution = [
         "module Solution where",
         "",
@@ -1136,6 +1462,10 @@ async fn all_test_cases_fail_string() {
                 value_type: ParameterType::String,
                 value: String::from("hellohello"),
             }]),
+            stdin: None,
+            expected_error: None,
+            label: None,
+            hidden: false,
         },
         TestCase {
             id: 1,
@@ -1147,11 +1477,25 @@ async fn all_test_cases_fail_string() {
                 value_type: ParameterType::String,
                 value: String::from("worldworld"),
             }]),
+            stdin: None,
+            expected_error: None,
+            label: None,
+            hidden: false,
         },
     ]);
     let submission = Submission {
         solution,
         test_cases,
+        unordered_lists: false,
+        files: Box::new([]),
+        checker: None,
+        reference_solution: None,
+        only_ids: None,
+        compiler_flags: Box::new([]),
+        debug: false,
+        float_tolerance: None,
+        toolchain_version: None,
+        metadata: None,
     };
     let body = serde_json::to_string(&submission).expect("failed to serialize submission");
     let request = Builder::new()
@@ -1169,9 +1513,12 @@ async fn all_test_cases_fail_string() {
                     value_type: ParameterType::String,
                     value: String::from("hello"),
                 }]),
-                actual: String::from(r#""hello""#),
-                expected: String::from(r#""hellohello""#),
+                actual: String::from("hello"),
+                expected: String::from("hellohello"),
+                actual_value: None,
+                expected_value: None,
             }),
+            label: None,
         },
         TestCaseResult {
             id: 1,
@@ -1180,9 +1527,12 @@ async fn all_test_cases_fail_string() {
                     value_type: ParameterType::String,
                     value: String::from("world"),
                 }]),
-                actual: String::from(r#""world""#),
-                expected: String::from(r#""worldworld""#),
+                actual: String::from("world"),
+                expected: String::from("worldworld"),
+                actual_value: None,
+                expected_value: None,
             }),
+            label: None,
         },
     ]));
 
@@ -1205,7 +1555,7 @@ async fn all_test_cases_fail_string() {
 
 #[tokio::test]
 async fn runtime_error_in_non_last_test_case() {
-    let mozart = app();
+    let mozart = app(AppState::default());
     let solution = [
         "module Solution where",
         "",
@@ -1224,6 +1574,10 @@ async fn runtime_error_in_non_last_test_case() {
                 value_type: ParameterType::Int,
                 value: String::from("5"),
             }]),
+            stdin: None,
+            expected_error: None,
+            label: None,
+            hidden: false,
         },
         TestCase {
             id: 1,
@@ -1235,6 +1589,10 @@ async fn runtime_error_in_non_last_test_case() {
                 value_type: ParameterType::Int,
                 value: String::from("0"),
             }]),
+            stdin: None,
+            expected_error: None,
+            label: None,
+            hidden: false,
         },
         TestCase {
             id: 2,
@@ -1246,11 +1604,25 @@ async fn runtime_error_in_non_last_test_case() {
                 value_type: ParameterType::Int,
                 value: String::from("5"),
             }]),
+            stdin: None,
+            expected_error: None,
+            label: None,
+            hidden: false,
         },
     ]);
     let submission = Submission {
         solution,
         test_cases,
+        unordered_lists: false,
+        files: Box::new([]),
+        checker: None,
+        reference_solution: None,
+        only_ids: None,
+        compiler_flags: Box::new([]),
+        debug: false,
+        float_tolerance: None,
+        toolchain_version: None,
+        metadata: None,
     };
     let body = serde_json::to_string(&submission).expect("failed to serialize submission");
     let request = Builder::new()
@@ -1264,16 +1636,19 @@ async fn runtime_error_in_non_last_test_case() {
         TestCaseResult {
             id: 0,
             test_result: TestResult::Pass,
+            label: None,
         },
         TestCaseResult {
             id: 1,
             test_result: TestResult::Failure(TestCaseFailureReason::RuntimeError(String::from(
                 "divide by zero",
             ))),
+            label: None,
         },
         TestCaseResult {
             id: 2,
             test_result: TestResult::Pass,
+            label: None,
         },
     ]));
 
@@ -1296,7 +1671,7 @@ async fn runtime_error_in_non_last_test_case() {
 
 #[tokio::test]
 async fn mixed_pass_and_fail_with_runtime_error() {
-    let mozart = app();
+    let mozart = app(AppState::default());
     let solution = [
         "module Solution where",
         "",
@@ -1317,6 +1692,10 @@ async fn mixed_pass_and_fail_with_runtime_error() {
                 value_type: ParameterType::Int,
                 value: String::from("2"),
             }]),
+            stdin: None,
+            expected_error: None,
+            label: None,
+            hidden: false,
         },
         TestCase {
             id: 1,
@@ -1328,6 +1707,10 @@ async fn mixed_pass_and_fail_with_runtime_error() {
                 value_type: ParameterType::Int,
                 value: String::from("5"),
             }]),
+            stdin: None,
+            expected_error: None,
+            label: None,
+            hidden: false,
         },
         TestCase {
             id: 2,
@@ -1339,6 +1722,10 @@ async fn mixed_pass_and_fail_with_runtime_error() {
                 value_type: ParameterType::Int,
                 value: String::from("3"),
             }]),
+            stdin: None,
+            expected_error: None,
+            label: None,
+            hidden: false,
         },
         TestCase {
             id: 3,
@@ -1350,6 +1737,10 @@ async fn mixed_pass_and_fail_with_runtime_error() {
                 value_type: ParameterType::Int,
                 value: String::from("2"),
             }]),
+            stdin: None,
+            expected_error: None,
+            label: None,
+            hidden: false,
         },
         TestCase {
             id: 4,
@@ -1361,6 +1752,10 @@ async fn mixed_pass_and_fail_with_runtime_error() {
                 value_type: ParameterType::Int,
                 value: String::from("-3"),
             }]),
+            stdin: None,
+            expected_error: None,
+            label: None,
+            hidden: false,
         },
         TestCase {
             id: 5,
@@ -1372,11 +1767,25 @@ async fn mixed_pass_and_fail_with_runtime_error() {
                 value_type: ParameterType::Int,
                 value: String::from("6"),
             }]),
+            stdin: None,
+            expected_error: None,
+            label: None,
+            hidden: false,
         },
     ]);
     let submission = Submission {
         solution,
         test_cases,
+        unordered_lists: false,
+        files: Box::new([]),
+        checker: None,
+        reference_solution: None,
+        only_ids: None,
+        compiler_flags: Box::new([]),
+        debug: false,
+        float_tolerance: None,
+        toolchain_version: None,
+        metadata: None,
     };
     let body = serde_json::to_string(&submission).expect("failed to serialize submission");
     let request = Builder::new()
@@ -1390,6 +1799,7 @@ async fn mixed_pass_and_fail_with_runtime_error() {
         TestCaseResult {
             id: 0,
             test_result: TestResult::Pass,
+            label: None,
         },
         TestCaseResult {
             id: 1,
@@ -1400,11 +1810,15 @@ async fn mixed_pass_and_fail_with_runtime_error() {
                 }]),
                 actual: String::from("4"),
                 expected: String::from("5"),
+                actual_value: None,
+                expected_value: None,
             }),
+            label: None,
         },
         TestCaseResult {
             id: 2,
             test_result: TestResult::Pass,
+            label: None,
         },
         TestCaseResult {
             id: 3,
@@ -1415,17 +1829,22 @@ async fn mixed_pass_and_fail_with_runtime_error() {
                 }]),
                 actual: String::from("7"),
                 expected: String::from("2"),
+                actual_value: None,
+                expected_value: None,
             }),
+            label: None,
         },
         TestCaseResult {
             id: 4,
             test_result: TestResult::Failure(TestCaseFailureReason::RuntimeError(String::from(
                 "divide by zero",
             ))),
+            label: None,
         },
         TestCaseResult {
             id: 5,
             test_result: TestResult::Pass,
+            label: None,
         },
     ]));
 
@@ -1445,3 +1864,5769 @@ async fn mixed_pass_and_fail_with_runtime_error() {
     assert_eq!(actual_status, expected_status);
     assert_eq!(actual_body, expected_body);
 }
+
+#[tokio::test]
+async fn big_int_factorial() {
+    let mozart = app(AppState::default());
+    let solution = [
+        "module Solution where",
+        "",
+        "solution :: Integer -> Integer",
+        "solution n = product [1..n]",
+    ]
+    .join("\n");
+    let test_cases = Box::new([TestCase {
+        id: 0,
+        input_parameters: Box::new([Parameter {
+            value_type: ParameterType::BigInt,
+            value: String::from("25"),
+        }]),
+        output_parameters: Box::new([Parameter {
+            value_type: ParameterType::BigInt,
+            value: String::from("15511210043330985984000000"),
+        }]),
+        stdin: None,
+        expected_error: None,
+        label: None,
+        hidden: false,
+    }]);
+    let submission = Submission {
+        solution,
+        test_cases,
+        unordered_lists: false,
+        files: Box::new([]),
+        checker: None,
+        reference_solution: None,
+        only_ids: None,
+        compiler_flags: Box::new([]),
+        debug: false,
+        float_tolerance: None,
+        toolchain_version: None,
+        metadata: None,
+    };
+    let body = serde_json::to_string(&submission).expect("failed to serialize submission");
+    let request = Builder::new()
+        .header("Content-Type", "application/json")
+        .method(Method::POST)
+        .uri("/submit")
+        .body(Body::from(body))
+        .expect("failed to build request");
+    let expected_status = StatusCode::OK;
+    let expected_body = SubmissionResult::Pass;
+
+    let actual = mozart
+        .oneshot(request)
+        .await
+        .expect("failed to execute oneshot request");
+
+    let actual_status = actual.status();
+    let body_bytes = to_bytes(actual.into_body(), usize::MAX)
+        .await
+        .expect("failed to convert body to bytes");
+
+    let actual_body: SubmissionResult =
+        serde_json::from_slice(&body_bytes).expect("failed to deserialize response body");
+
+    assert_eq!(actual_status, expected_status);
+    assert_eq!(actual_body, expected_body);
+}
+
+#[tokio::test]
+async fn int_factorial_overflow() {
+    let mozart = app(AppState::default());
+    let solution = [
+        "module Solution where",
+        "",
+        "solution :: Int -> Int",
+        "solution n = product [1..n]",
+    ]
+    .join("\n");
+    // 25! does not fit in a 64-bit integer, and would silently overflow if it was not rejected
+    let test_cases = Box::new([TestCase {
+        id: 0,
+        input_parameters: Box::new([Parameter {
+            value_type: ParameterType::Int,
+            value: String::from("25"),
+        }]),
+        output_parameters: Box::new([Parameter {
+            value_type: ParameterType::Int,
+            value: String::from("15511210043330985984000000"),
+        }]),
+        stdin: None,
+        expected_error: None,
+        label: None,
+        hidden: false,
+    }]);
+    let submission = Submission {
+        solution,
+        test_cases,
+        unordered_lists: false,
+        files: Box::new([]),
+        checker: None,
+        reference_solution: None,
+        only_ids: None,
+        compiler_flags: Box::new([]),
+        debug: false,
+        float_tolerance: None,
+        toolchain_version: None,
+        metadata: None,
+    };
+    let body = serde_json::to_string(&submission).expect("failed to serialize submission");
+    let request = Builder::new()
+        .header("Content-Type", "application/json")
+        .method(Method::POST)
+        .uri("/submit")
+        .body(Body::from(body))
+        .expect("failed to build request");
+    let expected_status = StatusCode::OK;
+
+    let actual = mozart
+        .oneshot(request)
+        .await
+        .expect("failed to execute oneshot request");
+
+    let actual_status = actual.status();
+    let body_bytes = to_bytes(actual.into_body(), usize::MAX)
+        .await
+        .expect("failed to convert body to bytes");
+
+    let actual_body: SubmissionResult =
+        serde_json::from_slice(&body_bytes).expect("failed to deserialize response body");
+
+    assert_eq!(actual_status, expected_status);
+
+    if let SubmissionResult::Error { code, .. } = actual_body {
+        assert_eq!(code, ErrorCode::InvalidParameter);
+    } else {
+        panic!("response body was not of error variant");
+    }
+}
+
+#[tokio::test]
+async fn float_whole_number_expected() {
+    let mozart = app(AppState::default());
+    let solution = [
+        "module Solution where",
+        "",
+        "solution :: Double -> Double",
+        "solution f = f * 2",
+    ]
+    .join("\n");
+    let test_cases = Box::new([TestCase {
+        id: 0,
+        input_parameters: Box::new([Parameter {
+            value_type: ParameterType::Float,
+            value: String::from("2.5"),
+        }]),
+        output_parameters: Box::new([Parameter {
+            value_type: ParameterType::Float,
+            value: String::from("5"),
+        }]),
+        stdin: None,
+        expected_error: None,
+        label: None,
+        hidden: false,
+    }]);
+    let submission = Submission {
+        solution,
+        test_cases,
+        unordered_lists: false,
+        files: Box::new([]),
+        checker: None,
+        reference_solution: None,
+        only_ids: None,
+        compiler_flags: Box::new([]),
+        debug: false,
+        float_tolerance: None,
+        toolchain_version: None,
+        metadata: None,
+    };
+    let body = serde_json::to_string(&submission).expect("failed to serialize submission");
+    let request = Builder::new()
+        .header("Content-Type", "application/json")
+        .method(Method::POST)
+        .uri("/submit")
+        .body(Body::from(body))
+        .expect("failed to build request");
+    let expected_status = StatusCode::OK;
+    let expected_body = SubmissionResult::Pass;
+
+    let actual = mozart
+        .oneshot(request)
+        .await
+        .expect("failed to execute oneshot request");
+
+    let actual_status = actual.status();
+    let body_bytes = to_bytes(actual.into_body(), usize::MAX)
+        .await
+        .expect("failed to convert body to bytes");
+
+    let actual_body: SubmissionResult =
+        serde_json::from_slice(&body_bytes).expect("failed to deserialize response body");
+
+    assert_eq!(actual_status, expected_status);
+    assert_eq!(actual_body, expected_body);
+}
+
+#[tokio::test]
+async fn float_whole_number_actual() {
+    let mozart = app(AppState::default());
+    let solution = [
+        "module Solution where",
+        "",
+        "solution :: Double -> Double",
+        "solution _ = 5",
+    ]
+    .join("\n");
+    let test_cases = Box::new([TestCase {
+        id: 0,
+        input_parameters: Box::new([Parameter {
+            value_type: ParameterType::Float,
+            value: String::from("2.5"),
+        }]),
+        output_parameters: Box::new([Parameter {
+            value_type: ParameterType::Float,
+            value: String::from("5.0"),
+        }]),
+        stdin: None,
+        expected_error: None,
+        label: None,
+        hidden: false,
+    }]);
+    let submission = Submission {
+        solution,
+        test_cases,
+        unordered_lists: false,
+        files: Box::new([]),
+        checker: None,
+        reference_solution: None,
+        only_ids: None,
+        compiler_flags: Box::new([]),
+        debug: false,
+        float_tolerance: None,
+        toolchain_version: None,
+        metadata: None,
+    };
+    let body = serde_json::to_string(&submission).expect("failed to serialize submission");
+    let request = Builder::new()
+        .header("Content-Type", "application/json")
+        .method(Method::POST)
+        .uri("/submit")
+        .body(Body::from(body))
+        .expect("failed to build request");
+    let expected_status = StatusCode::OK;
+    let expected_body = SubmissionResult::Pass;
+
+    let actual = mozart
+        .oneshot(request)
+        .await
+        .expect("failed to execute oneshot request");
+
+    let actual_status = actual.status();
+    let body_bytes = to_bytes(actual.into_body(), usize::MAX)
+        .await
+        .expect("failed to convert body to bytes");
+
+    let actual_body: SubmissionResult =
+        serde_json::from_slice(&body_bytes).expect("failed to deserialize response body");
+
+    assert_eq!(actual_status, expected_status);
+    assert_eq!(actual_body, expected_body);
+}
+
+#[tokio::test]
+async fn char_single_quote() {
+    let mozart = app(AppState::default());
+    let solution = [
+        "module Solution where",
+        "",
+        "solution :: Char -> Char",
+        "solution c = c",
+    ]
+    .join("\n");
+    let test_cases = Box::new([TestCase {
+        id: 0,
+        input_parameters: Box::new([Parameter {
+            value_type: ParameterType::Char,
+            value: String::from("'"),
+        }]),
+        output_parameters: Box::new([Parameter {
+            value_type: ParameterType::Char,
+            value: String::from("'"),
+        }]),
+        stdin: None,
+        expected_error: None,
+        label: None,
+        hidden: false,
+    }]);
+    let submission = Submission {
+        solution,
+        test_cases,
+        unordered_lists: false,
+        files: Box::new([]),
+        checker: None,
+        reference_solution: None,
+        only_ids: None,
+        compiler_flags: Box::new([]),
+        debug: false,
+        float_tolerance: None,
+        toolchain_version: None,
+        metadata: None,
+    };
+    let body = serde_json::to_string(&submission).expect("failed to serialize submission");
+    let request = Builder::new()
+        .header("Content-Type", "application/json")
+        .method(Method::POST)
+        .uri("/submit")
+        .body(Body::from(body))
+        .expect("failed to build request");
+    let expected_status = StatusCode::OK;
+    let expected_body = SubmissionResult::Pass;
+
+    let actual = mozart
+        .oneshot(request)
+        .await
+        .expect("failed to execute oneshot request");
+
+    let actual_status = actual.status();
+    let body_bytes = to_bytes(actual.into_body(), usize::MAX)
+        .await
+        .expect("failed to convert body to bytes");
+
+    let actual_body: SubmissionResult =
+        serde_json::from_slice(&body_bytes).expect("failed to deserialize response body");
+
+    assert_eq!(actual_status, expected_status);
+    assert_eq!(actual_body, expected_body);
+}
+
+#[tokio::test]
+async fn char_multiple_characters() {
+    let mozart = app(AppState::default());
+    let solution = [
+        "module Solution where",
+        "",
+        "solution :: Char -> Char",
+        "solution c = c",
+    ]
+    .join("\n");
+    let test_cases = Box::new([TestCase {
+        id: 0,
+        input_parameters: Box::new([Parameter {
+            value_type: ParameterType::Char,
+            value: String::from("ab"),
+        }]),
+        output_parameters: Box::new([Parameter {
+            value_type: ParameterType::Char,
+            value: String::from("a"),
+        }]),
+        stdin: None,
+        expected_error: None,
+        label: None,
+        hidden: false,
+    }]);
+    let submission = Submission {
+        solution,
+        test_cases,
+        unordered_lists: false,
+        files: Box::new([]),
+        checker: None,
+        reference_solution: None,
+        only_ids: None,
+        compiler_flags: Box::new([]),
+        debug: false,
+        float_tolerance: None,
+        toolchain_version: None,
+        metadata: None,
+    };
+    let body = serde_json::to_string(&submission).expect("failed to serialize submission");
+    let request = Builder::new()
+        .header("Content-Type", "application/json")
+        .method(Method::POST)
+        .uri("/submit")
+        .body(Body::from(body))
+        .expect("failed to build request");
+    let expected_status = StatusCode::OK;
+
+    let actual = mozart
+        .oneshot(request)
+        .await
+        .expect("failed to execute oneshot request");
+
+    let actual_status = actual.status();
+    let body_bytes = to_bytes(actual.into_body(), usize::MAX)
+        .await
+        .expect("failed to convert body to bytes");
+
+    let actual_body: SubmissionResult =
+        serde_json::from_slice(&body_bytes).expect("failed to deserialize response body");
+
+    assert_eq!(actual_status, expected_status);
+
+    if let SubmissionResult::Error { code, .. } = actual_body {
+        assert_eq!(code, ErrorCode::InvalidParameter);
+    } else {
+        panic!("response body was not of error variant");
+    }
+}
+
+#[tokio::test]
+async fn duplicate_test_case_ids() {
+    let mozart = app(AppState::default());
+    let solution = [
+        "module Solution where",
+        "",
+        "solution :: Int -> Int",
+        "solution x = x",
+    ]
+    .join("\n");
+    let test_cases = Box::new([
+        TestCase {
+            id: 0,
+            input_parameters: Box::new([Parameter {
+                value_type: ParameterType::Int,
+                value: String::from("1"),
+            }]),
+            output_parameters: Box::new([Parameter {
+                value_type: ParameterType::Int,
+                value: String::from("1"),
+            }]),
+            stdin: None,
+            expected_error: None,
+            label: None,
+            hidden: false,
+        },
+        TestCase {
+            id: 0,
+            input_parameters: Box::new([Parameter {
+                value_type: ParameterType::Int,
+                value: String::from("2"),
+            }]),
+            output_parameters: Box::new([Parameter {
+                value_type: ParameterType::Int,
+                value: String::from("2"),
+            }]),
+            stdin: None,
+            expected_error: None,
+            label: None,
+            hidden: false,
+        },
+    ]);
+    let submission = Submission {
+        solution,
+        test_cases,
+        unordered_lists: false,
+        files: Box::new([]),
+        checker: None,
+        reference_solution: None,
+        only_ids: None,
+        compiler_flags: Box::new([]),
+        debug: false,
+        float_tolerance: None,
+        toolchain_version: None,
+        metadata: None,
+    };
+    let body = serde_json::to_string(&submission).expect("failed to serialize submission");
+    let request = Builder::new()
+        .header("Content-Type", "application/json")
+        .method(Method::POST)
+        .uri("/submit")
+        .body(Body::from(body))
+        .expect("failed to build request");
+    let expected_status = StatusCode::OK;
+
+    let actual = mozart
+        .oneshot(request)
+        .await
+        .expect("failed to execute oneshot request");
+
+    let actual_status = actual.status();
+    let body_bytes = to_bytes(actual.into_body(), usize::MAX)
+        .await
+        .expect("failed to convert body to bytes");
+
+    let actual_body: SubmissionResult =
+        serde_json::from_slice(&body_bytes).expect("failed to deserialize response body");
+
+    assert_eq!(actual_status, expected_status);
+
+    if let SubmissionResult::Error { code, message } = actual_body {
+        assert_eq!(code, ErrorCode::DuplicateTestCaseId);
+        assert!(message.starts_with("multiple test cases have the id 0"));
+    } else {
+        panic!("response body was not of error variant");
+    }
+}
+
+#[tokio::test]
+async fn batch() {
+    let mozart = app(AppState::default());
+    let submissions = [
+        Submission {
+            solution: [
+                "module Solution where",
+                "",
+                "solution :: Int -> Int",
+                "solution x = x + x",
+            ]
+            .join("\n"),
+            test_cases: Box::new([TestCase {
+                id: 0,
+                input_parameters: Box::new([Parameter {
+                    value_type: ParameterType::Int,
+                    value: String::from("2"),
+                }]),
+                output_parameters: Box::new([Parameter {
+                    value_type: ParameterType::Int,
+                    value: String::from("4"),
+                }]),
+                stdin: None,
+                expected_error: None,
+                label: None,
+                hidden: false,
+            }]),
+            unordered_lists: false,
+            files: Box::new([]),
+            checker: None,
+            reference_solution: None,
+            only_ids: None,
+            compiler_flags: Box::new([]),
+            debug: false,
+            float_tolerance: None,
+            toolchain_version: None,
+            metadata: None,
+        },
+        Submission {
+            solution: [
+                "module Solution where",
+                "",
+                "solution :: Int -> Int",
+                "solution x = x",
+            ]
+            .join("\n"),
+            test_cases: Box::new([TestCase {
+                id: 0,
+                input_parameters: Box::new([Parameter {
+                    value_type: ParameterType::Int,
+                    value: String::from("2"),
+                }]),
+                output_parameters: Box::new([Parameter {
+                    value_type: ParameterType::Int,
+                    value: String::from("4"),
+                }]),
+                stdin: None,
+                expected_error: None,
+                label: None,
+                hidden: false,
+            }]),
+            unordered_lists: false,
+            files: Box::new([]),
+            checker: None,
+            reference_solution: None,
+            only_ids: None,
+            compiler_flags: Box::new([]),
+            debug: false,
+            float_tolerance: None,
+            toolchain_version: None,
+            metadata: None,
+        },
+        Submission {
+            solution: [
+                "module Solution where",
+                "",
+                "solution :: Int -> Int",
+                "solution x = y",
+            ]
+            .join("\n"),
+            test_cases: Box::new([TestCase {
+                id: 0,
+                input_parameters: Box::new([Parameter {
+                    value_type: ParameterType::Int,
+                    value: String::from("2"),
+                }]),
+                output_parameters: Box::new([Parameter {
+                    value_type: ParameterType::Int,
+                    value: String::from("4"),
+                }]),
+                stdin: None,
+                expected_error: None,
+                label: None,
+                hidden: false,
+            }]),
+            unordered_lists: false,
+            files: Box::new([]),
+            checker: None,
+            reference_solution: None,
+            only_ids: None,
+            compiler_flags: Box::new([]),
+            debug: false,
+            float_tolerance: None,
+            toolchain_version: None,
+            metadata: None,
+        },
+    ];
+    let body = serde_json::to_string(&submissions).expect("failed to serialize submissions");
+    let request = Builder::new()
+        .header("Content-Type", "application/json")
+        .method(Method::POST)
+        .uri("/submit/batch")
+        .body(Body::from(body))
+        .expect("failed to build request");
+    let expected_status = StatusCode::OK;
+
+    let actual = mozart
+        .oneshot(request)
+        .await
+        .expect("failed to execute oneshot request");
+
+    let actual_status = actual.status();
+    let body_bytes = to_bytes(actual.into_body(), usize::MAX)
+        .await
+        .expect("failed to convert body to bytes");
+
+    let actual_body: Vec<SubmissionResult> =
+        serde_json::from_slice(&body_bytes).expect("failed to deserialize response body");
+
+    assert_eq!(actual_status, expected_status);
+    assert_eq!(actual_body.len(), 3);
+    assert_eq!(actual_body[0], SubmissionResult::Pass);
+    assert!(matches!(actual_body[1], SubmissionResult::Failure(_)));
+    assert!(matches!(
+        actual_body[2],
+        SubmissionResult::CompilationError { .. }
+    ));
+}
+
+#[tokio::test]
+async fn crash_in_first_test_case() {
+    let mozart = app(AppState::default());
+    // exiting the process directly bypasses the exception handling of the test cases, but the test case was started
+    let solution = [
+        "{-# LANGUAGE ForeignFunctionInterface #-}",
+        "module Solution where",
+        "",
+        "import Foreign.C.Types",
+        "import System.IO",
+        "import System.IO.Unsafe",
+        "",
+        "foreign import ccall \"exit\" c_exit :: CInt -> IO ()",
+        "",
+        "solution :: Int -> Int",
+        "solution x = unsafePerformIO (hPutStrLn stderr \"boom\" >> c_exit 1 >> return x)",
+    ]
+    .join("\n");
+    let test_cases = Box::new([TestCase {
+        id: 0,
+        input_parameters: Box::new([Parameter {
+            value_type: ParameterType::Int,
+            value: String::from("2"),
+        }]),
+        output_parameters: Box::new([Parameter {
+            value_type: ParameterType::Int,
+            value: String::from("2"),
+        }]),
+        stdin: None,
+        expected_error: None,
+        label: None,
+        hidden: false,
+    }]);
+    let submission = Submission {
+        solution,
+        test_cases,
+        unordered_lists: false,
+        files: Box::new([]),
+        checker: None,
+        reference_solution: None,
+        only_ids: None,
+        compiler_flags: Box::new([]),
+        debug: false,
+        float_tolerance: None,
+        toolchain_version: None,
+        metadata: None,
+    };
+    let body = serde_json::to_string(&submission).expect("failed to serialize submission");
+    let request = Builder::new()
+        .header("Content-Type", "application/json")
+        .method(Method::POST)
+        .uri("/submit")
+        .body(Body::from(body))
+        .expect("failed to build request");
+    let expected_status = StatusCode::OK;
+
+    let actual = mozart
+        .oneshot(request)
+        .await
+        .expect("failed to execute oneshot request");
+
+    let actual_status = actual.status();
+    let body_bytes = to_bytes(actual.into_body(), usize::MAX)
+        .await
+        .expect("failed to convert body to bytes");
+
+    let actual_body: SubmissionResult =
+        serde_json::from_slice(&body_bytes).expect("failed to deserialize response body");
+
+    assert_eq!(actual_status, expected_status);
+
+    if let SubmissionResult::Failure(test_case_results) = actual_body {
+        assert_eq!(test_case_results.len(), 1);
+        assert!(matches!(
+            &test_case_results[0].test_result,
+            TestResult::Failure(TestCaseFailureReason::RuntimeError(message)) if message.contains("boom")
+        ));
+    } else {
+        panic!("response body was not of failure variant");
+    }
+}
+
+#[tokio::test]
+async fn unit_output() {
+    let mozart = app(AppState::default());
+    let solution = [
+        "module Solution where",
+        "",
+        "solution :: Int -> ()",
+        "solution x = if x > 0 then () else error \"negative\"",
+    ]
+    .join("\n");
+    let test_cases = Box::new([TestCase {
+        id: 0,
+        input_parameters: Box::new([Parameter {
+            value_type: ParameterType::Int,
+            value: String::from("2"),
+        }]),
+        output_parameters: Box::new([Parameter {
+            value_type: ParameterType::Unit,
+            value: String::from(""),
+        }]),
+        stdin: None,
+        expected_error: None,
+        label: None,
+        hidden: false,
+    }]);
+    let submission = Submission {
+        solution,
+        test_cases,
+        unordered_lists: false,
+        files: Box::new([]),
+        checker: None,
+        reference_solution: None,
+        only_ids: None,
+        compiler_flags: Box::new([]),
+        debug: false,
+        float_tolerance: None,
+        toolchain_version: None,
+        metadata: None,
+    };
+    let body = serde_json::to_string(&submission).expect("failed to serialize submission");
+    let request = Builder::new()
+        .header("Content-Type", "application/json")
+        .method(Method::POST)
+        .uri("/submit")
+        .body(Body::from(body))
+        .expect("failed to build request");
+    let expected_status = StatusCode::OK;
+    let expected_body = SubmissionResult::Pass;
+
+    let actual = mozart
+        .oneshot(request)
+        .await
+        .expect("failed to execute oneshot request");
+
+    let actual_status = actual.status();
+    let body_bytes = to_bytes(actual.into_body(), usize::MAX)
+        .await
+        .expect("failed to convert body to bytes");
+
+    let actual_body: SubmissionResult =
+        serde_json::from_slice(&body_bytes).expect("failed to deserialize response body");
+
+    assert_eq!(actual_status, expected_status);
+    assert_eq!(actual_body, expected_body);
+}
+
+#[tokio::test]
+async fn check_submission_without_http() {
+    let solution = [
+        "module Solution where",
+        "",
+        "solution :: Int -> Int",
+        "solution x = x + x",
+    ]
+    .join("\n");
+    let test_cases = Box::new([TestCase {
+        id: 0,
+        input_parameters: Box::new([Parameter {
+            value_type: ParameterType::Int,
+            value: String::from("2"),
+        }]),
+        output_parameters: Box::new([Parameter {
+            value_type: ParameterType::Int,
+            value: String::from("4"),
+        }]),
+        stdin: None,
+        expected_error: None,
+        label: None,
+        hidden: false,
+    }]);
+    let submission = Submission {
+        solution,
+        test_cases,
+        unordered_lists: false,
+        files: Box::new([]),
+        checker: None,
+        reference_solution: None,
+        only_ids: None,
+        compiler_flags: Box::new([]),
+        debug: false,
+        float_tolerance: None,
+        toolchain_version: None,
+        metadata: None,
+    };
+    let expected = SubmissionResult::Pass;
+
+    let actual = check_submission(submission, AppState::default()).await;
+
+    assert_eq!(actual, expected);
+}
+
+#[tokio::test]
+async fn fork_bomb() {
+    let mozart = app(AppState::default());
+    let solution = [
+        "module Solution where",
+        "",
+        "import Control.Concurrent (threadDelay)",
+        "import System.IO.Unsafe (unsafePerformIO)",
+        "import System.Posix.Process (forkProcess)",
+        "",
+        "solution :: Int -> Int",
+        "solution x = unsafePerformIO (mapM_ (\\_ -> forkProcess (threadDelay 1000000)) [1 .. 1000] >> return x)",
+    ]
+    .join("\n");
+    let test_cases = Box::new([TestCase {
+        id: 0,
+        input_parameters: Box::new([Parameter {
+            value_type: ParameterType::Int,
+            value: String::from("2"),
+        }]),
+        output_parameters: Box::new([Parameter {
+            value_type: ParameterType::Int,
+            value: String::from("2"),
+        }]),
+        stdin: None,
+        expected_error: None,
+        label: None,
+        hidden: false,
+    }]);
+    let submission = Submission {
+        solution,
+        test_cases,
+        unordered_lists: false,
+        files: Box::new([]),
+        checker: None,
+        reference_solution: None,
+        only_ids: None,
+        compiler_flags: Box::new([]),
+        debug: false,
+        float_tolerance: None,
+        toolchain_version: None,
+        metadata: None,
+    };
+    let body = serde_json::to_string(&submission).expect("failed to serialize submission");
+    let request = Builder::new()
+        .header("Content-Type", "application/json")
+        .method(Method::POST)
+        .uri("/submit")
+        .body(Body::from(body))
+        .expect("failed to build request");
+    let expected_status = StatusCode::OK;
+
+    let actual = mozart
+        .oneshot(request)
+        .await
+        .expect("failed to execute oneshot request");
+
+    let actual_status = actual.status();
+    let body_bytes = to_bytes(actual.into_body(), usize::MAX)
+        .await
+        .expect("failed to convert body to bytes");
+
+    let actual_body: SubmissionResult =
+        serde_json::from_slice(&body_bytes).expect("failed to deserialize response body");
+
+    assert_eq!(actual_status, expected_status);
+
+    if let SubmissionResult::Failure(test_case_results) = actual_body {
+        assert!(matches!(
+            test_case_results[0].test_result,
+            TestResult::Failure(TestCaseFailureReason::RuntimeError(_))
+        ));
+    } else {
+        panic!("response body was not of failure variant");
+    }
+
+    let request = Builder::new()
+        .method(Method::GET)
+        .uri("/status")
+        .body(Body::empty())
+        .expect("failed to build request");
+
+    let actual = app(AppState::default())
+        .oneshot(request)
+        .await
+        .expect("failed to execute oneshot request");
+
+    assert_eq!(actual.status(), StatusCode::OK);
+}
+
+#[tokio::test]
+async fn huge_stdout_keeps_verdict() {
+    let mozart = app(AppState::default());
+    let solution = [
+        "module Solution where",
+        "",
+        "import System.IO.Unsafe (unsafePerformIO)",
+        "",
+        "solution :: Int -> Int",
+        "solution x = unsafePerformIO (putStr (replicate 2000000 'x') >> return x)",
+    ]
+    .join("\n");
+    let test_cases = Box::new([TestCase {
+        id: 0,
+        input_parameters: Box::new([Parameter {
+            value_type: ParameterType::Int,
+            value: String::from("2"),
+        }]),
+        output_parameters: Box::new([Parameter {
+            value_type: ParameterType::Int,
+            value: String::from("2"),
+        }]),
+        stdin: None,
+        expected_error: None,
+        label: None,
+        hidden: false,
+    }]);
+    let submission = Submission {
+        solution,
+        test_cases,
+        unordered_lists: false,
+        files: Box::new([]),
+        checker: None,
+        reference_solution: None,
+        only_ids: None,
+        compiler_flags: Box::new([]),
+        debug: false,
+        float_tolerance: None,
+        toolchain_version: None,
+        metadata: None,
+    };
+    let body = serde_json::to_string(&submission).expect("failed to serialize submission");
+    let request = Builder::new()
+        .header("Content-Type", "application/json")
+        .method(Method::POST)
+        .uri("/submit")
+        .body(Body::from(body))
+        .expect("failed to build request");
+    let expected_status = StatusCode::OK;
+    let expected_result = SubmissionResult::Pass;
+    // the default maximum length of the stdout in a response
+    let expected_stdout_length = 10000 + "...".len();
+
+    let actual = mozart
+        .oneshot(request)
+        .await
+        .expect("failed to execute oneshot request");
+
+    let actual_status = actual.status();
+    let body_bytes = to_bytes(actual.into_body(), usize::MAX)
+        .await
+        .expect("failed to convert body to bytes");
+
+    let actual_body: Value =
+        serde_json::from_slice(&body_bytes).expect("failed to deserialize response body");
+    let actual_result: SubmissionResult =
+        serde_json::from_slice(&body_bytes).expect("failed to deserialize result");
+    let actual_stdout = actual_body["stdout"]
+        .as_str()
+        .expect("stdout should be a string");
+
+    assert_eq!(actual_status, expected_status);
+    assert_eq!(actual_result, expected_result);
+    assert_eq!(actual_stdout.len(), expected_stdout_length);
+    assert!(actual_stdout.ends_with("..."));
+}
+
+#[tokio::test]
+async fn submission_completes_during_shutdown() {
+    let listener = TcpListener::bind("127.0.0.1:0")
+        .await
+        .expect("failed to bind listener");
+    let address = listener.local_addr().expect("failed to get local address");
+    let (shutdown, shutdown_rx) = oneshot::channel::<()>();
+    let server = tokio::spawn(serve_until(listener, AppState::default(), async move {
+        let _ = shutdown_rx.await;
+    }));
+    let solution = [
+        "module Solution where",
+        "",
+        "import Control.Concurrent (threadDelay)",
+        "import System.IO.Unsafe (unsafePerformIO)",
+        "",
+        "solution :: Int -> Int",
+        "solution x = unsafePerformIO (threadDelay 500000 >> return x)",
+    ]
+    .join("\n");
+    let test_cases = Box::new([TestCase {
+        id: 0,
+        input_parameters: Box::new([Parameter {
+            value_type: ParameterType::Int,
+            value: String::from("2"),
+        }]),
+        output_parameters: Box::new([Parameter {
+            value_type: ParameterType::Int,
+            value: String::from("2"),
+        }]),
+        stdin: None,
+        expected_error: None,
+        label: None,
+        hidden: false,
+    }]);
+    let submission = Submission {
+        solution,
+        test_cases,
+        unordered_lists: false,
+        files: Box::new([]),
+        checker: None,
+        reference_solution: None,
+        only_ids: None,
+        compiler_flags: Box::new([]),
+        debug: false,
+        float_tolerance: None,
+        toolchain_version: None,
+        metadata: None,
+    };
+    let body = serde_json::to_string(&submission).expect("failed to serialize submission");
+    let request = format!(
+        "POST /submit HTTP/1.1\r\nHost: localhost\r\nContent-Type: application/json\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}",
+        body.len(),
+        body
+    );
+    let expected_body = SubmissionResult::Pass;
+
+    let mut stream = TcpStream::connect(address)
+        .await
+        .expect("failed to connect to mozart");
+    stream
+        .write_all(request.as_bytes())
+        .await
+        .expect("failed to send request");
+    // gives mozart time to start checking the submission before shutting down
+    sleep(Duration::from_millis(200)).await;
+    shutdown.send(()).expect("failed to signal shutdown");
+
+    let mut response = String::new();
+    stream
+        .read_to_string(&mut response)
+        .await
+        .expect("failed to read response");
+    server.await.expect("failed to shut down mozart");
+
+    let (head, body) = response
+        .split_once("\r\n\r\n")
+        .expect("response should have a body");
+    let actual_body: SubmissionResult =
+        serde_json::from_str(body).expect("failed to deserialize response body");
+
+    assert!(head.starts_with("HTTP/1.1 200 OK"));
+    assert_eq!(actual_body, expected_body);
+}
+
+#[tokio::test]
+async fn timeout_in_third_test_case() {
+    let mozart = app(AppState::default());
+    let solution = [
+        "{-# LANGUAGE ForeignFunctionInterface #-}",
+        "module Solution where",
+        "",
+        "import Foreign.C.Types",
+        "import System.IO.Unsafe",
+        "",
+        "foreign import ccall unsafe \"sleep\" c_sleep :: CUInt -> IO CUInt",
+        "",
+        "solution :: Int -> Int",
+        "solution 3 = fromIntegral (unsafePerformIO (c_sleep 60))",
+        "solution x = x",
+    ]
+    .join("\n");
+    // the third test case cannot be interrupted by its own timeout, as the runtime is blocked by the unsafe foreign
+    // call, so the execution is killed and the fourth and fifth test cases are never started
+    let test_cases = Box::new([
+        TestCase {
+            id: 0,
+            input_parameters: Box::new([Parameter {
+                value_type: ParameterType::Int,
+                value: String::from("1"),
+            }]),
+            output_parameters: Box::new([Parameter {
+                value_type: ParameterType::Int,
+                value: String::from("1"),
+            }]),
+            stdin: None,
+            expected_error: None,
+            label: None,
+            hidden: false,
+        },
+        TestCase {
+            id: 1,
+            input_parameters: Box::new([Parameter {
+                value_type: ParameterType::Int,
+                value: String::from("2"),
+            }]),
+            output_parameters: Box::new([Parameter {
+                value_type: ParameterType::Int,
+                value: String::from("2"),
+            }]),
+            stdin: None,
+            expected_error: None,
+            label: None,
+            hidden: false,
+        },
+        TestCase {
+            id: 2,
+            input_parameters: Box::new([Parameter {
+                value_type: ParameterType::Int,
+                value: String::from("3"),
+            }]),
+            output_parameters: Box::new([Parameter {
+                value_type: ParameterType::Int,
+                value: String::from("3"),
+            }]),
+            stdin: None,
+            expected_error: None,
+            label: None,
+            hidden: false,
+        },
+        TestCase {
+            id: 3,
+            input_parameters: Box::new([Parameter {
+                value_type: ParameterType::Int,
+                value: String::from("4"),
+            }]),
+            output_parameters: Box::new([Parameter {
+                value_type: ParameterType::Int,
+                value: String::from("4"),
+            }]),
+            stdin: None,
+            expected_error: None,
+            label: None,
+            hidden: false,
+        },
+        TestCase {
+            id: 4,
+            input_parameters: Box::new([Parameter {
+                value_type: ParameterType::Int,
+                value: String::from("5"),
+            }]),
+            output_parameters: Box::new([Parameter {
+                value_type: ParameterType::Int,
+                value: String::from("5"),
+            }]),
+            stdin: None,
+            expected_error: None,
+            label: None,
+            hidden: false,
+        },
+    ]);
+    let submission = Submission {
+        solution,
+        test_cases,
+        unordered_lists: false,
+        files: Box::new([]),
+        checker: None,
+        reference_solution: None,
+        only_ids: None,
+        compiler_flags: Box::new([]),
+        debug: false,
+        float_tolerance: None,
+        toolchain_version: None,
+        metadata: None,
+    };
+    let body = serde_json::to_string(&submission).expect("failed to serialize submission");
+    let request = Builder::new()
+        .header("Content-Type", "application/json")
+        .method(Method::POST)
+        .uri("/submit")
+        .body(Body::from(body))
+        .expect("failed to build request");
+    let expected_status = StatusCode::OK;
+    let expected_body = SubmissionResult::Failure(Box::new([
+        TestCaseResult {
+            id: 0,
+            test_result: TestResult::Pass,
+            label: None,
+        },
+        TestCaseResult {
+            id: 1,
+            test_result: TestResult::Pass,
+            label: None,
+        },
+        TestCaseResult {
+            id: 2,
+            test_result: TestResult::Failure(TestCaseFailureReason::Timeout),
+            label: None,
+        },
+        TestCaseResult {
+            id: 3,
+            test_result: TestResult::Unknown,
+            label: None,
+        },
+        TestCaseResult {
+            id: 4,
+            test_result: TestResult::Unknown,
+            label: None,
+        },
+    ]));
+
+    let actual = mozart
+        .oneshot(request)
+        .await
+        .expect("failed to execute oneshot request");
+
+    let actual_status = actual.status();
+    let body_bytes = to_bytes(actual.into_body(), usize::MAX)
+        .await
+        .expect("failed to convert body to bytes");
+
+    let actual_body: SubmissionResult =
+        serde_json::from_slice(&body_bytes).expect("failed to deserialize response body");
+
+    assert_eq!(actual_status, expected_status);
+    assert_eq!(actual_body, expected_body);
+}
+
+#[tokio::test]
+async fn per_test_case_timeout() {
+    let mozart = app(AppState::default());
+    let solution = [
+        "module Solution where",
+        "",
+        "solution :: Int -> Int",
+        "solution 3 = solution 3",
+        "solution x = x",
+    ]
+    .join("\n");
+    // the third test case exceeds its own timeout, while the other test cases still run
+    let test_cases = Box::new([
+        TestCase {
+            id: 0,
+            input_parameters: Box::new([Parameter {
+                value_type: ParameterType::Int,
+                value: String::from("1"),
+            }]),
+            output_parameters: Box::new([Parameter {
+                value_type: ParameterType::Int,
+                value: String::from("1"),
+            }]),
+            stdin: None,
+            expected_error: None,
+            label: None,
+            hidden: false,
+        },
+        TestCase {
+            id: 1,
+            input_parameters: Box::new([Parameter {
+                value_type: ParameterType::Int,
+                value: String::from("2"),
+            }]),
+            output_parameters: Box::new([Parameter {
+                value_type: ParameterType::Int,
+                value: String::from("2"),
+            }]),
+            stdin: None,
+            expected_error: None,
+            label: None,
+            hidden: false,
+        },
+        TestCase {
+            id: 2,
+            input_parameters: Box::new([Parameter {
+                value_type: ParameterType::Int,
+                value: String::from("3"),
+            }]),
+            output_parameters: Box::new([Parameter {
+                value_type: ParameterType::Int,
+                value: String::from("3"),
+            }]),
+            stdin: None,
+            expected_error: None,
+            label: None,
+            hidden: false,
+        },
+        TestCase {
+            id: 3,
+            input_parameters: Box::new([Parameter {
+                value_type: ParameterType::Int,
+                value: String::from("4"),
+            }]),
+            output_parameters: Box::new([Parameter {
+                value_type: ParameterType::Int,
+                value: String::from("4"),
+            }]),
+            stdin: None,
+            expected_error: None,
+            label: None,
+            hidden: false,
+        },
+        TestCase {
+            id: 4,
+            input_parameters: Box::new([Parameter {
+                value_type: ParameterType::Int,
+                value: String::from("5"),
+            }]),
+            output_parameters: Box::new([Parameter {
+                value_type: ParameterType::Int,
+                value: String::from("5"),
+            }]),
+            stdin: None,
+            expected_error: None,
+            label: None,
+            hidden: false,
+        },
+    ]);
+    let submission = Submission {
+        solution,
+        test_cases,
+        unordered_lists: false,
+        files: Box::new([]),
+        checker: None,
+        reference_solution: None,
+        only_ids: None,
+        compiler_flags: Box::new([]),
+        debug: false,
+        float_tolerance: None,
+        toolchain_version: None,
+        metadata: None,
+    };
+    let body = serde_json::to_string(&submission).expect("failed to serialize submission");
+    let request = Builder::new()
+        .header("Content-Type", "application/json")
+        .method(Method::POST)
+        .uri("/submit")
+        .body(Body::from(body))
+        .expect("failed to build request");
+    let expected_status = StatusCode::OK;
+    let expected_body = SubmissionResult::Failure(Box::new([
+        TestCaseResult {
+            id: 0,
+            test_result: TestResult::Pass,
+            label: None,
+        },
+        TestCaseResult {
+            id: 1,
+            test_result: TestResult::Pass,
+            label: None,
+        },
+        TestCaseResult {
+            id: 2,
+            test_result: TestResult::Failure(TestCaseFailureReason::Timeout),
+            label: None,
+        },
+        TestCaseResult {
+            id: 3,
+            test_result: TestResult::Pass,
+            label: None,
+        },
+        TestCaseResult {
+            id: 4,
+            test_result: TestResult::Pass,
+            label: None,
+        },
+    ]));
+
+    let actual = mozart
+        .oneshot(request)
+        .await
+        .expect("failed to execute oneshot request");
+
+    let actual_status = actual.status();
+    let body_bytes = to_bytes(actual.into_body(), usize::MAX)
+        .await
+        .expect("failed to convert body to bytes");
+
+    let actual_body: SubmissionResult =
+        serde_json::from_slice(&body_bytes).expect("failed to deserialize response body");
+
+    assert_eq!(actual_status, expected_status);
+    assert_eq!(actual_body, expected_body);
+}
+
+#[tokio::test]
+async fn int32_wraps_on_overflow() {
+    let mozart = app(AppState::default());
+    let solution = [
+        "module Solution where",
+        "",
+        "import Data.Int",
+        "",
+        "solution :: Int32 -> Int32",
+        "solution x = x * 2",
+    ]
+    .join("\n");
+    // doubling the value overflows 32 bits
+    let test_cases = Box::new([TestCase {
+        id: 0,
+        input_parameters: Box::new([Parameter {
+            value_type: ParameterType::Int32,
+            value: String::from("1073741824"),
+        }]),
+        output_parameters: Box::new([Parameter {
+            value_type: ParameterType::Int32,
+            value: String::from("-2147483648"),
+        }]),
+        stdin: None,
+        expected_error: None,
+        label: None,
+        hidden: false,
+    }]);
+    let submission = Submission {
+        solution,
+        test_cases,
+        unordered_lists: false,
+        files: Box::new([]),
+        checker: None,
+        reference_solution: None,
+        only_ids: None,
+        compiler_flags: Box::new([]),
+        debug: false,
+        float_tolerance: None,
+        toolchain_version: None,
+        metadata: None,
+    };
+    let body = serde_json::to_string(&submission).expect("failed to serialize submission");
+    let request = Builder::new()
+        .header("Content-Type", "application/json")
+        .method(Method::POST)
+        .uri("/submit")
+        .body(Body::from(body))
+        .expect("failed to build request");
+    let expected_status = StatusCode::OK;
+    let expected_body = SubmissionResult::Pass;
+
+    let actual = mozart
+        .oneshot(request)
+        .await
+        .expect("failed to execute oneshot request");
+
+    let actual_status = actual.status();
+    let body_bytes = to_bytes(actual.into_body(), usize::MAX)
+        .await
+        .expect("failed to convert body to bytes");
+
+    let actual_body: SubmissionResult =
+        serde_json::from_slice(&body_bytes).expect("failed to deserialize response body");
+
+    assert_eq!(actual_status, expected_status);
+    assert_eq!(actual_body, expected_body);
+}
+
+#[tokio::test]
+async fn int64_beyond_32_bits() {
+    let mozart = app(AppState::default());
+    let solution = [
+        "module Solution where",
+        "",
+        "import Data.Int",
+        "",
+        "solution :: Int64 -> Int64",
+        "solution x = x * 2",
+    ]
+    .join("\n");
+    let test_cases = Box::new([TestCase {
+        id: 0,
+        input_parameters: Box::new([Parameter {
+            value_type: ParameterType::Int64,
+            value: String::from("1073741824"),
+        }]),
+        output_parameters: Box::new([Parameter {
+            value_type: ParameterType::Int64,
+            value: String::from("2147483648"),
+        }]),
+        stdin: None,
+        expected_error: None,
+        label: None,
+        hidden: false,
+    }]);
+    let submission = Submission {
+        solution,
+        test_cases,
+        unordered_lists: false,
+        files: Box::new([]),
+        checker: None,
+        reference_solution: None,
+        only_ids: None,
+        compiler_flags: Box::new([]),
+        debug: false,
+        float_tolerance: None,
+        toolchain_version: None,
+        metadata: None,
+    };
+    let body = serde_json::to_string(&submission).expect("failed to serialize submission");
+    let request = Builder::new()
+        .header("Content-Type", "application/json")
+        .method(Method::POST)
+        .uri("/submit")
+        .body(Body::from(body))
+        .expect("failed to build request");
+    let expected_status = StatusCode::OK;
+    let expected_body = SubmissionResult::Pass;
+
+    let actual = mozart
+        .oneshot(request)
+        .await
+        .expect("failed to execute oneshot request");
+
+    let actual_status = actual.status();
+    let body_bytes = to_bytes(actual.into_body(), usize::MAX)
+        .await
+        .expect("failed to convert body to bytes");
+
+    let actual_body: SubmissionResult =
+        serde_json::from_slice(&body_bytes).expect("failed to deserialize response body");
+
+    assert_eq!(actual_status, expected_status);
+    assert_eq!(actual_body, expected_body);
+}
+
+#[tokio::test]
+async fn int32_overflowing_value() {
+    let mozart = app(AppState::default());
+    let solution = [
+        "module Solution where",
+        "",
+        "import Data.Int",
+        "",
+        "solution :: Int32 -> Int32",
+        "solution x = x * 2",
+    ]
+    .join("\n");
+    let test_cases = Box::new([TestCase {
+        id: 0,
+        input_parameters: Box::new([Parameter {
+            value_type: ParameterType::Int32,
+            value: String::from("2147483648"),
+        }]),
+        output_parameters: Box::new([Parameter {
+            value_type: ParameterType::Int32,
+            value: String::from("0"),
+        }]),
+        stdin: None,
+        expected_error: None,
+        label: None,
+        hidden: false,
+    }]);
+    let submission = Submission {
+        solution,
+        test_cases,
+        unordered_lists: false,
+        files: Box::new([]),
+        checker: None,
+        reference_solution: None,
+        only_ids: None,
+        compiler_flags: Box::new([]),
+        debug: false,
+        float_tolerance: None,
+        toolchain_version: None,
+        metadata: None,
+    };
+    let body = serde_json::to_string(&submission).expect("failed to serialize submission");
+    let request = Builder::new()
+        .header("Content-Type", "application/json")
+        .method(Method::POST)
+        .uri("/submit")
+        .body(Body::from(body))
+        .expect("failed to build request");
+    let expected_status = StatusCode::OK;
+
+    let actual = mozart
+        .oneshot(request)
+        .await
+        .expect("failed to execute oneshot request");
+
+    let actual_status = actual.status();
+    let body_bytes = to_bytes(actual.into_body(), usize::MAX)
+        .await
+        .expect("failed to convert body to bytes");
+
+    let actual_body: SubmissionResult =
+        serde_json::from_slice(&body_bytes).expect("failed to deserialize response body");
+
+    assert_eq!(actual_status, expected_status);
+
+    if let SubmissionResult::Error { code, message } = actual_body {
+        assert_eq!(code, ErrorCode::InvalidParameter);
+        assert!(message.starts_with(
+            "invalid parameter: test case 0, input parameter 0: '2147483648' is not a valid Int32"
+        ));
+    } else {
+        panic!("response body was not of error variant");
+    }
+}
+
+#[tokio::test]
+async fn crash_after_pass() {
+    let mozart = app(AppState::default());
+    let solution = [
+        "{-# LANGUAGE ForeignFunctionInterface #-}",
+        "module Solution where",
+        "",
+        "import Foreign.C.Types",
+        "import System.IO",
+        "import System.IO.Unsafe",
+        "",
+        "foreign import ccall \"exit\" c_exit :: CInt -> IO ()",
+        "",
+        "solution :: Int -> Int",
+        "solution 2 = unsafePerformIO (hPutStrLn stderr \"boom\" >> c_exit 1 >> return 2)",
+        "solution x = x",
+    ]
+    .join("\n");
+    // exiting the process directly bypasses the exception handling and the flushing of buffered output
+    let test_cases = Box::new([
+        TestCase {
+            id: 0,
+            input_parameters: Box::new([Parameter {
+                value_type: ParameterType::Int,
+                value: String::from("1"),
+            }]),
+            output_parameters: Box::new([Parameter {
+                value_type: ParameterType::Int,
+                value: String::from("1"),
+            }]),
+            stdin: None,
+            expected_error: None,
+            label: None,
+            hidden: false,
+        },
+        TestCase {
+            id: 1,
+            input_parameters: Box::new([Parameter {
+                value_type: ParameterType::Int,
+                value: String::from("2"),
+            }]),
+            output_parameters: Box::new([Parameter {
+                value_type: ParameterType::Int,
+                value: String::from("2"),
+            }]),
+            stdin: None,
+            expected_error: None,
+            label: None,
+            hidden: false,
+        },
+    ]);
+    let submission = Submission {
+        solution,
+        test_cases,
+        unordered_lists: false,
+        files: Box::new([]),
+        checker: None,
+        reference_solution: None,
+        only_ids: None,
+        compiler_flags: Box::new([]),
+        debug: false,
+        float_tolerance: None,
+        toolchain_version: None,
+        metadata: None,
+    };
+    let body = serde_json::to_string(&submission).expect("failed to serialize submission");
+    let request = Builder::new()
+        .header("Content-Type", "application/json")
+        .method(Method::POST)
+        .uri("/submit")
+        .body(Body::from(body))
+        .expect("failed to build request");
+    let expected_status = StatusCode::OK;
+    let expected_body = SubmissionResult::Failure(Box::new([
+        TestCaseResult {
+            id: 0,
+            test_result: TestResult::Pass,
+            label: None,
+        },
+        TestCaseResult {
+            id: 1,
+            test_result: TestResult::Failure(TestCaseFailureReason::RuntimeError(String::from(
+                "boom\n",
+            ))),
+            label: None,
+        },
+    ]));
+
+    let actual = mozart
+        .oneshot(request)
+        .await
+        .expect("failed to execute oneshot request");
+
+    let actual_status = actual.status();
+    let body_bytes = to_bytes(actual.into_body(), usize::MAX)
+        .await
+        .expect("failed to convert body to bytes");
+
+    let actual_body: SubmissionResult =
+        serde_json::from_slice(&body_bytes).expect("failed to deserialize response body");
+
+    assert_eq!(actual_status, expected_status);
+    assert_eq!(actual_body, expected_body);
+}
+
+#[tokio::test]
+async fn helper_module() {
+    let mozart = app(AppState::default());
+    let solution = [
+        "module Solution where",
+        "",
+        "import Helper (double)",
+        "",
+        "solution :: Int -> Int",
+        "solution x = double x",
+    ]
+    .join("\n");
+    let test_cases = Box::new([TestCase {
+        id: 0,
+        input_parameters: Box::new([Parameter {
+            value_type: ParameterType::Int,
+            value: String::from("2"),
+        }]),
+        output_parameters: Box::new([Parameter {
+            value_type: ParameterType::Int,
+            value: String::from("4"),
+        }]),
+        stdin: None,
+        expected_error: None,
+        label: None,
+        hidden: false,
+    }]);
+    let submission = Submission {
+        solution,
+        test_cases,
+        unordered_lists: false,
+        files: Box::new([SourceFile {
+            name: String::from("Helper.hs"),
+            contents: [
+                "module Helper where",
+                "",
+                "double :: Int -> Int",
+                "double x = x + x",
+            ]
+            .join("\n"),
+        }]),
+        checker: None,
+        reference_solution: None,
+        only_ids: None,
+        compiler_flags: Box::new([]),
+        debug: false,
+        float_tolerance: None,
+        toolchain_version: None,
+        metadata: None,
+    };
+    let body = serde_json::to_string(&submission).expect("failed to serialize submission");
+    let request = Builder::new()
+        .header("Content-Type", "application/json")
+        .method(Method::POST)
+        .uri("/submit")
+        .body(Body::from(body))
+        .expect("failed to build request");
+    let expected_status = StatusCode::OK;
+    let expected_body = SubmissionResult::Pass;
+
+    let actual = mozart
+        .oneshot(request)
+        .await
+        .expect("failed to execute oneshot request");
+
+    let actual_status = actual.status();
+    let body_bytes = to_bytes(actual.into_body(), usize::MAX)
+        .await
+        .expect("failed to convert body to bytes");
+
+    let actual_body: SubmissionResult =
+        serde_json::from_slice(&body_bytes).expect("failed to deserialize response body");
+
+    assert_eq!(actual_status, expected_status);
+    assert_eq!(actual_body, expected_body);
+}
+
+#[tokio::test]
+async fn file_name_outside_working_directory() {
+    let mozart = app(AppState::default());
+    let solution = [
+        "module Solution where",
+        "",
+        "solution :: Int -> Int",
+        "solution x = x + x",
+    ]
+    .join("\n");
+    let test_cases = Box::new([TestCase {
+        id: 0,
+        input_parameters: Box::new([Parameter {
+            value_type: ParameterType::Int,
+            value: String::from("2"),
+        }]),
+        output_parameters: Box::new([Parameter {
+            value_type: ParameterType::Int,
+            value: String::from("4"),
+        }]),
+        stdin: None,
+        expected_error: None,
+        label: None,
+        hidden: false,
+    }]);
+    let submission = Submission {
+        solution,
+        test_cases,
+        unordered_lists: false,
+        files: Box::new([SourceFile {
+            name: String::from("../Helper.hs"),
+            contents: ["module Helper where"].join("\n"),
+        }]),
+        checker: None,
+        reference_solution: None,
+        only_ids: None,
+        compiler_flags: Box::new([]),
+        debug: false,
+        float_tolerance: None,
+        toolchain_version: None,
+        metadata: None,
+    };
+    let body = serde_json::to_string(&submission).expect("failed to serialize submission");
+    let request = Builder::new()
+        .header("Content-Type", "application/json")
+        .method(Method::POST)
+        .uri("/submit")
+        .body(Body::from(body))
+        .expect("failed to build request");
+    let expected_status = StatusCode::OK;
+
+    let actual = mozart
+        .oneshot(request)
+        .await
+        .expect("failed to execute oneshot request");
+
+    let actual_status = actual.status();
+    let body_bytes = to_bytes(actual.into_body(), usize::MAX)
+        .await
+        .expect("failed to convert body to bytes");
+
+    let actual_body: SubmissionResult =
+        serde_json::from_slice(&body_bytes).expect("failed to deserialize response body");
+
+    assert_eq!(actual_status, expected_status);
+
+    if let SubmissionResult::Error { code, .. } = actual_body {
+        assert_eq!(code, ErrorCode::InvalidFileName);
+    } else {
+        panic!("response body was not of error variant");
+    }
+}
+
+#[tokio::test]
+async fn file_name_of_test_runner() {
+    let mozart = app(AppState::default());
+    let solution = [
+        "module Solution where",
+        "",
+        "solution :: Int -> Int",
+        "solution x = x + x",
+    ]
+    .join("\n");
+    let test_cases = Box::new([TestCase {
+        id: 0,
+        input_parameters: Box::new([Parameter {
+            value_type: ParameterType::Int,
+            value: String::from("2"),
+        }]),
+        output_parameters: Box::new([Parameter {
+            value_type: ParameterType::Int,
+            value: String::from("4"),
+        }]),
+        stdin: None,
+        expected_error: None,
+        label: None,
+        hidden: false,
+    }]);
+    let submission = Submission {
+        solution,
+        test_cases,
+        unordered_lists: false,
+        files: Box::new([SourceFile {
+            name: String::from("TestRunner.hs"),
+            contents: ["module TestRunner where"].join("\n"),
+        }]),
+        checker: None,
+        reference_solution: None,
+        only_ids: None,
+        compiler_flags: Box::new([]),
+        debug: false,
+        float_tolerance: None,
+        toolchain_version: None,
+        metadata: None,
+    };
+    let body = serde_json::to_string(&submission).expect("failed to serialize submission");
+    let request = Builder::new()
+        .header("Content-Type", "application/json")
+        .method(Method::POST)
+        .uri("/submit")
+        .body(Body::from(body))
+        .expect("failed to build request");
+    let expected_status = StatusCode::OK;
+
+    let actual = mozart
+        .oneshot(request)
+        .await
+        .expect("failed to execute oneshot request");
+
+    let actual_status = actual.status();
+    let body_bytes = to_bytes(actual.into_body(), usize::MAX)
+        .await
+        .expect("failed to convert body to bytes");
+
+    let actual_body: SubmissionResult =
+        serde_json::from_slice(&body_bytes).expect("failed to deserialize response body");
+
+    assert_eq!(actual_status, expected_status);
+
+    if let SubmissionResult::Error { code, .. } = actual_body {
+        assert_eq!(code, ErrorCode::InvalidFileName);
+    } else {
+        panic!("response body was not of error variant");
+    }
+}
+
+#[tokio::test]
+async fn file_name_of_jail_directory() {
+    let mozart = app(AppState::default());
+    let solution = [
+        "module Solution where",
+        "",
+        "solution :: Int -> Int",
+        "solution x = x + x",
+    ]
+    .join("\n");
+    let test_cases = Box::new([TestCase {
+        id: 0,
+        input_parameters: Box::new([Parameter {
+            value_type: ParameterType::Int,
+            value: String::from("2"),
+        }]),
+        output_parameters: Box::new([Parameter {
+            value_type: ParameterType::Int,
+            value: String::from("4"),
+        }]),
+        stdin: None,
+        expected_error: None,
+        label: None,
+        hidden: false,
+    }]);
+    let submission = Submission {
+        solution,
+        test_cases,
+        unordered_lists: false,
+        files: Box::new([SourceFile {
+            name: String::from("lib"),
+            contents: ["module TestRunner where"].join("\n"),
+        }]),
+        checker: None,
+        reference_solution: None,
+        only_ids: None,
+        compiler_flags: Box::new([]),
+        debug: false,
+        float_tolerance: None,
+        toolchain_version: None,
+        metadata: None,
+    };
+    let body = serde_json::to_string(&submission).expect("failed to serialize submission");
+    let request = Builder::new()
+        .header("Content-Type", "application/json")
+        .method(Method::POST)
+        .uri("/submit")
+        .body(Body::from(body))
+        .expect("failed to build request");
+    let expected_status = StatusCode::OK;
+
+    let actual = mozart
+        .oneshot(request)
+        .await
+        .expect("failed to execute oneshot request");
+
+    let actual_status = actual.status();
+    let body_bytes = to_bytes(actual.into_body(), usize::MAX)
+        .await
+        .expect("failed to convert body to bytes");
+
+    let actual_body: SubmissionResult =
+        serde_json::from_slice(&body_bytes).expect("failed to deserialize response body");
+
+    assert_eq!(actual_status, expected_status);
+
+    if let SubmissionResult::Error { code, .. } = actual_body {
+        assert_eq!(code, ErrorCode::InvalidFileName);
+    } else {
+        panic!("response body was not of error variant");
+    }
+}
+
+#[tokio::test]
+async fn empty_solution() {
+    let mozart = app(AppState::default());
+    let solution = String::new();
+    let test_cases = Box::new([TestCase {
+        id: 0,
+        input_parameters: Box::new([Parameter {
+            value_type: ParameterType::Int,
+            value: String::from("2"),
+        }]),
+        output_parameters: Box::new([Parameter {
+            value_type: ParameterType::Int,
+            value: String::from("4"),
+        }]),
+        stdin: None,
+        expected_error: None,
+        label: None,
+        hidden: false,
+    }]);
+    let submission = Submission {
+        solution,
+        test_cases,
+        unordered_lists: false,
+        files: Box::new([]),
+        checker: None,
+        reference_solution: None,
+        only_ids: None,
+        compiler_flags: Box::new([]),
+        debug: false,
+        float_tolerance: None,
+        toolchain_version: None,
+        metadata: None,
+    };
+    let body = serde_json::to_string(&submission).expect("failed to serialize submission");
+    let request = Builder::new()
+        .header("Content-Type", "application/json")
+        .method(Method::POST)
+        .uri("/submit")
+        .body(Body::from(body))
+        .expect("failed to build request");
+    let expected_status = StatusCode::OK;
+    let expected_body = SubmissionResult::Error {
+        code: ErrorCode::EmptySolution,
+        message: String::from("solution is empty"),
+    };
+
+    let actual = mozart
+        .oneshot(request)
+        .await
+        .expect("failed to execute oneshot request");
+
+    let actual_status = actual.status();
+    let body_bytes = to_bytes(actual.into_body(), usize::MAX)
+        .await
+        .expect("failed to convert body to bytes");
+
+    let actual_body: SubmissionResult =
+        serde_json::from_slice(&body_bytes).expect("failed to deserialize response body");
+
+    assert_eq!(actual_status, expected_status);
+    assert_eq!(actual_body, expected_body);
+}
+
+#[tokio::test]
+async fn whitespace_solution() {
+    let mozart = app(AppState::default());
+    let solution = ["  ", "\t"].join("\n");
+    let test_cases = Box::new([TestCase {
+        id: 0,
+        input_parameters: Box::new([Parameter {
+            value_type: ParameterType::Int,
+            value: String::from("2"),
+        }]),
+        output_parameters: Box::new([Parameter {
+            value_type: ParameterType::Int,
+            value: String::from("4"),
+        }]),
+        stdin: None,
+        expected_error: None,
+        label: None,
+        hidden: false,
+    }]);
+    let submission = Submission {
+        solution,
+        test_cases,
+        unordered_lists: false,
+        files: Box::new([]),
+        checker: None,
+        reference_solution: None,
+        only_ids: None,
+        compiler_flags: Box::new([]),
+        debug: false,
+        float_tolerance: None,
+        toolchain_version: None,
+        metadata: None,
+    };
+    let body = serde_json::to_string(&submission).expect("failed to serialize submission");
+    let request = Builder::new()
+        .header("Content-Type", "application/json")
+        .method(Method::POST)
+        .uri("/submit")
+        .body(Body::from(body))
+        .expect("failed to build request");
+    let expected_status = StatusCode::OK;
+    let expected_body = SubmissionResult::Error {
+        code: ErrorCode::EmptySolution,
+        message: String::from("solution is empty"),
+    };
+
+    let actual = mozart
+        .oneshot(request)
+        .await
+        .expect("failed to execute oneshot request");
+
+    let actual_status = actual.status();
+    let body_bytes = to_bytes(actual.into_body(), usize::MAX)
+        .await
+        .expect("failed to convert body to bytes");
+
+    let actual_body: SubmissionResult =
+        serde_json::from_slice(&body_bytes).expect("failed to deserialize response body");
+
+    assert_eq!(actual_status, expected_status);
+    assert_eq!(actual_body, expected_body);
+}
+
+#[tokio::test]
+async fn huge_wrong_answer_is_truncated() {
+    let mozart = app(AppState::default());
+    let solution = [
+        "module Solution where",
+        "",
+        "solution :: Int -> String",
+        "solution n = replicate n 'a'",
+    ]
+    .join("\n");
+    let test_cases = Box::new([TestCase {
+        id: 0,
+        input_parameters: Box::new([Parameter {
+            value_type: ParameterType::Int,
+            value: String::from("1000000"),
+        }]),
+        output_parameters: Box::new([Parameter {
+            value_type: ParameterType::String,
+            value: String::from("a"),
+        }]),
+        stdin: None,
+        expected_error: None,
+        label: None,
+        hidden: false,
+    }]);
+    let submission = Submission {
+        solution,
+        test_cases,
+        unordered_lists: false,
+        files: Box::new([]),
+        checker: None,
+        reference_solution: None,
+        only_ids: None,
+        compiler_flags: Box::new([]),
+        debug: false,
+        float_tolerance: None,
+        toolchain_version: None,
+        metadata: None,
+    };
+    let body = serde_json::to_string(&submission).expect("failed to serialize submission");
+    let request = Builder::new()
+        .header("Content-Type", "application/json")
+        .method(Method::POST)
+        .uri("/submit")
+        .body(Body::from(body))
+        .expect("failed to build request");
+    let expected_status = StatusCode::OK;
+    let expected_body = SubmissionResult::Failure(Box::new([TestCaseResult {
+        id: 0,
+        test_result: TestResult::Failure(TestCaseFailureReason::WrongAnswer {
+            input_parameters: Box::new([Parameter {
+                value_type: ParameterType::Int,
+                value: String::from("1000000"),
+            }]),
+            actual: format!("{}...", "a".repeat(1000)),
+            expected: String::from("a"),
+            actual_value: None,
+            expected_value: None,
+        }),
+        label: None,
+    }]));
+
+    let actual = mozart
+        .oneshot(request)
+        .await
+        .expect("failed to execute oneshot request");
+
+    let actual_status = actual.status();
+    let body_bytes = to_bytes(actual.into_body(), usize::MAX)
+        .await
+        .expect("failed to convert body to bytes");
+
+    let actual_body: SubmissionResult =
+        serde_json::from_slice(&body_bytes).expect("failed to deserialize response body");
+
+    assert_eq!(actual_status, expected_status);
+    assert_eq!(actual_body, expected_body);
+}
+
+#[tokio::test]
+async fn unordered_list() {
+    let solution = [
+        "module Solution where",
+        "",
+        "import Data.List (nub, sort)",
+        "",
+        "solution :: [Int] -> [Int]",
+        "solution = reverse . nub . sort",
+    ]
+    .join("\n");
+    // the solution returns the elements in descending order, which is accepted as the order does not matter
+    let test_cases = Box::new([TestCase {
+        id: 0,
+        input_parameters: Box::new([Parameter {
+            value_type: ParameterType::List(Box::new(ParameterType::Int)),
+            value: String::from(r#"["3", "1", "3", "2"]"#),
+        }]),
+        output_parameters: Box::new([Parameter {
+            value_type: ParameterType::List(Box::new(ParameterType::Int)),
+            value: String::from(r#"["1", "2", "3"]"#),
+        }]),
+        stdin: None,
+        expected_error: None,
+        label: None,
+        hidden: false,
+    }]);
+    let submission = Submission {
+        solution,
+        test_cases,
+        unordered_lists: true,
+        files: Box::new([]),
+        checker: None,
+        reference_solution: None,
+        only_ids: None,
+        compiler_flags: Box::new([]),
+        debug: false,
+        float_tolerance: None,
+        toolchain_version: None,
+        metadata: None,
+    };
+
+    let actual = check_submission(submission, AppState::default()).await;
+
+    assert_eq!(actual, SubmissionResult::Pass);
+}
+
+#[tokio::test]
+async fn ordered_list() {
+    let solution = [
+        "module Solution where",
+        "",
+        "import Data.List (nub, sort)",
+        "",
+        "solution :: [Int] -> [Int]",
+        "solution = reverse . nub . sort",
+    ]
+    .join("\n");
+    let test_cases = Box::new([TestCase {
+        id: 0,
+        input_parameters: Box::new([Parameter {
+            value_type: ParameterType::List(Box::new(ParameterType::Int)),
+            value: String::from(r#"["3", "1", "3", "2"]"#),
+        }]),
+        output_parameters: Box::new([Parameter {
+            value_type: ParameterType::List(Box::new(ParameterType::Int)),
+            value: String::from(r#"["1", "2", "3"]"#),
+        }]),
+        stdin: None,
+        expected_error: None,
+        label: None,
+        hidden: false,
+    }]);
+    let submission = Submission {
+        solution,
+        test_cases,
+        unordered_lists: false,
+        files: Box::new([]),
+        checker: None,
+        reference_solution: None,
+        only_ids: None,
+        compiler_flags: Box::new([]),
+        debug: false,
+        float_tolerance: None,
+        toolchain_version: None,
+        metadata: None,
+    };
+
+    let expected = SubmissionResult::Failure(Box::new([TestCaseResult {
+        id: 0,
+        test_result: TestResult::Failure(TestCaseFailureReason::WrongAnswer {
+            input_parameters: Box::new([Parameter {
+                value_type: ParameterType::List(Box::new(ParameterType::Int)),
+                value: String::from(r#"["3", "1", "3", "2"]"#),
+            }]),
+            actual: String::from("[3,2,1]"),
+            expected: String::from("[1,2,3]"),
+            actual_value: Some(json!([3, 2, 1])),
+            expected_value: Some(json!([1, 2, 3])),
+        }),
+        label: None,
+    }]));
+
+    let actual = check_submission(submission, AppState::default()).await;
+
+    assert_eq!(actual, expected);
+}
+
+#[tokio::test]
+async fn total_time_header() {
+    let mozart = app(AppState::default());
+    let solution = [
+        "module Solution where",
+        "",
+        "solution :: Int -> Int",
+        "solution x = x + x",
+    ]
+    .join("\n");
+    let test_cases = Box::new([TestCase {
+        id: 0,
+        input_parameters: Box::new([Parameter {
+            value_type: ParameterType::Int,
+            value: String::from("2"),
+        }]),
+        output_parameters: Box::new([Parameter {
+            value_type: ParameterType::Int,
+            value: String::from("4"),
+        }]),
+        stdin: None,
+        expected_error: None,
+        label: None,
+        hidden: false,
+    }]);
+    let submission = Submission {
+        solution,
+        test_cases,
+        unordered_lists: false,
+        files: Box::new([]),
+        checker: None,
+        reference_solution: None,
+        only_ids: None,
+        compiler_flags: Box::new([]),
+        debug: false,
+        float_tolerance: None,
+        toolchain_version: None,
+        metadata: None,
+    };
+    let body = serde_json::to_string(&submission).expect("failed to serialize submission");
+    let request = Builder::new()
+        .header("Content-Type", "application/json")
+        .method(Method::POST)
+        .uri("/submit")
+        .body(Body::from(body))
+        .expect("failed to build request");
+    let expected_status = StatusCode::OK;
+    let expected_body = SubmissionResult::Pass;
+
+    let actual = mozart
+        .oneshot(request)
+        .await
+        .expect("failed to execute oneshot request");
+
+    let actual_status = actual.status();
+    let actual_total_ms = actual
+        .headers()
+        .get(TOTAL_TIME_HEADER)
+        .and_then(|value| value.to_str().ok())
+        .and_then(|value| value.parse::<u64>().ok());
+    let body_bytes = to_bytes(actual.into_body(), usize::MAX)
+        .await
+        .expect("failed to convert body to bytes");
+
+    let actual_body: SubmissionResult =
+        serde_json::from_slice(&body_bytes).expect("failed to deserialize response body");
+
+    assert_eq!(actual_status, expected_status);
+    assert_eq!(actual_body, expected_body);
+    assert!(actual_total_ms.is_some_and(|total_ms| total_ms > 0));
+}
+
+// the sandbox is what prevents this, so it cannot be tested without it
+#[cfg(not(feature = "no-sandbox"))]
+#[tokio::test]
+async fn read_file_outside_working_directory() {
+    let mozart = app(AppState::default());
+    let solution = [
+        "module Solution where",
+        "",
+        "import System.IO.Unsafe (unsafePerformIO)",
+        "",
+        "solution :: Int -> String",
+        "solution _ = unsafePerformIO (readFile \"/etc/passwd\")",
+    ]
+    .join("\n");
+    let test_cases = Box::new([TestCase {
+        id: 0,
+        input_parameters: Box::new([Parameter {
+            value_type: ParameterType::Int,
+            value: String::from("0"),
+        }]),
+        output_parameters: Box::new([Parameter {
+            value_type: ParameterType::String,
+            value: String::from(""),
+        }]),
+        stdin: None,
+        expected_error: None,
+        label: None,
+        hidden: false,
+    }]);
+    let submission = Submission {
+        solution,
+        test_cases,
+        unordered_lists: false,
+        files: Box::new([]),
+        checker: None,
+        reference_solution: None,
+        only_ids: None,
+        compiler_flags: Box::new([]),
+        debug: false,
+        float_tolerance: None,
+        toolchain_version: None,
+        metadata: None,
+    };
+    let body = serde_json::to_string(&submission).expect("failed to serialize submission");
+    let request = Builder::new()
+        .header("Content-Type", "application/json")
+        .method(Method::POST)
+        .uri("/submit")
+        .body(Body::from(body))
+        .expect("failed to build request");
+    let expected_status = StatusCode::OK;
+    let expected_body = SubmissionResult::Failure(Box::new([TestCaseResult {
+        id: 0,
+        test_result: TestResult::Failure(TestCaseFailureReason::RuntimeError(String::from(
+            "/etc/passwd: openFile: does not exist (No such file or directory)",
+        ))),
+        label: None,
+    }]));
+
+    let actual = mozart
+        .oneshot(request)
+        .await
+        .expect("failed to execute oneshot request");
+
+    let actual_status = actual.status();
+    let body_bytes = to_bytes(actual.into_body(), usize::MAX)
+        .await
+        .expect("failed to convert body to bytes");
+
+    let actual_body: SubmissionResult =
+        serde_json::from_slice(&body_bytes).expect("failed to deserialize response body");
+
+    assert_eq!(actual_status, expected_status);
+    assert_eq!(actual_body, expected_body);
+}
+
+#[tokio::test]
+async fn values_with_delimiters() {
+    let mozart = app(AppState::default());
+    let solution = [
+        "module Solution where",
+        "",
+        "import Control.Exception",
+        "",
+        "solution :: Int -> String",
+        r#"solution 2 = throw (userError "invalid value, got \"2\"")"#,
+        r#"solution _ = "a, \"b\"\nc""#,
+    ]
+    .join("\n");
+    // commas, quotes and newlines must not break the reporting of the outcome
+    let test_cases = Box::new([
+        TestCase {
+            id: 0,
+            input_parameters: Box::new([Parameter {
+                value_type: ParameterType::Int,
+                value: String::from("1"),
+            }]),
+            output_parameters: Box::new([Parameter {
+                value_type: ParameterType::String,
+                value: String::from("x"),
+            }]),
+            stdin: None,
+            expected_error: None,
+            label: None,
+            hidden: false,
+        },
+        TestCase {
+            id: 1,
+            input_parameters: Box::new([Parameter {
+                value_type: ParameterType::Int,
+                value: String::from("2"),
+            }]),
+            output_parameters: Box::new([Parameter {
+                value_type: ParameterType::String,
+                value: String::from("x"),
+            }]),
+            stdin: None,
+            expected_error: None,
+            label: None,
+            hidden: false,
+        },
+    ]);
+    let submission = Submission {
+        solution,
+        test_cases,
+        unordered_lists: false,
+        files: Box::new([]),
+        checker: None,
+        reference_solution: None,
+        only_ids: None,
+        compiler_flags: Box::new([]),
+        debug: false,
+        float_tolerance: None,
+        toolchain_version: None,
+        metadata: None,
+    };
+    let body = serde_json::to_string(&submission).expect("failed to serialize submission");
+    let request = Builder::new()
+        .header("Content-Type", "application/json")
+        .method(Method::POST)
+        .uri("/submit")
+        .body(Body::from(body))
+        .expect("failed to build request");
+    let expected_status = StatusCode::OK;
+    let expected_body = SubmissionResult::Failure(Box::new([
+        TestCaseResult {
+            id: 0,
+            test_result: TestResult::Failure(TestCaseFailureReason::WrongAnswer {
+                input_parameters: Box::new([Parameter {
+                    value_type: ParameterType::Int,
+                    value: String::from("1"),
+                }]),
+                actual: String::from("a, \"b\"\nc"),
+                expected: String::from("x"),
+                actual_value: None,
+                expected_value: None,
+            }),
+            label: None,
+        },
+        TestCaseResult {
+            id: 1,
+            test_result: TestResult::Failure(TestCaseFailureReason::RuntimeError(String::from(
+                r#"user error (invalid value, got "2")"#,
+            ))),
+            label: None,
+        },
+    ]));
+
+    let actual = mozart
+        .oneshot(request)
+        .await
+        .expect("failed to execute oneshot request");
+
+    let actual_status = actual.status();
+    let body_bytes = to_bytes(actual.into_body(), usize::MAX)
+        .await
+        .expect("failed to convert body to bytes");
+
+    let actual_body: SubmissionResult =
+        serde_json::from_slice(&body_bytes).expect("failed to deserialize response body");
+
+    assert_eq!(actual_status, expected_status);
+    assert_eq!(actual_body, expected_body);
+}
+
+#[tokio::test]
+async fn custom_checker() {
+    let mozart = app(AppState::default());
+    let solution = [
+        "module Solution where",
+        "",
+        "solution :: Int -> String",
+        "solution n = replicate n 'a'",
+    ]
+    .join("\n");
+    // any value with the length of the expected value is accepted
+    let test_cases = Box::new([
+        TestCase {
+            id: 0,
+            input_parameters: Box::new([Parameter {
+                value_type: ParameterType::Int,
+                value: String::from("3"),
+            }]),
+            output_parameters: Box::new([Parameter {
+                value_type: ParameterType::String,
+                value: String::from("xyz"),
+            }]),
+            stdin: None,
+            expected_error: None,
+            label: None,
+            hidden: false,
+        },
+        TestCase {
+            id: 1,
+            input_parameters: Box::new([Parameter {
+                value_type: ParameterType::Int,
+                value: String::from("2"),
+            }]),
+            output_parameters: Box::new([Parameter {
+                value_type: ParameterType::String,
+                value: String::from("xyz"),
+            }]),
+            stdin: None,
+            expected_error: None,
+            label: None,
+            hidden: false,
+        },
+    ]);
+    let submission = Submission {
+        solution,
+        test_cases,
+        unordered_lists: false,
+        files: Box::new([]),
+        checker: Some(
+            [
+                "module Checker where",
+                "",
+                "checker actual expected = length actual == length expected",
+            ]
+            .join("\n"),
+        ),
+        reference_solution: None,
+        only_ids: None,
+        compiler_flags: Box::new([]),
+        debug: false,
+        float_tolerance: None,
+        toolchain_version: None,
+        metadata: None,
+    };
+    let body = serde_json::to_string(&submission).expect("failed to serialize submission");
+    let request = Builder::new()
+        .header("Content-Type", "application/json")
+        .method(Method::POST)
+        .uri("/submit")
+        .body(Body::from(body))
+        .expect("failed to build request");
+    let expected_status = StatusCode::OK;
+    let expected_body = SubmissionResult::Failure(Box::new([
+        TestCaseResult {
+            id: 0,
+            test_result: TestResult::Pass,
+            label: None,
+        },
+        TestCaseResult {
+            id: 1,
+            test_result: TestResult::Failure(TestCaseFailureReason::WrongAnswer {
+                input_parameters: Box::new([Parameter {
+                    value_type: ParameterType::Int,
+                    value: String::from("2"),
+                }]),
+                actual: String::from("aa"),
+                expected: String::from("xyz"),
+                actual_value: None,
+                expected_value: None,
+            }),
+            label: None,
+        },
+    ]));
+
+    let actual = mozart
+        .oneshot(request)
+        .await
+        .expect("failed to execute oneshot request");
+
+    let actual_status = actual.status();
+    let body_bytes = to_bytes(actual.into_body(), usize::MAX)
+        .await
+        .expect("failed to convert body to bytes");
+
+    let actual_body: SubmissionResult =
+        serde_json::from_slice(&body_bytes).expect("failed to deserialize response body");
+
+    assert_eq!(actual_status, expected_status);
+    assert_eq!(actual_body, expected_body);
+}
+
+#[tokio::test]
+async fn empty_checker() {
+    let mozart = app(AppState::default());
+    let solution = [
+        "module Solution where",
+        "",
+        "solution :: Int -> String",
+        "solution n = replicate n 'a'",
+    ]
+    .join("\n");
+    let test_cases = Box::new([TestCase {
+        id: 0,
+        input_parameters: Box::new([Parameter {
+            value_type: ParameterType::Int,
+            value: String::from("3"),
+        }]),
+        output_parameters: Box::new([Parameter {
+            value_type: ParameterType::String,
+            value: String::from("xyz"),
+        }]),
+        stdin: None,
+        expected_error: None,
+        label: None,
+        hidden: false,
+    }]);
+    let submission = Submission {
+        solution,
+        test_cases,
+        unordered_lists: false,
+        files: Box::new([]),
+        checker: Some(String::from("  ")),
+        reference_solution: None,
+        only_ids: None,
+        compiler_flags: Box::new([]),
+        debug: false,
+        float_tolerance: None,
+        toolchain_version: None,
+        metadata: None,
+    };
+    let body = serde_json::to_string(&submission).expect("failed to serialize submission");
+    let request = Builder::new()
+        .header("Content-Type", "application/json")
+        .method(Method::POST)
+        .uri("/submit")
+        .body(Body::from(body))
+        .expect("failed to build request");
+    let expected_status = StatusCode::OK;
+
+    let actual = mozart
+        .oneshot(request)
+        .await
+        .expect("failed to execute oneshot request");
+
+    let actual_status = actual.status();
+    let body_bytes = to_bytes(actual.into_body(), usize::MAX)
+        .await
+        .expect("failed to convert body to bytes");
+
+    let actual_body: SubmissionResult =
+        serde_json::from_slice(&body_bytes).expect("failed to deserialize response body");
+
+    assert_eq!(actual_status, expected_status);
+
+    if let SubmissionResult::Error { code, .. } = actual_body {
+        assert_eq!(code, ErrorCode::EmptyChecker);
+    } else {
+        panic!("response body was not of error variant");
+    }
+}
+
+#[tokio::test]
+async fn missing_module_declaration() {
+    let mozart = app(AppState::default());
+    let solution = ["solution :: Int -> Int", "solution x = x"].join("\n");
+    let test_cases = Box::new([TestCase {
+        id: 0,
+        input_parameters: Box::new([Parameter {
+            value_type: ParameterType::Int,
+            value: String::from("1"),
+        }]),
+        output_parameters: Box::new([Parameter {
+            value_type: ParameterType::Int,
+            value: String::from("1"),
+        }]),
+        stdin: None,
+        expected_error: None,
+        label: None,
+        hidden: false,
+    }]);
+    let submission = Submission {
+        solution,
+        test_cases,
+        unordered_lists: false,
+        files: Box::new([]),
+        checker: None,
+        reference_solution: None,
+        only_ids: None,
+        compiler_flags: Box::new([]),
+        debug: false,
+        float_tolerance: None,
+        toolchain_version: None,
+        metadata: None,
+    };
+    let body = serde_json::to_string(&submission).expect("failed to serialize submission");
+    let request = Builder::new()
+        .header("Content-Type", "application/json")
+        .method(Method::POST)
+        .uri("/submit")
+        .body(Body::from(body))
+        .expect("failed to build request");
+    let expected_status = StatusCode::OK;
+
+    let actual = mozart
+        .oneshot(request)
+        .await
+        .expect("failed to execute oneshot request");
+
+    let actual_status = actual.status();
+    let body_bytes = to_bytes(actual.into_body(), usize::MAX)
+        .await
+        .expect("failed to convert body to bytes");
+
+    let actual_body: SubmissionResult =
+        serde_json::from_slice(&body_bytes).expect("failed to deserialize response body");
+
+    assert_eq!(actual_status, expected_status);
+
+    if let SubmissionResult::Error { code, message } = actual_body {
+        assert_eq!(code, ErrorCode::InvalidSolution);
+        assert!(message
+            .starts_with("invalid solution: the solution must start with `module Solution where`"));
+    } else {
+        panic!("response body was not of error variant");
+    }
+}
+
+#[tokio::test]
+async fn wrong_module_name() {
+    let mozart = app(AppState::default());
+    let solution = [
+        "module Main where",
+        "",
+        "solution :: Int -> Int",
+        "solution x = x",
+    ]
+    .join("\n");
+    let test_cases = Box::new([TestCase {
+        id: 0,
+        input_parameters: Box::new([Parameter {
+            value_type: ParameterType::Int,
+            value: String::from("1"),
+        }]),
+        output_parameters: Box::new([Parameter {
+            value_type: ParameterType::Int,
+            value: String::from("1"),
+        }]),
+        stdin: None,
+        expected_error: None,
+        label: None,
+        hidden: false,
+    }]);
+    let submission = Submission {
+        solution,
+        test_cases,
+        unordered_lists: false,
+        files: Box::new([]),
+        checker: None,
+        reference_solution: None,
+        only_ids: None,
+        compiler_flags: Box::new([]),
+        debug: false,
+        float_tolerance: None,
+        toolchain_version: None,
+        metadata: None,
+    };
+    let body = serde_json::to_string(&submission).expect("failed to serialize submission");
+    let request = Builder::new()
+        .header("Content-Type", "application/json")
+        .method(Method::POST)
+        .uri("/submit")
+        .body(Body::from(body))
+        .expect("failed to build request");
+    let expected_status = StatusCode::OK;
+
+    let actual = mozart
+        .oneshot(request)
+        .await
+        .expect("failed to execute oneshot request");
+
+    let actual_status = actual.status();
+    let body_bytes = to_bytes(actual.into_body(), usize::MAX)
+        .await
+        .expect("failed to convert body to bytes");
+
+    let actual_body: SubmissionResult =
+        serde_json::from_slice(&body_bytes).expect("failed to deserialize response body");
+
+    assert_eq!(actual_status, expected_status);
+
+    if let SubmissionResult::Error { code, message } = actual_body {
+        assert_eq!(code, ErrorCode::InvalidSolution);
+        assert!(message.starts_with("invalid solution: the solution must be declared as `module Solution where`, not as module `Main`"));
+    } else {
+        panic!("response body was not of error variant");
+    }
+}
+
+#[tokio::test]
+async fn solution_defining_main() {
+    let mozart = app(AppState::default());
+    let solution = [
+        "module Solution where",
+        "",
+        "main :: IO ()",
+        "main = print (solution 1)",
+        "",
+        "solution :: Int -> Int",
+        "solution x = x",
+    ]
+    .join("\n");
+    // the main of the solution must not clash with the main of the test code
+    let test_cases = Box::new([TestCase {
+        id: 0,
+        input_parameters: Box::new([Parameter {
+            value_type: ParameterType::Int,
+            value: String::from("1"),
+        }]),
+        output_parameters: Box::new([Parameter {
+            value_type: ParameterType::Int,
+            value: String::from("1"),
+        }]),
+        stdin: None,
+        expected_error: None,
+        label: None,
+        hidden: false,
+    }]);
+    let submission = Submission {
+        solution,
+        test_cases,
+        unordered_lists: false,
+        files: Box::new([]),
+        checker: None,
+        reference_solution: None,
+        only_ids: None,
+        compiler_flags: Box::new([]),
+        debug: false,
+        float_tolerance: None,
+        toolchain_version: None,
+        metadata: None,
+    };
+    let body = serde_json::to_string(&submission).expect("failed to serialize submission");
+    let request = Builder::new()
+        .header("Content-Type", "application/json")
+        .method(Method::POST)
+        .uri("/submit")
+        .body(Body::from(body))
+        .expect("failed to build request");
+    let expected_status = StatusCode::OK;
+    let expected_body = SubmissionResult::Pass;
+
+    let actual = mozart
+        .oneshot(request)
+        .await
+        .expect("failed to execute oneshot request");
+
+    let actual_status = actual.status();
+    let body_bytes = to_bytes(actual.into_body(), usize::MAX)
+        .await
+        .expect("failed to convert body to bytes");
+
+    let actual_body: SubmissionResult =
+        serde_json::from_slice(&body_bytes).expect("failed to deserialize response body");
+
+    assert_eq!(actual_status, expected_status);
+    assert_eq!(actual_body, expected_body);
+}
+
+#[tokio::test]
+async fn read_stdin() {
+    let mozart = app(AppState::default());
+    let solution = [
+        "module Solution where",
+        "",
+        "import System.IO",
+        "import System.IO.Unsafe",
+        "",
+        "solution :: Int -> String",
+        "solution n = unsafePerformIO $ n `seq` do",
+        "  eof <- isEOF",
+        "  if eof then return \"\" else getLine",
+    ]
+    .join("\n");
+    // every test case reads from its own stdin, which is empty if it is not supplied
+    // the inputs differ, so that the reads cannot be shared between the test cases
+    let test_cases = Box::new([
+        TestCase {
+            id: 0,
+            input_parameters: Box::new([Parameter {
+                value_type: ParameterType::Int,
+                value: String::from("0"),
+            }]),
+            output_parameters: Box::new([Parameter {
+                value_type: ParameterType::String,
+                value: String::from("hello"),
+            }]),
+            stdin: Some(String::from("hello\nworld\n")),
+            expected_error: None,
+            label: None,
+            hidden: false,
+        },
+        TestCase {
+            id: 1,
+            input_parameters: Box::new([Parameter {
+                value_type: ParameterType::Int,
+                value: String::from("1"),
+            }]),
+            output_parameters: Box::new([Parameter {
+                value_type: ParameterType::String,
+                value: String::from("world"),
+            }]),
+            stdin: Some(String::from("world\n")),
+            expected_error: None,
+            label: None,
+            hidden: false,
+        },
+        TestCase {
+            id: 2,
+            input_parameters: Box::new([Parameter {
+                value_type: ParameterType::Int,
+                value: String::from("2"),
+            }]),
+            output_parameters: Box::new([Parameter {
+                value_type: ParameterType::String,
+                value: String::from(""),
+            }]),
+            stdin: None,
+            expected_error: None,
+            label: None,
+            hidden: false,
+        },
+    ]);
+    let submission = Submission {
+        solution,
+        test_cases,
+        unordered_lists: false,
+        files: Box::new([]),
+        checker: None,
+        reference_solution: None,
+        only_ids: None,
+        compiler_flags: Box::new([]),
+        debug: false,
+        float_tolerance: None,
+        toolchain_version: None,
+        metadata: None,
+    };
+    let body = serde_json::to_string(&submission).expect("failed to serialize submission");
+    let request = Builder::new()
+        .header("Content-Type", "application/json")
+        .method(Method::POST)
+        .uri("/submit")
+        .body(Body::from(body))
+        .expect("failed to build request");
+    let expected_status = StatusCode::OK;
+    let expected_body = SubmissionResult::Pass;
+
+    let actual = mozart
+        .oneshot(request)
+        .await
+        .expect("failed to execute oneshot request");
+
+    let actual_status = actual.status();
+    let body_bytes = to_bytes(actual.into_body(), usize::MAX)
+        .await
+        .expect("failed to convert body to bytes");
+
+    let actual_body: SubmissionResult =
+        serde_json::from_slice(&body_bytes).expect("failed to deserialize response body");
+
+    assert_eq!(actual_status, expected_status);
+    assert_eq!(actual_body, expected_body);
+}
+
+#[tokio::test]
+async fn write_huge_file() {
+    let mozart = app(AppState::default());
+    let solution = [
+        "module Solution where",
+        "",
+        "import System.IO.Unsafe",
+        "",
+        "solution :: Int -> Int",
+        "solution x = unsafePerformIO (writeFile \"huge.txt\" (replicate (1024 * 1024 * 1024) 'x') >> return (x + x))",
+    ]
+    .join("\n");
+    // writing a gigabyte must be stopped instead of filling the disk
+    let test_cases = Box::new([TestCase {
+        id: 0,
+        input_parameters: Box::new([Parameter {
+            value_type: ParameterType::Int,
+            value: String::from("2"),
+        }]),
+        output_parameters: Box::new([Parameter {
+            value_type: ParameterType::Int,
+            value: String::from("4"),
+        }]),
+        stdin: None,
+        expected_error: None,
+        label: None,
+        hidden: false,
+    }]);
+    let submission = Submission {
+        solution,
+        test_cases,
+        unordered_lists: false,
+        files: Box::new([]),
+        checker: None,
+        reference_solution: None,
+        only_ids: None,
+        compiler_flags: Box::new([]),
+        debug: false,
+        float_tolerance: None,
+        toolchain_version: None,
+        metadata: None,
+    };
+    let body = serde_json::to_string(&submission).expect("failed to serialize submission");
+    let request = Builder::new()
+        .header("Content-Type", "application/json")
+        .method(Method::POST)
+        .uri("/submit")
+        .body(Body::from(body))
+        .expect("failed to build request");
+    let expected_status = StatusCode::OK;
+
+    let actual = mozart
+        .oneshot(request)
+        .await
+        .expect("failed to execute oneshot request");
+
+    let actual_status = actual.status();
+    let body_bytes = to_bytes(actual.into_body(), usize::MAX)
+        .await
+        .expect("failed to convert body to bytes");
+
+    let actual_body: SubmissionResult =
+        serde_json::from_slice(&body_bytes).expect("failed to deserialize response body");
+
+    assert_eq!(actual_status, expected_status);
+
+    if let SubmissionResult::Failure(test_case_results) = actual_body {
+        assert!(matches!(
+            test_case_results[0].test_result,
+            TestResult::Failure(TestCaseFailureReason::RuntimeError(_))
+        ));
+    } else {
+        panic!("response body was not of failure variant");
+    }
+}
+
+#[tokio::test]
+async fn return_map() {
+    let mozart = app(AppState::default());
+    let solution = [
+        "module Solution where",
+        "",
+        "import qualified Data.Map as Map",
+        "",
+        "solution :: String -> Map.Map String Int",
+        "solution s = Map.fromListWith (+) [(word, 1) | word <- words s]",
+    ]
+    .join("\n");
+    // the entries are inserted in another order than they are listed, which must not matter
+    let test_cases = Box::new([
+        TestCase {
+            id: 0,
+            input_parameters: Box::new([Parameter {
+                value_type: ParameterType::String,
+                value: String::from("pear apple pear"),
+            }]),
+            output_parameters: Box::new([Parameter {
+                value_type: ParameterType::Map {
+                    key: Box::new(ParameterType::String),
+                    value: Box::new(ParameterType::Int),
+                },
+                value: String::from(r#"{"apple": "1", "pear": "2"}"#),
+            }]),
+            stdin: None,
+            expected_error: None,
+            label: None,
+            hidden: false,
+        },
+        TestCase {
+            id: 1,
+            input_parameters: Box::new([Parameter {
+                value_type: ParameterType::String,
+                value: String::from(""),
+            }]),
+            output_parameters: Box::new([Parameter {
+                value_type: ParameterType::Map {
+                    key: Box::new(ParameterType::String),
+                    value: Box::new(ParameterType::Int),
+                },
+                value: String::from("{}"),
+            }]),
+            stdin: None,
+            expected_error: None,
+            label: None,
+            hidden: false,
+        },
+    ]);
+    let submission = Submission {
+        solution,
+        test_cases,
+        unordered_lists: false,
+        files: Box::new([]),
+        checker: None,
+        reference_solution: None,
+        only_ids: None,
+        compiler_flags: Box::new([]),
+        debug: false,
+        float_tolerance: None,
+        toolchain_version: None,
+        metadata: None,
+    };
+    let body = serde_json::to_string(&submission).expect("failed to serialize submission");
+    let request = Builder::new()
+        .header("Content-Type", "application/json")
+        .method(Method::POST)
+        .uri("/submit")
+        .body(Body::from(body))
+        .expect("failed to build request");
+    let expected_status = StatusCode::OK;
+    let expected_body = SubmissionResult::Pass;
+
+    let actual = mozart
+        .oneshot(request)
+        .await
+        .expect("failed to execute oneshot request");
+
+    let actual_status = actual.status();
+    let body_bytes = to_bytes(actual.into_body(), usize::MAX)
+        .await
+        .expect("failed to convert body to bytes");
+
+    let actual_body: SubmissionResult =
+        serde_json::from_slice(&body_bytes).expect("failed to deserialize response body");
+
+    assert_eq!(actual_status, expected_status);
+    assert_eq!(actual_body, expected_body);
+}
+
+#[tokio::test]
+async fn invalid_map_value() {
+    let mozart = app(AppState::default());
+    let solution = [
+        "module Solution where",
+        "",
+        "import qualified Data.Map as Map",
+        "",
+        "solution :: String -> Map.Map String Int",
+        "solution s = Map.fromListWith (+) [(word, 1) | word <- words s]",
+    ]
+    .join("\n");
+    let test_cases = Box::new([TestCase {
+        id: 0,
+        input_parameters: Box::new([Parameter {
+            value_type: ParameterType::String,
+            value: String::from("pear"),
+        }]),
+        output_parameters: Box::new([Parameter {
+            value_type: ParameterType::Map {
+                key: Box::new(ParameterType::String),
+                value: Box::new(ParameterType::Int),
+            },
+            value: String::from(r#"{"pear": "one"}"#),
+        }]),
+        stdin: None,
+        expected_error: None,
+        label: None,
+        hidden: false,
+    }]);
+    let submission = Submission {
+        solution,
+        test_cases,
+        unordered_lists: false,
+        files: Box::new([]),
+        checker: None,
+        reference_solution: None,
+        only_ids: None,
+        compiler_flags: Box::new([]),
+        debug: false,
+        float_tolerance: None,
+        toolchain_version: None,
+        metadata: None,
+    };
+    let body = serde_json::to_string(&submission).expect("failed to serialize submission");
+    let request = Builder::new()
+        .header("Content-Type", "application/json")
+        .method(Method::POST)
+        .uri("/submit")
+        .body(Body::from(body))
+        .expect("failed to build request");
+    let expected_status = StatusCode::OK;
+
+    let actual = mozart
+        .oneshot(request)
+        .await
+        .expect("failed to execute oneshot request");
+
+    let actual_status = actual.status();
+    let body_bytes = to_bytes(actual.into_body(), usize::MAX)
+        .await
+        .expect("failed to convert body to bytes");
+
+    let actual_body: SubmissionResult =
+        serde_json::from_slice(&body_bytes).expect("failed to deserialize response body");
+
+    assert_eq!(actual_status, expected_status);
+
+    if let SubmissionResult::Error { code, .. } = actual_body {
+        assert_eq!(code, ErrorCode::InvalidParameter);
+    } else {
+        panic!("response body was not of error variant");
+    }
+}
+
+#[tokio::test]
+async fn only_ids() {
+    let mozart = app(AppState::default());
+    let solution = [
+        "module Solution where",
+        "",
+        "solution :: Int -> Int",
+        "solution x = x * 2",
+    ]
+    .join("\n");
+    // only the requested test case is run, so the failure of the others is not reported
+    let test_cases = Box::new([
+        TestCase {
+            id: 0,
+            input_parameters: Box::new([Parameter {
+                value_type: ParameterType::Int,
+                value: String::from("0"),
+            }]),
+            output_parameters: Box::new([Parameter {
+                value_type: ParameterType::Int,
+                value: String::from("0"),
+            }]),
+            stdin: None,
+            expected_error: None,
+            label: None,
+            hidden: false,
+        },
+        TestCase {
+            id: 1,
+            input_parameters: Box::new([Parameter {
+                value_type: ParameterType::Int,
+                value: String::from("1"),
+            }]),
+            output_parameters: Box::new([Parameter {
+                value_type: ParameterType::Int,
+                value: String::from("0"),
+            }]),
+            stdin: None,
+            expected_error: None,
+            label: None,
+            hidden: false,
+        },
+        TestCase {
+            id: 2,
+            input_parameters: Box::new([Parameter {
+                value_type: ParameterType::Int,
+                value: String::from("2"),
+            }]),
+            output_parameters: Box::new([Parameter {
+                value_type: ParameterType::Int,
+                value: String::from("4"),
+            }]),
+            stdin: None,
+            expected_error: None,
+            label: None,
+            hidden: false,
+        },
+        TestCase {
+            id: 3,
+            input_parameters: Box::new([Parameter {
+                value_type: ParameterType::Int,
+                value: String::from("3"),
+            }]),
+            output_parameters: Box::new([Parameter {
+                value_type: ParameterType::Int,
+                value: String::from("7"),
+            }]),
+            stdin: None,
+            expected_error: None,
+            label: None,
+            hidden: false,
+        },
+        TestCase {
+            id: 4,
+            input_parameters: Box::new([Parameter {
+                value_type: ParameterType::Int,
+                value: String::from("4"),
+            }]),
+            output_parameters: Box::new([Parameter {
+                value_type: ParameterType::Int,
+                value: String::from("8"),
+            }]),
+            stdin: None,
+            expected_error: None,
+            label: None,
+            hidden: false,
+        },
+    ]);
+    let submission = Submission {
+        solution,
+        test_cases,
+        unordered_lists: false,
+        files: Box::new([]),
+        checker: None,
+        reference_solution: None,
+        only_ids: Some(Box::new([3])),
+        compiler_flags: Box::new([]),
+        debug: false,
+        float_tolerance: None,
+        toolchain_version: None,
+        metadata: None,
+    };
+    let body = serde_json::to_string(&submission).expect("failed to serialize submission");
+    let request = Builder::new()
+        .header("Content-Type", "application/json")
+        .method(Method::POST)
+        .uri("/submit")
+        .body(Body::from(body))
+        .expect("failed to build request");
+    let expected_status = StatusCode::OK;
+    let expected_body = SubmissionResult::Failure(Box::new([TestCaseResult {
+        id: 3,
+        test_result: TestResult::Failure(TestCaseFailureReason::WrongAnswer {
+            input_parameters: Box::new([Parameter {
+                value_type: ParameterType::Int,
+                value: String::from("3"),
+            }]),
+            actual: String::from("6"),
+            expected: String::from("7"),
+            actual_value: None,
+            expected_value: None,
+        }),
+        label: None,
+    }]));
+
+    let actual = mozart
+        .oneshot(request)
+        .await
+        .expect("failed to execute oneshot request");
+
+    let actual_status = actual.status();
+    let body_bytes = to_bytes(actual.into_body(), usize::MAX)
+        .await
+        .expect("failed to convert body to bytes");
+
+    let actual_body: SubmissionResult =
+        serde_json::from_slice(&body_bytes).expect("failed to deserialize response body");
+
+    assert_eq!(actual_status, expected_status);
+    assert_eq!(actual_body, expected_body);
+}
+
+#[tokio::test]
+async fn allowed_compiler_flag() {
+    let mozart = app(AppState::new(Config {
+        allowed_compiler_flags: Box::new([String::from("-XLambdaCase")]),
+        ..Config::default()
+    }));
+    let solution = [
+        "module Solution where",
+        "",
+        "solution :: Int -> Int",
+        "solution = \\case",
+        "  0 -> 1",
+        "  x -> x",
+    ]
+    .join("\n");
+    // the solution only compiles if the flag is passed to the compiler
+    let test_cases = Box::new([
+        TestCase {
+            id: 0,
+            input_parameters: Box::new([Parameter {
+                value_type: ParameterType::Int,
+                value: String::from("0"),
+            }]),
+            output_parameters: Box::new([Parameter {
+                value_type: ParameterType::Int,
+                value: String::from("1"),
+            }]),
+            stdin: None,
+            expected_error: None,
+            label: None,
+            hidden: false,
+        },
+        TestCase {
+            id: 1,
+            input_parameters: Box::new([Parameter {
+                value_type: ParameterType::Int,
+                value: String::from("5"),
+            }]),
+            output_parameters: Box::new([Parameter {
+                value_type: ParameterType::Int,
+                value: String::from("5"),
+            }]),
+            stdin: None,
+            expected_error: None,
+            label: None,
+            hidden: false,
+        },
+    ]);
+    let submission = Submission {
+        solution,
+        test_cases,
+        unordered_lists: false,
+        files: Box::new([]),
+        checker: None,
+        reference_solution: None,
+        only_ids: None,
+        compiler_flags: Box::new([String::from("-XLambdaCase")]),
+        debug: false,
+        float_tolerance: None,
+        toolchain_version: None,
+        metadata: None,
+    };
+    let body = serde_json::to_string(&submission).expect("failed to serialize submission");
+    let request = Builder::new()
+        .header("Content-Type", "application/json")
+        .method(Method::POST)
+        .uri("/submit")
+        .body(Body::from(body))
+        .expect("failed to build request");
+    let expected_status = StatusCode::OK;
+    let expected_body = SubmissionResult::Pass;
+
+    let actual = mozart
+        .oneshot(request)
+        .await
+        .expect("failed to execute oneshot request");
+
+    let actual_status = actual.status();
+    let body_bytes = to_bytes(actual.into_body(), usize::MAX)
+        .await
+        .expect("failed to convert body to bytes");
+
+    let actual_body: SubmissionResult =
+        serde_json::from_slice(&body_bytes).expect("failed to deserialize response body");
+
+    assert_eq!(actual_status, expected_status);
+    assert_eq!(actual_body, expected_body);
+}
+
+#[tokio::test]
+async fn disallowed_compiler_flag() {
+    let mozart = app(AppState::new(Config {
+        allowed_compiler_flags: Box::new([String::from("-XLambdaCase")]),
+        ..Config::default()
+    }));
+    let solution = [
+        "module Solution where",
+        "",
+        "solution :: Int -> Int",
+        "solution = \\case",
+        "  0 -> 1",
+        "  x -> x",
+    ]
+    .join("\n");
+    let test_cases = Box::new([
+        TestCase {
+            id: 0,
+            input_parameters: Box::new([Parameter {
+                value_type: ParameterType::Int,
+                value: String::from("0"),
+            }]),
+            output_parameters: Box::new([Parameter {
+                value_type: ParameterType::Int,
+                value: String::from("1"),
+            }]),
+            stdin: None,
+            expected_error: None,
+            label: None,
+            hidden: false,
+        },
+        TestCase {
+            id: 1,
+            input_parameters: Box::new([Parameter {
+                value_type: ParameterType::Int,
+                value: String::from("5"),
+            }]),
+            output_parameters: Box::new([Parameter {
+                value_type: ParameterType::Int,
+                value: String::from("5"),
+            }]),
+            stdin: None,
+            expected_error: None,
+            label: None,
+            hidden: false,
+        },
+    ]);
+    let submission = Submission {
+        solution,
+        test_cases,
+        unordered_lists: false,
+        files: Box::new([]),
+        checker: None,
+        reference_solution: None,
+        only_ids: None,
+        compiler_flags: Box::new([String::from("-fplugin=Evil")]),
+        debug: false,
+        float_tolerance: None,
+        toolchain_version: None,
+        metadata: None,
+    };
+    let body = serde_json::to_string(&submission).expect("failed to serialize submission");
+    let request = Builder::new()
+        .header("Content-Type", "application/json")
+        .method(Method::POST)
+        .uri("/submit")
+        .body(Body::from(body))
+        .expect("failed to build request");
+    let expected_status = StatusCode::OK;
+
+    let actual = mozart
+        .oneshot(request)
+        .await
+        .expect("failed to execute oneshot request");
+
+    let actual_status = actual.status();
+    let body_bytes = to_bytes(actual.into_body(), usize::MAX)
+        .await
+        .expect("failed to convert body to bytes");
+
+    let actual_body: SubmissionResult =
+        serde_json::from_slice(&body_bytes).expect("failed to deserialize response body");
+
+    assert_eq!(actual_status, expected_status);
+
+    if let SubmissionResult::Error { code, message } = actual_body {
+        assert_eq!(code, ErrorCode::DisallowedCompilerFlag);
+        assert!(message.starts_with("the compiler flag '-fplugin=Evil' is not allowed"));
+    } else {
+        panic!("response body was not of error variant");
+    }
+}
+
+#[tokio::test]
+async fn debug_generated_source() {
+    let mozart = app(AppState::new(Config {
+        allow_debug: true,
+        ..Config::default()
+    }));
+    let solution = [
+        "module Solution where",
+        "",
+        "solution :: String -> String",
+        "solution s = s",
+    ]
+    .join("\n");
+    let test_cases = Box::new([TestCase {
+        id: 0,
+        input_parameters: Box::new([Parameter {
+            value_type: ParameterType::String,
+            value: String::from("mozart-debug"),
+        }]),
+        output_parameters: Box::new([Parameter {
+            value_type: ParameterType::String,
+            value: String::from("mozart-debug"),
+        }]),
+        stdin: None,
+        expected_error: None,
+        label: None,
+        hidden: false,
+    }]);
+    let submission = Submission {
+        solution,
+        test_cases,
+        unordered_lists: false,
+        files: Box::new([]),
+        checker: None,
+        reference_solution: None,
+        only_ids: None,
+        compiler_flags: Box::new([]),
+        debug: true,
+        float_tolerance: None,
+        toolchain_version: None,
+        metadata: None,
+    };
+    let body = serde_json::to_string(&submission).expect("failed to serialize submission");
+    let request = Builder::new()
+        .header("Content-Type", "application/json")
+        .method(Method::POST)
+        .uri("/submit")
+        .body(Body::from(body))
+        .expect("failed to build request");
+    let expected_status = StatusCode::OK;
+    let expected_result = SubmissionResult::Pass;
+
+    let actual = mozart
+        .oneshot(request)
+        .await
+        .expect("failed to execute oneshot request");
+
+    let actual_status = actual.status();
+    let body_bytes = to_bytes(actual.into_body(), usize::MAX)
+        .await
+        .expect("failed to convert body to bytes");
+
+    let actual_body: Value =
+        serde_json::from_slice(&body_bytes).expect("failed to deserialize response body");
+    let actual_result: SubmissionResult =
+        serde_json::from_slice(&body_bytes).expect("failed to deserialize result");
+
+    assert_eq!(actual_status, expected_status);
+    assert_eq!(actual_result, expected_result);
+    // the generated test file contains the test case, which is formatted as a literal of the language
+    let actual_source = actual_body["generatedSource"]
+        .as_str()
+        .expect("generated source should be present");
+    assert!(actual_source.contains("mozart-debug"));
+}
+
+#[tokio::test]
+async fn reference_solution() {
+    let mozart = app(AppState::default());
+    let solution = [
+        "module Solution where",
+        "",
+        "solution :: Int -> Int",
+        "solution x = x ^ 2",
+    ]
+    .join("\n");
+    let reference_solution = [
+        "module Reference where",
+        "",
+        "solution :: Int -> Int",
+        "solution x = x * x",
+    ]
+    .join("\n");
+    // the first test case has no output parameters, so its expected value is computed by the reference solution
+    let test_cases = Box::new([
+        TestCase {
+            id: 0,
+            input_parameters: Box::new([Parameter {
+                value_type: ParameterType::Int,
+                value: String::from("3"),
+            }]),
+            output_parameters: Box::new([]),
+            stdin: None,
+            expected_error: None,
+            label: None,
+            hidden: false,
+        },
+        TestCase {
+            id: 1,
+            input_parameters: Box::new([Parameter {
+                value_type: ParameterType::Int,
+                value: String::from("2"),
+            }]),
+            output_parameters: Box::new([Parameter {
+                value_type: ParameterType::Int,
+                value: String::from("4"),
+            }]),
+            stdin: None,
+            expected_error: None,
+            label: None,
+            hidden: false,
+        },
+    ]);
+    let submission = Submission {
+        solution,
+        test_cases,
+        unordered_lists: false,
+        files: Box::new([]),
+        checker: None,
+        reference_solution: Some(reference_solution),
+        only_ids: None,
+        compiler_flags: Box::new([]),
+        debug: false,
+        float_tolerance: None,
+        toolchain_version: None,
+        metadata: None,
+    };
+    let body = serde_json::to_string(&submission).expect("failed to serialize submission");
+    let request = Builder::new()
+        .header("Content-Type", "application/json")
+        .method(Method::POST)
+        .uri("/submit")
+        .body(Body::from(body))
+        .expect("failed to build request");
+    let expected_status = StatusCode::OK;
+    let expected_body = SubmissionResult::Pass;
+
+    let actual = mozart
+        .oneshot(request)
+        .await
+        .expect("failed to execute oneshot request");
+
+    let actual_status = actual.status();
+    let body_bytes = to_bytes(actual.into_body(), usize::MAX)
+        .await
+        .expect("failed to convert body to bytes");
+
+    let actual_body: SubmissionResult =
+        serde_json::from_slice(&body_bytes).expect("failed to deserialize response body");
+
+    assert_eq!(actual_status, expected_status);
+    assert_eq!(actual_body, expected_body);
+}
+
+#[tokio::test]
+async fn reference_solution_disagrees() {
+    let mozart = app(AppState::default());
+    let solution = [
+        "module Solution where",
+        "",
+        "solution :: Int -> Int",
+        "solution x = x * 2",
+    ]
+    .join("\n");
+    let reference_solution = [
+        "module Reference where",
+        "",
+        "solution :: Int -> Int",
+        "solution x = x * x",
+    ]
+    .join("\n");
+    // the first test case has no output parameters, so its expected value is computed by the reference solution
+    let test_cases = Box::new([
+        TestCase {
+            id: 0,
+            input_parameters: Box::new([Parameter {
+                value_type: ParameterType::Int,
+                value: String::from("3"),
+            }]),
+            output_parameters: Box::new([]),
+            stdin: None,
+            expected_error: None,
+            label: None,
+            hidden: false,
+        },
+        TestCase {
+            id: 1,
+            input_parameters: Box::new([Parameter {
+                value_type: ParameterType::Int,
+                value: String::from("2"),
+            }]),
+            output_parameters: Box::new([Parameter {
+                value_type: ParameterType::Int,
+                value: String::from("4"),
+            }]),
+            stdin: None,
+            expected_error: None,
+            label: None,
+            hidden: false,
+        },
+    ]);
+    let submission = Submission {
+        solution,
+        test_cases,
+        unordered_lists: false,
+        files: Box::new([]),
+        checker: None,
+        reference_solution: Some(reference_solution),
+        only_ids: None,
+        compiler_flags: Box::new([]),
+        debug: false,
+        float_tolerance: None,
+        toolchain_version: None,
+        metadata: None,
+    };
+    let body = serde_json::to_string(&submission).expect("failed to serialize submission");
+    let request = Builder::new()
+        .header("Content-Type", "application/json")
+        .method(Method::POST)
+        .uri("/submit")
+        .body(Body::from(body))
+        .expect("failed to build request");
+    let expected_status = StatusCode::OK;
+    let expected_body = SubmissionResult::Failure(Box::new([
+        TestCaseResult {
+            id: 0,
+            test_result: TestResult::Failure(TestCaseFailureReason::WrongAnswer {
+                input_parameters: Box::new([Parameter {
+                    value_type: ParameterType::Int,
+                    value: String::from("3"),
+                }]),
+                actual: String::from("6"),
+                expected: String::from("9"),
+                actual_value: None,
+                expected_value: None,
+            }),
+            label: None,
+        },
+        TestCaseResult {
+            id: 1,
+            test_result: TestResult::Pass,
+            label: None,
+        },
+    ]));
+
+    let actual = mozart
+        .oneshot(request)
+        .await
+        .expect("failed to execute oneshot request");
+
+    let actual_status = actual.status();
+    let body_bytes = to_bytes(actual.into_body(), usize::MAX)
+        .await
+        .expect("failed to convert body to bytes");
+
+    let actual_body: SubmissionResult =
+        serde_json::from_slice(&body_bytes).expect("failed to deserialize response body");
+
+    assert_eq!(actual_status, expected_status);
+    assert_eq!(actual_body, expected_body);
+}
+
+#[tokio::test]
+async fn float_tolerance_relative() {
+    let mozart = app(AppState::default());
+    let solution = [
+        "module Solution where",
+        "",
+        "solution :: Double -> Double",
+        "solution x = x * 1e12 + 1",
+    ]
+    .join("\n");
+    let test_cases = Box::new([TestCase {
+        id: 0,
+        input_parameters: Box::new([Parameter {
+            value_type: ParameterType::Float,
+            value: String::from("3.0"),
+        }]),
+        output_parameters: Box::new([Parameter {
+            value_type: ParameterType::Float,
+            value: String::from("3000000000000.0"),
+        }]),
+        stdin: None,
+        expected_error: None,
+        label: None,
+        hidden: false,
+    }]);
+    let submission = Submission {
+        solution,
+        test_cases,
+        unordered_lists: false,
+        files: Box::new([]),
+        checker: None,
+        reference_solution: None,
+        only_ids: None,
+        compiler_flags: Box::new([]),
+        debug: false,
+        float_tolerance: Some(FloatTolerance {
+            absolute: 1e-9,
+            relative: 1e-9,
+            match_nan: false,
+        }),
+        toolchain_version: None,
+        metadata: None,
+    };
+    let body = serde_json::to_string(&submission).expect("failed to serialize submission");
+    let request = Builder::new()
+        .header("Content-Type", "application/json")
+        .method(Method::POST)
+        .uri("/submit")
+        .body(Body::from(body))
+        .expect("failed to build request");
+    let expected_status = StatusCode::OK;
+    let expected_body = SubmissionResult::Pass;
+
+    let actual = mozart
+        .oneshot(request)
+        .await
+        .expect("failed to execute oneshot request");
+
+    let actual_status = actual.status();
+    let body_bytes = to_bytes(actual.into_body(), usize::MAX)
+        .await
+        .expect("failed to convert body to bytes");
+
+    let actual_body: SubmissionResult =
+        serde_json::from_slice(&body_bytes).expect("failed to deserialize response body");
+
+    assert_eq!(actual_status, expected_status);
+    assert_eq!(actual_body, expected_body);
+}
+
+#[tokio::test]
+async fn float_tolerance_absolute() {
+    let mozart = app(AppState::default());
+    let solution = [
+        "module Solution where",
+        "",
+        "solution :: Double -> Double",
+        "solution x = x * 1e12 + 1",
+    ]
+    .join("\n");
+    let test_cases = Box::new([TestCase {
+        id: 0,
+        input_parameters: Box::new([Parameter {
+            value_type: ParameterType::Float,
+            value: String::from("3.0"),
+        }]),
+        output_parameters: Box::new([Parameter {
+            value_type: ParameterType::Float,
+            value: String::from("3000000000000.0"),
+        }]),
+        stdin: None,
+        expected_error: None,
+        label: None,
+        hidden: false,
+    }]);
+    let submission = Submission {
+        solution,
+        test_cases,
+        unordered_lists: false,
+        files: Box::new([]),
+        checker: None,
+        reference_solution: None,
+        only_ids: None,
+        compiler_flags: Box::new([]),
+        debug: false,
+        float_tolerance: Some(FloatTolerance {
+            absolute: 1e-9,
+            relative: 0.0,
+            match_nan: false,
+        }),
+        toolchain_version: None,
+        metadata: None,
+    };
+    let body = serde_json::to_string(&submission).expect("failed to serialize submission");
+    let request = Builder::new()
+        .header("Content-Type", "application/json")
+        .method(Method::POST)
+        .uri("/submit")
+        .body(Body::from(body))
+        .expect("failed to build request");
+    let expected_status = StatusCode::OK;
+    let expected_body = SubmissionResult::Failure(Box::new([TestCaseResult {
+        id: 0,
+        test_result: TestResult::Failure(TestCaseFailureReason::WrongAnswer {
+            input_parameters: Box::new([Parameter {
+                value_type: ParameterType::Float,
+                value: String::from("3.0"),
+            }]),
+            actual: String::from("3.000000000001e12"),
+            expected: String::from("3.0e12"),
+            actual_value: None,
+            expected_value: None,
+        }),
+        label: None,
+    }]));
+
+    let actual = mozart
+        .oneshot(request)
+        .await
+        .expect("failed to execute oneshot request");
+
+    let actual_status = actual.status();
+    let body_bytes = to_bytes(actual.into_body(), usize::MAX)
+        .await
+        .expect("failed to convert body to bytes");
+
+    let actual_body: SubmissionResult =
+        serde_json::from_slice(&body_bytes).expect("failed to deserialize response body");
+
+    assert_eq!(actual_status, expected_status);
+    assert_eq!(actual_body, expected_body);
+}
+
+#[tokio::test]
+async fn float_tolerance_multiple_output_parameters() {
+    let mozart = app(AppState::default());
+    let solution = [
+        "module Solution where",
+        "",
+        "solution :: Double -> (Double, Int)",
+        "solution x = (x * 1e12 + 1, 3)",
+    ]
+    .join("\n");
+    let test_cases = Box::new([TestCase {
+        id: 0,
+        input_parameters: Box::new([Parameter {
+            value_type: ParameterType::Float,
+            value: String::from("3.0"),
+        }]),
+        output_parameters: Box::new([
+            Parameter {
+                value_type: ParameterType::Float,
+                value: String::from("3000000000000.0"),
+            },
+            Parameter {
+                value_type: ParameterType::Int,
+                value: String::from("3"),
+            },
+        ]),
+        stdin: None,
+        expected_error: None,
+        label: None,
+        hidden: false,
+    }]);
+    let submission = Submission {
+        solution,
+        test_cases,
+        unordered_lists: false,
+        files: Box::new([]),
+        checker: None,
+        reference_solution: None,
+        only_ids: None,
+        compiler_flags: Box::new([]),
+        debug: false,
+        float_tolerance: Some(FloatTolerance {
+            absolute: 1e-9,
+            relative: 1e-9,
+            match_nan: false,
+        }),
+        toolchain_version: None,
+        metadata: None,
+    };
+    let body = serde_json::to_string(&submission).expect("failed to serialize submission");
+    let request = Builder::new()
+        .header("Content-Type", "application/json")
+        .method(Method::POST)
+        .uri("/submit")
+        .body(Body::from(body))
+        .expect("failed to build request");
+    let expected_status = StatusCode::OK;
+    let expected_body = SubmissionResult::Pass;
+
+    let actual = mozart
+        .oneshot(request)
+        .await
+        .expect("failed to execute oneshot request");
+
+    let actual_status = actual.status();
+    let body_bytes = to_bytes(actual.into_body(), usize::MAX)
+        .await
+        .expect("failed to convert body to bytes");
+
+    let actual_body: SubmissionResult =
+        serde_json::from_slice(&body_bytes).expect("failed to deserialize response body");
+
+    assert_eq!(actual_status, expected_status);
+    assert_eq!(actual_body, expected_body);
+}
+
+#[tokio::test]
+async fn unknown_toolchain_version() {
+    let mozart = app(AppState::new(Config {
+        toolchain_versions: Box::new([String::from("9.4.7")]),
+        ..Config::default()
+    }));
+    let solution = [
+        "module Solution where",
+        "",
+        "solution :: Int -> Int",
+        "solution x = x * 2",
+    ]
+    .join("\n");
+    let test_cases = Box::new([TestCase {
+        id: 0,
+        input_parameters: Box::new([Parameter {
+            value_type: ParameterType::Int,
+            value: String::from("2"),
+        }]),
+        output_parameters: Box::new([Parameter {
+            value_type: ParameterType::Int,
+            value: String::from("4"),
+        }]),
+        stdin: None,
+        expected_error: None,
+        label: None,
+        hidden: false,
+    }]);
+    let submission = Submission {
+        solution,
+        test_cases,
+        unordered_lists: false,
+        files: Box::new([]),
+        checker: None,
+        reference_solution: None,
+        only_ids: None,
+        compiler_flags: Box::new([]),
+        debug: false,
+        float_tolerance: None,
+        toolchain_version: Some(String::from("8.10.7")),
+        metadata: None,
+    };
+    let body = serde_json::to_string(&submission).expect("failed to serialize submission");
+    let request = Builder::new()
+        .header("Content-Type", "application/json")
+        .method(Method::POST)
+        .uri("/submit")
+        .body(Body::from(body))
+        .expect("failed to build request");
+    let expected_status = StatusCode::OK;
+
+    let actual = mozart
+        .oneshot(request)
+        .await
+        .expect("failed to execute oneshot request");
+
+    let actual_status = actual.status();
+    let body_bytes = to_bytes(actual.into_body(), usize::MAX)
+        .await
+        .expect("failed to convert body to bytes");
+
+    let actual_body: SubmissionResult =
+        serde_json::from_slice(&body_bytes).expect("failed to deserialize response body");
+
+    assert_eq!(actual_status, expected_status);
+
+    if let SubmissionResult::Error { code, message } = actual_body {
+        assert_eq!(code, ErrorCode::UnknownToolchainVersion);
+        assert!(message.starts_with("the toolchain version '8.10.7' is not available"));
+    } else {
+        panic!("response body was not of error variant");
+    }
+}
+
+#[tokio::test]
+async fn structured_wrong_answer_multiple_output_parameters() {
+    let mozart = app(AppState::default());
+    let solution = [
+        "module Solution where",
+        "",
+        "solution :: Int -> (Int, String)",
+        "solution x = (x + 1, show x)",
+    ]
+    .join("\n");
+    // the typed values of multiple output parameters are an array
+    let test_cases = Box::new([TestCase {
+        id: 0,
+        input_parameters: Box::new([Parameter {
+            value_type: ParameterType::Int,
+            value: String::from("2"),
+        }]),
+        output_parameters: Box::new([
+            Parameter {
+                value_type: ParameterType::Int,
+                value: String::from("2"),
+            },
+            Parameter {
+                value_type: ParameterType::String,
+                value: String::from("2"),
+            },
+        ]),
+        stdin: None,
+        expected_error: None,
+        label: None,
+        hidden: false,
+    }]);
+    let submission = Submission {
+        solution,
+        test_cases,
+        unordered_lists: false,
+        files: Box::new([]),
+        checker: None,
+        reference_solution: None,
+        only_ids: None,
+        compiler_flags: Box::new([]),
+        debug: false,
+        float_tolerance: None,
+        toolchain_version: None,
+        metadata: None,
+    };
+    let body = serde_json::to_string(&submission).expect("failed to serialize submission");
+    let request = Builder::new()
+        .header("Content-Type", "application/json")
+        .method(Method::POST)
+        .uri("/submit")
+        .body(Body::from(body))
+        .expect("failed to build request");
+    let expected_status = StatusCode::OK;
+    let expected_body = SubmissionResult::Failure(Box::new([TestCaseResult {
+        id: 0,
+        test_result: TestResult::Failure(TestCaseFailureReason::WrongAnswer {
+            input_parameters: Box::new([Parameter {
+                value_type: ParameterType::Int,
+                value: String::from("2"),
+            }]),
+            actual: String::from(r#"(3,"2")"#),
+            expected: String::from(r#"(2,"2")"#),
+            actual_value: Some(json!([3, "2"])),
+            expected_value: Some(json!([2, "2"])),
+        }),
+        label: None,
+    }]));
+
+    let actual = mozart
+        .oneshot(request)
+        .await
+        .expect("failed to execute oneshot request");
+
+    let actual_status = actual.status();
+    let body_bytes = to_bytes(actual.into_body(), usize::MAX)
+        .await
+        .expect("failed to convert body to bytes");
+
+    let actual_body: SubmissionResult =
+        serde_json::from_slice(&body_bytes).expect("failed to deserialize response body");
+
+    assert_eq!(actual_status, expected_status);
+    assert_eq!(actual_body, expected_body);
+}
+
+#[tokio::test]
+async fn structured_wrong_answer_map() {
+    let mozart = app(AppState::default());
+    let solution = [
+        "module Solution where",
+        "",
+        "import qualified Data.Map as Map",
+        "",
+        "solution :: Int -> Map.Map Int Bool",
+        "solution n = Map.fromList [(n, even n)]",
+    ]
+    .join("\n");
+    // the keys of the typed value of a map are the text of the keys
+    let test_cases = Box::new([TestCase {
+        id: 0,
+        input_parameters: Box::new([Parameter {
+            value_type: ParameterType::Int,
+            value: String::from("3"),
+        }]),
+        output_parameters: Box::new([Parameter {
+            value_type: ParameterType::Map {
+                key: Box::new(ParameterType::Int),
+                value: Box::new(ParameterType::Bool),
+            },
+            value: String::from(r#"{"3": "true"}"#),
+        }]),
+        stdin: None,
+        expected_error: None,
+        label: None,
+        hidden: false,
+    }]);
+    let submission = Submission {
+        solution,
+        test_cases,
+        unordered_lists: false,
+        files: Box::new([]),
+        checker: None,
+        reference_solution: None,
+        only_ids: None,
+        compiler_flags: Box::new([]),
+        debug: false,
+        float_tolerance: None,
+        toolchain_version: None,
+        metadata: None,
+    };
+    let body = serde_json::to_string(&submission).expect("failed to serialize submission");
+    let request = Builder::new()
+        .header("Content-Type", "application/json")
+        .method(Method::POST)
+        .uri("/submit")
+        .body(Body::from(body))
+        .expect("failed to build request");
+    let expected_status = StatusCode::OK;
+    let expected_body = SubmissionResult::Failure(Box::new([TestCaseResult {
+        id: 0,
+        test_result: TestResult::Failure(TestCaseFailureReason::WrongAnswer {
+            input_parameters: Box::new([Parameter {
+                value_type: ParameterType::Int,
+                value: String::from("3"),
+            }]),
+            actual: String::from("fromList [(3,False)]"),
+            expected: String::from("fromList [(3,True)]"),
+            actual_value: Some(json!({ "3": false })),
+            expected_value: Some(json!({ "3": true })),
+        }),
+        label: None,
+    }]));
+
+    let actual = mozart
+        .oneshot(request)
+        .await
+        .expect("failed to execute oneshot request");
+
+    let actual_status = actual.status();
+    let body_bytes = to_bytes(actual.into_body(), usize::MAX)
+        .await
+        .expect("failed to convert body to bytes");
+
+    let actual_body: SubmissionResult =
+        serde_json::from_slice(&body_bytes).expect("failed to deserialize response body");
+
+    assert_eq!(actual_status, expected_status);
+    assert_eq!(actual_body, expected_body);
+}
+
+#[tokio::test]
+async fn solution_too_long() {
+    let mozart = app(AppState::new(Config {
+        max_solution_length: 10,
+        ..Config::default()
+    }));
+    let solution = [
+        "module Solution where",
+        "",
+        "solution :: Int -> Int",
+        "solution x = x * 2",
+    ]
+    .join("\n");
+    let test_cases = Box::new([TestCase {
+        id: 0,
+        input_parameters: Box::new([Parameter {
+            value_type: ParameterType::Int,
+            value: String::from("2"),
+        }]),
+        output_parameters: Box::new([Parameter {
+            value_type: ParameterType::Int,
+            value: String::from("4"),
+        }]),
+        stdin: None,
+        expected_error: None,
+        label: None,
+        hidden: false,
+    }]);
+    let submission = Submission {
+        solution,
+        test_cases,
+        unordered_lists: false,
+        files: Box::new([]),
+        checker: None,
+        reference_solution: None,
+        only_ids: None,
+        compiler_flags: Box::new([]),
+        debug: false,
+        float_tolerance: None,
+        toolchain_version: None,
+        metadata: None,
+    };
+    let body = serde_json::to_string(&submission).expect("failed to serialize submission");
+    let request = Builder::new()
+        .header("Content-Type", "application/json")
+        .method(Method::POST)
+        .uri("/submit")
+        .body(Body::from(body))
+        .expect("failed to build request");
+    let expected_status = StatusCode::OK;
+    let expected_body = SubmissionResult::Error {
+        code: ErrorCode::SolutionTooLong,
+        message: String::from("the solution exceeded the limit of 10 bytes"),
+    };
+
+    let actual = mozart
+        .oneshot(request)
+        .await
+        .expect("failed to execute oneshot request");
+
+    let actual_status = actual.status();
+    let body_bytes = to_bytes(actual.into_body(), usize::MAX)
+        .await
+        .expect("failed to convert body to bytes");
+
+    let actual_body: SubmissionResult =
+        serde_json::from_slice(&body_bytes).expect("failed to deserialize response body");
+
+    assert_eq!(actual_status, expected_status);
+    assert_eq!(actual_body, expected_body);
+}
+
+#[tokio::test]
+async fn quoted_char_and_string() {
+    let mozart = app(AppState::new(Config {
+        quote_values: true,
+        ..Config::default()
+    }));
+    let solution = [
+        "module Solution where",
+        "",
+        "solution :: Char -> String -> String",
+        "solution c s = c : s",
+    ]
+    .join("\n");
+    // the input parameters are quoted like the actual and expected values
+    let test_cases = Box::new([TestCase {
+        id: 0,
+        input_parameters: Box::new([
+            Parameter {
+                value_type: ParameterType::Char,
+                value: String::from("b"),
+            },
+            Parameter {
+                value_type: ParameterType::String,
+                value: String::from("say hi"),
+            },
+        ]),
+        output_parameters: Box::new([Parameter {
+            value_type: ParameterType::String,
+            value: String::from("x"),
+        }]),
+        stdin: None,
+        expected_error: None,
+        label: None,
+        hidden: false,
+    }]);
+    let submission = Submission {
+        solution,
+        test_cases,
+        unordered_lists: false,
+        files: Box::new([]),
+        checker: None,
+        reference_solution: None,
+        only_ids: None,
+        compiler_flags: Box::new([]),
+        debug: false,
+        float_tolerance: None,
+        toolchain_version: None,
+        metadata: None,
+    };
+    let body = serde_json::to_string(&submission).expect("failed to serialize submission");
+    let request = Builder::new()
+        .header("Content-Type", "application/json")
+        .method(Method::POST)
+        .uri("/submit")
+        .body(Body::from(body))
+        .expect("failed to build request");
+    let expected_status = StatusCode::OK;
+    let expected_body = SubmissionResult::Failure(Box::new([TestCaseResult {
+        id: 0,
+        test_result: TestResult::Failure(TestCaseFailureReason::WrongAnswer {
+            input_parameters: Box::new([
+                Parameter {
+                    value_type: ParameterType::Char,
+                    value: String::from("'b'"),
+                },
+                Parameter {
+                    value_type: ParameterType::String,
+                    value: String::from(r#""say hi""#),
+                },
+            ]),
+            actual: String::from(r#""bsay hi""#),
+            expected: String::from(r#""x""#),
+            actual_value: None,
+            expected_value: None,
+        }),
+        label: None,
+    }]));
+
+    let actual = mozart
+        .oneshot(request)
+        .await
+        .expect("failed to execute oneshot request");
+
+    let actual_status = actual.status();
+    let body_bytes = to_bytes(actual.into_body(), usize::MAX)
+        .await
+        .expect("failed to convert body to bytes");
+
+    let actual_body: SubmissionResult =
+        serde_json::from_slice(&body_bytes).expect("failed to deserialize response body");
+
+    assert_eq!(actual_status, expected_status);
+    assert_eq!(actual_body, expected_body);
+}
+
+#[tokio::test]
+async fn char_non_ascii() {
+    let mozart = app(AppState::default());
+    let solution = [
+        "module Solution where",
+        "",
+        "solution :: Char -> Char",
+        "solution c = c",
+    ]
+    .join("\n");
+    // a multi-byte character is still a single Haskell Char
+    let test_cases = Box::new([
+        TestCase {
+            id: 0,
+            input_parameters: Box::new([Parameter {
+                value_type: ParameterType::Char,
+                value: String::from("é"),
+            }]),
+            output_parameters: Box::new([Parameter {
+                value_type: ParameterType::Char,
+                value: String::from("é"),
+            }]),
+            stdin: None,
+            expected_error: None,
+            label: None,
+            hidden: false,
+        },
+        TestCase {
+            id: 1,
+            input_parameters: Box::new([Parameter {
+                value_type: ParameterType::Char,
+                value: String::from("🚀"),
+            }]),
+            output_parameters: Box::new([Parameter {
+                value_type: ParameterType::Char,
+                value: String::from("🚀"),
+            }]),
+            stdin: None,
+            expected_error: None,
+            label: None,
+            hidden: false,
+        },
+    ]);
+    let submission = Submission {
+        solution,
+        test_cases,
+        unordered_lists: false,
+        files: Box::new([]),
+        checker: None,
+        reference_solution: None,
+        only_ids: None,
+        compiler_flags: Box::new([]),
+        debug: false,
+        float_tolerance: None,
+        toolchain_version: None,
+        metadata: None,
+    };
+    let body = serde_json::to_string(&submission).expect("failed to serialize submission");
+    let request = Builder::new()
+        .header("Content-Type", "application/json")
+        .method(Method::POST)
+        .uri("/submit")
+        .body(Body::from(body))
+        .expect("failed to build request");
+    let expected_status = StatusCode::OK;
+    let expected_body = SubmissionResult::Pass;
+
+    let actual = mozart
+        .oneshot(request)
+        .await
+        .expect("failed to execute oneshot request");
+
+    let actual_status = actual.status();
+    let body_bytes = to_bytes(actual.into_body(), usize::MAX)
+        .await
+        .expect("failed to convert body to bytes");
+
+    let actual_body: SubmissionResult =
+        serde_json::from_slice(&body_bytes).expect("failed to deserialize response body");
+
+    assert_eq!(actual_status, expected_status);
+    assert_eq!(actual_body, expected_body);
+}
+
+#[tokio::test]
+async fn chatty_solution() {
+    let mozart = app(AppState::default());
+    let solution = [
+        "module Solution where",
+        "",
+        "import Debug.Trace",
+        "",
+        "solution :: Int -> Int",
+        "solution x = trace (replicate 500000 'x') x",
+    ]
+    .join("\n");
+    // the solution writes far more than the buffer of a pipe, which must not block it until the timeout
+    let test_cases = Box::new([TestCase {
+        id: 0,
+        input_parameters: Box::new([Parameter {
+            value_type: ParameterType::Int,
+            value: String::from("2"),
+        }]),
+        output_parameters: Box::new([Parameter {
+            value_type: ParameterType::Int,
+            value: String::from("2"),
+        }]),
+        stdin: None,
+        expected_error: None,
+        label: None,
+        hidden: false,
+    }]);
+    let submission = Submission {
+        solution,
+        test_cases,
+        unordered_lists: false,
+        files: Box::new([]),
+        checker: None,
+        reference_solution: None,
+        only_ids: None,
+        compiler_flags: Box::new([]),
+        debug: false,
+        float_tolerance: None,
+        toolchain_version: None,
+        metadata: None,
+    };
+    let body = serde_json::to_string(&submission).expect("failed to serialize submission");
+    let request = Builder::new()
+        .header("Content-Type", "application/json")
+        .method(Method::POST)
+        .uri("/submit")
+        .body(Body::from(body))
+        .expect("failed to build request");
+    let expected_status = StatusCode::OK;
+    let expected_body = SubmissionResult::Pass;
+
+    let actual = mozart
+        .oneshot(request)
+        .await
+        .expect("failed to execute oneshot request");
+
+    let actual_status = actual.status();
+    let body_bytes = to_bytes(actual.into_body(), usize::MAX)
+        .await
+        .expect("failed to convert body to bytes");
+
+    let actual_body: SubmissionResult =
+        serde_json::from_slice(&body_bytes).expect("failed to deserialize response body");
+
+    assert_eq!(actual_status, expected_status);
+    assert_eq!(actual_body, expected_body);
+}
+
+#[tokio::test]
+async fn expected_error() {
+    let mozart = app(AppState::default());
+    let solution = [
+        "module Solution where",
+        "",
+        "solution :: Int -> Int",
+        "solution x",
+        "  | x < 0 = error \"negative input\"",
+        "  | otherwise = x + x",
+    ]
+    .join("\n");
+    // the solution should raise an error for a negative input
+    let test_cases = Box::new([
+        TestCase {
+            id: 0,
+            input_parameters: Box::new([Parameter {
+                value_type: ParameterType::Int,
+                value: String::from("2"),
+            }]),
+            output_parameters: Box::new([Parameter {
+                value_type: ParameterType::Int,
+                value: String::from("4"),
+            }]),
+            stdin: None,
+            expected_error: None,
+            label: None,
+            hidden: false,
+        },
+        TestCase {
+            id: 1,
+            input_parameters: Box::new([Parameter {
+                value_type: ParameterType::Int,
+                value: String::from("-1"),
+            }]),
+            output_parameters: Box::new([]),
+            stdin: None,
+            expected_error: Some(ExpectedError {
+                message: Some(String::from("negative input")),
+            }),
+            label: None,
+            hidden: false,
+        },
+        TestCase {
+            id: 2,
+            input_parameters: Box::new([Parameter {
+                value_type: ParameterType::Int,
+                value: String::from("-3"),
+            }]),
+            output_parameters: Box::new([]),
+            stdin: None,
+            expected_error: Some(ExpectedError { message: None }),
+            label: None,
+            hidden: false,
+        },
+    ]);
+    let submission = Submission {
+        solution,
+        test_cases,
+        unordered_lists: false,
+        files: Box::new([]),
+        checker: None,
+        reference_solution: None,
+        only_ids: None,
+        compiler_flags: Box::new([]),
+        debug: false,
+        float_tolerance: None,
+        toolchain_version: None,
+        metadata: None,
+    };
+    let body = serde_json::to_string(&submission).expect("failed to serialize submission");
+    let request = Builder::new()
+        .header("Content-Type", "application/json")
+        .method(Method::POST)
+        .uri("/submit")
+        .body(Body::from(body))
+        .expect("failed to build request");
+    let expected_status = StatusCode::OK;
+    let expected_body = SubmissionResult::Pass;
+
+    let actual = mozart
+        .oneshot(request)
+        .await
+        .expect("failed to execute oneshot request");
+
+    let actual_status = actual.status();
+    let body_bytes = to_bytes(actual.into_body(), usize::MAX)
+        .await
+        .expect("failed to convert body to bytes");
+
+    let actual_body: SubmissionResult =
+        serde_json::from_slice(&body_bytes).expect("failed to deserialize response body");
+
+    assert_eq!(actual_status, expected_status);
+    assert_eq!(actual_body, expected_body);
+}
+
+#[tokio::test]
+async fn missing_error() {
+    let mozart = app(AppState::default());
+    let solution = [
+        "module Solution where",
+        "",
+        "solution :: Int -> Int",
+        "solution x",
+        "  | x < 0 = error \"negative input\"",
+        "  | otherwise = x + x",
+    ]
+    .join("\n");
+    let test_cases = Box::new([TestCase {
+        id: 0,
+        input_parameters: Box::new([Parameter {
+            value_type: ParameterType::Int,
+            value: String::from("2"),
+        }]),
+        output_parameters: Box::new([]),
+        stdin: None,
+        expected_error: Some(ExpectedError { message: None }),
+        label: None,
+        hidden: false,
+    }]);
+    let submission = Submission {
+        solution,
+        test_cases,
+        unordered_lists: false,
+        files: Box::new([]),
+        checker: None,
+        reference_solution: None,
+        only_ids: None,
+        compiler_flags: Box::new([]),
+        debug: false,
+        float_tolerance: None,
+        toolchain_version: None,
+        metadata: None,
+    };
+    let body = serde_json::to_string(&submission).expect("failed to serialize submission");
+    let request = Builder::new()
+        .header("Content-Type", "application/json")
+        .method(Method::POST)
+        .uri("/submit")
+        .body(Body::from(body))
+        .expect("failed to build request");
+    let expected_status = StatusCode::OK;
+    let expected_body = SubmissionResult::Failure(Box::new([TestCaseResult {
+        id: 0,
+        test_result: TestResult::Failure(TestCaseFailureReason::MissingError {
+            input_parameters: Box::new([Parameter {
+                value_type: ParameterType::Int,
+                value: String::from("2"),
+            }]),
+            actual: String::from("4"),
+        }),
+        label: None,
+    }]));
+
+    let actual = mozart
+        .oneshot(request)
+        .await
+        .expect("failed to execute oneshot request");
+
+    let actual_status = actual.status();
+    let body_bytes = to_bytes(actual.into_body(), usize::MAX)
+        .await
+        .expect("failed to convert body to bytes");
+
+    let actual_body: SubmissionResult =
+        serde_json::from_slice(&body_bytes).expect("failed to deserialize response body");
+
+    assert_eq!(actual_status, expected_status);
+    assert_eq!(actual_body, expected_body);
+}
+
+#[tokio::test]
+async fn expected_nan() {
+    let mozart = app(AppState::default());
+    let solution = [
+        "module Solution where",
+        "",
+        "solution :: Double -> Double",
+        "solution x = x * 0",
+    ]
+    .join("\n");
+    // an infinity multiplied by zero is NaN
+    let test_cases = Box::new([TestCase {
+        id: 0,
+        input_parameters: Box::new([Parameter {
+            value_type: ParameterType::Float,
+            value: String::from("Infinity"),
+        }]),
+        output_parameters: Box::new([Parameter {
+            value_type: ParameterType::Float,
+            value: String::from("NaN"),
+        }]),
+        stdin: None,
+        expected_error: None,
+        label: None,
+        hidden: false,
+    }]);
+    let submission = Submission {
+        solution,
+        test_cases,
+        unordered_lists: false,
+        files: Box::new([]),
+        checker: None,
+        reference_solution: None,
+        only_ids: None,
+        compiler_flags: Box::new([]),
+        debug: false,
+        float_tolerance: Some(FloatTolerance {
+            absolute: 0.0,
+            relative: 0.0,
+            match_nan: true,
+        }),
+        toolchain_version: None,
+        metadata: None,
+    };
+    let body = serde_json::to_string(&submission).expect("failed to serialize submission");
+    let request = Builder::new()
+        .header("Content-Type", "application/json")
+        .method(Method::POST)
+        .uri("/submit")
+        .body(Body::from(body))
+        .expect("failed to build request");
+    let expected_status = StatusCode::OK;
+    let expected_body = SubmissionResult::Pass;
+
+    let actual = mozart
+        .oneshot(request)
+        .await
+        .expect("failed to execute oneshot request");
+
+    let actual_status = actual.status();
+    let body_bytes = to_bytes(actual.into_body(), usize::MAX)
+        .await
+        .expect("failed to convert body to bytes");
+
+    let actual_body: SubmissionResult =
+        serde_json::from_slice(&body_bytes).expect("failed to deserialize response body");
+
+    assert_eq!(actual_status, expected_status);
+    assert_eq!(actual_body, expected_body);
+}
+
+#[tokio::test]
+async fn expected_infinity() {
+    let mozart = app(AppState::default());
+    let solution = [
+        "module Solution where",
+        "",
+        "solution :: Double -> Double",
+        "solution x = x * 2",
+    ]
+    .join("\n");
+    let test_cases = Box::new([
+        TestCase {
+            id: 0,
+            input_parameters: Box::new([Parameter {
+                value_type: ParameterType::Float,
+                value: String::from("Infinity"),
+            }]),
+            output_parameters: Box::new([Parameter {
+                value_type: ParameterType::Float,
+                value: String::from("Infinity"),
+            }]),
+            stdin: None,
+            expected_error: None,
+            label: None,
+            hidden: false,
+        },
+        TestCase {
+            id: 1,
+            input_parameters: Box::new([Parameter {
+                value_type: ParameterType::Float,
+                value: String::from("-Infinity"),
+            }]),
+            output_parameters: Box::new([Parameter {
+                value_type: ParameterType::Float,
+                value: String::from("-Infinity"),
+            }]),
+            stdin: None,
+            expected_error: None,
+            label: None,
+            hidden: false,
+        },
+        TestCase {
+            id: 2,
+            input_parameters: Box::new([Parameter {
+                value_type: ParameterType::Float,
+                value: String::from("1.5"),
+            }]),
+            output_parameters: Box::new([Parameter {
+                value_type: ParameterType::Float,
+                value: String::from("3.0"),
+            }]),
+            stdin: None,
+            expected_error: None,
+            label: None,
+            hidden: false,
+        },
+    ]);
+    let submission = Submission {
+        solution,
+        test_cases,
+        unordered_lists: false,
+        files: Box::new([]),
+        checker: None,
+        reference_solution: None,
+        only_ids: None,
+        compiler_flags: Box::new([]),
+        debug: false,
+        float_tolerance: Some(FloatTolerance {
+            absolute: 0.0,
+            relative: 1e-9,
+            match_nan: false,
+        }),
+        toolchain_version: None,
+        metadata: None,
+    };
+    let body = serde_json::to_string(&submission).expect("failed to serialize submission");
+    let request = Builder::new()
+        .header("Content-Type", "application/json")
+        .method(Method::POST)
+        .uri("/submit")
+        .body(Body::from(body))
+        .expect("failed to build request");
+    let expected_status = StatusCode::OK;
+    let expected_body = SubmissionResult::Pass;
+
+    let actual = mozart
+        .oneshot(request)
+        .await
+        .expect("failed to execute oneshot request");
+
+    let actual_status = actual.status();
+    let body_bytes = to_bytes(actual.into_body(), usize::MAX)
+        .await
+        .expect("failed to convert body to bytes");
+
+    let actual_body: SubmissionResult =
+        serde_json::from_slice(&body_bytes).expect("failed to deserialize response body");
+
+    assert_eq!(actual_status, expected_status);
+    assert_eq!(actual_body, expected_body);
+}
+
+#[tokio::test]
+async fn decimal_exact_arithmetic() {
+    let mozart = app(AppState::default());
+    let solution = [
+        "module Solution where",
+        "",
+        "solution :: Rational -> Rational -> Rational",
+        "solution a b = a + b",
+    ]
+    .join("\n");
+    // unlike floats, the sum of the decimals is exactly the expected decimal
+    let test_cases = Box::new([
+        TestCase {
+            id: 0,
+            input_parameters: Box::new([
+                Parameter {
+                    value_type: ParameterType::Decimal,
+                    value: String::from("0.1"),
+                },
+                Parameter {
+                    value_type: ParameterType::Decimal,
+                    value: String::from("0.2"),
+                },
+            ]),
+            output_parameters: Box::new([Parameter {
+                value_type: ParameterType::Decimal,
+                value: String::from("0.3"),
+            }]),
+            stdin: None,
+            expected_error: None,
+            label: None,
+            hidden: false,
+        },
+        TestCase {
+            id: 1,
+            input_parameters: Box::new([
+                Parameter {
+                    value_type: ParameterType::Decimal,
+                    value: String::from("10.25"),
+                },
+                Parameter {
+                    value_type: ParameterType::Decimal,
+                    value: String::from("-0.05"),
+                },
+            ]),
+            output_parameters: Box::new([Parameter {
+                value_type: ParameterType::Decimal,
+                value: String::from("10.20"),
+            }]),
+            stdin: None,
+            expected_error: None,
+            label: None,
+            hidden: false,
+        },
+    ]);
+    let submission = Submission {
+        solution,
+        test_cases,
+        unordered_lists: false,
+        files: Box::new([]),
+        checker: None,
+        reference_solution: None,
+        only_ids: None,
+        compiler_flags: Box::new([]),
+        debug: false,
+        float_tolerance: None,
+        toolchain_version: None,
+        metadata: None,
+    };
+    let body = serde_json::to_string(&submission).expect("failed to serialize submission");
+    let request = Builder::new()
+        .header("Content-Type", "application/json")
+        .method(Method::POST)
+        .uri("/submit")
+        .body(Body::from(body))
+        .expect("failed to build request");
+    let expected_status = StatusCode::OK;
+    let expected_body = SubmissionResult::Pass;
+
+    let actual = mozart
+        .oneshot(request)
+        .await
+        .expect("failed to execute oneshot request");
+
+    let actual_status = actual.status();
+    let body_bytes = to_bytes(actual.into_body(), usize::MAX)
+        .await
+        .expect("failed to convert body to bytes");
+
+    let actual_body: SubmissionResult =
+        serde_json::from_slice(&body_bytes).expect("failed to deserialize response body");
+
+    assert_eq!(actual_status, expected_status);
+    assert_eq!(actual_body, expected_body);
+}
+
+#[tokio::test]
+async fn decimal_float_arithmetic_fails() {
+    let mozart = app(AppState::default());
+    let solution = [
+        "module Solution where",
+        "",
+        "solution :: Rational -> Rational -> Rational",
+        "solution a b = toRational (fromRational a + fromRational b :: Double)",
+    ]
+    .join("\n");
+    // the sum of the decimals as floats is not exactly the expected decimal
+    let test_cases = Box::new([TestCase {
+        id: 0,
+        input_parameters: Box::new([
+            Parameter {
+                value_type: ParameterType::Decimal,
+                value: String::from("0.1"),
+            },
+            Parameter {
+                value_type: ParameterType::Decimal,
+                value: String::from("0.2"),
+            },
+        ]),
+        output_parameters: Box::new([Parameter {
+            value_type: ParameterType::Decimal,
+            value: String::from("0.3"),
+        }]),
+        stdin: None,
+        expected_error: None,
+        label: None,
+        hidden: false,
+    }]);
+    let submission = Submission {
+        solution,
+        test_cases,
+        unordered_lists: false,
+        files: Box::new([]),
+        checker: None,
+        reference_solution: None,
+        only_ids: None,
+        compiler_flags: Box::new([]),
+        debug: false,
+        float_tolerance: None,
+        toolchain_version: None,
+        metadata: None,
+    };
+    let body = serde_json::to_string(&submission).expect("failed to serialize submission");
+    let request = Builder::new()
+        .header("Content-Type", "application/json")
+        .method(Method::POST)
+        .uri("/submit")
+        .body(Body::from(body))
+        .expect("failed to build request");
+    let expected_status = StatusCode::OK;
+    let expected_body = SubmissionResult::Failure(Box::new([TestCaseResult {
+        id: 0,
+        test_result: TestResult::Failure(TestCaseFailureReason::WrongAnswer {
+            input_parameters: Box::new([
+                Parameter {
+                    value_type: ParameterType::Decimal,
+                    value: String::from("0.1"),
+                },
+                Parameter {
+                    value_type: ParameterType::Decimal,
+                    value: String::from("0.2"),
+                },
+            ]),
+            actual: String::from("0.3000000000000000444089209850062616169452667236328125"),
+            expected: String::from("0.3"),
+            actual_value: None,
+            expected_value: None,
+        }),
+        label: None,
+    }]));
+
+    let actual = mozart
+        .oneshot(request)
+        .await
+        .expect("failed to execute oneshot request");
+
+    let actual_status = actual.status();
+    let body_bytes = to_bytes(actual.into_body(), usize::MAX)
+        .await
+        .expect("failed to convert body to bytes");
+
+    let actual_body: SubmissionResult =
+        serde_json::from_slice(&body_bytes).expect("failed to deserialize response body");
+
+    assert_eq!(actual_status, expected_status);
+    assert_eq!(actual_body, expected_body);
+}
+
+#[tokio::test]
+async fn labels_are_echoed() {
+    let mozart = app(AppState::default());
+    let solution = [
+        "module Solution where",
+        "",
+        "solution :: Int -> Int",
+        "solution x = x + x",
+    ]
+    .join("\n");
+    let test_cases = Box::new([
+        TestCase {
+            id: 0,
+            input_parameters: Box::new([Parameter {
+                value_type: ParameterType::Int,
+                value: String::from("0"),
+            }]),
+            output_parameters: Box::new([Parameter {
+                value_type: ParameterType::Int,
+                value: String::from("0"),
+            }]),
+            stdin: None,
+            expected_error: None,
+            label: Some(String::from("edge case")),
+            hidden: false,
+        },
+        TestCase {
+            id: 1,
+            input_parameters: Box::new([Parameter {
+                value_type: ParameterType::Int,
+                value: String::from("5"),
+            }]),
+            output_parameters: Box::new([Parameter {
+                value_type: ParameterType::Int,
+                value: String::from("11"),
+            }]),
+            stdin: None,
+            expected_error: None,
+            label: Some(String::from("hidden")),
+            hidden: false,
+        },
+        TestCase {
+            id: 2,
+            input_parameters: Box::new([Parameter {
+                value_type: ParameterType::Int,
+                value: String::from("2"),
+            }]),
+            output_parameters: Box::new([Parameter {
+                value_type: ParameterType::Int,
+                value: String::from("4"),
+            }]),
+            stdin: None,
+            expected_error: None,
+            label: None,
+            hidden: false,
+        },
+    ]);
+    let submission = Submission {
+        solution,
+        test_cases,
+        unordered_lists: false,
+        files: Box::new([]),
+        checker: None,
+        reference_solution: None,
+        only_ids: None,
+        compiler_flags: Box::new([]),
+        debug: false,
+        float_tolerance: None,
+        toolchain_version: None,
+        metadata: None,
+    };
+    let body = serde_json::to_string(&submission).expect("failed to serialize submission");
+    let request = Builder::new()
+        .header("Content-Type", "application/json")
+        .method(Method::POST)
+        .uri("/submit")
+        .body(Body::from(body))
+        .expect("failed to build request");
+    let expected_status = StatusCode::OK;
+    let expected_body = SubmissionResult::Failure(Box::new([
+        TestCaseResult {
+            id: 0,
+            test_result: TestResult::Pass,
+            label: Some(String::from("edge case")),
+        },
+        TestCaseResult {
+            id: 1,
+            test_result: TestResult::Failure(TestCaseFailureReason::WrongAnswer {
+                input_parameters: Box::new([Parameter {
+                    value_type: ParameterType::Int,
+                    value: String::from("5"),
+                }]),
+                actual: String::from("10"),
+                expected: String::from("11"),
+                actual_value: None,
+                expected_value: None,
+            }),
+            label: Some(String::from("hidden")),
+        },
+        TestCaseResult {
+            id: 2,
+            test_result: TestResult::Pass,
+            label: None,
+        },
+    ]));
+
+    let actual = mozart
+        .oneshot(request)
+        .await
+        .expect("failed to execute oneshot request");
+
+    let actual_status = actual.status();
+    let body_bytes = to_bytes(actual.into_body(), usize::MAX)
+        .await
+        .expect("failed to convert body to bytes");
+
+    let actual_body: SubmissionResult =
+        serde_json::from_slice(&body_bytes).expect("failed to deserialize response body");
+
+    assert_eq!(actual_status, expected_status);
+    assert_eq!(actual_body, expected_body);
+}
+
+#[tokio::test]
+async fn stdout_is_returned() {
+    let mozart = app(AppState::default());
+    let solution = [
+        "module Solution where",
+        "",
+        "import System.IO.Unsafe",
+        "",
+        "solution :: Int -> Int",
+        "solution x = unsafePerformIO (putStrLn (\"debug \" ++ show x) >> return (x + x))",
+    ]
+    .join("\n");
+    let test_cases = Box::new([
+        TestCase {
+            id: 0,
+            input_parameters: Box::new([Parameter {
+                value_type: ParameterType::Int,
+                value: String::from("2"),
+            }]),
+            output_parameters: Box::new([Parameter {
+                value_type: ParameterType::Int,
+                value: String::from("4"),
+            }]),
+            stdin: None,
+            expected_error: None,
+            label: None,
+            hidden: false,
+        },
+        TestCase {
+            id: 1,
+            input_parameters: Box::new([Parameter {
+                value_type: ParameterType::Int,
+                value: String::from("5"),
+            }]),
+            output_parameters: Box::new([Parameter {
+                value_type: ParameterType::Int,
+                value: String::from("10"),
+            }]),
+            stdin: None,
+            expected_error: None,
+            label: None,
+            hidden: false,
+        },
+    ]);
+    let submission = Submission {
+        solution,
+        test_cases,
+        unordered_lists: false,
+        files: Box::new([]),
+        checker: None,
+        reference_solution: None,
+        only_ids: None,
+        compiler_flags: Box::new([]),
+        debug: false,
+        float_tolerance: None,
+        toolchain_version: None,
+        metadata: None,
+    };
+    let body = serde_json::to_string(&submission).expect("failed to serialize submission");
+    let request = Builder::new()
+        .header("Content-Type", "application/json")
+        .method(Method::POST)
+        .uri("/submit")
+        .body(Body::from(body))
+        .expect("failed to build request");
+    let expected_status = StatusCode::OK;
+    let expected_result = SubmissionResult::Pass;
+    let expected_stdout = "debug 2\ndebug 5\n";
+
+    let actual = mozart
+        .oneshot(request)
+        .await
+        .expect("failed to execute oneshot request");
+
+    let actual_status = actual.status();
+    let body_bytes = to_bytes(actual.into_body(), usize::MAX)
+        .await
+        .expect("failed to convert body to bytes");
+
+    let actual_body: Value =
+        serde_json::from_slice(&body_bytes).expect("failed to deserialize response body");
+    let actual_result: SubmissionResult =
+        serde_json::from_slice(&body_bytes).expect("failed to deserialize result");
+
+    assert_eq!(actual_status, expected_status);
+    assert_eq!(actual_result, expected_result);
+    assert_eq!(actual_body["stdout"], expected_stdout);
+}
+
+#[tokio::test]
+async fn warning_passes_by_default() {
+    let mozart = app(AppState::default());
+    let solution = [
+        "module Solution where",
+        "",
+        "solution :: Int -> Int",
+        "solution x = x + x",
+        "solution _ = 0",
+    ]
+    .join("\n");
+    // the redundant pattern is only a warning
+    let test_cases = Box::new([TestCase {
+        id: 0,
+        input_parameters: Box::new([Parameter {
+            value_type: ParameterType::Int,
+            value: String::from("2"),
+        }]),
+        output_parameters: Box::new([Parameter {
+            value_type: ParameterType::Int,
+            value: String::from("4"),
+        }]),
+        stdin: None,
+        expected_error: None,
+        label: None,
+        hidden: false,
+    }]);
+    let submission = Submission {
+        solution,
+        test_cases,
+        unordered_lists: false,
+        files: Box::new([]),
+        checker: None,
+        reference_solution: None,
+        only_ids: None,
+        compiler_flags: Box::new([]),
+        debug: false,
+        float_tolerance: None,
+        toolchain_version: None,
+        metadata: None,
+    };
+    let body = serde_json::to_string(&submission).expect("failed to serialize submission");
+    let request = Builder::new()
+        .header("Content-Type", "application/json")
+        .method(Method::POST)
+        .uri("/submit")
+        .body(Body::from(body))
+        .expect("failed to build request");
+    let expected_status = StatusCode::OK;
+    let expected_body = SubmissionResult::Pass;
+
+    let actual = mozart
+        .oneshot(request)
+        .await
+        .expect("failed to execute oneshot request");
+
+    let actual_status = actual.status();
+    let body_bytes = to_bytes(actual.into_body(), usize::MAX)
+        .await
+        .expect("failed to convert body to bytes");
+
+    let actual_body: SubmissionResult =
+        serde_json::from_slice(&body_bytes).expect("failed to deserialize response body");
+
+    assert_eq!(actual_status, expected_status);
+    assert_eq!(actual_body, expected_body);
+}
+
+#[tokio::test]
+async fn warning_fails_with_warnings_as_errors() {
+    let mozart = app(AppState::new(Config {
+        warnings_as_errors: true,
+        ..Config::default()
+    }));
+    let solution = [
+        "module Solution where",
+        "",
+        "solution :: Int -> Int",
+        "solution x = x + x",
+        "solution _ = 0",
+    ]
+    .join("\n");
+    let test_cases = Box::new([TestCase {
+        id: 0,
+        input_parameters: Box::new([Parameter {
+            value_type: ParameterType::Int,
+            value: String::from("2"),
+        }]),
+        output_parameters: Box::new([Parameter {
+            value_type: ParameterType::Int,
+            value: String::from("4"),
+        }]),
+        stdin: None,
+        expected_error: None,
+        label: None,
+        hidden: false,
+    }]);
+    let submission = Submission {
+        solution,
+        test_cases,
+        unordered_lists: false,
+        files: Box::new([]),
+        checker: None,
+        reference_solution: None,
+        only_ids: None,
+        compiler_flags: Box::new([]),
+        debug: false,
+        float_tolerance: None,
+        toolchain_version: None,
+        metadata: None,
+    };
+    let body = serde_json::to_string(&submission).expect("failed to serialize submission");
+    let request = Builder::new()
+        .header("Content-Type", "application/json")
+        .method(Method::POST)
+        .uri("/submit")
+        .body(Body::from(body))
+        .expect("failed to build request");
+    let expected_status = StatusCode::OK;
+
+    let actual = mozart
+        .oneshot(request)
+        .await
+        .expect("failed to execute oneshot request");
+
+    let actual_status = actual.status();
+    let body_bytes = to_bytes(actual.into_body(), usize::MAX)
+        .await
+        .expect("failed to convert body to bytes");
+
+    let actual_body: SubmissionResult =
+        serde_json::from_slice(&body_bytes).expect("failed to deserialize response body");
+
+    assert_eq!(actual_status, expected_status);
+
+    if let SubmissionResult::CompilationError {
+        message,
+        diagnostics,
+    } = actual_body
+    {
+        assert!(message.starts_with("an error occurred during compilation:"));
+        assert_eq!(diagnostics[0].line, 5);
+    } else {
+        panic!("response body was not of compilation error variant");
+    }
+}
+
+#[tokio::test]
+async fn solution_with_wrong_arity() {
+    let solution = [
+        "module Solution where",
+        "",
+        "solution :: Int -> Int -> Int",
+        "solution x y = x + y",
+    ]
+    .join("\n");
+    let test_cases = Box::new([TestCase {
+        id: 0,
+        input_parameters: Box::new([Parameter {
+            value_type: ParameterType::Int,
+            value: String::from("2"),
+        }]),
+        output_parameters: Box::new([Parameter {
+            value_type: ParameterType::Int,
+            value: String::from("4"),
+        }]),
+        stdin: None,
+        expected_error: None,
+        label: None,
+        hidden: false,
+    }]);
+    let submission = Submission {
+        solution,
+        test_cases,
+        unordered_lists: false,
+        files: Box::new([]),
+        checker: None,
+        reference_solution: None,
+        only_ids: None,
+        compiler_flags: Box::new([]),
+        debug: false,
+        float_tolerance: None,
+        toolchain_version: None,
+        metadata: None,
+    };
+    let expected = SubmissionResult::Error {
+        code: ErrorCode::Compilation,
+        message: String::from(
+            "an error occurred during compilation: solution expects 2 arguments but test case provides 1",
+        ),
+    };
+
+    let actual = check_submission(submission, AppState::default()).await;
+
+    assert_eq!(actual, expected);
+}
+
+#[tokio::test]
+async fn optional_int() {
+    let solution = [
+        "module Solution where",
+        "",
+        "solution :: Maybe Int -> Maybe Int",
+        "solution = fmap (* 2)",
+    ]
+    .join("\n");
+    let test_cases = Box::new([
+        TestCase {
+            id: 0,
+            input_parameters: Box::new([Parameter {
+                value_type: ParameterType::Optional(Box::new(ParameterType::Int)),
+                value: String::from(r#""5""#),
+            }]),
+            output_parameters: Box::new([Parameter {
+                value_type: ParameterType::Optional(Box::new(ParameterType::Int)),
+                value: String::from(r#""10""#),
+            }]),
+            stdin: None,
+            expected_error: None,
+            label: None,
+            hidden: false,
+        },
+        TestCase {
+            id: 1,
+            input_parameters: Box::new([Parameter {
+                value_type: ParameterType::Optional(Box::new(ParameterType::Int)),
+                value: String::from("null"),
+            }]),
+            output_parameters: Box::new([Parameter {
+                value_type: ParameterType::Optional(Box::new(ParameterType::Int)),
+                value: String::from("null"),
+            }]),
+            stdin: None,
+            expected_error: None,
+            label: None,
+            hidden: false,
+        },
+    ]);
+    let submission = Submission {
+        solution,
+        test_cases,
+        unordered_lists: false,
+        files: Box::new([]),
+        checker: None,
+        reference_solution: None,
+        only_ids: None,
+        compiler_flags: Box::new([]),
+        debug: false,
+        float_tolerance: None,
+        toolchain_version: None,
+        metadata: None,
+    };
+
+    let actual = check_submission(submission, AppState::default()).await;
+
+    assert_eq!(actual, SubmissionResult::Pass);
+}
+
+#[tokio::test]
+async fn optional_int_wrong_answer() {
+    let solution = [
+        "module Solution where",
+        "",
+        "solution :: Maybe Int -> Maybe Int",
+        "solution = fmap (* 2)",
+    ]
+    .join("\n");
+    let test_cases = Box::new([TestCase {
+        id: 0,
+        input_parameters: Box::new([Parameter {
+            value_type: ParameterType::Optional(Box::new(ParameterType::Int)),
+            value: String::from("null"),
+        }]),
+        output_parameters: Box::new([Parameter {
+            value_type: ParameterType::Optional(Box::new(ParameterType::Int)),
+            value: String::from(r#""0""#),
+        }]),
+        stdin: None,
+        expected_error: None,
+        label: None,
+        hidden: false,
+    }]);
+    let submission = Submission {
+        solution,
+        test_cases,
+        unordered_lists: false,
+        files: Box::new([]),
+        checker: None,
+        reference_solution: None,
+        only_ids: None,
+        compiler_flags: Box::new([]),
+        debug: false,
+        float_tolerance: None,
+        toolchain_version: None,
+        metadata: None,
+    };
+    let expected = SubmissionResult::Failure(Box::new([TestCaseResult {
+        id: 0,
+        test_result: TestResult::Failure(TestCaseFailureReason::WrongAnswer {
+            input_parameters: Box::new([Parameter {
+                value_type: ParameterType::Optional(Box::new(ParameterType::Int)),
+                value: String::from("null"),
+            }]),
+            actual: String::from("Nothing"),
+            expected: String::from("Just 0"),
+            actual_value: None,
+            expected_value: None,
+        }),
+        label: None,
+    }]));
+
+    let actual = check_submission(submission, AppState::default()).await;
+
+    assert_eq!(actual, expected);
+}
@@ -0,0 +1,4201 @@
+use axum::{
+    body::{to_bytes, Body},
+    http::{request::Builder, Method, StatusCode},
+};
+use mozart::{
+    app,
+    config::Config,
+    model::{
+        ExpectedError, FloatTolerance, Parameter, ParameterType, Submission, TestCase,
+        TestCaseFailureReason, TestCaseResult, TestResult,
+    },
+    response::{ErrorCode, SubmissionResult},
+    state::AppState,
+};
+use serde_json::{json, Value};
+use tower::ServiceExt;
+
+#[tokio::test]
+async fn invalid_http_method() {
+    let mozart = app(AppState::default());
+    let expected_status_code = StatusCode::METHOD_NOT_ALLOWED;
+    let request = Builder::new()
+        .method(Method::GET)
+        .uri("/submit")
+        .body(Body::empty())
+        .expect("failed to build request");
+
+    let actual = mozart
+        .oneshot(request)
+        .await
+        .expect("failed to await oneshot");
+
+    assert_eq!(actual.status(), expected_status_code);
+}
+
+#[tokio::test]
+async fn no_json_header() {
+    let mozart = app(AppState::default());
+    let expected_status_code = StatusCode::UNSUPPORTED_MEDIA_TYPE;
+    let request = Builder::new()
+        .method(Method::POST)
+        .uri("/submit")
+        .body(Body::empty())
+        .expect("failed to build request");
+
+    let actual = mozart
+        .oneshot(request)
+        .await
+        .expect("failed to await oneshot");
+
+    assert_eq!(actual.status(), expected_status_code);
+}
+
+#[tokio::test]
+async fn empty_request_body() {
+    let mozart = app(AppState::default());
+    let expected_status_code = StatusCode::BAD_REQUEST;
+    let request = Builder::new()
+        .method(Method::POST)
+        .header("Content-Type", "application/json")
+        .uri("/submit")
+        .body(Body::empty())
+        .expect("failed to build request");
+
+    let actual = mozart
+        .oneshot(request)
+        .await
+        .expect("failed to await oneshot");
+
+    let actual_status = actual.status();
+    let body_bytes = to_bytes(actual.into_body(), usize::MAX)
+        .await
+        .expect("failed to convert body to bytes");
+
+    let actual_body: SubmissionResult =
+        serde_json::from_slice(&body_bytes).expect("failed to deserialize response body");
+
+    assert_eq!(actual_status, expected_status_code);
+
+    if let SubmissionResult::Error { code, message } = actual_body {
+        assert_eq!(code, ErrorCode::InvalidRequest);
+        assert!(message.starts_with("Failed to parse the request body as JSON"));
+    } else {
+        panic!("response body was not of error variant");
+    }
+}
+
+#[tokio::test]
+async fn invalid_json() {
+    let mozart = app(AppState::default());
+    let expected_status_code = StatusCode::UNPROCESSABLE_ENTITY;
+    let body = serde_json::to_string(&ParameterType::Int).expect("failed to serialize body");
+    let request = Builder::new()
+        .method(Method::POST)
+        .header("Content-Type", "application/json")
+        .uri("/submit")
+        .body(body)
+        .expect("failed to build request");
+
+    let actual = mozart
+        .oneshot(request)
+        .await
+        .expect("failed to await oneshot");
+
+    let actual_status = actual.status();
+    let body_bytes = to_bytes(actual.into_body(), usize::MAX)
+        .await
+        .expect("failed to convert body to bytes");
+
+    let actual_body: SubmissionResult =
+        serde_json::from_slice(&body_bytes).expect("failed to deserialize response body");
+
+    assert_eq!(actual_status, expected_status_code);
+
+    if let SubmissionResult::Error { code, message } = actual_body {
+        assert_eq!(code, ErrorCode::InvalidRequest);
+        assert!(message.starts_with("Failed to deserialize the JSON body into the target type"));
+    } else {
+        panic!("response body was not of error variant");
+    }
+}
+
+#[tokio::test]
+async fn compilation_error() {
+    let mozart = app(AppState::default());
+    let solution = [
+        "fun solution(x: Long): Long {",
+        "    if (x < 0) {",
+        "        return x * -1",
+        "    }",
+        // "    return x",
+        "}",
+    ]
+    .join("\n");
+    let test_cases = Box::new([
+        TestCase {
+            id: 0,
+            input_parameters: Box::new([Parameter {
+                value_type: ParameterType::Int,
+                value: String::from("10"),
+            }]),
+            output_parameters: Box::new([Parameter {
+                value_type: ParameterType::Int,
+                value: String::from("10"),
+            }]),
+            stdin: None,
+            expected_error: None,
+            label: None,
+            hidden: false,
+        },
+        TestCase {
+            id: 1,
+            input_parameters: Box::new([Parameter {
+                value_type: ParameterType::Int,
+                value: String::from("-10"),
+            }]),
+            output_parameters: Box::new([Parameter {
+                value_type: ParameterType::Int,
+                value: String::from("10"),
+            }]),
+            stdin: None,
+            expected_error: None,
+            label: None,
+            hidden: false,
+        },
+    ]);
+    let submission = Submission {
+        solution,
+        test_cases,
+        unordered_lists: false,
+        files: Box::new([]),
+        checker: None,
+        reference_solution: None,
+        only_ids: None,
+        compiler_flags: Box::new([]),
+        debug: false,
+        float_tolerance: None,
+        toolchain_version: None,
+        metadata: None,
+    };
+    let body = serde_json::to_string(&submission).expect("failed to serialize submission");
+    let request = Builder::new()
+        .header("Content-Type", "application/json")
+        .method(Method::POST)
+        .uri("/submit")
+        .body(Body::from(body))
+        .expect("failed to build request");
+    let expected_status = StatusCode::OK;
+
+    let actual = mozart
+        .oneshot(request)
+        .await
+        .expect("failed to execute oneshot request");
+
+    let actual_status = actual.status();
+    let body_bytes = to_bytes(actual.into_body(), usize::MAX)
+        .await
+        .expect("failed to convert body to bytes");
+
+    let actual_body: SubmissionResult =
+        serde_json::from_slice(&body_bytes).expect("failed to deserialize response body");
+
+    assert_eq!(actual_status, expected_status);
+
+    if let SubmissionResult::CompilationError {
+        message,
+        diagnostics,
+    } = actual_body
+    {
+        assert!(message.starts_with("an error occurred during compilation:"));
+        assert!(!diagnostics.is_empty());
+    } else {
+        panic!("response body was not of compilation error variant");
+    }
+}
+
+#[tokio::test]
+async fn execution_timeout() {
+    let mozart = app(AppState::default());
+    let solution = [
+        "fun solution(x: Long): Long {",
+        "    while (x == x) {}",
+        "    return x",
+        "}",
+    ]
+    .join("\n");
+    // the contents of the test cases are entirely irrelevant
+    let test_cases = Box::new([
+        TestCase {
+            id: 0,
+            input_parameters: Box::new([Parameter {
+                value_type: ParameterType::Int,
+                value: String::from("10"),
+            }]),
+            output_parameters: Box::new([Parameter {
+                value_type: ParameterType::Int,
+                value: String::from("10"),
+            }]),
+            stdin: None,
+            expected_error: None,
+            label: None,
+            hidden: false,
+        },
+        TestCase {
+            id: 1,
+            input_parameters: Box::new([Parameter {
+                value_type: ParameterType::Int,
+                value: String::from("-10"),
+            }]),
+            output_parameters: Box::new([Parameter {
+                value_type: ParameterType::Int,
+                value: String::from("10"),
+            }]),
+            stdin: None,
+            expected_error: None,
+            label: None,
+            hidden: false,
+        },
+    ]);
+    let submission = Submission {
+        solution,
+        test_cases,
+        unordered_lists: false,
+        files: Box::new([]),
+        checker: None,
+        reference_solution: None,
+        only_ids: None,
+        compiler_flags: Box::new([]),
+        debug: false,
+        float_tolerance: None,
+        toolchain_version: None,
+        metadata: None,
+    };
+    let body = serde_json::to_string(&submission).expect("failed to serialize submission");
+    let request = Builder::new()
+        .header("Content-Type", "application/json")
+        .method(Method::POST)
+        .uri("/submit")
+        .body(Body::from(body))
+        .expect("failed to build request");
+    let expected_status = StatusCode::OK;
+    let expected_body = SubmissionResult::Failure(Box::new([
+        TestCaseResult {
+            id: 0,
+            test_result: TestResult::Failure(TestCaseFailureReason::Timeout),
+            label: None,
+        },
+        TestCaseResult {
+            id: 1,
+            test_result: TestResult::Failure(TestCaseFailureReason::Timeout),
+            label: None,
+        },
+    ]));
+
+    let actual = mozart
+        .oneshot(request)
+        .await
+        .expect("failed to execute oneshot request");
+
+    let actual_status = actual.status();
+    let body_bytes = to_bytes(actual.into_body(), usize::MAX)
+        .await
+        .expect("failed to convert body to bytes");
+
+    let actual_body: SubmissionResult =
+        serde_json::from_slice(&body_bytes).expect("failed to deserialize response body");
+
+    assert_eq!(actual_status, expected_status);
+    assert_eq!(actual_body, expected_body);
+}
+
+#[tokio::test]
+async fn all_test_cases_pass_int() {
+    let mozart = app(AppState::default());
+    let solution = ["fun solution(x: Long): Long = if (x < 0) -x else x"].join("\n");
+    let test_cases = Box::new([
+        TestCase {
+            id: 0,
+            input_parameters: Box::new([Parameter {
+                value_type: ParameterType::Int,
+                value: String::from("10"),
+            }]),
+            output_parameters: Box::new([Parameter {
+                value_type: ParameterType::Int,
+                value: String::from("10"),
+            }]),
+            stdin: None,
+            expected_error: None,
+            label: None,
+            hidden: false,
+        },
+        TestCase {
+            id: 1,
+            input_parameters: Box::new([Parameter {
+                value_type: ParameterType::Int,
+                value: String::from("-10"),
+            }]),
+            output_parameters: Box::new([Parameter {
+                value_type: ParameterType::Int,
+                value: String::from("10"),
+            }]),
+            stdin: None,
+            expected_error: None,
+            label: None,
+            hidden: false,
+        },
+    ]);
+    let submission = Submission {
+        solution,
+        test_cases,
+        unordered_lists: false,
+        files: Box::new([]),
+        checker: None,
+        reference_solution: None,
+        only_ids: None,
+        compiler_flags: Box::new([]),
+        debug: false,
+        float_tolerance: None,
+        toolchain_version: None,
+        metadata: None,
+    };
+    let body = serde_json::to_string(&submission).expect("failed to serialize submission");
+    let request = Builder::new()
+        .header("Content-Type", "application/json")
+        .method(Method::POST)
+        .uri("/submit")
+        .body(Body::from(body))
+        .expect("failed to build request");
+    let expected_status = StatusCode::OK;
+    let expected_body = SubmissionResult::Pass;
+
+    let actual = mozart
+        .oneshot(request)
+        .await
+        .expect("failed to execute oneshot request");
+
+    let actual_status = actual.status();
+    let body_bytes = to_bytes(actual.into_body(), usize::MAX)
+        .await
+        .expect("failed to convert body to bytes");
+
+    let actual_body: SubmissionResult =
+        serde_json::from_slice(&body_bytes).expect("failed to deserialize response body");
+
+    assert_eq!(actual_status, expected_status);
+    assert_eq!(actual_body, expected_body);
+}
+
+#[tokio::test]
+async fn all_test_cases_pass_bool() {
+    let mozart = app(AppState::default());
+    let solution = ["fun solution(x: Boolean): Boolean = !x"].join("\n");
+    let test_cases = Box::new([
+        TestCase {
+            id: 0,
+            input_parameters: Box::new([Parameter {
+                value_type: ParameterType::Bool,
+                value: String::from("true"),
+            }]),
+            output_parameters: Box::new([Parameter {
+                value_type: ParameterType::Bool,
+                value: String::from("false"),
+            }]),
+            stdin: None,
+            expected_error: None,
+            label: None,
+            hidden: false,
+        },
+        TestCase {
+            id: 1,
+            input_parameters: Box::new([Parameter {
+                value_type: ParameterType::Bool,
+                value: String::from("false"),
+            }]),
+            output_parameters: Box::new([Parameter {
+                value_type: ParameterType::Bool,
+                value: String::from("true"),
+            }]),
+            stdin: None,
+            expected_error: None,
+            label: None,
+            hidden: false,
+        },
+    ]);
+    let submission = Submission {
+        solution,
+        test_cases,
+        unordered_lists: false,
+        files: Box::new([]),
+        checker: None,
+        reference_solution: None,
+        only_ids: None,
+        compiler_flags: Box::new([]),
+        debug: false,
+        float_tolerance: None,
+        toolchain_version: None,
+        metadata: None,
+    };
+    let body = serde_json::to_string(&submission).expect("failed to serialize submission");
+    let request = Builder::new()
+        .header("Content-Type", "application/json")
+        .method(Method::POST)
+        .uri("/submit")
+        .body(Body::from(body))
+        .expect("failed to build request");
+    let expected_status = StatusCode::OK;
+    let expected_body = SubmissionResult::Pass;
+
+    let actual = mozart
+        .oneshot(request)
+        .await
+        .expect("failed to execute oneshot request");
+
+    let actual_status = actual.status();
+    let body_bytes = to_bytes(actual.into_body(), usize::MAX)
+        .await
+        .expect("failed to convert body to bytes");
+
+    let actual_body: SubmissionResult =
+        serde_json::from_slice(&body_bytes).expect("failed to deserialize response body");
+
+    assert_eq!(actual_status, expected_status);
+    assert_eq!(actual_body, expected_body);
+}
+
+#[tokio::test]
+async fn all_test_cases_pass_float() {
+    let mozart = app(AppState::default());
+    let solution = ["fun solution(x: Double): Double = x * 2"].join("\n");
+    let test_cases = Box::new([
+        TestCase {
+            id: 0,
+            input_parameters: Box::new([Parameter {
+                value_type: ParameterType::Float,
+                value: String::from("2.5"),
+            }]),
+            output_parameters: Box::new([Parameter {
+                value_type: ParameterType::Float,
+                value: String::from("5.0"),
+            }]),
+            stdin: None,
+            expected_error: None,
+            label: None,
+            hidden: false,
+        },
+        TestCase {
+            id: 1,
+            input_parameters: Box::new([Parameter {
+                value_type: ParameterType::Float,
+                value: String::from("-1"),
+            }]),
+            output_parameters: Box::new([Parameter {
+                value_type: ParameterType::Float,
+                value: String::from("-2"),
+            }]),
+            stdin: None,
+            expected_error: None,
+            label: None,
+            hidden: false,
+        },
+    ]);
+    let submission = Submission {
+        solution,
+        test_cases,
+        unordered_lists: false,
+        files: Box::new([]),
+        checker: None,
+        reference_solution: None,
+        only_ids: None,
+        compiler_flags: Box::new([]),
+        debug: false,
+        float_tolerance: None,
+        toolchain_version: None,
+        metadata: None,
+    };
+    let body = serde_json::to_string(&submission).expect("failed to serialize submission");
+    let request = Builder::new()
+        .header("Content-Type", "application/json")
+        .method(Method::POST)
+        .uri("/submit")
+        .body(Body::from(body))
+        .expect("failed to build request");
+    let expected_status = StatusCode::OK;
+    let expected_body = SubmissionResult::Pass;
+
+    let actual = mozart
+        .oneshot(request)
+        .await
+        .expect("failed to execute oneshot request");
+
+    let actual_status = actual.status();
+    let body_bytes = to_bytes(actual.into_body(), usize::MAX)
+        .await
+        .expect("failed to convert body to bytes");
+
+    let actual_body: SubmissionResult =
+        serde_json::from_slice(&body_bytes).expect("failed to deserialize response body");
+
+    assert_eq!(actual_status, expected_status);
+    assert_eq!(actual_body, expected_body);
+}
+
+#[tokio::test]
+async fn all_test_cases_pass_char() {
+    let mozart = app(AppState::default());
+    let solution = ["fun solution(x: Char): Char = x.uppercaseChar()"].join("\n");
+    let test_cases = Box::new([
+        TestCase {
+            id: 0,
+            input_parameters: Box::new([Parameter {
+                value_type: ParameterType::Char,
+                value: String::from("a"),
+            }]),
+            output_parameters: Box::new([Parameter {
+                value_type: ParameterType::Char,
+                value: String::from("A"),
+            }]),
+            stdin: None,
+            expected_error: None,
+            label: None,
+            hidden: false,
+        },
+        TestCase {
+            id: 1,
+            input_parameters: Box::new([Parameter {
+                value_type: ParameterType::Char,
+                value: String::from("z"),
+            }]),
+            output_parameters: Box::new([Parameter {
+                value_type: ParameterType::Char,
+                value: String::from("Z"),
+            }]),
+            stdin: None,
+            expected_error: None,
+            label: None,
+            hidden: false,
+        },
+    ]);
+    let submission = Submission {
+        solution,
+        test_cases,
+        unordered_lists: false,
+        files: Box::new([]),
+        checker: None,
+        reference_solution: None,
+        only_ids: None,
+        compiler_flags: Box::new([]),
+        debug: false,
+        float_tolerance: None,
+        toolchain_version: None,
+        metadata: None,
+    };
+    let body = serde_json::to_string(&submission).expect("failed to serialize submission");
+    let request = Builder::new()
+        .header("Content-Type", "application/json")
+        .method(Method::POST)
+        .uri("/submit")
+        .body(Body::from(body))
+        .expect("failed to build request");
+    let expected_status = StatusCode::OK;
+    let expected_body = SubmissionResult::Pass;
+
+    let actual = mozart
+        .oneshot(request)
+        .await
+        .expect("failed to execute oneshot request");
+
+    let actual_status = actual.status();
+    let body_bytes = to_bytes(actual.into_body(), usize::MAX)
+        .await
+        .expect("failed to convert body to bytes");
+
+    let actual_body: SubmissionResult =
+        serde_json::from_slice(&body_bytes).expect("failed to deserialize response body");
+
+    assert_eq!(actual_status, expected_status);
+    assert_eq!(actual_body, expected_body);
+}
+
+#[tokio::test]
+async fn all_test_cases_pass_string() {
+    let mozart = app(AppState::default());
+    let solution = ["fun solution(x: String): String = x.reversed()"].join("\n");
+    let test_cases = Box::new([
+        TestCase {
+            id: 0,
+            input_parameters: Box::new([Parameter {
+                value_type: ParameterType::String,
+                value: String::from("abc"),
+            }]),
+            output_parameters: Box::new([Parameter {
+                value_type: ParameterType::String,
+                value: String::from("cba"),
+            }]),
+            stdin: None,
+            expected_error: None,
+            label: None,
+            hidden: false,
+        },
+        TestCase {
+            id: 1,
+            input_parameters: Box::new([Parameter {
+                value_type: ParameterType::String,
+                value: String::from("racecar"),
+            }]),
+            output_parameters: Box::new([Parameter {
+                value_type: ParameterType::String,
+                value: String::from("racecar"),
+            }]),
+            stdin: None,
+            expected_error: None,
+            label: None,
+            hidden: false,
+        },
+    ]);
+    let submission = Submission {
+        solution,
+        test_cases,
+        unordered_lists: false,
+        files: Box::new([]),
+        checker: None,
+        reference_solution: None,
+        only_ids: None,
+        compiler_flags: Box::new([]),
+        debug: false,
+        float_tolerance: None,
+        toolchain_version: None,
+        metadata: None,
+    };
+    let body = serde_json::to_string(&submission).expect("failed to serialize submission");
+    let request = Builder::new()
+        .header("Content-Type", "application/json")
+        .method(Method::POST)
+        .uri("/submit")
+        .body(Body::from(body))
+        .expect("failed to build request");
+    let expected_status = StatusCode::OK;
+    let expected_body = SubmissionResult::Pass;
+
+    let actual = mozart
+        .oneshot(request)
+        .await
+        .expect("failed to execute oneshot request");
+
+    let actual_status = actual.status();
+    let body_bytes = to_bytes(actual.into_body(), usize::MAX)
+        .await
+        .expect("failed to convert body to bytes");
+
+    let actual_body: SubmissionResult =
+        serde_json::from_slice(&body_bytes).expect("failed to deserialize response body");
+
+    assert_eq!(actual_status, expected_status);
+    assert_eq!(actual_body, expected_body);
+}
+
+#[tokio::test]
+async fn all_test_cases_fail_int() {
+    let mozart = app(AppState::default());
+    let solution = ["fun solution(x: Long): Long = x"].join("\n");
+    let test_cases = Box::new([
+        TestCase {
+            id: 0,
+            input_parameters: Box::new([Parameter {
+                value_type: ParameterType::Int,
+                value: String::from("10"),
+            }]),
+            output_parameters: Box::new([Parameter {
+                value_type: ParameterType::Int,
+                value: String::from("20"),
+            }]),
+            stdin: None,
+            expected_error: None,
+            label: None,
+            hidden: false,
+        },
+        TestCase {
+            id: 1,
+            input_parameters: Box::new([Parameter {
+                value_type: ParameterType::Int,
+                value: String::from("5"),
+            }]),
+            output_parameters: Box::new([Parameter {
+                value_type: ParameterType::Int,
+                value: String::from("10"),
+            }]),
+            stdin: None,
+            expected_error: None,
+            label: None,
+            hidden: false,
+        },
+    ]);
+    let submission = Submission {
+        solution,
+        test_cases,
+        unordered_lists: false,
+        files: Box::new([]),
+        checker: None,
+        reference_solution: None,
+        only_ids: None,
+        compiler_flags: Box::new([]),
+        debug: false,
+        float_tolerance: None,
+        toolchain_version: None,
+        metadata: None,
+    };
+    let body = serde_json::to_string(&submission).expect("failed to serialize submission");
+    let request = Builder::new()
+        .header("Content-Type", "application/json")
+        .method(Method::POST)
+        .uri("/submit")
+        .body(Body::from(body))
+        .expect("failed to build request");
+    let expected_status = StatusCode::OK;
+    let expected_body = SubmissionResult::Failure(Box::new([
+        TestCaseResult {
+            id: 0,
+            test_result: TestResult::Failure(TestCaseFailureReason::WrongAnswer {
+                input_parameters: Box::new([Parameter {
+                    value_type: ParameterType::Int,
+                    value: String::from("10"),
+                }]),
+                actual: String::from("10"),
+                expected: String::from("20"),
+                actual_value: None,
+                expected_value: None,
+            }),
+            label: None,
+        },
+        TestCaseResult {
+            id: 1,
+            test_result: TestResult::Failure(TestCaseFailureReason::WrongAnswer {
+                input_parameters: Box::new([Parameter {
+                    value_type: ParameterType::Int,
+                    value: String::from("5"),
+                }]),
+                actual: String::from("5"),
+                expected: String::from("10"),
+                actual_value: None,
+                expected_value: None,
+            }),
+            label: None,
+        },
+    ]));
+
+    let actual = mozart
+        .oneshot(request)
+        .await
+        .expect("failed to execute oneshot request");
+
+    let actual_status = actual.status();
+    let body_bytes = to_bytes(actual.into_body(), usize::MAX)
+        .await
+        .expect("failed to convert body to bytes");
+
+    let actual_body: SubmissionResult =
+        serde_json::from_slice(&body_bytes).expect("failed to deserialize response body");
+
+    assert_eq!(actual_status, expected_status);
+    assert_eq!(actual_body, expected_body);
+}
+
+#[tokio::test]
+async fn all_test_cases_fail_bool() {
+    let mozart = app(AppState::default());
+    let solution = ["fun solution(x: Boolean): Boolean = x"].join("\n");
+    let test_cases = Box::new([
+        TestCase {
+            id: 0,
+            input_parameters: Box::new([Parameter {
+                value_type: ParameterType::Bool,
+                value: String::from("true"),
+            }]),
+            output_parameters: Box::new([Parameter {
+                value_type: ParameterType::Bool,
+                value: String::from("false"),
+            }]),
+            stdin: None,
+            expected_error: None,
+            label: None,
+            hidden: false,
+        },
+        TestCase {
+            id: 1,
+            input_parameters: Box::new([Parameter {
+                value_type: ParameterType::Bool,
+                value: String::from("false"),
+            }]),
+            output_parameters: Box::new([Parameter {
+                value_type: ParameterType::Bool,
+                value: String::from("true"),
+            }]),
+            stdin: None,
+            expected_error: None,
+            label: None,
+            hidden: false,
+        },
+    ]);
+    let submission = Submission {
+        solution,
+        test_cases,
+        unordered_lists: false,
+        files: Box::new([]),
+        checker: None,
+        reference_solution: None,
+        only_ids: None,
+        compiler_flags: Box::new([]),
+        debug: false,
+        float_tolerance: None,
+        toolchain_version: None,
+        metadata: None,
+    };
+    let body = serde_json::to_string(&submission).expect("failed to serialize submission");
+    let request = Builder::new()
+        .header("Content-Type", "application/json")
+        .method(Method::POST)
+        .uri("/submit")
+        .body(Body::from(body))
+        .expect("failed to build request");
+    let expected_status = StatusCode::OK;
+    let expected_body = SubmissionResult::Failure(Box::new([
+        TestCaseResult {
+            id: 0,
+            test_result: TestResult::Failure(TestCaseFailureReason::WrongAnswer {
+                input_parameters: Box::new([Parameter {
+                    value_type: ParameterType::Bool,
+                    value: String::from("true"),
+                }]),
+                actual: String::from("true"),
+                expected: String::from("false"),
+                actual_value: None,
+                expected_value: None,
+            }),
+            label: None,
+        },
+        TestCaseResult {
+            id: 1,
+            test_result: TestResult::Failure(TestCaseFailureReason::WrongAnswer {
+                input_parameters: Box::new([Parameter {
+                    value_type: ParameterType::Bool,
+                    value: String::from("false"),
+                }]),
+                actual: String::from("false"),
+                expected: String::from("true"),
+                actual_value: None,
+                expected_value: None,
+            }),
+            label: None,
+        },
+    ]));
+
+    let actual = mozart
+        .oneshot(request)
+        .await
+        .expect("failed to execute oneshot request");
+
+    let actual_status = actual.status();
+    let body_bytes = to_bytes(actual.into_body(), usize::MAX)
+        .await
+        .expect("failed to convert body to bytes");
+
+    let actual_body: SubmissionResult =
+        serde_json::from_slice(&body_bytes).expect("failed to deserialize response body");
+
+    assert_eq!(actual_status, expected_status);
+    assert_eq!(actual_body, expected_body);
+}
+
+#[tokio::test]
+async fn all_test_cases_fail_float() {
+    let mozart = app(AppState::default());
+    let solution = ["fun solution(x: Double): Double = x"].join("\n");
+    let test_cases = Box::new([
+        TestCase {
+            id: 0,
+            input_parameters: Box::new([Parameter {
+                value_type: ParameterType::Float,
+                value: String::from("1.5"),
+            }]),
+            output_parameters: Box::new([Parameter {
+                value_type: ParameterType::Float,
+                value: String::from("2.5"),
+            }]),
+            stdin: None,
+            expected_error: None,
+            label: None,
+            hidden: false,
+        },
+        TestCase {
+            id: 1,
+            input_parameters: Box::new([Parameter {
+                value_type: ParameterType::Float,
+                value: String::from("3"),
+            }]),
+            output_parameters: Box::new([Parameter {
+                value_type: ParameterType::Float,
+                value: String::from("4"),
+            }]),
+            stdin: None,
+            expected_error: None,
+            label: None,
+            hidden: false,
+        },
+    ]);
+    let submission = Submission {
+        solution,
+        test_cases,
+        unordered_lists: false,
+        files: Box::new([]),
+        checker: None,
+        reference_solution: None,
+        only_ids: None,
+        compiler_flags: Box::new([]),
+        debug: false,
+        float_tolerance: None,
+        toolchain_version: None,
+        metadata: None,
+    };
+    let body = serde_json::to_string(&submission).expect("failed to serialize submission");
+    let request = Builder::new()
+        .header("Content-Type", "application/json")
+        .method(Method::POST)
+        .uri("/submit")
+        .body(Body::from(body))
+        .expect("failed to build request");
+    let expected_status = StatusCode::OK;
+    let expected_body = SubmissionResult::Failure(Box::new([
+        TestCaseResult {
+            id: 0,
+            test_result: TestResult::Failure(TestCaseFailureReason::WrongAnswer {
+                input_parameters: Box::new([Parameter {
+                    value_type: ParameterType::Float,
+                    value: String::from("1.5"),
+                }]),
+                actual: String::from("1.5"),
+                expected: String::from("2.5"),
+                actual_value: None,
+                expected_value: None,
+            }),
+            label: None,
+        },
+        TestCaseResult {
+            id: 1,
+            test_result: TestResult::Failure(TestCaseFailureReason::WrongAnswer {
+                input_parameters: Box::new([Parameter {
+                    value_type: ParameterType::Float,
+                    value: String::from("3"),
+                }]),
+                actual: String::from("3.0"),
+                expected: String::from("4.0"),
+                actual_value: None,
+                expected_value: None,
+            }),
+            label: None,
+        },
+    ]));
+
+    let actual = mozart
+        .oneshot(request)
+        .await
+        .expect("failed to execute oneshot request");
+
+    let actual_status = actual.status();
+    let body_bytes = to_bytes(actual.into_body(), usize::MAX)
+        .await
+        .expect("failed to convert body to bytes");
+
+    let actual_body: SubmissionResult =
+        serde_json::from_slice(&body_bytes).expect("failed to deserialize response body");
+
+    assert_eq!(actual_status, expected_status);
+    assert_eq!(actual_body, expected_body);
+}
+
+#[tokio::test]
+async fn all_test_cases_fail_char() {
+    let mozart = app(AppState::default());
+    let solution = ["fun solution(x: Char): Char = x"].join("\n");
+    let test_cases = Box::new([
+        TestCase {
+            id: 0,
+            input_parameters: Box::new([Parameter {
+                value_type: ParameterType::Char,
+                value: String::from("a"),
+            }]),
+            output_parameters: Box::new([Parameter {
+                value_type: ParameterType::Char,
+                value: String::from("b"),
+            }]),
+            stdin: None,
+            expected_error: None,
+            label: None,
+            hidden: false,
+        },
+        TestCase {
+            id: 1,
+            input_parameters: Box::new([Parameter {
+                value_type: ParameterType::Char,
+                value: String::from("x"),
+            }]),
+            output_parameters: Box::new([Parameter {
+                value_type: ParameterType::Char,
+                value: String::from("y"),
+            }]),
+            stdin: None,
+            expected_error: None,
+            label: None,
+            hidden: false,
+        },
+    ]);
+    let submission = Submission {
+        solution,
+        test_cases,
+        unordered_lists: false,
+        files: Box::new([]),
+        checker: None,
+        reference_solution: None,
+        only_ids: None,
+        compiler_flags: Box::new([]),
+        debug: false,
+        float_tolerance: None,
+        toolchain_version: None,
+        metadata: None,
+    };
+    let body = serde_json::to_string(&submission).expect("failed to serialize submission");
+    let request = Builder::new()
+        .header("Content-Type", "application/json")
+        .method(Method::POST)
+        .uri("/submit")
+        .body(Body::from(body))
+        .expect("failed to build request");
+    let expected_status = StatusCode::OK;
+    let expected_body = SubmissionResult::Failure(Box::new([
+        TestCaseResult {
+            id: 0,
+            test_result: TestResult::Failure(TestCaseFailureReason::WrongAnswer {
+                input_parameters: Box::new([Parameter {
+                    value_type: ParameterType::Char,
+                    value: String::from("a"),
+                }]),
+                actual: String::from("a"),
+                expected: String::from("b"),
+                actual_value: None,
+                expected_value: None,
+            }),
+            label: None,
+        },
+        TestCaseResult {
+            id: 1,
+            test_result: TestResult::Failure(TestCaseFailureReason::WrongAnswer {
+                input_parameters: Box::new([Parameter {
+                    value_type: ParameterType::Char,
+                    value: String::from("x"),
+                }]),
+                actual: String::from("x"),
+                expected: String::from("y"),
+                actual_value: None,
+                expected_value: None,
+            }),
+            label: None,
+        },
+    ]));
+
+    let actual = mozart
+        .oneshot(request)
+        .await
+        .expect("failed to execute oneshot request");
+
+    let actual_status = actual.status();
+    let body_bytes = to_bytes(actual.into_body(), usize::MAX)
+        .await
+        .expect("failed to convert body to bytes");
+
+    let actual_body: SubmissionResult =
+        serde_json::from_slice(&body_bytes).expect("failed to deserialize response body");
+
+    assert_eq!(actual_status, expected_status);
+    assert_eq!(actual_body, expected_body);
+}
+
+#[tokio::test]
+async fn all_test_cases_fail_string() {
+    let mozart = app(AppState::default());
+    let solution = ["fun solution(x: String): String = x"].join("\n");
+    let test_cases = Box::new([
+        TestCase {
+            id: 0,
+            input_parameters: Box::new([Parameter {
+                value_type: ParameterType::String,
+                value: String::from("abc"),
+            }]),
+            output_parameters: Box::new([Parameter {
+                value_type: ParameterType::String,
+                value: String::from("xyz"),
+            }]),
+            stdin: None,
+            expected_error: None,
+            label: None,
+            hidden: false,
+        },
+        TestCase {
+            id: 1,
+            input_parameters: Box::new([Parameter {
+                value_type: ParameterType::String,
+                value: String::from("hello"),
+            }]),
+            output_parameters: Box::new([Parameter {
+                value_type: ParameterType::String,
+                value: String::from("world"),
+            }]),
+            stdin: None,
+            expected_error: None,
+            label: None,
+            hidden: false,
+        },
+    ]);
+    let submission = Submission {
+        solution,
+        test_cases,
+        unordered_lists: false,
+        files: Box::new([]),
+        checker: None,
+        reference_solution: None,
+        only_ids: None,
+        compiler_flags: Box::new([]),
+        debug: false,
+        float_tolerance: None,
+        toolchain_version: None,
+        metadata: None,
+    };
+    let body = serde_json::to_string(&submission).expect("failed to serialize submission");
+    let request = Builder::new()
+        .header("Content-Type", "application/json")
+        .method(Method::POST)
+        .uri("/submit")
+        .body(Body::from(body))
+        .expect("failed to build request");
+    let expected_status = StatusCode::OK;
+    let expected_body = SubmissionResult::Failure(Box::new([
+        TestCaseResult {
+            id: 0,
+            test_result: TestResult::Failure(TestCaseFailureReason::WrongAnswer {
+                input_parameters: Box::new([Parameter {
+                    value_type: ParameterType::String,
+                    value: String::from("abc"),
+                }]),
+                actual: String::from("abc"),
+                expected: String::from("xyz"),
+                actual_value: None,
+                expected_value: None,
+            }),
+            label: None,
+        },
+        TestCaseResult {
+            id: 1,
+            test_result: TestResult::Failure(TestCaseFailureReason::WrongAnswer {
+                input_parameters: Box::new([Parameter {
+                    value_type: ParameterType::String,
+                    value: String::from("hello"),
+                }]),
+                actual: String::from("hello"),
+                expected: String::from("world"),
+                actual_value: None,
+                expected_value: None,
+            }),
+            label: None,
+        },
+    ]));
+
+    let actual = mozart
+        .oneshot(request)
+        .await
+        .expect("failed to execute oneshot request");
+
+    let actual_status = actual.status();
+    let body_bytes = to_bytes(actual.into_body(), usize::MAX)
+        .await
+        .expect("failed to convert body to bytes");
+
+    let actual_body: SubmissionResult =
+        serde_json::from_slice(&body_bytes).expect("failed to deserialize response body");
+
+    assert_eq!(actual_status, expected_status);
+    assert_eq!(actual_body, expected_body);
+}
+
+#[tokio::test]
+async fn runtime_error_in_non_last_test_case() {
+    let mozart = app(AppState::default());
+    let solution = ["fun solution(i: Long): Long = 10 / i"].join("\n");
+    let test_cases = Box::new([
+        TestCase {
+            id: 0,
+            input_parameters: Box::new([Parameter {
+                value_type: ParameterType::Int,
+                value: String::from("2"),
+            }]),
+            output_parameters: Box::new([Parameter {
+                value_type: ParameterType::Int,
+                value: String::from("5"),
+            }]),
+            stdin: None,
+            expected_error: None,
+            label: None,
+            hidden: false,
+        },
+        TestCase {
+            id: 1,
+            input_parameters: Box::new([Parameter {
+                value_type: ParameterType::Int,
+                value: String::from("0"),
+            }]),
+            output_parameters: Box::new([Parameter {
+                value_type: ParameterType::Int,
+                value: String::from("0"),
+            }]),
+            stdin: None,
+            expected_error: None,
+            label: None,
+            hidden: false,
+        },
+        TestCase {
+            id: 2,
+            input_parameters: Box::new([Parameter {
+                value_type: ParameterType::Int,
+                value: String::from("2"),
+            }]),
+            output_parameters: Box::new([Parameter {
+                value_type: ParameterType::Int,
+                value: String::from("5"),
+            }]),
+            stdin: None,
+            expected_error: None,
+            label: None,
+            hidden: false,
+        },
+    ]);
+    let submission = Submission {
+        solution,
+        test_cases,
+        unordered_lists: false,
+        files: Box::new([]),
+        checker: None,
+        reference_solution: None,
+        only_ids: None,
+        compiler_flags: Box::new([]),
+        debug: false,
+        float_tolerance: None,
+        toolchain_version: None,
+        metadata: None,
+    };
+    let body = serde_json::to_string(&submission).expect("failed to serialize submission");
+    let request = Builder::new()
+        .header("Content-Type", "application/json")
+        .method(Method::POST)
+        .uri("/submit")
+        .body(Body::from(body))
+        .expect("failed to build request");
+    let expected_status = StatusCode::OK;
+    let expected_body = SubmissionResult::Failure(Box::new([
+        TestCaseResult {
+            id: 0,
+            test_result: TestResult::Pass,
+            label: None,
+        },
+        TestCaseResult {
+            id: 1,
+            test_result: TestResult::Failure(TestCaseFailureReason::RuntimeError(String::from(
+                "java.lang.ArithmeticException: / by zero",
+            ))),
+            label: None,
+        },
+        TestCaseResult {
+            id: 2,
+            test_result: TestResult::Pass,
+            label: None,
+        },
+    ]));
+
+    let actual = mozart
+        .oneshot(request)
+        .await
+        .expect("failed to execute oneshot request");
+
+    let actual_status = actual.status();
+    let body_bytes = to_bytes(actual.into_body(), usize::MAX)
+        .await
+        .expect("failed to convert body to bytes");
+
+    let actual_body: SubmissionResult =
+        serde_json::from_slice(&body_bytes).expect("failed to deserialize response body");
+
+    assert_eq!(actual_status, expected_status);
+    assert_eq!(actual_body, expected_body);
+}
+
+#[tokio::test]
+async fn big_int_factorial() {
+    let mozart = app(AppState::default());
+    let solution = [
+        "import java.math.BigInteger",
+        "",
+        "fun solution(n: BigInteger): BigInteger =",
+        "    generateSequence(BigInteger.ONE) { it + BigInteger.ONE }",
+        "        .take(n.toInt())",
+        "        .fold(BigInteger.ONE) { product, i -> product * i }",
+    ]
+    .join("\n");
+    let test_cases = Box::new([TestCase {
+        id: 0,
+        input_parameters: Box::new([Parameter {
+            value_type: ParameterType::BigInt,
+            value: String::from("25"),
+        }]),
+        output_parameters: Box::new([Parameter {
+            value_type: ParameterType::BigInt,
+            value: String::from("15511210043330985984000000"),
+        }]),
+        stdin: None,
+        expected_error: None,
+        label: None,
+        hidden: false,
+    }]);
+    let submission = Submission {
+        solution,
+        test_cases,
+        unordered_lists: false,
+        files: Box::new([]),
+        checker: None,
+        reference_solution: None,
+        only_ids: None,
+        compiler_flags: Box::new([]),
+        debug: false,
+        float_tolerance: None,
+        toolchain_version: None,
+        metadata: None,
+    };
+    let body = serde_json::to_string(&submission).expect("failed to serialize submission");
+    let request = Builder::new()
+        .header("Content-Type", "application/json")
+        .method(Method::POST)
+        .uri("/submit")
+        .body(Body::from(body))
+        .expect("failed to build request");
+    let expected_status = StatusCode::OK;
+    let expected_body = SubmissionResult::Pass;
+
+    let actual = mozart
+        .oneshot(request)
+        .await
+        .expect("failed to execute oneshot request");
+
+    let actual_status = actual.status();
+    let body_bytes = to_bytes(actual.into_body(), usize::MAX)
+        .await
+        .expect("failed to convert body to bytes");
+
+    let actual_body: SubmissionResult =
+        serde_json::from_slice(&body_bytes).expect("failed to deserialize response body");
+
+    assert_eq!(actual_status, expected_status);
+    assert_eq!(actual_body, expected_body);
+}
+
+#[tokio::test]
+async fn int32_wraps_on_overflow() {
+    let mozart = app(AppState::default());
+    let solution = ["fun solution(x: Int): Int = x + 1"].join("\n");
+    // an Int32 is a Kotlin Int, which wraps around like in the JVM
+    let test_cases = Box::new([TestCase {
+        id: 0,
+        input_parameters: Box::new([Parameter {
+            value_type: ParameterType::Int32,
+            value: String::from("2147483647"),
+        }]),
+        output_parameters: Box::new([Parameter {
+            value_type: ParameterType::Int32,
+            value: String::from("-2147483648"),
+        }]),
+        stdin: None,
+        expected_error: None,
+        label: None,
+        hidden: false,
+    }]);
+    let submission = Submission {
+        solution,
+        test_cases,
+        unordered_lists: false,
+        files: Box::new([]),
+        checker: None,
+        reference_solution: None,
+        only_ids: None,
+        compiler_flags: Box::new([]),
+        debug: false,
+        float_tolerance: None,
+        toolchain_version: None,
+        metadata: None,
+    };
+    let body = serde_json::to_string(&submission).expect("failed to serialize submission");
+    let request = Builder::new()
+        .header("Content-Type", "application/json")
+        .method(Method::POST)
+        .uri("/submit")
+        .body(Body::from(body))
+        .expect("failed to build request");
+    let expected_status = StatusCode::OK;
+    let expected_body = SubmissionResult::Pass;
+
+    let actual = mozart
+        .oneshot(request)
+        .await
+        .expect("failed to execute oneshot request");
+
+    let actual_status = actual.status();
+    let body_bytes = to_bytes(actual.into_body(), usize::MAX)
+        .await
+        .expect("failed to convert body to bytes");
+
+    let actual_body: SubmissionResult =
+        serde_json::from_slice(&body_bytes).expect("failed to deserialize response body");
+
+    assert_eq!(actual_status, expected_status);
+    assert_eq!(actual_body, expected_body);
+}
+
+#[tokio::test]
+async fn unit_output() {
+    let mozart = app(AppState::default());
+    let solution = [
+        "fun solution(x: Long): Unit {",
+        "    if (x < 0) {",
+        "        throw IllegalArgumentException(\"negative\")",
+        "    }",
+        "}",
+    ]
+    .join("\n");
+    let test_cases = Box::new([TestCase {
+        id: 0,
+        input_parameters: Box::new([Parameter {
+            value_type: ParameterType::Int,
+            value: String::from("2"),
+        }]),
+        output_parameters: Box::new([Parameter {
+            value_type: ParameterType::Unit,
+            value: String::from(""),
+        }]),
+        stdin: None,
+        expected_error: None,
+        label: None,
+        hidden: false,
+    }]);
+    let submission = Submission {
+        solution,
+        test_cases,
+        unordered_lists: false,
+        files: Box::new([]),
+        checker: None,
+        reference_solution: None,
+        only_ids: None,
+        compiler_flags: Box::new([]),
+        debug: false,
+        float_tolerance: None,
+        toolchain_version: None,
+        metadata: None,
+    };
+    let body = serde_json::to_string(&submission).expect("failed to serialize submission");
+    let request = Builder::new()
+        .header("Content-Type", "application/json")
+        .method(Method::POST)
+        .uri("/submit")
+        .body(Body::from(body))
+        .expect("failed to build request");
+    let expected_status = StatusCode::OK;
+    let expected_body = SubmissionResult::Pass;
+
+    let actual = mozart
+        .oneshot(request)
+        .await
+        .expect("failed to execute oneshot request");
+
+    let actual_status = actual.status();
+    let body_bytes = to_bytes(actual.into_body(), usize::MAX)
+        .await
+        .expect("failed to convert body to bytes");
+
+    let actual_body: SubmissionResult =
+        serde_json::from_slice(&body_bytes).expect("failed to deserialize response body");
+
+    assert_eq!(actual_status, expected_status);
+    assert_eq!(actual_body, expected_body);
+}
+
+#[tokio::test]
+async fn multiple_output_parameters() {
+    let mozart = app(AppState::default());
+    let solution = ["fun solution(x: Long): List<Any> = listOf(x / 2, x % 2 == 0L)"].join("\n");
+    // multiple output parameters are returned as a list
+    let test_cases = Box::new([
+        TestCase {
+            id: 0,
+            input_parameters: Box::new([Parameter {
+                value_type: ParameterType::Int,
+                value: String::from("4"),
+            }]),
+            output_parameters: Box::new([
+                Parameter {
+                    value_type: ParameterType::Int,
+                    value: String::from("2"),
+                },
+                Parameter {
+                    value_type: ParameterType::Bool,
+                    value: String::from("true"),
+                },
+            ]),
+            stdin: None,
+            expected_error: None,
+            label: None,
+            hidden: false,
+        },
+        TestCase {
+            id: 1,
+            input_parameters: Box::new([Parameter {
+                value_type: ParameterType::Int,
+                value: String::from("5"),
+            }]),
+            output_parameters: Box::new([
+                Parameter {
+                    value_type: ParameterType::Int,
+                    value: String::from("2"),
+                },
+                Parameter {
+                    value_type: ParameterType::Bool,
+                    value: String::from("false"),
+                },
+            ]),
+            stdin: None,
+            expected_error: None,
+            label: None,
+            hidden: false,
+        },
+    ]);
+    let submission = Submission {
+        solution,
+        test_cases,
+        unordered_lists: false,
+        files: Box::new([]),
+        checker: None,
+        reference_solution: None,
+        only_ids: None,
+        compiler_flags: Box::new([]),
+        debug: false,
+        float_tolerance: None,
+        toolchain_version: None,
+        metadata: None,
+    };
+    let body = serde_json::to_string(&submission).expect("failed to serialize submission");
+    let request = Builder::new()
+        .header("Content-Type", "application/json")
+        .method(Method::POST)
+        .uri("/submit")
+        .body(Body::from(body))
+        .expect("failed to build request");
+    let expected_status = StatusCode::OK;
+    let expected_body = SubmissionResult::Pass;
+
+    let actual = mozart
+        .oneshot(request)
+        .await
+        .expect("failed to execute oneshot request");
+
+    let actual_status = actual.status();
+    let body_bytes = to_bytes(actual.into_body(), usize::MAX)
+        .await
+        .expect("failed to convert body to bytes");
+
+    let actual_body: SubmissionResult =
+        serde_json::from_slice(&body_bytes).expect("failed to deserialize response body");
+
+    assert_eq!(actual_status, expected_status);
+    assert_eq!(actual_body, expected_body);
+}
+
+#[tokio::test]
+async fn duplicate_test_case_ids() {
+    let mozart = app(AppState::default());
+    let solution = ["fun solution(x: Long): Long = x"].join("\n");
+    let test_cases = Box::new([
+        TestCase {
+            id: 0,
+            input_parameters: Box::new([Parameter {
+                value_type: ParameterType::Int,
+                value: String::from("1"),
+            }]),
+            output_parameters: Box::new([Parameter {
+                value_type: ParameterType::Int,
+                value: String::from("1"),
+            }]),
+            stdin: None,
+            expected_error: None,
+            label: None,
+            hidden: false,
+        },
+        TestCase {
+            id: 0,
+            input_parameters: Box::new([Parameter {
+                value_type: ParameterType::Int,
+                value: String::from("2"),
+            }]),
+            output_parameters: Box::new([Parameter {
+                value_type: ParameterType::Int,
+                value: String::from("2"),
+            }]),
+            stdin: None,
+            expected_error: None,
+            label: None,
+            hidden: false,
+        },
+    ]);
+    let submission = Submission {
+        solution,
+        test_cases,
+        unordered_lists: false,
+        files: Box::new([]),
+        checker: None,
+        reference_solution: None,
+        only_ids: None,
+        compiler_flags: Box::new([]),
+        debug: false,
+        float_tolerance: None,
+        toolchain_version: None,
+        metadata: None,
+    };
+    let body = serde_json::to_string(&submission).expect("failed to serialize submission");
+    let request = Builder::new()
+        .header("Content-Type", "application/json")
+        .method(Method::POST)
+        .uri("/submit")
+        .body(Body::from(body))
+        .expect("failed to build request");
+    let expected_status = StatusCode::OK;
+
+    let actual = mozart
+        .oneshot(request)
+        .await
+        .expect("failed to execute oneshot request");
+
+    let actual_status = actual.status();
+    let body_bytes = to_bytes(actual.into_body(), usize::MAX)
+        .await
+        .expect("failed to convert body to bytes");
+
+    let actual_body: SubmissionResult =
+        serde_json::from_slice(&body_bytes).expect("failed to deserialize response body");
+
+    assert_eq!(actual_status, expected_status);
+
+    if let SubmissionResult::Error { code, message } = actual_body {
+        assert_eq!(code, ErrorCode::DuplicateTestCaseId);
+        assert!(message.starts_with("multiple test cases have the id 0"));
+    } else {
+        panic!("response body was not of error variant");
+    }
+}
+
+#[tokio::test]
+async fn package_declaration() {
+    let mozart = app(AppState::default());
+    let solution = ["package solutions", "", "fun solution(x: Long): Long = x"].join("\n");
+    let test_cases = Box::new([TestCase {
+        id: 0,
+        input_parameters: Box::new([Parameter {
+            value_type: ParameterType::Int,
+            value: String::from("1"),
+        }]),
+        output_parameters: Box::new([Parameter {
+            value_type: ParameterType::Int,
+            value: String::from("1"),
+        }]),
+        stdin: None,
+        expected_error: None,
+        label: None,
+        hidden: false,
+    }]);
+    let submission = Submission {
+        solution,
+        test_cases,
+        unordered_lists: false,
+        files: Box::new([]),
+        checker: None,
+        reference_solution: None,
+        only_ids: None,
+        compiler_flags: Box::new([]),
+        debug: false,
+        float_tolerance: None,
+        toolchain_version: None,
+        metadata: None,
+    };
+    let body = serde_json::to_string(&submission).expect("failed to serialize submission");
+    let request = Builder::new()
+        .header("Content-Type", "application/json")
+        .method(Method::POST)
+        .uri("/submit")
+        .body(Body::from(body))
+        .expect("failed to build request");
+    let expected_status = StatusCode::OK;
+
+    let actual = mozart
+        .oneshot(request)
+        .await
+        .expect("failed to execute oneshot request");
+
+    let actual_status = actual.status();
+    let body_bytes = to_bytes(actual.into_body(), usize::MAX)
+        .await
+        .expect("failed to convert body to bytes");
+
+    let actual_body: SubmissionResult =
+        serde_json::from_slice(&body_bytes).expect("failed to deserialize response body");
+
+    assert_eq!(actual_status, expected_status);
+
+    if let SubmissionResult::Error { code, message } = actual_body {
+        assert_eq!(code, ErrorCode::InvalidSolution);
+        assert!(message.starts_with("invalid solution: the solution cannot declare a package"));
+    } else {
+        panic!("response body was not of error variant");
+    }
+}
+
+#[tokio::test]
+async fn values_with_delimiters() {
+    let mozart = app(AppState::default());
+    let solution = [
+        "fun solution(x: Long): String {",
+        "    if (x == 2L) {",
+        r#"        throw IllegalArgumentException("invalid value, got \"2\"")"#,
+        "    }",
+        r#"    return "a, \"b\"\nc""#,
+        "}",
+    ]
+    .join("\n");
+    // commas, quotes and newlines must not break the reporting of the outcome
+    let test_cases = Box::new([
+        TestCase {
+            id: 0,
+            input_parameters: Box::new([Parameter {
+                value_type: ParameterType::Int,
+                value: String::from("1"),
+            }]),
+            output_parameters: Box::new([Parameter {
+                value_type: ParameterType::String,
+                value: String::from("x"),
+            }]),
+            stdin: None,
+            expected_error: None,
+            label: None,
+            hidden: false,
+        },
+        TestCase {
+            id: 1,
+            input_parameters: Box::new([Parameter {
+                value_type: ParameterType::Int,
+                value: String::from("2"),
+            }]),
+            output_parameters: Box::new([Parameter {
+                value_type: ParameterType::String,
+                value: String::from("x"),
+            }]),
+            stdin: None,
+            expected_error: None,
+            label: None,
+            hidden: false,
+        },
+    ]);
+    let submission = Submission {
+        solution,
+        test_cases,
+        unordered_lists: false,
+        files: Box::new([]),
+        checker: None,
+        reference_solution: None,
+        only_ids: None,
+        compiler_flags: Box::new([]),
+        debug: false,
+        float_tolerance: None,
+        toolchain_version: None,
+        metadata: None,
+    };
+    let body = serde_json::to_string(&submission).expect("failed to serialize submission");
+    let request = Builder::new()
+        .header("Content-Type", "application/json")
+        .method(Method::POST)
+        .uri("/submit")
+        .body(Body::from(body))
+        .expect("failed to build request");
+    let expected_status = StatusCode::OK;
+    let expected_body = SubmissionResult::Failure(Box::new([
+        TestCaseResult {
+            id: 0,
+            test_result: TestResult::Failure(TestCaseFailureReason::WrongAnswer {
+                input_parameters: Box::new([Parameter {
+                    value_type: ParameterType::Int,
+                    value: String::from("1"),
+                }]),
+                actual: String::from("a, \"b\"\nc"),
+                expected: String::from("x"),
+                actual_value: None,
+                expected_value: None,
+            }),
+            label: None,
+        },
+        TestCaseResult {
+            id: 1,
+            test_result: TestResult::Failure(TestCaseFailureReason::RuntimeError(String::from(
+                r#"java.lang.IllegalArgumentException: invalid value, got "2""#,
+            ))),
+            label: None,
+        },
+    ]));
+
+    let actual = mozart
+        .oneshot(request)
+        .await
+        .expect("failed to execute oneshot request");
+
+    let actual_status = actual.status();
+    let body_bytes = to_bytes(actual.into_body(), usize::MAX)
+        .await
+        .expect("failed to convert body to bytes");
+
+    let actual_body: SubmissionResult =
+        serde_json::from_slice(&body_bytes).expect("failed to deserialize response body");
+
+    assert_eq!(actual_status, expected_status);
+    assert_eq!(actual_body, expected_body);
+}
+
+#[tokio::test]
+async fn custom_checker() {
+    let mozart = app(AppState::default());
+    let solution = ["fun solution(n: Long): String = \"a\".repeat(n.toInt())"].join("\n");
+    // any value with the length of the expected value is accepted
+    let test_cases = Box::new([
+        TestCase {
+            id: 0,
+            input_parameters: Box::new([Parameter {
+                value_type: ParameterType::Int,
+                value: String::from("3"),
+            }]),
+            output_parameters: Box::new([Parameter {
+                value_type: ParameterType::String,
+                value: String::from("xyz"),
+            }]),
+            stdin: None,
+            expected_error: None,
+            label: None,
+            hidden: false,
+        },
+        TestCase {
+            id: 1,
+            input_parameters: Box::new([Parameter {
+                value_type: ParameterType::Int,
+                value: String::from("2"),
+            }]),
+            output_parameters: Box::new([Parameter {
+                value_type: ParameterType::String,
+                value: String::from("xyz"),
+            }]),
+            stdin: None,
+            expected_error: None,
+            label: None,
+            hidden: false,
+        },
+    ]);
+    let submission = Submission {
+        solution,
+        test_cases,
+        unordered_lists: false,
+        files: Box::new([]),
+        checker: Some(String::from(
+            "fun checker(actual: String, expected: String): Boolean = actual.length == expected.length\n",
+        )),
+        reference_solution: None,
+        only_ids: None,
+        compiler_flags: Box::new([]),
+        debug: false,
+        float_tolerance: None,
+        toolchain_version: None,
+        metadata: None,
+    };
+    let body = serde_json::to_string(&submission).expect("failed to serialize submission");
+    let request = Builder::new()
+        .header("Content-Type", "application/json")
+        .method(Method::POST)
+        .uri("/submit")
+        .body(Body::from(body))
+        .expect("failed to build request");
+    let expected_status = StatusCode::OK;
+    let expected_body = SubmissionResult::Failure(Box::new([
+        TestCaseResult {
+            id: 0,
+            test_result: TestResult::Pass,
+            label: None,
+        },
+        TestCaseResult {
+            id: 1,
+            test_result: TestResult::Failure(TestCaseFailureReason::WrongAnswer {
+                input_parameters: Box::new([Parameter {
+                    value_type: ParameterType::Int,
+                    value: String::from("2"),
+                }]),
+                actual: String::from("aa"),
+                expected: String::from("xyz"),
+                actual_value: None,
+                expected_value: None,
+            }),
+            label: None,
+        },
+    ]));
+
+    let actual = mozart
+        .oneshot(request)
+        .await
+        .expect("failed to execute oneshot request");
+
+    let actual_status = actual.status();
+    let body_bytes = to_bytes(actual.into_body(), usize::MAX)
+        .await
+        .expect("failed to convert body to bytes");
+
+    let actual_body: SubmissionResult =
+        serde_json::from_slice(&body_bytes).expect("failed to deserialize response body");
+
+    assert_eq!(actual_status, expected_status);
+    assert_eq!(actual_body, expected_body);
+}
+
+#[tokio::test]
+async fn read_stdin() {
+    let mozart = app(AppState::default());
+    let solution = ["fun solution(n: Long): String = readlnOrNull() ?: \"\""].join("\n");
+    // every test case reads from its own stdin, which is empty if it is not supplied
+    let test_cases = Box::new([
+        TestCase {
+            id: 0,
+            input_parameters: Box::new([Parameter {
+                value_type: ParameterType::Int,
+                value: String::from("0"),
+            }]),
+            output_parameters: Box::new([Parameter {
+                value_type: ParameterType::String,
+                value: String::from("hello"),
+            }]),
+            stdin: Some(String::from("hello\nworld\n")),
+            expected_error: None,
+            label: None,
+            hidden: false,
+        },
+        TestCase {
+            id: 1,
+            input_parameters: Box::new([Parameter {
+                value_type: ParameterType::Int,
+                value: String::from("1"),
+            }]),
+            output_parameters: Box::new([Parameter {
+                value_type: ParameterType::String,
+                value: String::from("world"),
+            }]),
+            stdin: Some(String::from("world\n")),
+            expected_error: None,
+            label: None,
+            hidden: false,
+        },
+        TestCase {
+            id: 2,
+            input_parameters: Box::new([Parameter {
+                value_type: ParameterType::Int,
+                value: String::from("2"),
+            }]),
+            output_parameters: Box::new([Parameter {
+                value_type: ParameterType::String,
+                value: String::from(""),
+            }]),
+            stdin: None,
+            expected_error: None,
+            label: None,
+            hidden: false,
+        },
+    ]);
+    let submission = Submission {
+        solution,
+        test_cases,
+        unordered_lists: false,
+        files: Box::new([]),
+        checker: None,
+        reference_solution: None,
+        only_ids: None,
+        compiler_flags: Box::new([]),
+        debug: false,
+        float_tolerance: None,
+        toolchain_version: None,
+        metadata: None,
+    };
+    let body = serde_json::to_string(&submission).expect("failed to serialize submission");
+    let request = Builder::new()
+        .header("Content-Type", "application/json")
+        .method(Method::POST)
+        .uri("/submit")
+        .body(Body::from(body))
+        .expect("failed to build request");
+    let expected_status = StatusCode::OK;
+    let expected_body = SubmissionResult::Pass;
+
+    let actual = mozart
+        .oneshot(request)
+        .await
+        .expect("failed to execute oneshot request");
+
+    let actual_status = actual.status();
+    let body_bytes = to_bytes(actual.into_body(), usize::MAX)
+        .await
+        .expect("failed to convert body to bytes");
+
+    let actual_body: SubmissionResult =
+        serde_json::from_slice(&body_bytes).expect("failed to deserialize response body");
+
+    assert_eq!(actual_status, expected_status);
+    assert_eq!(actual_body, expected_body);
+}
+
+#[tokio::test]
+async fn return_map() {
+    let mozart = app(AppState::default());
+    let solution = [
+        "fun solution(s: String): Map<String, Long> =",
+        "    s.split(\" \").filter { it.isNotEmpty() }.groupingBy { it }.eachCount().mapValues { it.value.toLong() }",
+    ]
+    .join("\n");
+    // the entries are inserted in another order than they are listed, which must not matter
+    let test_cases = Box::new([
+        TestCase {
+            id: 0,
+            input_parameters: Box::new([Parameter {
+                value_type: ParameterType::String,
+                value: String::from("pear apple pear"),
+            }]),
+            output_parameters: Box::new([Parameter {
+                value_type: ParameterType::Map {
+                    key: Box::new(ParameterType::String),
+                    value: Box::new(ParameterType::Int),
+                },
+                value: String::from(r#"{"apple": "1", "pear": "2"}"#),
+            }]),
+            stdin: None,
+            expected_error: None,
+            label: None,
+            hidden: false,
+        },
+        TestCase {
+            id: 1,
+            input_parameters: Box::new([Parameter {
+                value_type: ParameterType::String,
+                value: String::from(""),
+            }]),
+            output_parameters: Box::new([Parameter {
+                value_type: ParameterType::Map {
+                    key: Box::new(ParameterType::String),
+                    value: Box::new(ParameterType::Int),
+                },
+                value: String::from("{}"),
+            }]),
+            stdin: None,
+            expected_error: None,
+            label: None,
+            hidden: false,
+        },
+    ]);
+    let submission = Submission {
+        solution,
+        test_cases,
+        unordered_lists: false,
+        files: Box::new([]),
+        checker: None,
+        reference_solution: None,
+        only_ids: None,
+        compiler_flags: Box::new([]),
+        debug: false,
+        float_tolerance: None,
+        toolchain_version: None,
+        metadata: None,
+    };
+    let body = serde_json::to_string(&submission).expect("failed to serialize submission");
+    let request = Builder::new()
+        .header("Content-Type", "application/json")
+        .method(Method::POST)
+        .uri("/submit")
+        .body(Body::from(body))
+        .expect("failed to build request");
+    let expected_status = StatusCode::OK;
+    let expected_body = SubmissionResult::Pass;
+
+    let actual = mozart
+        .oneshot(request)
+        .await
+        .expect("failed to execute oneshot request");
+
+    let actual_status = actual.status();
+    let body_bytes = to_bytes(actual.into_body(), usize::MAX)
+        .await
+        .expect("failed to convert body to bytes");
+
+    let actual_body: SubmissionResult =
+        serde_json::from_slice(&body_bytes).expect("failed to deserialize response body");
+
+    assert_eq!(actual_status, expected_status);
+    assert_eq!(actual_body, expected_body);
+}
+
+#[tokio::test]
+async fn unordered_list() {
+    let mozart = app(AppState::default());
+    let solution =
+        ["fun solution(xs: List<Long>): List<Long> = xs.distinct().sortedDescending()"].join("\n");
+    // the solution returns the elements in descending order, which is accepted as the order does not matter
+    let test_cases = Box::new([TestCase {
+        id: 0,
+        input_parameters: Box::new([Parameter {
+            value_type: ParameterType::List(Box::new(ParameterType::Int)),
+            value: String::from(r#"["3", "1", "3", "2"]"#),
+        }]),
+        output_parameters: Box::new([Parameter {
+            value_type: ParameterType::List(Box::new(ParameterType::Int)),
+            value: String::from(r#"["1", "2", "3"]"#),
+        }]),
+        stdin: None,
+        expected_error: None,
+        label: None,
+        hidden: false,
+    }]);
+    let submission = Submission {
+        solution,
+        test_cases,
+        unordered_lists: true,
+        files: Box::new([]),
+        checker: None,
+        reference_solution: None,
+        only_ids: None,
+        compiler_flags: Box::new([]),
+        debug: false,
+        float_tolerance: None,
+        toolchain_version: None,
+        metadata: None,
+    };
+    let body = serde_json::to_string(&submission).expect("failed to serialize submission");
+    let request = Builder::new()
+        .header("Content-Type", "application/json")
+        .method(Method::POST)
+        .uri("/submit")
+        .body(Body::from(body))
+        .expect("failed to build request");
+    let expected_status = StatusCode::OK;
+    let expected_body = SubmissionResult::Pass;
+
+    let actual = mozart
+        .oneshot(request)
+        .await
+        .expect("failed to execute oneshot request");
+
+    let actual_status = actual.status();
+    let body_bytes = to_bytes(actual.into_body(), usize::MAX)
+        .await
+        .expect("failed to convert body to bytes");
+
+    let actual_body: SubmissionResult =
+        serde_json::from_slice(&body_bytes).expect("failed to deserialize response body");
+
+    assert_eq!(actual_status, expected_status);
+    assert_eq!(actual_body, expected_body);
+}
+
+#[tokio::test]
+async fn ordered_list() {
+    let mozart = app(AppState::default());
+    let solution =
+        ["fun solution(xs: List<Long>): List<Long> = xs.distinct().sortedDescending()"].join("\n");
+    let test_cases = Box::new([TestCase {
+        id: 0,
+        input_parameters: Box::new([Parameter {
+            value_type: ParameterType::List(Box::new(ParameterType::Int)),
+            value: String::from(r#"["3", "1", "3", "2"]"#),
+        }]),
+        output_parameters: Box::new([Parameter {
+            value_type: ParameterType::List(Box::new(ParameterType::Int)),
+            value: String::from(r#"["1", "2", "3"]"#),
+        }]),
+        stdin: None,
+        expected_error: None,
+        label: None,
+        hidden: false,
+    }]);
+    let submission = Submission {
+        solution,
+        test_cases,
+        unordered_lists: false,
+        files: Box::new([]),
+        checker: None,
+        reference_solution: None,
+        only_ids: None,
+        compiler_flags: Box::new([]),
+        debug: false,
+        float_tolerance: None,
+        toolchain_version: None,
+        metadata: None,
+    };
+    let body = serde_json::to_string(&submission).expect("failed to serialize submission");
+    let request = Builder::new()
+        .header("Content-Type", "application/json")
+        .method(Method::POST)
+        .uri("/submit")
+        .body(Body::from(body))
+        .expect("failed to build request");
+    let expected_status = StatusCode::OK;
+    let expected_body = SubmissionResult::Failure(Box::new([TestCaseResult {
+        id: 0,
+        test_result: TestResult::Failure(TestCaseFailureReason::WrongAnswer {
+            input_parameters: Box::new([Parameter {
+                value_type: ParameterType::List(Box::new(ParameterType::Int)),
+                value: String::from(r#"["3", "1", "3", "2"]"#),
+            }]),
+            actual: String::from("[3, 2, 1]"),
+            expected: String::from("[1, 2, 3]"),
+            actual_value: Some(json!([3, 2, 1])),
+            expected_value: Some(json!([1, 2, 3])),
+        }),
+        label: None,
+    }]));
+
+    let actual = mozart
+        .oneshot(request)
+        .await
+        .expect("failed to execute oneshot request");
+
+    let actual_status = actual.status();
+    let body_bytes = to_bytes(actual.into_body(), usize::MAX)
+        .await
+        .expect("failed to convert body to bytes");
+
+    let actual_body: SubmissionResult =
+        serde_json::from_slice(&body_bytes).expect("failed to deserialize response body");
+
+    assert_eq!(actual_status, expected_status);
+    assert_eq!(actual_body, expected_body);
+}
+
+#[tokio::test]
+async fn only_ids() {
+    let mozart = app(AppState::default());
+    let solution = ["fun solution(x: Long): Long = x * 2"].join("\n");
+    // only the requested test case is run, so the failure of the others is not reported
+    let test_cases = Box::new([
+        TestCase {
+            id: 0,
+            input_parameters: Box::new([Parameter {
+                value_type: ParameterType::Int,
+                value: String::from("0"),
+            }]),
+            output_parameters: Box::new([Parameter {
+                value_type: ParameterType::Int,
+                value: String::from("0"),
+            }]),
+            stdin: None,
+            expected_error: None,
+            label: None,
+            hidden: false,
+        },
+        TestCase {
+            id: 1,
+            input_parameters: Box::new([Parameter {
+                value_type: ParameterType::Int,
+                value: String::from("1"),
+            }]),
+            output_parameters: Box::new([Parameter {
+                value_type: ParameterType::Int,
+                value: String::from("0"),
+            }]),
+            stdin: None,
+            expected_error: None,
+            label: None,
+            hidden: false,
+        },
+        TestCase {
+            id: 2,
+            input_parameters: Box::new([Parameter {
+                value_type: ParameterType::Int,
+                value: String::from("2"),
+            }]),
+            output_parameters: Box::new([Parameter {
+                value_type: ParameterType::Int,
+                value: String::from("4"),
+            }]),
+            stdin: None,
+            expected_error: None,
+            label: None,
+            hidden: false,
+        },
+        TestCase {
+            id: 3,
+            input_parameters: Box::new([Parameter {
+                value_type: ParameterType::Int,
+                value: String::from("3"),
+            }]),
+            output_parameters: Box::new([Parameter {
+                value_type: ParameterType::Int,
+                value: String::from("7"),
+            }]),
+            stdin: None,
+            expected_error: None,
+            label: None,
+            hidden: false,
+        },
+        TestCase {
+            id: 4,
+            input_parameters: Box::new([Parameter {
+                value_type: ParameterType::Int,
+                value: String::from("4"),
+            }]),
+            output_parameters: Box::new([Parameter {
+                value_type: ParameterType::Int,
+                value: String::from("8"),
+            }]),
+            stdin: None,
+            expected_error: None,
+            label: None,
+            hidden: false,
+        },
+    ]);
+    let submission = Submission {
+        solution,
+        test_cases,
+        unordered_lists: false,
+        files: Box::new([]),
+        checker: None,
+        reference_solution: None,
+        only_ids: Some(Box::new([3])),
+        compiler_flags: Box::new([]),
+        debug: false,
+        float_tolerance: None,
+        toolchain_version: None,
+        metadata: None,
+    };
+    let body = serde_json::to_string(&submission).expect("failed to serialize submission");
+    let request = Builder::new()
+        .header("Content-Type", "application/json")
+        .method(Method::POST)
+        .uri("/submit")
+        .body(Body::from(body))
+        .expect("failed to build request");
+    let expected_status = StatusCode::OK;
+    let expected_body = SubmissionResult::Failure(Box::new([TestCaseResult {
+        id: 3,
+        test_result: TestResult::Failure(TestCaseFailureReason::WrongAnswer {
+            input_parameters: Box::new([Parameter {
+                value_type: ParameterType::Int,
+                value: String::from("3"),
+            }]),
+            actual: String::from("6"),
+            expected: String::from("7"),
+            actual_value: None,
+            expected_value: None,
+        }),
+        label: None,
+    }]));
+
+    let actual = mozart
+        .oneshot(request)
+        .await
+        .expect("failed to execute oneshot request");
+
+    let actual_status = actual.status();
+    let body_bytes = to_bytes(actual.into_body(), usize::MAX)
+        .await
+        .expect("failed to convert body to bytes");
+
+    let actual_body: SubmissionResult =
+        serde_json::from_slice(&body_bytes).expect("failed to deserialize response body");
+
+    assert_eq!(actual_status, expected_status);
+    assert_eq!(actual_body, expected_body);
+}
+
+#[tokio::test]
+async fn allowed_compiler_flag() {
+    let mozart = app(AppState::new(Config {
+        allowed_compiler_flags: Box::new([String::from("-opt-in=kotlin.ExperimentalStdlibApi")]),
+        ..Config::default()
+    }));
+    let solution = ["fun solution(x: Long): String = x.toHexString()"].join("\n");
+    // the solution only compiles if the flag is passed to the compiler
+    let test_cases = Box::new([TestCase {
+        id: 0,
+        input_parameters: Box::new([Parameter {
+            value_type: ParameterType::Int,
+            value: String::from("255"),
+        }]),
+        output_parameters: Box::new([Parameter {
+            value_type: ParameterType::String,
+            value: String::from("00000000000000ff"),
+        }]),
+        stdin: None,
+        expected_error: None,
+        label: None,
+        hidden: false,
+    }]);
+    let submission = Submission {
+        solution,
+        test_cases,
+        unordered_lists: false,
+        files: Box::new([]),
+        checker: None,
+        reference_solution: None,
+        only_ids: None,
+        compiler_flags: Box::new([String::from("-opt-in=kotlin.ExperimentalStdlibApi")]),
+        debug: false,
+        float_tolerance: None,
+        toolchain_version: None,
+        metadata: None,
+    };
+    let body = serde_json::to_string(&submission).expect("failed to serialize submission");
+    let request = Builder::new()
+        .header("Content-Type", "application/json")
+        .method(Method::POST)
+        .uri("/submit")
+        .body(Body::from(body))
+        .expect("failed to build request");
+    let expected_status = StatusCode::OK;
+    let expected_body = SubmissionResult::Pass;
+
+    let actual = mozart
+        .oneshot(request)
+        .await
+        .expect("failed to execute oneshot request");
+
+    let actual_status = actual.status();
+    let body_bytes = to_bytes(actual.into_body(), usize::MAX)
+        .await
+        .expect("failed to convert body to bytes");
+
+    let actual_body: SubmissionResult =
+        serde_json::from_slice(&body_bytes).expect("failed to deserialize response body");
+
+    assert_eq!(actual_status, expected_status);
+    assert_eq!(actual_body, expected_body);
+}
+
+#[tokio::test]
+async fn disallowed_compiler_flag() {
+    let mozart = app(AppState::new(Config {
+        allowed_compiler_flags: Box::new([String::from("-opt-in=kotlin.ExperimentalStdlibApi")]),
+        ..Config::default()
+    }));
+    let solution = ["fun solution(x: Long): String = x.toHexString()"].join("\n");
+    let test_cases = Box::new([TestCase {
+        id: 0,
+        input_parameters: Box::new([Parameter {
+            value_type: ParameterType::Int,
+            value: String::from("255"),
+        }]),
+        output_parameters: Box::new([Parameter {
+            value_type: ParameterType::String,
+            value: String::from("00000000000000ff"),
+        }]),
+        stdin: None,
+        expected_error: None,
+        label: None,
+        hidden: false,
+    }]);
+    let submission = Submission {
+        solution,
+        test_cases,
+        unordered_lists: false,
+        files: Box::new([]),
+        checker: None,
+        reference_solution: None,
+        only_ids: None,
+        compiler_flags: Box::new([String::from("-fplugin=Evil")]),
+        debug: false,
+        float_tolerance: None,
+        toolchain_version: None,
+        metadata: None,
+    };
+    let body = serde_json::to_string(&submission).expect("failed to serialize submission");
+    let request = Builder::new()
+        .header("Content-Type", "application/json")
+        .method(Method::POST)
+        .uri("/submit")
+        .body(Body::from(body))
+        .expect("failed to build request");
+    let expected_status = StatusCode::OK;
+
+    let actual = mozart
+        .oneshot(request)
+        .await
+        .expect("failed to execute oneshot request");
+
+    let actual_status = actual.status();
+    let body_bytes = to_bytes(actual.into_body(), usize::MAX)
+        .await
+        .expect("failed to convert body to bytes");
+
+    let actual_body: SubmissionResult =
+        serde_json::from_slice(&body_bytes).expect("failed to deserialize response body");
+
+    assert_eq!(actual_status, expected_status);
+
+    if let SubmissionResult::Error { code, message } = actual_body {
+        assert_eq!(code, ErrorCode::DisallowedCompilerFlag);
+        assert!(message.starts_with("the compiler flag '-fplugin=Evil' is not allowed"));
+    } else {
+        panic!("response body was not of error variant");
+    }
+}
+
+#[tokio::test]
+async fn reference_solution() {
+    let mozart = app(AppState::default());
+    let solution = ["fun solution(x: Long): Long = x * x"].join("\n");
+    let reference_solution = ["fun solution(x: Long): Long = (1..x).sumOf { x }"].join("\n");
+    // the first test case has no output parameters, so its expected value is computed by the reference solution
+    let test_cases = Box::new([
+        TestCase {
+            id: 0,
+            input_parameters: Box::new([Parameter {
+                value_type: ParameterType::Int,
+                value: String::from("3"),
+            }]),
+            output_parameters: Box::new([]),
+            stdin: None,
+            expected_error: None,
+            label: None,
+            hidden: false,
+        },
+        TestCase {
+            id: 1,
+            input_parameters: Box::new([Parameter {
+                value_type: ParameterType::Int,
+                value: String::from("2"),
+            }]),
+            output_parameters: Box::new([Parameter {
+                value_type: ParameterType::Int,
+                value: String::from("4"),
+            }]),
+            stdin: None,
+            expected_error: None,
+            label: None,
+            hidden: false,
+        },
+    ]);
+    let submission = Submission {
+        solution,
+        test_cases,
+        unordered_lists: false,
+        files: Box::new([]),
+        checker: None,
+        reference_solution: Some(reference_solution),
+        only_ids: None,
+        compiler_flags: Box::new([]),
+        debug: false,
+        float_tolerance: None,
+        toolchain_version: None,
+        metadata: None,
+    };
+    let body = serde_json::to_string(&submission).expect("failed to serialize submission");
+    let request = Builder::new()
+        .header("Content-Type", "application/json")
+        .method(Method::POST)
+        .uri("/submit")
+        .body(Body::from(body))
+        .expect("failed to build request");
+    let expected_status = StatusCode::OK;
+    let expected_body = SubmissionResult::Pass;
+
+    let actual = mozart
+        .oneshot(request)
+        .await
+        .expect("failed to execute oneshot request");
+
+    let actual_status = actual.status();
+    let body_bytes = to_bytes(actual.into_body(), usize::MAX)
+        .await
+        .expect("failed to convert body to bytes");
+
+    let actual_body: SubmissionResult =
+        serde_json::from_slice(&body_bytes).expect("failed to deserialize response body");
+
+    assert_eq!(actual_status, expected_status);
+    assert_eq!(actual_body, expected_body);
+}
+
+#[tokio::test]
+async fn reference_solution_disagrees() {
+    let mozart = app(AppState::default());
+    let solution = ["fun solution(x: Long): Long = x * 2"].join("\n");
+    let reference_solution = ["fun solution(x: Long): Long = (1..x).sumOf { x }"].join("\n");
+    // the first test case has no output parameters, so its expected value is computed by the reference solution
+    let test_cases = Box::new([
+        TestCase {
+            id: 0,
+            input_parameters: Box::new([Parameter {
+                value_type: ParameterType::Int,
+                value: String::from("3"),
+            }]),
+            output_parameters: Box::new([]),
+            stdin: None,
+            expected_error: None,
+            label: None,
+            hidden: false,
+        },
+        TestCase {
+            id: 1,
+            input_parameters: Box::new([Parameter {
+                value_type: ParameterType::Int,
+                value: String::from("2"),
+            }]),
+            output_parameters: Box::new([Parameter {
+                value_type: ParameterType::Int,
+                value: String::from("4"),
+            }]),
+            stdin: None,
+            expected_error: None,
+            label: None,
+            hidden: false,
+        },
+    ]);
+    let submission = Submission {
+        solution,
+        test_cases,
+        unordered_lists: false,
+        files: Box::new([]),
+        checker: None,
+        reference_solution: Some(reference_solution),
+        only_ids: None,
+        compiler_flags: Box::new([]),
+        debug: false,
+        float_tolerance: None,
+        toolchain_version: None,
+        metadata: None,
+    };
+    let body = serde_json::to_string(&submission).expect("failed to serialize submission");
+    let request = Builder::new()
+        .header("Content-Type", "application/json")
+        .method(Method::POST)
+        .uri("/submit")
+        .body(Body::from(body))
+        .expect("failed to build request");
+    let expected_status = StatusCode::OK;
+    let expected_body = SubmissionResult::Failure(Box::new([
+        TestCaseResult {
+            id: 0,
+            test_result: TestResult::Failure(TestCaseFailureReason::WrongAnswer {
+                input_parameters: Box::new([Parameter {
+                    value_type: ParameterType::Int,
+                    value: String::from("3"),
+                }]),
+                actual: String::from("6"),
+                expected: String::from("9"),
+                actual_value: None,
+                expected_value: None,
+            }),
+            label: None,
+        },
+        TestCaseResult {
+            id: 1,
+            test_result: TestResult::Pass,
+            label: None,
+        },
+    ]));
+
+    let actual = mozart
+        .oneshot(request)
+        .await
+        .expect("failed to execute oneshot request");
+
+    let actual_status = actual.status();
+    let body_bytes = to_bytes(actual.into_body(), usize::MAX)
+        .await
+        .expect("failed to convert body to bytes");
+
+    let actual_body: SubmissionResult =
+        serde_json::from_slice(&body_bytes).expect("failed to deserialize response body");
+
+    assert_eq!(actual_status, expected_status);
+    assert_eq!(actual_body, expected_body);
+}
+
+#[tokio::test]
+async fn float_tolerance_relative() {
+    let mozart = app(AppState::default());
+    let solution = ["fun solution(x: Double): Double = x * 1e12 + 1"].join("\n");
+    let test_cases = Box::new([TestCase {
+        id: 0,
+        input_parameters: Box::new([Parameter {
+            value_type: ParameterType::Float,
+            value: String::from("3.0"),
+        }]),
+        output_parameters: Box::new([Parameter {
+            value_type: ParameterType::Float,
+            value: String::from("3000000000000.0"),
+        }]),
+        stdin: None,
+        expected_error: None,
+        label: None,
+        hidden: false,
+    }]);
+    let submission = Submission {
+        solution,
+        test_cases,
+        unordered_lists: false,
+        files: Box::new([]),
+        checker: None,
+        reference_solution: None,
+        only_ids: None,
+        compiler_flags: Box::new([]),
+        debug: false,
+        float_tolerance: Some(FloatTolerance {
+            absolute: 1e-9,
+            relative: 1e-9,
+            match_nan: false,
+        }),
+        toolchain_version: None,
+        metadata: None,
+    };
+    let body = serde_json::to_string(&submission).expect("failed to serialize submission");
+    let request = Builder::new()
+        .header("Content-Type", "application/json")
+        .method(Method::POST)
+        .uri("/submit")
+        .body(Body::from(body))
+        .expect("failed to build request");
+    let expected_status = StatusCode::OK;
+    let expected_body = SubmissionResult::Pass;
+
+    let actual = mozart
+        .oneshot(request)
+        .await
+        .expect("failed to execute oneshot request");
+
+    let actual_status = actual.status();
+    let body_bytes = to_bytes(actual.into_body(), usize::MAX)
+        .await
+        .expect("failed to convert body to bytes");
+
+    let actual_body: SubmissionResult =
+        serde_json::from_slice(&body_bytes).expect("failed to deserialize response body");
+
+    assert_eq!(actual_status, expected_status);
+    assert_eq!(actual_body, expected_body);
+}
+
+#[tokio::test]
+async fn float_tolerance_absolute() {
+    let mozart = app(AppState::default());
+    let solution = ["fun solution(x: Double): Double = x * 1e12 + 1"].join("\n");
+    let test_cases = Box::new([TestCase {
+        id: 0,
+        input_parameters: Box::new([Parameter {
+            value_type: ParameterType::Float,
+            value: String::from("3.0"),
+        }]),
+        output_parameters: Box::new([Parameter {
+            value_type: ParameterType::Float,
+            value: String::from("3000000000000.0"),
+        }]),
+        stdin: None,
+        expected_error: None,
+        label: None,
+        hidden: false,
+    }]);
+    let submission = Submission {
+        solution,
+        test_cases,
+        unordered_lists: false,
+        files: Box::new([]),
+        checker: None,
+        reference_solution: None,
+        only_ids: None,
+        compiler_flags: Box::new([]),
+        debug: false,
+        float_tolerance: Some(FloatTolerance {
+            absolute: 1e-9,
+            relative: 0.0,
+            match_nan: false,
+        }),
+        toolchain_version: None,
+        metadata: None,
+    };
+    let body = serde_json::to_string(&submission).expect("failed to serialize submission");
+    let request = Builder::new()
+        .header("Content-Type", "application/json")
+        .method(Method::POST)
+        .uri("/submit")
+        .body(Body::from(body))
+        .expect("failed to build request");
+    let expected_status = StatusCode::OK;
+    let expected_body = SubmissionResult::Failure(Box::new([TestCaseResult {
+        id: 0,
+        test_result: TestResult::Failure(TestCaseFailureReason::WrongAnswer {
+            input_parameters: Box::new([Parameter {
+                value_type: ParameterType::Float,
+                value: String::from("3.0"),
+            }]),
+            actual: String::from("3.000000000001E12"),
+            expected: String::from("3.0E12"),
+            actual_value: None,
+            expected_value: None,
+        }),
+        label: None,
+    }]));
+
+    let actual = mozart
+        .oneshot(request)
+        .await
+        .expect("failed to execute oneshot request");
+
+    let actual_status = actual.status();
+    let body_bytes = to_bytes(actual.into_body(), usize::MAX)
+        .await
+        .expect("failed to convert body to bytes");
+
+    let actual_body: SubmissionResult =
+        serde_json::from_slice(&body_bytes).expect("failed to deserialize response body");
+
+    assert_eq!(actual_status, expected_status);
+    assert_eq!(actual_body, expected_body);
+}
+
+#[tokio::test]
+async fn float_tolerance_multiple_output_parameters() {
+    let mozart = app(AppState::default());
+    let solution = ["fun solution(x: Double): List<Any> = listOf(x * 1e12 + 1, 3L)"].join("\n");
+    let test_cases = Box::new([TestCase {
+        id: 0,
+        input_parameters: Box::new([Parameter {
+            value_type: ParameterType::Float,
+            value: String::from("3.0"),
+        }]),
+        output_parameters: Box::new([
+            Parameter {
+                value_type: ParameterType::Float,
+                value: String::from("3000000000000.0"),
+            },
+            Parameter {
+                value_type: ParameterType::Int,
+                value: String::from("3"),
+            },
+        ]),
+        stdin: None,
+        expected_error: None,
+        label: None,
+        hidden: false,
+    }]);
+    let submission = Submission {
+        solution,
+        test_cases,
+        unordered_lists: false,
+        files: Box::new([]),
+        checker: None,
+        reference_solution: None,
+        only_ids: None,
+        compiler_flags: Box::new([]),
+        debug: false,
+        float_tolerance: Some(FloatTolerance {
+            absolute: 1e-9,
+            relative: 1e-9,
+            match_nan: false,
+        }),
+        toolchain_version: None,
+        metadata: None,
+    };
+    let body = serde_json::to_string(&submission).expect("failed to serialize submission");
+    let request = Builder::new()
+        .header("Content-Type", "application/json")
+        .method(Method::POST)
+        .uri("/submit")
+        .body(Body::from(body))
+        .expect("failed to build request");
+    let expected_status = StatusCode::OK;
+    let expected_body = SubmissionResult::Pass;
+
+    let actual = mozart
+        .oneshot(request)
+        .await
+        .expect("failed to execute oneshot request");
+
+    let actual_status = actual.status();
+    let body_bytes = to_bytes(actual.into_body(), usize::MAX)
+        .await
+        .expect("failed to convert body to bytes");
+
+    let actual_body: SubmissionResult =
+        serde_json::from_slice(&body_bytes).expect("failed to deserialize response body");
+
+    assert_eq!(actual_status, expected_status);
+    assert_eq!(actual_body, expected_body);
+}
+
+#[tokio::test]
+async fn unknown_toolchain_version() {
+    let mozart = app(AppState::new(Config {
+        toolchain_versions: Box::new([String::from("2.0.0")]),
+        ..Config::default()
+    }));
+    let solution = ["fun solution(x: Long): Long = x * 2"].join("\n");
+    let test_cases = Box::new([TestCase {
+        id: 0,
+        input_parameters: Box::new([Parameter {
+            value_type: ParameterType::Int,
+            value: String::from("2"),
+        }]),
+        output_parameters: Box::new([Parameter {
+            value_type: ParameterType::Int,
+            value: String::from("4"),
+        }]),
+        stdin: None,
+        expected_error: None,
+        label: None,
+        hidden: false,
+    }]);
+    let submission = Submission {
+        solution,
+        test_cases,
+        unordered_lists: false,
+        files: Box::new([]),
+        checker: None,
+        reference_solution: None,
+        only_ids: None,
+        compiler_flags: Box::new([]),
+        debug: false,
+        float_tolerance: None,
+        toolchain_version: Some(String::from("1.3.72")),
+        metadata: None,
+    };
+    let body = serde_json::to_string(&submission).expect("failed to serialize submission");
+    let request = Builder::new()
+        .header("Content-Type", "application/json")
+        .method(Method::POST)
+        .uri("/submit")
+        .body(Body::from(body))
+        .expect("failed to build request");
+    let expected_status = StatusCode::OK;
+
+    let actual = mozart
+        .oneshot(request)
+        .await
+        .expect("failed to execute oneshot request");
+
+    let actual_status = actual.status();
+    let body_bytes = to_bytes(actual.into_body(), usize::MAX)
+        .await
+        .expect("failed to convert body to bytes");
+
+    let actual_body: SubmissionResult =
+        serde_json::from_slice(&body_bytes).expect("failed to deserialize response body");
+
+    assert_eq!(actual_status, expected_status);
+
+    if let SubmissionResult::Error { code, message } = actual_body {
+        assert_eq!(code, ErrorCode::UnknownToolchainVersion);
+        assert!(message.starts_with("the toolchain version '1.3.72' is not available"));
+    } else {
+        panic!("response body was not of error variant");
+    }
+}
+
+#[tokio::test]
+async fn structured_wrong_answer_multiple_output_parameters() {
+    let mozart = app(AppState::default());
+    let solution = ["fun solution(x: Long): List<Any> = listOf(x + 1, x.toString())"].join("\n");
+    // the typed values of multiple output parameters are an array
+    let test_cases = Box::new([TestCase {
+        id: 0,
+        input_parameters: Box::new([Parameter {
+            value_type: ParameterType::Int,
+            value: String::from("2"),
+        }]),
+        output_parameters: Box::new([
+            Parameter {
+                value_type: ParameterType::Int,
+                value: String::from("2"),
+            },
+            Parameter {
+                value_type: ParameterType::String,
+                value: String::from("2"),
+            },
+        ]),
+        stdin: None,
+        expected_error: None,
+        label: None,
+        hidden: false,
+    }]);
+    let submission = Submission {
+        solution,
+        test_cases,
+        unordered_lists: false,
+        files: Box::new([]),
+        checker: None,
+        reference_solution: None,
+        only_ids: None,
+        compiler_flags: Box::new([]),
+        debug: false,
+        float_tolerance: None,
+        toolchain_version: None,
+        metadata: None,
+    };
+    let body = serde_json::to_string(&submission).expect("failed to serialize submission");
+    let request = Builder::new()
+        .header("Content-Type", "application/json")
+        .method(Method::POST)
+        .uri("/submit")
+        .body(Body::from(body))
+        .expect("failed to build request");
+    let expected_status = StatusCode::OK;
+    let expected_body = SubmissionResult::Failure(Box::new([TestCaseResult {
+        id: 0,
+        test_result: TestResult::Failure(TestCaseFailureReason::WrongAnswer {
+            input_parameters: Box::new([Parameter {
+                value_type: ParameterType::Int,
+                value: String::from("2"),
+            }]),
+            actual: String::from(r#"[3, "2"]"#),
+            expected: String::from(r#"[2, "2"]"#),
+            actual_value: Some(json!([3, "2"])),
+            expected_value: Some(json!([2, "2"])),
+        }),
+        label: None,
+    }]));
+
+    let actual = mozart
+        .oneshot(request)
+        .await
+        .expect("failed to execute oneshot request");
+
+    let actual_status = actual.status();
+    let body_bytes = to_bytes(actual.into_body(), usize::MAX)
+        .await
+        .expect("failed to convert body to bytes");
+
+    let actual_body: SubmissionResult =
+        serde_json::from_slice(&body_bytes).expect("failed to deserialize response body");
+
+    assert_eq!(actual_status, expected_status);
+    assert_eq!(actual_body, expected_body);
+}
+
+#[tokio::test]
+async fn structured_wrong_answer_map() {
+    let mozart = app(AppState::default());
+    let solution =
+        ["fun solution(n: Long): Map<Long, Boolean> = mapOf(n to (n % 2 == 0L))"].join("\n");
+    // the keys of the typed value of a map are the text of the keys
+    let test_cases = Box::new([TestCase {
+        id: 0,
+        input_parameters: Box::new([Parameter {
+            value_type: ParameterType::Int,
+            value: String::from("3"),
+        }]),
+        output_parameters: Box::new([Parameter {
+            value_type: ParameterType::Map {
+                key: Box::new(ParameterType::Int),
+                value: Box::new(ParameterType::Bool),
+            },
+            value: String::from(r#"{"3": "true"}"#),
+        }]),
+        stdin: None,
+        expected_error: None,
+        label: None,
+        hidden: false,
+    }]);
+    let submission = Submission {
+        solution,
+        test_cases,
+        unordered_lists: false,
+        files: Box::new([]),
+        checker: None,
+        reference_solution: None,
+        only_ids: None,
+        compiler_flags: Box::new([]),
+        debug: false,
+        float_tolerance: None,
+        toolchain_version: None,
+        metadata: None,
+    };
+    let body = serde_json::to_string(&submission).expect("failed to serialize submission");
+    let request = Builder::new()
+        .header("Content-Type", "application/json")
+        .method(Method::POST)
+        .uri("/submit")
+        .body(Body::from(body))
+        .expect("failed to build request");
+    let expected_status = StatusCode::OK;
+    let expected_body = SubmissionResult::Failure(Box::new([TestCaseResult {
+        id: 0,
+        test_result: TestResult::Failure(TestCaseFailureReason::WrongAnswer {
+            input_parameters: Box::new([Parameter {
+                value_type: ParameterType::Int,
+                value: String::from("3"),
+            }]),
+            actual: String::from("{3=false}"),
+            expected: String::from("{3=true}"),
+            actual_value: Some(json!({ "3": false })),
+            expected_value: Some(json!({ "3": true })),
+        }),
+        label: None,
+    }]));
+
+    let actual = mozart
+        .oneshot(request)
+        .await
+        .expect("failed to execute oneshot request");
+
+    let actual_status = actual.status();
+    let body_bytes = to_bytes(actual.into_body(), usize::MAX)
+        .await
+        .expect("failed to convert body to bytes");
+
+    let actual_body: SubmissionResult =
+        serde_json::from_slice(&body_bytes).expect("failed to deserialize response body");
+
+    assert_eq!(actual_status, expected_status);
+    assert_eq!(actual_body, expected_body);
+}
+
+#[tokio::test]
+async fn solution_too_long() {
+    let mozart = app(AppState::new(Config {
+        max_solution_length: 10,
+        ..Config::default()
+    }));
+    let solution = ["fun solution(x: Long): Long = x * 2"].join("\n");
+    let test_cases = Box::new([TestCase {
+        id: 0,
+        input_parameters: Box::new([Parameter {
+            value_type: ParameterType::Int,
+            value: String::from("2"),
+        }]),
+        output_parameters: Box::new([Parameter {
+            value_type: ParameterType::Int,
+            value: String::from("4"),
+        }]),
+        stdin: None,
+        expected_error: None,
+        label: None,
+        hidden: false,
+    }]);
+    let submission = Submission {
+        solution,
+        test_cases,
+        unordered_lists: false,
+        files: Box::new([]),
+        checker: None,
+        reference_solution: None,
+        only_ids: None,
+        compiler_flags: Box::new([]),
+        debug: false,
+        float_tolerance: None,
+        toolchain_version: None,
+        metadata: None,
+    };
+    let body = serde_json::to_string(&submission).expect("failed to serialize submission");
+    let request = Builder::new()
+        .header("Content-Type", "application/json")
+        .method(Method::POST)
+        .uri("/submit")
+        .body(Body::from(body))
+        .expect("failed to build request");
+    let expected_status = StatusCode::OK;
+    let expected_body = SubmissionResult::Error {
+        code: ErrorCode::SolutionTooLong,
+        message: String::from("the solution exceeded the limit of 10 bytes"),
+    };
+
+    let actual = mozart
+        .oneshot(request)
+        .await
+        .expect("failed to execute oneshot request");
+
+    let actual_status = actual.status();
+    let body_bytes = to_bytes(actual.into_body(), usize::MAX)
+        .await
+        .expect("failed to convert body to bytes");
+
+    let actual_body: SubmissionResult =
+        serde_json::from_slice(&body_bytes).expect("failed to deserialize response body");
+
+    assert_eq!(actual_status, expected_status);
+    assert_eq!(actual_body, expected_body);
+}
+
+#[tokio::test]
+async fn quoted_char_and_string() {
+    let mozart = app(AppState::new(Config {
+        quote_values: true,
+        ..Config::default()
+    }));
+    let solution = ["fun solution(c: Char, s: String): String = \"$c$s\""].join("\n");
+    // the input parameters are quoted like the actual and expected values
+    let test_cases = Box::new([TestCase {
+        id: 0,
+        input_parameters: Box::new([
+            Parameter {
+                value_type: ParameterType::Char,
+                value: String::from("b"),
+            },
+            Parameter {
+                value_type: ParameterType::String,
+                value: String::from("say hi"),
+            },
+        ]),
+        output_parameters: Box::new([Parameter {
+            value_type: ParameterType::String,
+            value: String::from("x"),
+        }]),
+        stdin: None,
+        expected_error: None,
+        label: None,
+        hidden: false,
+    }]);
+    let submission = Submission {
+        solution,
+        test_cases,
+        unordered_lists: false,
+        files: Box::new([]),
+        checker: None,
+        reference_solution: None,
+        only_ids: None,
+        compiler_flags: Box::new([]),
+        debug: false,
+        float_tolerance: None,
+        toolchain_version: None,
+        metadata: None,
+    };
+    let body = serde_json::to_string(&submission).expect("failed to serialize submission");
+    let request = Builder::new()
+        .header("Content-Type", "application/json")
+        .method(Method::POST)
+        .uri("/submit")
+        .body(Body::from(body))
+        .expect("failed to build request");
+    let expected_status = StatusCode::OK;
+    let expected_body = SubmissionResult::Failure(Box::new([TestCaseResult {
+        id: 0,
+        test_result: TestResult::Failure(TestCaseFailureReason::WrongAnswer {
+            input_parameters: Box::new([
+                Parameter {
+                    value_type: ParameterType::Char,
+                    value: String::from("'b'"),
+                },
+                Parameter {
+                    value_type: ParameterType::String,
+                    value: String::from(r#""say hi""#),
+                },
+            ]),
+            actual: String::from(r#""bsay hi""#),
+            expected: String::from(r#""x""#),
+            actual_value: None,
+            expected_value: None,
+        }),
+        label: None,
+    }]));
+
+    let actual = mozart
+        .oneshot(request)
+        .await
+        .expect("failed to execute oneshot request");
+
+    let actual_status = actual.status();
+    let body_bytes = to_bytes(actual.into_body(), usize::MAX)
+        .await
+        .expect("failed to convert body to bytes");
+
+    let actual_body: SubmissionResult =
+        serde_json::from_slice(&body_bytes).expect("failed to deserialize response body");
+
+    assert_eq!(actual_status, expected_status);
+    assert_eq!(actual_body, expected_body);
+}
+
+#[tokio::test]
+async fn chatty_solution() {
+    let mozart = app(AppState::default());
+    let solution = [
+        "fun solution(x: Long): Long {",
+        "    System.err.print(\"x\".repeat(500000))",
+        "    return x",
+        "}",
+    ]
+    .join("\n");
+    // the solution writes far more than the buffer of a pipe, which must not block it until the timeout
+    let test_cases = Box::new([TestCase {
+        id: 0,
+        input_parameters: Box::new([Parameter {
+            value_type: ParameterType::Int,
+            value: String::from("2"),
+        }]),
+        output_parameters: Box::new([Parameter {
+            value_type: ParameterType::Int,
+            value: String::from("2"),
+        }]),
+        stdin: None,
+        expected_error: None,
+        label: None,
+        hidden: false,
+    }]);
+    let submission = Submission {
+        solution,
+        test_cases,
+        unordered_lists: false,
+        files: Box::new([]),
+        checker: None,
+        reference_solution: None,
+        only_ids: None,
+        compiler_flags: Box::new([]),
+        debug: false,
+        float_tolerance: None,
+        toolchain_version: None,
+        metadata: None,
+    };
+    let body = serde_json::to_string(&submission).expect("failed to serialize submission");
+    let request = Builder::new()
+        .header("Content-Type", "application/json")
+        .method(Method::POST)
+        .uri("/submit")
+        .body(Body::from(body))
+        .expect("failed to build request");
+    let expected_status = StatusCode::OK;
+    let expected_body = SubmissionResult::Pass;
+
+    let actual = mozart
+        .oneshot(request)
+        .await
+        .expect("failed to execute oneshot request");
+
+    let actual_status = actual.status();
+    let body_bytes = to_bytes(actual.into_body(), usize::MAX)
+        .await
+        .expect("failed to convert body to bytes");
+
+    let actual_body: SubmissionResult =
+        serde_json::from_slice(&body_bytes).expect("failed to deserialize response body");
+
+    assert_eq!(actual_status, expected_status);
+    assert_eq!(actual_body, expected_body);
+}
+
+#[tokio::test]
+async fn expected_error() {
+    let mozart = app(AppState::default());
+    let solution = [
+        "fun solution(x: Long): Long {",
+        "    if (x < 0) {",
+        "        throw IllegalArgumentException(\"negative input\")",
+        "    }",
+        "    return x + x",
+        "}",
+    ]
+    .join("\n");
+    // the solution should raise an error for a negative input
+    let test_cases = Box::new([
+        TestCase {
+            id: 0,
+            input_parameters: Box::new([Parameter {
+                value_type: ParameterType::Int,
+                value: String::from("2"),
+            }]),
+            output_parameters: Box::new([Parameter {
+                value_type: ParameterType::Int,
+                value: String::from("4"),
+            }]),
+            stdin: None,
+            expected_error: None,
+            label: None,
+            hidden: false,
+        },
+        TestCase {
+            id: 1,
+            input_parameters: Box::new([Parameter {
+                value_type: ParameterType::Int,
+                value: String::from("-1"),
+            }]),
+            output_parameters: Box::new([]),
+            stdin: None,
+            expected_error: Some(ExpectedError {
+                message: Some(String::from("negative input")),
+            }),
+            label: None,
+            hidden: false,
+        },
+        TestCase {
+            id: 2,
+            input_parameters: Box::new([Parameter {
+                value_type: ParameterType::Int,
+                value: String::from("-3"),
+            }]),
+            output_parameters: Box::new([]),
+            stdin: None,
+            expected_error: Some(ExpectedError { message: None }),
+            label: None,
+            hidden: false,
+        },
+    ]);
+    let submission = Submission {
+        solution,
+        test_cases,
+        unordered_lists: false,
+        files: Box::new([]),
+        checker: None,
+        reference_solution: None,
+        only_ids: None,
+        compiler_flags: Box::new([]),
+        debug: false,
+        float_tolerance: None,
+        toolchain_version: None,
+        metadata: None,
+    };
+    let body = serde_json::to_string(&submission).expect("failed to serialize submission");
+    let request = Builder::new()
+        .header("Content-Type", "application/json")
+        .method(Method::POST)
+        .uri("/submit")
+        .body(Body::from(body))
+        .expect("failed to build request");
+    let expected_status = StatusCode::OK;
+    let expected_body = SubmissionResult::Pass;
+
+    let actual = mozart
+        .oneshot(request)
+        .await
+        .expect("failed to execute oneshot request");
+
+    let actual_status = actual.status();
+    let body_bytes = to_bytes(actual.into_body(), usize::MAX)
+        .await
+        .expect("failed to convert body to bytes");
+
+    let actual_body: SubmissionResult =
+        serde_json::from_slice(&body_bytes).expect("failed to deserialize response body");
+
+    assert_eq!(actual_status, expected_status);
+    assert_eq!(actual_body, expected_body);
+}
+
+#[tokio::test]
+async fn missing_error() {
+    let mozart = app(AppState::default());
+    let solution = [
+        "fun solution(x: Long): Long {",
+        "    if (x < 0) {",
+        "        throw IllegalArgumentException(\"negative input\")",
+        "    }",
+        "    return x + x",
+        "}",
+    ]
+    .join("\n");
+    let test_cases = Box::new([TestCase {
+        id: 0,
+        input_parameters: Box::new([Parameter {
+            value_type: ParameterType::Int,
+            value: String::from("2"),
+        }]),
+        output_parameters: Box::new([]),
+        stdin: None,
+        expected_error: Some(ExpectedError { message: None }),
+        label: None,
+        hidden: false,
+    }]);
+    let submission = Submission {
+        solution,
+        test_cases,
+        unordered_lists: false,
+        files: Box::new([]),
+        checker: None,
+        reference_solution: None,
+        only_ids: None,
+        compiler_flags: Box::new([]),
+        debug: false,
+        float_tolerance: None,
+        toolchain_version: None,
+        metadata: None,
+    };
+    let body = serde_json::to_string(&submission).expect("failed to serialize submission");
+    let request = Builder::new()
+        .header("Content-Type", "application/json")
+        .method(Method::POST)
+        .uri("/submit")
+        .body(Body::from(body))
+        .expect("failed to build request");
+    let expected_status = StatusCode::OK;
+    let expected_body = SubmissionResult::Failure(Box::new([TestCaseResult {
+        id: 0,
+        test_result: TestResult::Failure(TestCaseFailureReason::MissingError {
+            input_parameters: Box::new([Parameter {
+                value_type: ParameterType::Int,
+                value: String::from("2"),
+            }]),
+            actual: String::from("4"),
+        }),
+        label: None,
+    }]));
+
+    let actual = mozart
+        .oneshot(request)
+        .await
+        .expect("failed to execute oneshot request");
+
+    let actual_status = actual.status();
+    let body_bytes = to_bytes(actual.into_body(), usize::MAX)
+        .await
+        .expect("failed to convert body to bytes");
+
+    let actual_body: SubmissionResult =
+        serde_json::from_slice(&body_bytes).expect("failed to deserialize response body");
+
+    assert_eq!(actual_status, expected_status);
+    assert_eq!(actual_body, expected_body);
+}
+
+#[tokio::test]
+async fn expected_nan() {
+    let mozart = app(AppState::default());
+    let solution = ["fun solution(x: Double): Double = x * 0"].join("\n");
+    // an infinity multiplied by zero is NaN
+    let test_cases = Box::new([TestCase {
+        id: 0,
+        input_parameters: Box::new([Parameter {
+            value_type: ParameterType::Float,
+            value: String::from("Infinity"),
+        }]),
+        output_parameters: Box::new([Parameter {
+            value_type: ParameterType::Float,
+            value: String::from("NaN"),
+        }]),
+        stdin: None,
+        expected_error: None,
+        label: None,
+        hidden: false,
+    }]);
+    let submission = Submission {
+        solution,
+        test_cases,
+        unordered_lists: false,
+        files: Box::new([]),
+        checker: None,
+        reference_solution: None,
+        only_ids: None,
+        compiler_flags: Box::new([]),
+        debug: false,
+        float_tolerance: Some(FloatTolerance {
+            absolute: 0.0,
+            relative: 0.0,
+            match_nan: true,
+        }),
+        toolchain_version: None,
+        metadata: None,
+    };
+    let body = serde_json::to_string(&submission).expect("failed to serialize submission");
+    let request = Builder::new()
+        .header("Content-Type", "application/json")
+        .method(Method::POST)
+        .uri("/submit")
+        .body(Body::from(body))
+        .expect("failed to build request");
+    let expected_status = StatusCode::OK;
+    let expected_body = SubmissionResult::Pass;
+
+    let actual = mozart
+        .oneshot(request)
+        .await
+        .expect("failed to execute oneshot request");
+
+    let actual_status = actual.status();
+    let body_bytes = to_bytes(actual.into_body(), usize::MAX)
+        .await
+        .expect("failed to convert body to bytes");
+
+    let actual_body: SubmissionResult =
+        serde_json::from_slice(&body_bytes).expect("failed to deserialize response body");
+
+    assert_eq!(actual_status, expected_status);
+    assert_eq!(actual_body, expected_body);
+}
+
+#[tokio::test]
+async fn expected_infinity() {
+    let mozart = app(AppState::default());
+    let solution = ["fun solution(x: Double): Double = x * 2"].join("\n");
+    let test_cases = Box::new([
+        TestCase {
+            id: 0,
+            input_parameters: Box::new([Parameter {
+                value_type: ParameterType::Float,
+                value: String::from("Infinity"),
+            }]),
+            output_parameters: Box::new([Parameter {
+                value_type: ParameterType::Float,
+                value: String::from("Infinity"),
+            }]),
+            stdin: None,
+            expected_error: None,
+            label: None,
+            hidden: false,
+        },
+        TestCase {
+            id: 1,
+            input_parameters: Box::new([Parameter {
+                value_type: ParameterType::Float,
+                value: String::from("-Infinity"),
+            }]),
+            output_parameters: Box::new([Parameter {
+                value_type: ParameterType::Float,
+                value: String::from("-Infinity"),
+            }]),
+            stdin: None,
+            expected_error: None,
+            label: None,
+            hidden: false,
+        },
+        TestCase {
+            id: 2,
+            input_parameters: Box::new([Parameter {
+                value_type: ParameterType::Float,
+                value: String::from("1.5"),
+            }]),
+            output_parameters: Box::new([Parameter {
+                value_type: ParameterType::Float,
+                value: String::from("3.0"),
+            }]),
+            stdin: None,
+            expected_error: None,
+            label: None,
+            hidden: false,
+        },
+    ]);
+    let submission = Submission {
+        solution,
+        test_cases,
+        unordered_lists: false,
+        files: Box::new([]),
+        checker: None,
+        reference_solution: None,
+        only_ids: None,
+        compiler_flags: Box::new([]),
+        debug: false,
+        float_tolerance: Some(FloatTolerance {
+            absolute: 0.0,
+            relative: 1e-9,
+            match_nan: false,
+        }),
+        toolchain_version: None,
+        metadata: None,
+    };
+    let body = serde_json::to_string(&submission).expect("failed to serialize submission");
+    let request = Builder::new()
+        .header("Content-Type", "application/json")
+        .method(Method::POST)
+        .uri("/submit")
+        .body(Body::from(body))
+        .expect("failed to build request");
+    let expected_status = StatusCode::OK;
+    let expected_body = SubmissionResult::Pass;
+
+    let actual = mozart
+        .oneshot(request)
+        .await
+        .expect("failed to execute oneshot request");
+
+    let actual_status = actual.status();
+    let body_bytes = to_bytes(actual.into_body(), usize::MAX)
+        .await
+        .expect("failed to convert body to bytes");
+
+    let actual_body: SubmissionResult =
+        serde_json::from_slice(&body_bytes).expect("failed to deserialize response body");
+
+    assert_eq!(actual_status, expected_status);
+    assert_eq!(actual_body, expected_body);
+}
+
+#[tokio::test]
+async fn decimal_is_not_supported() {
+    let mozart = app(AppState::default());
+    let solution = ["fun solution(x: Long): Long = x"].join("\n");
+    // a BigDecimal is not compared exactly, as its equality depends on its scale
+    let test_cases = Box::new([TestCase {
+        id: 0,
+        input_parameters: Box::new([Parameter {
+            value_type: ParameterType::Decimal,
+            value: String::from("10.25"),
+        }]),
+        output_parameters: Box::new([Parameter {
+            value_type: ParameterType::Decimal,
+            value: String::from("10.25"),
+        }]),
+        stdin: None,
+        expected_error: None,
+        label: None,
+        hidden: false,
+    }]);
+    let submission = Submission {
+        solution,
+        test_cases,
+        unordered_lists: false,
+        files: Box::new([]),
+        checker: None,
+        reference_solution: None,
+        only_ids: None,
+        compiler_flags: Box::new([]),
+        debug: false,
+        float_tolerance: None,
+        toolchain_version: None,
+        metadata: None,
+    };
+    let body = serde_json::to_string(&submission).expect("failed to serialize submission");
+    let request = Builder::new()
+        .header("Content-Type", "application/json")
+        .method(Method::POST)
+        .uri("/submit")
+        .body(Body::from(body))
+        .expect("failed to build request");
+    let expected_status = StatusCode::OK;
+
+    let actual = mozart
+        .oneshot(request)
+        .await
+        .expect("failed to execute oneshot request");
+
+    let actual_status = actual.status();
+    let body_bytes = to_bytes(actual.into_body(), usize::MAX)
+        .await
+        .expect("failed to convert body to bytes");
+
+    let actual_body: SubmissionResult =
+        serde_json::from_slice(&body_bytes).expect("failed to deserialize response body");
+
+    assert_eq!(actual_status, expected_status);
+
+    if let SubmissionResult::Error { code, message } = actual_body {
+        assert_eq!(code, ErrorCode::InvalidParameter);
+        assert!(message.starts_with(
+            "invalid parameter: test case 0, input parameter 0: the Decimal type is not supported"
+        ));
+    } else {
+        panic!("response body was not of error variant");
+    }
+}
+
+#[tokio::test]
+async fn labels_are_echoed() {
+    let mozart = app(AppState::default());
+    let solution = ["fun solution(x: Long): Long = x + x"].join("\n");
+    let test_cases = Box::new([
+        TestCase {
+            id: 0,
+            input_parameters: Box::new([Parameter {
+                value_type: ParameterType::Int,
+                value: String::from("0"),
+            }]),
+            output_parameters: Box::new([Parameter {
+                value_type: ParameterType::Int,
+                value: String::from("0"),
+            }]),
+            stdin: None,
+            expected_error: None,
+            label: Some(String::from("edge case")),
+            hidden: false,
+        },
+        TestCase {
+            id: 1,
+            input_parameters: Box::new([Parameter {
+                value_type: ParameterType::Int,
+                value: String::from("5"),
+            }]),
+            output_parameters: Box::new([Parameter {
+                value_type: ParameterType::Int,
+                value: String::from("11"),
+            }]),
+            stdin: None,
+            expected_error: None,
+            label: Some(String::from("hidden")),
+            hidden: false,
+        },
+        TestCase {
+            id: 2,
+            input_parameters: Box::new([Parameter {
+                value_type: ParameterType::Int,
+                value: String::from("2"),
+            }]),
+            output_parameters: Box::new([Parameter {
+                value_type: ParameterType::Int,
+                value: String::from("4"),
+            }]),
+            stdin: None,
+            expected_error: None,
+            label: None,
+            hidden: false,
+        },
+    ]);
+    let submission = Submission {
+        solution,
+        test_cases,
+        unordered_lists: false,
+        files: Box::new([]),
+        checker: None,
+        reference_solution: None,
+        only_ids: None,
+        compiler_flags: Box::new([]),
+        debug: false,
+        float_tolerance: None,
+        toolchain_version: None,
+        metadata: None,
+    };
+    let body = serde_json::to_string(&submission).expect("failed to serialize submission");
+    let request = Builder::new()
+        .header("Content-Type", "application/json")
+        .method(Method::POST)
+        .uri("/submit")
+        .body(Body::from(body))
+        .expect("failed to build request");
+    let expected_status = StatusCode::OK;
+    let expected_body = SubmissionResult::Failure(Box::new([
+        TestCaseResult {
+            id: 0,
+            test_result: TestResult::Pass,
+            label: Some(String::from("edge case")),
+        },
+        TestCaseResult {
+            id: 1,
+            test_result: TestResult::Failure(TestCaseFailureReason::WrongAnswer {
+                input_parameters: Box::new([Parameter {
+                    value_type: ParameterType::Int,
+                    value: String::from("5"),
+                }]),
+                actual: String::from("10"),
+                expected: String::from("11"),
+                actual_value: None,
+                expected_value: None,
+            }),
+            label: Some(String::from("hidden")),
+        },
+        TestCaseResult {
+            id: 2,
+            test_result: TestResult::Pass,
+            label: None,
+        },
+    ]));
+
+    let actual = mozart
+        .oneshot(request)
+        .await
+        .expect("failed to execute oneshot request");
+
+    let actual_status = actual.status();
+    let body_bytes = to_bytes(actual.into_body(), usize::MAX)
+        .await
+        .expect("failed to convert body to bytes");
+
+    let actual_body: SubmissionResult =
+        serde_json::from_slice(&body_bytes).expect("failed to deserialize response body");
+
+    assert_eq!(actual_status, expected_status);
+    assert_eq!(actual_body, expected_body);
+}
+
+#[tokio::test]
+async fn stdout_is_returned() {
+    let mozart = app(AppState::default());
+    let solution = [
+        "fun solution(x: Long): Long {",
+        "    println(\"debug $x\")",
+        "    return x + x",
+        "}",
+    ]
+    .join("\n");
+    let test_cases = Box::new([
+        TestCase {
+            id: 0,
+            input_parameters: Box::new([Parameter {
+                value_type: ParameterType::Int,
+                value: String::from("2"),
+            }]),
+            output_parameters: Box::new([Parameter {
+                value_type: ParameterType::Int,
+                value: String::from("4"),
+            }]),
+            stdin: None,
+            expected_error: None,
+            label: None,
+            hidden: false,
+        },
+        TestCase {
+            id: 1,
+            input_parameters: Box::new([Parameter {
+                value_type: ParameterType::Int,
+                value: String::from("5"),
+            }]),
+            output_parameters: Box::new([Parameter {
+                value_type: ParameterType::Int,
+                value: String::from("10"),
+            }]),
+            stdin: None,
+            expected_error: None,
+            label: None,
+            hidden: false,
+        },
+    ]);
+    let submission = Submission {
+        solution,
+        test_cases,
+        unordered_lists: false,
+        files: Box::new([]),
+        checker: None,
+        reference_solution: None,
+        only_ids: None,
+        compiler_flags: Box::new([]),
+        debug: false,
+        float_tolerance: None,
+        toolchain_version: None,
+        metadata: None,
+    };
+    let body = serde_json::to_string(&submission).expect("failed to serialize submission");
+    let request = Builder::new()
+        .header("Content-Type", "application/json")
+        .method(Method::POST)
+        .uri("/submit")
+        .body(Body::from(body))
+        .expect("failed to build request");
+    let expected_status = StatusCode::OK;
+    let expected_result = SubmissionResult::Pass;
+    let expected_stdout = "debug 2\ndebug 5\n";
+
+    let actual = mozart
+        .oneshot(request)
+        .await
+        .expect("failed to execute oneshot request");
+
+    let actual_status = actual.status();
+    let body_bytes = to_bytes(actual.into_body(), usize::MAX)
+        .await
+        .expect("failed to convert body to bytes");
+
+    let actual_body: Value =
+        serde_json::from_slice(&body_bytes).expect("failed to deserialize response body");
+    let actual_result: SubmissionResult =
+        serde_json::from_slice(&body_bytes).expect("failed to deserialize result");
+
+    assert_eq!(actual_status, expected_status);
+    assert_eq!(actual_result, expected_result);
+    assert_eq!(actual_body["stdout"], expected_stdout);
+}
+
+#[tokio::test]
+async fn optional_is_not_supported() {
+    let mozart = app(AppState::default());
+    let solution = ["fun solution(x: Long): Long = x"].join("\n");
+    // the Kotlin test runner cannot display or compare an optional value
+    let test_cases = Box::new([TestCase {
+        id: 0,
+        input_parameters: Box::new([Parameter {
+            value_type: ParameterType::Optional(Box::new(ParameterType::Int)),
+            value: String::from("null"),
+        }]),
+        output_parameters: Box::new([Parameter {
+            value_type: ParameterType::Optional(Box::new(ParameterType::Int)),
+            value: String::from("null"),
+        }]),
+        stdin: None,
+        expected_error: None,
+        label: None,
+        hidden: false,
+    }]);
+    let submission = Submission {
+        solution,
+        test_cases,
+        unordered_lists: false,
+        files: Box::new([]),
+        checker: None,
+        reference_solution: None,
+        only_ids: None,
+        compiler_flags: Box::new([]),
+        debug: false,
+        float_tolerance: None,
+        toolchain_version: None,
+        metadata: None,
+    };
+    let body = serde_json::to_string(&submission).expect("failed to serialize submission");
+    let request = Builder::new()
+        .header("Content-Type", "application/json")
+        .method(Method::POST)
+        .uri("/submit")
+        .body(Body::from(body))
+        .expect("failed to build request");
+    let expected_status = StatusCode::OK;
+
+    let actual = mozart
+        .oneshot(request)
+        .await
+        .expect("failed to execute oneshot request");
+
+    let actual_status = actual.status();
+    let body_bytes = to_bytes(actual.into_body(), usize::MAX)
+        .await
+        .expect("failed to convert body to bytes");
+
+    let actual_body: SubmissionResult =
+        serde_json::from_slice(&body_bytes).expect("failed to deserialize response body");
+
+    assert_eq!(actual_status, expected_status);
+
+    if let SubmissionResult::Error { code, message } = actual_body {
+        assert_eq!(code, ErrorCode::InvalidParameter);
+        assert!(message.starts_with(
+            "invalid parameter: test case 0, input parameter 0: the Optional(Int) type is not supported"
+        ));
+    } else {
+        panic!("response body was not of error variant");
+    }
+}
+
+#[tokio::test]
+async fn int_overflowing_value() {
+    let mozart = app(AppState::default());
+    let solution = String::from("fun solution(x: Long): Long = x * 2");
+    // the value would be written as a literal that does not fit in 64 bits, which fails to compile
+    let test_cases = Box::new([TestCase {
+        id: 0,
+        input_parameters: Box::new([Parameter {
+            value_type: ParameterType::Int,
+            value: String::from("9223372036854775808"),
+        }]),
+        output_parameters: Box::new([Parameter {
+            value_type: ParameterType::Int,
+            value: String::from("0"),
+        }]),
+        stdin: None,
+        expected_error: None,
+        label: None,
+        hidden: false,
+    }]);
+    let submission = Submission {
+        solution,
+        test_cases,
+        unordered_lists: false,
+        files: Box::new([]),
+        checker: None,
+        reference_solution: None,
+        only_ids: None,
+        compiler_flags: Box::new([]),
+        debug: false,
+        float_tolerance: None,
+        toolchain_version: None,
+        metadata: None,
+    };
+    let body = serde_json::to_string(&submission).expect("failed to serialize submission");
+    let request = Builder::new()
+        .header("Content-Type", "application/json")
+        .method(Method::POST)
+        .uri("/submit")
+        .body(Body::from(body))
+        .expect("failed to build request");
+    let expected_status = StatusCode::OK;
+
+    let actual = mozart
+        .oneshot(request)
+        .await
+        .expect("failed to execute oneshot request");
+
+    let actual_status = actual.status();
+    let body_bytes = to_bytes(actual.into_body(), usize::MAX)
+        .await
+        .expect("failed to convert body to bytes");
+
+    let actual_body: SubmissionResult =
+        serde_json::from_slice(&body_bytes).expect("failed to deserialize response body");
+
+    assert_eq!(actual_status, expected_status);
+
+    if let SubmissionResult::Error { code, message } = actual_body {
+        assert_eq!(code, ErrorCode::InvalidParameter);
+        assert!(message.starts_with(
+            "invalid parameter: test case 0, input parameter 0: '9223372036854775808' is not a valid Int"
+        ));
+    } else {
+        panic!("response body was not of error variant");
+    }
+}
+
+#[tokio::test]
+async fn char_supplementary_value() {
+    let mozart = app(AppState::default());
+    let solution = String::from("fun solution(c: Char): Char = c");
+    // a Kotlin Char is a single UTF-16 code unit, so the value would be a character literal that fails to compile
+    let test_cases = Box::new([TestCase {
+        id: 0,
+        input_parameters: Box::new([Parameter {
+            value_type: ParameterType::Char,
+            value: String::from("😀"),
+        }]),
+        output_parameters: Box::new([Parameter {
+            value_type: ParameterType::Char,
+            value: String::from("😀"),
+        }]),
+        stdin: None,
+        expected_error: None,
+        label: None,
+        hidden: false,
+    }]);
+    let submission = Submission {
+        solution,
+        test_cases,
+        unordered_lists: false,
+        files: Box::new([]),
+        checker: None,
+        reference_solution: None,
+        only_ids: None,
+        compiler_flags: Box::new([]),
+        debug: false,
+        float_tolerance: None,
+        toolchain_version: None,
+        metadata: None,
+    };
+    let body = serde_json::to_string(&submission).expect("failed to serialize submission");
+    let request = Builder::new()
+        .header("Content-Type", "application/json")
+        .method(Method::POST)
+        .uri("/submit")
+        .body(Body::from(body))
+        .expect("failed to build request");
+    let expected_status = StatusCode::OK;
+
+    let actual = mozart
+        .oneshot(request)
+        .await
+        .expect("failed to execute oneshot request");
+
+    let actual_status = actual.status();
+    let body_bytes = to_bytes(actual.into_body(), usize::MAX)
+        .await
+        .expect("failed to convert body to bytes");
+
+    let actual_body: SubmissionResult =
+        serde_json::from_slice(&body_bytes).expect("failed to deserialize response body");
+
+    assert_eq!(actual_status, expected_status);
+
+    if let SubmissionResult::Error { code, message } = actual_body {
+        assert_eq!(code, ErrorCode::InvalidParameter);
+        assert!(message.starts_with(
+            "invalid parameter: test case 0, input parameter 0: '😀' is not a supported Char"
+        ));
+    } else {
+        panic!("response body was not of error variant");
+    }
+}
@@ -1,5 +1,11 @@
+#[cfg(feature = "cpp")]
+mod cpp;
+
 #[cfg(feature = "haskell")]
 mod haskell;
 
+#[cfg(feature = "kotlin")]
+mod kotlin;
+
 #[cfg(feature = "python")]
 mod python;
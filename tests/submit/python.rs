@@ -3,18 +3,34 @@ use axum::{
     http::{request::Builder, Method, StatusCode},
 };
 use mozart::{
-    app,
+    app, check_submission,
+    config::Config,
     model::{
-        Parameter, ParameterType, Submission, TestCase, TestCaseFailureReason, TestCaseResult,
-        TestResult,
+        ExpectedError, FloatTolerance, Parameter, ParameterType, SourceFile, Submission, TestCase,
+        TestCaseFailureReason, TestCaseResult, TestResult,
     },
-    response::SubmissionResult,
+    response::{ErrorCode, SubmissionResult},
+    serve_until,
+    state::AppState,
+    TOTAL_TIME_HEADER,
+};
+use serde_json::{json, Value};
+use std::{
+    fs,
+    time::{Duration, Instant},
+};
+use tokio::{
+    io::{AsyncReadExt, AsyncWriteExt},
+    net::{TcpListener, TcpStream},
+    sync::oneshot,
+    time::sleep,
 };
 use tower::ServiceExt;
+use uuid::Uuid;
 
 #[tokio::test]
 async fn invalid_http_method() {
-    let mozart = app();
+    let mozart = app(AppState::default());
     let expected_status_code = StatusCode::METHOD_NOT_ALLOWED;
     let request = Builder::new()
         .method(Method::GET)
@@ -32,7 +48,7 @@ async fn invalid_http_method() {
 
 #[tokio::test]
 async fn no_json_header() {
-    let mozart = app();
+    let mozart = app(AppState::default());
     let expected_status_code = StatusCode::UNSUPPORTED_MEDIA_TYPE;
     let request = Builder::new()
         .method(Method::POST)
@@ -50,7 +66,7 @@ async fn no_json_header() {
 
 #[tokio::test]
 async fn empty_request_body() {
-    let mozart = app();
+    let mozart = app(AppState::default());
     let expected_status_code = StatusCode::BAD_REQUEST;
     let request = Builder::new()
         .method(Method::POST)
@@ -64,12 +80,27 @@ async fn empty_request_body() {
         .await
         .expect("failed to await oneshot");
 
-    assert_eq!(actual.status(), expected_status_code);
+    let actual_status = actual.status();
+    let body_bytes = to_bytes(actual.into_body(), usize::MAX)
+        .await
+        .expect("failed to convert body to bytes");
+
+    let actual_body: SubmissionResult =
+        serde_json::from_slice(&body_bytes).expect("failed to deserialize response body");
+
+    assert_eq!(actual_status, expected_status_code);
+
+    if let SubmissionResult::Error { code, message } = actual_body {
+        assert_eq!(code, ErrorCode::InvalidRequest);
+        assert!(message.starts_with("Failed to parse the request body as JSON"));
+    } else {
+        panic!("response body was not of error variant");
+    }
 }
 
 #[tokio::test]
 async fn invalid_json() {
-    let mozart = app();
+    let mozart = app(AppState::default());
     let expected_status_code = StatusCode::UNPROCESSABLE_ENTITY;
     let body = serde_json::to_string(&ParameterType::Int).expect("failed to serialize body");
     let request = Builder::new()
@@ -84,12 +115,27 @@ async fn invalid_json() {
         .await
         .expect("failed to await oneshot");
 
-    assert_eq!(actual.status(), expected_status_code);
+    let actual_status = actual.status();
+    let body_bytes = to_bytes(actual.into_body(), usize::MAX)
+        .await
+        .expect("failed to convert body to bytes");
+
+    let actual_body: SubmissionResult =
+        serde_json::from_slice(&body_bytes).expect("failed to deserialize response body");
+
+    assert_eq!(actual_status, expected_status_code);
+
+    if let SubmissionResult::Error { code, message } = actual_body {
+        assert_eq!(code, ErrorCode::InvalidRequest);
+        assert!(message.starts_with("Failed to deserialize the JSON body into the target type"));
+    } else {
+        panic!("response body was not of error variant");
+    }
 }
 
 #[tokio::test]
 async fn solution_with_all_data_types_as_input() {
-    let mozart = app();
+    let mozart = app(AppState::default());
     let solution = [
         "def solution(i: int, f: float, b: bool, c: str, s: str):",
         "    return str(i) + str(f) + str(b) + c + s",
@@ -123,10 +169,24 @@ async fn solution_with_all_data_types_as_input() {
             value_type: ParameterType::String,
             value: String::from("105.5Truefhello"),
         }]),
+        stdin: None,
+        expected_error: None,
+        label: None,
+        hidden: false,
     }]);
     let submission = Submission {
         solution,
         test_cases,
+        unordered_lists: false,
+        files: Box::new([]),
+        checker: None,
+        reference_solution: None,
+        only_ids: None,
+        compiler_flags: Box::new([]),
+        debug: false,
+        float_tolerance: None,
+        toolchain_version: None,
+        metadata: None,
     };
     let body = serde_json::to_string(&submission).expect("failed to serialize submission");
     let request = Builder::new()
@@ -157,7 +217,7 @@ async fn solution_with_all_data_types_as_input() {
 
 #[tokio::test]
 async fn solution_with_all_data_types_as_output_and_no_input() {
-    let mozart = app();
+    let mozart = app(AppState::default());
     let solution = ["def solution():", "    return 7, 8.6, True, \"a\", \"hhh\""].join("\n");
     let test_cases = Box::new([TestCase {
         id: 0,
@@ -184,10 +244,24 @@ async fn solution_with_all_data_types_as_output_and_no_input() {
                 value: String::from("hhh"),
             },
         ]),
+        stdin: None,
+        expected_error: None,
+        label: None,
+        hidden: false,
     }]);
     let submission = Submission {
         solution,
         test_cases,
+        unordered_lists: false,
+        files: Box::new([]),
+        checker: None,
+        reference_solution: None,
+        only_ids: None,
+        compiler_flags: Box::new([]),
+        debug: false,
+        float_tolerance: None,
+        toolchain_version: None,
+        metadata: None,
     };
     let body = serde_json::to_string(&submission).expect("failed to serialize submission");
     let request = Builder::new()
@@ -218,7 +292,7 @@ async fn solution_with_all_data_types_as_output_and_no_input() {
 
 #[tokio::test]
 async fn execution_timeout() {
-    let mozart = app();
+    let mozart = app(AppState::default());
     let solution = ["def solution(x: int):", "    while True:", "        x + x"].join("\n");
     // the contents of the test cases are entirely irrelevant
     let test_cases = Box::new([
@@ -232,6 +306,10 @@ async fn execution_timeout() {
                 value_type: ParameterType::Int,
                 value: String::from("10"),
             }]),
+            stdin: None,
+            expected_error: None,
+            label: None,
+            hidden: false,
         },
         TestCase {
             id: 1,
@@ -243,11 +321,25 @@ async fn execution_timeout() {
                 value_type: ParameterType::Int,
                 value: String::from("10"),
             }]),
+            stdin: None,
+            expected_error: None,
+            label: None,
+            hidden: false,
         },
     ]);
     let submission = Submission {
         solution,
         test_cases,
+        unordered_lists: false,
+        files: Box::new([]),
+        checker: None,
+        reference_solution: None,
+        only_ids: None,
+        compiler_flags: Box::new([]),
+        debug: false,
+        float_tolerance: None,
+        toolchain_version: None,
+        metadata: None,
     };
     let body = serde_json::to_string(&submission).expect("failed to serialize submission");
     let request = Builder::new()
@@ -257,6 +349,18 @@ async fn execution_timeout() {
         .body(Body::from(body))
         .expect("failed to build request");
     let expected_status = StatusCode::OK;
+    let expected_body = SubmissionResult::Failure(Box::new([
+        TestCaseResult {
+            id: 0,
+            test_result: TestResult::Failure(TestCaseFailureReason::Timeout),
+            label: None,
+        },
+        TestCaseResult {
+            id: 1,
+            test_result: TestResult::Failure(TestCaseFailureReason::Timeout),
+            label: None,
+        },
+    ]));
 
     let actual = mozart
         .oneshot(request)
@@ -272,17 +376,12 @@ async fn execution_timeout() {
         serde_json::from_slice(&body_bytes).expect("failed to deserialize response body");
 
     assert_eq!(actual_status, expected_status);
-
-    if let SubmissionResult::Error(err) = actual_body {
-        assert!(err.starts_with("execution exceeded the timeout limit of"));
-    } else {
-        panic!("response body was not of error variant");
-    }
+    assert_eq!(actual_body, expected_body);
 }
 
 #[tokio::test]
 async fn all_test_cases_pass_int() {
-    let mozart = app();
+    let mozart = app(AppState::default());
     let solution = ["def solution(x: int):", "    return x + x"].join("\n");
     let test_cases = Box::new([
         TestCase {
@@ -295,6 +394,10 @@ async fn all_test_cases_pass_int() {
                 value_type: ParameterType::Int,
                 value: String::from("20"),
             }]),
+            stdin: None,
+            expected_error: None,
+            label: None,
+            hidden: false,
         },
         TestCase {
             id: 1,
@@ -306,11 +409,25 @@ async fn all_test_cases_pass_int() {
                 value_type: ParameterType::Int,
                 value: String::from("10"),
             }]),
+            stdin: None,
+            expected_error: None,
+            label: None,
+            hidden: false,
         },
     ]);
     let submission = Submission {
         solution,
         test_cases,
+        unordered_lists: false,
+        files: Box::new([]),
+        checker: None,
+        reference_solution: None,
+        only_ids: None,
+        compiler_flags: Box::new([]),
+        debug: false,
+        float_tolerance: None,
+        toolchain_version: None,
+        metadata: None,
     };
     let body = serde_json::to_string(&submission).expect("failed to serialize submission");
     let request = Builder::new()
@@ -341,7 +458,7 @@ async fn all_test_cases_pass_int() {
 
 #[tokio::test]
 async fn all_test_cases_pass_bool() {
-    let mozart = app();
+    let mozart = app(AppState::default());
     let solution = ["def solution(b: bool):", "    return not b"].join("\n");
     let test_cases = Box::new([
         TestCase {
@@ -354,6 +471,10 @@ async fn all_test_cases_pass_bool() {
                 value_type: ParameterType::Bool,
                 value: String::from("false"),
             }]),
+            stdin: None,
+            expected_error: None,
+            label: None,
+            hidden: false,
         },
         TestCase {
             id: 1,
@@ -365,11 +486,25 @@ async fn all_test_cases_pass_bool() {
                 value_type: ParameterType::Bool,
                 value: String::from("true"),
             }]),
+            stdin: None,
+            expected_error: None,
+            label: None,
+            hidden: false,
         },
     ]);
     let submission = Submission {
         solution,
         test_cases,
+        unordered_lists: false,
+        files: Box::new([]),
+        checker: None,
+        reference_solution: None,
+        only_ids: None,
+        compiler_flags: Box::new([]),
+        debug: false,
+        float_tolerance: None,
+        toolchain_version: None,
+        metadata: None,
     };
     let body = serde_json::to_string(&submission).expect("failed to serialize submission");
     let request = Builder::new()
@@ -400,7 +535,7 @@ async fn all_test_cases_pass_bool() {
 
 #[tokio::test]
 async fn all_test_cases_pass_float() {
-    let mozart = app();
+    let mozart = app(AppState::default());
     let solution = ["def solution(f: float):", "    return f + f"].join("\n");
     let test_cases = Box::new([
         TestCase {
@@ -413,6 +548,10 @@ async fn all_test_cases_pass_float() {
                 value_type: ParameterType::Float,
                 value: String::from("5.0"),
             }]),
+            stdin: None,
+            expected_error: None,
+            label: None,
+            hidden: false,
         },
         TestCase {
             id: 1,
@@ -424,11 +563,25 @@ async fn all_test_cases_pass_float() {
                 value_type: ParameterType::Float,
                 value: String::from("6.6"),
             }]),
+            stdin: None,
+            expected_error: None,
+            label: None,
+            hidden: false,
         },
     ]);
     let submission = Submission {
         solution,
         test_cases,
+        unordered_lists: false,
+        files: Box::new([]),
+        checker: None,
+        reference_solution: None,
+        only_ids: None,
+        compiler_flags: Box::new([]),
+        debug: false,
+        float_tolerance: None,
+        toolchain_version: None,
+        metadata: None,
     };
     let body = serde_json::to_string(&submission).expect("failed to serialize submission");
     let request = Builder::new()
@@ -459,7 +612,7 @@ async fn all_test_cases_pass_float() {
 
 #[tokio::test]
 async fn all_test_cases_pass_char() {
-    let mozart = app();
+    let mozart = app(AppState::default());
     let solution = ["def solution(c: str):", "    return c"].join("\n");
     let test_cases = Box::new([
         TestCase {
@@ -472,6 +625,10 @@ async fn all_test_cases_pass_char() {
                 value_type: ParameterType::Char,
                 value: String::from("a"),
             }]),
+            stdin: None,
+            expected_error: None,
+            label: None,
+            hidden: false,
         },
         TestCase {
             id: 1,
@@ -483,11 +640,25 @@ async fn all_test_cases_pass_char() {
                 value_type: ParameterType::Char,
                 value: String::from("b"),
             }]),
+            stdin: None,
+            expected_error: None,
+            label: None,
+            hidden: false,
         },
     ]);
     let submission = Submission {
         solution,
         test_cases,
+        unordered_lists: false,
+        files: Box::new([]),
+        checker: None,
+        reference_solution: None,
+        only_ids: None,
+        compiler_flags: Box::new([]),
+        debug: false,
+        float_tolerance: None,
+        toolchain_version: None,
+        metadata: None,
     };
     let body = serde_json::to_string(&submission).expect("failed to serialize submission");
     let request = Builder::new()
@@ -518,7 +689,7 @@ async fn all_test_cases_pass_char() {
 
 #[tokio::test]
 async fn all_test_cases_pass_string() {
-    let mozart = app();
+    let mozart = app(AppState::default());
     let solution = ["def solution(s: str):", "    return s + s"].join("\n");
     let test_cases = Box::new([
         TestCase {
@@ -531,6 +702,10 @@ async fn all_test_cases_pass_string() {
                 value_type: ParameterType::String,
                 value: String::from("hellohello"),
             }]),
+            stdin: None,
+            expected_error: None,
+            label: None,
+            hidden: false,
         },
         TestCase {
             id: 1,
@@ -542,11 +717,91 @@ async fn all_test_cases_pass_string() {
                 value_type: ParameterType::String,
                 value: String::from("worldworld"),
             }]),
+            stdin: None,
+            expected_error: None,
+            label: None,
+            hidden: false,
         },
     ]);
     let submission = Submission {
         solution,
         test_cases,
+        unordered_lists: false,
+        files: Box::new([]),
+        checker: None,
+        reference_solution: None,
+        only_ids: None,
+        compiler_flags: Box::new([]),
+        debug: false,
+        float_tolerance: None,
+        toolchain_version: None,
+        metadata: None,
+    };
+    let body = serde_json::to_string(&submission).expect("failed to serialize submission");
+    let request = Builder::new()
+        .header("Content-Type", "application/json")
+        .method(Method::POST)
+        .uri("/submit")
+        .body(Body::from(body))
+        .expect("failed to build request");
+    let expected_status = StatusCode::OK;
+    let expected_body = SubmissionResult::Pass;
+
+    let actual = mozart
+        .oneshot(request)
+        .await
+        .expect("failed to execute oneshot request");
+
+    let actual_status = actual.status();
+    let body_bytes = to_bytes(actual.into_body(), usize::MAX)
+        .await
+        .expect("failed to convert body to bytes");
+
+    let actual_body: SubmissionResult =
+        serde_json::from_slice(&body_bytes).expect("failed to deserialize response body");
+
+    assert_eq!(actual_status, expected_status);
+    assert_eq!(actual_body, expected_body);
+}
+
+#[tokio::test]
+async fn escaped_string_and_char() {
+    let mozart = app(AppState::default());
+    let solution = ["def solution(s: str, c: str):", "    return s + c"].join("\n");
+    let test_cases = Box::new([TestCase {
+        id: 0,
+        input_parameters: Box::new([
+            Parameter {
+                value_type: ParameterType::String,
+                value: String::from("a\"b\\c\nd"),
+            },
+            Parameter {
+                value_type: ParameterType::Char,
+                value: String::from("\""),
+            },
+        ]),
+        output_parameters: Box::new([Parameter {
+            value_type: ParameterType::String,
+            value: String::from("a\"b\\c\nd\""),
+        }]),
+        stdin: None,
+        expected_error: None,
+        label: None,
+        hidden: false,
+    }]);
+    let submission = Submission {
+        solution,
+        test_cases,
+        unordered_lists: false,
+        files: Box::new([]),
+        checker: None,
+        reference_solution: None,
+        only_ids: None,
+        compiler_flags: Box::new([]),
+        debug: false,
+        float_tolerance: None,
+        toolchain_version: None,
+        metadata: None,
     };
     let body = serde_json::to_string(&submission).expect("failed to serialize submission");
     let request = Builder::new()
@@ -577,7 +832,7 @@ async fn all_test_cases_pass_string() {
 
 #[tokio::test]
 async fn all_test_cases_fail_int() {
-    let mozart = app();
+    let mozart = app(AppState::default());
     let solution = ["def solution(x: int):", "    return x"].join("\n");
     let test_cases = Box::new([
         TestCase {
@@ -590,6 +845,10 @@ async fn all_test_cases_fail_int() {
                 value_type: ParameterType::Int,
                 value: String::from("20"),
             }]),
+            stdin: None,
+            expected_error: None,
+            label: None,
+            hidden: false,
         },
         TestCase {
             id: 1,
@@ -601,11 +860,25 @@ async fn all_test_cases_fail_int() {
                 value_type: ParameterType::Int,
                 value: String::from("10"),
             }]),
+            stdin: None,
+            expected_error: None,
+            label: None,
+            hidden: false,
         },
     ]);
     let submission = Submission {
         solution,
         test_cases,
+        unordered_lists: false,
+        files: Box::new([]),
+        checker: None,
+        reference_solution: None,
+        only_ids: None,
+        compiler_flags: Box::new([]),
+        debug: false,
+        float_tolerance: None,
+        toolchain_version: None,
+        metadata: None,
     };
     let body = serde_json::to_string(&submission).expect("failed to serialize submission");
     let request = Builder::new()
@@ -625,7 +898,10 @@ async fn all_test_cases_fail_int() {
                 }]),
                 actual: String::from("10"),
                 expected: String::from("20"),
+                actual_value: None,
+                expected_value: None,
             }),
+            label: None,
         },
         TestCaseResult {
             id: 1,
@@ -636,7 +912,10 @@ async fn all_test_cases_fail_int() {
                 }]),
                 actual: String::from("5"),
                 expected: String::from("10"),
+                actual_value: None,
+                expected_value: None,
             }),
+            label: None,
         },
     ]));
 
@@ -659,7 +938,7 @@ async fn all_test_cases_fail_int() {
 
 #[tokio::test]
 async fn all_test_cases_fail_bool() {
-    let mozart = app();
+    let mozart = app(AppState::default());
     let solution = ["def solution(b: bool):", "    return b"].join("\n");
     let test_cases = Box::new([
         TestCase {
@@ -672,6 +951,10 @@ async fn all_test_cases_fail_bool() {
                 value_type: ParameterType::Bool,
                 value: String::from("false"),
             }]),
+            stdin: None,
+            expected_error: None,
+            label: None,
+            hidden: false,
         },
         TestCase {
             id: 1,
@@ -683,11 +966,25 @@ async fn all_test_cases_fail_bool() {
                 value_type: ParameterType::Bool,
                 value: String::from("true"),
             }]),
+            stdin: None,
+            expected_error: None,
+            label: None,
+            hidden: false,
         },
     ]);
     let submission = Submission {
         solution,
         test_cases,
+        unordered_lists: false,
+        files: Box::new([]),
+        checker: None,
+        reference_solution: None,
+        only_ids: None,
+        compiler_flags: Box::new([]),
+        debug: false,
+        float_tolerance: None,
+        toolchain_version: None,
+        metadata: None,
     };
     let body = serde_json::to_string(&submission).expect("failed to serialize submission");
     let request = Builder::new()
@@ -707,7 +1004,10 @@ async fn all_test_cases_fail_bool() {
                 }]),
                 actual: String::from("True"),
                 expected: String::from("False"),
+                actual_value: None,
+                expected_value: None,
             }),
+            label: None,
         },
         TestCaseResult {
             id: 1,
@@ -718,7 +1018,10 @@ async fn all_test_cases_fail_bool() {
                 }]),
                 actual: String::from("False"),
                 expected: String::from("True"),
+                actual_value: None,
+                expected_value: None,
             }),
+            label: None,
         },
     ]));
 
@@ -741,7 +1044,7 @@ async fn all_test_cases_fail_bool() {
 
 #[tokio::test]
 async fn all_test_cases_fail_float() {
-    let mozart = app();
+    let mozart = app(AppState::default());
     let solution = ["def solution(f: float):", "    return f"].join("\n");
     let test_cases = Box::new([
         TestCase {
@@ -754,6 +1057,10 @@ async fn all_test_cases_fail_float() {
                 value_type: ParameterType::Float,
                 value: String::from("4.4"),
             }]),
+            stdin: None,
+            expected_error: None,
+            label: None,
+            hidden: false,
         },
         TestCase {
             id: 1,
@@ -765,11 +1072,25 @@ async fn all_test_cases_fail_float() {
                 value_type: ParameterType::Float,
                 value: String::from("10.0"),
             }]),
+            stdin: None,
+            expected_error: None,
+            label: None,
+            hidden: false,
         },
     ]);
     let submission = Submission {
         solution,
         test_cases,
+        unordered_lists: false,
+        files: Box::new([]),
+        checker: None,
+        reference_solution: None,
+        only_ids: None,
+        compiler_flags: Box::new([]),
+        debug: false,
+        float_tolerance: None,
+        toolchain_version: None,
+        metadata: None,
     };
     let body = serde_json::to_string(&submission).expect("failed to serialize submission");
     let request = Builder::new()
@@ -789,7 +1110,10 @@ async fn all_test_cases_fail_float() {
                 }]),
                 actual: String::from("2.2"),
                 expected: String::from("4.4"),
+                actual_value: None,
+                expected_value: None,
             }),
+            label: None,
         },
         TestCaseResult {
             id: 1,
@@ -800,7 +1124,10 @@ async fn all_test_cases_fail_float() {
                 }]),
                 actual: String::from("5.0"),
                 expected: String::from("10.0"),
+                actual_value: None,
+                expected_value: None,
             }),
+            label: None,
         },
     ]));
 
@@ -823,7 +1150,7 @@ async fn all_test_cases_fail_float() {
 
 #[tokio::test]
 async fn all_test_cases_fail_char() {
-    let mozart = app();
+    let mozart = app(AppState::default());
     let solution = ["def solution(c: str):", "    return \"a\""].join("\n");
     let test_cases = Box::new([
         TestCase {
@@ -836,6 +1163,10 @@ async fn all_test_cases_fail_char() {
                 value_type: ParameterType::Char,
                 value: String::from("b"),
             }]),
+            stdin: None,
+            expected_error: None,
+            label: None,
+            hidden: false,
         },
         TestCase {
             id: 1,
@@ -847,11 +1178,25 @@ async fn all_test_cases_fail_char() {
                 value_type: ParameterType::Char,
                 value: String::from("c"),
             }]),
+            stdin: None,
+            expected_error: None,
+            label: None,
+            hidden: false,
         },
     ]);
     let submission = Submission {
         solution,
         test_cases,
+        unordered_lists: false,
+        files: Box::new([]),
+        checker: None,
+        reference_solution: None,
+        only_ids: None,
+        compiler_flags: Box::new([]),
+        debug: false,
+        float_tolerance: None,
+        toolchain_version: None,
+        metadata: None,
     };
     let body = serde_json::to_string(&submission).expect("failed to serialize submission");
     let request = Builder::new()
@@ -869,9 +1214,12 @@ async fn all_test_cases_fail_char() {
                     value_type: ParameterType::Char,
                     value: String::from("b"),
                 }]),
-                actual: String::from("'a'"),
-                expected: String::from("'b'"),
+                actual: String::from("a"),
+                expected: String::from("b"),
+                actual_value: None,
+                expected_value: None,
             }),
+            label: None,
         },
         TestCaseResult {
             id: 1,
@@ -880,9 +1228,12 @@ async fn all_test_cases_fail_char() {
                     value_type: ParameterType::Char,
                     value: String::from("c"),
                 }]),
-                actual: String::from("'a'"),
-                expected: String::from("'c'"),
+                actual: String::from("a"),
+                expected: String::from("c"),
+                actual_value: None,
+                expected_value: None,
             }),
+            label: None,
         },
     ]));
 
@@ -905,7 +1256,7 @@ async fn all_test_cases_fail_char() {
 
 #[tokio::test]
 async fn all_test_cases_fail_string() {
-    let mozart = app();
+    let mozart = app(AppState::default());
     let solution = ["def solution(s: str):", "    return s"].join("\n");
     let test_cases = Box::new([
         TestCase {
@@ -918,6 +1269,10 @@ async fn all_test_cases_fail_string() {
                 value_type: ParameterType::String,
                 value: String::from("hellohello"),
             }]),
+            stdin: None,
+            expected_error: None,
+            label: None,
+            hidden: false,
         },
         TestCase {
             id: 1,
@@ -929,11 +1284,25 @@ async fn all_test_cases_fail_string() {
                 value_type: ParameterType::String,
                 value: String::from("worldworld"),
             }]),
+            stdin: None,
+            expected_error: None,
+            label: None,
+            hidden: false,
         },
     ]);
     let submission = Submission {
         solution,
         test_cases,
+        unordered_lists: false,
+        files: Box::new([]),
+        checker: None,
+        reference_solution: None,
+        only_ids: None,
+        compiler_flags: Box::new([]),
+        debug: false,
+        float_tolerance: None,
+        toolchain_version: None,
+        metadata: None,
     };
     let body = serde_json::to_string(&submission).expect("failed to serialize submission");
     let request = Builder::new()
@@ -951,9 +1320,12 @@ async fn all_test_cases_fail_string() {
                     value_type: ParameterType::String,
                     value: String::from("hello"),
                 }]),
-                actual: String::from("'hello'"),
-                expected: String::from("'hellohello'"),
+                actual: String::from("hello"),
+                expected: String::from("hellohello"),
+                actual_value: None,
+                expected_value: None,
             }),
+            label: None,
         },
         TestCaseResult {
             id: 1,
@@ -962,9 +1334,12 @@ async fn all_test_cases_fail_string() {
                     value_type: ParameterType::String,
                     value: String::from("world"),
                 }]),
-                actual: String::from("'world'"),
-                expected: String::from("'worldworld'"),
+                actual: String::from("world"),
+                expected: String::from("worldworld"),
+                actual_value: None,
+                expected_value: None,
             }),
+            label: None,
         },
     ]));
 
@@ -987,7 +1362,7 @@ async fn all_test_cases_fail_string() {
 
 #[tokio::test]
 async fn runtime_error_in_non_last_test_case() {
-    let mozart = app();
+    let mozart = app(AppState::default());
     let solution = ["def solution(x: int):", "    return 10 / x"].join("\n");
     let test_cases = Box::new([
         TestCase {
@@ -1000,6 +1375,10 @@ async fn runtime_error_in_non_last_test_case() {
                 value_type: ParameterType::Int,
                 value: String::from("5"),
             }]),
+            stdin: None,
+            expected_error: None,
+            label: None,
+            hidden: false,
         },
         TestCase {
             id: 1,
@@ -1011,6 +1390,10 @@ async fn runtime_error_in_non_last_test_case() {
                 value_type: ParameterType::Int,
                 value: String::from("0"),
             }]),
+            stdin: None,
+            expected_error: None,
+            label: None,
+            hidden: false,
         },
         TestCase {
             id: 2,
@@ -1022,11 +1405,25 @@ async fn runtime_error_in_non_last_test_case() {
                 value_type: ParameterType::Int,
                 value: String::from("5"),
             }]),
+            stdin: None,
+            expected_error: None,
+            label: None,
+            hidden: false,
         },
     ]);
     let submission = Submission {
         solution,
         test_cases,
+        unordered_lists: false,
+        files: Box::new([]),
+        checker: None,
+        reference_solution: None,
+        only_ids: None,
+        compiler_flags: Box::new([]),
+        debug: false,
+        float_tolerance: None,
+        toolchain_version: None,
+        metadata: None,
     };
     let body = serde_json::to_string(&submission).expect("failed to serialize submission");
     let request = Builder::new()
@@ -1040,16 +1437,25 @@ async fn runtime_error_in_non_last_test_case() {
         TestCaseResult {
             id: 0,
             test_result: TestResult::Pass,
+            label: None,
         },
         TestCaseResult {
             id: 1,
-            test_result: TestResult::Failure(TestCaseFailureReason::RuntimeError(String::from(
-                "division by zero",
-            ))),
+            test_result: TestResult::Failure(TestCaseFailureReason::RuntimeError(
+                [
+                    "Traceback (most recent call last):",
+                    "  File \"solution.py\", line 2, in solution",
+                    "    return 10 / x",
+                    "ZeroDivisionError: division by zero",
+                ]
+                .join("\n"),
+            )),
+            label: None,
         },
         TestCaseResult {
             id: 2,
             test_result: TestResult::Pass,
+            label: None,
         },
     ]));
 
@@ -1072,7 +1478,7 @@ async fn runtime_error_in_non_last_test_case() {
 
 #[tokio::test]
 async fn mixed_pass_and_fail_with_runtime_error() {
-    let mozart = app();
+    let mozart = app(AppState::default());
     let solution = [
         "def solution(x: int):",
         "    if x >= 0:",
@@ -1092,6 +1498,10 @@ async fn mixed_pass_and_fail_with_runtime_error() {
                 value_type: ParameterType::Int,
                 value: String::from("2"),
             }]),
+            stdin: None,
+            expected_error: None,
+            label: None,
+            hidden: false,
         },
         TestCase {
             id: 1,
@@ -1103,6 +1513,10 @@ async fn mixed_pass_and_fail_with_runtime_error() {
                 value_type: ParameterType::Int,
                 value: String::from("5"),
             }]),
+            stdin: None,
+            expected_error: None,
+            label: None,
+            hidden: false,
         },
         TestCase {
             id: 2,
@@ -1114,6 +1528,10 @@ async fn mixed_pass_and_fail_with_runtime_error() {
                 value_type: ParameterType::Int,
                 value: String::from("3"),
             }]),
+            stdin: None,
+            expected_error: None,
+            label: None,
+            hidden: false,
         },
         TestCase {
             id: 3,
@@ -1125,6 +1543,10 @@ async fn mixed_pass_and_fail_with_runtime_error() {
                 value_type: ParameterType::Int,
                 value: String::from("2"),
             }]),
+            stdin: None,
+            expected_error: None,
+            label: None,
+            hidden: false,
         },
         TestCase {
             id: 4,
@@ -1136,6 +1558,10 @@ async fn mixed_pass_and_fail_with_runtime_error() {
                 value_type: ParameterType::Int,
                 value: String::from("-3"),
             }]),
+            stdin: None,
+            expected_error: None,
+            label: None,
+            hidden: false,
         },
         TestCase {
             id: 5,
@@ -1147,11 +1573,25 @@ async fn mixed_pass_and_fail_with_runtime_error() {
                 value_type: ParameterType::Int,
                 value: String::from("6"),
             }]),
+            stdin: None,
+            expected_error: None,
+            label: None,
+            hidden: false,
         },
     ]);
     let submission = Submission {
         solution,
         test_cases,
+        unordered_lists: false,
+        files: Box::new([]),
+        checker: None,
+        reference_solution: None,
+        only_ids: None,
+        compiler_flags: Box::new([]),
+        debug: false,
+        float_tolerance: None,
+        toolchain_version: None,
+        metadata: None,
     };
     let body = serde_json::to_string(&submission).expect("failed to serialize submission");
     let request = Builder::new()
@@ -1165,6 +1605,7 @@ async fn mixed_pass_and_fail_with_runtime_error() {
         TestCaseResult {
             id: 0,
             test_result: TestResult::Pass,
+            label: None,
         },
         TestCaseResult {
             id: 1,
@@ -1175,11 +1616,15 @@ async fn mixed_pass_and_fail_with_runtime_error() {
                 }]),
                 actual: String::from("4"),
                 expected: String::from("5"),
+                actual_value: None,
+                expected_value: None,
             }),
+            label: None,
         },
         TestCaseResult {
             id: 2,
             test_result: TestResult::Pass,
+            label: None,
         },
         TestCaseResult {
             id: 3,
@@ -1190,17 +1635,28 @@ async fn mixed_pass_and_fail_with_runtime_error() {
                 }]),
                 actual: String::from("7"),
                 expected: String::from("2"),
+                actual_value: None,
+                expected_value: None,
             }),
+            label: None,
         },
         TestCaseResult {
             id: 4,
-            test_result: TestResult::Failure(TestCaseFailureReason::RuntimeError(String::from(
-                "division by zero",
-            ))),
+            test_result: TestResult::Failure(TestCaseFailureReason::RuntimeError(
+                [
+                    "Traceback (most recent call last):",
+                    "  File \"solution.py\", line 5, in solution",
+                    "    return x / 0",
+                    "ZeroDivisionError: division by zero",
+                ]
+                .join("\n"),
+            )),
+            label: None,
         },
         TestCaseResult {
             id: 5,
             test_result: TestResult::Pass,
+            label: None,
         },
     ]));
 
@@ -1221,9 +1677,11 @@ async fn mixed_pass_and_fail_with_runtime_error() {
     assert_eq!(actual_body, expected_body);
 }
 
+// the sandbox is what prevents this, so it cannot be tested without it
+#[cfg(not(feature = "no-sandbox"))]
 #[tokio::test]
 async fn create_file_in_mozart_directory() {
-    let mozart = app();
+    let mozart = app(AppState::default());
     let solution = [
         "def solution(x: int):",
         "    with open('/mozart/my_file.txt', 'w') as file:",
@@ -1241,10 +1699,24 @@ async fn create_file_in_mozart_directory() {
             value_type: ParameterType::Int,
             value: String::from("4"),
         }]),
+        stdin: None,
+        expected_error: None,
+        label: None,
+        hidden: false,
     }]);
     let submission = Submission {
         solution,
         test_cases,
+        unordered_lists: false,
+        files: Box::new([]),
+        checker: None,
+        reference_solution: None,
+        only_ids: None,
+        compiler_flags: Box::new([]),
+        debug: false,
+        float_tolerance: None,
+        toolchain_version: None,
+        metadata: None,
     };
     let body = serde_json::to_string(&submission).expect("failed to serialize submission");
     let request = Builder::new()
@@ -1256,9 +1728,16 @@ async fn create_file_in_mozart_directory() {
     let expected_status = StatusCode::OK;
     let expected_body = SubmissionResult::Failure(Box::new([TestCaseResult {
         id: 0,
-        test_result: TestResult::Failure(TestCaseFailureReason::RuntimeError(String::from(
-            "[Errno 13] Permission denied: '/mozart/my_file.txt'",
-        ))),
+        test_result: TestResult::Failure(TestCaseFailureReason::RuntimeError(
+            [
+                "Traceback (most recent call last):",
+                "  File \"solution.py\", line 2, in solution",
+                "    with open('/mozart/my_file.txt', 'w') as file:",
+                "FileNotFoundError: [Errno 2] No such file or directory: '/mozart/my_file.txt'",
+            ]
+            .join("\n"),
+        )),
+        label: None,
     }]));
 
     let actual = mozart
@@ -1278,9 +1757,11 @@ async fn create_file_in_mozart_directory() {
     assert_eq!(actual_body, expected_body);
 }
 
+// the sandbox is what prevents this, so it cannot be tested without it
+#[cfg(not(feature = "no-sandbox"))]
 #[tokio::test]
 async fn create_file_in_tmp_directory() {
-    let mozart = app();
+    let mozart = app(AppState::default());
     let solution = [
         "def solution(x: int):",
         "    with open('/tmp/my_file.txt', 'w') as file:",
@@ -1298,10 +1779,24 @@ async fn create_file_in_tmp_directory() {
             value_type: ParameterType::Int,
             value: String::from("4"),
         }]),
+        stdin: None,
+        expected_error: None,
+        label: None,
+        hidden: false,
     }]);
     let submission = Submission {
         solution,
         test_cases,
+        unordered_lists: false,
+        files: Box::new([]),
+        checker: None,
+        reference_solution: None,
+        only_ids: None,
+        compiler_flags: Box::new([]),
+        debug: false,
+        float_tolerance: None,
+        toolchain_version: None,
+        metadata: None,
     };
     let body = serde_json::to_string(&submission).expect("failed to serialize submission");
     let request = Builder::new()
@@ -1313,9 +1808,16 @@ async fn create_file_in_tmp_directory() {
     let expected_status = StatusCode::OK;
     let expected_body = SubmissionResult::Failure(Box::new([TestCaseResult {
         id: 0,
-        test_result: TestResult::Failure(TestCaseFailureReason::RuntimeError(String::from(
-            "[Errno 13] Permission denied: '/tmp/my_file.txt'",
-        ))),
+        test_result: TestResult::Failure(TestCaseFailureReason::RuntimeError(
+            [
+                "Traceback (most recent call last):",
+                "  File \"solution.py\", line 2, in solution",
+                "    with open('/tmp/my_file.txt', 'w') as file:",
+                "FileNotFoundError: [Errno 2] No such file or directory: '/tmp/my_file.txt'",
+            ]
+            .join("\n"),
+        )),
+        label: None,
     }]));
 
     let actual = mozart
@@ -1335,9 +1837,11 @@ async fn create_file_in_tmp_directory() {
     assert_eq!(actual_body, expected_body);
 }
 
+// the sandbox is what prevents this, so it cannot be tested without it
+#[cfg(not(feature = "no-sandbox"))]
 #[tokio::test]
 async fn create_file_in_var_tmp_directory() {
-    let mozart = app();
+    let mozart = app(AppState::default());
     let solution = [
         "def solution(x: int):",
         "    with open('/var/tmp/my_file.txt', 'w') as file:",
@@ -1355,10 +1859,24 @@ async fn create_file_in_var_tmp_directory() {
             value_type: ParameterType::Int,
             value: String::from("4"),
         }]),
+        stdin: None,
+        expected_error: None,
+        label: None,
+        hidden: false,
     }]);
     let submission = Submission {
         solution,
         test_cases,
+        unordered_lists: false,
+        files: Box::new([]),
+        checker: None,
+        reference_solution: None,
+        only_ids: None,
+        compiler_flags: Box::new([]),
+        debug: false,
+        float_tolerance: None,
+        toolchain_version: None,
+        metadata: None,
     };
     let body = serde_json::to_string(&submission).expect("failed to serialize submission");
     let request = Builder::new()
@@ -1370,9 +1888,16 @@ async fn create_file_in_var_tmp_directory() {
     let expected_status = StatusCode::OK;
     let expected_body = SubmissionResult::Failure(Box::new([TestCaseResult {
         id: 0,
-        test_result: TestResult::Failure(TestCaseFailureReason::RuntimeError(String::from(
-            "[Errno 13] Permission denied: '/var/tmp/my_file.txt'",
-        ))),
+        test_result: TestResult::Failure(TestCaseFailureReason::RuntimeError(
+            [
+                "Traceback (most recent call last):",
+                "  File \"solution.py\", line 2, in solution",
+                "    with open('/var/tmp/my_file.txt', 'w') as file:",
+                "FileNotFoundError: [Errno 2] No such file or directory: '/var/tmp/my_file.txt'",
+            ]
+            .join("\n"),
+        )),
+        label: None,
     }]));
 
     let actual = mozart
@@ -1394,7 +1919,7 @@ async fn create_file_in_var_tmp_directory() {
 
 #[tokio::test]
 async fn syntax_error_in_submission() {
-    let mozart = app();
+    let mozart = app(AppState::default());
     let solution = [
         "def solution(x: int)", // there is missing a ':' at end of line here
         "    return x + x",
@@ -1410,10 +1935,168 @@ async fn syntax_error_in_submission() {
             value_type: ParameterType::Int,
             value: String::from("4"),
         }]),
+        stdin: None,
+        expected_error: None,
+        label: None,
+        hidden: false,
+    }]);
+    let submission = Submission {
+        solution,
+        test_cases,
+        unordered_lists: false,
+        files: Box::new([]),
+        checker: None,
+        reference_solution: None,
+        only_ids: None,
+        compiler_flags: Box::new([]),
+        debug: false,
+        float_tolerance: None,
+        toolchain_version: None,
+        metadata: None,
+    };
+    let body = serde_json::to_string(&submission).expect("failed to serialize submission");
+    let request = Builder::new()
+        .header("Content-Type", "application/json")
+        .method(Method::POST)
+        .uri("/submit")
+        .body(Body::from(body))
+        .expect("failed to build request");
+    let expected_status = StatusCode::OK;
+
+    let actual = mozart
+        .oneshot(request)
+        .await
+        .expect("failed to execute oneshot request");
+
+    let actual_status = actual.status();
+    let body_bytes = to_bytes(actual.into_body(), usize::MAX)
+        .await
+        .expect("failed to convert body to bytes");
+
+    let actual_body: SubmissionResult =
+        serde_json::from_slice(&body_bytes).expect("failed to deserialize response body");
+
+    assert_eq!(actual_status, expected_status);
+
+    if let SubmissionResult::CompilationError {
+        message,
+        diagnostics,
+    } = actual_body
+    {
+        assert!(message.starts_with("an error occurred during compilation:"));
+        assert!(message.contains("SyntaxError"));
+        assert_eq!(diagnostics.len(), 1);
+        assert_eq!(diagnostics[0].line, 1);
+    } else {
+        panic!("response body was not of compilation error variant");
+    }
+}
+
+#[tokio::test]
+async fn big_int_factorial() {
+    let mozart = app(AppState::default());
+    let solution = [
+        "import math",
+        "",
+        "def solution(n: int):",
+        "    return math.factorial(n)",
+    ]
+    .join("\n");
+    let test_cases = Box::new([TestCase {
+        id: 0,
+        input_parameters: Box::new([Parameter {
+            value_type: ParameterType::BigInt,
+            value: String::from("25"),
+        }]),
+        output_parameters: Box::new([Parameter {
+            value_type: ParameterType::BigInt,
+            value: String::from("15511210043330985984000000"),
+        }]),
+        stdin: None,
+        expected_error: None,
+        label: None,
+        hidden: false,
+    }]);
+    let submission = Submission {
+        solution,
+        test_cases,
+        unordered_lists: false,
+        files: Box::new([]),
+        checker: None,
+        reference_solution: None,
+        only_ids: None,
+        compiler_flags: Box::new([]),
+        debug: false,
+        float_tolerance: None,
+        toolchain_version: None,
+        metadata: None,
+    };
+    let body = serde_json::to_string(&submission).expect("failed to serialize submission");
+    let request = Builder::new()
+        .header("Content-Type", "application/json")
+        .method(Method::POST)
+        .uri("/submit")
+        .body(Body::from(body))
+        .expect("failed to build request");
+    let expected_status = StatusCode::OK;
+    let expected_body = SubmissionResult::Pass;
+
+    let actual = mozart
+        .oneshot(request)
+        .await
+        .expect("failed to execute oneshot request");
+
+    let actual_status = actual.status();
+    let body_bytes = to_bytes(actual.into_body(), usize::MAX)
+        .await
+        .expect("failed to convert body to bytes");
+
+    let actual_body: SubmissionResult =
+        serde_json::from_slice(&body_bytes).expect("failed to deserialize response body");
+
+    assert_eq!(actual_status, expected_status);
+    assert_eq!(actual_body, expected_body);
+}
+
+#[tokio::test]
+async fn int_factorial_overflow() {
+    let mozart = app(AppState::default());
+    let solution = [
+        "import math",
+        "",
+        "def solution(n: int):",
+        "    return math.factorial(n)",
+    ]
+    .join("\n");
+    // 25! does not fit in a 64-bit integer, which `Int` is specified as
+    let test_cases = Box::new([TestCase {
+        id: 0,
+        input_parameters: Box::new([Parameter {
+            value_type: ParameterType::Int,
+            value: String::from("25"),
+        }]),
+        output_parameters: Box::new([Parameter {
+            value_type: ParameterType::Int,
+            value: String::from("15511210043330985984000000"),
+        }]),
+        stdin: None,
+        expected_error: None,
+        label: None,
+        hidden: false,
     }]);
     let submission = Submission {
         solution,
         test_cases,
+        unordered_lists: false,
+        files: Box::new([]),
+        checker: None,
+        reference_solution: None,
+        only_ids: None,
+        compiler_flags: Box::new([]),
+        debug: false,
+        float_tolerance: None,
+        toolchain_version: None,
+        metadata: None,
     };
     let body = serde_json::to_string(&submission).expect("failed to serialize submission");
     let request = Builder::new()
@@ -1439,9 +2122,6524 @@ async fn syntax_error_in_submission() {
 
     assert_eq!(actual_status, expected_status);
 
-    if let SubmissionResult::Error(err) = actual_body {
-        assert!(err.starts_with("an error occured during execution:"));
+    if let SubmissionResult::Error { code, .. } = actual_body {
+        assert_eq!(code, ErrorCode::InvalidParameter);
     } else {
         panic!("response body was not of error variant");
     }
 }
+
+#[tokio::test]
+async fn float_whole_number_expected() {
+    let mozart = app(AppState::default());
+    let solution = ["def solution(f: float):", "    return f * 2"].join("\n");
+    let test_cases = Box::new([TestCase {
+        id: 0,
+        input_parameters: Box::new([Parameter {
+            value_type: ParameterType::Float,
+            value: String::from("2.5"),
+        }]),
+        output_parameters: Box::new([Parameter {
+            value_type: ParameterType::Float,
+            value: String::from("5"),
+        }]),
+        stdin: None,
+        expected_error: None,
+        label: None,
+        hidden: false,
+    }]);
+    let submission = Submission {
+        solution,
+        test_cases,
+        unordered_lists: false,
+        files: Box::new([]),
+        checker: None,
+        reference_solution: None,
+        only_ids: None,
+        compiler_flags: Box::new([]),
+        debug: false,
+        float_tolerance: None,
+        toolchain_version: None,
+        metadata: None,
+    };
+    let body = serde_json::to_string(&submission).expect("failed to serialize submission");
+    let request = Builder::new()
+        .header("Content-Type", "application/json")
+        .method(Method::POST)
+        .uri("/submit")
+        .body(Body::from(body))
+        .expect("failed to build request");
+    let expected_status = StatusCode::OK;
+    let expected_body = SubmissionResult::Pass;
+
+    let actual = mozart
+        .oneshot(request)
+        .await
+        .expect("failed to execute oneshot request");
+
+    let actual_status = actual.status();
+    let body_bytes = to_bytes(actual.into_body(), usize::MAX)
+        .await
+        .expect("failed to convert body to bytes");
+
+    let actual_body: SubmissionResult =
+        serde_json::from_slice(&body_bytes).expect("failed to deserialize response body");
+
+    assert_eq!(actual_status, expected_status);
+    assert_eq!(actual_body, expected_body);
+}
+
+#[tokio::test]
+async fn float_whole_number_actual() {
+    let mozart = app(AppState::default());
+    let solution = ["def solution(f: float):", "    return 5"].join("\n");
+    let test_cases = Box::new([TestCase {
+        id: 0,
+        input_parameters: Box::new([Parameter {
+            value_type: ParameterType::Float,
+            value: String::from("2.5"),
+        }]),
+        output_parameters: Box::new([Parameter {
+            value_type: ParameterType::Float,
+            value: String::from("5.0"),
+        }]),
+        stdin: None,
+        expected_error: None,
+        label: None,
+        hidden: false,
+    }]);
+    let submission = Submission {
+        solution,
+        test_cases,
+        unordered_lists: false,
+        files: Box::new([]),
+        checker: None,
+        reference_solution: None,
+        only_ids: None,
+        compiler_flags: Box::new([]),
+        debug: false,
+        float_tolerance: None,
+        toolchain_version: None,
+        metadata: None,
+    };
+    let body = serde_json::to_string(&submission).expect("failed to serialize submission");
+    let request = Builder::new()
+        .header("Content-Type", "application/json")
+        .method(Method::POST)
+        .uri("/submit")
+        .body(Body::from(body))
+        .expect("failed to build request");
+    let expected_status = StatusCode::OK;
+    let expected_body = SubmissionResult::Pass;
+
+    let actual = mozart
+        .oneshot(request)
+        .await
+        .expect("failed to execute oneshot request");
+
+    let actual_status = actual.status();
+    let body_bytes = to_bytes(actual.into_body(), usize::MAX)
+        .await
+        .expect("failed to convert body to bytes");
+
+    let actual_body: SubmissionResult =
+        serde_json::from_slice(&body_bytes).expect("failed to deserialize response body");
+
+    assert_eq!(actual_status, expected_status);
+    assert_eq!(actual_body, expected_body);
+}
+
+#[tokio::test]
+async fn duplicate_test_case_ids() {
+    let mozart = app(AppState::default());
+    let solution = ["def solution(x: int):", "    return x"].join("\n");
+    let test_cases = Box::new([
+        TestCase {
+            id: 0,
+            input_parameters: Box::new([Parameter {
+                value_type: ParameterType::Int,
+                value: String::from("1"),
+            }]),
+            output_parameters: Box::new([Parameter {
+                value_type: ParameterType::Int,
+                value: String::from("1"),
+            }]),
+            stdin: None,
+            expected_error: None,
+            label: None,
+            hidden: false,
+        },
+        TestCase {
+            id: 0,
+            input_parameters: Box::new([Parameter {
+                value_type: ParameterType::Int,
+                value: String::from("2"),
+            }]),
+            output_parameters: Box::new([Parameter {
+                value_type: ParameterType::Int,
+                value: String::from("2"),
+            }]),
+            stdin: None,
+            expected_error: None,
+            label: None,
+            hidden: false,
+        },
+    ]);
+    let submission = Submission {
+        solution,
+        test_cases,
+        unordered_lists: false,
+        files: Box::new([]),
+        checker: None,
+        reference_solution: None,
+        only_ids: None,
+        compiler_flags: Box::new([]),
+        debug: false,
+        float_tolerance: None,
+        toolchain_version: None,
+        metadata: None,
+    };
+    let body = serde_json::to_string(&submission).expect("failed to serialize submission");
+    let request = Builder::new()
+        .header("Content-Type", "application/json")
+        .method(Method::POST)
+        .uri("/submit")
+        .body(Body::from(body))
+        .expect("failed to build request");
+    let expected_status = StatusCode::OK;
+
+    let actual = mozart
+        .oneshot(request)
+        .await
+        .expect("failed to execute oneshot request");
+
+    let actual_status = actual.status();
+    let body_bytes = to_bytes(actual.into_body(), usize::MAX)
+        .await
+        .expect("failed to convert body to bytes");
+
+    let actual_body: SubmissionResult =
+        serde_json::from_slice(&body_bytes).expect("failed to deserialize response body");
+
+    assert_eq!(actual_status, expected_status);
+
+    if let SubmissionResult::Error { code, message } = actual_body {
+        assert_eq!(code, ErrorCode::DuplicateTestCaseId);
+        assert!(message.starts_with("multiple test cases have the id 0"));
+    } else {
+        panic!("response body was not of error variant");
+    }
+}
+
+#[tokio::test]
+async fn batch() {
+    let mozart = app(AppState::default());
+    let submissions = [
+        Submission {
+            solution: ["def solution(x: int):", "    return x + x"].join("\n"),
+            test_cases: Box::new([TestCase {
+                id: 0,
+                input_parameters: Box::new([Parameter {
+                    value_type: ParameterType::Int,
+                    value: String::from("2"),
+                }]),
+                output_parameters: Box::new([Parameter {
+                    value_type: ParameterType::Int,
+                    value: String::from("4"),
+                }]),
+                stdin: None,
+                expected_error: None,
+                label: None,
+                hidden: false,
+            }]),
+            unordered_lists: false,
+            files: Box::new([]),
+            checker: None,
+            reference_solution: None,
+            only_ids: None,
+            compiler_flags: Box::new([]),
+            debug: false,
+            float_tolerance: None,
+            toolchain_version: None,
+            metadata: None,
+        },
+        Submission {
+            solution: ["def solution(x: int):", "    return x"].join("\n"),
+            test_cases: Box::new([TestCase {
+                id: 0,
+                input_parameters: Box::new([Parameter {
+                    value_type: ParameterType::Int,
+                    value: String::from("2"),
+                }]),
+                output_parameters: Box::new([Parameter {
+                    value_type: ParameterType::Int,
+                    value: String::from("4"),
+                }]),
+                stdin: None,
+                expected_error: None,
+                label: None,
+                hidden: false,
+            }]),
+            unordered_lists: false,
+            files: Box::new([]),
+            checker: None,
+            reference_solution: None,
+            only_ids: None,
+            compiler_flags: Box::new([]),
+            debug: false,
+            float_tolerance: None,
+            toolchain_version: None,
+            metadata: None,
+        },
+        Submission {
+            solution: ["def solution(x: int)", "    return x + x"].join("\n"),
+            test_cases: Box::new([TestCase {
+                id: 0,
+                input_parameters: Box::new([Parameter {
+                    value_type: ParameterType::Int,
+                    value: String::from("2"),
+                }]),
+                output_parameters: Box::new([Parameter {
+                    value_type: ParameterType::Int,
+                    value: String::from("4"),
+                }]),
+                stdin: None,
+                expected_error: None,
+                label: None,
+                hidden: false,
+            }]),
+            unordered_lists: false,
+            files: Box::new([]),
+            checker: None,
+            reference_solution: None,
+            only_ids: None,
+            compiler_flags: Box::new([]),
+            debug: false,
+            float_tolerance: None,
+            toolchain_version: None,
+            metadata: None,
+        },
+    ];
+    let body = serde_json::to_string(&submissions).expect("failed to serialize submissions");
+    let request = Builder::new()
+        .header("Content-Type", "application/json")
+        .method(Method::POST)
+        .uri("/submit/batch")
+        .body(Body::from(body))
+        .expect("failed to build request");
+    let expected_status = StatusCode::OK;
+
+    let actual = mozart
+        .oneshot(request)
+        .await
+        .expect("failed to execute oneshot request");
+
+    let actual_status = actual.status();
+    let body_bytes = to_bytes(actual.into_body(), usize::MAX)
+        .await
+        .expect("failed to convert body to bytes");
+
+    let actual_body: Vec<SubmissionResult> =
+        serde_json::from_slice(&body_bytes).expect("failed to deserialize response body");
+
+    assert_eq!(actual_status, expected_status);
+    assert_eq!(actual_body.len(), 3);
+    assert_eq!(actual_body[0], SubmissionResult::Pass);
+    assert!(matches!(actual_body[1], SubmissionResult::Failure(_)));
+    assert!(matches!(
+        actual_body[2],
+        SubmissionResult::CompilationError { .. }
+    ));
+}
+
+#[tokio::test]
+async fn unit_output() {
+    let mozart = app(AppState::default());
+    let solution = [
+        "def solution(x: int):",
+        "    if x < 0:",
+        "        raise ValueError('negative')",
+    ]
+    .join("\n");
+    let test_cases = Box::new([TestCase {
+        id: 0,
+        input_parameters: Box::new([Parameter {
+            value_type: ParameterType::Int,
+            value: String::from("2"),
+        }]),
+        output_parameters: Box::new([Parameter {
+            value_type: ParameterType::Unit,
+            value: String::from(""),
+        }]),
+        stdin: None,
+        expected_error: None,
+        label: None,
+        hidden: false,
+    }]);
+    let submission = Submission {
+        solution,
+        test_cases,
+        unordered_lists: false,
+        files: Box::new([]),
+        checker: None,
+        reference_solution: None,
+        only_ids: None,
+        compiler_flags: Box::new([]),
+        debug: false,
+        float_tolerance: None,
+        toolchain_version: None,
+        metadata: None,
+    };
+    let body = serde_json::to_string(&submission).expect("failed to serialize submission");
+    let request = Builder::new()
+        .header("Content-Type", "application/json")
+        .method(Method::POST)
+        .uri("/submit")
+        .body(Body::from(body))
+        .expect("failed to build request");
+    let expected_status = StatusCode::OK;
+    let expected_body = SubmissionResult::Pass;
+
+    let actual = mozart
+        .oneshot(request)
+        .await
+        .expect("failed to execute oneshot request");
+
+    let actual_status = actual.status();
+    let body_bytes = to_bytes(actual.into_body(), usize::MAX)
+        .await
+        .expect("failed to convert body to bytes");
+
+    let actual_body: SubmissionResult =
+        serde_json::from_slice(&body_bytes).expect("failed to deserialize response body");
+
+    assert_eq!(actual_status, expected_status);
+    assert_eq!(actual_body, expected_body);
+}
+
+#[tokio::test]
+async fn check_submission_without_http() {
+    let solution = ["def solution(x: int):", "    return x + x"].join("\n");
+    let test_cases = Box::new([TestCase {
+        id: 0,
+        input_parameters: Box::new([Parameter {
+            value_type: ParameterType::Int,
+            value: String::from("2"),
+        }]),
+        output_parameters: Box::new([Parameter {
+            value_type: ParameterType::Int,
+            value: String::from("4"),
+        }]),
+        stdin: None,
+        expected_error: None,
+        label: None,
+        hidden: false,
+    }]);
+    let submission = Submission {
+        solution,
+        test_cases,
+        unordered_lists: false,
+        files: Box::new([]),
+        checker: None,
+        reference_solution: None,
+        only_ids: None,
+        compiler_flags: Box::new([]),
+        debug: false,
+        float_tolerance: None,
+        toolchain_version: None,
+        metadata: None,
+    };
+    let expected = SubmissionResult::Pass;
+
+    let actual = check_submission(submission, AppState::default()).await;
+
+    assert_eq!(actual, expected);
+}
+
+#[cfg(not(feature = "no-sandbox"))]
+#[tokio::test]
+async fn restricted_group() {
+    let state = AppState::new(Config {
+        python_modules: Box::new([String::from("os")]),
+        ..Config::default()
+    });
+    let solution = [
+        "import os",
+        "",
+        "def solution() -> bool:",
+        "    return os.getgid() != 0 and os.getegid() != 0 and 0 not in os.getgroups()",
+    ]
+    .join("\n");
+    // the solution must neither keep the root group of mozart, nor any of its supplementary groups
+    let test_cases = Box::new([TestCase {
+        id: 0,
+        input_parameters: Box::new([]),
+        output_parameters: Box::new([Parameter {
+            value_type: ParameterType::Bool,
+            value: String::from("true"),
+        }]),
+        stdin: None,
+        expected_error: None,
+        label: None,
+        hidden: false,
+    }]);
+    let submission = Submission {
+        solution,
+        test_cases,
+        unordered_lists: false,
+        files: Box::new([]),
+        checker: None,
+        reference_solution: None,
+        only_ids: None,
+        compiler_flags: Box::new([]),
+        debug: false,
+        float_tolerance: None,
+        toolchain_version: None,
+        metadata: None,
+    };
+
+    let actual = check_submission(submission, state).await;
+
+    assert_eq!(actual, SubmissionResult::Pass);
+}
+
+// without the sandbox, every execution runs as the current user, so the process limit is shared by all of them
+#[cfg(not(feature = "no-sandbox"))]
+#[tokio::test]
+async fn fork_bomb_beside_other_submission() {
+    // the os module is allowed, such that the fork bomb runs into the process limit rather than the import hook
+    let state = AppState::new(Config {
+        python_modules: Box::new([String::from("os")]),
+        concurrency_limit: 2,
+        ..Config::default()
+    });
+    let fork_bomb = [
+        "import os",
+        "import time",
+        "",
+        "def solution(x: int):",
+        "    while True:",
+        "        try:",
+        "            if os.fork() == 0:",
+        "                time.sleep(60)",
+        "        except OSError:",
+        "            time.sleep(0.01)",
+    ]
+    .join("\n");
+    // the other submission spawns processes of its own, which it could not if it shared the process limit
+    let spawning = [
+        "import os",
+        "",
+        "def solution(x: int) -> int:",
+        "    pid = os.fork()",
+        "    if pid == 0:",
+        "        os._exit(0)",
+        "    os.waitpid(pid, 0)",
+        "    return x",
+    ]
+    .join("\n");
+    let submission = |solution: String| Submission {
+        solution,
+        test_cases: Box::new([TestCase {
+            id: 0,
+            input_parameters: Box::new([Parameter {
+                value_type: ParameterType::Int,
+                value: String::from("2"),
+            }]),
+            output_parameters: Box::new([Parameter {
+                value_type: ParameterType::Int,
+                value: String::from("2"),
+            }]),
+            stdin: None,
+            expected_error: None,
+            label: None,
+            hidden: false,
+        }]),
+        unordered_lists: false,
+        files: Box::new([]),
+        checker: None,
+        reference_solution: None,
+        only_ids: None,
+        compiler_flags: Box::new([]),
+        debug: false,
+        float_tolerance: None,
+        toolchain_version: None,
+        metadata: None,
+    };
+
+    let fork_bomb = tokio::spawn(check_submission(submission(fork_bomb), state.clone()));
+    // the fork bomb is given time to exhaust its process limit before the other submission starts
+    sleep(Duration::from_millis(500)).await;
+    let actual = check_submission(submission(spawning), state).await;
+    fork_bomb.await.expect("failed to join fork bomb");
+
+    assert_eq!(actual, SubmissionResult::Pass);
+}
+
+#[tokio::test]
+async fn fork_bomb() {
+    // the os module is allowed, such that the fork bomb runs into the process limit rather than the import hook
+    let mozart = app(AppState::new(Config {
+        python_modules: Box::new([String::from("os")]),
+        ..Config::default()
+    }));
+    // the children sleep forever under a name of their own, such that those left behind can be found
+    let name = format!("fork-bomb-{}", Uuid::new_v4());
+    let solution = [
+        "import os",
+        "",
+        "def solution(x: int):",
+        "    for _ in range(1000):",
+        "        if os.fork() == 0:",
+        &format!(
+            "            os.execv(\"/bin/sleep\", [\"{}\", \"infinity\"])",
+            name
+        ),
+        "    return x",
+    ]
+    .join("\n");
+    let test_cases = Box::new([TestCase {
+        id: 0,
+        input_parameters: Box::new([Parameter {
+            value_type: ParameterType::Int,
+            value: String::from("2"),
+        }]),
+        output_parameters: Box::new([Parameter {
+            value_type: ParameterType::Int,
+            value: String::from("2"),
+        }]),
+        stdin: None,
+        expected_error: None,
+        label: None,
+        hidden: false,
+    }]);
+    let submission = Submission {
+        solution,
+        test_cases,
+        unordered_lists: false,
+        files: Box::new([]),
+        checker: None,
+        reference_solution: None,
+        only_ids: None,
+        compiler_flags: Box::new([]),
+        debug: false,
+        float_tolerance: None,
+        toolchain_version: None,
+        metadata: None,
+    };
+    let body = serde_json::to_string(&submission).expect("failed to serialize submission");
+    let request = Builder::new()
+        .header("Content-Type", "application/json")
+        .method(Method::POST)
+        .uri("/submit")
+        .body(Body::from(body))
+        .expect("failed to build request");
+    let expected_status = StatusCode::OK;
+
+    let actual = mozart
+        .oneshot(request)
+        .await
+        .expect("failed to execute oneshot request");
+
+    let actual_status = actual.status();
+    let body_bytes = to_bytes(actual.into_body(), usize::MAX)
+        .await
+        .expect("failed to convert body to bytes");
+
+    let actual_body: SubmissionResult =
+        serde_json::from_slice(&body_bytes).expect("failed to deserialize response body");
+
+    assert_eq!(actual_status, expected_status);
+
+    if let SubmissionResult::Failure(test_case_results) = actual_body {
+        assert!(matches!(
+            test_case_results[0].test_result,
+            TestResult::Failure(TestCaseFailureReason::RuntimeError(_))
+        ));
+    } else {
+        panic!("response body was not of failure variant");
+    }
+
+    // the children are killed along with the execution, though they may take a moment to exit
+    let deadline = Instant::now() + Duration::from_secs(2);
+    while running_processes_named(&name) > 0 && Instant::now() < deadline {
+        sleep(Duration::from_millis(50)).await;
+    }
+    assert_eq!(running_processes_named(&name), 0);
+
+    let request = Builder::new()
+        .method(Method::GET)
+        .uri("/status")
+        .body(Body::empty())
+        .expect("failed to build request");
+
+    let actual = app(AppState::default())
+        .oneshot(request)
+        .await
+        .expect("failed to execute oneshot request");
+
+    assert_eq!(actual.status(), StatusCode::OK);
+}
+
+/// Counts the running processes whose first argument is the `name`, where an exited process that has not been reaped
+/// yet does not count, as its arguments are gone.
+fn running_processes_named(name: &str) -> usize {
+    fs::read_dir("/proc")
+        .expect("failed to read processes")
+        .filter_map(Result::ok)
+        .filter_map(|entry| fs::read(entry.path().join("cmdline")).ok())
+        .filter(|cmdline| cmdline.split(|&byte| byte == 0).next() == Some(name.as_bytes()))
+        .count()
+}
+
+#[tokio::test]
+async fn huge_stdout_keeps_verdict() {
+    let mozart = app(AppState::default());
+    let solution = [
+        "def solution(x: int) -> int:",
+        "    for _ in range(2000):",
+        "        print(\"x\" * 1000)",
+        "    return x",
+    ]
+    .join("\n");
+    let test_cases = Box::new([TestCase {
+        id: 0,
+        input_parameters: Box::new([Parameter {
+            value_type: ParameterType::Int,
+            value: String::from("2"),
+        }]),
+        output_parameters: Box::new([Parameter {
+            value_type: ParameterType::Int,
+            value: String::from("2"),
+        }]),
+        stdin: None,
+        expected_error: None,
+        label: None,
+        hidden: false,
+    }]);
+    let submission = Submission {
+        solution,
+        test_cases,
+        unordered_lists: false,
+        files: Box::new([]),
+        checker: None,
+        reference_solution: None,
+        only_ids: None,
+        compiler_flags: Box::new([]),
+        debug: false,
+        float_tolerance: None,
+        toolchain_version: None,
+        metadata: None,
+    };
+    let body = serde_json::to_string(&submission).expect("failed to serialize submission");
+    let request = Builder::new()
+        .header("Content-Type", "application/json")
+        .method(Method::POST)
+        .uri("/submit")
+        .body(Body::from(body))
+        .expect("failed to build request");
+    let expected_status = StatusCode::OK;
+    let expected_result = SubmissionResult::Pass;
+    // the default maximum length of the stdout in a response
+    let expected_stdout_length = 10000 + "...".len();
+
+    let actual = mozart
+        .oneshot(request)
+        .await
+        .expect("failed to execute oneshot request");
+
+    let actual_status = actual.status();
+    let body_bytes = to_bytes(actual.into_body(), usize::MAX)
+        .await
+        .expect("failed to convert body to bytes");
+
+    let actual_body: Value =
+        serde_json::from_slice(&body_bytes).expect("failed to deserialize response body");
+    let actual_result: SubmissionResult =
+        serde_json::from_slice(&body_bytes).expect("failed to deserialize result");
+    let actual_stdout = actual_body["stdout"]
+        .as_str()
+        .expect("stdout should be a string");
+
+    assert_eq!(actual_status, expected_status);
+    assert_eq!(actual_result, expected_result);
+    assert_eq!(actual_stdout.len(), expected_stdout_length);
+    assert!(actual_stdout.ends_with("..."));
+}
+
+#[tokio::test]
+async fn submission_completes_during_shutdown() {
+    let listener = TcpListener::bind("127.0.0.1:0")
+        .await
+        .expect("failed to bind listener");
+    let address = listener.local_addr().expect("failed to get local address");
+    let (shutdown, shutdown_rx) = oneshot::channel::<()>();
+    let server = tokio::spawn(serve_until(listener, AppState::default(), async move {
+        let _ = shutdown_rx.await;
+    }));
+    let solution = [
+        "import time",
+        "",
+        "def solution(x: int) -> int:",
+        "    time.sleep(0.5)",
+        "    return x",
+    ]
+    .join("\n");
+    let test_cases = Box::new([TestCase {
+        id: 0,
+        input_parameters: Box::new([Parameter {
+            value_type: ParameterType::Int,
+            value: String::from("2"),
+        }]),
+        output_parameters: Box::new([Parameter {
+            value_type: ParameterType::Int,
+            value: String::from("2"),
+        }]),
+        stdin: None,
+        expected_error: None,
+        label: None,
+        hidden: false,
+    }]);
+    let submission = Submission {
+        solution,
+        test_cases,
+        unordered_lists: false,
+        files: Box::new([]),
+        checker: None,
+        reference_solution: None,
+        only_ids: None,
+        compiler_flags: Box::new([]),
+        debug: false,
+        float_tolerance: None,
+        toolchain_version: None,
+        metadata: None,
+    };
+    let body = serde_json::to_string(&submission).expect("failed to serialize submission");
+    let request = format!(
+        "POST /submit HTTP/1.1\r\nHost: localhost\r\nContent-Type: application/json\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}",
+        body.len(),
+        body
+    );
+    let expected_body = SubmissionResult::Pass;
+
+    let mut stream = TcpStream::connect(address)
+        .await
+        .expect("failed to connect to mozart");
+    stream
+        .write_all(request.as_bytes())
+        .await
+        .expect("failed to send request");
+    // gives mozart time to start checking the submission before shutting down
+    sleep(Duration::from_millis(200)).await;
+    shutdown.send(()).expect("failed to signal shutdown");
+
+    let mut response = String::new();
+    stream
+        .read_to_string(&mut response)
+        .await
+        .expect("failed to read response");
+    server.await.expect("failed to shut down mozart");
+
+    let (head, body) = response
+        .split_once("\r\n\r\n")
+        .expect("response should have a body");
+    let actual_body: SubmissionResult =
+        serde_json::from_str(body).expect("failed to deserialize response body");
+
+    assert!(head.starts_with("HTTP/1.1 200 OK"));
+    assert_eq!(actual_body, expected_body);
+}
+
+#[tokio::test]
+async fn timeout_in_third_test_case() {
+    let mozart = app(AppState::default());
+    let solution = [
+        "def solution(x: int) -> int:",
+        "    if x == 3:",
+        "        return sum(range(10**18))",
+        "    return x",
+    ]
+    .join("\n");
+    // the third test case cannot be interrupted by its own timeout, as the signal is only handled once the builtin
+    // returns, so the execution is killed and the results of the never started fourth and fifth test cases are unknown
+    let test_cases = Box::new([
+        TestCase {
+            id: 0,
+            input_parameters: Box::new([Parameter {
+                value_type: ParameterType::Int,
+                value: String::from("1"),
+            }]),
+            output_parameters: Box::new([Parameter {
+                value_type: ParameterType::Int,
+                value: String::from("1"),
+            }]),
+            stdin: None,
+            expected_error: None,
+            label: None,
+            hidden: false,
+        },
+        TestCase {
+            id: 1,
+            input_parameters: Box::new([Parameter {
+                value_type: ParameterType::Int,
+                value: String::from("2"),
+            }]),
+            output_parameters: Box::new([Parameter {
+                value_type: ParameterType::Int,
+                value: String::from("2"),
+            }]),
+            stdin: None,
+            expected_error: None,
+            label: None,
+            hidden: false,
+        },
+        TestCase {
+            id: 2,
+            input_parameters: Box::new([Parameter {
+                value_type: ParameterType::Int,
+                value: String::from("3"),
+            }]),
+            output_parameters: Box::new([Parameter {
+                value_type: ParameterType::Int,
+                value: String::from("3"),
+            }]),
+            stdin: None,
+            expected_error: None,
+            label: None,
+            hidden: false,
+        },
+        TestCase {
+            id: 3,
+            input_parameters: Box::new([Parameter {
+                value_type: ParameterType::Int,
+                value: String::from("4"),
+            }]),
+            output_parameters: Box::new([Parameter {
+                value_type: ParameterType::Int,
+                value: String::from("4"),
+            }]),
+            stdin: None,
+            expected_error: None,
+            label: None,
+            hidden: false,
+        },
+        TestCase {
+            id: 4,
+            input_parameters: Box::new([Parameter {
+                value_type: ParameterType::Int,
+                value: String::from("5"),
+            }]),
+            output_parameters: Box::new([Parameter {
+                value_type: ParameterType::Int,
+                value: String::from("5"),
+            }]),
+            stdin: None,
+            expected_error: None,
+            label: None,
+            hidden: false,
+        },
+    ]);
+    let submission = Submission {
+        solution,
+        test_cases,
+        unordered_lists: false,
+        files: Box::new([]),
+        checker: None,
+        reference_solution: None,
+        only_ids: None,
+        compiler_flags: Box::new([]),
+        debug: false,
+        float_tolerance: None,
+        toolchain_version: None,
+        metadata: None,
+    };
+    let body = serde_json::to_string(&submission).expect("failed to serialize submission");
+    let request = Builder::new()
+        .header("Content-Type", "application/json")
+        .method(Method::POST)
+        .uri("/submit")
+        .body(Body::from(body))
+        .expect("failed to build request");
+    let expected_status = StatusCode::OK;
+    let expected_body = SubmissionResult::Failure(Box::new([
+        TestCaseResult {
+            id: 0,
+            test_result: TestResult::Pass,
+            label: None,
+        },
+        TestCaseResult {
+            id: 1,
+            test_result: TestResult::Pass,
+            label: None,
+        },
+        TestCaseResult {
+            id: 2,
+            test_result: TestResult::Failure(TestCaseFailureReason::Timeout),
+            label: None,
+        },
+        TestCaseResult {
+            id: 3,
+            test_result: TestResult::Unknown,
+            label: None,
+        },
+        TestCaseResult {
+            id: 4,
+            test_result: TestResult::Unknown,
+            label: None,
+        },
+    ]));
+
+    let actual = mozart
+        .oneshot(request)
+        .await
+        .expect("failed to execute oneshot request");
+
+    let actual_status = actual.status();
+    let body_bytes = to_bytes(actual.into_body(), usize::MAX)
+        .await
+        .expect("failed to convert body to bytes");
+
+    let actual_body: SubmissionResult =
+        serde_json::from_slice(&body_bytes).expect("failed to deserialize response body");
+
+    assert_eq!(actual_status, expected_status);
+    assert_eq!(actual_body, expected_body);
+}
+
+#[tokio::test]
+async fn per_test_case_timeout() {
+    let mozart = app(AppState::default());
+    let solution = [
+        "def solution(x: int) -> int:",
+        "    while x == 3:",
+        "        pass",
+        "    return x",
+    ]
+    .join("\n");
+    // the third test case exceeds its own timeout, while the other test cases still run
+    let test_cases = Box::new([
+        TestCase {
+            id: 0,
+            input_parameters: Box::new([Parameter {
+                value_type: ParameterType::Int,
+                value: String::from("1"),
+            }]),
+            output_parameters: Box::new([Parameter {
+                value_type: ParameterType::Int,
+                value: String::from("1"),
+            }]),
+            stdin: None,
+            expected_error: None,
+            label: None,
+            hidden: false,
+        },
+        TestCase {
+            id: 1,
+            input_parameters: Box::new([Parameter {
+                value_type: ParameterType::Int,
+                value: String::from("2"),
+            }]),
+            output_parameters: Box::new([Parameter {
+                value_type: ParameterType::Int,
+                value: String::from("2"),
+            }]),
+            stdin: None,
+            expected_error: None,
+            label: None,
+            hidden: false,
+        },
+        TestCase {
+            id: 2,
+            input_parameters: Box::new([Parameter {
+                value_type: ParameterType::Int,
+                value: String::from("3"),
+            }]),
+            output_parameters: Box::new([Parameter {
+                value_type: ParameterType::Int,
+                value: String::from("3"),
+            }]),
+            stdin: None,
+            expected_error: None,
+            label: None,
+            hidden: false,
+        },
+        TestCase {
+            id: 3,
+            input_parameters: Box::new([Parameter {
+                value_type: ParameterType::Int,
+                value: String::from("4"),
+            }]),
+            output_parameters: Box::new([Parameter {
+                value_type: ParameterType::Int,
+                value: String::from("4"),
+            }]),
+            stdin: None,
+            expected_error: None,
+            label: None,
+            hidden: false,
+        },
+        TestCase {
+            id: 4,
+            input_parameters: Box::new([Parameter {
+                value_type: ParameterType::Int,
+                value: String::from("5"),
+            }]),
+            output_parameters: Box::new([Parameter {
+                value_type: ParameterType::Int,
+                value: String::from("5"),
+            }]),
+            stdin: None,
+            expected_error: None,
+            label: None,
+            hidden: false,
+        },
+    ]);
+    let submission = Submission {
+        solution,
+        test_cases,
+        unordered_lists: false,
+        files: Box::new([]),
+        checker: None,
+        reference_solution: None,
+        only_ids: None,
+        compiler_flags: Box::new([]),
+        debug: false,
+        float_tolerance: None,
+        toolchain_version: None,
+        metadata: None,
+    };
+    let body = serde_json::to_string(&submission).expect("failed to serialize submission");
+    let request = Builder::new()
+        .header("Content-Type", "application/json")
+        .method(Method::POST)
+        .uri("/submit")
+        .body(Body::from(body))
+        .expect("failed to build request");
+    let expected_status = StatusCode::OK;
+    let expected_body = SubmissionResult::Failure(Box::new([
+        TestCaseResult {
+            id: 0,
+            test_result: TestResult::Pass,
+            label: None,
+        },
+        TestCaseResult {
+            id: 1,
+            test_result: TestResult::Pass,
+            label: None,
+        },
+        TestCaseResult {
+            id: 2,
+            test_result: TestResult::Failure(TestCaseFailureReason::Timeout),
+            label: None,
+        },
+        TestCaseResult {
+            id: 3,
+            test_result: TestResult::Pass,
+            label: None,
+        },
+        TestCaseResult {
+            id: 4,
+            test_result: TestResult::Pass,
+            label: None,
+        },
+    ]));
+
+    let actual = mozart
+        .oneshot(request)
+        .await
+        .expect("failed to execute oneshot request");
+
+    let actual_status = actual.status();
+    let body_bytes = to_bytes(actual.into_body(), usize::MAX)
+        .await
+        .expect("failed to convert body to bytes");
+
+    let actual_body: SubmissionResult =
+        serde_json::from_slice(&body_bytes).expect("failed to deserialize response body");
+
+    assert_eq!(actual_status, expected_status);
+    assert_eq!(actual_body, expected_body);
+}
+
+#[tokio::test]
+async fn int64_beyond_32_bits() {
+    let mozart = app(AppState::default());
+    let solution = ["def solution(x: int) -> int:", "    return x * 2"].join("\n");
+    let test_cases = Box::new([TestCase {
+        id: 0,
+        input_parameters: Box::new([Parameter {
+            value_type: ParameterType::Int64,
+            value: String::from("1073741824"),
+        }]),
+        output_parameters: Box::new([Parameter {
+            value_type: ParameterType::Int64,
+            value: String::from("2147483648"),
+        }]),
+        stdin: None,
+        expected_error: None,
+        label: None,
+        hidden: false,
+    }]);
+    let submission = Submission {
+        solution,
+        test_cases,
+        unordered_lists: false,
+        files: Box::new([]),
+        checker: None,
+        reference_solution: None,
+        only_ids: None,
+        compiler_flags: Box::new([]),
+        debug: false,
+        float_tolerance: None,
+        toolchain_version: None,
+        metadata: None,
+    };
+    let body = serde_json::to_string(&submission).expect("failed to serialize submission");
+    let request = Builder::new()
+        .header("Content-Type", "application/json")
+        .method(Method::POST)
+        .uri("/submit")
+        .body(Body::from(body))
+        .expect("failed to build request");
+    let expected_status = StatusCode::OK;
+    let expected_body = SubmissionResult::Pass;
+
+    let actual = mozart
+        .oneshot(request)
+        .await
+        .expect("failed to execute oneshot request");
+
+    let actual_status = actual.status();
+    let body_bytes = to_bytes(actual.into_body(), usize::MAX)
+        .await
+        .expect("failed to convert body to bytes");
+
+    let actual_body: SubmissionResult =
+        serde_json::from_slice(&body_bytes).expect("failed to deserialize response body");
+
+    assert_eq!(actual_status, expected_status);
+    assert_eq!(actual_body, expected_body);
+}
+
+#[tokio::test]
+async fn int32_overflowing_value() {
+    let mozart = app(AppState::default());
+    let solution = ["def solution(x: int) -> int:", "    return x * 2"].join("\n");
+    let test_cases = Box::new([TestCase {
+        id: 0,
+        input_parameters: Box::new([Parameter {
+            value_type: ParameterType::Int32,
+            value: String::from("2147483648"),
+        }]),
+        output_parameters: Box::new([Parameter {
+            value_type: ParameterType::Int32,
+            value: String::from("0"),
+        }]),
+        stdin: None,
+        expected_error: None,
+        label: None,
+        hidden: false,
+    }]);
+    let submission = Submission {
+        solution,
+        test_cases,
+        unordered_lists: false,
+        files: Box::new([]),
+        checker: None,
+        reference_solution: None,
+        only_ids: None,
+        compiler_flags: Box::new([]),
+        debug: false,
+        float_tolerance: None,
+        toolchain_version: None,
+        metadata: None,
+    };
+    let body = serde_json::to_string(&submission).expect("failed to serialize submission");
+    let request = Builder::new()
+        .header("Content-Type", "application/json")
+        .method(Method::POST)
+        .uri("/submit")
+        .body(Body::from(body))
+        .expect("failed to build request");
+    let expected_status = StatusCode::OK;
+
+    let actual = mozart
+        .oneshot(request)
+        .await
+        .expect("failed to execute oneshot request");
+
+    let actual_status = actual.status();
+    let body_bytes = to_bytes(actual.into_body(), usize::MAX)
+        .await
+        .expect("failed to convert body to bytes");
+
+    let actual_body: SubmissionResult =
+        serde_json::from_slice(&body_bytes).expect("failed to deserialize response body");
+
+    assert_eq!(actual_status, expected_status);
+
+    if let SubmissionResult::Error { code, message } = actual_body {
+        assert_eq!(code, ErrorCode::InvalidParameter);
+        assert!(message.starts_with(
+            "invalid parameter: test case 0, input parameter 0: '2147483648' is not a valid Int32"
+        ));
+    } else {
+        panic!("response body was not of error variant");
+    }
+}
+
+#[tokio::test]
+async fn crash_after_pass() {
+    let mozart = app(AppState::new(Config {
+        python_modules: Box::new([String::from("os")]),
+        ..Config::default()
+    }));
+    let solution = [
+        "import os",
+        "",
+        "def solution(x: int) -> int:",
+        "    if x == 2:",
+        "        os._exit(1)",
+        "    return x",
+    ]
+    .join("\n");
+    // exiting the process directly bypasses the exception handling and the flushing of buffered output
+    let test_cases = Box::new([
+        TestCase {
+            id: 0,
+            input_parameters: Box::new([Parameter {
+                value_type: ParameterType::Int,
+                value: String::from("1"),
+            }]),
+            output_parameters: Box::new([Parameter {
+                value_type: ParameterType::Int,
+                value: String::from("1"),
+            }]),
+            stdin: None,
+            expected_error: None,
+            label: None,
+            hidden: false,
+        },
+        TestCase {
+            id: 1,
+            input_parameters: Box::new([Parameter {
+                value_type: ParameterType::Int,
+                value: String::from("2"),
+            }]),
+            output_parameters: Box::new([Parameter {
+                value_type: ParameterType::Int,
+                value: String::from("2"),
+            }]),
+            stdin: None,
+            expected_error: None,
+            label: None,
+            hidden: false,
+        },
+    ]);
+    let submission = Submission {
+        solution,
+        test_cases,
+        unordered_lists: false,
+        files: Box::new([]),
+        checker: None,
+        reference_solution: None,
+        only_ids: None,
+        compiler_flags: Box::new([]),
+        debug: false,
+        float_tolerance: None,
+        toolchain_version: None,
+        metadata: None,
+    };
+    let body = serde_json::to_string(&submission).expect("failed to serialize submission");
+    let request = Builder::new()
+        .header("Content-Type", "application/json")
+        .method(Method::POST)
+        .uri("/submit")
+        .body(Body::from(body))
+        .expect("failed to build request");
+    let expected_status = StatusCode::OK;
+    let expected_body = SubmissionResult::Failure(Box::new([
+        TestCaseResult {
+            id: 0,
+            test_result: TestResult::Pass,
+            label: None,
+        },
+        TestCaseResult {
+            id: 1,
+            test_result: TestResult::Failure(TestCaseFailureReason::RuntimeError(String::from(
+                "the process terminated abnormally (exit status: 1)",
+            ))),
+            label: None,
+        },
+    ]));
+
+    let actual = mozart
+        .oneshot(request)
+        .await
+        .expect("failed to execute oneshot request");
+
+    let actual_status = actual.status();
+    let body_bytes = to_bytes(actual.into_body(), usize::MAX)
+        .await
+        .expect("failed to convert body to bytes");
+
+    let actual_body: SubmissionResult =
+        serde_json::from_slice(&body_bytes).expect("failed to deserialize response body");
+
+    assert_eq!(actual_status, expected_status);
+    assert_eq!(actual_body, expected_body);
+}
+
+#[tokio::test]
+async fn hard_exit_mid_run() {
+    let mozart = app(AppState::new(Config {
+        python_modules: Box::new([String::from("os")]),
+        ..Config::default()
+    }));
+    let solution = [
+        "import os",
+        "",
+        "def solution(x: int) -> int:",
+        "    if x == 2:",
+        "        os._exit(1)",
+        "    return x",
+    ]
+    .join("\n");
+    // exiting the process directly skips every handler, so the third test case is never started
+    let test_cases = Box::new([
+        TestCase {
+            id: 0,
+            input_parameters: Box::new([Parameter {
+                value_type: ParameterType::Int,
+                value: String::from("1"),
+            }]),
+            output_parameters: Box::new([Parameter {
+                value_type: ParameterType::Int,
+                value: String::from("1"),
+            }]),
+            stdin: None,
+            expected_error: None,
+            label: None,
+            hidden: false,
+        },
+        TestCase {
+            id: 1,
+            input_parameters: Box::new([Parameter {
+                value_type: ParameterType::Int,
+                value: String::from("2"),
+            }]),
+            output_parameters: Box::new([Parameter {
+                value_type: ParameterType::Int,
+                value: String::from("2"),
+            }]),
+            stdin: None,
+            expected_error: None,
+            label: None,
+            hidden: false,
+        },
+        TestCase {
+            id: 2,
+            input_parameters: Box::new([Parameter {
+                value_type: ParameterType::Int,
+                value: String::from("3"),
+            }]),
+            output_parameters: Box::new([Parameter {
+                value_type: ParameterType::Int,
+                value: String::from("3"),
+            }]),
+            stdin: None,
+            expected_error: None,
+            label: None,
+            hidden: false,
+        },
+    ]);
+    let submission = Submission {
+        solution,
+        test_cases,
+        unordered_lists: false,
+        files: Box::new([]),
+        checker: None,
+        reference_solution: None,
+        only_ids: None,
+        compiler_flags: Box::new([]),
+        debug: false,
+        float_tolerance: None,
+        toolchain_version: None,
+        metadata: None,
+    };
+    let body = serde_json::to_string(&submission).expect("failed to serialize submission");
+    let request = Builder::new()
+        .header("Content-Type", "application/json")
+        .method(Method::POST)
+        .uri("/submit")
+        .body(Body::from(body))
+        .expect("failed to build request");
+    let expected_status = StatusCode::OK;
+    let expected_body = SubmissionResult::Failure(Box::new([
+        TestCaseResult {
+            id: 0,
+            test_result: TestResult::Pass,
+            label: None,
+        },
+        TestCaseResult {
+            id: 1,
+            test_result: TestResult::Failure(TestCaseFailureReason::RuntimeError(String::from(
+                "the process terminated abnormally (exit status: 1)",
+            ))),
+            label: None,
+        },
+        TestCaseResult {
+            id: 2,
+            test_result: TestResult::Unknown,
+            label: None,
+        },
+    ]));
+
+    let actual = mozart
+        .oneshot(request)
+        .await
+        .expect("failed to execute oneshot request");
+
+    let actual_status = actual.status();
+    let body_bytes = to_bytes(actual.into_body(), usize::MAX)
+        .await
+        .expect("failed to convert body to bytes");
+
+    let actual_body: SubmissionResult =
+        serde_json::from_slice(&body_bytes).expect("failed to deserialize response body");
+
+    assert_eq!(actual_status, expected_status);
+    assert_eq!(actual_body, expected_body);
+}
+
+#[tokio::test]
+async fn allowed_import() {
+    let mozart = app(AppState::default());
+    let solution = [
+        "from heapq import nlargest",
+        "",
+        "def solution(x: int) -> int:",
+        "    return nlargest(1, [x])[0]",
+    ]
+    .join("\n");
+    let test_cases = Box::new([TestCase {
+        id: 0,
+        input_parameters: Box::new([Parameter {
+            value_type: ParameterType::Int,
+            value: String::from("2"),
+        }]),
+        output_parameters: Box::new([Parameter {
+            value_type: ParameterType::Int,
+            value: String::from("2"),
+        }]),
+        stdin: None,
+        expected_error: None,
+        label: None,
+        hidden: false,
+    }]);
+    let submission = Submission {
+        solution,
+        test_cases,
+        unordered_lists: false,
+        files: Box::new([]),
+        checker: None,
+        reference_solution: None,
+        only_ids: None,
+        compiler_flags: Box::new([]),
+        debug: false,
+        float_tolerance: None,
+        toolchain_version: None,
+        metadata: None,
+    };
+    let body = serde_json::to_string(&submission).expect("failed to serialize submission");
+    let request = Builder::new()
+        .header("Content-Type", "application/json")
+        .method(Method::POST)
+        .uri("/submit")
+        .body(Body::from(body))
+        .expect("failed to build request");
+    let expected_status = StatusCode::OK;
+    let expected_body = SubmissionResult::Pass;
+
+    let actual = mozart
+        .oneshot(request)
+        .await
+        .expect("failed to execute oneshot request");
+
+    let actual_status = actual.status();
+    let body_bytes = to_bytes(actual.into_body(), usize::MAX)
+        .await
+        .expect("failed to convert body to bytes");
+
+    let actual_body: SubmissionResult =
+        serde_json::from_slice(&body_bytes).expect("failed to deserialize response body");
+
+    assert_eq!(actual_status, expected_status);
+    assert_eq!(actual_body, expected_body);
+}
+
+#[tokio::test]
+async fn disallowed_import() {
+    let mozart = app(AppState::default());
+    let solution = [
+        "import os",
+        "",
+        "def solution(x: int) -> int:",
+        "    return x",
+    ]
+    .join("\n");
+    let test_cases = Box::new([TestCase {
+        id: 0,
+        input_parameters: Box::new([Parameter {
+            value_type: ParameterType::Int,
+            value: String::from("2"),
+        }]),
+        output_parameters: Box::new([Parameter {
+            value_type: ParameterType::Int,
+            value: String::from("2"),
+        }]),
+        stdin: None,
+        expected_error: None,
+        label: None,
+        hidden: false,
+    }]);
+    let submission = Submission {
+        solution,
+        test_cases,
+        unordered_lists: false,
+        files: Box::new([]),
+        checker: None,
+        reference_solution: None,
+        only_ids: None,
+        compiler_flags: Box::new([]),
+        debug: false,
+        float_tolerance: None,
+        toolchain_version: None,
+        metadata: None,
+    };
+    let body = serde_json::to_string(&submission).expect("failed to serialize submission");
+    let request = Builder::new()
+        .header("Content-Type", "application/json")
+        .method(Method::POST)
+        .uri("/submit")
+        .body(Body::from(body))
+        .expect("failed to build request");
+    let expected_status = StatusCode::OK;
+
+    let actual = mozart
+        .oneshot(request)
+        .await
+        .expect("failed to execute oneshot request");
+
+    let actual_status = actual.status();
+    let body_bytes = to_bytes(actual.into_body(), usize::MAX)
+        .await
+        .expect("failed to convert body to bytes");
+
+    let actual_body: SubmissionResult =
+        serde_json::from_slice(&body_bytes).expect("failed to deserialize response body");
+
+    assert_eq!(actual_status, expected_status);
+
+    if let SubmissionResult::Error { code, message } = actual_body {
+        assert_eq!(code, ErrorCode::Execution);
+        assert!(message.contains("importing 'os' is not allowed"));
+    } else {
+        panic!("response body was not of error variant");
+    }
+}
+
+#[tokio::test]
+async fn disallowed_import_at_runtime() {
+    let mozart = app(AppState::default());
+    let solution = [
+        "def solution(x: int) -> int:",
+        "    return __import__(\"subprocess\").call([\"true\"])",
+    ]
+    .join("\n");
+    let test_cases = Box::new([TestCase {
+        id: 0,
+        input_parameters: Box::new([Parameter {
+            value_type: ParameterType::Int,
+            value: String::from("2"),
+        }]),
+        output_parameters: Box::new([Parameter {
+            value_type: ParameterType::Int,
+            value: String::from("2"),
+        }]),
+        stdin: None,
+        expected_error: None,
+        label: None,
+        hidden: false,
+    }]);
+    let submission = Submission {
+        solution,
+        test_cases,
+        unordered_lists: false,
+        files: Box::new([]),
+        checker: None,
+        reference_solution: None,
+        only_ids: None,
+        compiler_flags: Box::new([]),
+        debug: false,
+        float_tolerance: None,
+        toolchain_version: None,
+        metadata: None,
+    };
+    let body = serde_json::to_string(&submission).expect("failed to serialize submission");
+    let request = Builder::new()
+        .header("Content-Type", "application/json")
+        .method(Method::POST)
+        .uri("/submit")
+        .body(Body::from(body))
+        .expect("failed to build request");
+    let expected_status = StatusCode::OK;
+    let expected_body = SubmissionResult::Failure(Box::new([TestCaseResult {
+        id: 0,
+        test_result: TestResult::Failure(TestCaseFailureReason::RuntimeError(
+            [
+                "Traceback (most recent call last):",
+                "  File \"solution.py\", line 2, in solution",
+                "    return __import__(\"subprocess\").call([\"true\"])",
+                "ImportError: importing 'subprocess' is not allowed",
+            ]
+            .join("\n"),
+        )),
+        label: None,
+    }]));
+
+    let actual = mozart
+        .oneshot(request)
+        .await
+        .expect("failed to execute oneshot request");
+
+    let actual_status = actual.status();
+    let body_bytes = to_bytes(actual.into_body(), usize::MAX)
+        .await
+        .expect("failed to convert body to bytes");
+
+    let actual_body: SubmissionResult =
+        serde_json::from_slice(&body_bytes).expect("failed to deserialize response body");
+
+    assert_eq!(actual_status, expected_status);
+    assert_eq!(actual_body, expected_body);
+}
+
+#[tokio::test]
+async fn import_with_supplied_globals() {
+    let mozart = app(AppState::default());
+    // the globals passed to the import do not tell who is importing
+    let solution = [
+        "def solution(x: int) -> int:",
+        "    return __import__(\"os\", {}).getpid()",
+    ]
+    .join("\n");
+    let test_cases = Box::new([TestCase {
+        id: 0,
+        input_parameters: Box::new([Parameter {
+            value_type: ParameterType::Int,
+            value: String::from("2"),
+        }]),
+        output_parameters: Box::new([Parameter {
+            value_type: ParameterType::Int,
+            value: String::from("2"),
+        }]),
+        stdin: None,
+        expected_error: None,
+        label: None,
+        hidden: false,
+    }]);
+    let submission = Submission {
+        solution,
+        test_cases,
+        unordered_lists: false,
+        files: Box::new([]),
+        checker: None,
+        reference_solution: None,
+        only_ids: None,
+        compiler_flags: Box::new([]),
+        debug: false,
+        float_tolerance: None,
+        toolchain_version: None,
+        metadata: None,
+    };
+    let body = serde_json::to_string(&submission).expect("failed to serialize submission");
+    let request = Builder::new()
+        .header("Content-Type", "application/json")
+        .method(Method::POST)
+        .uri("/submit")
+        .body(Body::from(body))
+        .expect("failed to build request");
+    let expected_status = StatusCode::OK;
+    let actual = mozart
+        .oneshot(request)
+        .await
+        .expect("failed to execute oneshot request");
+
+    let actual_status = actual.status();
+    let body_bytes = to_bytes(actual.into_body(), usize::MAX)
+        .await
+        .expect("failed to convert body to bytes");
+
+    let actual_body: SubmissionResult =
+        serde_json::from_slice(&body_bytes).expect("failed to deserialize response body");
+
+    assert_eq!(actual_status, expected_status);
+
+    if let SubmissionResult::Failure(test_case_results) = actual_body {
+        assert!(matches!(
+            &test_case_results[0].test_result,
+            TestResult::Failure(TestCaseFailureReason::RuntimeError(error))
+                if error.contains("ImportError: importing 'os' is not allowed")
+        ));
+    } else {
+        panic!("response body was not of failure variant");
+    }
+}
+
+#[tokio::test]
+async fn default_modules() {
+    let mozart = app(AppState::default());
+    let solution = [
+        "from collections import deque",
+        "from typing import List",
+        "",
+        "def solution(x: int) -> int:",
+        "    queue: List[int] = [x]",
+        "    return deque(queue).popleft()",
+    ]
+    .join("\n");
+    let test_cases = Box::new([TestCase {
+        id: 0,
+        input_parameters: Box::new([Parameter {
+            value_type: ParameterType::Int,
+            value: String::from("2"),
+        }]),
+        output_parameters: Box::new([Parameter {
+            value_type: ParameterType::Int,
+            value: String::from("2"),
+        }]),
+        stdin: None,
+        expected_error: None,
+        label: None,
+        hidden: false,
+    }]);
+    let submission = Submission {
+        solution,
+        test_cases,
+        unordered_lists: false,
+        files: Box::new([]),
+        checker: None,
+        reference_solution: None,
+        only_ids: None,
+        compiler_flags: Box::new([]),
+        debug: false,
+        float_tolerance: None,
+        toolchain_version: None,
+        metadata: None,
+    };
+    let body = serde_json::to_string(&submission).expect("failed to serialize submission");
+    let request = Builder::new()
+        .header("Content-Type", "application/json")
+        .method(Method::POST)
+        .uri("/submit")
+        .body(Body::from(body))
+        .expect("failed to build request");
+    let expected_status = StatusCode::OK;
+    let expected_body = SubmissionResult::Pass;
+
+    let actual = mozart
+        .oneshot(request)
+        .await
+        .expect("failed to execute oneshot request");
+
+    let actual_status = actual.status();
+    let body_bytes = to_bytes(actual.into_body(), usize::MAX)
+        .await
+        .expect("failed to convert body to bytes");
+
+    let actual_body: SubmissionResult =
+        serde_json::from_slice(&body_bytes).expect("failed to deserialize response body");
+
+    assert_eq!(actual_status, expected_status);
+    assert_eq!(actual_body, expected_body);
+}
+
+#[tokio::test]
+async fn import_hook_internals_are_hidden() {
+    let mozart = app(AppState::default());
+    let solution = [
+        "def solution(x: int) -> int:",
+        "    hook_globals = __import__.__globals__",
+        "    if \"original_import\" in hook_globals or \"os\" in hook_globals or \"sys\" in hook_globals:",
+        "        return -1",
+        "    return __import__.__kwdefaults__[\"internals\"][0](\"os\").getpid()",
+    ]
+    .join("\n");
+    let test_cases = Box::new([TestCase {
+        id: 0,
+        input_parameters: Box::new([Parameter {
+            value_type: ParameterType::Int,
+            value: String::from("2"),
+        }]),
+        output_parameters: Box::new([Parameter {
+            value_type: ParameterType::Int,
+            value: String::from("2"),
+        }]),
+        stdin: None,
+        expected_error: None,
+        label: None,
+        hidden: false,
+    }]);
+    let submission = Submission {
+        solution,
+        test_cases,
+        unordered_lists: false,
+        files: Box::new([]),
+        checker: None,
+        reference_solution: None,
+        only_ids: None,
+        compiler_flags: Box::new([]),
+        debug: false,
+        float_tolerance: None,
+        toolchain_version: None,
+        metadata: None,
+    };
+    let body = serde_json::to_string(&submission).expect("failed to serialize submission");
+    let request = Builder::new()
+        .header("Content-Type", "application/json")
+        .method(Method::POST)
+        .uri("/submit")
+        .body(Body::from(body))
+        .expect("failed to build request");
+    let expected_status = StatusCode::OK;
+    let actual = mozart
+        .oneshot(request)
+        .await
+        .expect("failed to execute oneshot request");
+
+    let actual_status = actual.status();
+    let body_bytes = to_bytes(actual.into_body(), usize::MAX)
+        .await
+        .expect("failed to convert body to bytes");
+
+    let actual_body: SubmissionResult =
+        serde_json::from_slice(&body_bytes).expect("failed to deserialize response body");
+
+    assert_eq!(actual_status, expected_status);
+
+    if let SubmissionResult::Failure(test_case_results) = actual_body {
+        assert!(matches!(
+            &test_case_results[0].test_result,
+            TestResult::Failure(TestCaseFailureReason::RuntimeError(error))
+                if error.contains("RuntimeError: accessing '__kwdefaults__' is not allowed")
+        ));
+    } else {
+        panic!("response body was not of failure variant");
+    }
+}
+
+#[tokio::test]
+async fn traceback_frames_are_hidden() {
+    let mozart = app(AppState::default());
+    // the frames calling the solution would lead to the globals of the test code
+    let solution = [
+        "def solution(x: int) -> int:",
+        "    try:",
+        "        raise ValueError()",
+        "    except ValueError as error:",
+        "        return error.__traceback__.tb_frame.f_back.f_globals[\"os\"].getpid()",
+    ]
+    .join("\n");
+    let test_cases = Box::new([TestCase {
+        id: 0,
+        input_parameters: Box::new([Parameter {
+            value_type: ParameterType::Int,
+            value: String::from("2"),
+        }]),
+        output_parameters: Box::new([Parameter {
+            value_type: ParameterType::Int,
+            value: String::from("2"),
+        }]),
+        stdin: None,
+        expected_error: None,
+        label: None,
+        hidden: false,
+    }]);
+    let submission = Submission {
+        solution,
+        test_cases,
+        unordered_lists: false,
+        files: Box::new([]),
+        checker: None,
+        reference_solution: None,
+        only_ids: None,
+        compiler_flags: Box::new([]),
+        debug: false,
+        float_tolerance: None,
+        toolchain_version: None,
+        metadata: None,
+    };
+    let body = serde_json::to_string(&submission).expect("failed to serialize submission");
+    let request = Builder::new()
+        .header("Content-Type", "application/json")
+        .method(Method::POST)
+        .uri("/submit")
+        .body(Body::from(body))
+        .expect("failed to build request");
+    let expected_status = StatusCode::OK;
+    let actual = mozart
+        .oneshot(request)
+        .await
+        .expect("failed to execute oneshot request");
+
+    let actual_status = actual.status();
+    let body_bytes = to_bytes(actual.into_body(), usize::MAX)
+        .await
+        .expect("failed to convert body to bytes");
+
+    let actual_body: SubmissionResult =
+        serde_json::from_slice(&body_bytes).expect("failed to deserialize response body");
+
+    assert_eq!(actual_status, expected_status);
+
+    if let SubmissionResult::Failure(test_case_results) = actual_body {
+        assert!(matches!(
+            &test_case_results[0].test_result,
+            TestResult::Failure(TestCaseFailureReason::RuntimeError(error))
+                if error.contains("RuntimeError: accessing 'tb_frame' is not allowed")
+        ));
+    } else {
+        panic!("response body was not of failure variant");
+    }
+}
+
+// the restricted modules can reach os regardless of the import hook, so only the sandbox contains what they do with it
+#[cfg(not(feature = "no-sandbox"))]
+#[tokio::test]
+async fn loader_globals_stay_in_jail() {
+    // the globals of the loader of the solution contain the os module as _os, which the audit hook does not cover
+    let solution = [
+        "def solution() -> bool:",
+        "    os = type(__loader__).__init__.__globals__[\"_os\"]",
+        "    try:",
+        "        os.mkdir(\"/usr/escaped\")",
+        "        return False",
+        "    except OSError:",
+        "        pass",
+        "    return os.getuid() != 0 and \"etc\" not in os.listdir(\"/\")",
+    ]
+    .join("\n");
+    let test_cases = Box::new([TestCase {
+        id: 0,
+        input_parameters: Box::new([]),
+        output_parameters: Box::new([Parameter {
+            value_type: ParameterType::Bool,
+            value: String::from("true"),
+        }]),
+        stdin: None,
+        expected_error: None,
+        label: None,
+        hidden: false,
+    }]);
+    let submission = Submission {
+        solution,
+        test_cases,
+        unordered_lists: false,
+        files: Box::new([]),
+        checker: None,
+        reference_solution: None,
+        only_ids: None,
+        compiler_flags: Box::new([]),
+        debug: false,
+        float_tolerance: None,
+        toolchain_version: None,
+        metadata: None,
+    };
+
+    let actual = check_submission(submission, AppState::default()).await;
+
+    assert_eq!(actual, SubmissionResult::Pass);
+}
+
+// only the sandbox contains this as well
+#[cfg(not(feature = "no-sandbox"))]
+#[tokio::test]
+async fn subclass_globals_stay_in_jail() {
+    // a subclass defined by the os module leads to its globals, which the audit hook does not cover
+    let solution = [
+        "def solution() -> bool:",
+        "    wrap_close = next(cls for cls in object.__subclasses__() if cls.__name__ == \"_wrap_close\")",
+        "    os = wrap_close.__init__.__globals__",
+        "    try:",
+        "        os[\"mkdir\"](\"/usr/escaped\")",
+        "        return False",
+        "    except OSError:",
+        "        pass",
+        "    return os[\"getuid\"]() != 0 and \"etc\" not in os[\"listdir\"](\"/\")",
+    ]
+    .join("\n");
+    let test_cases = Box::new([TestCase {
+        id: 0,
+        input_parameters: Box::new([]),
+        output_parameters: Box::new([Parameter {
+            value_type: ParameterType::Bool,
+            value: String::from("true"),
+        }]),
+        stdin: None,
+        expected_error: None,
+        label: None,
+        hidden: false,
+    }]);
+    let submission = Submission {
+        solution,
+        test_cases,
+        unordered_lists: false,
+        files: Box::new([]),
+        checker: None,
+        reference_solution: None,
+        only_ids: None,
+        compiler_flags: Box::new([]),
+        debug: false,
+        float_tolerance: None,
+        toolchain_version: None,
+        metadata: None,
+    };
+
+    let actual = check_submission(submission, AppState::default()).await;
+
+    assert_eq!(actual, SubmissionResult::Pass);
+}
+
+#[tokio::test]
+async fn helper_module() {
+    let mozart = app(AppState::default());
+    let solution = [
+        "from helper import double",
+        "",
+        "def solution(x: int) -> int:",
+        "    return double(x)",
+    ]
+    .join("\n");
+    let test_cases = Box::new([TestCase {
+        id: 0,
+        input_parameters: Box::new([Parameter {
+            value_type: ParameterType::Int,
+            value: String::from("2"),
+        }]),
+        output_parameters: Box::new([Parameter {
+            value_type: ParameterType::Int,
+            value: String::from("4"),
+        }]),
+        stdin: None,
+        expected_error: None,
+        label: None,
+        hidden: false,
+    }]);
+    let submission = Submission {
+        solution,
+        test_cases,
+        unordered_lists: false,
+        files: Box::new([SourceFile {
+            name: String::from("helper.py"),
+            contents: ["def double(x: int) -> int:", "    return x + x"].join("\n"),
+        }]),
+        checker: None,
+        reference_solution: None,
+        only_ids: None,
+        compiler_flags: Box::new([]),
+        debug: false,
+        float_tolerance: None,
+        toolchain_version: None,
+        metadata: None,
+    };
+    let body = serde_json::to_string(&submission).expect("failed to serialize submission");
+    let request = Builder::new()
+        .header("Content-Type", "application/json")
+        .method(Method::POST)
+        .uri("/submit")
+        .body(Body::from(body))
+        .expect("failed to build request");
+    let expected_status = StatusCode::OK;
+    let expected_body = SubmissionResult::Pass;
+
+    let actual = mozart
+        .oneshot(request)
+        .await
+        .expect("failed to execute oneshot request");
+
+    let actual_status = actual.status();
+    let body_bytes = to_bytes(actual.into_body(), usize::MAX)
+        .await
+        .expect("failed to convert body to bytes");
+
+    let actual_body: SubmissionResult =
+        serde_json::from_slice(&body_bytes).expect("failed to deserialize response body");
+
+    assert_eq!(actual_status, expected_status);
+    assert_eq!(actual_body, expected_body);
+}
+
+#[tokio::test]
+async fn helper_module_importing_disallowed_module() {
+    let mozart = app(AppState::default());
+    let solution = [
+        "from helper import double",
+        "",
+        "def solution(x: int) -> int:",
+        "    return double(x)",
+    ]
+    .join("\n");
+    let test_cases = Box::new([TestCase {
+        id: 0,
+        input_parameters: Box::new([Parameter {
+            value_type: ParameterType::Int,
+            value: String::from("2"),
+        }]),
+        output_parameters: Box::new([Parameter {
+            value_type: ParameterType::Int,
+            value: String::from("4"),
+        }]),
+        stdin: None,
+        expected_error: None,
+        label: None,
+        hidden: false,
+    }]);
+    let submission = Submission {
+        solution,
+        test_cases,
+        unordered_lists: false,
+        files: Box::new([SourceFile {
+            name: String::from("helper.py"),
+            contents: [
+                "import os",
+                "",
+                "def double(x: int) -> int:",
+                "    return x + x",
+            ]
+            .join("\n"),
+        }]),
+        checker: None,
+        reference_solution: None,
+        only_ids: None,
+        compiler_flags: Box::new([]),
+        debug: false,
+        float_tolerance: None,
+        toolchain_version: None,
+        metadata: None,
+    };
+    let body = serde_json::to_string(&submission).expect("failed to serialize submission");
+    let request = Builder::new()
+        .header("Content-Type", "application/json")
+        .method(Method::POST)
+        .uri("/submit")
+        .body(Body::from(body))
+        .expect("failed to build request");
+    let expected_status = StatusCode::OK;
+
+    let actual = mozart
+        .oneshot(request)
+        .await
+        .expect("failed to execute oneshot request");
+
+    let actual_status = actual.status();
+    let body_bytes = to_bytes(actual.into_body(), usize::MAX)
+        .await
+        .expect("failed to convert body to bytes");
+
+    let actual_body: SubmissionResult =
+        serde_json::from_slice(&body_bytes).expect("failed to deserialize response body");
+
+    assert_eq!(actual_status, expected_status);
+
+    if let SubmissionResult::Error { code, message } = actual_body {
+        assert_eq!(code, ErrorCode::Execution);
+        assert!(message.contains("importing 'os' is not allowed"));
+    } else {
+        panic!("response body was not of error variant");
+    }
+}
+
+#[tokio::test]
+async fn file_name_outside_working_directory() {
+    let mozart = app(AppState::default());
+    let solution = ["def solution(x: int) -> int:", "    return x + x"].join("\n");
+    let test_cases = Box::new([TestCase {
+        id: 0,
+        input_parameters: Box::new([Parameter {
+            value_type: ParameterType::Int,
+            value: String::from("2"),
+        }]),
+        output_parameters: Box::new([Parameter {
+            value_type: ParameterType::Int,
+            value: String::from("4"),
+        }]),
+        stdin: None,
+        expected_error: None,
+        label: None,
+        hidden: false,
+    }]);
+    let submission = Submission {
+        solution,
+        test_cases,
+        unordered_lists: false,
+        files: Box::new([SourceFile {
+            name: String::from("../helper.py"),
+            contents: ["x = 1"].join("\n"),
+        }]),
+        checker: None,
+        reference_solution: None,
+        only_ids: None,
+        compiler_flags: Box::new([]),
+        debug: false,
+        float_tolerance: None,
+        toolchain_version: None,
+        metadata: None,
+    };
+    let body = serde_json::to_string(&submission).expect("failed to serialize submission");
+    let request = Builder::new()
+        .header("Content-Type", "application/json")
+        .method(Method::POST)
+        .uri("/submit")
+        .body(Body::from(body))
+        .expect("failed to build request");
+    let expected_status = StatusCode::OK;
+
+    let actual = mozart
+        .oneshot(request)
+        .await
+        .expect("failed to execute oneshot request");
+
+    let actual_status = actual.status();
+    let body_bytes = to_bytes(actual.into_body(), usize::MAX)
+        .await
+        .expect("failed to convert body to bytes");
+
+    let actual_body: SubmissionResult =
+        serde_json::from_slice(&body_bytes).expect("failed to deserialize response body");
+
+    assert_eq!(actual_status, expected_status);
+
+    if let SubmissionResult::Error { code, .. } = actual_body {
+        assert_eq!(code, ErrorCode::InvalidFileName);
+    } else {
+        panic!("response body was not of error variant");
+    }
+}
+
+#[tokio::test]
+async fn file_name_of_test_runner() {
+    let mozart = app(AppState::default());
+    let solution = ["def solution(x: int) -> int:", "    return x + x"].join("\n");
+    let test_cases = Box::new([TestCase {
+        id: 0,
+        input_parameters: Box::new([Parameter {
+            value_type: ParameterType::Int,
+            value: String::from("2"),
+        }]),
+        output_parameters: Box::new([Parameter {
+            value_type: ParameterType::Int,
+            value: String::from("4"),
+        }]),
+        stdin: None,
+        expected_error: None,
+        label: None,
+        hidden: false,
+    }]);
+    let submission = Submission {
+        solution,
+        test_cases,
+        unordered_lists: false,
+        files: Box::new([SourceFile {
+            name: String::from("test_runner.py"),
+            contents: ["x = 1"].join("\n"),
+        }]),
+        checker: None,
+        reference_solution: None,
+        only_ids: None,
+        compiler_flags: Box::new([]),
+        debug: false,
+        float_tolerance: None,
+        toolchain_version: None,
+        metadata: None,
+    };
+    let body = serde_json::to_string(&submission).expect("failed to serialize submission");
+    let request = Builder::new()
+        .header("Content-Type", "application/json")
+        .method(Method::POST)
+        .uri("/submit")
+        .body(Body::from(body))
+        .expect("failed to build request");
+    let expected_status = StatusCode::OK;
+
+    let actual = mozart
+        .oneshot(request)
+        .await
+        .expect("failed to execute oneshot request");
+
+    let actual_status = actual.status();
+    let body_bytes = to_bytes(actual.into_body(), usize::MAX)
+        .await
+        .expect("failed to convert body to bytes");
+
+    let actual_body: SubmissionResult =
+        serde_json::from_slice(&body_bytes).expect("failed to deserialize response body");
+
+    assert_eq!(actual_status, expected_status);
+
+    if let SubmissionResult::Error { code, .. } = actual_body {
+        assert_eq!(code, ErrorCode::InvalidFileName);
+    } else {
+        panic!("response body was not of error variant");
+    }
+}
+
+#[tokio::test]
+async fn file_name_of_jail_directory() {
+    let mozart = app(AppState::default());
+    let solution = ["def solution(x: int) -> int:", "    return x + x"].join("\n");
+    let test_cases = Box::new([TestCase {
+        id: 0,
+        input_parameters: Box::new([Parameter {
+            value_type: ParameterType::Int,
+            value: String::from("2"),
+        }]),
+        output_parameters: Box::new([Parameter {
+            value_type: ParameterType::Int,
+            value: String::from("4"),
+        }]),
+        stdin: None,
+        expected_error: None,
+        label: None,
+        hidden: false,
+    }]);
+    let submission = Submission {
+        solution,
+        test_cases,
+        unordered_lists: false,
+        files: Box::new([SourceFile {
+            name: String::from("lib"),
+            contents: ["x = 1"].join("\n"),
+        }]),
+        checker: None,
+        reference_solution: None,
+        only_ids: None,
+        compiler_flags: Box::new([]),
+        debug: false,
+        float_tolerance: None,
+        toolchain_version: None,
+        metadata: None,
+    };
+    let body = serde_json::to_string(&submission).expect("failed to serialize submission");
+    let request = Builder::new()
+        .header("Content-Type", "application/json")
+        .method(Method::POST)
+        .uri("/submit")
+        .body(Body::from(body))
+        .expect("failed to build request");
+    let expected_status = StatusCode::OK;
+
+    let actual = mozart
+        .oneshot(request)
+        .await
+        .expect("failed to execute oneshot request");
+
+    let actual_status = actual.status();
+    let body_bytes = to_bytes(actual.into_body(), usize::MAX)
+        .await
+        .expect("failed to convert body to bytes");
+
+    let actual_body: SubmissionResult =
+        serde_json::from_slice(&body_bytes).expect("failed to deserialize response body");
+
+    assert_eq!(actual_status, expected_status);
+
+    if let SubmissionResult::Error { code, .. } = actual_body {
+        assert_eq!(code, ErrorCode::InvalidFileName);
+    } else {
+        panic!("response body was not of error variant");
+    }
+}
+
+#[tokio::test]
+async fn empty_solution() {
+    let mozart = app(AppState::default());
+    let solution = String::new();
+    let test_cases = Box::new([TestCase {
+        id: 0,
+        input_parameters: Box::new([Parameter {
+            value_type: ParameterType::Int,
+            value: String::from("2"),
+        }]),
+        output_parameters: Box::new([Parameter {
+            value_type: ParameterType::Int,
+            value: String::from("4"),
+        }]),
+        stdin: None,
+        expected_error: None,
+        label: None,
+        hidden: false,
+    }]);
+    let submission = Submission {
+        solution,
+        test_cases,
+        unordered_lists: false,
+        files: Box::new([]),
+        checker: None,
+        reference_solution: None,
+        only_ids: None,
+        compiler_flags: Box::new([]),
+        debug: false,
+        float_tolerance: None,
+        toolchain_version: None,
+        metadata: None,
+    };
+    let body = serde_json::to_string(&submission).expect("failed to serialize submission");
+    let request = Builder::new()
+        .header("Content-Type", "application/json")
+        .method(Method::POST)
+        .uri("/submit")
+        .body(Body::from(body))
+        .expect("failed to build request");
+    let expected_status = StatusCode::OK;
+    let expected_body = SubmissionResult::Error {
+        code: ErrorCode::EmptySolution,
+        message: String::from("solution is empty"),
+    };
+
+    let actual = mozart
+        .oneshot(request)
+        .await
+        .expect("failed to execute oneshot request");
+
+    let actual_status = actual.status();
+    let body_bytes = to_bytes(actual.into_body(), usize::MAX)
+        .await
+        .expect("failed to convert body to bytes");
+
+    let actual_body: SubmissionResult =
+        serde_json::from_slice(&body_bytes).expect("failed to deserialize response body");
+
+    assert_eq!(actual_status, expected_status);
+    assert_eq!(actual_body, expected_body);
+}
+
+#[tokio::test]
+async fn whitespace_solution() {
+    let mozart = app(AppState::default());
+    let solution = ["  ", "\t"].join("\n");
+    let test_cases = Box::new([TestCase {
+        id: 0,
+        input_parameters: Box::new([Parameter {
+            value_type: ParameterType::Int,
+            value: String::from("2"),
+        }]),
+        output_parameters: Box::new([Parameter {
+            value_type: ParameterType::Int,
+            value: String::from("4"),
+        }]),
+        stdin: None,
+        expected_error: None,
+        label: None,
+        hidden: false,
+    }]);
+    let submission = Submission {
+        solution,
+        test_cases,
+        unordered_lists: false,
+        files: Box::new([]),
+        checker: None,
+        reference_solution: None,
+        only_ids: None,
+        compiler_flags: Box::new([]),
+        debug: false,
+        float_tolerance: None,
+        toolchain_version: None,
+        metadata: None,
+    };
+    let body = serde_json::to_string(&submission).expect("failed to serialize submission");
+    let request = Builder::new()
+        .header("Content-Type", "application/json")
+        .method(Method::POST)
+        .uri("/submit")
+        .body(Body::from(body))
+        .expect("failed to build request");
+    let expected_status = StatusCode::OK;
+    let expected_body = SubmissionResult::Error {
+        code: ErrorCode::EmptySolution,
+        message: String::from("solution is empty"),
+    };
+
+    let actual = mozart
+        .oneshot(request)
+        .await
+        .expect("failed to execute oneshot request");
+
+    let actual_status = actual.status();
+    let body_bytes = to_bytes(actual.into_body(), usize::MAX)
+        .await
+        .expect("failed to convert body to bytes");
+
+    let actual_body: SubmissionResult =
+        serde_json::from_slice(&body_bytes).expect("failed to deserialize response body");
+
+    assert_eq!(actual_status, expected_status);
+    assert_eq!(actual_body, expected_body);
+}
+
+#[tokio::test]
+async fn huge_wrong_answer_is_truncated() {
+    let mozart = app(AppState::default());
+    let solution = ["def solution(n: int) -> str:", "    return \"a\" * n"].join("\n");
+    let test_cases = Box::new([TestCase {
+        id: 0,
+        input_parameters: Box::new([Parameter {
+            value_type: ParameterType::Int,
+            value: String::from("1000000"),
+        }]),
+        output_parameters: Box::new([Parameter {
+            value_type: ParameterType::String,
+            value: String::from("a"),
+        }]),
+        stdin: None,
+        expected_error: None,
+        label: None,
+        hidden: false,
+    }]);
+    let submission = Submission {
+        solution,
+        test_cases,
+        unordered_lists: false,
+        files: Box::new([]),
+        checker: None,
+        reference_solution: None,
+        only_ids: None,
+        compiler_flags: Box::new([]),
+        debug: false,
+        float_tolerance: None,
+        toolchain_version: None,
+        metadata: None,
+    };
+    let body = serde_json::to_string(&submission).expect("failed to serialize submission");
+    let request = Builder::new()
+        .header("Content-Type", "application/json")
+        .method(Method::POST)
+        .uri("/submit")
+        .body(Body::from(body))
+        .expect("failed to build request");
+    let expected_status = StatusCode::OK;
+    let expected_body = SubmissionResult::Failure(Box::new([TestCaseResult {
+        id: 0,
+        test_result: TestResult::Failure(TestCaseFailureReason::WrongAnswer {
+            input_parameters: Box::new([Parameter {
+                value_type: ParameterType::Int,
+                value: String::from("1000000"),
+            }]),
+            actual: format!("{}...", "a".repeat(1000)),
+            expected: String::from("a"),
+            actual_value: None,
+            expected_value: None,
+        }),
+        label: None,
+    }]));
+
+    let actual = mozart
+        .oneshot(request)
+        .await
+        .expect("failed to execute oneshot request");
+
+    let actual_status = actual.status();
+    let body_bytes = to_bytes(actual.into_body(), usize::MAX)
+        .await
+        .expect("failed to convert body to bytes");
+
+    let actual_body: SubmissionResult =
+        serde_json::from_slice(&body_bytes).expect("failed to deserialize response body");
+
+    assert_eq!(actual_status, expected_status);
+    assert_eq!(actual_body, expected_body);
+}
+
+#[tokio::test]
+async fn unordered_list() {
+    let solution = [
+        "def solution(xs: list[int]) -> list[int]:",
+        "    return sorted(set(xs), reverse=True)",
+    ]
+    .join("\n");
+    // the solution returns the elements in descending order, which is accepted as the order does not matter
+    let test_cases = Box::new([TestCase {
+        id: 0,
+        input_parameters: Box::new([Parameter {
+            value_type: ParameterType::List(Box::new(ParameterType::Int)),
+            value: String::from(r#"["3", "1", "3", "2"]"#),
+        }]),
+        output_parameters: Box::new([Parameter {
+            value_type: ParameterType::List(Box::new(ParameterType::Int)),
+            value: String::from(r#"["1", "2", "3"]"#),
+        }]),
+        stdin: None,
+        expected_error: None,
+        label: None,
+        hidden: false,
+    }]);
+    let submission = Submission {
+        solution,
+        test_cases,
+        unordered_lists: true,
+        files: Box::new([]),
+        checker: None,
+        reference_solution: None,
+        only_ids: None,
+        compiler_flags: Box::new([]),
+        debug: false,
+        float_tolerance: None,
+        toolchain_version: None,
+        metadata: None,
+    };
+
+    let actual = check_submission(submission, AppState::default()).await;
+
+    assert_eq!(actual, SubmissionResult::Pass);
+}
+
+#[tokio::test]
+async fn ordered_list() {
+    let solution = [
+        "def solution(xs: list[int]) -> list[int]:",
+        "    return sorted(set(xs), reverse=True)",
+    ]
+    .join("\n");
+    let test_cases = Box::new([TestCase {
+        id: 0,
+        input_parameters: Box::new([Parameter {
+            value_type: ParameterType::List(Box::new(ParameterType::Int)),
+            value: String::from(r#"["3", "1", "3", "2"]"#),
+        }]),
+        output_parameters: Box::new([Parameter {
+            value_type: ParameterType::List(Box::new(ParameterType::Int)),
+            value: String::from(r#"["1", "2", "3"]"#),
+        }]),
+        stdin: None,
+        expected_error: None,
+        label: None,
+        hidden: false,
+    }]);
+    let submission = Submission {
+        solution,
+        test_cases,
+        unordered_lists: false,
+        files: Box::new([]),
+        checker: None,
+        reference_solution: None,
+        only_ids: None,
+        compiler_flags: Box::new([]),
+        debug: false,
+        float_tolerance: None,
+        toolchain_version: None,
+        metadata: None,
+    };
+
+    let expected = SubmissionResult::Failure(Box::new([TestCaseResult {
+        id: 0,
+        test_result: TestResult::Failure(TestCaseFailureReason::WrongAnswer {
+            input_parameters: Box::new([Parameter {
+                value_type: ParameterType::List(Box::new(ParameterType::Int)),
+                value: String::from(r#"["3", "1", "3", "2"]"#),
+            }]),
+            actual: String::from("[3, 2, 1]"),
+            expected: String::from("[1, 2, 3]"),
+            actual_value: Some(json!([3, 2, 1])),
+            expected_value: Some(json!([1, 2, 3])),
+        }),
+        label: None,
+    }]));
+
+    let actual = check_submission(submission, AppState::default()).await;
+
+    assert_eq!(actual, expected);
+}
+
+#[tokio::test]
+async fn unordered_list_counts_duplicates() {
+    let solution = [
+        "def solution(xs: list[int]) -> list[int]:",
+        "    return sorted(set(xs), reverse=True)",
+    ]
+    .join("\n");
+    // the lists are compared as multisets, so a missing duplicate is still a wrong answer, reported in the order returned
+    let test_cases = Box::new([TestCase {
+        id: 0,
+        input_parameters: Box::new([Parameter {
+            value_type: ParameterType::List(Box::new(ParameterType::Int)),
+            value: String::from(r#"["3", "1", "3", "2"]"#),
+        }]),
+        output_parameters: Box::new([Parameter {
+            value_type: ParameterType::List(Box::new(ParameterType::Int)),
+            value: String::from(r#"["3", "3", "2", "1"]"#),
+        }]),
+        stdin: None,
+        expected_error: None,
+        label: None,
+        hidden: false,
+    }]);
+    let submission = Submission {
+        solution,
+        test_cases,
+        unordered_lists: true,
+        files: Box::new([]),
+        checker: None,
+        reference_solution: None,
+        only_ids: None,
+        compiler_flags: Box::new([]),
+        debug: false,
+        float_tolerance: None,
+        toolchain_version: None,
+        metadata: None,
+    };
+
+    let expected = SubmissionResult::Failure(Box::new([TestCaseResult {
+        id: 0,
+        test_result: TestResult::Failure(TestCaseFailureReason::WrongAnswer {
+            input_parameters: Box::new([Parameter {
+                value_type: ParameterType::List(Box::new(ParameterType::Int)),
+                value: String::from(r#"["3", "1", "3", "2"]"#),
+            }]),
+            actual: String::from("[3, 2, 1]"),
+            expected: String::from("[3, 3, 2, 1]"),
+            actual_value: Some(json!([3, 2, 1])),
+            expected_value: Some(json!([3, 3, 2, 1])),
+        }),
+        label: None,
+    }]));
+
+    let actual = check_submission(submission, AppState::default()).await;
+
+    assert_eq!(actual, expected);
+}
+
+#[tokio::test]
+async fn total_time_header() {
+    let mozart = app(AppState::default());
+    let solution = ["def solution(x: int) -> int:", "    return x + x"].join("\n");
+    let test_cases = Box::new([TestCase {
+        id: 0,
+        input_parameters: Box::new([Parameter {
+            value_type: ParameterType::Int,
+            value: String::from("2"),
+        }]),
+        output_parameters: Box::new([Parameter {
+            value_type: ParameterType::Int,
+            value: String::from("4"),
+        }]),
+        stdin: None,
+        expected_error: None,
+        label: None,
+        hidden: false,
+    }]);
+    let submission = Submission {
+        solution,
+        test_cases,
+        unordered_lists: false,
+        files: Box::new([]),
+        checker: None,
+        reference_solution: None,
+        only_ids: None,
+        compiler_flags: Box::new([]),
+        debug: false,
+        float_tolerance: None,
+        toolchain_version: None,
+        metadata: None,
+    };
+    let body = serde_json::to_string(&submission).expect("failed to serialize submission");
+    let request = Builder::new()
+        .header("Content-Type", "application/json")
+        .method(Method::POST)
+        .uri("/submit")
+        .body(Body::from(body))
+        .expect("failed to build request");
+    let expected_status = StatusCode::OK;
+    let expected_body = SubmissionResult::Pass;
+
+    let actual = mozart
+        .oneshot(request)
+        .await
+        .expect("failed to execute oneshot request");
+
+    let actual_status = actual.status();
+    let actual_total_ms = actual
+        .headers()
+        .get(TOTAL_TIME_HEADER)
+        .and_then(|value| value.to_str().ok())
+        .and_then(|value| value.parse::<u64>().ok());
+    let body_bytes = to_bytes(actual.into_body(), usize::MAX)
+        .await
+        .expect("failed to convert body to bytes");
+
+    let actual_body: SubmissionResult =
+        serde_json::from_slice(&body_bytes).expect("failed to deserialize response body");
+
+    assert_eq!(actual_status, expected_status);
+    assert_eq!(actual_body, expected_body);
+    assert!(actual_total_ms.is_some_and(|total_ms| total_ms > 0));
+}
+
+// the sandbox is what prevents this, so it cannot be tested without it
+#[cfg(not(feature = "no-sandbox"))]
+#[tokio::test]
+async fn read_file_outside_working_directory() {
+    let mozart = app(AppState::default());
+    let solution = [
+        "def solution(x: int) -> str:",
+        "    return open(\"/etc/passwd\").read()",
+    ]
+    .join("\n");
+    let test_cases = Box::new([TestCase {
+        id: 0,
+        input_parameters: Box::new([Parameter {
+            value_type: ParameterType::Int,
+            value: String::from("0"),
+        }]),
+        output_parameters: Box::new([Parameter {
+            value_type: ParameterType::String,
+            value: String::from(""),
+        }]),
+        stdin: None,
+        expected_error: None,
+        label: None,
+        hidden: false,
+    }]);
+    let submission = Submission {
+        solution,
+        test_cases,
+        unordered_lists: false,
+        files: Box::new([]),
+        checker: None,
+        reference_solution: None,
+        only_ids: None,
+        compiler_flags: Box::new([]),
+        debug: false,
+        float_tolerance: None,
+        toolchain_version: None,
+        metadata: None,
+    };
+    let body = serde_json::to_string(&submission).expect("failed to serialize submission");
+    let request = Builder::new()
+        .header("Content-Type", "application/json")
+        .method(Method::POST)
+        .uri("/submit")
+        .body(Body::from(body))
+        .expect("failed to build request");
+    let expected_status = StatusCode::OK;
+    let expected_body = SubmissionResult::Failure(Box::new([TestCaseResult {
+        id: 0,
+        test_result: TestResult::Failure(TestCaseFailureReason::RuntimeError(
+            [
+                "Traceback (most recent call last):",
+                "  File \"solution.py\", line 2, in solution",
+                "    return open(\"/etc/passwd\").read()",
+                "FileNotFoundError: [Errno 2] No such file or directory: '/etc/passwd'",
+            ]
+            .join("\n"),
+        )),
+        label: None,
+    }]));
+
+    let actual = mozart
+        .oneshot(request)
+        .await
+        .expect("failed to execute oneshot request");
+
+    let actual_status = actual.status();
+    let body_bytes = to_bytes(actual.into_body(), usize::MAX)
+        .await
+        .expect("failed to convert body to bytes");
+
+    let actual_body: SubmissionResult =
+        serde_json::from_slice(&body_bytes).expect("failed to deserialize response body");
+
+    assert_eq!(actual_status, expected_status);
+    assert_eq!(actual_body, expected_body);
+}
+
+#[tokio::test]
+async fn values_with_delimiters() {
+    let mozart = app(AppState::default());
+    let solution = [
+        "def solution(x: int) -> str:",
+        "    if x == 2:",
+        r#"        raise ValueError("invalid value, got \"2\"")"#,
+        r#"    return "a, \"b\"\nc""#,
+    ]
+    .join("\n");
+    // commas, quotes and newlines must not break the reporting of the outcome
+    let test_cases = Box::new([
+        TestCase {
+            id: 0,
+            input_parameters: Box::new([Parameter {
+                value_type: ParameterType::Int,
+                value: String::from("1"),
+            }]),
+            output_parameters: Box::new([Parameter {
+                value_type: ParameterType::String,
+                value: String::from("x"),
+            }]),
+            stdin: None,
+            expected_error: None,
+            label: None,
+            hidden: false,
+        },
+        TestCase {
+            id: 1,
+            input_parameters: Box::new([Parameter {
+                value_type: ParameterType::Int,
+                value: String::from("2"),
+            }]),
+            output_parameters: Box::new([Parameter {
+                value_type: ParameterType::String,
+                value: String::from("x"),
+            }]),
+            stdin: None,
+            expected_error: None,
+            label: None,
+            hidden: false,
+        },
+    ]);
+    let submission = Submission {
+        solution,
+        test_cases,
+        unordered_lists: false,
+        files: Box::new([]),
+        checker: None,
+        reference_solution: None,
+        only_ids: None,
+        compiler_flags: Box::new([]),
+        debug: false,
+        float_tolerance: None,
+        toolchain_version: None,
+        metadata: None,
+    };
+    let body = serde_json::to_string(&submission).expect("failed to serialize submission");
+    let request = Builder::new()
+        .header("Content-Type", "application/json")
+        .method(Method::POST)
+        .uri("/submit")
+        .body(Body::from(body))
+        .expect("failed to build request");
+    let expected_status = StatusCode::OK;
+    let expected_body = SubmissionResult::Failure(Box::new([
+        TestCaseResult {
+            id: 0,
+            test_result: TestResult::Failure(TestCaseFailureReason::WrongAnswer {
+                input_parameters: Box::new([Parameter {
+                    value_type: ParameterType::Int,
+                    value: String::from("1"),
+                }]),
+                actual: String::from("a, \"b\"\nc"),
+                expected: String::from("x"),
+                actual_value: None,
+                expected_value: None,
+            }),
+            label: None,
+        },
+        TestCaseResult {
+            id: 1,
+            test_result: TestResult::Failure(TestCaseFailureReason::RuntimeError(
+                [
+                    "Traceback (most recent call last):",
+                    "  File \"solution.py\", line 3, in solution",
+                    "    raise ValueError(\"invalid value, got \\\"2\\\"\")",
+                    "ValueError: invalid value, got \"2\"",
+                ]
+                .join("\n"),
+            )),
+            label: None,
+        },
+    ]));
+
+    let actual = mozart
+        .oneshot(request)
+        .await
+        .expect("failed to execute oneshot request");
+
+    let actual_status = actual.status();
+    let body_bytes = to_bytes(actual.into_body(), usize::MAX)
+        .await
+        .expect("failed to convert body to bytes");
+
+    let actual_body: SubmissionResult =
+        serde_json::from_slice(&body_bytes).expect("failed to deserialize response body");
+
+    assert_eq!(actual_status, expected_status);
+    assert_eq!(actual_body, expected_body);
+}
+
+#[tokio::test]
+async fn custom_checker() {
+    let mozart = app(AppState::default());
+    let solution = ["def solution(n: int) -> str:", "    return \"a\" * n"].join("\n");
+    // any value with the length of the expected value is accepted
+    let test_cases = Box::new([
+        TestCase {
+            id: 0,
+            input_parameters: Box::new([Parameter {
+                value_type: ParameterType::Int,
+                value: String::from("3"),
+            }]),
+            output_parameters: Box::new([Parameter {
+                value_type: ParameterType::String,
+                value: String::from("xyz"),
+            }]),
+            stdin: None,
+            expected_error: None,
+            label: None,
+            hidden: false,
+        },
+        TestCase {
+            id: 1,
+            input_parameters: Box::new([Parameter {
+                value_type: ParameterType::Int,
+                value: String::from("2"),
+            }]),
+            output_parameters: Box::new([Parameter {
+                value_type: ParameterType::String,
+                value: String::from("xyz"),
+            }]),
+            stdin: None,
+            expected_error: None,
+            label: None,
+            hidden: false,
+        },
+    ]);
+    let submission = Submission {
+        solution,
+        test_cases,
+        unordered_lists: false,
+        files: Box::new([]),
+        checker: Some(
+            [
+                "def checker(actual, expected):",
+                "    return len(actual) == len(expected)",
+            ]
+            .join("\n"),
+        ),
+        reference_solution: None,
+        only_ids: None,
+        compiler_flags: Box::new([]),
+        debug: false,
+        float_tolerance: None,
+        toolchain_version: None,
+        metadata: None,
+    };
+    let body = serde_json::to_string(&submission).expect("failed to serialize submission");
+    let request = Builder::new()
+        .header("Content-Type", "application/json")
+        .method(Method::POST)
+        .uri("/submit")
+        .body(Body::from(body))
+        .expect("failed to build request");
+    let expected_status = StatusCode::OK;
+    let expected_body = SubmissionResult::Failure(Box::new([
+        TestCaseResult {
+            id: 0,
+            test_result: TestResult::Pass,
+            label: None,
+        },
+        TestCaseResult {
+            id: 1,
+            test_result: TestResult::Failure(TestCaseFailureReason::WrongAnswer {
+                input_parameters: Box::new([Parameter {
+                    value_type: ParameterType::Int,
+                    value: String::from("2"),
+                }]),
+                actual: String::from("aa"),
+                expected: String::from("xyz"),
+                actual_value: None,
+                expected_value: None,
+            }),
+            label: None,
+        },
+    ]));
+
+    let actual = mozart
+        .oneshot(request)
+        .await
+        .expect("failed to execute oneshot request");
+
+    let actual_status = actual.status();
+    let body_bytes = to_bytes(actual.into_body(), usize::MAX)
+        .await
+        .expect("failed to convert body to bytes");
+
+    let actual_body: SubmissionResult =
+        serde_json::from_slice(&body_bytes).expect("failed to deserialize response body");
+
+    assert_eq!(actual_status, expected_status);
+    assert_eq!(actual_body, expected_body);
+}
+
+#[tokio::test]
+async fn empty_checker() {
+    let mozart = app(AppState::default());
+    let solution = ["def solution(n: int) -> str:", "    return \"a\" * n"].join("\n");
+    let test_cases = Box::new([TestCase {
+        id: 0,
+        input_parameters: Box::new([Parameter {
+            value_type: ParameterType::Int,
+            value: String::from("3"),
+        }]),
+        output_parameters: Box::new([Parameter {
+            value_type: ParameterType::String,
+            value: String::from("xyz"),
+        }]),
+        stdin: None,
+        expected_error: None,
+        label: None,
+        hidden: false,
+    }]);
+    let submission = Submission {
+        solution,
+        test_cases,
+        unordered_lists: false,
+        files: Box::new([]),
+        checker: Some(String::from("  ")),
+        reference_solution: None,
+        only_ids: None,
+        compiler_flags: Box::new([]),
+        debug: false,
+        float_tolerance: None,
+        toolchain_version: None,
+        metadata: None,
+    };
+    let body = serde_json::to_string(&submission).expect("failed to serialize submission");
+    let request = Builder::new()
+        .header("Content-Type", "application/json")
+        .method(Method::POST)
+        .uri("/submit")
+        .body(Body::from(body))
+        .expect("failed to build request");
+    let expected_status = StatusCode::OK;
+
+    let actual = mozart
+        .oneshot(request)
+        .await
+        .expect("failed to execute oneshot request");
+
+    let actual_status = actual.status();
+    let body_bytes = to_bytes(actual.into_body(), usize::MAX)
+        .await
+        .expect("failed to convert body to bytes");
+
+    let actual_body: SubmissionResult =
+        serde_json::from_slice(&body_bytes).expect("failed to deserialize response body");
+
+    assert_eq!(actual_status, expected_status);
+
+    if let SubmissionResult::Error { code, .. } = actual_body {
+        assert_eq!(code, ErrorCode::EmptyChecker);
+    } else {
+        panic!("response body was not of error variant");
+    }
+}
+
+#[tokio::test]
+async fn solution_importing_checker() {
+    let mozart = app(AppState::default());
+    let solution = [
+        "import checker",
+        "",
+        "def solution(n: int) -> str:",
+        "    return \"a\" * n",
+    ]
+    .join("\n");
+    // the solution must not be able to tamper with the checker
+    let test_cases = Box::new([TestCase {
+        id: 0,
+        input_parameters: Box::new([Parameter {
+            value_type: ParameterType::Int,
+            value: String::from("3"),
+        }]),
+        output_parameters: Box::new([Parameter {
+            value_type: ParameterType::String,
+            value: String::from("xyz"),
+        }]),
+        stdin: None,
+        expected_error: None,
+        label: None,
+        hidden: false,
+    }]);
+    let submission = Submission {
+        solution,
+        test_cases,
+        unordered_lists: false,
+        files: Box::new([]),
+        checker: None,
+        reference_solution: None,
+        only_ids: None,
+        compiler_flags: Box::new([]),
+        debug: false,
+        float_tolerance: None,
+        toolchain_version: None,
+        metadata: None,
+    };
+    let body = serde_json::to_string(&submission).expect("failed to serialize submission");
+    let request = Builder::new()
+        .header("Content-Type", "application/json")
+        .method(Method::POST)
+        .uri("/submit")
+        .body(Body::from(body))
+        .expect("failed to build request");
+    let expected_status = StatusCode::OK;
+
+    let actual = mozart
+        .oneshot(request)
+        .await
+        .expect("failed to execute oneshot request");
+
+    let actual_status = actual.status();
+    let body_bytes = to_bytes(actual.into_body(), usize::MAX)
+        .await
+        .expect("failed to convert body to bytes");
+
+    let actual_body: SubmissionResult =
+        serde_json::from_slice(&body_bytes).expect("failed to deserialize response body");
+
+    assert_eq!(actual_status, expected_status);
+
+    if let SubmissionResult::Error { code, message } = actual_body {
+        assert_eq!(code, ErrorCode::Execution);
+        assert!(message.contains("importing 'checker' is not allowed"));
+    } else {
+        panic!("response body was not of error variant");
+    }
+}
+
+#[tokio::test]
+async fn read_stdin() {
+    let mozart = app(AppState::default());
+    let solution = [
+        "def solution() -> str:",
+        "    try:",
+        "        return input()",
+        "    except EOFError:",
+        "        return \"\"",
+    ]
+    .join("\n");
+    // every test case reads from its own stdin, which is empty if it is not supplied
+    let test_cases = Box::new([
+        TestCase {
+            id: 0,
+            input_parameters: Box::new([]),
+            output_parameters: Box::new([Parameter {
+                value_type: ParameterType::String,
+                value: String::from("hello"),
+            }]),
+            stdin: Some(String::from("hello\nworld\n")),
+            expected_error: None,
+            label: None,
+            hidden: false,
+        },
+        TestCase {
+            id: 1,
+            input_parameters: Box::new([]),
+            output_parameters: Box::new([Parameter {
+                value_type: ParameterType::String,
+                value: String::from("world"),
+            }]),
+            stdin: Some(String::from("world\n")),
+            expected_error: None,
+            label: None,
+            hidden: false,
+        },
+        TestCase {
+            id: 2,
+            input_parameters: Box::new([]),
+            output_parameters: Box::new([Parameter {
+                value_type: ParameterType::String,
+                value: String::from(""),
+            }]),
+            stdin: None,
+            expected_error: None,
+            label: None,
+            hidden: false,
+        },
+    ]);
+    let submission = Submission {
+        solution,
+        test_cases,
+        unordered_lists: false,
+        files: Box::new([]),
+        checker: None,
+        reference_solution: None,
+        only_ids: None,
+        compiler_flags: Box::new([]),
+        debug: false,
+        float_tolerance: None,
+        toolchain_version: None,
+        metadata: None,
+    };
+    let body = serde_json::to_string(&submission).expect("failed to serialize submission");
+    let request = Builder::new()
+        .header("Content-Type", "application/json")
+        .method(Method::POST)
+        .uri("/submit")
+        .body(Body::from(body))
+        .expect("failed to build request");
+    let expected_status = StatusCode::OK;
+    let expected_body = SubmissionResult::Pass;
+
+    let actual = mozart
+        .oneshot(request)
+        .await
+        .expect("failed to execute oneshot request");
+
+    let actual_status = actual.status();
+    let body_bytes = to_bytes(actual.into_body(), usize::MAX)
+        .await
+        .expect("failed to convert body to bytes");
+
+    let actual_body: SubmissionResult =
+        serde_json::from_slice(&body_bytes).expect("failed to deserialize response body");
+
+    assert_eq!(actual_status, expected_status);
+    assert_eq!(actual_body, expected_body);
+}
+
+#[tokio::test]
+async fn write_huge_file() {
+    let mozart = app(AppState::default());
+    let solution = [
+        "def solution(x: int):",
+        "    with open('huge.txt', 'w') as file:",
+        "        for _ in range(1024):",
+        "            file.write('x' * 1024 * 1024)",
+        "    return x + x",
+    ]
+    .join("\n");
+    // writing a gigabyte must be stopped instead of filling the disk
+    let test_cases = Box::new([TestCase {
+        id: 0,
+        input_parameters: Box::new([Parameter {
+            value_type: ParameterType::Int,
+            value: String::from("2"),
+        }]),
+        output_parameters: Box::new([Parameter {
+            value_type: ParameterType::Int,
+            value: String::from("4"),
+        }]),
+        stdin: None,
+        expected_error: None,
+        label: None,
+        hidden: false,
+    }]);
+    let submission = Submission {
+        solution,
+        test_cases,
+        unordered_lists: false,
+        files: Box::new([]),
+        checker: None,
+        reference_solution: None,
+        only_ids: None,
+        compiler_flags: Box::new([]),
+        debug: false,
+        float_tolerance: None,
+        toolchain_version: None,
+        metadata: None,
+    };
+    let body = serde_json::to_string(&submission).expect("failed to serialize submission");
+    let request = Builder::new()
+        .header("Content-Type", "application/json")
+        .method(Method::POST)
+        .uri("/submit")
+        .body(Body::from(body))
+        .expect("failed to build request");
+    let expected_status = StatusCode::OK;
+
+    let actual = mozart
+        .oneshot(request)
+        .await
+        .expect("failed to execute oneshot request");
+
+    let actual_status = actual.status();
+    let body_bytes = to_bytes(actual.into_body(), usize::MAX)
+        .await
+        .expect("failed to convert body to bytes");
+
+    let actual_body: SubmissionResult =
+        serde_json::from_slice(&body_bytes).expect("failed to deserialize response body");
+
+    assert_eq!(actual_status, expected_status);
+
+    if let SubmissionResult::Failure(test_case_results) = actual_body {
+        assert!(matches!(
+            test_case_results[0].test_result,
+            TestResult::Failure(TestCaseFailureReason::RuntimeError(_))
+        ));
+    } else {
+        panic!("response body was not of failure variant");
+    }
+}
+
+#[tokio::test]
+async fn runtime_error_traceback() {
+    let mozart = app(AppState::default());
+    let solution = [
+        "def validate(x: int):",
+        "    if x < 0:",
+        r#"        raise ValueError("bad")"#,
+        "",
+        "def solution(x: int) -> int:",
+        "    validate(x)",
+        "    return x",
+    ]
+    .join("\n");
+    // the traceback only contains the frames of the solution
+    let test_cases = Box::new([TestCase {
+        id: 0,
+        input_parameters: Box::new([Parameter {
+            value_type: ParameterType::Int,
+            value: String::from("-1"),
+        }]),
+        output_parameters: Box::new([Parameter {
+            value_type: ParameterType::Int,
+            value: String::from("-1"),
+        }]),
+        stdin: None,
+        expected_error: None,
+        label: None,
+        hidden: false,
+    }]);
+    let submission = Submission {
+        solution,
+        test_cases,
+        unordered_lists: false,
+        files: Box::new([]),
+        checker: None,
+        reference_solution: None,
+        only_ids: None,
+        compiler_flags: Box::new([]),
+        debug: false,
+        float_tolerance: None,
+        toolchain_version: None,
+        metadata: None,
+    };
+    let body = serde_json::to_string(&submission).expect("failed to serialize submission");
+    let request = Builder::new()
+        .header("Content-Type", "application/json")
+        .method(Method::POST)
+        .uri("/submit")
+        .body(Body::from(body))
+        .expect("failed to build request");
+    let expected_status = StatusCode::OK;
+    let expected_body = SubmissionResult::Failure(Box::new([TestCaseResult {
+        id: 0,
+        test_result: TestResult::Failure(TestCaseFailureReason::RuntimeError(
+            [
+                "Traceback (most recent call last):",
+                "  File \"solution.py\", line 6, in solution",
+                "    validate(x)",
+                "  File \"solution.py\", line 3, in validate",
+                "    raise ValueError(\"bad\")",
+                "ValueError: bad",
+            ]
+            .join("\n"),
+        )),
+        label: None,
+    }]));
+
+    let actual = mozart
+        .oneshot(request)
+        .await
+        .expect("failed to execute oneshot request");
+
+    let actual_status = actual.status();
+    let body_bytes = to_bytes(actual.into_body(), usize::MAX)
+        .await
+        .expect("failed to convert body to bytes");
+
+    let actual_body: SubmissionResult =
+        serde_json::from_slice(&body_bytes).expect("failed to deserialize response body");
+
+    assert_eq!(actual_status, expected_status);
+    assert_eq!(actual_body, expected_body);
+}
+
+#[tokio::test]
+async fn return_map() {
+    let mozart = app(AppState::default());
+    let solution = [
+        "def solution(s: str) -> dict[str, int]:",
+        "    counts = {}",
+        "    for word in s.split():",
+        "        counts[word] = counts.get(word, 0) + 1",
+        "    return counts",
+    ]
+    .join("\n");
+    // the entries are inserted in another order than they are listed, which must not matter
+    let test_cases = Box::new([
+        TestCase {
+            id: 0,
+            input_parameters: Box::new([Parameter {
+                value_type: ParameterType::String,
+                value: String::from("pear apple pear"),
+            }]),
+            output_parameters: Box::new([Parameter {
+                value_type: ParameterType::Map {
+                    key: Box::new(ParameterType::String),
+                    value: Box::new(ParameterType::Int),
+                },
+                value: String::from(r#"{"apple": "1", "pear": "2"}"#),
+            }]),
+            stdin: None,
+            expected_error: None,
+            label: None,
+            hidden: false,
+        },
+        TestCase {
+            id: 1,
+            input_parameters: Box::new([Parameter {
+                value_type: ParameterType::String,
+                value: String::from(""),
+            }]),
+            output_parameters: Box::new([Parameter {
+                value_type: ParameterType::Map {
+                    key: Box::new(ParameterType::String),
+                    value: Box::new(ParameterType::Int),
+                },
+                value: String::from("{}"),
+            }]),
+            stdin: None,
+            expected_error: None,
+            label: None,
+            hidden: false,
+        },
+    ]);
+    let submission = Submission {
+        solution,
+        test_cases,
+        unordered_lists: false,
+        files: Box::new([]),
+        checker: None,
+        reference_solution: None,
+        only_ids: None,
+        compiler_flags: Box::new([]),
+        debug: false,
+        float_tolerance: None,
+        toolchain_version: None,
+        metadata: None,
+    };
+    let body = serde_json::to_string(&submission).expect("failed to serialize submission");
+    let request = Builder::new()
+        .header("Content-Type", "application/json")
+        .method(Method::POST)
+        .uri("/submit")
+        .body(Body::from(body))
+        .expect("failed to build request");
+    let expected_status = StatusCode::OK;
+    let expected_body = SubmissionResult::Pass;
+
+    let actual = mozart
+        .oneshot(request)
+        .await
+        .expect("failed to execute oneshot request");
+
+    let actual_status = actual.status();
+    let body_bytes = to_bytes(actual.into_body(), usize::MAX)
+        .await
+        .expect("failed to convert body to bytes");
+
+    let actual_body: SubmissionResult =
+        serde_json::from_slice(&body_bytes).expect("failed to deserialize response body");
+
+    assert_eq!(actual_status, expected_status);
+    assert_eq!(actual_body, expected_body);
+}
+
+#[tokio::test]
+async fn invalid_map_value() {
+    let mozart = app(AppState::default());
+    let solution = [
+        "def solution(s: str) -> dict[str, int]:",
+        "    counts = {}",
+        "    for word in s.split():",
+        "        counts[word] = counts.get(word, 0) + 1",
+        "    return counts",
+    ]
+    .join("\n");
+    let test_cases = Box::new([TestCase {
+        id: 0,
+        input_parameters: Box::new([Parameter {
+            value_type: ParameterType::String,
+            value: String::from("pear"),
+        }]),
+        output_parameters: Box::new([Parameter {
+            value_type: ParameterType::Map {
+                key: Box::new(ParameterType::String),
+                value: Box::new(ParameterType::Int),
+            },
+            value: String::from(r#"{"pear": "one"}"#),
+        }]),
+        stdin: None,
+        expected_error: None,
+        label: None,
+        hidden: false,
+    }]);
+    let submission = Submission {
+        solution,
+        test_cases,
+        unordered_lists: false,
+        files: Box::new([]),
+        checker: None,
+        reference_solution: None,
+        only_ids: None,
+        compiler_flags: Box::new([]),
+        debug: false,
+        float_tolerance: None,
+        toolchain_version: None,
+        metadata: None,
+    };
+    let body = serde_json::to_string(&submission).expect("failed to serialize submission");
+    let request = Builder::new()
+        .header("Content-Type", "application/json")
+        .method(Method::POST)
+        .uri("/submit")
+        .body(Body::from(body))
+        .expect("failed to build request");
+    let expected_status = StatusCode::OK;
+
+    let actual = mozart
+        .oneshot(request)
+        .await
+        .expect("failed to execute oneshot request");
+
+    let actual_status = actual.status();
+    let body_bytes = to_bytes(actual.into_body(), usize::MAX)
+        .await
+        .expect("failed to convert body to bytes");
+
+    let actual_body: SubmissionResult =
+        serde_json::from_slice(&body_bytes).expect("failed to deserialize response body");
+
+    assert_eq!(actual_status, expected_status);
+
+    if let SubmissionResult::Error { code, .. } = actual_body {
+        assert_eq!(code, ErrorCode::InvalidParameter);
+    } else {
+        panic!("response body was not of error variant");
+    }
+}
+
+#[tokio::test]
+async fn indentation_error_in_submission() {
+    let mozart = app(AppState::default());
+    let solution = [
+        "def solution(x: int) -> int:",
+        "    y = x + x",
+        "      return y",
+    ]
+    .join("\n");
+    // the return statement is indented further than the statement before it
+    let test_cases = Box::new([TestCase {
+        id: 0,
+        input_parameters: Box::new([Parameter {
+            value_type: ParameterType::Int,
+            value: String::from("2"),
+        }]),
+        output_parameters: Box::new([Parameter {
+            value_type: ParameterType::Int,
+            value: String::from("4"),
+        }]),
+        stdin: None,
+        expected_error: None,
+        label: None,
+        hidden: false,
+    }]);
+    let submission = Submission {
+        solution,
+        test_cases,
+        unordered_lists: false,
+        files: Box::new([]),
+        checker: None,
+        reference_solution: None,
+        only_ids: None,
+        compiler_flags: Box::new([]),
+        debug: false,
+        float_tolerance: None,
+        toolchain_version: None,
+        metadata: None,
+    };
+    let body = serde_json::to_string(&submission).expect("failed to serialize submission");
+    let request = Builder::new()
+        .header("Content-Type", "application/json")
+        .method(Method::POST)
+        .uri("/submit")
+        .body(Body::from(body))
+        .expect("failed to build request");
+    let expected_status = StatusCode::OK;
+
+    let actual = mozart
+        .oneshot(request)
+        .await
+        .expect("failed to execute oneshot request");
+
+    let actual_status = actual.status();
+    let body_bytes = to_bytes(actual.into_body(), usize::MAX)
+        .await
+        .expect("failed to convert body to bytes");
+
+    let actual_body: SubmissionResult =
+        serde_json::from_slice(&body_bytes).expect("failed to deserialize response body");
+
+    assert_eq!(actual_status, expected_status);
+
+    if let SubmissionResult::CompilationError {
+        message,
+        diagnostics,
+    } = actual_body
+    {
+        assert!(message.contains("IndentationError"));
+        assert_eq!(diagnostics.len(), 1);
+        assert_eq!(diagnostics[0].line, 3);
+    } else {
+        panic!("response body was not of compilation error variant");
+    }
+}
+
+#[tokio::test]
+async fn only_ids() {
+    let mozart = app(AppState::default());
+    let solution = ["def solution(x: int) -> int:", "    return x * 2"].join("\n");
+    // only the requested test case is run, so the failure of the others is not reported
+    let test_cases = Box::new([
+        TestCase {
+            id: 0,
+            input_parameters: Box::new([Parameter {
+                value_type: ParameterType::Int,
+                value: String::from("0"),
+            }]),
+            output_parameters: Box::new([Parameter {
+                value_type: ParameterType::Int,
+                value: String::from("0"),
+            }]),
+            stdin: None,
+            expected_error: None,
+            label: None,
+            hidden: false,
+        },
+        TestCase {
+            id: 1,
+            input_parameters: Box::new([Parameter {
+                value_type: ParameterType::Int,
+                value: String::from("1"),
+            }]),
+            output_parameters: Box::new([Parameter {
+                value_type: ParameterType::Int,
+                value: String::from("0"),
+            }]),
+            stdin: None,
+            expected_error: None,
+            label: None,
+            hidden: false,
+        },
+        TestCase {
+            id: 2,
+            input_parameters: Box::new([Parameter {
+                value_type: ParameterType::Int,
+                value: String::from("2"),
+            }]),
+            output_parameters: Box::new([Parameter {
+                value_type: ParameterType::Int,
+                value: String::from("4"),
+            }]),
+            stdin: None,
+            expected_error: None,
+            label: None,
+            hidden: false,
+        },
+        TestCase {
+            id: 3,
+            input_parameters: Box::new([Parameter {
+                value_type: ParameterType::Int,
+                value: String::from("3"),
+            }]),
+            output_parameters: Box::new([Parameter {
+                value_type: ParameterType::Int,
+                value: String::from("7"),
+            }]),
+            stdin: None,
+            expected_error: None,
+            label: None,
+            hidden: false,
+        },
+        TestCase {
+            id: 4,
+            input_parameters: Box::new([Parameter {
+                value_type: ParameterType::Int,
+                value: String::from("4"),
+            }]),
+            output_parameters: Box::new([Parameter {
+                value_type: ParameterType::Int,
+                value: String::from("8"),
+            }]),
+            stdin: None,
+            expected_error: None,
+            label: None,
+            hidden: false,
+        },
+    ]);
+    let submission = Submission {
+        solution,
+        test_cases,
+        unordered_lists: false,
+        files: Box::new([]),
+        checker: None,
+        reference_solution: None,
+        only_ids: Some(Box::new([3])),
+        compiler_flags: Box::new([]),
+        debug: false,
+        float_tolerance: None,
+        toolchain_version: None,
+        metadata: None,
+    };
+    let body = serde_json::to_string(&submission).expect("failed to serialize submission");
+    let request = Builder::new()
+        .header("Content-Type", "application/json")
+        .method(Method::POST)
+        .uri("/submit")
+        .body(Body::from(body))
+        .expect("failed to build request");
+    let expected_status = StatusCode::OK;
+    let expected_body = SubmissionResult::Failure(Box::new([TestCaseResult {
+        id: 3,
+        test_result: TestResult::Failure(TestCaseFailureReason::WrongAnswer {
+            input_parameters: Box::new([Parameter {
+                value_type: ParameterType::Int,
+                value: String::from("3"),
+            }]),
+            actual: String::from("6"),
+            expected: String::from("7"),
+            actual_value: None,
+            expected_value: None,
+        }),
+        label: None,
+    }]));
+
+    let actual = mozart
+        .oneshot(request)
+        .await
+        .expect("failed to execute oneshot request");
+
+    let actual_status = actual.status();
+    let body_bytes = to_bytes(actual.into_body(), usize::MAX)
+        .await
+        .expect("failed to convert body to bytes");
+
+    let actual_body: SubmissionResult =
+        serde_json::from_slice(&body_bytes).expect("failed to deserialize response body");
+
+    assert_eq!(actual_status, expected_status);
+    assert_eq!(actual_body, expected_body);
+}
+
+#[tokio::test]
+async fn allowed_compiler_flag() {
+    let mozart = app(AppState::new(Config {
+        allowed_compiler_flags: Box::new([String::from("-O")]),
+        ..Config::default()
+    }));
+    let solution = [
+        "def solution(x: int) -> int:",
+        "    assert x < 0",
+        "    return x",
+    ]
+    .join("\n");
+    // the assertion is only removed if the flag is passed to the interpreter
+    let test_cases = Box::new([TestCase {
+        id: 0,
+        input_parameters: Box::new([Parameter {
+            value_type: ParameterType::Int,
+            value: String::from("5"),
+        }]),
+        output_parameters: Box::new([Parameter {
+            value_type: ParameterType::Int,
+            value: String::from("5"),
+        }]),
+        stdin: None,
+        expected_error: None,
+        label: None,
+        hidden: false,
+    }]);
+    let submission = Submission {
+        solution,
+        test_cases,
+        unordered_lists: false,
+        files: Box::new([]),
+        checker: None,
+        reference_solution: None,
+        only_ids: None,
+        compiler_flags: Box::new([String::from("-O")]),
+        debug: false,
+        float_tolerance: None,
+        toolchain_version: None,
+        metadata: None,
+    };
+    let body = serde_json::to_string(&submission).expect("failed to serialize submission");
+    let request = Builder::new()
+        .header("Content-Type", "application/json")
+        .method(Method::POST)
+        .uri("/submit")
+        .body(Body::from(body))
+        .expect("failed to build request");
+    let expected_status = StatusCode::OK;
+    let expected_body = SubmissionResult::Pass;
+
+    let actual = mozart
+        .oneshot(request)
+        .await
+        .expect("failed to execute oneshot request");
+
+    let actual_status = actual.status();
+    let body_bytes = to_bytes(actual.into_body(), usize::MAX)
+        .await
+        .expect("failed to convert body to bytes");
+
+    let actual_body: SubmissionResult =
+        serde_json::from_slice(&body_bytes).expect("failed to deserialize response body");
+
+    assert_eq!(actual_status, expected_status);
+    assert_eq!(actual_body, expected_body);
+}
+
+#[tokio::test]
+async fn disallowed_compiler_flag() {
+    let mozart = app(AppState::new(Config {
+        allowed_compiler_flags: Box::new([String::from("-O")]),
+        ..Config::default()
+    }));
+    let solution = [
+        "def solution(x: int) -> int:",
+        "    assert x < 0",
+        "    return x",
+    ]
+    .join("\n");
+    let test_cases = Box::new([TestCase {
+        id: 0,
+        input_parameters: Box::new([Parameter {
+            value_type: ParameterType::Int,
+            value: String::from("5"),
+        }]),
+        output_parameters: Box::new([Parameter {
+            value_type: ParameterType::Int,
+            value: String::from("5"),
+        }]),
+        stdin: None,
+        expected_error: None,
+        label: None,
+        hidden: false,
+    }]);
+    let submission = Submission {
+        solution,
+        test_cases,
+        unordered_lists: false,
+        files: Box::new([]),
+        checker: None,
+        reference_solution: None,
+        only_ids: None,
+        compiler_flags: Box::new([String::from("-fplugin=Evil")]),
+        debug: false,
+        float_tolerance: None,
+        toolchain_version: None,
+        metadata: None,
+    };
+    let body = serde_json::to_string(&submission).expect("failed to serialize submission");
+    let request = Builder::new()
+        .header("Content-Type", "application/json")
+        .method(Method::POST)
+        .uri("/submit")
+        .body(Body::from(body))
+        .expect("failed to build request");
+    let expected_status = StatusCode::OK;
+
+    let actual = mozart
+        .oneshot(request)
+        .await
+        .expect("failed to execute oneshot request");
+
+    let actual_status = actual.status();
+    let body_bytes = to_bytes(actual.into_body(), usize::MAX)
+        .await
+        .expect("failed to convert body to bytes");
+
+    let actual_body: SubmissionResult =
+        serde_json::from_slice(&body_bytes).expect("failed to deserialize response body");
+
+    assert_eq!(actual_status, expected_status);
+
+    if let SubmissionResult::Error { code, message } = actual_body {
+        assert_eq!(code, ErrorCode::DisallowedCompilerFlag);
+        assert!(message.starts_with("the compiler flag '-fplugin=Evil' is not allowed"));
+    } else {
+        panic!("response body was not of error variant");
+    }
+}
+
+#[tokio::test]
+async fn configured_max_value_length() {
+    let mozart = app(AppState::new(Config {
+        max_value_length: 10,
+        ..Config::default()
+    }));
+    let solution = ["def solution(n: int) -> str:", "    return \"a\" * n"].join("\n");
+    let test_cases = Box::new([TestCase {
+        id: 0,
+        input_parameters: Box::new([Parameter {
+            value_type: ParameterType::Int,
+            value: String::from("100"),
+        }]),
+        output_parameters: Box::new([Parameter {
+            value_type: ParameterType::String,
+            value: String::from("a"),
+        }]),
+        stdin: None,
+        expected_error: None,
+        label: None,
+        hidden: false,
+    }]);
+    let submission = Submission {
+        solution,
+        test_cases,
+        unordered_lists: false,
+        files: Box::new([]),
+        checker: None,
+        reference_solution: None,
+        only_ids: None,
+        compiler_flags: Box::new([]),
+        debug: false,
+        float_tolerance: None,
+        toolchain_version: None,
+        metadata: None,
+    };
+    let body = serde_json::to_string(&submission).expect("failed to serialize submission");
+    let request = Builder::new()
+        .header("Content-Type", "application/json")
+        .method(Method::POST)
+        .uri("/submit")
+        .body(Body::from(body))
+        .expect("failed to build request");
+    let expected_status = StatusCode::OK;
+    let expected_body = SubmissionResult::Failure(Box::new([TestCaseResult {
+        id: 0,
+        test_result: TestResult::Failure(TestCaseFailureReason::WrongAnswer {
+            input_parameters: Box::new([Parameter {
+                value_type: ParameterType::Int,
+                value: String::from("100"),
+            }]),
+            actual: format!("{}...", "a".repeat(10)),
+            expected: String::from("a"),
+            actual_value: None,
+            expected_value: None,
+        }),
+        label: None,
+    }]));
+
+    let actual = mozart
+        .oneshot(request)
+        .await
+        .expect("failed to execute oneshot request");
+
+    let actual_status = actual.status();
+    let body_bytes = to_bytes(actual.into_body(), usize::MAX)
+        .await
+        .expect("failed to convert body to bytes");
+
+    let actual_body: SubmissionResult =
+        serde_json::from_slice(&body_bytes).expect("failed to deserialize response body");
+
+    assert_eq!(actual_status, expected_status);
+    assert_eq!(actual_body, expected_body);
+}
+
+#[tokio::test]
+async fn debug_generated_source() {
+    let mozart = app(AppState::new(Config {
+        allow_debug: true,
+        ..Config::default()
+    }));
+    let solution = ["def solution(s: str) -> str:", "    return s"].join("\n");
+    let test_cases = Box::new([TestCase {
+        id: 0,
+        input_parameters: Box::new([Parameter {
+            value_type: ParameterType::String,
+            value: String::from("mozart-debug"),
+        }]),
+        output_parameters: Box::new([Parameter {
+            value_type: ParameterType::String,
+            value: String::from("mozart-debug"),
+        }]),
+        stdin: None,
+        expected_error: None,
+        label: None,
+        hidden: false,
+    }]);
+    let submission = Submission {
+        solution,
+        test_cases,
+        unordered_lists: false,
+        files: Box::new([]),
+        checker: None,
+        reference_solution: None,
+        only_ids: None,
+        compiler_flags: Box::new([]),
+        debug: true,
+        float_tolerance: None,
+        toolchain_version: None,
+        metadata: None,
+    };
+    let body = serde_json::to_string(&submission).expect("failed to serialize submission");
+    let request = Builder::new()
+        .header("Content-Type", "application/json")
+        .method(Method::POST)
+        .uri("/submit")
+        .body(Body::from(body))
+        .expect("failed to build request");
+    let expected_status = StatusCode::OK;
+    let expected_result = SubmissionResult::Pass;
+
+    let actual = mozart
+        .oneshot(request)
+        .await
+        .expect("failed to execute oneshot request");
+
+    let actual_status = actual.status();
+    let body_bytes = to_bytes(actual.into_body(), usize::MAX)
+        .await
+        .expect("failed to convert body to bytes");
+
+    let actual_body: Value =
+        serde_json::from_slice(&body_bytes).expect("failed to deserialize response body");
+    let actual_result: SubmissionResult =
+        serde_json::from_slice(&body_bytes).expect("failed to deserialize result");
+
+    assert_eq!(actual_status, expected_status);
+    assert_eq!(actual_result, expected_result);
+    // the generated test file contains the test case, which is formatted as a literal of the language
+    let actual_source = actual_body["generatedSource"]
+        .as_str()
+        .expect("generated source should be present");
+    assert!(actual_source.contains("mozart-debug"));
+}
+
+#[tokio::test]
+async fn debug_not_allowed() {
+    let mozart = app(AppState::default());
+    let solution = ["def solution(s: str) -> str:", "    return s"].join("\n");
+    let test_cases = Box::new([TestCase {
+        id: 0,
+        input_parameters: Box::new([Parameter {
+            value_type: ParameterType::String,
+            value: String::from("mozart-debug"),
+        }]),
+        output_parameters: Box::new([Parameter {
+            value_type: ParameterType::String,
+            value: String::from("mozart-debug"),
+        }]),
+        stdin: None,
+        expected_error: None,
+        label: None,
+        hidden: false,
+    }]);
+    let submission = Submission {
+        solution,
+        test_cases,
+        unordered_lists: false,
+        files: Box::new([]),
+        checker: None,
+        reference_solution: None,
+        only_ids: None,
+        compiler_flags: Box::new([]),
+        debug: true,
+        float_tolerance: None,
+        toolchain_version: None,
+        metadata: None,
+    };
+    let body = serde_json::to_string(&submission).expect("failed to serialize submission");
+    let request = Builder::new()
+        .header("Content-Type", "application/json")
+        .method(Method::POST)
+        .uri("/submit")
+        .body(Body::from(body))
+        .expect("failed to build request");
+    let expected_status = StatusCode::OK;
+    let expected_result = SubmissionResult::Pass;
+
+    let actual = mozart
+        .oneshot(request)
+        .await
+        .expect("failed to execute oneshot request");
+
+    let actual_status = actual.status();
+    let body_bytes = to_bytes(actual.into_body(), usize::MAX)
+        .await
+        .expect("failed to convert body to bytes");
+
+    let actual_body: Value =
+        serde_json::from_slice(&body_bytes).expect("failed to deserialize response body");
+    let actual_result: SubmissionResult =
+        serde_json::from_slice(&body_bytes).expect("failed to deserialize result");
+
+    assert_eq!(actual_status, expected_status);
+    assert_eq!(actual_result, expected_result);
+    assert!(actual_body.get("generatedSource").is_none());
+}
+
+#[tokio::test]
+async fn crash_without_output() {
+    let mozart = app(AppState::default());
+    // exiting while the solution is imported happens before any test case is started
+    let solution = [
+        "raise SystemExit(\"boom\")",
+        "",
+        "def solution(x: int) -> int:",
+        "    return x",
+    ]
+    .join("\n");
+    let test_cases = Box::new([TestCase {
+        id: 0,
+        input_parameters: Box::new([Parameter {
+            value_type: ParameterType::Int,
+            value: String::from("2"),
+        }]),
+        output_parameters: Box::new([Parameter {
+            value_type: ParameterType::Int,
+            value: String::from("2"),
+        }]),
+        stdin: None,
+        expected_error: None,
+        label: None,
+        hidden: false,
+    }]);
+    let submission = Submission {
+        solution,
+        test_cases,
+        unordered_lists: false,
+        files: Box::new([]),
+        checker: None,
+        reference_solution: None,
+        only_ids: None,
+        compiler_flags: Box::new([]),
+        debug: false,
+        float_tolerance: None,
+        toolchain_version: None,
+        metadata: None,
+    };
+    let body = serde_json::to_string(&submission).expect("failed to serialize submission");
+    let request = Builder::new()
+        .header("Content-Type", "application/json")
+        .method(Method::POST)
+        .uri("/submit")
+        .body(Body::from(body))
+        .expect("failed to build request");
+    let expected_status = StatusCode::OK;
+    let expected_body = SubmissionResult::Error {
+        code: ErrorCode::Execution,
+        message: String::from("an error occured during execution: boom\n"),
+    };
+
+    let actual = mozart
+        .oneshot(request)
+        .await
+        .expect("failed to execute oneshot request");
+
+    let actual_status = actual.status();
+    let body_bytes = to_bytes(actual.into_body(), usize::MAX)
+        .await
+        .expect("failed to convert body to bytes");
+
+    let actual_body: SubmissionResult =
+        serde_json::from_slice(&body_bytes).expect("failed to deserialize response body");
+
+    assert_eq!(actual_status, expected_status);
+    assert_eq!(actual_body, expected_body);
+}
+
+#[tokio::test]
+async fn reference_solution() {
+    let mozart = app(AppState::default());
+    let solution = ["def solution(x: int) -> int:", "    return x ** 2"].join("\n");
+    let reference_solution = ["def solution(x: int) -> int:", "    return x * x"].join("\n");
+    // the first test case has no output parameters, so its expected value is computed by the reference solution
+    let test_cases = Box::new([
+        TestCase {
+            id: 0,
+            input_parameters: Box::new([Parameter {
+                value_type: ParameterType::Int,
+                value: String::from("3"),
+            }]),
+            output_parameters: Box::new([]),
+            stdin: None,
+            expected_error: None,
+            label: None,
+            hidden: false,
+        },
+        TestCase {
+            id: 1,
+            input_parameters: Box::new([Parameter {
+                value_type: ParameterType::Int,
+                value: String::from("2"),
+            }]),
+            output_parameters: Box::new([Parameter {
+                value_type: ParameterType::Int,
+                value: String::from("4"),
+            }]),
+            stdin: None,
+            expected_error: None,
+            label: None,
+            hidden: false,
+        },
+    ]);
+    let submission = Submission {
+        solution,
+        test_cases,
+        unordered_lists: false,
+        files: Box::new([]),
+        checker: None,
+        reference_solution: Some(reference_solution),
+        only_ids: None,
+        compiler_flags: Box::new([]),
+        debug: false,
+        float_tolerance: None,
+        toolchain_version: None,
+        metadata: None,
+    };
+    let body = serde_json::to_string(&submission).expect("failed to serialize submission");
+    let request = Builder::new()
+        .header("Content-Type", "application/json")
+        .method(Method::POST)
+        .uri("/submit")
+        .body(Body::from(body))
+        .expect("failed to build request");
+    let expected_status = StatusCode::OK;
+    let expected_body = SubmissionResult::Pass;
+
+    let actual = mozart
+        .oneshot(request)
+        .await
+        .expect("failed to execute oneshot request");
+
+    let actual_status = actual.status();
+    let body_bytes = to_bytes(actual.into_body(), usize::MAX)
+        .await
+        .expect("failed to convert body to bytes");
+
+    let actual_body: SubmissionResult =
+        serde_json::from_slice(&body_bytes).expect("failed to deserialize response body");
+
+    assert_eq!(actual_status, expected_status);
+    assert_eq!(actual_body, expected_body);
+}
+
+#[tokio::test]
+async fn reference_solution_disagrees() {
+    let mozart = app(AppState::default());
+    let solution = ["def solution(x: int) -> int:", "    return x * 2"].join("\n");
+    let reference_solution = ["def solution(x: int) -> int:", "    return x * x"].join("\n");
+    // the first test case has no output parameters, so its expected value is computed by the reference solution
+    let test_cases = Box::new([
+        TestCase {
+            id: 0,
+            input_parameters: Box::new([Parameter {
+                value_type: ParameterType::Int,
+                value: String::from("3"),
+            }]),
+            output_parameters: Box::new([]),
+            stdin: None,
+            expected_error: None,
+            label: None,
+            hidden: false,
+        },
+        TestCase {
+            id: 1,
+            input_parameters: Box::new([Parameter {
+                value_type: ParameterType::Int,
+                value: String::from("2"),
+            }]),
+            output_parameters: Box::new([Parameter {
+                value_type: ParameterType::Int,
+                value: String::from("4"),
+            }]),
+            stdin: None,
+            expected_error: None,
+            label: None,
+            hidden: false,
+        },
+    ]);
+    let submission = Submission {
+        solution,
+        test_cases,
+        unordered_lists: false,
+        files: Box::new([]),
+        checker: None,
+        reference_solution: Some(reference_solution),
+        only_ids: None,
+        compiler_flags: Box::new([]),
+        debug: false,
+        float_tolerance: None,
+        toolchain_version: None,
+        metadata: None,
+    };
+    let body = serde_json::to_string(&submission).expect("failed to serialize submission");
+    let request = Builder::new()
+        .header("Content-Type", "application/json")
+        .method(Method::POST)
+        .uri("/submit")
+        .body(Body::from(body))
+        .expect("failed to build request");
+    let expected_status = StatusCode::OK;
+    let expected_body = SubmissionResult::Failure(Box::new([
+        TestCaseResult {
+            id: 0,
+            test_result: TestResult::Failure(TestCaseFailureReason::WrongAnswer {
+                input_parameters: Box::new([Parameter {
+                    value_type: ParameterType::Int,
+                    value: String::from("3"),
+                }]),
+                actual: String::from("6"),
+                expected: String::from("9"),
+                actual_value: None,
+                expected_value: None,
+            }),
+            label: None,
+        },
+        TestCaseResult {
+            id: 1,
+            test_result: TestResult::Pass,
+            label: None,
+        },
+    ]));
+
+    let actual = mozart
+        .oneshot(request)
+        .await
+        .expect("failed to execute oneshot request");
+
+    let actual_status = actual.status();
+    let body_bytes = to_bytes(actual.into_body(), usize::MAX)
+        .await
+        .expect("failed to convert body to bytes");
+
+    let actual_body: SubmissionResult =
+        serde_json::from_slice(&body_bytes).expect("failed to deserialize response body");
+
+    assert_eq!(actual_status, expected_status);
+    assert_eq!(actual_body, expected_body);
+}
+
+#[tokio::test]
+async fn solution_importing_reference_solution() {
+    let mozart = app(AppState::default());
+    let solution = [
+        "from reference import solution as reference",
+        "",
+        "def solution(x: int) -> int:",
+        "    return reference(x)",
+    ]
+    .join("\n");
+    let reference_solution = ["def solution(x: int) -> int:", "    return x * 2"].join("\n");
+    let test_cases = Box::new([TestCase {
+        id: 0,
+        input_parameters: Box::new([Parameter {
+            value_type: ParameterType::Int,
+            value: String::from("2"),
+        }]),
+        output_parameters: Box::new([Parameter {
+            value_type: ParameterType::Int,
+            value: String::from("4"),
+        }]),
+        stdin: None,
+        expected_error: None,
+        label: None,
+        hidden: false,
+    }]);
+    let submission = Submission {
+        solution,
+        test_cases,
+        unordered_lists: false,
+        files: Box::new([]),
+        checker: None,
+        reference_solution: Some(reference_solution),
+        only_ids: None,
+        compiler_flags: Box::new([]),
+        debug: false,
+        float_tolerance: None,
+        toolchain_version: None,
+        metadata: None,
+    };
+    let body = serde_json::to_string(&submission).expect("failed to serialize submission");
+    let request = Builder::new()
+        .header("Content-Type", "application/json")
+        .method(Method::POST)
+        .uri("/submit")
+        .body(Body::from(body))
+        .expect("failed to build request");
+    let expected_status = StatusCode::OK;
+
+    let actual = mozart
+        .oneshot(request)
+        .await
+        .expect("failed to execute oneshot request");
+
+    let actual_status = actual.status();
+    let body_bytes = to_bytes(actual.into_body(), usize::MAX)
+        .await
+        .expect("failed to convert body to bytes");
+
+    let actual_body: SubmissionResult =
+        serde_json::from_slice(&body_bytes).expect("failed to deserialize response body");
+
+    assert_eq!(actual_status, expected_status);
+
+    if let SubmissionResult::Error { code, message } = actual_body {
+        assert_eq!(code, ErrorCode::Execution);
+        assert!(message.contains("importing 'reference' is not allowed"));
+    } else {
+        panic!("response body was not of error variant");
+    }
+}
+
+#[tokio::test]
+async fn float_tolerance_relative() {
+    let mozart = app(AppState::default());
+    let solution = [
+        "def solution(x: float) -> float:",
+        "    return x * 1e12 + 1",
+    ]
+    .join("\n");
+    let test_cases = Box::new([TestCase {
+        id: 0,
+        input_parameters: Box::new([Parameter {
+            value_type: ParameterType::Float,
+            value: String::from("3.0"),
+        }]),
+        output_parameters: Box::new([Parameter {
+            value_type: ParameterType::Float,
+            value: String::from("3000000000000.0"),
+        }]),
+        stdin: None,
+        expected_error: None,
+        label: None,
+        hidden: false,
+    }]);
+    let submission = Submission {
+        solution,
+        test_cases,
+        unordered_lists: false,
+        files: Box::new([]),
+        checker: None,
+        reference_solution: None,
+        only_ids: None,
+        compiler_flags: Box::new([]),
+        debug: false,
+        float_tolerance: Some(FloatTolerance {
+            absolute: 1e-9,
+            relative: 1e-9,
+            match_nan: false,
+        }),
+        toolchain_version: None,
+        metadata: None,
+    };
+    let body = serde_json::to_string(&submission).expect("failed to serialize submission");
+    let request = Builder::new()
+        .header("Content-Type", "application/json")
+        .method(Method::POST)
+        .uri("/submit")
+        .body(Body::from(body))
+        .expect("failed to build request");
+    let expected_status = StatusCode::OK;
+    let expected_body = SubmissionResult::Pass;
+
+    let actual = mozart
+        .oneshot(request)
+        .await
+        .expect("failed to execute oneshot request");
+
+    let actual_status = actual.status();
+    let body_bytes = to_bytes(actual.into_body(), usize::MAX)
+        .await
+        .expect("failed to convert body to bytes");
+
+    let actual_body: SubmissionResult =
+        serde_json::from_slice(&body_bytes).expect("failed to deserialize response body");
+
+    assert_eq!(actual_status, expected_status);
+    assert_eq!(actual_body, expected_body);
+}
+
+#[tokio::test]
+async fn float_tolerance_absolute() {
+    let mozart = app(AppState::default());
+    let solution = [
+        "def solution(x: float) -> float:",
+        "    return x * 1e12 + 1",
+    ]
+    .join("\n");
+    let test_cases = Box::new([TestCase {
+        id: 0,
+        input_parameters: Box::new([Parameter {
+            value_type: ParameterType::Float,
+            value: String::from("3.0"),
+        }]),
+        output_parameters: Box::new([Parameter {
+            value_type: ParameterType::Float,
+            value: String::from("3000000000000.0"),
+        }]),
+        stdin: None,
+        expected_error: None,
+        label: None,
+        hidden: false,
+    }]);
+    let submission = Submission {
+        solution,
+        test_cases,
+        unordered_lists: false,
+        files: Box::new([]),
+        checker: None,
+        reference_solution: None,
+        only_ids: None,
+        compiler_flags: Box::new([]),
+        debug: false,
+        float_tolerance: Some(FloatTolerance {
+            absolute: 1e-9,
+            relative: 0.0,
+            match_nan: false,
+        }),
+        toolchain_version: None,
+        metadata: None,
+    };
+    let body = serde_json::to_string(&submission).expect("failed to serialize submission");
+    let request = Builder::new()
+        .header("Content-Type", "application/json")
+        .method(Method::POST)
+        .uri("/submit")
+        .body(Body::from(body))
+        .expect("failed to build request");
+    let expected_status = StatusCode::OK;
+    let expected_body = SubmissionResult::Failure(Box::new([TestCaseResult {
+        id: 0,
+        test_result: TestResult::Failure(TestCaseFailureReason::WrongAnswer {
+            input_parameters: Box::new([Parameter {
+                value_type: ParameterType::Float,
+                value: String::from("3.0"),
+            }]),
+            actual: String::from("3000000000001.0"),
+            expected: String::from("3000000000000.0"),
+            actual_value: None,
+            expected_value: None,
+        }),
+        label: None,
+    }]));
+
+    let actual = mozart
+        .oneshot(request)
+        .await
+        .expect("failed to execute oneshot request");
+
+    let actual_status = actual.status();
+    let body_bytes = to_bytes(actual.into_body(), usize::MAX)
+        .await
+        .expect("failed to convert body to bytes");
+
+    let actual_body: SubmissionResult =
+        serde_json::from_slice(&body_bytes).expect("failed to deserialize response body");
+
+    assert_eq!(actual_status, expected_status);
+    assert_eq!(actual_body, expected_body);
+}
+
+#[tokio::test]
+async fn float_tolerance_multiple_output_parameters() {
+    let mozart = app(AppState::default());
+    let solution = [
+        "def solution(x: float) -> tuple[float, int]:",
+        "    return x * 1e12 + 1, 3",
+    ]
+    .join("\n");
+    let test_cases = Box::new([TestCase {
+        id: 0,
+        input_parameters: Box::new([Parameter {
+            value_type: ParameterType::Float,
+            value: String::from("3.0"),
+        }]),
+        output_parameters: Box::new([
+            Parameter {
+                value_type: ParameterType::Float,
+                value: String::from("3000000000000.0"),
+            },
+            Parameter {
+                value_type: ParameterType::Int,
+                value: String::from("3"),
+            },
+        ]),
+        stdin: None,
+        expected_error: None,
+        label: None,
+        hidden: false,
+    }]);
+    let submission = Submission {
+        solution,
+        test_cases,
+        unordered_lists: false,
+        files: Box::new([]),
+        checker: None,
+        reference_solution: None,
+        only_ids: None,
+        compiler_flags: Box::new([]),
+        debug: false,
+        float_tolerance: Some(FloatTolerance {
+            absolute: 1e-9,
+            relative: 1e-9,
+            match_nan: false,
+        }),
+        toolchain_version: None,
+        metadata: None,
+    };
+    let body = serde_json::to_string(&submission).expect("failed to serialize submission");
+    let request = Builder::new()
+        .header("Content-Type", "application/json")
+        .method(Method::POST)
+        .uri("/submit")
+        .body(Body::from(body))
+        .expect("failed to build request");
+    let expected_status = StatusCode::OK;
+    let expected_body = SubmissionResult::Pass;
+
+    let actual = mozart
+        .oneshot(request)
+        .await
+        .expect("failed to execute oneshot request");
+
+    let actual_status = actual.status();
+    let body_bytes = to_bytes(actual.into_body(), usize::MAX)
+        .await
+        .expect("failed to convert body to bytes");
+
+    let actual_body: SubmissionResult =
+        serde_json::from_slice(&body_bytes).expect("failed to deserialize response body");
+
+    assert_eq!(actual_status, expected_status);
+    assert_eq!(actual_body, expected_body);
+}
+
+#[tokio::test]
+async fn toolchain_version() {
+    let mozart = app(AppState::new(Config {
+        toolchain_versions: Box::new([String::from("3.11")]),
+        ..Config::default()
+    }));
+    let solution = ["def solution(x: int) -> int:", "    return x * 2"].join("\n");
+    let test_cases = Box::new([TestCase {
+        id: 0,
+        input_parameters: Box::new([Parameter {
+            value_type: ParameterType::Int,
+            value: String::from("2"),
+        }]),
+        output_parameters: Box::new([Parameter {
+            value_type: ParameterType::Int,
+            value: String::from("4"),
+        }]),
+        stdin: None,
+        expected_error: None,
+        label: None,
+        hidden: false,
+    }]);
+    let submission = Submission {
+        solution,
+        test_cases,
+        unordered_lists: false,
+        files: Box::new([]),
+        checker: None,
+        reference_solution: None,
+        only_ids: None,
+        compiler_flags: Box::new([]),
+        debug: false,
+        float_tolerance: None,
+        toolchain_version: Some(String::from("3.11")),
+        metadata: None,
+    };
+    let body = serde_json::to_string(&submission).expect("failed to serialize submission");
+    let request = Builder::new()
+        .header("Content-Type", "application/json")
+        .method(Method::POST)
+        .uri("/submit")
+        .body(Body::from(body))
+        .expect("failed to build request");
+    let expected_status = StatusCode::OK;
+    let expected_body = SubmissionResult::Pass;
+
+    let actual = mozart
+        .oneshot(request)
+        .await
+        .expect("failed to execute oneshot request");
+
+    let actual_status = actual.status();
+    let body_bytes = to_bytes(actual.into_body(), usize::MAX)
+        .await
+        .expect("failed to convert body to bytes");
+
+    let actual_body: SubmissionResult =
+        serde_json::from_slice(&body_bytes).expect("failed to deserialize response body");
+
+    assert_eq!(actual_status, expected_status);
+    assert_eq!(actual_body, expected_body);
+}
+
+#[tokio::test]
+async fn unknown_toolchain_version() {
+    let mozart = app(AppState::new(Config {
+        toolchain_versions: Box::new([String::from("3.11")]),
+        ..Config::default()
+    }));
+    let solution = ["def solution(x: int) -> int:", "    return x * 2"].join("\n");
+    let test_cases = Box::new([TestCase {
+        id: 0,
+        input_parameters: Box::new([Parameter {
+            value_type: ParameterType::Int,
+            value: String::from("2"),
+        }]),
+        output_parameters: Box::new([Parameter {
+            value_type: ParameterType::Int,
+            value: String::from("4"),
+        }]),
+        stdin: None,
+        expected_error: None,
+        label: None,
+        hidden: false,
+    }]);
+    let submission = Submission {
+        solution,
+        test_cases,
+        unordered_lists: false,
+        files: Box::new([]),
+        checker: None,
+        reference_solution: None,
+        only_ids: None,
+        compiler_flags: Box::new([]),
+        debug: false,
+        float_tolerance: None,
+        toolchain_version: Some(String::from("2.7")),
+        metadata: None,
+    };
+    let body = serde_json::to_string(&submission).expect("failed to serialize submission");
+    let request = Builder::new()
+        .header("Content-Type", "application/json")
+        .method(Method::POST)
+        .uri("/submit")
+        .body(Body::from(body))
+        .expect("failed to build request");
+    let expected_status = StatusCode::OK;
+
+    let actual = mozart
+        .oneshot(request)
+        .await
+        .expect("failed to execute oneshot request");
+
+    let actual_status = actual.status();
+    let body_bytes = to_bytes(actual.into_body(), usize::MAX)
+        .await
+        .expect("failed to convert body to bytes");
+
+    let actual_body: SubmissionResult =
+        serde_json::from_slice(&body_bytes).expect("failed to deserialize response body");
+
+    assert_eq!(actual_status, expected_status);
+
+    if let SubmissionResult::Error { code, message } = actual_body {
+        assert_eq!(code, ErrorCode::UnknownToolchainVersion);
+        assert!(message.starts_with("the toolchain version '2.7' is not available"));
+    } else {
+        panic!("response body was not of error variant");
+    }
+}
+
+#[tokio::test]
+async fn structured_wrong_answer_multiple_output_parameters() {
+    let mozart = app(AppState::default());
+    let solution = [
+        "def solution(x: int) -> tuple[int, str]:",
+        "    return x + 1, str(x)",
+    ]
+    .join("\n");
+    // the typed values of multiple output parameters are an array
+    let test_cases = Box::new([TestCase {
+        id: 0,
+        input_parameters: Box::new([Parameter {
+            value_type: ParameterType::Int,
+            value: String::from("2"),
+        }]),
+        output_parameters: Box::new([
+            Parameter {
+                value_type: ParameterType::Int,
+                value: String::from("2"),
+            },
+            Parameter {
+                value_type: ParameterType::String,
+                value: String::from("2"),
+            },
+        ]),
+        stdin: None,
+        expected_error: None,
+        label: None,
+        hidden: false,
+    }]);
+    let submission = Submission {
+        solution,
+        test_cases,
+        unordered_lists: false,
+        files: Box::new([]),
+        checker: None,
+        reference_solution: None,
+        only_ids: None,
+        compiler_flags: Box::new([]),
+        debug: false,
+        float_tolerance: None,
+        toolchain_version: None,
+        metadata: None,
+    };
+    let body = serde_json::to_string(&submission).expect("failed to serialize submission");
+    let request = Builder::new()
+        .header("Content-Type", "application/json")
+        .method(Method::POST)
+        .uri("/submit")
+        .body(Body::from(body))
+        .expect("failed to build request");
+    let expected_status = StatusCode::OK;
+    let expected_body = SubmissionResult::Failure(Box::new([TestCaseResult {
+        id: 0,
+        test_result: TestResult::Failure(TestCaseFailureReason::WrongAnswer {
+            input_parameters: Box::new([Parameter {
+                value_type: ParameterType::Int,
+                value: String::from("2"),
+            }]),
+            actual: String::from(r#"(3, '2')"#),
+            expected: String::from(r#"(2, '2')"#),
+            actual_value: Some(json!([3, "2"])),
+            expected_value: Some(json!([2, "2"])),
+        }),
+        label: None,
+    }]));
+
+    let actual = mozart
+        .oneshot(request)
+        .await
+        .expect("failed to execute oneshot request");
+
+    let actual_status = actual.status();
+    let body_bytes = to_bytes(actual.into_body(), usize::MAX)
+        .await
+        .expect("failed to convert body to bytes");
+
+    let actual_body: SubmissionResult =
+        serde_json::from_slice(&body_bytes).expect("failed to deserialize response body");
+
+    assert_eq!(actual_status, expected_status);
+    assert_eq!(actual_body, expected_body);
+}
+
+#[tokio::test]
+async fn structured_wrong_answer_map() {
+    let mozart = app(AppState::default());
+    let solution = [
+        "def solution(n: int) -> dict[int, bool]:",
+        "    return {n: n % 2 == 0}",
+    ]
+    .join("\n");
+    // the keys of the typed value of a map are the text of the keys
+    let test_cases = Box::new([TestCase {
+        id: 0,
+        input_parameters: Box::new([Parameter {
+            value_type: ParameterType::Int,
+            value: String::from("3"),
+        }]),
+        output_parameters: Box::new([Parameter {
+            value_type: ParameterType::Map {
+                key: Box::new(ParameterType::Int),
+                value: Box::new(ParameterType::Bool),
+            },
+            value: String::from(r#"{"3": "true"}"#),
+        }]),
+        stdin: None,
+        expected_error: None,
+        label: None,
+        hidden: false,
+    }]);
+    let submission = Submission {
+        solution,
+        test_cases,
+        unordered_lists: false,
+        files: Box::new([]),
+        checker: None,
+        reference_solution: None,
+        only_ids: None,
+        compiler_flags: Box::new([]),
+        debug: false,
+        float_tolerance: None,
+        toolchain_version: None,
+        metadata: None,
+    };
+    let body = serde_json::to_string(&submission).expect("failed to serialize submission");
+    let request = Builder::new()
+        .header("Content-Type", "application/json")
+        .method(Method::POST)
+        .uri("/submit")
+        .body(Body::from(body))
+        .expect("failed to build request");
+    let expected_status = StatusCode::OK;
+    let expected_body = SubmissionResult::Failure(Box::new([TestCaseResult {
+        id: 0,
+        test_result: TestResult::Failure(TestCaseFailureReason::WrongAnswer {
+            input_parameters: Box::new([Parameter {
+                value_type: ParameterType::Int,
+                value: String::from("3"),
+            }]),
+            actual: String::from("{3: False}"),
+            expected: String::from("{3: True}"),
+            actual_value: Some(json!({ "3": false })),
+            expected_value: Some(json!({ "3": true })),
+        }),
+        label: None,
+    }]));
+
+    let actual = mozart
+        .oneshot(request)
+        .await
+        .expect("failed to execute oneshot request");
+
+    let actual_status = actual.status();
+    let body_bytes = to_bytes(actual.into_body(), usize::MAX)
+        .await
+        .expect("failed to convert body to bytes");
+
+    let actual_body: SubmissionResult =
+        serde_json::from_slice(&body_bytes).expect("failed to deserialize response body");
+
+    assert_eq!(actual_status, expected_status);
+    assert_eq!(actual_body, expected_body);
+}
+
+#[tokio::test]
+async fn solution_too_long() {
+    let mozart = app(AppState::new(Config {
+        max_solution_length: 10,
+        ..Config::default()
+    }));
+    let solution = ["def solution(x: int) -> int:", "    return x * 2"].join("\n");
+    let test_cases = Box::new([TestCase {
+        id: 0,
+        input_parameters: Box::new([Parameter {
+            value_type: ParameterType::Int,
+            value: String::from("2"),
+        }]),
+        output_parameters: Box::new([Parameter {
+            value_type: ParameterType::Int,
+            value: String::from("4"),
+        }]),
+        stdin: None,
+        expected_error: None,
+        label: None,
+        hidden: false,
+    }]);
+    let submission = Submission {
+        solution,
+        test_cases,
+        unordered_lists: false,
+        files: Box::new([]),
+        checker: None,
+        reference_solution: None,
+        only_ids: None,
+        compiler_flags: Box::new([]),
+        debug: false,
+        float_tolerance: None,
+        toolchain_version: None,
+        metadata: None,
+    };
+    let body = serde_json::to_string(&submission).expect("failed to serialize submission");
+    let request = Builder::new()
+        .header("Content-Type", "application/json")
+        .method(Method::POST)
+        .uri("/submit")
+        .body(Body::from(body))
+        .expect("failed to build request");
+    let expected_status = StatusCode::OK;
+    let expected_body = SubmissionResult::Error {
+        code: ErrorCode::SolutionTooLong,
+        message: String::from("the solution exceeded the limit of 10 bytes"),
+    };
+
+    let actual = mozart
+        .oneshot(request)
+        .await
+        .expect("failed to execute oneshot request");
+
+    let actual_status = actual.status();
+    let body_bytes = to_bytes(actual.into_body(), usize::MAX)
+        .await
+        .expect("failed to convert body to bytes");
+
+    let actual_body: SubmissionResult =
+        serde_json::from_slice(&body_bytes).expect("failed to deserialize response body");
+
+    assert_eq!(actual_status, expected_status);
+    assert_eq!(actual_body, expected_body);
+}
+
+#[tokio::test]
+async fn quoted_char_and_string() {
+    let mozart = app(AppState::new(Config {
+        quote_values: true,
+        ..Config::default()
+    }));
+    let solution = ["def solution(c: str, s: str) -> str:", "    return c + s"].join("\n");
+    // the input parameters are quoted like the actual and expected values
+    let test_cases = Box::new([TestCase {
+        id: 0,
+        input_parameters: Box::new([
+            Parameter {
+                value_type: ParameterType::Char,
+                value: String::from("b"),
+            },
+            Parameter {
+                value_type: ParameterType::String,
+                value: String::from("say hi"),
+            },
+        ]),
+        output_parameters: Box::new([Parameter {
+            value_type: ParameterType::String,
+            value: String::from("x"),
+        }]),
+        stdin: None,
+        expected_error: None,
+        label: None,
+        hidden: false,
+    }]);
+    let submission = Submission {
+        solution,
+        test_cases,
+        unordered_lists: false,
+        files: Box::new([]),
+        checker: None,
+        reference_solution: None,
+        only_ids: None,
+        compiler_flags: Box::new([]),
+        debug: false,
+        float_tolerance: None,
+        toolchain_version: None,
+        metadata: None,
+    };
+    let body = serde_json::to_string(&submission).expect("failed to serialize submission");
+    let request = Builder::new()
+        .header("Content-Type", "application/json")
+        .method(Method::POST)
+        .uri("/submit")
+        .body(Body::from(body))
+        .expect("failed to build request");
+    let expected_status = StatusCode::OK;
+    let expected_body = SubmissionResult::Failure(Box::new([TestCaseResult {
+        id: 0,
+        test_result: TestResult::Failure(TestCaseFailureReason::WrongAnswer {
+            input_parameters: Box::new([
+                Parameter {
+                    value_type: ParameterType::Char,
+                    value: String::from("'b'"),
+                },
+                Parameter {
+                    value_type: ParameterType::String,
+                    value: String::from("'say hi'"),
+                },
+            ]),
+            actual: String::from("'bsay hi'"),
+            expected: String::from("'x'"),
+            actual_value: None,
+            expected_value: None,
+        }),
+        label: None,
+    }]));
+
+    let actual = mozart
+        .oneshot(request)
+        .await
+        .expect("failed to execute oneshot request");
+
+    let actual_status = actual.status();
+    let body_bytes = to_bytes(actual.into_body(), usize::MAX)
+        .await
+        .expect("failed to convert body to bytes");
+
+    let actual_body: SubmissionResult =
+        serde_json::from_slice(&body_bytes).expect("failed to deserialize response body");
+
+    assert_eq!(actual_status, expected_status);
+    assert_eq!(actual_body, expected_body);
+}
+
+#[tokio::test]
+async fn chatty_solution() {
+    let mozart = app(AppState::new(Config {
+        python_modules: Box::new([String::from("os")]),
+        ..Config::default()
+    }));
+    let solution = [
+        "import os",
+        "",
+        "def solution(x: int) -> int:",
+        "    os.write(2, b\"x\" * 500000)",
+        "    return x",
+    ]
+    .join("\n");
+    // the solution writes far more than the buffer of a pipe, which must not block it until the timeout
+    let test_cases = Box::new([TestCase {
+        id: 0,
+        input_parameters: Box::new([Parameter {
+            value_type: ParameterType::Int,
+            value: String::from("2"),
+        }]),
+        output_parameters: Box::new([Parameter {
+            value_type: ParameterType::Int,
+            value: String::from("2"),
+        }]),
+        stdin: None,
+        expected_error: None,
+        label: None,
+        hidden: false,
+    }]);
+    let submission = Submission {
+        solution,
+        test_cases,
+        unordered_lists: false,
+        files: Box::new([]),
+        checker: None,
+        reference_solution: None,
+        only_ids: None,
+        compiler_flags: Box::new([]),
+        debug: false,
+        float_tolerance: None,
+        toolchain_version: None,
+        metadata: None,
+    };
+    let body = serde_json::to_string(&submission).expect("failed to serialize submission");
+    let request = Builder::new()
+        .header("Content-Type", "application/json")
+        .method(Method::POST)
+        .uri("/submit")
+        .body(Body::from(body))
+        .expect("failed to build request");
+    let expected_status = StatusCode::OK;
+    let expected_body = SubmissionResult::Pass;
+
+    let start = Instant::now();
+    let actual = mozart
+        .oneshot(request)
+        .await
+        .expect("failed to execute oneshot request");
+
+    let actual_status = actual.status();
+    let body_bytes = to_bytes(actual.into_body(), usize::MAX)
+        .await
+        .expect("failed to convert body to bytes");
+
+    let actual_body: SubmissionResult =
+        serde_json::from_slice(&body_bytes).expect("failed to deserialize response body");
+
+    assert_eq!(actual_status, expected_status);
+    assert_eq!(actual_body, expected_body);
+    assert!(start.elapsed() < Config::default().timeout);
+}
+
+#[tokio::test]
+async fn expected_error() {
+    let mozart = app(AppState::default());
+    let solution = [
+        "def solution(x: int) -> int:",
+        "    if x < 0:",
+        "        raise ValueError(\"negative input\")",
+        "    return x + x",
+    ]
+    .join("\n");
+    // the solution should raise an error for a negative input
+    let test_cases = Box::new([
+        TestCase {
+            id: 0,
+            input_parameters: Box::new([Parameter {
+                value_type: ParameterType::Int,
+                value: String::from("2"),
+            }]),
+            output_parameters: Box::new([Parameter {
+                value_type: ParameterType::Int,
+                value: String::from("4"),
+            }]),
+            stdin: None,
+            expected_error: None,
+            label: None,
+            hidden: false,
+        },
+        TestCase {
+            id: 1,
+            input_parameters: Box::new([Parameter {
+                value_type: ParameterType::Int,
+                value: String::from("-1"),
+            }]),
+            output_parameters: Box::new([]),
+            stdin: None,
+            expected_error: Some(ExpectedError {
+                message: Some(String::from("negative input")),
+            }),
+            label: None,
+            hidden: false,
+        },
+        TestCase {
+            id: 2,
+            input_parameters: Box::new([Parameter {
+                value_type: ParameterType::Int,
+                value: String::from("-3"),
+            }]),
+            output_parameters: Box::new([]),
+            stdin: None,
+            expected_error: Some(ExpectedError { message: None }),
+            label: None,
+            hidden: false,
+        },
+    ]);
+    let submission = Submission {
+        solution,
+        test_cases,
+        unordered_lists: false,
+        files: Box::new([]),
+        checker: None,
+        reference_solution: None,
+        only_ids: None,
+        compiler_flags: Box::new([]),
+        debug: false,
+        float_tolerance: None,
+        toolchain_version: None,
+        metadata: None,
+    };
+    let body = serde_json::to_string(&submission).expect("failed to serialize submission");
+    let request = Builder::new()
+        .header("Content-Type", "application/json")
+        .method(Method::POST)
+        .uri("/submit")
+        .body(Body::from(body))
+        .expect("failed to build request");
+    let expected_status = StatusCode::OK;
+    let expected_body = SubmissionResult::Pass;
+
+    let actual = mozart
+        .oneshot(request)
+        .await
+        .expect("failed to execute oneshot request");
+
+    let actual_status = actual.status();
+    let body_bytes = to_bytes(actual.into_body(), usize::MAX)
+        .await
+        .expect("failed to convert body to bytes");
+
+    let actual_body: SubmissionResult =
+        serde_json::from_slice(&body_bytes).expect("failed to deserialize response body");
+
+    assert_eq!(actual_status, expected_status);
+    assert_eq!(actual_body, expected_body);
+}
+
+#[tokio::test]
+async fn missing_error() {
+    let mozart = app(AppState::default());
+    let solution = [
+        "def solution(x: int) -> int:",
+        "    if x < 0:",
+        "        raise ValueError(\"negative input\")",
+        "    return x + x",
+    ]
+    .join("\n");
+    let test_cases = Box::new([TestCase {
+        id: 0,
+        input_parameters: Box::new([Parameter {
+            value_type: ParameterType::Int,
+            value: String::from("2"),
+        }]),
+        output_parameters: Box::new([]),
+        stdin: None,
+        expected_error: Some(ExpectedError { message: None }),
+        label: None,
+        hidden: false,
+    }]);
+    let submission = Submission {
+        solution,
+        test_cases,
+        unordered_lists: false,
+        files: Box::new([]),
+        checker: None,
+        reference_solution: None,
+        only_ids: None,
+        compiler_flags: Box::new([]),
+        debug: false,
+        float_tolerance: None,
+        toolchain_version: None,
+        metadata: None,
+    };
+    let body = serde_json::to_string(&submission).expect("failed to serialize submission");
+    let request = Builder::new()
+        .header("Content-Type", "application/json")
+        .method(Method::POST)
+        .uri("/submit")
+        .body(Body::from(body))
+        .expect("failed to build request");
+    let expected_status = StatusCode::OK;
+    let expected_body = SubmissionResult::Failure(Box::new([TestCaseResult {
+        id: 0,
+        test_result: TestResult::Failure(TestCaseFailureReason::MissingError {
+            input_parameters: Box::new([Parameter {
+                value_type: ParameterType::Int,
+                value: String::from("2"),
+            }]),
+            actual: String::from("4"),
+        }),
+        label: None,
+    }]));
+
+    let actual = mozart
+        .oneshot(request)
+        .await
+        .expect("failed to execute oneshot request");
+
+    let actual_status = actual.status();
+    let body_bytes = to_bytes(actual.into_body(), usize::MAX)
+        .await
+        .expect("failed to convert body to bytes");
+
+    let actual_body: SubmissionResult =
+        serde_json::from_slice(&body_bytes).expect("failed to deserialize response body");
+
+    assert_eq!(actual_status, expected_status);
+    assert_eq!(actual_body, expected_body);
+}
+
+#[tokio::test]
+async fn expected_nan() {
+    let mozart = app(AppState::default());
+    let solution = ["def solution(x: float) -> float:", "    return x * 0"].join("\n");
+    // an infinity multiplied by zero is NaN
+    let test_cases = Box::new([TestCase {
+        id: 0,
+        input_parameters: Box::new([Parameter {
+            value_type: ParameterType::Float,
+            value: String::from("Infinity"),
+        }]),
+        output_parameters: Box::new([Parameter {
+            value_type: ParameterType::Float,
+            value: String::from("NaN"),
+        }]),
+        stdin: None,
+        expected_error: None,
+        label: None,
+        hidden: false,
+    }]);
+    let submission = Submission {
+        solution,
+        test_cases,
+        unordered_lists: false,
+        files: Box::new([]),
+        checker: None,
+        reference_solution: None,
+        only_ids: None,
+        compiler_flags: Box::new([]),
+        debug: false,
+        float_tolerance: Some(FloatTolerance {
+            absolute: 0.0,
+            relative: 0.0,
+            match_nan: true,
+        }),
+        toolchain_version: None,
+        metadata: None,
+    };
+    let body = serde_json::to_string(&submission).expect("failed to serialize submission");
+    let request = Builder::new()
+        .header("Content-Type", "application/json")
+        .method(Method::POST)
+        .uri("/submit")
+        .body(Body::from(body))
+        .expect("failed to build request");
+    let expected_status = StatusCode::OK;
+    let expected_body = SubmissionResult::Pass;
+
+    let actual = mozart
+        .oneshot(request)
+        .await
+        .expect("failed to execute oneshot request");
+
+    let actual_status = actual.status();
+    let body_bytes = to_bytes(actual.into_body(), usize::MAX)
+        .await
+        .expect("failed to convert body to bytes");
+
+    let actual_body: SubmissionResult =
+        serde_json::from_slice(&body_bytes).expect("failed to deserialize response body");
+
+    assert_eq!(actual_status, expected_status);
+    assert_eq!(actual_body, expected_body);
+}
+
+#[tokio::test]
+async fn expected_infinity() {
+    let mozart = app(AppState::default());
+    let solution = ["def solution(x: float) -> float:", "    return x * 2"].join("\n");
+    let test_cases = Box::new([
+        TestCase {
+            id: 0,
+            input_parameters: Box::new([Parameter {
+                value_type: ParameterType::Float,
+                value: String::from("Infinity"),
+            }]),
+            output_parameters: Box::new([Parameter {
+                value_type: ParameterType::Float,
+                value: String::from("Infinity"),
+            }]),
+            stdin: None,
+            expected_error: None,
+            label: None,
+            hidden: false,
+        },
+        TestCase {
+            id: 1,
+            input_parameters: Box::new([Parameter {
+                value_type: ParameterType::Float,
+                value: String::from("-Infinity"),
+            }]),
+            output_parameters: Box::new([Parameter {
+                value_type: ParameterType::Float,
+                value: String::from("-Infinity"),
+            }]),
+            stdin: None,
+            expected_error: None,
+            label: None,
+            hidden: false,
+        },
+        TestCase {
+            id: 2,
+            input_parameters: Box::new([Parameter {
+                value_type: ParameterType::Float,
+                value: String::from("1.5"),
+            }]),
+            output_parameters: Box::new([Parameter {
+                value_type: ParameterType::Float,
+                value: String::from("3.0"),
+            }]),
+            stdin: None,
+            expected_error: None,
+            label: None,
+            hidden: false,
+        },
+    ]);
+    let submission = Submission {
+        solution,
+        test_cases,
+        unordered_lists: false,
+        files: Box::new([]),
+        checker: None,
+        reference_solution: None,
+        only_ids: None,
+        compiler_flags: Box::new([]),
+        debug: false,
+        float_tolerance: Some(FloatTolerance {
+            absolute: 0.0,
+            relative: 1e-9,
+            match_nan: false,
+        }),
+        toolchain_version: None,
+        metadata: None,
+    };
+    let body = serde_json::to_string(&submission).expect("failed to serialize submission");
+    let request = Builder::new()
+        .header("Content-Type", "application/json")
+        .method(Method::POST)
+        .uri("/submit")
+        .body(Body::from(body))
+        .expect("failed to build request");
+    let expected_status = StatusCode::OK;
+    let expected_body = SubmissionResult::Pass;
+
+    let actual = mozart
+        .oneshot(request)
+        .await
+        .expect("failed to execute oneshot request");
+
+    let actual_status = actual.status();
+    let body_bytes = to_bytes(actual.into_body(), usize::MAX)
+        .await
+        .expect("failed to convert body to bytes");
+
+    let actual_body: SubmissionResult =
+        serde_json::from_slice(&body_bytes).expect("failed to deserialize response body");
+
+    assert_eq!(actual_status, expected_status);
+    assert_eq!(actual_body, expected_body);
+}
+
+#[tokio::test]
+async fn infinite_loop_in_later_test_case() {
+    let mozart = app(AppState::new(Config {
+        timeout: Duration::from_secs(2),
+        test_case_timeout: Duration::from_secs(10),
+        ..Config::default()
+    }));
+    let solution = [
+        "def solution(x: int) -> int:",
+        "    while x == 0:",
+        "        pass",
+        "    return x",
+    ]
+    .join("\n");
+    // the execution is killed before the third test case reaches its own timeout, which keeps the results of the first two
+    let test_cases = Box::new([
+        TestCase {
+            id: 0,
+            input_parameters: Box::new([Parameter {
+                value_type: ParameterType::Int,
+                value: String::from("1"),
+            }]),
+            output_parameters: Box::new([Parameter {
+                value_type: ParameterType::Int,
+                value: String::from("1"),
+            }]),
+            stdin: None,
+            expected_error: None,
+            label: None,
+            hidden: false,
+        },
+        TestCase {
+            id: 1,
+            input_parameters: Box::new([Parameter {
+                value_type: ParameterType::Int,
+                value: String::from("2"),
+            }]),
+            output_parameters: Box::new([Parameter {
+                value_type: ParameterType::Int,
+                value: String::from("5"),
+            }]),
+            stdin: None,
+            expected_error: None,
+            label: None,
+            hidden: false,
+        },
+        TestCase {
+            id: 2,
+            input_parameters: Box::new([Parameter {
+                value_type: ParameterType::Int,
+                value: String::from("0"),
+            }]),
+            output_parameters: Box::new([Parameter {
+                value_type: ParameterType::Int,
+                value: String::from("0"),
+            }]),
+            stdin: None,
+            expected_error: None,
+            label: None,
+            hidden: false,
+        },
+        TestCase {
+            id: 3,
+            input_parameters: Box::new([Parameter {
+                value_type: ParameterType::Int,
+                value: String::from("4"),
+            }]),
+            output_parameters: Box::new([Parameter {
+                value_type: ParameterType::Int,
+                value: String::from("4"),
+            }]),
+            stdin: None,
+            expected_error: None,
+            label: None,
+            hidden: false,
+        },
+    ]);
+    let submission = Submission {
+        solution,
+        test_cases,
+        unordered_lists: false,
+        files: Box::new([]),
+        checker: None,
+        reference_solution: None,
+        only_ids: None,
+        compiler_flags: Box::new([]),
+        debug: false,
+        float_tolerance: None,
+        toolchain_version: None,
+        metadata: None,
+    };
+    let body = serde_json::to_string(&submission).expect("failed to serialize submission");
+    let request = Builder::new()
+        .header("Content-Type", "application/json")
+        .method(Method::POST)
+        .uri("/submit")
+        .body(Body::from(body))
+        .expect("failed to build request");
+    let expected_status = StatusCode::OK;
+    let expected_body = SubmissionResult::Failure(Box::new([
+        TestCaseResult {
+            id: 0,
+            test_result: TestResult::Pass,
+            label: None,
+        },
+        TestCaseResult {
+            id: 1,
+            test_result: TestResult::Failure(TestCaseFailureReason::WrongAnswer {
+                input_parameters: Box::new([Parameter {
+                    value_type: ParameterType::Int,
+                    value: String::from("2"),
+                }]),
+                actual: String::from("2"),
+                expected: String::from("5"),
+                actual_value: None,
+                expected_value: None,
+            }),
+            label: None,
+        },
+        TestCaseResult {
+            id: 2,
+            test_result: TestResult::Failure(TestCaseFailureReason::Timeout),
+            label: None,
+        },
+        TestCaseResult {
+            id: 3,
+            test_result: TestResult::Unknown,
+            label: None,
+        },
+    ]));
+
+    let actual = mozart
+        .oneshot(request)
+        .await
+        .expect("failed to execute oneshot request");
+
+    let actual_status = actual.status();
+    let body_bytes = to_bytes(actual.into_body(), usize::MAX)
+        .await
+        .expect("failed to convert body to bytes");
+
+    let actual_body: SubmissionResult =
+        serde_json::from_slice(&body_bytes).expect("failed to deserialize response body");
+
+    assert_eq!(actual_status, expected_status);
+    assert_eq!(actual_body, expected_body);
+}
+
+#[tokio::test]
+async fn decimal_exact_arithmetic() {
+    let mozart = app(AppState::default());
+    let solution = [
+        "from decimal import Decimal",
+        "",
+        "def solution(a: Decimal, b: Decimal) -> Decimal:",
+        "    return a + b",
+    ]
+    .join("\n");
+    // unlike floats, the sum of the decimals is exactly the expected decimal
+    let test_cases = Box::new([
+        TestCase {
+            id: 0,
+            input_parameters: Box::new([
+                Parameter {
+                    value_type: ParameterType::Decimal,
+                    value: String::from("0.1"),
+                },
+                Parameter {
+                    value_type: ParameterType::Decimal,
+                    value: String::from("0.2"),
+                },
+            ]),
+            output_parameters: Box::new([Parameter {
+                value_type: ParameterType::Decimal,
+                value: String::from("0.3"),
+            }]),
+            stdin: None,
+            expected_error: None,
+            label: None,
+            hidden: false,
+        },
+        TestCase {
+            id: 1,
+            input_parameters: Box::new([
+                Parameter {
+                    value_type: ParameterType::Decimal,
+                    value: String::from("10.25"),
+                },
+                Parameter {
+                    value_type: ParameterType::Decimal,
+                    value: String::from("-0.05"),
+                },
+            ]),
+            output_parameters: Box::new([Parameter {
+                value_type: ParameterType::Decimal,
+                value: String::from("10.20"),
+            }]),
+            stdin: None,
+            expected_error: None,
+            label: None,
+            hidden: false,
+        },
+    ]);
+    let submission = Submission {
+        solution,
+        test_cases,
+        unordered_lists: false,
+        files: Box::new([]),
+        checker: None,
+        reference_solution: None,
+        only_ids: None,
+        compiler_flags: Box::new([]),
+        debug: false,
+        float_tolerance: None,
+        toolchain_version: None,
+        metadata: None,
+    };
+    let body = serde_json::to_string(&submission).expect("failed to serialize submission");
+    let request = Builder::new()
+        .header("Content-Type", "application/json")
+        .method(Method::POST)
+        .uri("/submit")
+        .body(Body::from(body))
+        .expect("failed to build request");
+    let expected_status = StatusCode::OK;
+    let expected_body = SubmissionResult::Pass;
+
+    let actual = mozart
+        .oneshot(request)
+        .await
+        .expect("failed to execute oneshot request");
+
+    let actual_status = actual.status();
+    let body_bytes = to_bytes(actual.into_body(), usize::MAX)
+        .await
+        .expect("failed to convert body to bytes");
+
+    let actual_body: SubmissionResult =
+        serde_json::from_slice(&body_bytes).expect("failed to deserialize response body");
+
+    assert_eq!(actual_status, expected_status);
+    assert_eq!(actual_body, expected_body);
+}
+
+#[tokio::test]
+async fn decimal_float_arithmetic_fails() {
+    let mozart = app(AppState::default());
+    let solution = [
+        "from decimal import Decimal",
+        "",
+        "def solution(a: Decimal, b: Decimal) -> float:",
+        "    return float(a) + float(b)",
+    ]
+    .join("\n");
+    // the sum of the decimals as floats is not exactly the expected decimal
+    let test_cases = Box::new([TestCase {
+        id: 0,
+        input_parameters: Box::new([
+            Parameter {
+                value_type: ParameterType::Decimal,
+                value: String::from("0.1"),
+            },
+            Parameter {
+                value_type: ParameterType::Decimal,
+                value: String::from("0.2"),
+            },
+        ]),
+        output_parameters: Box::new([Parameter {
+            value_type: ParameterType::Decimal,
+            value: String::from("0.3"),
+        }]),
+        stdin: None,
+        expected_error: None,
+        label: None,
+        hidden: false,
+    }]);
+    let submission = Submission {
+        solution,
+        test_cases,
+        unordered_lists: false,
+        files: Box::new([]),
+        checker: None,
+        reference_solution: None,
+        only_ids: None,
+        compiler_flags: Box::new([]),
+        debug: false,
+        float_tolerance: None,
+        toolchain_version: None,
+        metadata: None,
+    };
+    let body = serde_json::to_string(&submission).expect("failed to serialize submission");
+    let request = Builder::new()
+        .header("Content-Type", "application/json")
+        .method(Method::POST)
+        .uri("/submit")
+        .body(Body::from(body))
+        .expect("failed to build request");
+    let expected_status = StatusCode::OK;
+    let expected_body = SubmissionResult::Failure(Box::new([TestCaseResult {
+        id: 0,
+        test_result: TestResult::Failure(TestCaseFailureReason::WrongAnswer {
+            input_parameters: Box::new([
+                Parameter {
+                    value_type: ParameterType::Decimal,
+                    value: String::from("0.1"),
+                },
+                Parameter {
+                    value_type: ParameterType::Decimal,
+                    value: String::from("0.2"),
+                },
+            ]),
+            actual: String::from("0.30000000000000004"),
+            expected: String::from("0.3"),
+            actual_value: None,
+            expected_value: None,
+        }),
+        label: None,
+    }]));
+
+    let actual = mozart
+        .oneshot(request)
+        .await
+        .expect("failed to execute oneshot request");
+
+    let actual_status = actual.status();
+    let body_bytes = to_bytes(actual.into_body(), usize::MAX)
+        .await
+        .expect("failed to convert body to bytes");
+
+    let actual_body: SubmissionResult =
+        serde_json::from_slice(&body_bytes).expect("failed to deserialize response body");
+
+    assert_eq!(actual_status, expected_status);
+    assert_eq!(actual_body, expected_body);
+}
+
+#[tokio::test]
+async fn labels_are_echoed() {
+    let mozart = app(AppState::default());
+    let solution = ["def solution(x: int) -> int:", "    return x + x"].join("\n");
+    let test_cases = Box::new([
+        TestCase {
+            id: 0,
+            input_parameters: Box::new([Parameter {
+                value_type: ParameterType::Int,
+                value: String::from("0"),
+            }]),
+            output_parameters: Box::new([Parameter {
+                value_type: ParameterType::Int,
+                value: String::from("0"),
+            }]),
+            stdin: None,
+            expected_error: None,
+            label: Some(String::from("edge case")),
+            hidden: false,
+        },
+        TestCase {
+            id: 1,
+            input_parameters: Box::new([Parameter {
+                value_type: ParameterType::Int,
+                value: String::from("5"),
+            }]),
+            output_parameters: Box::new([Parameter {
+                value_type: ParameterType::Int,
+                value: String::from("11"),
+            }]),
+            stdin: None,
+            expected_error: None,
+            label: Some(String::from("hidden")),
+            hidden: false,
+        },
+        TestCase {
+            id: 2,
+            input_parameters: Box::new([Parameter {
+                value_type: ParameterType::Int,
+                value: String::from("2"),
+            }]),
+            output_parameters: Box::new([Parameter {
+                value_type: ParameterType::Int,
+                value: String::from("4"),
+            }]),
+            stdin: None,
+            expected_error: None,
+            label: None,
+            hidden: false,
+        },
+    ]);
+    let submission = Submission {
+        solution,
+        test_cases,
+        unordered_lists: false,
+        files: Box::new([]),
+        checker: None,
+        reference_solution: None,
+        only_ids: None,
+        compiler_flags: Box::new([]),
+        debug: false,
+        float_tolerance: None,
+        toolchain_version: None,
+        metadata: None,
+    };
+    let body = serde_json::to_string(&submission).expect("failed to serialize submission");
+    let request = Builder::new()
+        .header("Content-Type", "application/json")
+        .method(Method::POST)
+        .uri("/submit")
+        .body(Body::from(body))
+        .expect("failed to build request");
+    let expected_status = StatusCode::OK;
+    let expected_body = SubmissionResult::Failure(Box::new([
+        TestCaseResult {
+            id: 0,
+            test_result: TestResult::Pass,
+            label: Some(String::from("edge case")),
+        },
+        TestCaseResult {
+            id: 1,
+            test_result: TestResult::Failure(TestCaseFailureReason::WrongAnswer {
+                input_parameters: Box::new([Parameter {
+                    value_type: ParameterType::Int,
+                    value: String::from("5"),
+                }]),
+                actual: String::from("10"),
+                expected: String::from("11"),
+                actual_value: None,
+                expected_value: None,
+            }),
+            label: Some(String::from("hidden")),
+        },
+        TestCaseResult {
+            id: 2,
+            test_result: TestResult::Pass,
+            label: None,
+        },
+    ]));
+
+    let actual = mozart
+        .oneshot(request)
+        .await
+        .expect("failed to execute oneshot request");
+
+    let actual_status = actual.status();
+    let body_bytes = to_bytes(actual.into_body(), usize::MAX)
+        .await
+        .expect("failed to convert body to bytes");
+
+    let actual_body: SubmissionResult =
+        serde_json::from_slice(&body_bytes).expect("failed to deserialize response body");
+
+    assert_eq!(actual_status, expected_status);
+    assert_eq!(actual_body, expected_body);
+}
+
+#[tokio::test]
+async fn stdout_is_returned() {
+    let mozart = app(AppState::default());
+    let solution = [
+        "def solution(x: int) -> int:",
+        "    print(\"debug\", x)",
+        "    return x + x",
+    ]
+    .join("\n");
+    let test_cases = Box::new([
+        TestCase {
+            id: 0,
+            input_parameters: Box::new([Parameter {
+                value_type: ParameterType::Int,
+                value: String::from("2"),
+            }]),
+            output_parameters: Box::new([Parameter {
+                value_type: ParameterType::Int,
+                value: String::from("4"),
+            }]),
+            stdin: None,
+            expected_error: None,
+            label: None,
+            hidden: false,
+        },
+        TestCase {
+            id: 1,
+            input_parameters: Box::new([Parameter {
+                value_type: ParameterType::Int,
+                value: String::from("5"),
+            }]),
+            output_parameters: Box::new([Parameter {
+                value_type: ParameterType::Int,
+                value: String::from("10"),
+            }]),
+            stdin: None,
+            expected_error: None,
+            label: None,
+            hidden: false,
+        },
+    ]);
+    let submission = Submission {
+        solution,
+        test_cases,
+        unordered_lists: false,
+        files: Box::new([]),
+        checker: None,
+        reference_solution: None,
+        only_ids: None,
+        compiler_flags: Box::new([]),
+        debug: false,
+        float_tolerance: None,
+        toolchain_version: None,
+        metadata: None,
+    };
+    let body = serde_json::to_string(&submission).expect("failed to serialize submission");
+    let request = Builder::new()
+        .header("Content-Type", "application/json")
+        .method(Method::POST)
+        .uri("/submit")
+        .body(Body::from(body))
+        .expect("failed to build request");
+    let expected_status = StatusCode::OK;
+    let expected_result = SubmissionResult::Pass;
+    let expected_stdout = "debug 2\ndebug 5\n";
+
+    let actual = mozart
+        .oneshot(request)
+        .await
+        .expect("failed to execute oneshot request");
+
+    let actual_status = actual.status();
+    let body_bytes = to_bytes(actual.into_body(), usize::MAX)
+        .await
+        .expect("failed to convert body to bytes");
+
+    let actual_body: Value =
+        serde_json::from_slice(&body_bytes).expect("failed to deserialize response body");
+    let actual_result: SubmissionResult =
+        serde_json::from_slice(&body_bytes).expect("failed to deserialize result");
+
+    assert_eq!(actual_status, expected_status);
+    assert_eq!(actual_result, expected_result);
+    assert_eq!(actual_body["stdout"], expected_stdout);
+}
+
+#[tokio::test]
+async fn printed_outcome_is_not_graded() {
+    let mozart = app(AppState::default());
+    // an outcome the solution prints is only part of its stdout, and cannot pass a test case
+    let solution = [
+        "def solution(x: int) -> int:",
+        "    print('{\"r\":\"p\"}')",
+        "    return x + x",
+    ]
+    .join("\n");
+    let test_cases = Box::new([
+        TestCase {
+            id: 0,
+            input_parameters: Box::new([Parameter {
+                value_type: ParameterType::Int,
+                value: String::from("2"),
+            }]),
+            output_parameters: Box::new([Parameter {
+                value_type: ParameterType::Int,
+                value: String::from("4"),
+            }]),
+            stdin: None,
+            expected_error: None,
+            label: None,
+            hidden: false,
+        },
+        TestCase {
+            id: 1,
+            input_parameters: Box::new([Parameter {
+                value_type: ParameterType::Int,
+                value: String::from("5"),
+            }]),
+            output_parameters: Box::new([Parameter {
+                value_type: ParameterType::Int,
+                value: String::from("11"),
+            }]),
+            stdin: None,
+            expected_error: None,
+            label: None,
+            hidden: false,
+        },
+    ]);
+    let submission = Submission {
+        solution,
+        test_cases,
+        unordered_lists: false,
+        files: Box::new([]),
+        checker: None,
+        reference_solution: None,
+        only_ids: None,
+        compiler_flags: Box::new([]),
+        debug: false,
+        float_tolerance: None,
+        toolchain_version: None,
+        metadata: None,
+    };
+    let body = serde_json::to_string(&submission).expect("failed to serialize submission");
+    let request = Builder::new()
+        .header("Content-Type", "application/json")
+        .method(Method::POST)
+        .uri("/submit")
+        .body(Body::from(body))
+        .expect("failed to build request");
+    let expected_status = StatusCode::OK;
+    let expected_result = SubmissionResult::Failure(Box::new([
+        TestCaseResult {
+            id: 0,
+            test_result: TestResult::Pass,
+            label: None,
+        },
+        TestCaseResult {
+            id: 1,
+            test_result: TestResult::Failure(TestCaseFailureReason::WrongAnswer {
+                input_parameters: Box::new([Parameter {
+                    value_type: ParameterType::Int,
+                    value: String::from("5"),
+                }]),
+                actual: String::from("10"),
+                expected: String::from("11"),
+                actual_value: None,
+                expected_value: None,
+            }),
+            label: None,
+        },
+    ]));
+    let expected_stdout = "{\"r\":\"p\"}\n{\"r\":\"p\"}\n";
+
+    let actual = mozart
+        .oneshot(request)
+        .await
+        .expect("failed to execute oneshot request");
+
+    let actual_status = actual.status();
+    let body_bytes = to_bytes(actual.into_body(), usize::MAX)
+        .await
+        .expect("failed to convert body to bytes");
+
+    let actual_body: Value =
+        serde_json::from_slice(&body_bytes).expect("failed to deserialize response body");
+    let actual_result: SubmissionResult =
+        serde_json::from_slice(&body_bytes).expect("failed to deserialize result");
+
+    assert_eq!(actual_status, expected_status);
+    assert_eq!(actual_result, expected_result);
+    assert_eq!(actual_body["stdout"], expected_stdout);
+}
+
+#[tokio::test]
+async fn set_iteration_order_is_deterministic() {
+    let solution = [
+        "def solution(s: str) -> str:",
+        "    return \",\".join(set(s.split()))",
+    ]
+    .join("\n");
+    // the expected value is never the actual one, such that the actual iteration order is reported
+    let test_cases = Box::new([TestCase {
+        id: 0,
+        input_parameters: Box::new([Parameter {
+            value_type: ParameterType::String,
+            value: String::from("apple banana cherry date elderberry fig grape"),
+        }]),
+        output_parameters: Box::new([Parameter {
+            value_type: ParameterType::String,
+            value: String::new(),
+        }]),
+        stdin: None,
+        expected_error: None,
+        label: None,
+        hidden: false,
+    }]);
+    let submission = Submission {
+        solution,
+        test_cases,
+        unordered_lists: false,
+        files: Box::new([]),
+        checker: None,
+        reference_solution: None,
+        only_ids: None,
+        compiler_flags: Box::new([]),
+        debug: false,
+        float_tolerance: None,
+        toolchain_version: None,
+        metadata: None,
+    };
+
+    // every execution is a new process, which would seed the string hashing differently if it was random
+    let mut actual_orders = Vec::new();
+    for _ in 0..3 {
+        let actual = check_submission(submission.clone(), AppState::default()).await;
+        let SubmissionResult::Failure(test_case_results) = actual else {
+            panic!("response body was not of failure variant");
+        };
+        let TestResult::Failure(TestCaseFailureReason::WrongAnswer { actual, .. }) =
+            &test_case_results[0].test_result
+        else {
+            panic!("test case was not a wrong answer");
+        };
+        actual_orders.push(actual.clone());
+    }
+
+    assert!(actual_orders.iter().all(|order| *order == actual_orders[0]));
+}
+
+#[tokio::test]
+async fn solution_with_wrong_arity() {
+    let solution = ["def solution(x: int, y: int) -> int:", "    return x + y"].join("\n");
+    let test_cases = Box::new([TestCase {
+        id: 0,
+        input_parameters: Box::new([Parameter {
+            value_type: ParameterType::Int,
+            value: String::from("2"),
+        }]),
+        output_parameters: Box::new([Parameter {
+            value_type: ParameterType::Int,
+            value: String::from("4"),
+        }]),
+        stdin: None,
+        expected_error: None,
+        label: None,
+        hidden: false,
+    }]);
+    let submission = Submission {
+        solution,
+        test_cases,
+        unordered_lists: false,
+        files: Box::new([]),
+        checker: None,
+        reference_solution: None,
+        only_ids: None,
+        compiler_flags: Box::new([]),
+        debug: false,
+        float_tolerance: None,
+        toolchain_version: None,
+        metadata: None,
+    };
+    let expected = SubmissionResult::Failure(Box::new([TestCaseResult {
+        id: 0,
+        test_result: TestResult::Failure(TestCaseFailureReason::RuntimeError(String::from(
+            "solution expects 2 arguments but test case provides 1",
+        ))),
+        label: None,
+    }]));
+
+    let actual = check_submission(submission, AppState::default()).await;
+
+    assert_eq!(actual, expected);
+}
+
+#[tokio::test]
+async fn optional_int() {
+    let solution = [
+        "def solution(x: int | None) -> int | None:",
+        "    return None if x is None else x * 2",
+    ]
+    .join("\n");
+    let test_cases = Box::new([
+        TestCase {
+            id: 0,
+            input_parameters: Box::new([Parameter {
+                value_type: ParameterType::Optional(Box::new(ParameterType::Int)),
+                value: String::from(r#""5""#),
+            }]),
+            output_parameters: Box::new([Parameter {
+                value_type: ParameterType::Optional(Box::new(ParameterType::Int)),
+                value: String::from(r#""10""#),
+            }]),
+            stdin: None,
+            expected_error: None,
+            label: None,
+            hidden: false,
+        },
+        TestCase {
+            id: 1,
+            input_parameters: Box::new([Parameter {
+                value_type: ParameterType::Optional(Box::new(ParameterType::Int)),
+                value: String::from("null"),
+            }]),
+            output_parameters: Box::new([Parameter {
+                value_type: ParameterType::Optional(Box::new(ParameterType::Int)),
+                value: String::from("null"),
+            }]),
+            stdin: None,
+            expected_error: None,
+            label: None,
+            hidden: false,
+        },
+    ]);
+    let submission = Submission {
+        solution,
+        test_cases,
+        unordered_lists: false,
+        files: Box::new([]),
+        checker: None,
+        reference_solution: None,
+        only_ids: None,
+        compiler_flags: Box::new([]),
+        debug: false,
+        float_tolerance: None,
+        toolchain_version: None,
+        metadata: None,
+    };
+
+    let actual = check_submission(submission, AppState::default()).await;
+
+    assert_eq!(actual, SubmissionResult::Pass);
+}
+
+#[tokio::test]
+async fn optional_int_wrong_answer() {
+    let solution = [
+        "def solution(x: int | None) -> int | None:",
+        "    return None if x is None else x * 2",
+    ]
+    .join("\n");
+    let test_cases = Box::new([TestCase {
+        id: 0,
+        input_parameters: Box::new([Parameter {
+            value_type: ParameterType::Optional(Box::new(ParameterType::Int)),
+            value: String::from("null"),
+        }]),
+        output_parameters: Box::new([Parameter {
+            value_type: ParameterType::Optional(Box::new(ParameterType::Int)),
+            value: String::from(r#""0""#),
+        }]),
+        stdin: None,
+        expected_error: None,
+        label: None,
+        hidden: false,
+    }]);
+    let submission = Submission {
+        solution,
+        test_cases,
+        unordered_lists: false,
+        files: Box::new([]),
+        checker: None,
+        reference_solution: None,
+        only_ids: None,
+        compiler_flags: Box::new([]),
+        debug: false,
+        float_tolerance: None,
+        toolchain_version: None,
+        metadata: None,
+    };
+    let expected = SubmissionResult::Failure(Box::new([TestCaseResult {
+        id: 0,
+        test_result: TestResult::Failure(TestCaseFailureReason::WrongAnswer {
+            input_parameters: Box::new([Parameter {
+                value_type: ParameterType::Optional(Box::new(ParameterType::Int)),
+                value: String::from("null"),
+            }]),
+            actual: String::from("None"),
+            expected: String::from("0"),
+            actual_value: None,
+            expected_value: None,
+        }),
+        label: None,
+    }]));
+
+    let actual = check_submission(submission, AppState::default()).await;
+
+    assert_eq!(actual, expected);
+}
@@ -0,0 +1,8 @@
+use mozart::{state::AppState, warmup};
+
+#[tokio::test]
+async fn working_toolchain() {
+    let actual = warmup(AppState::default()).await;
+
+    assert!(actual.is_ok());
+}
@@ -0,0 +1,141 @@
+use axum::{
+    body::Body,
+    http::{request::Builder, Method, StatusCode},
+};
+use mozart::{app, config::Config, state::AppState, warmup};
+use std::{fs, path::PathBuf};
+use tower::ServiceExt;
+use uuid::Uuid;
+
+#[tokio::test]
+async fn delete_existing_workdir() {
+    let state = AppState::default();
+    let uuid = Uuid::new_v4();
+    let workdir = state.config.working_directory(&uuid);
+    fs::create_dir_all(&workdir).expect("failed to create working directory");
+    fs::write(workdir.join("solution.py"), "").expect("failed to create file");
+    let mozart = app(state);
+    let request = Builder::new()
+        .method(Method::DELETE)
+        .uri(format!("/workdir/{uuid}"))
+        .body(Body::empty())
+        .expect("failed to build request");
+    let expected_status_code = StatusCode::NO_CONTENT;
+
+    let actual = mozart
+        .oneshot(request)
+        .await
+        .expect("failed to await oneshot");
+
+    assert_eq!(actual.status(), expected_status_code);
+    assert!(!workdir.exists());
+}
+
+#[tokio::test]
+async fn delete_requires_key() {
+    let state = AppState::new(Config {
+        api_key: Some(String::from("secret")),
+        ..Config::default()
+    });
+    let uuid = Uuid::new_v4();
+    let workdir = state.config.working_directory(&uuid);
+    fs::create_dir_all(&workdir).expect("failed to create working directory");
+    let mozart = app(state);
+    let request = Builder::new()
+        .method(Method::DELETE)
+        .uri(format!("/workdir/{uuid}"))
+        .body(Body::empty())
+        .expect("failed to build request");
+    let expected_status_code = StatusCode::UNAUTHORIZED;
+
+    let actual = mozart
+        .oneshot(request)
+        .await
+        .expect("failed to await oneshot");
+
+    assert_eq!(actual.status(), expected_status_code);
+    assert!(workdir.exists());
+    fs::remove_dir_all(&workdir).expect("failed to remove working directory");
+}
+
+#[tokio::test]
+async fn sharded_working_directory() {
+    let parent = PathBuf::from(format!("/mozart/{}", Uuid::new_v4()));
+    let state = AppState::new(Config {
+        workdir: parent.clone(),
+        ..Config::default()
+    });
+
+    // the warmup checks a trivial submission of the enabled language, like any other submission
+    let actual = warmup(state).await;
+
+    // the working directory was created inside a shard, and removed again afterwards
+    let shards = fs::read_dir(&parent)
+        .expect("failed to read parent directory")
+        .map(|entry| entry.expect("failed to read shard").path())
+        .collect::<Vec<_>>();
+    let shard_entries = shards
+        .iter()
+        .map(|shard| fs::read_dir(shard).map_or(0, |entries| entries.count()))
+        .sum::<usize>();
+    fs::remove_dir_all(&parent).expect("failed to remove parent directory");
+
+    assert!(actual.is_ok());
+    assert_eq!(shards.len(), 1);
+    assert_eq!(shards[0].file_name().map(|name| name.len()), Some(2));
+    assert_eq!(shard_entries, 0);
+}
+
+#[tokio::test]
+async fn delete_nonexistent_workdir() {
+    let mozart = app(AppState::default());
+    let request = Builder::new()
+        .method(Method::DELETE)
+        .uri(format!("/workdir/{}", Uuid::new_v4()))
+        .body(Body::empty())
+        .expect("failed to build request");
+    let expected_status_code = StatusCode::NOT_FOUND;
+
+    let actual = mozart
+        .oneshot(request)
+        .await
+        .expect("failed to await oneshot");
+
+    assert_eq!(actual.status(), expected_status_code);
+}
+
+#[tokio::test]
+async fn delete_path_traversal() {
+    let mozart = app(AppState::default());
+    let request = Builder::new()
+        .method(Method::DELETE)
+        .uri("/workdir/..")
+        .body(Body::empty())
+        .expect("failed to build request");
+    let expected_status_code = StatusCode::BAD_REQUEST;
+
+    let actual = mozart
+        .oneshot(request)
+        .await
+        .expect("failed to await oneshot");
+
+    assert_eq!(actual.status(), expected_status_code);
+}
+
+#[tokio::test]
+async fn delete_encoded_path_traversal() {
+    let mozart = app(AppState::default());
+    let request = Builder::new()
+        .method(Method::DELETE)
+        .uri("/workdir/..%2F..%2Fetc")
+        .body(Body::empty())
+        .expect("failed to build request");
+    let expected_status_code = StatusCode::BAD_REQUEST;
+
+    let actual = mozart
+        .oneshot(request)
+        .await
+        .expect("failed to await oneshot");
+
+    assert_eq!(actual.status(), expected_status_code);
+}